@@ -0,0 +1,4908 @@
+import { Buffer } from "buffer";
+import { Address } from "@stellar/stellar-sdk";
+import {
+  AssembledTransaction,
+  Client as ContractClient,
+  ClientOptions as ContractClientOptions,
+  MethodOptions,
+  Result,
+  Spec as ContractSpec,
+} from "@stellar/stellar-sdk/contract";
+import type {
+  u32,
+  i32,
+  u64,
+  i64,
+  u128,
+  i128,
+  u256,
+  i256,
+  Option,
+  Timepoint,
+  Duration,
+} from "@stellar/stellar-sdk/contract";
+export * from "@stellar/stellar-sdk";
+export * as contract from "@stellar/stellar-sdk/contract";
+export * as rpc from "@stellar/stellar-sdk/rpc";
+
+if (typeof window !== "undefined") {
+  //@ts-ignore Buffer exists
+  window.Buffer = window.Buffer || Buffer;
+}
+
+
+
+
+
+/**
+ * * @title Criteria
+ *  * @dev What a token must be to fill a collection offer.
+ */
+export interface Criteria {
+  /**
+ * The earliest week accepted, from 1.
+ */
+first_week: u32;
+  /**
+ * The property, or None for any.
+ */
+hotel_id: Option<u32>;
+  /**
+ * The latest week accepted, up to WEEKS_PER_YEAR.
+ */
+last_week: u32;
+  /**
+ * The tier, or None for any tier or none.
+ */
+tier: Option<string>;
+}
+
+
+/**
+ * * @title CollectionOffer
+ *  * @dev A buyer's escrowed offer for any one token meeting its criteria.
+ */
+export interface CollectionOffer {
+  amount: i128;
+  buyer: string;
+  criteria: Criteria;
+  /**
+ * Ledger timestamp after which the offer can't be filled.
+ */
+expires_at: u64;
+  /**
+ * Token the amount is paid in.
+ */
+payment_token: string;
+}
+
+
+
+
+
+
+/**
+ * * @title Retirement
+ *  * @dev Terms and running totals of a retired property.
+ */
+export interface Retirement {
+  /**
+ * Total deposited to fund payouts.
+ */
+funded: i128;
+  /**
+ * Total paid out (or refunded to the admin by a sweep).
+ */
+paid: i128;
+  /**
+ * Paid per redeemed timeshare token.
+ */
+payout: i128;
+  /**
+ * Tokens redeemed by their owners.
+ */
+redeemed: u32;
+  /**
+ * Unredeemed tokens burned by sweeps.
+ */
+swept: u32;
+  /**
+ * Token payouts are made in.
+ */
+token: string;
+  /**
+ * Ledger timestamp after which tokens can no longer be redeemed.
+ */
+window_end: u64;
+}
+
+
+
+
+/**
+ * * @title Deprecation
+ *  * @dev Deprecation notice for one endpoint.
+ */
+export interface Deprecation {
+  /**
+ * Endpoint to migrate to, if any.
+ */
+replacement: Option<string>;
+  /**
+ * First ledger in which calls fail with EndpointSunset.
+ */
+sunset_ledger: u32;
+}
+
+
+
+/**
+ * * @title TokenPage
+ *  * @dev One page of existing token IDs, in ascending order.
+ */
+export interface TokenPage {
+  /**
+ * Pass as `start_after` to get the next page; None once all IDs are covered.
+ */
+next_start_after: Option<u64>;
+  token_ids: Array<u64>;
+}
+
+
+
+/**
+ * * @title MaintenanceStatus
+ *  * @dev Whether a token's maintenance fee is paid through the current year.
+ */
+export interface MaintenanceStatus {
+  /**
+ * Unpaid while a positive fee is billed.
+ */
+delinquent: boolean;
+  paid: boolean;
+  /**
+ * The last year paid for.
+ */
+paid_through: u32;
+  /**
+ * Years owed, up to and including the current one.
+ */
+unpaid_years: u32;
+  /**
+ * The current calendar year.
+ */
+year: u32;
+}
+
+/**
+ * * @title MaintenanceBilling
+ *  * @dev Whether a maintenance schedule is set, as reported by config(). (A plain
+ *  * Option<MaintenanceSchedule> field can't be converted to an ScVal by the SDK.)
+ */
+export type MaintenanceBilling = {tag: "Disabled", values: void} | {tag: "Enabled", values: readonly [MaintenanceSchedule]};
+
+
+/**
+ * * @title MaintenanceSchedule
+ *  * @dev The annual maintenance fee every token owes.
+ */
+export interface MaintenanceSchedule {
+  /**
+ * Receives the fees.
+ */
+collector: string;
+  /**
+ * The fee per token and year. 0 bills nothing.
+ */
+fee: i128;
+  /**
+ * Token the fee is billed in.
+ */
+token: string;
+}
+
+
+
+
+/**
+ * * @title WithholdingRule
+ *  * @dev What a property's resales withhold from the seller.
+ */
+export interface WithholdingRule {
+  /**
+ * The share withheld, in basis points of the sale price.
+ */
+bps: u32;
+  /**
+ * The jurisdiction whose rules apply, e.g. `ES` or `US_FIRPTA`.
+ */
+jurisdiction: string;
+  /**
+ * Receives what is withheld.
+ */
+recipient: string;
+}
+
+
+
+/**
+ * * @title LabelCode
+ *  * @dev What a label names.
+ */
+export type LabelCode = {tag: "Tier", values: readonly [string]} | {tag: "ErrorCode", values: readonly [u32]} | {tag: "Custom", values: readonly [string]};
+
+
+
+
+
+/**
+ * * @title ForceMajeureFlag
+ *  * @dev An oracle's flag closing a property's week of a year.
+ */
+export interface ForceMajeureFlag {
+  /**
+ * Ledger timestamp it was raised at.
+ */
+flagged_at: u64;
+  /**
+ * The oracle that raised it.
+ */
+oracle: string;
+  /**
+ * Why, e.g. `hurricane`.
+ */
+reason: string;
+}
+
+
+
+
+
+
+/**
+ * * @title Error
+ *  * @dev Every way a call can fail. Endpoints return Result<T, Error> so that
+ *  * client SDKs can tell failure causes apart by code instead of parsing panics.
+ *  * Codes are part of the public interface: never renumber, only append.
+ *  * The contract spec caps an error enum at 50 cases, and this one is full:
+ *  * new failures go in ExtError.
+ */
+export const Errors = {
+  1: {message:"AlreadyInitialized"},
+  2: {message:"NotInitialized"},
+  10: {message:"TokenNotFound"},
+  11: {message:"NotOwner"},
+  12: {message:"InvalidRecipient"},
+  20: {message:"NoPendingTransfer"},
+  21: {message:"PendingTransferExpired"},
+  30: {message:"AlreadyInTrust"},
+  31: {message:"NotInTrust"},
+  32: {message:"NotTrustee"},
+  33: {message:"NotBeneficiary"},
+  34: {message:"TransferNotAcknowledged"},
+  35: {message:"NoPendingBeneficiary"},
+  36: {message:"TimelockNotElapsed"},
+  40: {message:"UnknownAlias"},
+  50: {message:"UnknownNotificationBits"},
+  60: {message:"DailyRootAlreadyCommitted"},
+  70: {message:"EndpointSunset"},
+  80: {message:"NotApproved"},
+  90: {message:"BatchTooLarge"},
+  100: {message:"TokenIdCollision"},
+  110: {message:"RentPoolNotConfigured"},
+  111: {message:"InvalidRentPolicy"},
+  112: {message:"InvalidAmount"},
+  113: {message:"InsufficientRentBalance"},
+  114: {message:"RentNotDue"},
+  120: {message:"PropertyRetired"},
+  121: {message:"AlreadyRetired"},
+  122: {message:"NotRetired"},
+  123: {message:"RedemptionWindowClosed"},
+  124: {message:"RedemptionWindowOpen"},
+  125: {message:"RedemptionUnderfunded"},
+  126: {message:"WrongProperty"},
+  130: {message:"NoPendingAdmin"},
+  140: {message:"MissingRole"},
+  150: {message:"ContractPaused"},
+  160: {message:"InvalidWeek"},
+  161: {message:"DuplicateTimeshare"},
+  162: {message:"InvalidLeaseTerm"},
+  163: {message:"LeaseExpired"},
+  170: {message:"HotelNotFound"},
+  171: {message:"DuplicateHotel"},
+  180: {message:"RoomNotFound"},
+  181: {message:"DuplicateRoom"},
+  182: {message:"RoomRetired"},
+  190: {message:"InvalidUsageState"},
+  191: {message:"CheckInNotConfirmed"},
+  200: {message:"InvalidSwap"},
+  210: {message:"NotListed"},
+  211: {message:"ListingClosed"}
+}
+
+
+/**
+ * * @title Token
+ *  * @dev A token's details together with its current owner, returned by get_token().
+ */
+export interface Token {
+  info: TimeshareInfo;
+  owner: string;
+  token_id: u64;
+}
+
+/**
+ * * @title ExtKey
+ *  * @dev More storage keys, in the same role as DataKey. The contract spec caps
+ *  * an enum at 50 cases and DataKey is full, so newer keys are added here.
+ */
+export type ExtKey = {tag: "VoucherSigner", values: void} | {tag: "Voucher", values: readonly [Buffer]} | {tag: "Allowlist", values: void} | {tag: "AllowlistClaim", values: readonly [Buffer, string]} | {tag: "TokenUri", values: readonly [u64]} | {tag: "MetadataFrozen", values: readonly [u64]} | {tag: "MintPrice", values: readonly [u32]} | {tag: "Payees", values: void} | {tag: "Payable", values: readonly [string, string]} | {tag: "CoolingOff", values: void} | {tag: "Purchase", values: readonly [u64]} | {tag: "SupplyCap", values: readonly [u32]} | {tag: "HotelSupply", values: readonly [u32]} | {tag: "ShareRound", values: readonly [u64]} | {tag: "MetricsLane", values: readonly [u64, string, u32]} | {tag: "YearClaims", values: readonly [Buffer]} | {tag: "BillingSince", values: void} | {tag: "CappedHotels", values: void} | {tag: "SupplyRecount", values: void} | {tag: "ReserveShare", values: readonly [u32]} | {tag: "Reserve", values: readonly [u32]} | {tag: "ReserveSpend", values: readonly [u32, u32]} | {tag: "ReserveHotels", values: void} | {tag: "Directory", values: readonly [u32]} | {tag: "DirectoryEntry", values: readonly [u32, string]} | {tag: "CheckinKey", values: readonly [u64]} | {tag: "ReceiptsEnabled", values: void} | {tag: "Receipt", values: readonly [u64, u32]} | {tag: "ReceiptCount", values: readonly [u64]} | {tag: "PartyReceipt", values: readonly [string, u32]} | {tag: "PartyReceiptCount", values: readonly [string]} | {tag: "Withholding", values: readonly [u32]} | {tag: "WithholdingExempt", values: readonly [string, string]} | {tag: "Withheld", values: readonly [string, u32, string]} | {tag: "MinorUnits", values: readonly [u32]} | {tag: "SealedAuction", values: readonly [u64]} | {tag: "SealedBid", values: readonly [u64, string]} | {tag: "HeldShares", values: readonly [string]} | {tag: "PriceMark", values: readonly [u64]} | {tag: "Appraisal", values: readonly [u64]} | {tag: "Tier", values: readonly [u64]} | {tag: "CollectionOffer", values: readonly [u32]} | {tag: "CollectionOfferCount", values: void} | {tag: "TierIndex", values: readonly [u32, string]} | {tag: "WeekIndex", values: readonly [u32]} | {tag: "SwapChain", values: readonly [u32]} | {tag: "SwapChainCount", values: void} | {tag: "ChainOf", values: readonly [u64]} | {tag: "SwapIntent", values: readonly [u64]} | {tag: "SwapIntents", values: void};
+
+/**
+ * * @title DataKey
+ *  * @dev We use this enum to create organized, unique keys for our contract's storage.
+ *  * This is a common pattern in Soroban to avoid "key collisions".
+ */
+export type DataKey = {tag: "Info", values: readonly [u64]} | {tag: "Owner", values: readonly [u64]} | {tag: "NotifyPrefs", values: readonly [string]} | {tag: "Trust", values: readonly [u64]} | {tag: "TrustAck", values: readonly [u64]} | {tag: "PendingBeneficiary", values: readonly [u64]} | {tag: "Alias", values: readonly [string, Buffer]} | {tag: "ConfirmTransfers", values: readonly [string]} | {tag: "PendingTransfer", values: readonly [u64]} | {tag: "Sink", values: readonly [string]} | {tag: "UpgradeHistory", values: void} | {tag: "EventLane", values: readonly [u32]} | {tag: "DailyRoot", values: readonly [u64]} | {tag: "Metrics", values: readonly [u64, string]} | {tag: "Deprecations", values: void} | {tag: "OwnedTokens", values: readonly [string]} | {tag: "Approval", values: readonly [u64]} | {tag: "Operator", values: readonly [string, string]} | {tag: "DailyLanes", values: readonly [u64]} | {tag: "MetricsIndex", values: readonly [u64]} | {tag: "Minter", values: readonly [string]} | {tag: "Minters", values: void} | {tag: "Burned", values: void} | {tag: "RentPolicy", values: void} | {tag: "RentBalance", values: readonly [string]} | {tag: "RentDue", values: readonly [string, u32]} | {tag: "Retirement", values: readonly [u32]} | {tag: "PendingAdmin", values: void} | {tag: "Role", values: readonly [Role, string]} | {tag: "Slot", values: readonly [Buffer]} | {tag: "Hotel", values: readonly [u32]} | {tag: "HotelName", values: readonly [string]} | {tag: "HotelCount", values: void} | {tag: "Room", values: readonly [u32, string]} | {tag: "Rooms", values: readonly [u32]} | {tag: "Usage", values: readonly [u64]} | {tag: "Rental", values: readonly [u64]} | {tag: "MaintenanceSchedule", values: void} | {tag: "Maintenance", values: readonly [u64]} | {tag: "Frozen", values: readonly [u64]} | {tag: "Clawback", values: readonly [u64]} | {tag: "Listing", values: readonly [u64]} | {tag: "Auction", values: readonly [u64]} | {tag: "DutchAuction", values: readonly [u64]} | {tag: "Offer", values: readonly [u64, string]} | {tag: "Trade", values: readonly [u64]} | {tag: "Royalty", values: readonly [u32]} | {tag: "Fraction", values: readonly [u64]} | {tag: "Shares", values: readonly [u64, string]};
+
+/**
+ * * @title ExtKey2
+ *  * @dev Yet more storage keys, in the same role as DataKey and ExtKey, which
+ *  * are both full.
+ */
+export type ExtKey2 = {tag: "Branding", values: readonly [u32]} | {tag: "Labels", values: readonly [string]} | {tag: "Locales", values: void} | {tag: "PrepayDiscounts", values: void} | {tag: "Prepaid", values: readonly [u64, u32]} | {tag: "PrepaidYears", values: readonly [u64]} | {tag: "LateFeePolicy", values: void} | {tag: "Delinquency", values: readonly [u64]} | {tag: "GracePeriods", values: void} | {tag: "PendingGrace", values: readonly [GraceRule]} | {tag: "DepthBook", values: readonly [u32, Option<string>, string]} | {tag: "Buyback", values: readonly [u32, string]} | {tag: "Buybacks", values: void} | {tag: "FirstRefusalHotels", values: void} | {tag: "FirstRefusal", values: readonly [u64]} | {tag: "OperatorUntil", values: readonly [string, string]} | {tag: "Charity", values: readonly [string]} | {tag: "CharitySplit", values: readonly [u32]} | {tag: "Donated", values: readonly [string, u32, string]} | {tag: "HotelDonated", values: readonly [u32, u32, string]} | {tag: "FeeIndex", values: void} | {tag: "FeeIndexedYear", values: void} | {tag: "FeeBilledYear", values: void} | {tag: "ForceMajeure", values: readonly [u32, u32, u32]} | {tag: "ForceMajeureSettled", values: readonly [u64]} | {tag: "ReliefFund", values: readonly [u32, string]} | {tag: "ReplacementCredits", values: readonly [string, u32]} | {tag: "Reminded", values: readonly [u64]} | {tag: "StatsPrivacy", values: void};
+
+/**
+ * * @title ExtError
+ *  * @dev More ways a call can fail, with codes of their own after Error's. A
+ *  * function can only return one error type, so endpoints keep returning
+ *  * Error and raise these with fail(): the host reports a raised code exactly
+ *  * like a returned one, and clients look both enums up by code. It is full
+ *  * too: newer failures go in ExtError2.
+ */
+export const ExtError = {
+  300: {message:"SupplyCapReached"},
+  310: {message:"NoAuction"},
+  311: {message:"AuctionRunning"},
+  312: {message:"AuctionEnded"},
+  320: {message:"NoOffer"},
+  321: {message:"OfferExpired"},
+  330: {message:"NoTrade"},
+  340: {message:"NotFractionalized"},
+  341: {message:"InsufficientShares"},
+  350: {message:"NoVoucherSigner"},
+  351: {message:"VoucherExpired"},
+  352: {message:"VoucherRedeemed"},
+  353: {message:"WrongBuyer"},
+  360: {message:"NoAllowlist"},
+  361: {message:"NotAllowlisted"},
+  362: {message:"AllowlistClaimed"},
+  370: {message:"MetadataFrozen"},
+  380: {message:"TooManyPayees"},
+  381: {message:"InvalidSplit"},
+  390: {message:"NoPurchase"},
+  391: {message:"CoolingOffEnded"},
+  392: {message:"CoolingOffRunning"},
+  400: {message:"TokenFrozen"},
+  410: {message:"RentalActive"},
+  420: {message:"InsufficientReserve"},
+  421: {message:"NoSpendProposal"},
+  430: {message:"NoCheckinKey"},
+  431: {message:"CheckinCodeExpired"},
+  432: {message:"CheckinCodeUsed"},
+  440: {message:"AmountOverflow"},
+  441: {message:"NegativeAmount"},
+  442: {message:"InvalidBps"},
+  443: {message:"InvalidMinorUnit"},
+  450: {message:"NoCommitment"},
+  451: {message:"CommitmentMismatch"},
+  460: {message:"NoCollectionOffer"},
+  461: {message:"CriteriaMismatch"},
+  470: {message:"NoSwapChain"},
+  480: {message:"NoSwapIntent"},
+  481: {message:"IntentBookFull"},
+  490: {message:"InvalidBranding"},
+  500: {message:"LabelTooLong"},
+  501: {message:"TooManyLabels"},
+  510: {message:"MaintenanceArrears"},
+  520: {message:"NoPendingGracePeriod"},
+  530: {message:"NoBuyback"},
+  531: {message:"InvalidBuyback"},
+  532: {message:"BuybackNotOpen"},
+  533: {message:"BuybackExhausted"},
+  534: {message:"TooManyBuybacks"}
+}
+
+/**
+ * * @title ExtError2
+ *  * @dev Failures added once ExtError was full, raised with fail() like it,
+ *  * with codes after ExtError's.
+ */
+export const ExtError2 = {
+  540: {message:"NoFirstRefusal"},
+  541: {message:"FirstRefusalOpen"},
+  542: {message:"FirstRefusalLapsed"},
+  550: {message:"NotCharity"},
+  551: {message:"DuplicateCharity"},
+  560: {message:"NoFeeIndex"},
+  561: {message:"FeeAlreadyIndexed"},
+  562: {message:"StaleIndex"},
+  563: {message:"FeeIndexed"},
+  564: {message:"FeeYearBilled"},
+  570: {message:"NotForceMajeure"},
+  571: {message:"WeekClosed"},
+  572: {message:"AlreadySettled"},
+  573: {message:"ReliefUnfunded"},
+  574: {message:"NoReplacementCredit"}
+}
+
+
+/**
+ * * @title TimeshareInfo
+ *  * @dev This struct holds the specific details for a single timeshare token.
+ *  * It's "Clone" so we can copy it, and "Debug/Eq/PartialEq" for testing/logging.
+ */
+export interface TimeshareInfo {
+  hotel_id: u32;
+  lease_end_ledger: Option<u32>;
+  room: string;
+  week: u32;
+  year: Option<u32>;
+}
+
+
+/**
+ * * @title MinterState
+ *  * @dev A minter's current ID block and how many tokens it has minted in total.
+ */
+export interface MinterState {
+  /**
+ * Last ID of the block (inclusive).
+ */
+end: u64;
+  minted: u64;
+  /**
+ * Next ID to issue from the block.
+ */
+next: u64;
+}
+
+
+/**
+ * * @title LateFees
+ *  * @dev Whether late fees are charged, as reported by config().
+ */
+export type LateFees = {tag: "Disabled", values: void} | {tag: "Enabled", values: readonly [LateFeePolicy]};
+
+
+/**
+ * * @title Delinquency
+ *  * @dev The delinquency level a keeper last recorded for a token.
+ */
+export interface Delinquency {
+  /**
+ * How many of the policy's thresholds the token had crossed.
+ */
+level: u32;
+  /**
+ * The oldest unpaid year.
+ */
+year: u32;
+}
+
+
+/**
+ * * @title LateFeePolicy
+ *  * @dev What owners pay for settling a year's maintenance fee late.
+ */
+export interface LateFeePolicy {
+  /**
+ * Charged once the fee is overdue.
+ */
+flat: i128;
+  /**
+ * The most the late fee can reach, flat fee included.
+ */
+max: i128;
+  /**
+ * Seconds per interest period; must not be 0.
+ */
+period: u64;
+  /**
+ * Interest per whole period overdue, in basis points of the fee; at
+ * most FULL_BPS.
+ */
+rate_bps: u32;
+  /**
+ * Seconds overdue at which a token reaches each delinquency level, in
+ * ascending order.
+ */
+thresholds: Array<u64>;
+}
+
+
+/**
+ * * @title RentPool
+ *  * @dev Whether the rent pool is configured, as reported by config(). (A plain
+ *  * Option<RentPolicy> field can't be converted to an ScVal by the SDK.)
+ */
+export type RentPool = {tag: "Disabled", values: void} | {tag: "Enabled", values: readonly [RentPolicy]};
+
+
+/**
+ * * @title RentPolicy
+ *  * @dev How the rent pool is funded and what keepers are paid for.
+ */
+export interface RentPolicy {
+  /**
+ * TTL, in ledgers, entries are bumped to.
+ */
+extend_to: u32;
+  /**
+ * Paid to the keeper for each token kept alive.
+ */
+fee_per_token: i128;
+  /**
+ * Balance under which owners are warned after a charge.
+ */
+low_balance: i128;
+  /**
+ * TTL, in ledgers, under which entries are bumped.
+ */
+threshold: u32;
+  /**
+ * Token balances are held in. Fixed once set.
+ */
+token: string;
+}
+
+
+
+
+
+
+
+
+
+
+
+/**
+ * * @title DepthBook
+ *  * @dev A book as stored: its listing count and the cheapest listings, as
+ *  * (price, token ID) in ascending order.
+ */
+export interface DepthBook {
+  cheapest: Array<readonly [i128, u64]>;
+  listings: u32;
+}
+
+
+/**
+ * * @title MarketDepth
+ *  * @dev The listings of a property's tier in one asset.
+ */
+export interface MarketDepth {
+  /**
+ * The tracked listings' token IDs, cheapest first, ties by token ID.
+ */
+cheapest: Array<u64>;
+  /**
+ * The lowest listed price; None without listings, or while unknown.
+ */
+floor: Option<i128>;
+  /**
+ * How many tokens are listed.
+ */
+listings: u32;
+}
+
+
+/**
+ * * @title DutchAuction
+ *  * @dev A token offered at a declining price.
+ */
+export interface DutchAuction {
+  /**
+ * How much the price falls each ledger.
+ */
+decay_per_ledger: i128;
+  /**
+ * The price never falls below this.
+ */
+floor_price: i128;
+  /**
+ * Token the price is paid in.
+ */
+payment_token: string;
+  /**
+ * The admin who offered the token, and is paid for it.
+ */
+seller: string;
+  /**
+ * Ledger sequence the price starts falling from.
+ */
+start_ledger: u32;
+  start_price: i128;
+}
+
+
+/**
+ * * @title GraceRule
+ *  * @dev A window whose length the grace period table sets.
+ */
+export type GraceRule = {tag: "PendingTransfer", values: void} | {tag: "SealedReveal", values: void} | {tag: "Delinquency", values: void} | {tag: "FirstRefusal", values: void};
+
+
+/**
+ * * @title PendingGracePeriod
+ *  * @dev A grace period change waiting for its timelock to pass.
+ */
+export interface PendingGracePeriod {
+  /**
+ * Ledger timestamp from which it can be executed.
+ */
+effective_at: u64;
+  seconds: u64;
+}
+
+
+
+
+
+
+/**
+ * * @title Role
+ *  * @dev A delegated permission; see the module comment.
+ */
+export type Role = {tag: "Minter", values: void} | {tag: "Manager", values: void} | {tag: "Pauser", values: void} | {tag: "Compliance", values: void} | {tag: "Oracle", values: void};
+
+
+
+
+
+
+/**
+ * * @title Trust
+ *  * @dev The parties of a token held in trust. The trustee is also the token's owner.
+ */
+export interface Trust {
+  beneficiary: string;
+  trustee: string;
+}
+
+
+
+/**
+ * * @title PendingBeneficiary
+ *  * @dev A beneficiary change waiting for its timelock to pass.
+ */
+export interface PendingBeneficiary {
+  beneficiary: string;
+  effective_at: u64;
+}
+
+
+
+/**
+ * * @title Usage
+ *  * @dev A token's usage record for one year.
+ */
+export interface Usage {
+  /**
+ * Whether the front desk confirmed the check-in.
+ */
+confirmed: boolean;
+  state: UsageState;
+  /**
+ * The calendar year the record is for.
+ */
+year: u32;
+}
+
+
+/**
+ * * @title UsageState
+ *  * @dev Where a token's week stands this year.
+ */
+export type UsageState = {tag: "Unused", values: void} | {tag: "CheckedIn", values: void} | {tag: "Consumed", values: void};
+
+
+
+
+/**
+ * * @title ChainLeg
+ *  * @dev One owner's part of a swap chain: the token they give up.
+ */
+export interface ChainLeg {
+  owner: string;
+  token_id: u64;
+}
+
+
+/**
+ * * @title SwapChain
+ *  * @dev A proposed cycle of swaps. Each leg's token goes to the owner of the
+ *  * next leg, and the last leg's to the first.
+ */
+export interface SwapChain {
+  /**
+ * Whether each leg's token is escrowed, in the order of `legs`.
+ */
+joined: Array<boolean>;
+  legs: Array<ChainLeg>;
+}
+
+
+
+
+
+
+/**
+ * * @title ContractConfig
+ *  * @dev Snapshot of everything that shapes the contract's behavior.
+ */
+export interface ContractConfig {
+  /**
+ * The hotel operator.
+ */
+admin: string;
+  /**
+ * Seconds before a trust beneficiary change applies.
+ */
+beneficiary_timelock: u64;
+  /**
+ * Display settings of properties, by hotel ID.
+ */
+brandings: Map<u32, Branding>;
+  /**
+ * Open buybacks, in the order they were opened.
+ */
+buybacks: Array<Buyback>;
+  /**
+ * Charity splits of royalties, by hotel ID (see `charity_split`).
+ */
+charity_splits: Map<u32, Array<readonly [string, u32]>>;
+  /**
+ * The cooling-off terms of new primary sales; a period of 0 while off.
+ */
+cooling_off: CoolingOff;
+  /**
+ * Deprecated endpoints and their sunsets.
+ */
+deprecations: Map<string, Deprecation>;
+  /**
+ * How the maintenance fee follows an index, once linked.
+ */
+fee_index: FeeIndexing;
+  /**
+ * Properties whose sales the hotel has a right of first refusal on.
+ */
+first_refusal_hotels: Array<u32>;
+  /**
+ * Grace periods in force, in seconds by rule (see `policy`).
+ */
+grace_periods: Map<GraceRule, u64>;
+  /**
+ * Highest token ID reserved so far; IDs above it are unused (see ids.rs).
+ */
+last_token_id: u64;
+  /**
+ * The late fee policy of overdue maintenance, once set.
+ */
+late_fees: LateFees;
+  /**
+ * Locales with localized labels (see `labels`).
+ */
+locales: Array<string>;
+  /**
+ * The annual maintenance fee schedule, once set.
+ */
+maintenance: MaintenanceBilling;
+  /**
+ * Minor units of payment assets, by hotel ID.
+ */
+minor_units: Map<u32, Map<string, i128>>;
+  /**
+ * Whether mints, transfers and payments are paused.
+ */
+paused: boolean;
+  /**
+ * The revenue split: each payee and its share in basis points; empty
+ * while proceeds go to the admin.
+ */
+payees: Array<readonly [string, u32]>;
+  /**
+ * Proposed next admin, while a handover is pending.
+ */
+pending_admin: Option<string>;
+  /**
+ * Seconds a recipient has to accept a two-phase transfer.
+ */
+pending_transfer_window: u64;
+  /**
+ * Maintenance prepayment discounts in basis points, entry n - 1 for
+ * prepaying n years.
+ */
+prepay_discounts: Array<u32>;
+  /**
+ * Whether sales and trades are recorded as receipts.
+ */
+receipts: boolean;
+  /**
+ * The rent pool's policy, once configured.
+ */
+rent_pool: RentPool;
+  /**
+ * Shares of maintenance fees held in reserve, in basis points by hotel ID.
+ */
+reserve_shares: Map<u32, u32>;
+  /**
+ * Layout version of the stored data (see migration.rs).
+ */
+schema_version: u32;
+  /**
+ * How exact the public stats endpoints are (see `stats_privacy`).
+ */
+stats_privacy: StatsPrivacy;
+  /**
+ * Supply caps, by hotel ID.
+ */
+supply_caps: Map<u32, u32>;
+  /**
+ * TTL, in ledgers, those automatic bumps extend a token to.
+ */
+token_ttl_extend_to: u32;
+  /**
+ * TTL, in ledgers, under which mints, transfers and reads bump a token.
+ */
+token_ttl_threshold: u32;
+  /**
+ * Crate version of the running build.
+ */
+version: string;
+  /**
+ * The ed25519 key sale vouchers are signed with, once registered.
+ */
+voucher_signer: Option<Buffer>;
+  /**
+ * Tax withholding rules of resales, by hotel ID.
+ */
+withholdings: Map<u32, WithholdingRule>;
+}
+
+
+/**
+ * * @title Trade
+ *  * @dev An open trade, keyed by the escrowed token's ID.
+ */
+export interface Trade {
+  /**
+ * Who may accept, by giving up the wanted token.
+ */
+counterparty: string;
+  /**
+ * Who escrowed the token, and receives the wanted one.
+ */
+owner: string;
+  wanted_token_id: u64;
+}
+
+
+
+
+
+
+
+/**
+ * * @title EventChain
+ *  * @dev Head of the event hash chain (or of one lane) and the number of events folded into it.
+ */
+export interface EventChain {
+  count: u64;
+  head: Buffer;
+}
+
+
+
+
+
+/**
+ * * @title Hotel
+ *  * @dev A registered property.
+ */
+export interface Hotel {
+  id: u32;
+  name: string;
+}
+
+
+
+
+
+
+/**
+ * * @title Listing
+ *  * @dev A token offered for sale.
+ */
+export interface Listing {
+  /**
+ * Token the price is paid in.
+ */
+payment_token: string;
+  price: i128;
+  /**
+ * The owner who listed the token, and is paid for it.
+ */
+seller: string;
+}
+
+
+
+/**
+ * * @title Offer
+ *  * @dev A buyer's escrowed offer for a token.
+ */
+export interface Offer {
+  amount: i128;
+  /**
+ * Ledger timestamp after which the offer can't be accepted.
+ */
+expires_at: u64;
+  /**
+ * Token the amount is paid in.
+ */
+payment_token: string;
+}
+
+
+
+
+
+/**
+ * * @title FeeQuote
+ *  * @dev One year's maintenance fee in a prepayment.
+ */
+export interface FeeQuote {
+  /**
+ * The fee after the prepayment discount.
+ */
+fee: i128;
+  year: u32;
+}
+
+
+/**
+ * * @title Prepayment
+ *  * @dev A prepaid year's fee, held by the contract until the year begins.
+ */
+export interface Prepayment {
+  amount: i128;
+  /**
+ * Token the fee was paid in.
+ */
+token: string;
+}
+
+
+/**
+ * * @title PrepaidYears
+ *  * @dev The years of a token whose prepaid fees the contract still holds.
+ */
+export interface PrepaidYears {
+  first: u32;
+  last: u32;
+}
+
+
+
+
+/**
+ * * @title Rental
+ *  * @dev A token's rental listing for one year, and its renter once taken.
+ */
+export interface Rental {
+  /**
+ * The owner who listed the week, and is paid the rent.
+ */
+lister: string;
+  price: i128;
+  /**
+ * Who rented the week, once someone has.
+ */
+renter: Option<string>;
+  /**
+ * Ledger timestamp after which the listing can no longer be taken.
+ */
+renter_window: u64;
+  /**
+ * Token the rent is paid in.
+ */
+token: string;
+  /**
+ * The calendar year the week is rented for.
+ */
+year: u32;
+}
+
+
+
+
+
+
+
+/**
+ * * @title SealedAuction
+ *  * @dev A token's sealed-bid second-price auction.
+ */
+export interface SealedAuction {
+  /**
+ * Everyone who committed, in order.
+ */
+bidders: Array<string>;
+  /**
+ * Posted with each commitment; forfeited to the seller if not revealed.
+ */
+bond: i128;
+  /**
+ * Ledger timestamp at which commitments close and reveals open.
+ */
+commit_ends_at: u64;
+  high_bid: i128;
+  /**
+ * The highest revealed bidder so far, whose bid the contract holds.
+ */
+high_bidder: Option<string>;
+  /**
+ * Token bids and bonds are paid in.
+ */
+payment_token: string;
+  /**
+ * The lowest bid that counts, and the lowest price paid.
+ */
+reserve: i128;
+  /**
+ * Ledger timestamp at which reveals close.
+ */
+reveal_ends_at: u64;
+  /**
+ * The second-highest revealed bid, 0 if there is none.
+ */
+second_bid: i128;
+  /**
+ * The owner who started the auction, and is paid for the token.
+ */
+seller: string;
+}
+
+
+
+
+
+/**
+ * * @title ShareBalance
+ *  * @dev A holder's shares of a token, in the fractionalization round they were
+ *  * issued in.
+ */
+export interface ShareBalance {
+  amount: u32;
+  round: u32;
+}
+
+
+
+
+
+/**
+ * * @title Auction
+ *  * @dev A token's running auction.
+ */
+export interface Auction {
+  /**
+ * Ledger timestamp at which bidding closes.
+ */
+ends_at: u64;
+  high_bid: i128;
+  /**
+ * The highest bidder so far, whose bid the contract holds.
+ */
+high_bidder: Option<string>;
+  /**
+ * Token bids are made in.
+ */
+payment_token: string;
+  /**
+ * The lowest acceptable first bid.
+ */
+reserve: i128;
+  /**
+ * The owner who started the auction, and is paid the winning bid.
+ */
+seller: string;
+}
+
+
+
+
+
+
+/**
+ * * @title Buyback
+ *  * @dev The hotel's standing bid for the tokens of one of a property's tiers.
+ */
+export interface Buyback {
+  /**
+ * What is left of the funds the contract holds for the buyback.
+ */
+budget: i128;
+  /**
+ * Receives the tokens bought back and, on closing, the rest of the
+ * budget: the admin who last funded it.
+ */
+buyer: string;
+  /**
+ * Ledger timestamp after which owners can't sell.
+ */
+closes_at: u64;
+  /**
+ * Whether it paused itself because the budget can't pay the price.
+ */
+exhausted: boolean;
+  hotel_id: u32;
+  /**
+ * Ledger timestamp from which owners can sell.
+ */
+opens_at: u64;
+  /**
+ * Token the price is paid in. Fixed once funded.
+ */
+payment_token: string;
+  /**
+ * Paid for each token sold to the hotel.
+ */
+price: i128;
+  tier: string;
+}
+
+
+
+
+
+
+
+
+
+
+/**
+ * * @title CheckinKey
+ *  * @dev The key a guest's device signs a token's check-in codes with.
+ */
+export interface CheckinKey {
+  /**
+ * Who registered the key.
+ */
+guest: string;
+  /**
+ * The highest nonce used so far, 0 before the first code.
+ */
+nonce: u64;
+  public_key: Buffer;
+}
+
+
+/**
+ * * @title CheckinCode
+ *  * @dev What a guest's QR code carries, next to its signature.
+ */
+export interface CheckinCode {
+  /**
+ * Ledger timestamp after which the code can't be used.
+ */
+expires_at: u64;
+  /**
+ * Must be higher than any nonce used for the token before.
+ */
+nonce: u64;
+  token_id: u64;
+  /**
+ * The calendar year of the stay.
+ */
+year: u32;
+}
+
+
+/**
+ * * @title SwapIntent
+ *  * @dev An owner's standing offer to swap an escrowed token for any token
+ *  * meeting `want`.
+ */
+export interface SwapIntent {
+  owner: string;
+  token_id: u64;
+  want: Criteria;
+}
+
+
+
+
+
+/**
+ * * @title PendingTransfer
+ *  * @dev A transfer waiting for the recipient's acceptance.
+ */
+export interface PendingTransfer {
+  expires_at: u64;
+  from: string;
+  to: string;
+}
+
+
+
+/**
+ * * @title StatsPrivacy
+ *  * @dev How exact the public stats endpoints are; see the module comment.
+ */
+export interface StatsPrivacy {
+  /**
+ * Counts below it read as 0.
+ */
+min_count: u32;
+  /**
+ * Counts are rounded down to a multiple of it; at least 1.
+ */
+round_to: u32;
+}
+
+
+
+/**
+ * * @title Purchase
+ *  * @dev A primary sale whose proceeds are escrowed until its cooling-off period ends.
+ */
+export interface Purchase {
+  buyer: string;
+  /**
+ * Last ledger sequence the buyer may cancel at.
+ */
+cancel_until: u32;
+  payment_token: string;
+  price: i128;
+  /**
+ * Share of the price refunded on cancellation, as configured at the sale.
+ */
+refund_bps: u32;
+  /**
+ * Who is paid once the period ends.
+ */
+seller: string;
+}
+
+
+/**
+ * * @title CoolingOff
+ *  * @dev How long buyers have to cancel a primary purchase, and what they get back.
+ */
+export interface CoolingOff {
+  /**
+ * Ledgers after the sale during which the buyer may cancel.
+ */
+period: u32;
+  /**
+ * Share of the price refunded on cancellation, in basis points.
+ */
+refund_bps: u32;
+}
+
+
+
+
+/**
+ * * @title FirstRefusal
+ *  * @dev An accepted offer waiting out the hotel's right of first refusal,
+ *  * keyed by the token's ID.
+ */
+export interface FirstRefusal {
+  /**
+ * Who made the offer, and receives the token if the hotel doesn't match it.
+ */
+buyer: string;
+  /**
+ * Ledger timestamp after which the hotel can no longer match it.
+ */
+ends_at: u64;
+  /**
+ * The escrowed offer.
+ */
+offer: Offer;
+  /**
+ * The owner who accepted the offer, and is paid for the token.
+ */
+seller: string;
+}
+
+
+
+
+/**
+ * * @title SpendState
+ *  * @dev Where a spend proposal stands.
+ */
+export type SpendState = {tag: "Pending", values: void} | {tag: "Executed", values: void} | {tag: "Cancelled", values: void};
+
+
+/**
+ * * @title ReserveFund
+ *  * @dev A hotel's maintenance reserve.
+ */
+export interface ReserveFund {
+  /**
+ * What the contract holds for the reserve.
+ */
+balance: i128;
+  /**
+ * What pending proposals will pay out; never more than the balance.
+ */
+committed: i128;
+  /**
+ * Everything ever paid in, from fees and top-ups.
+ */
+funded: i128;
+  /**
+ * Number of spend proposals made so far; they are numbered from 1.
+ */
+proposals: u32;
+  /**
+ * Everything ever paid out by executed proposals.
+ */
+spent: i128;
+  /**
+ * The token the reserve is held in.
+ */
+token: string;
+}
+
+
+
+/**
+ * * @title ReserveReport
+ *  * @dev A hotel's reserve share and fund, as returned by reserve_report().
+ *  * The fund's fields are 0 until the reserve is first funded.
+ */
+export interface ReserveReport {
+  balance: i128;
+  committed: i128;
+  funded: i128;
+  proposals: u32;
+  share_bps: u32;
+  spent: i128;
+  /**
+ * None until the reserve is first funded.
+ */
+token: Option<string>;
+}
+
+
+/**
+ * * @title SpendProposal
+ *  * @dev A proposed payment out of a hotel's reserve.
+ */
+export interface SpendProposal {
+  amount: i128;
+  /**
+ * Ledger timestamp from which the payment can be executed.
+ */
+executable_at: u64;
+  /**
+ * What the payment is for, e.g. "Roof repair, invoice 2031-17".
+ */
+memo: string;
+  recipient: string;
+  state: SpendState;
+}
+
+
+
+
+
+
+/**
+ * * @title VersionInfo
+ *  * @dev Returned by version_info().
+ */
+export interface VersionInfo {
+  upgrades: Array<UpgradeRecord>;
+  version: string;
+  wasm_hash: Option<Buffer>;
+}
+
+
+/**
+ * * @title UpgradeRecord
+ *  * @dev One entry in the on-chain upgrade log.
+ */
+export interface UpgradeRecord {
+  ledger: u32;
+  scheduler: string;
+  wasm_hash: Buffer;
+}
+
+
+/**
+ * * @title Branding
+ *  * @dev How a property's frontends present it.
+ */
+export interface Branding {
+  /**
+ * Colors as 0xRRGGBB, primary first; at most MAX_BRAND_COLORS.
+ */
+colors: Array<u32>;
+  display_name: string;
+  logo_uri: string;
+  /**
+ * SHA-256 of the support contact, e.g. an email address, so frontends
+ * can check one without the contract publishing it.
+ */
+support_contact_hash: Buffer;
+}
+
+
+
+
+/**
+ * * @title ClawbackRecord
+ *  * @dev The last clawback of a token.
+ */
+export interface ClawbackRecord {
+  /**
+ * The owner the token was taken from.
+ */
+from: string;
+  /**
+ * Ledger sequence of the clawback.
+ */
+ledger: u32;
+  reason_code: u32;
+  /**
+ * Who received it, or None if it was burned.
+ */
+to: Option<string>;
+}
+
+
+
+
+
+
+/**
+ * * @title Receipt
+ *  * @dev The terms of a completed sale or trade.
+ */
+export interface Receipt {
+  /**
+ * Who received the token.
+ */
+buyer: string;
+  kind: ReceiptKind;
+  /**
+ * None for trades.
+ */
+payment_token: Option<string>;
+  /**
+ * What the buyer paid, royalty included; 0 for trades.
+ */
+price: i128;
+  /**
+ * Who gave it up; the admin for primary sales.
+ */
+seller: string;
+  /**
+ * Ledger timestamp of the settlement.
+ */
+timestamp: u64;
+  token_id: u64;
+  /**
+ * For trades, the token the buyer gave in exchange.
+ */
+traded_for: Option<u64>;
+}
+
+/**
+ * * @title ReceiptKind
+ *  * @dev How a token changed hands.
+ */
+export type ReceiptKind = {tag: "Resale", values: void} | {tag: "PrimarySale", values: void} | {tag: "Trade", values: void};
+
+
+
+
+
+/**
+ * * @title Voucher
+ *  * @dev The admin's signed promise to mint a week for a price.
+ */
+export interface Voucher {
+  /**
+ * The only buyer who may redeem it, or None for anyone.
+ */
+buyer: Option<string>;
+  /**
+ * Ledger timestamp after which it can't be redeemed.
+ */
+expires_at: u64;
+  /**
+ * The token to mint.
+ */
+info: TimeshareInfo;
+  /**
+ * Token the price is paid in.
+ */
+payment_token: string;
+  price: i128;
+}
+
+
+
+/**
+ * * @title Allowlist
+ *  * @dev An allowlist sale phase.
+ */
+export interface Allowlist {
+  /**
+ * Token the price is paid in.
+ */
+payment_token: string;
+  price: i128;
+  /**
+ * Merkle root of the eligible addresses.
+ */
+root: Buffer;
+}
+
+
+
+
+
+/**
+ * * @title DirectoryEntry
+ *  * @dev An owner listed in a property's directory.
+ */
+export interface DirectoryEntry {
+  /**
+ * Hash of the owner's contact details and a salt, both kept off-chain.
+ */
+contact_hash: Buffer;
+  owner: string;
+  /**
+ * Asks list builders to use the contact for property notices only and
+ * never share it onwards.
+ */
+private: boolean;
+}
+
+
+
+/**
+ * * @title FeeIndex
+ *  * @dev How the maintenance fee follows an oracle's index.
+ */
+export interface FeeIndex {
+  /**
+ * The fee when the index reads `base_index`.
+ */
+base_fee: i128;
+  /**
+ * A reading of the index, in the oracle's units; must be positive.
+ */
+base_index: i128;
+  /**
+ * The index's feed on the oracle.
+ */
+feed: string;
+  /**
+ * Seconds after which a reading is too old to use.
+ */
+max_age: u64;
+  /**
+ * The highest the indexed fee may go.
+ */
+max_fee: i128;
+  /**
+ * The most the fee may move per year, in the fee's token.
+ */
+max_step: i128;
+  /**
+ * The lowest the indexed fee may go.
+ */
+min_fee: i128;
+  /**
+ * The SEP-40 oracle publishing the index.
+ */
+oracle: string;
+}
+
+
+/**
+ * * @title PriceData
+ *  * @dev A SEP-40 oracle reading.
+ */
+export interface PriceData {
+  price: i128;
+  /**
+ * Ledger timestamp of the reading.
+ */
+timestamp: u64;
+}
+
+/**
+ * * @title FeeIndexing
+ *  * @dev Whether the maintenance fee follows an index, as reported by config().
+ */
+export type FeeIndexing = {tag: "Manual", values: void} | {tag: "Indexed", values: readonly [FeeIndex]};
+
+/**
+ * * @title OracleAsset
+ *  * @dev A SEP-40 oracle feed: a Stellar asset or any other symbol, e.g. `CPI`.
+ */
+export type OracleAsset = {tag: "Stellar", values: readonly [string]} | {tag: "Other", values: readonly [string]};
+
+
+
+
+/**
+ * * @title MintPrice
+ *  * @dev What a hotel's unsold weeks sell for.
+ */
+export interface MintPrice {
+  /**
+ * Token the price is paid in.
+ */
+payment_token: string;
+  price: i128;
+}
+
+
+
+/**
+ * * @title Deadline
+ *  * @dev An upcoming deadline of a token.
+ */
+export interface Deadline {
+  /**
+ * Ledger timestamp it falls at.
+ */
+at: u64;
+  kind: DeadlineKind;
+  /**
+ * Who to notify: the owner, lister or seller.
+ */
+party: string;
+}
+
+/**
+ * * @title DeadlineKind
+ *  * @dev What a deadline is for; see the module comment.
+ */
+export type DeadlineKind = {tag: "FeeDue", values: void} | {tag: "BookingWindow", values: void} | {tag: "AuctionEnding", values: void};
+
+
+
+/**
+ * * @title Royalty
+ *  * @dev A hotel's cut of resales of its tokens.
+ */
+export interface Royalty {
+  /**
+ * The cut, in basis points of the sale price.
+ */
+bps: u32;
+  /**
+ * Receives the royalties.
+ */
+payout: string;
+}
+
+
+
+
+/**
+ * * @title Appraisal
+ *  * @dev A manager's appraisal of a token.
+ */
+export interface Appraisal {
+  /**
+ * Ledger timestamp of the appraisal.
+ */
+appraised_at: u64;
+  asset: string;
+  value: i128;
+}
+
+
+/**
+ * * @title PriceMark
+ *  * @dev What a token has sold for.
+ */
+export interface PriceMark {
+  /**
+ * The asset of the last sale. A sale in another asset starts afresh.
+ */
+asset: string;
+  /**
+ * The time-weighted average price up to the last sale.
+ */
+average: i128;
+  /**
+ * The last sale price.
+ */
+price: i128;
+  /**
+ * Ledger timestamp of the last sale.
+ */
+sold_at: u64;
+}
+
+
+export interface Client {
+  /**
+   * Construct and simulate a collection_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a collection offer, unless it was filled or cancelled.
+   */
+  collection_offer: ({offer_id}: {offer_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<CollectionOffer>>>
+
+  /**
+   * Construct and simulate a fill_collection_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sells a token into a collection offer: the owner is paid the
+   *      * escrowed amount and the buyer receives the token. Fails with
+   *      * NoCollectionOffer for an unknown offer, OfferExpired once it expired,
+   *      * and CriteriaMismatch unless the token meets the offer's criteria.
+   *      * @param owner The token's current owner (must sign).
+   */
+  fill_collection_offer: ({owner, offer_id, token_id}: {owner: string, offer_id: u32, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a make_collection_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Offers to buy any one token meeting `criteria`, moving the amount
+   *      * into the contract. Fails with InvalidWeek unless the weeks are a range
+   *      * within 1..=WEEKS_PER_YEAR, and OfferExpired if the offer would already
+   *      * have expired.
+   *      * @param buyer The buyer (must sign and pay).
+   *      * @param amount The offer; must be positive.
+   *      * @param expires_at Ledger timestamp until which the offer can be filled.
+   *      * @return The offer's ID.
+   */
+  make_collection_offer: ({buyer, criteria, payment_token, amount, expires_at}: {buyer: string, criteria: Criteria, payment_token: string, amount: i128, expires_at: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a collection_offer_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns how many collection offers have been made, i.e. the
+   *      * highest offer ID.
+   */
+  collection_offer_count: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a cancel_collection_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Takes back a collection offer and its escrowed amount, expired or
+   *      * not. Fails with NotOwner for someone else's offer. Works while paused,
+   *      * so a pause never locks a buyer's funds.
+   *      * @param buyer The buyer who made it (must sign).
+   */
+  cancel_collection_offer: ({buyer, offer_id}: {buyer: string, offer_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a redeem transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Burns a token of a retired property and pays its owner the payout.
+   *      * Fails with RedemptionWindowClosed after the window, AlreadyInTrust for
+   *      * tokens held in trust, and RedemptionUnderfunded if the funding left
+   *      * doesn't cover the payout.
+   *      * @param owner The current owner (must sign).
+   *      * @param token_id The token to redeem.
+   */
+  redeem: ({owner, token_id}: {owner: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a retirement transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a property's retirement record, if it has been retired.
+   */
+  retirement: ({hotel_id}: {hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Retirement>>>
+
+  /**
+   * Construct and simulate a sweep_property transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev After the redemption window, burns unredeemed tokens of a retired
+   *      * property and refunds the unused funding to the admin. Admin only.
+   *      * @param token_ids Tokens of the property to burn, at most MAX_MINT_BATCH per call.
+   *      * @return The property's updated retirement record.
+   */
+  sweep_property: ({hotel_id, token_ids}: {hotel_id: u32, token_ids: Array<u64>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Retirement>>>
+
+  /**
+   * Construct and simulate a fund_retirement transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Adds funding for a retired property's payouts. Admin only.
+   *      * @return The property's updated retirement record.
+   */
+  fund_retirement: ({hotel_id, amount}: {hotel_id: u32, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Retirement>>>
+
+  /**
+   * Construct and simulate a retire_property transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Retires a property and opens its redemption window. Admin only.
+   *      * @param token The payment token payouts are made in.
+   *      * @param payout Paid per redeemed token.
+   *      * @param window_end Ledger timestamp at which the window closes.
+   *      * @param funding Moved from the admin into the contract now; top up with fund_retirement().
+   */
+  retire_property: ({hotel_id, token, payout, window_end, funding}: {hotel_id: u32, token: string, payout: i128, window_end: u64, funding: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a deprecations transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists every deprecated endpoint with its notice.
+   */
+  deprecations: (options?: MethodOptions) => Promise<AssembledTransaction<Map<string, Deprecation>>>
+
+  /**
+   * Construct and simulate a deprecate_endpoint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Marks an endpoint deprecated (or updates its notice). Admin only.
+   *      * @param endpoint The endpoint's name, e.g. "transfer_to_alias".
+   *      * @param sunset_ledger The ledger from which the endpoint stops working.
+   *      * @param replacement The endpoint integrators should migrate to, if any.
+   */
+  deprecate_endpoint: ({endpoint, sunset_ledger, replacement}: {endpoint: string, sunset_ledger: u32, replacement: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a undeprecate_endpoint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Withdraws a deprecation notice. Admin only.
+   */
+  undeprecate_endpoint: ({endpoint}: {endpoint: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists existing token IDs above `start_after`, skipping burned ones.
+   *      * At most `limit` (capped at MAX_PAGE) IDs are examined per call, so a page
+   *      * can hold fewer IDs than requested even when more follow.
+   *      * @param start_after 0 for the first page, then the previous page's next_start_after.
+   *      * @param limit The page size.
+   */
+  tokens: ({start_after, limit}: {start_after: u64, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<TokenPage>>
+
+  /**
+   * Construct and simulate a balance_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns how many tokens `owner` currently holds.
+   */
+  balance_of: ({owner}: {owner: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the number of tokens currently in existence.
+   */
+  total_supply: (options?: MethodOptions) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a tokens_of_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists the IDs of the tokens `owner` currently holds.
+   *      * @return Token IDs in the order the owner received them.
+   */
+  tokens_of_owner: ({owner}: {owner: string}, options?: MethodOptions) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a pay_maintenance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Pays a token's maintenance fee for every unpaid year through the
+   *      * current one, plus the late fees they accrued. A token already paid
+   *      * through this year is charged nothing. Fails with InvalidAmount unless
+   *      * the schedule bills fees in `token_address`, or if the total overflows.
+   *      * @param owner The current owner (must sign and pay).
+   *      * @param token_address The token to pay in; must be the schedule's.
+   *      * @return The token's status after the payment.
+   */
+  pay_maintenance: ({owner, token_id, token_address}: {owner: string, token_id: u64, token_address: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<MaintenanceStatus>>>
+
+  /**
+   * Construct and simulate a delinquent_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists delinquent token IDs above `start_after`, examining at most
+   *      * `limit` (capped at MAX_PAGE) IDs per call, like tokens().
+   *      * @param start_after 0 for the first page, then the previous page's next_start_after.
+   */
+  delinquent_tokens: ({start_after, limit}: {start_after: u64, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<TokenPage>>
+
+  /**
+   * Construct and simulate a maintenance_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns whether a token's maintenance fee is paid through the
+   *      * current year, and how many years are owed.
+   */
+  maintenance_status: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<MaintenanceStatus>>>
+
+  /**
+   * Construct and simulate a maintenance_schedule transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the maintenance fee schedule, if the admin has set one.
+   */
+  maintenance_schedule: (options?: MethodOptions) => Promise<AssembledTransaction<Option<MaintenanceSchedule>>>
+
+  /**
+   * Construct and simulate a set_maintenance_schedule transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sets the maintenance fee schedule. Admin only. Starting to bill a
+   *      * positive fee bills from the current year on; earlier years aren't owed.
+   *      * Fails with FeeIndexed for a change of fee while it follows an index.
+   *      * @param schedule fee must not be negative.
+   */
+  set_maintenance_schedule: ({schedule}: {schedule: MaintenanceSchedule}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a withheld transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns how much was withheld from a seller's resales in a calendar
+   *      * year, in one payment token.
+   */
+  withheld: ({seller, year, payment_token}: {seller: string, year: u32, payment_token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a is_exempt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns whether a seller holds a valid exemption for a jurisdiction
+   *      * in this ledger.
+   */
+  is_exempt: ({seller, jurisdiction}: {seller: string, jurisdiction: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a withholding transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the withholding rule of a property's resales, if any.
+   */
+  withholding: ({hotel_id}: {hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<WithholdingRule>>>
+
+  /**
+   * Construct and simulate a withholdings transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the withholding rules of every property that has one, by
+   *      * hotel ID.
+   */
+  withholdings: (options?: MethodOptions) => Promise<AssembledTransaction<Map<u32, WithholdingRule>>>
+
+  /**
+   * Construct and simulate a set_withholding transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sets the withholding rule of a property's resales. Compliance role
+   *      * (or admin).
+   *      * @param caller The compliance officer (must sign).
+   *      * @param bps The share withheld, at most FULL_WITHHOLDING_BPS; 0 clears
+   *      * the rule.
+   */
+  set_withholding: ({caller, hotel_id, jurisdiction, bps, recipient}: {caller: string, hotel_id: u32, jurisdiction: string, bps: u32, recipient: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a revoke_exemption transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Withdraws a seller's exemption certificate. Compliance role (or
+   *      * admin).
+   *      * @param caller The compliance officer (must sign).
+   */
+  revoke_exemption: ({caller, seller, jurisdiction}: {caller: string, seller: string, jurisdiction: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a register_exemption transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Registers a seller's exemption certificate for a jurisdiction.
+   *      * Compliance role (or admin). Registering again replaces the expiry.
+   *      * @param caller The compliance officer (must sign).
+   *      * @param valid_until Ledger timestamp the certificate is valid through.
+   */
+  register_exemption: ({caller, seller, jurisdiction, valid_until}: {caller: string, seller: string, jurisdiction: string, valid_until: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a capabilities transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists the interfaces this deployment implements.
+   *      * @return One Symbol per interface, e.g. "nft_core".
+   */
+  capabilities: (options?: MethodOptions) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a label transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the label of a code in a locale, if it has one.
+   */
+  label: ({locale, code}: {locale: string, code: LabelCode}, options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a labels transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns every label of a locale.
+   */
+  labels: ({locale}: {locale: string}, options?: MethodOptions) => Promise<AssembledTransaction<Map<LabelCode, string>>>
+
+  /**
+   * Construct and simulate a locales transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists the locales that have labels, in the order they were added.
+   */
+  locales: (options?: MethodOptions) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a set_labels transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sets labels of a locale, keeping its other labels. An empty label
+   *      * removes the code's label. Admin only. Fails with LabelTooLong for a
+   *      * label over MAX_LABEL_LEN bytes, and TooManyLabels if the locale would
+   *      * have more than MAX_LABELS.
+   */
+  set_labels: ({locale, labels}: {locale: string, labels: Map<LabelCode, string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a fund_relief transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Moves `amount` from the funder into a property's relief fund in a
+   *      * payment token. Anyone may fund it.
+   *      * @param funder Who pays (must sign), e.g. the hotel or its insurer.
+   *      * @return The fund's balance.
+   */
+  fund_relief: ({funder, hotel_id, payment_token, amount}: {funder: string, hotel_id: u32, payment_token: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a relief_fund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a property's relief fund balance in a payment token.
+   */
+  relief_fund: ({hotel_id, payment_token}: {hotel_id: u32, payment_token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a force_majeure transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the flag closing a property's week of a year, if any.
+   */
+  force_majeure: ({hotel_id, year, week}: {hotel_id: u32, year: u32, week: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<ForceMajeureFlag>>>
+
+  /**
+   * Construct and simulate a withdraw_relief transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Withdraws `amount` from a property's relief fund. Admin only.
+   *      * Works while paused. Fails with ReliefUnfunded for more than the fund
+   *      * holds.
+   *      * @return The fund's balance.
+   */
+  withdraw_relief: ({hotel_id, payment_token, to, amount}: {hotel_id: u32, payment_token: string, to: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a flag_force_majeure transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Closes a property's week of a year for force majeure. Oracle role
+   *      * (or admin). Fails with InvalidWeek outside 1..=52, and InvalidAmount
+   *      * for a year already over.
+   *      * @param oracle The oracle (must sign).
+   *      * @param reason Why, e.g. `hurricane`.
+   */
+  flag_force_majeure: ({oracle, hotel_id, year, week, reason}: {oracle: string, hotel_id: u32, year: u32, week: u32, reason: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a lift_force_majeure transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lifts a flag raised in error, reopening the week; tokens already
+   *      * settled stay settled. Oracle role (or admin). Fails with
+   *      * NotForceMajeure if the week isn't flagged.
+   *      * @param oracle The oracle (must sign).
+   */
+  lift_force_majeure: ({oracle, hotel_id, year, week}: {oracle: string, hotel_id: u32, year: u32, week: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a replacement_credits transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns how many replacement stays a property owes an owner.
+   */
+  replacement_credits: ({owner, hotel_id}: {owner: string, hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a settle_force_majeure transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Settles a token of a week closed this year: refunds its renter,
+   *      * if any, the full rent from the relief fund, and credits its owner a
+   *      * replacement stay. Anyone may call it, once per token and year. Fails
+   *      * with NotForceMajeure unless the token's week is flagged this year,
+   *      * InvalidUsageState once the week has begun, AlreadySettled if the
+   *      * token is already settled, and ReliefUnfunded if the relief fund can't
+   *      * cover the refund.
+   */
+  settle_force_majeure: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a force_majeure_settled transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the last year a token was settled for force majeure, if
+   *      * it has been.
+   */
+  force_majeure_settled: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<u32>>>
+
+  /**
+   * Construct and simulate a redeem_replacement_credit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Redeems one of an owner's replacement credits at a property, once
+   *      * the front desk has booked the replacement stay. Manager role (or
+   *      * admin). Fails with NoReplacementCredit without one.
+   *      * @param caller The front desk (must sign).
+   *      * @return Credits the owner has left at the property.
+   */
+  redeem_replacement_credit: ({caller, owner, hotel_id}: {caller: string, owner: string, hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_notification_prefs transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the notification preferences of `owner`.
+   *      * @return The stored bitmask, or 0 if the address never opted in.
+   */
+  get_notification_prefs: ({owner}: {owner: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_notification_prefs transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Replaces the notification preferences of `owner`.
+   *      * @param owner The address whose preferences are updated (must sign).
+   *      * @param mask A combination of the NOTIFY_* bits. 0 opts out of everything.
+   */
+  set_notification_prefs: ({owner, mask}: {owner: string, mask: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Mints a new timeshare token and assigns it to an owner. Each week of
+   *      * a room is minted once (see slots.rs), up to the hotel's supply cap (see
+   *      * supply.rs).
+   *      * Only the contract ADMIN can call this function.
+   *      * @param to The address that will receive the new token.
+   *      * @param hotel_id The hotel's ID in the registry (see hotels.rs).
+   *      * @param room The room, from the hotel's inventory (see rooms.rs).
+   *      * @param week The week of the year (1-52).
+   *      * @param year The year the right starts in, or None for every year.
+   *      * @param lease_end_ledger The ledger sequence the right expires at, or None.
+   *      * @return The unique token ID of the newly minted timeshare.
+   */
+  mint: ({to, hotel_id, room, week, year, lease_end_ledger}: {to: string, hotel_id: u32, room: string, week: u32, year: Option<u32>, lease_end_ledger: Option<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a mint_by transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Mints a new timeshare token on behalf of a holder of the Minter role
+   *      * (see roles.rs), e.g. a front-desk system. IDs come from the minter's own block.
+   *      * @param minter The minter (must sign).
+   *      * @param info The token's details, as for mint().
+   *      * @return The unique token ID of the newly minted timeshare.
+   */
+  mint_by: ({minter, to, info}: {minter: string, to: string, info: TimeshareInfo}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a minter_state transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a minter's current ID block and mint count, if it has minted.
+   */
+  minter_state: ({minter}: {minter: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<MinterState>>>
+
+  /**
+   * Construct and simulate a bump_portfolio transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Extends the TTL of `owner`'s token entries, and of the indexes that
+   *      * refer to them, to at least `extend_to` ledgers (capped at the network's
+   *      * maximum TTL).
+   *      * @param page Which MAX_BUMP_PAGE-sized slice of tokens_of_owner() to cover, from 0.
+   *      * @return The next page to pass, or None once the whole portfolio is covered.
+   */
+  bump_portfolio: ({owner, page, extend_to}: {owner: string, page: u32, extend_to: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<u32>>>>
+
+  /**
+   * Construct and simulate a extend_token_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Extends the TTL of all of a token's entries to at least `extend_to`
+   *      * ledgers (capped at the network's maximum TTL).
+   *      * @param token_id The token to keep alive.
+   */
+  extend_token_ttl: ({token_id, extend_to}: {token_id: u64, extend_to: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev A public, read-only function to get the details of a timeshare.
+   *      * Anyone can call this function without authentication.
+   *      * @param token_id The ID of the token to query.
+   *      * @return The TimeshareInfo struct (hotel, room, week and term). The term
+   *      * may have ended; see is_active().
+   */
+  get_info: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<TimeshareInfo>>>
+
+  /**
+   * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Transfers a timeshare token from the current owner to a new owner.
+   *      * Only the current owner of the token can authorize this.
+   *      * @param from The current owner's address (who must sign).
+   *      * @param to The new owner's address.
+   *      * @param token_id The ID of the token to transfer.
+   */
+  transfer: ({from, to, token_id}: {from: string, to: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev A public, read-only function to get the current owner of a timeshare.
+   *      * @param token_id The ID of the token to query.
+   *      * @return The owner's Address.
+   */
+  get_owner: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a token's details and its owner in a single call.
+   *      * @param token_id The ID of the token to query.
+   *      * @return The Token struct (token_id, owner, info).
+   */
+  get_token: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Token>>>
+
+  /**
+   * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Initializes the contract by setting the administrator.
+   *      * This function should only be called ONCE when the contract is deployed.
+   *      * @param admin The address of the person/account who will be the "hotel admin".
+   */
+  initialize: ({admin}: {admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a mint_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Mints several tokens to one owner under a single admin signature,
+   *      * e.g. when onboarding every week of a room.
+   *      * @param to The address that will receive the new tokens.
+   *      * @param infos The details of each token, at most MAX_MINT_BATCH of them.
+   *      * @return The new token IDs, in the order of `infos`. They increase but need not be consecutive.
+   */
+  mint_batch: ({to, infos}: {to: string, infos: Array<TimeshareInfo>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<u64>>>>
+
+  /**
+   * Construct and simulate a list_many transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists tokens for sale, replacing any earlier listings of them.
+   *      * @param caller The owner or one of its operators (must sign).
+   *      * @param items (token ID, price) pairs, at most MAX_LIST_BATCH of them.
+   *      * @param payment_token The token the prices are paid in.
+   *      * @return A code per item: BULK_OK or the error it failed with.
+   */
+  list_many: ({caller, owner, items, payment_token}: {caller: string, owner: string, items: Array<readonly [u64, i128]>, payment_token: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<u32>>>>
+
+  /**
+   * Construct and simulate a cancel_many transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Withdraws the listings of tokens.
+   *      * @param caller The owner or one of its operators (must sign).
+   *      * @param token_ids At most MAX_LIST_BATCH tokens.
+   *      * @return A code per token: BULK_OK or the error it failed with.
+   */
+  cancel_many: ({caller, owner, token_ids}: {caller: string, owner: string, token_ids: Array<u64>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<u32>>>>
+
+  /**
+   * Construct and simulate a reprice_many transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Changes the prices of listed tokens, keeping their payment tokens.
+   *      * @param caller The owner or one of its operators (must sign).
+   *      * @param items (token ID, new price) pairs, at most MAX_LIST_BATCH of them.
+   *      * @return A code per item: BULK_OK or the error it failed with.
+   */
+  reprice_many: ({caller, owner, items}: {caller: string, owner: string, items: Array<readonly [u64, i128]>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<u32>>>>
+
+  /**
+   * Construct and simulate a burn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Destroys a token. Fails with AlreadyInTrust for tokens held in trust,
+   *      * TokenFrozen for frozen ones, and RentalActive for rented ones.
+   *      * @param owner The current owner (must sign).
+   *      * @param token_id The token to burn.
+   */
+  burn: ({owner, token_id}: {owner: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a admin_burn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Destroys any token, including one held in trust. Admin only.
+   *      * Fails with RentalActive while the token is rented out.
+   *      * @param token_id The token to burn.
+   */
+  admin_burn: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a late_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the late fees a token owes on top of its unpaid maintenance
+   *      * fees in this ledger; 0 if it is paid up or nothing is overdue yet.
+   */
+  late_fee: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a delinquency transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the delinquency level last recorded for a token, if any.
+   */
+  delinquency: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Delinquency>>>
+
+  /**
+   * Construct and simulate a late_fee_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the late fee policy, if late fees are charged.
+   */
+  late_fee_policy: (options?: MethodOptions) => Promise<AssembledTransaction<Option<LateFeePolicy>>>
+
+  /**
+   * Construct and simulate a check_delinquency transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Records how many delinquency thresholds a token has crossed since
+   *      * its oldest unpaid year fell due, publishing DelinquencyEscalated if it is
+   *      * more than last recorded.
+   *      * Anyone may call it, e.g. a keeper sweeping delinquent_tokens().
+   *      * @return The token's level; 0 if it isn't overdue.
+   */
+  check_delinquency: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a set_late_fee_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sets the late fee policy, or turns late fees off with None. Admin
+   *      * only. Fails with InvalidAmount for negative amounts, a rate over
+   *      * FULL_BPS, a 0 period, or more than MAX_DELINQUENCY_LEVELS thresholds or
+   *      * thresholds out of order.
+   */
+  set_late_fee_policy: ({policy}: {policy: Option<LateFeePolicy>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a keeper_bump transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Bumps one page of `owner`'s portfolio to the policy's extend_to and
+   *      * pays `keeper` fee_per_token per token from the owner's rent balance.
+   *      * Fails with RentNotDue if the page was paid for less than
+   *      * `extend_to - threshold` ledgers ago.
+   *      * @param page As for bump_portfolio().
+   *      * @return The next page to pass, or None once the whole portfolio is covered.
+   */
+  keeper_bump: ({keeper, owner, page}: {keeper: string, owner: string, page: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<u32>>>>
+
+  /**
+   * Construct and simulate a rent_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the rent pool's policy, if the admin has configured one.
+   */
+  rent_policy: (options?: MethodOptions) => Promise<AssembledTransaction<Option<RentPolicy>>>
+
+  /**
+   * Construct and simulate a deposit_rent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Moves `amount` of the policy token from `owner` into their rent balance.
+   *      * @return The new balance.
+   */
+  deposit_rent: ({owner, amount}: {owner: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a rent_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns `owner`'s unused rent balance.
+   */
+  rent_balance: ({owner}: {owner: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a withdraw_rent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Pays `amount` of `owner`'s unused rent balance back to them.
+   *      * @return The new balance.
+   */
+  withdraw_rent: ({owner, amount}: {owner: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a set_rent_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Configures the rent pool. Admin only. The token cannot change once
+   *      * set, since balances are held in it.
+   *      * @param policy threshold must be below extend_to; amounts must not be negative.
+   */
+  set_rent_policy: ({policy}: {policy: RentPolicy}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a swap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Gives `token_a` to `owner_b` and `token_b` to `owner_a` atomically.
+   *      * Fails with InvalidSwap if both sides are the same owner or token.
+   *      * @param owner_a The current owner of token_a (must sign).
+   *      * @param owner_b The current owner of token_b (must sign).
+   */
+  swap: ({owner_a, token_a, owner_b, token_b}: {owner_a: string, token_a: u64, owner_b: string, token_b: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a is_active transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns whether a token's term is still running.
+   *      * @return false once the ledger has reached the token's lease_end_ledger.
+   */
+  is_active: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Completes the handover. Must be signed by the proposed admin.
+   */
+  accept_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the proposed next admin, if a handover is pending.
+   */
+  pending_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Proposes `new_admin` as the next admin. Admin only.
+   *      * @param new_admin Becomes admin once it calls accept_admin().
+   */
+  propose_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_alias transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Registers (or replaces) an alias in `owner`'s address book.
+   *      * @param owner The address book's owner (must sign).
+   *      * @param name_hash A hash of the alias name, so names aren't stored in clear.
+   *      * @param addr The address the alias resolves to.
+   */
+  set_alias: ({owner, name_hash, addr}: {owner: string, name_hash: Buffer, addr: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a remove_alias transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Removes an alias from `owner`'s address book.
+   *      * @param owner The address book's owner (must sign).
+   */
+  remove_alias: ({owner, name_hash}: {owner: string, name_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a resolve_alias transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Looks up an alias in `owner`'s address book.
+   *      * @return The address, or None if the alias is not registered.
+   */
+  resolve_alias: ({owner, name_hash}: {owner: string, name_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a transfer_to_alias transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Transfers a token to the address registered under one of `from`'s aliases.
+   *      * @param from The current owner's address (who must sign).
+   *      * @param name_hash The alias to resolve in `from`'s address book.
+   *      * @param token_id The ID of the token to transfer.
+   */
+  transfer_to_alias: ({from, name_hash, token_id}: {from: string, name_hash: Buffer, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a floor_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the lowest price a token of a property's tier is listed at
+   *      * in an asset; None without listings, while the floor is unknown, or
+   *      * while the stats privacy hides the book.
+   *      * @param tier The tier, or None for tokens without one.
+   */
+  floor_price: ({hotel_id, tier, asset}: {hotel_id: u32, tier: Option<string>, asset: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<i128>>>
+
+  /**
+   * Construct and simulate a market_depth transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns how many tokens of a property's tier are listed in an
+   *      * asset, as exact as the stats privacy allows, and the cheapest of them.
+   *      * @param tier The tier, or None for tokens without one.
+   */
+  market_depth: ({hotel_id, tier, asset}: {hotel_id: u32, tier: Option<string>, asset: string}, options?: MethodOptions) => Promise<AssembledTransaction<MarketDepth>>
+
+  /**
+   * Construct and simulate a buy_dutch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Buys a token at its current Dutch auction price.
+   *      * @param buyer The buyer (must sign and pay).
+   *      * @return The price paid.
+   */
+  buy_dutch: ({buyer, token_id}: {buyer: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a dutch_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns what buy_dutch would charge for a token in this ledger.
+   */
+  dutch_price: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a dutch_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a token's Dutch auction, if it is offered in one.
+   */
+  dutch_auction: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<DutchAuction>>>
+
+  /**
+   * Construct and simulate a start_dutch_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Offers a token the admin holds at a price declining from now.
+   *      * Admin only. Replaces an earlier Dutch auction of the token.
+   *      * @param floor_price Must not be negative, nor above start_price.
+   *      * @param decay_per_ledger Must not be negative.
+   */
+  start_dutch_auction: ({token_id, payment_token, start_price, floor_price, decay_per_ledger}: {token_id: u64, payment_token: string, start_price: i128, floor_price: i128, decay_per_ledger: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns every grace period in force, in seconds by rule.
+   */
+  policy: (options?: MethodOptions) => Promise<AssembledTransaction<Map<GraceRule, u64>>>
+
+  /**
+   * Construct and simulate a cancel_grace_period transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Withdraws a proposed grace period. Admin only. Fails with
+   *      * NoPendingGracePeriod without a proposal.
+   */
+  cancel_grace_period: ({rule}: {rule: GraceRule}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a execute_grace_period transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Applies a proposed grace period whose timelock has passed. Anyone
+   *      * may call it. Fails with NoPendingGracePeriod without a proposal, and
+   *      * TimelockNotElapsed before its effective_at.
+   */
+  execute_grace_period: ({rule}: {rule: GraceRule}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a pending_grace_period transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the grace period change proposed for a rule, if any.
+   */
+  pending_grace_period: ({rule}: {rule: GraceRule}, options?: MethodOptions) => Promise<AssembledTransaction<Option<PendingGracePeriod>>>
+
+  /**
+   * Construct and simulate a propose_grace_period transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Proposes a new length for a grace period. It can be executed once
+   *      * GRACE_POLICY_DELAY has passed. A new proposal replaces any pending one
+   *      * for the rule. Admin only.
+   */
+  propose_grace_period: ({rule, seconds}: {rule: GraceRule, seconds: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a minor_units transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the minor units a property set, by payment asset. Assets
+   *      * not listed use their base unit.
+   */
+  minor_units: ({hotel_id}: {hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Map<string, i128>>>
+
+  /**
+   * Construct and simulate a set_minor_unit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sets the smallest amount a property's payments in an asset are
+   *      * worked out in. Manager role (or admin).
+   *      * @param caller The manager (must sign).
+   *      * @param unit In the asset's base units; must be positive, and 1 clears it.
+   */
+  set_minor_unit: ({caller, hotel_id, asset, unit}: {caller: string, hotel_id: u32, asset: string, unit: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Stops mints, transfers and payments. Pauser role (or admin).
+   *      * @param caller The pauser (must sign).
+   */
+  pause: ({caller}: {caller: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns whether the contract is paused.
+   */
+  paused: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Resumes activity after pause(). Pauser role (or admin).
+   *      * @param caller The pauser (must sign).
+   */
+  unpause: ({caller}: {caller: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a has_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns whether `account` was granted `role`. (The admin holds every
+   *      * role implicitly without being listed.)
+   */
+  has_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a grant_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Grants `role` to `account`. Admin only.
+   */
+  grant_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a revoke_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Takes `role` away from `account`. Admin only.
+   */
+  revoke_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a add_room transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Adds a room to a hotel's inventory. Manager role (or admin).
+   *      * @param caller The manager (must sign).
+   *      * @param room The room's name, e.g. "Room 305"; unique within the hotel.
+   */
+  add_room: ({caller, hotel_id, room}: {caller: string, hotel_id: u32, room: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a list_rooms transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the rooms in a hotel's inventory (retired rooms excluded),
+   *      * in the order they were added.
+   */
+  list_rooms: ({hotel_id}: {hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a retire_room transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Takes a room out of a hotel's inventory for good: no more tokens can
+   *      * be minted for it. Existing tokens are unaffected. Manager role (or admin).
+   *      * @param caller The manager (must sign).
+   */
+  retire_room: ({caller, hotel_id, room}: {caller: string, hotel_id: u32, room: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a force_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Transfers a token even if the recipient is a sink address.
+   *      * Requires the owner's signature AND the admin's, so a deliberate burn-by-transfer
+   *      * is always a two-party decision.
+   *      * @param from The current owner's address (who must sign).
+   *      * @param to The recipient, which may be a sink address.
+   *      * @param token_id The ID of the token to transfer.
+   */
+  force_transfer: ({from, to, token_id}: {from: string, to: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a is_sink_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns true if tokens would be rejected when sent to `addr`.
+   */
+  is_sink_address: ({addr}: {addr: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a add_sink_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Flags an address as a known sink that must not receive tokens. Admin only.
+   */
+  add_sink_address: ({addr}: {addr: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a remove_sink_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Removes an address from the sink list. Admin only.
+   */
+  remove_sink_address: ({addr}: {addr: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a token_by_slot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the token minted for a week of a room, if there is one.
+   *      * @param year The year, or None for the perpetual token.
+   */
+  token_by_slot: ({hotel_id, room, week, year}: {hotel_id: u32, room: string, week: u32, year: Option<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<Option<u64>>>
+
+  /**
+   * Construct and simulate a get_trust transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the trust a token is held in, if any.
+   */
+  get_trust: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Trust>>>
+
+  /**
+   * Construct and simulate a place_in_trust transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Places a token in trust. Title moves to the trustee, who becomes the
+   *      * owner, and a Transfer event is published.
+   *      * @param owner The current owner (must sign).
+   *      * @param token_id The token to place in trust.
+   *      * @param trustee The address that will hold title.
+   *      * @param beneficiary The address the token is held for.
+   */
+  place_in_trust: ({owner, token_id, trustee, beneficiary}: {owner: string, token_id: u64, trustee: string, beneficiary: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a propose_beneficiary transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Proposes a new beneficiary. It can be executed once BENEFICIARY_TIMELOCK has passed.
+   *      * A new proposal replaces any pending one.
+   *      * @param trustee The trustee (must sign).
+   */
+  propose_beneficiary: ({trustee, token_id, beneficiary}: {trustee: string, token_id: u64, beneficiary: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a acknowledge_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Records the beneficiary's consent to the trustee transferring the token to `to`.
+   *      * @param beneficiary The current beneficiary (must sign).
+   *      * @param token_id The token held in trust.
+   *      * @param to The only recipient the trustee may transfer to.
+   */
+  acknowledge_transfer: ({beneficiary, token_id, to}: {beneficiary: string, token_id: u64, to: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a execute_beneficiary_change transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Applies a pending beneficiary change whose timelock has passed. Anyone can call this.
+   */
+  execute_beneficiary_change: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a usage transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a token's usage record for the current year.
+   */
+  usage: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Usage>>>
+
+  /**
+   * Construct and simulate a check_in transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Checks in on this year's week of a token. Fails with
+   *      * InvalidUsageState unless the week is unused this year.
+   *      * @param guest The owner, or this year's renter (must sign).
+   */
+  check_in: ({guest, token_id}: {guest: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a check_out transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Checks out of a confirmed stay; the week is then used up for the
+   *      * year. Fails with CheckInNotConfirmed before the front desk confirmed.
+   *      * @param guest The guest who checked in (must sign).
+   */
+  check_out: ({guest, token_id}: {guest: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a confirm_check_in transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Confirms a guest's check-in at the front desk. Manager role (or admin).
+   *      * @param caller The front desk (must sign).
+   */
+  confirm_check_in: ({caller, token_id}: {caller: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a join_chain transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Escrows the caller's leg of a swap chain, settling the chain if it
+   *      * was the last. Joining twice does nothing. Fails with NoSwapChain for an
+   *      * unknown or closed chain, and NotOwner unless the caller has a leg.
+   *      * @param owner The owner of the leg (must sign).
+   */
+  join_chain: ({owner, chain_id}: {owner: string, chain_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a swap_chain transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a swap chain, unless it settled or was withdrawn.
+   */
+  swap_chain: ({chain_id}: {chain_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SwapChain>>>
+
+  /**
+   * Construct and simulate a chain_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns how many swap chains have been proposed, i.e. the highest
+   *      * chain ID.
+   */
+  chain_count: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a propose_chain transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Proposes a swap chain and joins the proposer's leg. Fails with
+   *      * InvalidSwap unless there are 2 to MAX_CHAIN_LEGS legs of distinct owners
+   *      * and tokens, and NotOwner unless the proposer has a leg and every owner
+   *      * holds their token.
+   *      * @param proposer The owner of one of the legs (must sign).
+   *      * @return The chain's ID.
+   */
+  propose_chain: ({proposer, legs}: {proposer: string, legs: Array<ChainLeg>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a withdraw_chain transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Calls off a swap chain, returning every escrowed token to its
+   *      * owner. Fails with NoSwapChain for an unknown or closed chain.
+   *      * @param caller The owner of one of the legs (must sign).
+   */
+  withdraw_chain: ({caller, chain_id}: {caller: string, chain_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the full effective configuration of this deployment.
+   *      * @return The ContractConfig struct, or NotInitialized before initialize().
+   */
+  config: (options?: MethodOptions) => Promise<AssembledTransaction<Result<ContractConfig>>>
+
+  /**
+   * Construct and simulate a trade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the open trade of an escrowed token.
+   */
+  trade: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Trade>>>
+
+  /**
+   * Construct and simulate a open_trade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Escrows a token in exchange for another. Fails with InvalidSwap if
+   *      * the token or the party is the same on both sides.
+   *      * @param owner The current owner (must sign).
+   *      * @param counterparty The holder of the wanted token.
+   */
+  open_trade: ({owner, token_id, counterparty, wanted_token_id}: {owner: string, token_id: u64, counterparty: string, wanted_token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a accept_trade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Completes a trade: the wanted token goes to the opener and the
+   *      * escrowed one to the counterparty.
+   *      * @param counterparty The trade's counterparty (must sign); must hold the
+   *      * wanted token.
+   */
+  accept_trade: ({counterparty, token_id}: {counterparty: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a withdraw_trade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Calls off a trade, returning the escrowed token to the opener.
+   *      * @param caller The opener or the counterparty (must sign).
+   */
+  withdraw_trade: ({caller, token_id}: {caller: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a daily_root transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the chain head committed for `day`, if any.
+   */
+  daily_root: ({day}: {day: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<EventChain>>>
+
+  /**
+   * Construct and simulate a event_lanes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the live head of every lane, in lane order.
+   */
+  event_lanes: (options?: MethodOptions) => Promise<AssembledTransaction<Array<EventChain>>>
+
+  /**
+   * Construct and simulate a daily_root_lanes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the lane heads committed for `day`, in lane order, if any.
+   *      * Replaying a day's events starts from these.
+   */
+  daily_root_lanes: ({day}: {day: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Array<Buffer>>>>
+
+  /**
+   * Construct and simulate a event_chain_head transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the live (uncommitted) chain head.
+   */
+  event_chain_head: (options?: MethodOptions) => Promise<AssembledTransaction<EventChain>>
+
+  /**
+   * Construct and simulate a commit_daily_root transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Snapshots the current event chain head under today's day number
+   *      * (ledger timestamp / DAY_SECONDS). Anyone can call this, at most once per day.
+   *      * @return The committed day number.
+   */
+  commit_daily_root: (options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a freeze transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Freezes a token. Admin only.
+   *      * @param token_id The token to freeze.
+   */
+  freeze: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a unfreeze transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lifts a token's freeze. Admin only.
+   *      * @param token_id The token to unfreeze.
+   */
+  unfreeze: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a is_frozen transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns whether a token is frozen.
+   */
+  is_frozen: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_hotel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a registered hotel.
+   */
+  get_hotel: ({hotel_id}: {hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Hotel>>>
+
+  /**
+   * Construct and simulate a list_hotels transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists registered hotels with IDs above `start_after`, in ID order.
+   *      * @param start_after 0 for the first page, then the last ID of the previous page.
+   *      * @param limit The page size, capped at MAX_PAGE.
+   */
+  list_hotels: ({start_after, limit}: {start_after: u32, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<Hotel>>>
+
+  /**
+   * Construct and simulate a update_hotel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Renames a registered hotel. Manager role (or admin).
+   *      * @param caller The manager (must sign).
+   *      * @return The updated hotel.
+   */
+  update_hotel: ({caller, hotel_id, name}: {caller: string, hotel_id: u32, name: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Hotel>>>
+
+  /**
+   * Construct and simulate a register_hotel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Adds a hotel to the registry. Manager role (or admin).
+   *      * @param caller The manager (must sign).
+   *      * @param name The hotel's name, unique among registered hotels.
+   *      * @return The new hotel's ID.
+   */
+  register_hotel: ({caller, name}: {caller: string, name: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a buy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Buys a listed token: pays the seller its price and takes ownership.
+   *      * Fails with NotOwner if the seller no longer owns it.
+   *      * @param buyer The buyer (must sign and pay).
+   */
+  buy: ({buyer, token_id}: {buyer: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a token's listing, if it is for sale.
+   */
+  listing: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Listing>>>
+
+  /**
+   * Construct and simulate a list_for_sale transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists a token for sale, replacing any earlier listing of it.
+   *      * @param owner The current owner (must sign); receives the price.
+   *      * @param price The price; must not be negative.
+   *      * @param payment_token The token the price is paid in.
+   */
+  list_for_sale: ({owner, token_id, price, payment_token}: {owner: string, token_id: u64, price: i128, payment_token: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a cancel_listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Withdraws a token's listing.
+   *      * @param owner The current owner (must sign).
+   */
+  cancel_listing: ({owner, token_id}: {owner: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a buyer's offer for a token, if they have made one.
+   */
+  offer: ({token_id, buyer}: {token_id: u64, buyer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Offer>>>
+
+  /**
+   * Construct and simulate a make_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Offers to buy a token, moving the amount into the contract. Fails
+   *      * with OfferExpired if the offer would already have expired.
+   *      * @param buyer The buyer (must sign and pay).
+   *      * @param amount The offer; must be positive.
+   *      * @param expires_at Ledger timestamp until which the offer can be accepted.
+   */
+  make_offer: ({buyer, token_id, payment_token, amount, expires_at}: {buyer: string, token_id: u64, payment_token: string, amount: i128, expires_at: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a accept_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Accepts a buyer's offer: the owner is paid the escrowed amount and
+   *      * the buyer receives the token. Fails with OfferExpired once it expired.
+   *      * Where the hotel has a right of first refusal, the token and the
+   *      * escrowed amount are held until the hotel matches the offer or its
+   *      * window lapses instead (see first_refusal()).
+   *      * @param owner The current owner (must sign).
+   */
+  accept_offer: ({owner, token_id, buyer}: {owner: string, token_id: u64, buyer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a cancel_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Takes back an offer and its escrowed amount, expired or not. Works
+   *      * while paused, so a pause never locks a buyer's funds in escrow.
+   *      * @param buyer The buyer who made it (must sign).
+   */
+  cancel_offer: ({buyer, token_id}: {buyer: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prepayment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the prepaid fee the contract holds for one year of a token.
+   */
+  prepayment: ({token_id, year}: {token_id: u64, year: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Prepayment>>>
+
+  /**
+   * Construct and simulate a fee_schedule transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Previews a prepayment: the years it would cover, from the token's
+   *      * first unpaid year, and each year's discounted fee. Fails with
+   *      * InvalidAmount without a fee schedule or unless `years` is in
+   *      * 1..=MAX_PREPAY_YEARS, and MaintenanceArrears while years before the
+   *      * current one are owed: those are paid with `pay_maintenance`, undiscounted.
+   */
+  fee_schedule: ({token_id, years}: {token_id: u64, years: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<FeeQuote>>>>
+
+  /**
+   * Construct and simulate a prepaid_years transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the years of a token whose prepaid fees the contract still
+   *      * holds, if any.
+   */
+  prepaid_years: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<PrepaidYears>>>
+
+  /**
+   * Construct and simulate a release_prepaid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Releases the prepaid fees of a token's years that have begun to
+   *      * the collector. Anyone may call it.
+   */
+  release_prepaid: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prepay_discounts transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the prepayment discount curve.
+   */
+  prepay_discounts: (options?: MethodOptions) => Promise<AssembledTransaction<Array<u32>>>
+
+  /**
+   * Construct and simulate a prepay_maintenance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Prepays maintenance for `years` years from the token's first unpaid
+   *      * year, as quoted by `fee_schedule`. The current year, if covered, is paid
+   *      * to the collector straight away. Fails like `fee_schedule`, and with
+   *      * InvalidAmount unless the schedule bills fees in `token_address`.
+   *      * @param owner The current owner (must sign and pay).
+   *      * @return The years paid for and their fees.
+   */
+  prepay_maintenance: ({owner, token_id, token_address, years}: {owner: string, token_id: u64, token_address: string, years: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<FeeQuote>>>>
+
+  /**
+   * Construct and simulate a set_prepay_discounts transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sets the prepayment discount curve. Admin only.
+   *      * @param discounts Entry n - 1 is the discount, in basis points of each
+   *      * year's fee, for prepaying n years. At most MAX_PREPAY_YEARS entries of
+   *      * at most FULL_DISCOUNT_BPS; empty gives no discount.
+   */
+  set_prepay_discounts: ({discounts}: {discounts: Array<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a rent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Rents a listed week, paying the price to the lister. The renter may
+   *      * then check in instead of the owner this year.
+   *      * @param renter The renter (must sign and pay).
+   */
+  rent: ({renter, token_id}: {renter: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Rental>>>
+
+  /**
+   * Construct and simulate a rental transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a token's rental for the current year, listed or taken.
+   */
+  rental: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Rental>>>
+
+  /**
+   * Construct and simulate a cancel_rental transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Withdraws a listing nobody has taken.
+   *      * @param owner The current owner (must sign).
+   */
+  cancel_rental: ({owner, token_id}: {owner: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a list_for_rent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists this year's week of a token for rent, replacing any listing
+   *      * nobody has taken. Fails with ListingClosed once a renter has taken it.
+   *      * @param owner The current owner (must sign); receives the rent.
+   *      * @param token The token the rent is paid in.
+   *      * @param price The rent.
+   *      * @param renter_window Ledger timestamp until which the listing can be taken.
+   */
+  list_for_rent: ({owner, token_id, token, price, renter_window}: {owner: string, token_id: u64, token: string, price: i128, renter_window: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a commit_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Commits a sealed bid, posting the bond the first time. Fails with
+   *      * AuctionEnded once commitments have closed, and BatchTooLarge past
+   *      * MAX_SEALED_BIDDERS bidders.
+   *      * @param bidder The bidder (must sign and pay the bond).
+   *      * @param commitment sha256 of the XDR of (bidder, amount, salt).
+   */
+  commit_bid: ({bidder, token_id, commitment}: {bidder: string, token_id: u64, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Reveals a committed bid and returns its bond. A bid that is the
+   *      * highest so far is escrowed, refunding the one it beats. Fails with
+   *      * AuctionRunning before the reveal window, AuctionEnded after it,
+   *      * NoCommitment without a commitment and CommitmentMismatch if the bid
+   *      * and salt don't hash to it.
+   *      * @param bidder The bidder (must sign, and pay the bid if it leads).
+   */
+  reveal_bid: ({bidder, token_id, amount, salt}: {bidder: string, token_id: u64, amount: i128, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a settle_sealed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Settles a sealed auction once reveals have closed: the highest
+   *      * bidder gets the token for the second-highest bid (at least the
+   *      * reserve), and unrevealed bonds go to the seller. Anyone may call it.
+   *      * Fails with AuctionRunning until reveals close. If the token can't move
+   *      * to the winner, the auction lapses and the bid is refunded instead.
+   *      * @return The price paid, 0 if nobody won.
+   */
+  settle_sealed: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a sealed_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a token's sealed auction, if one is running or awaiting
+   *      * settlement.
+   */
+  sealed_auction: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SealedAuction>>>
+
+  /**
+   * Construct and simulate a start_sealed_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Starts a sealed auction of a token. Fails with AuctionRunning while
+   *      * one is running.
+   *      * @param seller The current owner (must sign); receives the price.
+   *      * @param reserve The lowest bid that counts; must not be negative.
+   *      * @param bond Posted with each commitment; must not be negative.
+   *      * @param duration Seconds commitments are taken, before the reveal
+   *      * window; must not be 0.
+   */
+  start_sealed_auction: ({seller, token_id, payment_token, reserve, bond, duration}: {seller: string, token_id: u64, payment_token: string, reserve: i128, bond: i128, duration: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a fraction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns how many shares a fractionalized token was split into.
+   */
+  fraction: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<u32>>>
+
+  /**
+   * Construct and simulate a fractionalize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Splits a token into shares, all credited to its owner, and moves
+   *      * the token into the contract.
+   *      * @param owner The current owner (must sign).
+   *      * @param shares How many shares to split it into; must be at least 2.
+   */
+  fractionalize: ({owner, token_id, shares}: {owner: string, token_id: u64, shares: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a redeem_shares transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Reconstitutes sole ownership: the holder of every share gets the
+   *      * token back and the shares are retired. Fails with InsufficientShares
+   *      * while anyone else holds some.
+   *      * @param holder The holder of all shares (must sign).
+   */
+  redeem_shares: ({holder, token_id}: {holder: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a share_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns how many of a token's shares an address holds.
+   */
+  share_balance: ({token_id, holder}: {token_id: u64, holder: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a transfer_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Moves shares of a fractionalized token. Fails with
+   *      * NotFractionalized if the token isn't fractionalized, InvalidAmount for
+   *      * no shares, and InsufficientShares if `from` holds fewer than `amount`.
+   *      * @param from The holder (must sign).
+   */
+  transfer_share: ({from, to, token_id, amount}: {from: string, to: string, token_id: u64, amount: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a supply_cap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a hotel's supply cap, if it has one.
+   */
+  supply_cap: ({hotel_id}: {hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<u32>>>
+
+  /**
+   * Construct and simulate a supply_caps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns every hotel's supply cap, by hotel ID.
+   */
+  supply_caps: (options?: MethodOptions) => Promise<AssembledTransaction<Map<u32, u32>>>
+
+  /**
+   * Construct and simulate a count_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Counts the next `limit` (capped at MAX_PAGE) token IDs into their
+   *      * hotels' supplies, while a recount started by migrate() is running.
+   *      * Admin only.
+   *      * @return Whether every token is counted.
+   */
+  count_supply: ({limit}: {limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a hotel_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns how many live tokens a hotel has, as exact as the stats
+   *      * privacy allows (see privacy.rs).
+   */
+  hotel_supply: ({hotel_id}: {hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_supply_cap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Caps how many live tokens a hotel may have. Admin only. A cap
+   *      * under the current supply only stops further mints.
+   *      * @param max_tokens The most live tokens; u32::MAX for no limit.
+   */
+  set_supply_cap: ({hotel_id, max_tokens}: {hotel_id: u32, max_tokens: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the collection's name.
+   */
+  name: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the collection's symbol.
+   */
+  symbol: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns how many tokens `account` owns, like balance_of().
+   */
+  balance: ({account}: {account: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a owner_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the owner of a token, like get_owner().
+   */
+  owner_of: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a royalty_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns who is owed a royalty on a sale of a token at
+   *      * `sale_price`, and how much: the hotel's payout address, or the contract
+   *      * with 0 if the hotel takes none. The amount is rounded as a sale paid
+   *      * in the asset's base unit would be. Fails with InvalidAmount for a
+   *      * negative price, and AmountOverflow for one too large to take a share of.
+   */
+  royalty_info: ({token_id, sale_price}: {token_id: u64, sale_price: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<readonly [string, i128]>>>
+
+  /**
+   * Construct and simulate a approve_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Approves `operator` for all of `owner`'s tokens through ledger
+   *      * `live_until_ledger`, or revokes it with 0. Either replaces or, with 0,
+   *      * revokes a set_approval_for_all() approval too. Fails with
+   *      * InvalidAmount for a ledger already past.
+   *      * @param owner The owner (must sign).
+   */
+  approve_for_all: ({owner, operator, live_until_ledger}: {owner: string, operator: string, live_until_ledger: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Bids on a running auction. The amount is held by the contract, and
+   *      * the bid it beats is refunded. Fails with InvalidAmount unless the bid
+   *      * beats the high bid (or meets the reserve, for the first bid), and with
+   *      * AuctionEnded once bidding has closed.
+   *      * @param bidder The bidder (must sign and pay).
+   */
+  bid: ({bidder, token_id, amount}: {bidder: string, token_id: u64, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a token's auction, if one is running or awaiting settlement.
+   */
+  auction: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Auction>>>
+
+  /**
+   * Construct and simulate a start_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Auctions a token, replacing an earlier auction of it nobody bid on.
+   *      * Fails with AuctionRunning while an auction with bids is running.
+   *      * @param seller The current owner (must sign); receives the winning bid.
+   *      * @param reserve The lowest acceptable first bid; must not be negative.
+   *      * @param duration Seconds until bidding closes; must not be 0.
+   */
+  start_auction: ({seller, token_id, payment_token, reserve, duration}: {seller: string, token_id: u64, payment_token: string, reserve: i128, duration: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a settle_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Settles an ended auction: pays the seller the high bid and gives
+   *      * the high bidder the token. Anyone may call it. Fails with
+   *      * AuctionRunning while bidding is open. If the token can't move to the
+   *      * high bidder, the auction lapses and the high bid is refunded instead.
+   */
+  settle_auction: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a buyback transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the buyback open for a property's tier, if any.
+   */
+  buyback: ({hotel_id, tier}: {hotel_id: u32, tier: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Buyback>>>
+
+  /**
+   * Construct and simulate a buybacks transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists the open buybacks, as (hotel ID, tier), in the order they
+   *      * were opened.
+   */
+  buybacks: (options?: MethodOptions) => Promise<AssembledTransaction<Array<readonly [u32, string]>>>
+
+  /**
+   * Construct and simulate a fund_buyback transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Opens a buyback for a property's tier, or tops up and updates the
+   *      * open one, moving `amount` from the admin into its budget. Admin only.
+   *      * Fails with InvalidBuyback for a price that isn't positive, a window
+   *      * that closes before it opens, or another payment token than the open
+   *      * buyback's, and TooManyBuybacks if MAX_BUYBACKS are open.
+   *      * @param amount Added to the budget; must not be negative.
+   *      * @return The buyback as updated.
+   */
+  fund_buyback: ({hotel_id, tier, payment_token, price, amount, opens_at, closes_at}: {hotel_id: u32, tier: string, payment_token: string, price: i128, amount: i128, opens_at: u64, closes_at: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Buyback>>>
+
+  /**
+   * Construct and simulate a close_buyback transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Closes a buyback and refunds what is left of its budget to its
+   *      * buyer. Admin only. Works while paused. Fails with NoBuyback if none is
+   *      * open for the tier.
+   */
+  close_buyback: ({hotel_id, tier}: {hotel_id: u32, tier: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a sell_to_hotel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sells a token to the buyback of its property and tier: the owner
+   *      * is paid the price from the budget and the buyer receives the token.
+   *      * Fails with NoBuyback if none is open for the token's tier,
+   *      * BuybackNotOpen outside its window, and BuybackExhausted while its
+   *      * budget can't pay the price.
+   *      * @param owner The current owner (must sign); receives the price.
+   *      * @return What is left of the budget.
+   */
+  sell_to_hotel: ({owner, token_id}: {owner: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a charity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the name a charity is registered under, if it is.
+   */
+  charity: ({charity}: {charity: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a donated transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns what a charity was donated in a calendar year (UTC) in a
+   *      * payment token.
+   */
+  donated: ({charity, year, payment_token}: {charity: string, year: u32, payment_token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a charity_split transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a hotel's charity split; empty if it donates nothing.
+   */
+  charity_split: ({hotel_id}: {hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<readonly [string, u32]>>>
+
+  /**
+   * Construct and simulate a hotel_donated transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns what a hotel donated out of its royalties in a calendar
+   *      * year (UTC) in a payment token.
+   */
+  hotel_donated: ({hotel_id, year, payment_token}: {hotel_id: u32, year: u32, payment_token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a remove_charity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Removes a charity from the register. Admin only. Splits that name
+   *      * it are kept, but its share stays with the hotel until it is registered
+   *      * again.
+   */
+  remove_charity: ({charity}: {charity: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a register_charity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Registers a charity that hotels may donate to, or renames one.
+   *      * Admin only.
+   */
+  register_charity: ({charity, name}: {charity: string, name: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_charity_split transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sets the share of a hotel's royalties donated to each of its
+   *      * charities. Manager role (or admin). Fails with NotCharity for a charity
+   *      * that isn't registered, DuplicateCharity for one named twice,
+   *      * TooManyPayees past MAX_CHARITIES, and InvalidSplit if the shares add up
+   *      * to more than FULL_BPS.
+   *      * @param caller The manager (must sign).
+   *      * @param split Each charity and its share in basis points of the royalty;
+   *      * empty to donate nothing.
+   */
+  set_charity_split: ({caller, hotel_id, split}: {caller: string, hotel_id: u32, split: Array<readonly [string, u32]>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a checkin_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the key a token's check-in codes are signed with, if one is
+   *      * registered.
+   */
+  checkin_key: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<CheckinKey>>>
+
+  /**
+   * Construct and simulate a set_checkin_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Registers the ed25519 public key a guest's device signs a token's
+   *      * check-in codes with, replacing any earlier one. Nonces already used stay
+   *      * used.
+   *      * @param guest The owner, or this year's renter (must sign).
+   */
+  set_checkin_key: ({guest, token_id, public_key}: {guest: string, token_id: u64, public_key: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a verify_checkin_code transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Checks a guest in with a scanned QR code, confirmed at once. Manager
+   *      * role (or admin). Fails with NoCheckinKey before the guest registered a
+   *      * key, CheckinCodeExpired for a code past its expiry or for another year,
+   *      * CheckinCodeUsed unless its nonce is new, and InvalidUsageState unless the
+   *      * week is unused this year. A bad signature traps.
+   *      * @param caller The kiosk (must sign).
+   *      * @param signature The guest device's ed25519 signature of the code.
+   *      * @return The guest who checked in.
+   */
+  verify_checkin_code: ({caller, code, signature}: {caller: string, code: CheckinCode, signature: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a checkin_code_message transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the bytes a check-in code's signature must cover, for signers.
+   */
+  checkin_code_message: ({code}: {code: CheckinCode}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a index_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Adds tokens minted before the indexes existed to them. Anyone may
+   *      * call it; unknown IDs are skipped, and at most MAX_PAGE are examined.
+   */
+  index_tokens: ({token_ids}: {token_ids: Array<u64>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a tokens_by_tier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists a property's tokens of a tier.
+   *      * @param start The index of the first token, 0 for the first page.
+   *      * @param limit The page size, capped at MAX_PAGE.
+   */
+  tokens_by_tier: ({hotel_id, tier, start, limit}: {hotel_id: u32, tier: string, start: u32, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a tokens_by_week transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists the tokens of a week of the year, at every property.
+   *      * @param start The index of the first token, 0 for the first page.
+   *      * @param limit The page size, capped at MAX_PAGE.
+   */
+  tokens_by_week: ({week, start, limit}: {week: u32, start: u32, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a match_swaps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Settles up to `limit` (at most MAX_MATCHES) pairs or three-way
+   *      * cycles of intents that satisfy each other, by the rules in the module
+   *      * notes. Anyone may call it.
+   *      * @return How many swaps were settled.
+   */
+  match_swaps: ({limit}: {limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a post_intent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Posts a swap intent, moving the token into the contract until it
+   *      * is matched or cancelled. Fails with InvalidWeek or HotelNotFound for
+   *      * bad criteria, and IntentBookFull once MAX_SWAP_INTENTS are open.
+   *      * @param owner The token's current owner (must sign).
+   *      * @param want What the owner would take for it.
+   */
+  post_intent: ({owner, token_id, want}: {owner: string, token_id: u64, want: Criteria}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a swap_intent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a token's open swap intent, if it has one.
+   */
+  swap_intent: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SwapIntent>>>
+
+  /**
+   * Construct and simulate a swap_intents transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists the tokens with open swap intents, oldest first.
+   */
+  swap_intents: (options?: MethodOptions) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a cancel_intent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Takes back a swap intent and its token. Fails with NoSwapIntent
+   *      * unless the token has an open intent, and NotOwner for someone else's.
+   *      * @param owner The owner who posted it (must sign).
+   */
+  cancel_intent: ({owner, token_id}: {owner: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a metrics transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the invocation counts recorded for `day` (ledger timestamp / DAY_SECONDS).
+   *      * @return A map from endpoint name to number of successful calls. Empty once expired.
+   */
+  metrics: ({day}: {day: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Map<string, u32>>>
+
+  /**
+   * Construct and simulate a accept_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Completes a pending transfer. Must be signed by the recipient before it expires.
+   *      * @param token_id The ID of the token being transferred.
+   */
+  accept_transfer: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_pending_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the pending transfer of a token, if any, including lapsed ones.
+   */
+  get_pending_transfer: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<PendingTransfer>>>
+
+  /**
+   * Construct and simulate a set_transfer_confirmation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Turns two-phase transfers on or off for tokens sent by `owner`.
+   *      * @param owner The owner changing the setting (must sign).
+   */
+  set_transfer_confirmation: ({owner, enabled}: {owner: string, enabled: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a stats_privacy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns how exact the public stats endpoints are.
+   */
+  stats_privacy: (options?: MethodOptions) => Promise<AssembledTransaction<StatsPrivacy>>
+
+  /**
+   * Construct and simulate a set_stats_privacy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sets how exact the public stats endpoints are. Admin only. Fails
+   *      * with InvalidAmount for a `round_to` of 0.
+   */
+  set_stats_privacy: ({privacy}: {privacy: StatsPrivacy}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a purchase transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the escrowed purchase of a token, until it is released or
+   *      * cancelled.
+   */
+  purchase: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Purchase>>>
+
+  /**
+   * Construct and simulate a cooling_off transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the cooling-off terms of new primary sales, if any.
+   */
+  cooling_off: (options?: MethodOptions) => Promise<AssembledTransaction<Option<CoolingOff>>>
+
+  /**
+   * Construct and simulate a cancel_purchase transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Cancels a purchase within its cooling-off period: burns the token
+   *      * and refunds the buyer. Fails with NoPurchase if there is no escrowed
+   *      * purchase, CoolingOffEnded after the period, like `burn` for tokens
+   *      * held in trust or frozen, and AmountOverflow if the refund overflows.
+   *      * @param buyer The buyer, who must still own the token (must sign).
+   *      * @return The amount refunded.
+   */
+  cancel_purchase: ({buyer, token_id}: {buyer: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a set_cooling_off transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sets the cooling-off period for future primary sales. Admin only.
+   *      * Purchases already made keep their terms.
+   *      * @param period Ledgers buyers have to cancel; 0 turns cooling-off off.
+   *      * @param refund_bps Share of the price refunded, at most FULL_REFUND_BPS.
+   */
+  set_cooling_off: ({period, refund_bps}: {period: u32, refund_bps: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a release_purchase transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Pays the seller a purchase's escrowed proceeds once its cooling-off
+   *      * period is over. Anyone may call it. Fails with CoolingOffRunning during
+   *      * the period.
+   */
+  release_purchase: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a first_refusal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a token's accepted offer waiting out the hotel's right of
+   *      * first refusal, if any.
+   */
+  first_refusal: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<FirstRefusal>>>
+
+  /**
+   * Construct and simulate a set_first_refusal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Turns the hotel's right of first refusal on or off for a property.
+   *      * Offers accepted before it was turned off still wait out their window.
+   *      * Admin only.
+   */
+  set_first_refusal: ({hotel_id, enabled}: {hotel_id: u32, enabled: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a first_refusal_hotels transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists the properties whose sales the hotel has a right of first
+   *      * refusal on, in the order it was turned on.
+   */
+  first_refusal_hotels: (options?: MethodOptions) => Promise<AssembledTransaction<Array<u32>>>
+
+  /**
+   * Construct and simulate a complete_first_refusal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Settles an accepted offer the hotel didn't match: the seller is
+   *      * paid the escrowed amount (royalty included) and the buyer receives the
+   *      * token. Anyone may call it once the refusal window has passed; the
+   *      * admin may call it earlier to waive the right. Fails with
+   *      * NoFirstRefusal without one, and FirstRefusalOpen while the window is
+   *      * open and the admin hasn't signed.
+   *      * @param caller Whoever settles it (must sign).
+   */
+  complete_first_refusal: ({caller, token_id}: {caller: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a exercise_first_refusal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Matches an accepted offer while its refusal window is open: the
+   *      * seller is paid the offered amount by the admin (royalty included), the
+   *      * admin receives the token and the buyer is refunded. Admin only. Fails
+   *      * with NoFirstRefusal without one, and FirstRefusalLapsed once its
+   *      * window has passed.
+   */
+  exercise_first_refusal: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a fund_reserve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Pays into a hotel's reserve, e.g. a special assessment. Fails with
+   *      * InvalidAmount unless the amount is positive and in the reserve's token.
+   *      * @param from The payer (must sign and pay).
+   */
+  fund_reserve: ({from, hotel_id, payment_token, amount}: {from: string, hotel_id: u32, payment_token: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reserve_spend transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a spend proposal of a hotel's reserve, whatever its state.
+   */
+  reserve_spend: ({hotel_id, proposal_id}: {hotel_id: u32, proposal_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SpendProposal>>>
+
+  /**
+   * Construct and simulate a reserve_report transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a hotel's reserve share and what its reserve took in,
+   *      * paid out and has committed.
+   */
+  reserve_report: ({hotel_id}: {hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<ReserveReport>>>
+
+  /**
+   * Construct and simulate a reserve_shares transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns every reserve share set, by hotel ID.
+   */
+  reserve_shares: (options?: MethodOptions) => Promise<AssembledTransaction<Map<u32, u32>>>
+
+  /**
+   * Construct and simulate a set_reserve_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sets the share of a hotel's maintenance fees held in its reserve.
+   *      * Manager role (or admin).
+   *      * @param caller The manager (must sign).
+   *      * @param share_bps At most FULL_SHARE_BPS; 0 reserves nothing.
+   */
+  set_reserve_share: ({caller, hotel_id, share_bps}: {caller: string, hotel_id: u32, share_bps: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a cancel_reserve_spend transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Cancels a pending spend proposal. Manager role (or admin).
+   *      * @param caller The manager (must sign).
+   */
+  cancel_reserve_spend: ({caller, hotel_id, proposal_id}: {caller: string, hotel_id: u32, proposal_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a execute_reserve_spend transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Pays out a pending spend proposal once RESERVE_SPEND_DELAY has
+   *      * passed. Anyone may call it. Fails with TimelockNotElapsed before then.
+   */
+  execute_reserve_spend: ({hotel_id, proposal_id}: {hotel_id: u32, proposal_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a propose_reserve_spend transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Proposes paying an amount out of a hotel's reserve. Manager role
+   *      * (or admin). Fails with InsufficientReserve if the reserve, less what
+   *      * pending proposals commit, can't cover it.
+   *      * @param caller The manager (must sign).
+   *      * @param memo What the payment is for.
+   *      * @return The proposal's ID within the hotel.
+   */
+  propose_reserve_spend: ({caller, hotel_id, recipient, amount, memo}: {caller: string, hotel_id: u32, recipient: string, amount: i128, memo: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Replaces the contract's code, keeping its address and state. Admin only.
+   *      * The new code runs from the next invocation on.
+   *      * @param new_wasm_hash Hash of wasm already uploaded to the network.
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a version_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the semantic version, the hash of the running code, and the upgrade log.
+   */
+  version_info: (options?: MethodOptions) => Promise<AssembledTransaction<VersionInfo>>
+
+  /**
+   * Construct and simulate a restore_hint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists the ledger keys that must be live to use `token_id`.
+   *      * @return One XDR-encoded LedgerKey per entry, ready to base64 into a
+   *      * restore footprint. Restoring a key that is already live is harmless.
+   */
+  restore_hint: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Array<Buffer>>>
+
+  /**
+   * Construct and simulate a branding transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a property's branding, if it has one.
+   */
+  branding: ({hotel_id}: {hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Branding>>>
+
+  /**
+   * Construct and simulate a brandings transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the branding of every property that has one, by hotel ID.
+   */
+  brandings: (options?: MethodOptions) => Promise<AssembledTransaction<Map<u32, Branding>>>
+
+  /**
+   * Construct and simulate a set_branding transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sets a property's branding, replacing any earlier one. Manager role
+   *      * (or admin). Fails with InvalidBranding for more than MAX_BRAND_COLORS
+   *      * colors or one above 0xFFFFFF.
+   *      * @param caller The manager (must sign).
+   */
+  set_branding: ({caller, hotel_id, branding}: {caller: string, hotel_id: u32, branding: Branding}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a clear_branding transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Clears a property's branding. Manager role (or admin).
+   *      * @param caller The manager (must sign).
+   */
+  clear_branding: ({caller, hotel_id}: {caller: string, hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a clawback transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Takes a token from its owner. Admin only.
+   *      * @param to The new owner, or None to burn the token.
+   *      * @param reason_code Why, in the hotel's own codes; recorded on-chain.
+   */
+  clawback: ({token_id, to, reason_code}: {token_id: u64, to: Option<string>, reason_code: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a clawback_record transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the record of a token's last clawback, if it was clawed back.
+   */
+  clawback_record: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<ClawbackRecord>>>
+
+  /**
+   * Construct and simulate a tier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a token's tier, if it has one.
+   */
+  tier: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_tier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sets or clears a token's tier. Manager role (or admin). Fails with
+   *      * MetadataFrozen once the metadata is frozen.
+   *      * @param caller The manager (must sign).
+   *      * @param tier The tier, e.g. "Platinum", or None to clear it.
+   */
+  set_tier: ({caller, token_id, tier}: {caller: string, token_id: u64, tier: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a token_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a token's metadata URI, if one was set.
+   */
+  token_uri: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
+
+  /**
+   * Construct and simulate a update_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Replaces a token's info, e.g. after a room is renumbered. Manager
+   *      * role (or admin). The new info is checked like a mint's, and frees the
+   *      * old week for sale. Fails with MetadataFrozen once the metadata is frozen.
+   *      * @param caller The manager (must sign).
+   */
+  update_info: ({caller, token_id, new_info}: {caller: string, token_id: u64, new_info: TimeshareInfo}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_token_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Links a token to its off-chain metadata, replacing any earlier URI.
+   *      * Manager role (or admin). Fails with MetadataFrozen once the metadata
+   *      * is frozen.
+   *      * @param caller The manager (must sign).
+   *      * @param uri The metadata's location, e.g. "ipfs://<cid>".
+   */
+  set_token_uri: ({caller, token_id, uri}: {caller: string, token_id: u64, uri: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a freeze_metadata transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Locks a token's info, URI and tier for good. Manager role (or admin).
+   *      * Freezing frozen metadata does nothing.
+   *      * @param caller The manager (must sign).
+   */
+  freeze_metadata: ({caller, token_id}: {caller: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a is_metadata_frozen transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns whether a token's metadata is frozen.
+   */
+  is_metadata_frozen: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a receipt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns one of a token's receipts, numbered from 0 in the order
+   *      * they were issued.
+   */
+  receipt: ({token_id, number}: {token_id: u64, number: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Receipt>>>
+
+  /**
+   * Construct and simulate a receipts_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists the receipts naming an address as buyer or seller, oldest
+   *      * first.
+   *      * @param start The index of the first receipt, 0 for the first page.
+   *      * @param limit The page size, capped at MAX_PAGE.
+   */
+  receipts_of: ({party, start, limit}: {party: string, start: u32, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<Receipt>>>
+
+  /**
+   * Construct and simulate a set_receipts transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Turns receipts for future settlements on or off. Admin only.
+   *      * Receipts already issued are kept.
+   */
+  set_receipts: ({enabled}: {enabled: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a receipt_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns how many receipts a token has.
+   */
+  receipt_count: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a receipts_enabled transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns whether settlements are recorded as receipts.
+   */
+  receipts_enabled: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a party_receipt_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns how many receipts name an address as buyer or seller.
+   */
+  party_receipt_count: ({party}: {party: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a payees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the revenue split; empty if proceeds go to the admin.
+   */
+  payees: (options?: MethodOptions) => Promise<AssembledTransaction<Array<readonly [string, u32]>>>
+
+  /**
+   * Construct and simulate a withdraw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Pays a payee everything they are owed in a payment token. Fails
+   *      * with InvalidAmount if that is nothing.
+   *      * @param payee The payee (must sign).
+   *      * @return The amount paid.
+   */
+  withdraw: ({payee, payment_token}: {payee: string, payment_token: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a set_payees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sets the revenue split. Admin only. Balances already credited are
+   *      * kept. Fails with InvalidSplit unless the shares add up to
+   *      * SPLIT_TOTAL_BPS, and TooManyPayees past MAX_PAYEES.
+   *      * @param payees Each payee and its share in basis points; empty to pay
+   *      * the admin directly again.
+   */
+  set_payees: ({payees}: {payees: Array<readonly [string, u32]>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a withdrawable transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns what a payee is owed in a payment token.
+   */
+  withdrawable: ({payee, payment_token}: {payee: string, payment_token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a redeem_voucher transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Redeems a voucher: the buyer pays its price to the admin and is
+   *      * minted its token. Fails with NoVoucherSigner before a signer is
+   *      * registered, WrongBuyer if the voucher names another buyer, and
+   *      * VoucherExpired or VoucherRedeemed once it can't be used. A bad signature
+   *      * traps.
+   *      * @param buyer The buyer (must sign and pay).
+   *      * @param signature The signer's ed25519 signature of the voucher.
+   *      * @return The new token ID.
+   */
+  redeem_voucher: ({buyer, voucher, signature}: {buyer: string, voucher: Voucher, signature: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a voucher_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the key vouchers are signed with, if one is registered.
+   */
+  voucher_signer: (options?: MethodOptions) => Promise<AssembledTransaction<Option<Buffer>>>
+
+  /**
+   * Construct and simulate a voucher_message transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the bytes a voucher's signature must cover, for signers.
+   */
+  voucher_message: ({voucher}: {voucher: Voucher}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a set_voucher_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Registers the ed25519 public key vouchers must be signed with,
+   *      * replacing any earlier one. Admin only.
+   */
+  set_voucher_signer: ({public_key}: {public_key: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a allowlist transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the open allowlist phase, if any.
+   */
+  allowlist: (options?: MethodOptions) => Promise<AssembledTransaction<Option<Allowlist>>>
+
+  /**
+   * Construct and simulate a set_allowlist transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Opens an allowlist phase, replacing any earlier one. Admin only.
+   *      * @param allowlist The root, price and payment token; the price must not
+   *      * be negative.
+   */
+  set_allowlist: ({allowlist}: {allowlist: Allowlist}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a allowlist_mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Mints a week to an allowlisted buyer for the phase's price, paid to
+   *      * the admin. Fails with NoAllowlist if no phase is open, NotAllowlisted
+   *      * if the proof doesn't lead to the root, and AllowlistClaimed if the buyer
+   *      * already minted in this phase.
+   *      * @param buyer The buyer (must sign and pay).
+   *      * @param proof The sibling hashes from the buyer's leaf up to the root.
+   *      * @param info The week to mint; it must be unsold.
+   *      * @return The new token ID.
+   */
+  allowlist_mint: ({buyer, proof, info}: {buyer: string, proof: Array<Buffer>, info: TimeshareInfo}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a close_allowlist transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Ends the allowlist phase. Admin only.
+   */
+  close_allowlist: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lets `spender` transfer `token_id` on the owner's behalf, replacing
+   *      * any earlier approval for that token.
+   *      * @param owner The current owner (must sign).
+   *      * @param spender The address allowed to call transfer_from.
+   *      * @param token_id The token being approved.
+   */
+  approve: ({owner, spender, token_id}: {owner: string, spender: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_approved transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the address approved to transfer `token_id`, if any.
+   */
+  get_approved: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Transfers a token on behalf of its owner. A single-token approval is
+   *      * used up; an operator approval stays in place.
+   *      * @param spender The approved address or an operator of `from` (must sign).
+   *      * @param from The current owner.
+   *      * @param to The new owner.
+   *      * @param token_id The token to transfer.
+   */
+  transfer_from: ({spender, from, to, token_id}: {spender: string, from: string, to: string, token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a is_approved_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns true if `operator` may transfer any of `owner`'s tokens,
+   *      * through `set_approval_for_all` or an unexpired `approve_for_all`.
+   */
+  is_approved_for_all: ({owner, operator}: {owner: string, operator: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a set_approval_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Approves or revokes `operator` for all of `owner`'s tokens,
+   *      * replacing an approval_for_all() expiry.
+   *      * @param owner The owner (must sign).
+   *      * @param operator The address allowed to call transfer_from for any of the owner's tokens.
+   *      * @param approved true to approve, false to revoke.
+   */
+  set_approval_for_all: ({owner, operator, approved}: {owner: string, operator: string, approved: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a directory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists a property's directory entries, in the order owners joined.
+   *      * @param start The index of the first entry, 0 for the first page.
+   *      * @param limit The page size, capped at MAX_PAGE.
+   */
+  directory: ({hotel_id, start, limit}: {hotel_id: u32, start: u32, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<DirectoryEntry>>>
+
+  /**
+   * Construct and simulate a join_directory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists an owner in the directory of a token's property, or updates
+   *      * their entry there.
+   *      * @param owner The token's owner (must sign).
+   *      * @param token_id Any token of the property the owner holds.
+   *      * @return The property's hotel ID.
+   */
+  join_directory: ({owner, token_id, contact_hash, private}: {owner: string, token_id: u64, contact_hash: Buffer, private: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a directory_entry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns an owner's entry in a property's directory, if listed.
+   */
+  directory_entry: ({hotel_id, owner}: {hotel_id: u32, owner: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<DirectoryEntry>>>
+
+  /**
+   * Construct and simulate a leave_directory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Removes an owner from a property's directory. Leaving a directory
+   *      * one isn't in does nothing.
+   *      * @param owner The listed owner (must sign).
+   */
+  leave_directory: ({owner, hotel_id}: {owner: string, hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prune_directory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Removes an owner who holds no token of the property any more from
+   *      * its directory. Anyone may call it.
+   *      * @return Whether the owner was removed.
+   */
+  prune_directory: ({hotel_id, owner}: {hotel_id: u32, owner: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a fee_index transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns how the maintenance fee follows an index, if it does.
+   */
+  fee_index: (options?: MethodOptions) => Promise<AssembledTransaction<Option<FeeIndex>>>
+
+  /**
+   * Construct and simulate a set_fee_index transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Links the maintenance fee to an oracle's index, or unlinks it with
+   *      * None. Admin only. Fails with InvalidAmount for negative fees, a base
+   *      * index that isn't positive, a negative step, or a floor above the
+   *      * ceiling.
+   */
+  set_fee_index: ({index}: {index: Option<FeeIndex>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a fee_billed_year transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the last year maintenance fees were collected in, which
+   *      * fixes that year's fee, if they have been.
+   */
+  fee_billed_year: (options?: MethodOptions) => Promise<AssembledTransaction<Option<u32>>>
+
+  /**
+   * Construct and simulate a fee_indexed_year transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the last year the maintenance fee was repriced from the
+   *      * index, if it has been.
+   */
+  fee_indexed_year: (options?: MethodOptions) => Promise<AssembledTransaction<Option<u32>>>
+
+  /**
+   * Construct and simulate a index_maintenance_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Reprices the maintenance fee from the oracle's latest reading of
+   *      * the index, within the caps. Anyone may call it, once per calendar year.
+   *      * Fails with NoFeeIndex if the fee isn't indexed, InvalidAmount without a
+   *      * fee schedule, FeeAlreadyIndexed if this year's fee is set,
+   *      * FeeYearBilled once this year's fees are being collected, and
+   *      * StaleIndex without a reading newer than the index's max age.
+   *      * @return The new fee.
+   */
+  index_maintenance_fee: (options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Runs every migration step between the stored layout version and
+   *      * SCHEMA_VERSION. Admin only; a no-op once the layout is current.
+   *      * @return The layout version now stored.
+   */
+  migrate: (options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a schema_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the layout version of the stored data.
+   */
+  schema_version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a migrate_entries transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Moves the listed entries from their version 1 location (instance
+   *      * storage) to persistent storage, skipping keys that aren't there. Admin only.
+   *      * @param keys At most MAX_MINT_BATCH keys per call.
+   *      * @return How many entries were moved.
+   */
+  migrate_entries: ({keys}: {keys: Array<DataKey>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a buy_mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Buys an unsold week: pays the hotel's price and mints the week to
+   *      * the buyer. Fails with NotListed if the hotel's weeks aren't on sale.
+   *      * @param buyer The buyer (must sign and pay).
+   *      * @param room The room, from the hotel's inventory.
+   *      * @param week The week of the year (1-52).
+   *      * @return The new token ID.
+   */
+  buy_mint: ({buyer, hotel_id, room, week}: {buyer: string, hotel_id: u32, room: string, week: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a mint_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns what a hotel's unsold weeks sell for, if they are on sale.
+   */
+  mint_price: ({hotel_id}: {hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<MintPrice>>>
+
+  /**
+   * Construct and simulate a set_mint_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Puts a hotel's unsold weeks on sale, or changes their price.
+   *      * Manager role (or admin).
+   *      * @param caller The manager (must sign).
+   *      * @param price The price of a week; must not be negative.
+   *      * @param payment_token The token the price is paid in.
+   */
+  set_mint_price: ({caller, hotel_id, price, payment_token}: {caller: string, hotel_id: u32, price: i128, payment_token: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a clear_mint_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Takes a hotel's weeks off sale. Manager role (or admin).
+   *      * @param caller The manager (must sign).
+   */
+  clear_mint_price: ({caller, hotel_id}: {caller: string, hotel_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a deadlines transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Lists a token's upcoming deadlines, in DeadlineKind order.
+   */
+  deadlines: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<Deadline>>>>
+
+  /**
+   * Construct and simulate a send_reminders transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Publishes DeadlineApproaching for each of a token's deadlines less
+   *      * than REMINDER_LEAD away that hasn't been reminded yet. Anyone may call
+   *      * it, e.g. a keeper sweeping tokens().
+   *      * @return How many reminders were published.
+   */
+  send_reminders: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_royalty transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the royalty owed on resales of a token, if its hotel set one.
+   */
+  get_royalty: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<Royalty>>>>
+
+  /**
+   * Construct and simulate a set_royalty transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Sets a hotel's royalty. Manager role (or admin).
+   *      * @param caller The manager (must sign).
+   *      * @param payout Receives the royalties.
+   *      * @param bps The cut in basis points, at most MAX_ROYALTY_BPS; 0 takes none.
+   */
+  set_royalty: ({caller, hotel_id, payout, bps}: {caller: string, hotel_id: u32, payout: string, bps: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a appraisal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a token's appraisal, if it has one.
+   */
+  appraisal: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Appraisal>>>
+
+  /**
+   * Construct and simulate a price_mark transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns what a token has sold for, if it has sold.
+   */
+  price_mark: ({token_id}: {token_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<PriceMark>>>
+
+  /**
+   * Construct and simulate a token_value transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns a token's value in an asset: its appraisal or its
+   *      * time-weighted average sale price, whichever is more recent, or 0 if
+   *      * neither is in that asset.
+   */
+  token_value: ({token_id, asset}: {token_id: u64, asset: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a set_appraisal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Appraises a token, replacing any earlier appraisal. Manager role
+   *      * (or admin). It values the token until the token next sells.
+   *      * @param caller The manager (must sign).
+   *      * @param value In the asset's base units; must not be negative.
+   */
+  set_appraisal: ({caller, token_id, asset, value}: {caller: string, token_id: u64, asset: string, value: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a portfolio_value transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * * @dev Returns the value in an asset of everything an owner holds: their
+   *      * tokens, and their part of each fractionalized token they hold shares
+   *      * of, rounded half to even.
+   */
+  portfolio_value: ({owner, asset}: {owner: string, asset: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+}
+export class Client extends ContractClient {
+  static async deploy<T = Client>(
+    /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
+    options: MethodOptions &
+      Omit<ContractClientOptions, "contractId"> & {
+        /** The hash of the Wasm blob, which must already be installed on-chain. */
+        wasmHash: Buffer | string;
+        /** Salt used to generate the contract's ID. Passed through to {@link Operation.createCustomContract}. Default: random. */
+        salt?: Buffer | Uint8Array;
+        /** The format used to decode `wasmHash`, if it's provided as a string. */
+        format?: "hex" | "base64";
+      }
+  ): Promise<AssembledTransaction<T>> {
+    return ContractClient.deploy(null, options)
+  }
+  constructor(public readonly options: ContractClientOptions) {
+    super(
+      new ContractSpec([ "AAAAAQAAAEoqIEB0aXRsZSBDcml0ZXJpYQogKiBAZGV2IFdoYXQgYSB0b2tlbiBtdXN0IGJlIHRvIGZpbGwgYSBjb2xsZWN0aW9uIG9mZmVyLgAAAAAAAAAAAAhDcml0ZXJpYQAAAAQAAAAjVGhlIGVhcmxpZXN0IHdlZWsgYWNjZXB0ZWQsIGZyb20gMS4AAAAACmZpcnN0X3dlZWsAAAAAAAQAAAAeVGhlIHByb3BlcnR5LCBvciBOb25lIGZvciBhbnkuAAAAAAAIaG90ZWxfaWQAAAPoAAAABAAAAC9UaGUgbGF0ZXN0IHdlZWsgYWNjZXB0ZWQsIHVwIHRvIFdFRUtTX1BFUl9ZRUFSLgAAAAAJbGFzdF93ZWVrAAAAAAAABAAAACdUaGUgdGllciwgb3IgTm9uZSBmb3IgYW55IHRpZXIgb3Igbm9uZS4AAAAABHRpZXIAAAPoAAAAEQ==",
+        "AAAAAQAAAGEqIEB0aXRsZSBDb2xsZWN0aW9uT2ZmZXIKICogQGRldiBBIGJ1eWVyJ3MgZXNjcm93ZWQgb2ZmZXIgZm9yIGFueSBvbmUgdG9rZW4gbWVldGluZyBpdHMgY3JpdGVyaWEuAAAAAAAAAAAAAA9Db2xsZWN0aW9uT2ZmZXIAAAAABQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAACGNyaXRlcmlhAAAH0AAAAAhDcml0ZXJpYQAAADdMZWRnZXIgdGltZXN0YW1wIGFmdGVyIHdoaWNoIHRoZSBvZmZlciBjYW4ndCBiZSBmaWxsZWQuAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAHFRva2VuIHRoZSBhbW91bnQgaXMgcGFpZCBpbi4AAAANcGF5bWVudF90b2tlbgAAAAAAABM=",
+        "AAAABQAAAFUqIEB0aXRsZSBDb2xsZWN0aW9uT2ZmZXJNYWRlCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBidXllciBtYWtlcyBhIGNvbGxlY3Rpb24gb2ZmZXIuAAAAAAAAAAAAABNDb2xsZWN0aW9uT2ZmZXJNYWRlAAAAAAEAAAAVY29sbGVjdGlvbl9vZmZlcl9tYWRlAAAAAAAABgAAAAAAAAAIb2ZmZXJfaWQAAAAEAAAAAQAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAAAAAAAAIY3JpdGVyaWEAAAfQAAAACENyaXRlcmlhAAAAAAAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAAAAAAI=",
+        "AAAABQAAAIQqIEB0aXRsZSBDb2xsZWN0aW9uT2ZmZXJGaWxsZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhbiBvd25lciBzZWxscyBhIHRva2VuIGludG8gYSBjb2xsZWN0aW9uIG9mZmVyLCBuZXh0CiAqIHRvIGl0cyBUcmFuc2ZlciBldmVudC4AAAAAAAAAFUNvbGxlY3Rpb25PZmZlckZpbGxlZAAAAAAAAAEAAAAXY29sbGVjdGlvbl9vZmZlcl9maWxsZWQAAAAABQAAAAAAAAAIb2ZmZXJfaWQAAAAEAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAF8qIEB0aXRsZSBDb2xsZWN0aW9uT2ZmZXJDYW5jZWxsZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIGJ1eWVyIHRha2VzIGJhY2sgYSBjb2xsZWN0aW9uIG9mZmVyLgAAAAAAAAAAGENvbGxlY3Rpb25PZmZlckNhbmNlbGxlZAAAAAEAAAAaY29sbGVjdGlvbl9vZmZlcl9jYW5jZWxsZWQAAAAAAAEAAAAAAAAACG9mZmVyX2lkAAAABAAAAAEAAAAC",
+        "AAAAAAAAAEUqIEBkZXYgUmV0dXJucyBhIGNvbGxlY3Rpb24gb2ZmZXIsIHVubGVzcyBpdCB3YXMgZmlsbGVkIG9yIGNhbmNlbGxlZC4AAAAAAAAQY29sbGVjdGlvbl9vZmZlcgAAAAEAAAAAAAAACG9mZmVyX2lkAAAABAAAAAEAAAPoAAAH0AAAAA9Db2xsZWN0aW9uT2ZmZXIA",
+        "AAAAAAAAAVgqIEBkZXYgU2VsbHMgYSB0b2tlbiBpbnRvIGEgY29sbGVjdGlvbiBvZmZlcjogdGhlIG93bmVyIGlzIHBhaWQgdGhlCiAgICAgKiBlc2Nyb3dlZCBhbW91bnQgYW5kIHRoZSBidXllciByZWNlaXZlcyB0aGUgdG9rZW4uIEZhaWxzIHdpdGgKICAgICAqIE5vQ29sbGVjdGlvbk9mZmVyIGZvciBhbiB1bmtub3duIG9mZmVyLCBPZmZlckV4cGlyZWQgb25jZSBpdCBleHBpcmVkLAogICAgICogYW5kIENyaXRlcmlhTWlzbWF0Y2ggdW5sZXNzIHRoZSB0b2tlbiBtZWV0cyB0aGUgb2ZmZXIncyBjcml0ZXJpYS4KICAgICAqIEBwYXJhbSBvd25lciBUaGUgdG9rZW4ncyBjdXJyZW50IG93bmVyIChtdXN0IHNpZ24pLgAAABVmaWxsX2NvbGxlY3Rpb25fb2ZmZXIAAAAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACG9mZmVyX2lkAAAABAAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAcwqIEBkZXYgT2ZmZXJzIHRvIGJ1eSBhbnkgb25lIHRva2VuIG1lZXRpbmcgYGNyaXRlcmlhYCwgbW92aW5nIHRoZSBhbW91bnQKICAgICAqIGludG8gdGhlIGNvbnRyYWN0LiBGYWlscyB3aXRoIEludmFsaWRXZWVrIHVubGVzcyB0aGUgd2Vla3MgYXJlIGEgcmFuZ2UKICAgICAqIHdpdGhpbiAxLi49V0VFS1NfUEVSX1lFQVIsIGFuZCBPZmZlckV4cGlyZWQgaWYgdGhlIG9mZmVyIHdvdWxkIGFscmVhZHkKICAgICAqIGhhdmUgZXhwaXJlZC4KICAgICAqIEBwYXJhbSBidXllciBUaGUgYnV5ZXIgKG11c3Qgc2lnbiBhbmQgcGF5KS4KICAgICAqIEBwYXJhbSBhbW91bnQgVGhlIG9mZmVyOyBtdXN0IGJlIHBvc2l0aXZlLgogICAgICogQHBhcmFtIGV4cGlyZXNfYXQgTGVkZ2VyIHRpbWVzdGFtcCB1bnRpbCB3aGljaCB0aGUgb2ZmZXIgY2FuIGJlIGZpbGxlZC4KICAgICAqIEByZXR1cm4gVGhlIG9mZmVyJ3MgSUQuAAAAFW1ha2VfY29sbGVjdGlvbl9vZmZlcgAAAAAAAAUAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAIY3JpdGVyaWEAAAfQAAAACENyaXRlcmlhAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAFsqIEBkZXYgUmV0dXJucyBob3cgbWFueSBjb2xsZWN0aW9uIG9mZmVycyBoYXZlIGJlZW4gbWFkZSwgaS5lLiB0aGUKICAgICAqIGhpZ2hlc3Qgb2ZmZXIgSUQuAAAAABZjb2xsZWN0aW9uX29mZmVyX2NvdW50AAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAPwqIEBkZXYgVGFrZXMgYmFjayBhIGNvbGxlY3Rpb24gb2ZmZXIgYW5kIGl0cyBlc2Nyb3dlZCBhbW91bnQsIGV4cGlyZWQgb3IKICAgICAqIG5vdC4gRmFpbHMgd2l0aCBOb3RPd25lciBmb3Igc29tZW9uZSBlbHNlJ3Mgb2ZmZXIuIFdvcmtzIHdoaWxlIHBhdXNlZCwKICAgICAqIHNvIGEgcGF1c2UgbmV2ZXIgbG9ja3MgYSBidXllcidzIGZ1bmRzLgogICAgICogQHBhcmFtIGJ1eWVyIFRoZSBidXllciB3aG8gbWFkZSBpdCAobXVzdCBzaWduKS4AAAAXY2FuY2VsX2NvbGxlY3Rpb25fb2ZmZXIAAAAAAgAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAhvZmZlcl9pZAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAABQAAAFgqIEB0aXRsZSBSZWRlZW1lZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGFuIG93bmVyIHJlZGVlbXMgYSB0b2tlbiBvZiBhIHJldGlyZWQgcHJvcGVydHkuAAAAAAAAAAhSZWRlZW1lZAAAAAEAAAAIcmVkZWVtZWQAAAADAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAAAAAAAZwYXlvdXQAAAAAAAsAAAAAAAAAAg==",
+        "AAAAAQAAAEsqIEB0aXRsZSBSZXRpcmVtZW50CiAqIEBkZXYgVGVybXMgYW5kIHJ1bm5pbmcgdG90YWxzIG9mIGEgcmV0aXJlZCBwcm9wZXJ0eS4AAAAAAAAAAApSZXRpcmVtZW50AAAAAAAHAAAAIFRvdGFsIGRlcG9zaXRlZCB0byBmdW5kIHBheW91dHMuAAAABmZ1bmRlZAAAAAAACwAAADVUb3RhbCBwYWlkIG91dCAob3IgcmVmdW5kZWQgdG8gdGhlIGFkbWluIGJ5IGEgc3dlZXApLgAAAAAAAARwYWlkAAAACwAAACJQYWlkIHBlciByZWRlZW1lZCB0aW1lc2hhcmUgdG9rZW4uAAAAAAAGcGF5b3V0AAAAAAALAAAAIFRva2VucyByZWRlZW1lZCBieSB0aGVpciBvd25lcnMuAAAACHJlZGVlbWVkAAAABAAAACNVbnJlZGVlbWVkIHRva2VucyBidXJuZWQgYnkgc3dlZXBzLgAAAAAFc3dlcHQAAAAAAAAEAAAAGlRva2VuIHBheW91dHMgYXJlIG1hZGUgaW4uAAAAAAAFdG9rZW4AAAAAAAATAAAAPkxlZGdlciB0aW1lc3RhbXAgYWZ0ZXIgd2hpY2ggdG9rZW5zIGNhbiBubyBsb25nZXIgYmUgcmVkZWVtZWQuAAAAAAAKd2luZG93X2VuZAAAAAAABg==",
+        "AAAABQAAAGMqIEB0aXRsZSBQcm9wZXJ0eVN3ZXB0CiAqIEBkZXYgUHVibGlzaGVkIGZvciBldmVyeSBzd2VlcCBvZiBhIHJldGlyZWQgcHJvcGVydHkncyB1bnJlZGVlbWVkIHRva2Vucy4AAAAAAAAAAA1Qcm9wZXJ0eVN3ZXB0AAAAAAAAAQAAAA5wcm9wZXJ0eV9zd2VwdAAAAAAAAwAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAAAAAAAAGYnVybmVkAAAAAAAEAAAAAAAAAAAAAAAIcmVmdW5kZWQAAAALAAAAAAAAAAI=",
+        "AAAABQAAAGYqIEB0aXRsZSBQcm9wZXJ0eVJldGlyZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIHByb3BlcnR5IGlzIHJldGlyZWQgYW5kIGl0cyByZWRlbXB0aW9uIHdpbmRvdyBvcGVucy4AAAAAAAAAAAAPUHJvcGVydHlSZXRpcmVkAAAAAAEAAAAQcHJvcGVydHlfcmV0aXJlZAAAAAMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAEAAAAAAAAABnBheW91dAAAAAAACwAAAAAAAAAAAAAACndpbmRvd19lbmQAAAAAAAYAAAAAAAAAAg==",
+        "AAAAAAAAAWIqIEBkZXYgQnVybnMgYSB0b2tlbiBvZiBhIHJldGlyZWQgcHJvcGVydHkgYW5kIHBheXMgaXRzIG93bmVyIHRoZSBwYXlvdXQuCiAgICAgKiBGYWlscyB3aXRoIFJlZGVtcHRpb25XaW5kb3dDbG9zZWQgYWZ0ZXIgdGhlIHdpbmRvdywgQWxyZWFkeUluVHJ1c3QgZm9yCiAgICAgKiB0b2tlbnMgaGVsZCBpbiB0cnVzdCwgYW5kIFJlZGVtcHRpb25VbmRlcmZ1bmRlZCBpZiB0aGUgZnVuZGluZyBsZWZ0CiAgICAgKiBkb2Vzbid0IGNvdmVyIHRoZSBwYXlvdXQuCiAgICAgKiBAcGFyYW0gb3duZXIgVGhlIGN1cnJlbnQgb3duZXIgKG11c3Qgc2lnbikuCiAgICAgKiBAcGFyYW0gdG9rZW5faWQgVGhlIHRva2VuIHRvIHJlZGVlbS4AAAAAAAZyZWRlZW0AAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEYqIEBkZXYgUmV0dXJucyBhIHByb3BlcnR5J3MgcmV0aXJlbWVudCByZWNvcmQsIGlmIGl0IGhhcyBiZWVuIHJldGlyZWQuAAAAAAAKcmV0aXJlbWVudAAAAAAAAQAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAA+gAAAfQAAAAClJldGlyZW1lbnQAAA==",
+        "AAAAAAAAASMqIEBkZXYgQWZ0ZXIgdGhlIHJlZGVtcHRpb24gd2luZG93LCBidXJucyB1bnJlZGVlbWVkIHRva2VucyBvZiBhIHJldGlyZWQKICAgICAqIHByb3BlcnR5IGFuZCByZWZ1bmRzIHRoZSB1bnVzZWQgZnVuZGluZyB0byB0aGUgYWRtaW4uIEFkbWluIG9ubHkuCiAgICAgKiBAcGFyYW0gdG9rZW5faWRzIFRva2VucyBvZiB0aGUgcHJvcGVydHkgdG8gYnVybiwgYXQgbW9zdCBNQVhfTUlOVF9CQVRDSCBwZXIgY2FsbC4KICAgICAqIEByZXR1cm4gVGhlIHByb3BlcnR5J3MgdXBkYXRlZCByZXRpcmVtZW50IHJlY29yZC4AAAAADnN3ZWVwX3Byb3BlcnR5AAAAAAACAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAACXRva2VuX2lkcwAAAAAAA+oAAAAGAAAAAQAAA+kAAAfQAAAAClJldGlyZW1lbnQAAAAAAAM=",
+        "AAAAAAAAAHoqIEBkZXYgQWRkcyBmdW5kaW5nIGZvciBhIHJldGlyZWQgcHJvcGVydHkncyBwYXlvdXRzLiBBZG1pbiBvbmx5LgogICAgICogQHJldHVybiBUaGUgcHJvcGVydHkncyB1cGRhdGVkIHJldGlyZW1lbnQgcmVjb3JkLgAAAAAAD2Z1bmRfcmV0aXJlbWVudAAAAAACAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAH0AAAAApSZXRpcmVtZW50AAAAAAAD",
+        "AAAAAAAAAVYqIEBkZXYgUmV0aXJlcyBhIHByb3BlcnR5IGFuZCBvcGVucyBpdHMgcmVkZW1wdGlvbiB3aW5kb3cuIEFkbWluIG9ubHkuCiAgICAgKiBAcGFyYW0gdG9rZW4gVGhlIHBheW1lbnQgdG9rZW4gcGF5b3V0cyBhcmUgbWFkZSBpbi4KICAgICAqIEBwYXJhbSBwYXlvdXQgUGFpZCBwZXIgcmVkZWVtZWQgdG9rZW4uCiAgICAgKiBAcGFyYW0gd2luZG93X2VuZCBMZWRnZXIgdGltZXN0YW1wIGF0IHdoaWNoIHRoZSB3aW5kb3cgY2xvc2VzLgogICAgICogQHBhcmFtIGZ1bmRpbmcgTW92ZWQgZnJvbSB0aGUgYWRtaW4gaW50byB0aGUgY29udHJhY3Qgbm93OyB0b3AgdXAgd2l0aCBmdW5kX3JldGlyZW1lbnQoKS4AAAAAAA9yZXRpcmVfcHJvcGVydHkAAAAABQAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABnBheW91dAAAAAAACwAAAAAAAAAKd2luZG93X2VuZAAAAAAABgAAAAAAAAAHZnVuZGluZwAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAQAAAEEqIEB0aXRsZSBEZXByZWNhdGlvbgogKiBAZGV2IERlcHJlY2F0aW9uIG5vdGljZSBmb3Igb25lIGVuZHBvaW50LgAAAAAAAAAAAAALRGVwcmVjYXRpb24AAAAAAgAAAB9FbmRwb2ludCB0byBtaWdyYXRlIHRvLCBpZiBhbnkuAAAAAAtyZXBsYWNlbWVudAAAAAPoAAAAEQAAADVGaXJzdCBsZWRnZXIgaW4gd2hpY2ggY2FsbHMgZmFpbCB3aXRoIEVuZHBvaW50U3Vuc2V0LgAAAAAAAA1zdW5zZXRfbGVkZ2VyAAAAAAAABA==",
+        "AAAABQAAAAAAAAAAAAAAEkVuZHBvaW50RGVwcmVjYXRlZAAAAAAAAQAAABNlbmRwb2ludF9kZXByZWNhdGVkAAAAAAMAAAAAAAAACGVuZHBvaW50AAAAEQAAAAEAAAAAAAAADXN1bnNldF9sZWRnZXIAAAAAAAAEAAAAAAAAAAAAAAALcmVwbGFjZW1lbnQAAAAD6AAAABEAAAAAAAAAAg==",
+        "AAAAAAAAADcqIEBkZXYgTGlzdHMgZXZlcnkgZGVwcmVjYXRlZCBlbmRwb2ludCB3aXRoIGl0cyBub3RpY2UuAAAAAAxkZXByZWNhdGlvbnMAAAAAAAAAAQAAA+wAAAARAAAH0AAAAAtEZXByZWNhdGlvbgA=",
+        "AAAAAAAAASoqIEBkZXYgTWFya3MgYW4gZW5kcG9pbnQgZGVwcmVjYXRlZCAob3IgdXBkYXRlcyBpdHMgbm90aWNlKS4gQWRtaW4gb25seS4KICAgICAqIEBwYXJhbSBlbmRwb2ludCBUaGUgZW5kcG9pbnQncyBuYW1lLCBlLmcuICJ0cmFuc2Zlcl90b19hbGlhcyIuCiAgICAgKiBAcGFyYW0gc3Vuc2V0X2xlZGdlciBUaGUgbGVkZ2VyIGZyb20gd2hpY2ggdGhlIGVuZHBvaW50IHN0b3BzIHdvcmtpbmcuCiAgICAgKiBAcGFyYW0gcmVwbGFjZW1lbnQgVGhlIGVuZHBvaW50IGludGVncmF0b3JzIHNob3VsZCBtaWdyYXRlIHRvLCBpZiBhbnkuAAAAAAASZGVwcmVjYXRlX2VuZHBvaW50AAAAAAADAAAAAAAAAAhlbmRwb2ludAAAABEAAAAAAAAADXN1bnNldF9sZWRnZXIAAAAAAAAEAAAAAAAAAAtyZXBsYWNlbWVudAAAAAPoAAAAEQAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAADIqIEBkZXYgV2l0aGRyYXdzIGEgZGVwcmVjYXRpb24gbm90aWNlLiBBZG1pbiBvbmx5LgAAAAAAFHVuZGVwcmVjYXRlX2VuZHBvaW50AAAAAQAAAAAAAAAIZW5kcG9pbnQAAAARAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAQAAAE4qIEB0aXRsZSBUb2tlblBhZ2UKICogQGRldiBPbmUgcGFnZSBvZiBleGlzdGluZyB0b2tlbiBJRHMsIGluIGFzY2VuZGluZyBvcmRlci4AAAAAAAAAAAAJVG9rZW5QYWdlAAAAAAAAAgAAAEpQYXNzIGFzIGBzdGFydF9hZnRlcmAgdG8gZ2V0IHRoZSBuZXh0IHBhZ2U7IE5vbmUgb25jZSBhbGwgSURzIGFyZSBjb3ZlcmVkLgAAAAAAEG5leHRfc3RhcnRfYWZ0ZXIAAAPoAAAABgAAAAAAAAAJdG9rZW5faWRzAAAAAAAD6gAAAAY=",
+        "AAAAAAAAAVkqIEBkZXYgTGlzdHMgZXhpc3RpbmcgdG9rZW4gSURzIGFib3ZlIGBzdGFydF9hZnRlcmAsIHNraXBwaW5nIGJ1cm5lZCBvbmVzLgogICAgICogQXQgbW9zdCBgbGltaXRgIChjYXBwZWQgYXQgTUFYX1BBR0UpIElEcyBhcmUgZXhhbWluZWQgcGVyIGNhbGwsIHNvIGEgcGFnZQogICAgICogY2FuIGhvbGQgZmV3ZXIgSURzIHRoYW4gcmVxdWVzdGVkIGV2ZW4gd2hlbiBtb3JlIGZvbGxvdy4KICAgICAqIEBwYXJhbSBzdGFydF9hZnRlciAwIGZvciB0aGUgZmlyc3QgcGFnZSwgdGhlbiB0aGUgcHJldmlvdXMgcGFnZSdzIG5leHRfc3RhcnRfYWZ0ZXIuCiAgICAgKiBAcGFyYW0gbGltaXQgVGhlIHBhZ2Ugc2l6ZS4AAAAAAAAGdG9rZW5zAAAAAAACAAAAAAAAAAtzdGFydF9hZnRlcgAAAAAGAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAH0AAAAAlUb2tlblBhZ2UAAAA=",
+        "AAAAAAAAADcqIEBkZXYgUmV0dXJucyBob3cgbWFueSB0b2tlbnMgYG93bmVyYCBjdXJyZW50bHkgaG9sZHMuAAAAAApiYWxhbmNlX29mAAAAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAABA==",
+        "AAAAAAAAADsqIEBkZXYgUmV0dXJucyB0aGUgbnVtYmVyIG9mIHRva2VucyBjdXJyZW50bHkgaW4gZXhpc3RlbmNlLgAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAG",
+        "AAAAAAAAAHoqIEBkZXYgTGlzdHMgdGhlIElEcyBvZiB0aGUgdG9rZW5zIGBvd25lcmAgY3VycmVudGx5IGhvbGRzLgogICAgICogQHJldHVybiBUb2tlbiBJRHMgaW4gdGhlIG9yZGVyIHRoZSBvd25lciByZWNlaXZlZCB0aGVtLgAAAAAAD3Rva2Vuc19vZl9vd25lcgAAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAD6gAAAAY=",
+        "AAAABQAAAGEqIEB0aXRsZSBNYWludGVuYW5jZVBhaWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIHRva2VuJ3MgbWFpbnRlbmFuY2UgZmVlIGlzIHBhaWQgdGhyb3VnaCBhIHllYXIuAAAAAAAAAAAAAA9NYWludGVuYW5jZVBhaWQAAAAAAQAAABBtYWludGVuYW5jZV9wYWlkAAAABQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAABpUaGUgeWVhciBub3cgcGFpZCB0aHJvdWdoLgAAAAAABHllYXIAAAAEAAAAAAAAACNIb3cgbWFueSB5ZWFycyB0aGUgcGF5bWVudCBzZXR0bGVkLgAAAAAFeWVhcnMAAAAAAAAEAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAAAQAAAGYqIEB0aXRsZSBNYWludGVuYW5jZVN0YXR1cwogKiBAZGV2IFdoZXRoZXIgYSB0b2tlbidzIG1haW50ZW5hbmNlIGZlZSBpcyBwYWlkIHRocm91Z2ggdGhlIGN1cnJlbnQgeWVhci4AAAAAAAAAAAARTWFpbnRlbmFuY2VTdGF0dXMAAAAAAAAFAAAAJlVucGFpZCB3aGlsZSBhIHBvc2l0aXZlIGZlZSBpcyBiaWxsZWQuAAAAAAAKZGVsaW5xdWVudAAAAAAAAQAAAAAAAAAEcGFpZAAAAAEAAAAXVGhlIGxhc3QgeWVhciBwYWlkIGZvci4AAAAADHBhaWRfdGhyb3VnaAAAAAQAAAAwWWVhcnMgb3dlZCwgdXAgdG8gYW5kIGluY2x1ZGluZyB0aGUgY3VycmVudCBvbmUuAAAADHVucGFpZF95ZWFycwAAAAQAAAAaVGhlIGN1cnJlbnQgY2FsZW5kYXIgeWVhci4AAAAAAAR5ZWFyAAAABA==",
+        "AAAAAgAAAL0qIEB0aXRsZSBNYWludGVuYW5jZUJpbGxpbmcKICogQGRldiBXaGV0aGVyIGEgbWFpbnRlbmFuY2Ugc2NoZWR1bGUgaXMgc2V0LCBhcyByZXBvcnRlZCBieSBjb25maWcoKS4gKEEgcGxhaW4KICogT3B0aW9uPE1haW50ZW5hbmNlU2NoZWR1bGU+IGZpZWxkIGNhbid0IGJlIGNvbnZlcnRlZCB0byBhbiBTY1ZhbCBieSB0aGUgU0RLLikAAAAAAAAAAAAAEk1haW50ZW5hbmNlQmlsbGluZwAAAAAAAgAAAAAAAAAAAAAACERpc2FibGVkAAAAAQAAAAAAAAAHRW5hYmxlZAAAAAABAAAH0AAAABNNYWludGVuYW5jZVNjaGVkdWxlAA==",
+        "AAAAAQAAAFEqIEB0aXRsZSBNYWludGVuYW5jZVNjaGVkdWxlCiAqIEBkZXYgVGhlIGFubnVhbCBtYWludGVuYW5jZSBmZWUgZXZlcnkgdG9rZW4gb3dlcy4AAAAAAAAAAAAAE01haW50ZW5hbmNlU2NoZWR1bGUAAAAAAwAAABJSZWNlaXZlcyB0aGUgZmVlcy4AAAAAAAljb2xsZWN0b3IAAAAAAAATAAAALFRoZSBmZWUgcGVyIHRva2VuIGFuZCB5ZWFyLiAwIGJpbGxzIG5vdGhpbmcuAAAAA2ZlZQAAAAALAAAAG1Rva2VuIHRoZSBmZWUgaXMgYmlsbGVkIGluLgAAAAAFdG9rZW4AAAAAAAAT",
+        "AAAAAAAAAeUqIEBkZXYgUGF5cyBhIHRva2VuJ3MgbWFpbnRlbmFuY2UgZmVlIGZvciBldmVyeSB1bnBhaWQgeWVhciB0aHJvdWdoIHRoZQogICAgICogY3VycmVudCBvbmUsIHBsdXMgdGhlIGxhdGUgZmVlcyB0aGV5IGFjY3J1ZWQuIEEgdG9rZW4gYWxyZWFkeSBwYWlkCiAgICAgKiB0aHJvdWdoIHRoaXMgeWVhciBpcyBjaGFyZ2VkIG5vdGhpbmcuIEZhaWxzIHdpdGggSW52YWxpZEFtb3VudCB1bmxlc3MKICAgICAqIHRoZSBzY2hlZHVsZSBiaWxscyBmZWVzIGluIGB0b2tlbl9hZGRyZXNzYCwgb3IgaWYgdGhlIHRvdGFsIG92ZXJmbG93cy4KICAgICAqIEBwYXJhbSBvd25lciBUaGUgY3VycmVudCBvd25lciAobXVzdCBzaWduIGFuZCBwYXkpLgogICAgICogQHBhcmFtIHRva2VuX2FkZHJlc3MgVGhlIHRva2VuIHRvIHBheSBpbjsgbXVzdCBiZSB0aGUgc2NoZWR1bGUncy4KICAgICAqIEByZXR1cm4gVGhlIHRva2VuJ3Mgc3RhdHVzIGFmdGVyIHRoZSBwYXltZW50LgAAAAAAAA9wYXlfbWFpbnRlbmFuY2UAAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAADXRva2VuX2FkZHJlc3MAAAAAAAATAAAAAQAAA+kAAAfQAAAAEU1haW50ZW5hbmNlU3RhdHVzAAAAAAAAAw==",
+        "AAAAAAAAAOQqIEBkZXYgTGlzdHMgZGVsaW5xdWVudCB0b2tlbiBJRHMgYWJvdmUgYHN0YXJ0X2FmdGVyYCwgZXhhbWluaW5nIGF0IG1vc3QKICAgICAqIGBsaW1pdGAgKGNhcHBlZCBhdCBNQVhfUEFHRSkgSURzIHBlciBjYWxsLCBsaWtlIHRva2VucygpLgogICAgICogQHBhcmFtIHN0YXJ0X2FmdGVyIDAgZm9yIHRoZSBmaXJzdCBwYWdlLCB0aGVuIHRoZSBwcmV2aW91cyBwYWdlJ3MgbmV4dF9zdGFydF9hZnRlci4AAAARZGVsaW5xdWVudF90b2tlbnMAAAAAAAACAAAAAAAAAAtzdGFydF9hZnRlcgAAAAAGAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAH0AAAAAlUb2tlblBhZ2UAAAA=",
+        "AAAAAAAAAHYqIEBkZXYgUmV0dXJucyB3aGV0aGVyIGEgdG9rZW4ncyBtYWludGVuYW5jZSBmZWUgaXMgcGFpZCB0aHJvdWdoIHRoZQogICAgICogY3VycmVudCB5ZWFyLCBhbmQgaG93IG1hbnkgeWVhcnMgYXJlIG93ZWQuAAAAAAASbWFpbnRlbmFuY2Vfc3RhdHVzAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6QAAB9AAAAARTWFpbnRlbmFuY2VTdGF0dXMAAAAAAAAD",
+        "AAAAAAAAAEYqIEBkZXYgUmV0dXJucyB0aGUgbWFpbnRlbmFuY2UgZmVlIHNjaGVkdWxlLCBpZiB0aGUgYWRtaW4gaGFzIHNldCBvbmUuAAAAAAAUbWFpbnRlbmFuY2Vfc2NoZWR1bGUAAAAAAAAAAQAAA+gAAAfQAAAAE01haW50ZW5hbmNlU2NoZWR1bGUA",
+        "AAAAAAAAARQqIEBkZXYgU2V0cyB0aGUgbWFpbnRlbmFuY2UgZmVlIHNjaGVkdWxlLiBBZG1pbiBvbmx5LiBTdGFydGluZyB0byBiaWxsIGEKICAgICAqIHBvc2l0aXZlIGZlZSBiaWxscyBmcm9tIHRoZSBjdXJyZW50IHllYXIgb247IGVhcmxpZXIgeWVhcnMgYXJlbid0IG93ZWQuCiAgICAgKiBGYWlscyB3aXRoIEZlZUluZGV4ZWQgZm9yIGEgY2hhbmdlIG9mIGZlZSB3aGlsZSBpdCBmb2xsb3dzIGFuIGluZGV4LgogICAgICogQHBhcmFtIHNjaGVkdWxlIGZlZSBtdXN0IG5vdCBiZSBuZWdhdGl2ZS4AAAAYc2V0X21haW50ZW5hbmNlX3NjaGVkdWxlAAAAAQAAAAAAAAAIc2NoZWR1bGUAAAfQAAAAE01haW50ZW5hbmNlU2NoZWR1bGUAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAABQAAAF0qIEB0aXRsZSBUYXhXaXRoaGVsZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgcmVzYWxlIHdpdGhob2xkcyBwYXJ0IG9mIHRoZSBzZWxsZXIncyBwcm9jZWVkcy4AAAAAAAAAAAAAC1RheFdpdGhoZWxkAAAAAAEAAAAMdGF4X3dpdGhoZWxkAAAABQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAQAAAAAAAAAManVyaXNkaWN0aW9uAAAAEQAAAAAAAAAAAAAACXJlY2lwaWVudAAAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAHAqIEB0aXRsZSBXaXRoaG9sZGluZ1NldAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgcHJvcGVydHkncyB3aXRoaG9sZGluZyBydWxlIGNoYW5nZXMgb3IgaXMgY2xlYXJlZAogKiAoYGJwc2AgMCkuAAAAAAAAAA5XaXRoaG9sZGluZ1NldAAAAAAAAQAAAA93aXRoaG9sZGluZ19zZXQAAAAAAwAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAAAAAAAAManVyaXNkaWN0aW9uAAAAEQAAAAAAAAAAAAAAA2JwcwAAAAAEAAAAAAAAAAI=",
+        "AAAAAQAAAFQqIEB0aXRsZSBXaXRoaG9sZGluZ1J1bGUKICogQGRldiBXaGF0IGEgcHJvcGVydHkncyByZXNhbGVzIHdpdGhob2xkIGZyb20gdGhlIHNlbGxlci4AAAAAAAAAD1dpdGhob2xkaW5nUnVsZQAAAAADAAAANlRoZSBzaGFyZSB3aXRoaGVsZCwgaW4gYmFzaXMgcG9pbnRzIG9mIHRoZSBzYWxlIHByaWNlLgAAAAAAA2JwcwAAAAAEAAAAPVRoZSBqdXJpc2RpY3Rpb24gd2hvc2UgcnVsZXMgYXBwbHksIGUuZy4gYEVTYCBvciBgVVNfRklSUFRBYC4AAAAAAAAManVyaXNkaWN0aW9uAAAAEQAAABpSZWNlaXZlcyB3aGF0IGlzIHdpdGhoZWxkLgAAAAAACXJlY2lwaWVudAAAAAAAABM=",
+        "AAAABQAAAF8qIEB0aXRsZSBFeGVtcHRpb25SZXZva2VkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBzZWxsZXIncyBleGVtcHRpb24gY2VydGlmaWNhdGUgaXMgd2l0aGRyYXduLgAAAAAAAAAAEEV4ZW1wdGlvblJldm9rZWQAAAABAAAAEWV4ZW1wdGlvbl9yZXZva2VkAAAAAAAAAgAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAQAAAAAAAAAManVyaXNkaWN0aW9uAAAAEQAAAAEAAAAC",
+        "AAAABQAAAGMqIEB0aXRsZSBFeGVtcHRpb25SZWdpc3RlcmVkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBzZWxsZXIncyBleGVtcHRpb24gY2VydGlmaWNhdGUgaXMgcmVnaXN0ZXJlZC4AAAAAAAAAABNFeGVtcHRpb25SZWdpc3RlcmVkAAAAAAEAAAAUZXhlbXB0aW9uX3JlZ2lzdGVyZWQAAAADAAAAAAAAAAZzZWxsZXIAAAAAABMAAAABAAAAAAAAAAxqdXJpc2RpY3Rpb24AAAARAAAAAQAAAAAAAAALdmFsaWRfdW50aWwAAAAABgAAAAAAAAAC",
+        "AAAAAAAAAG0qIEBkZXYgUmV0dXJucyBob3cgbXVjaCB3YXMgd2l0aGhlbGQgZnJvbSBhIHNlbGxlcidzIHJlc2FsZXMgaW4gYSBjYWxlbmRhcgogICAgICogeWVhciwgaW4gb25lIHBheW1lbnQgdG9rZW4uAAAAAAAACHdpdGhoZWxkAAAAAwAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAR5ZWFyAAAABAAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAGEqIEBkZXYgUmV0dXJucyB3aGV0aGVyIGEgc2VsbGVyIGhvbGRzIGEgdmFsaWQgZXhlbXB0aW9uIGZvciBhIGp1cmlzZGljdGlvbgogICAgICogaW4gdGhpcyBsZWRnZXIuAAAAAAAACWlzX2V4ZW1wdAAAAAAAAAIAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAManVyaXNkaWN0aW9uAAAAEQAAAAEAAAAB",
+        "AAAAAAAAAEQqIEBkZXYgUmV0dXJucyB0aGUgd2l0aGhvbGRpbmcgcnVsZSBvZiBhIHByb3BlcnR5J3MgcmVzYWxlcywgaWYgYW55LgAAAAt3aXRoaG9sZGluZwAAAAABAAAAAAAAAAhob3RlbF9pZAAAAAQAAAABAAAD6AAAB9AAAAAPV2l0aGhvbGRpbmdSdWxlAA==",
+        "AAAAAAAAAFgqIEBkZXYgUmV0dXJucyB0aGUgd2l0aGhvbGRpbmcgcnVsZXMgb2YgZXZlcnkgcHJvcGVydHkgdGhhdCBoYXMgb25lLCBieQogICAgICogaG90ZWwgSUQuAAAADHdpdGhob2xkaW5ncwAAAAAAAAABAAAD7AAAAAQAAAfQAAAAD1dpdGhob2xkaW5nUnVsZQA=",
+        "AAAAAAAAAPMqIEBkZXYgU2V0cyB0aGUgd2l0aGhvbGRpbmcgcnVsZSBvZiBhIHByb3BlcnR5J3MgcmVzYWxlcy4gQ29tcGxpYW5jZSByb2xlCiAgICAgKiAob3IgYWRtaW4pLgogICAgICogQHBhcmFtIGNhbGxlciBUaGUgY29tcGxpYW5jZSBvZmZpY2VyIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIGJwcyBUaGUgc2hhcmUgd2l0aGhlbGQsIGF0IG1vc3QgRlVMTF9XSVRISE9MRElOR19CUFM7IDAgY2xlYXJzCiAgICAgKiB0aGUgcnVsZS4AAAAAD3NldF93aXRoaG9sZGluZwAAAAAFAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAAManVyaXNkaWN0aW9uAAAAEQAAAAAAAAADYnBzAAAAAAQAAAAAAAAACXJlY2lwaWVudAAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAI4qIEBkZXYgV2l0aGRyYXdzIGEgc2VsbGVyJ3MgZXhlbXB0aW9uIGNlcnRpZmljYXRlLiBDb21wbGlhbmNlIHJvbGUgKG9yCiAgICAgKiBhZG1pbikuCiAgICAgKiBAcGFyYW0gY2FsbGVyIFRoZSBjb21wbGlhbmNlIG9mZmljZXIgKG11c3Qgc2lnbikuAAAAAAAQcmV2b2tlX2V4ZW1wdGlvbgAAAAMAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAxqdXJpc2RpY3Rpb24AAAARAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAARUqIEBkZXYgUmVnaXN0ZXJzIGEgc2VsbGVyJ3MgZXhlbXB0aW9uIGNlcnRpZmljYXRlIGZvciBhIGp1cmlzZGljdGlvbi4KICAgICAqIENvbXBsaWFuY2Ugcm9sZSAob3IgYWRtaW4pLiBSZWdpc3RlcmluZyBhZ2FpbiByZXBsYWNlcyB0aGUgZXhwaXJ5LgogICAgICogQHBhcmFtIGNhbGxlciBUaGUgY29tcGxpYW5jZSBvZmZpY2VyIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIHZhbGlkX3VudGlsIExlZGdlciB0aW1lc3RhbXAgdGhlIGNlcnRpZmljYXRlIGlzIHZhbGlkIHRocm91Z2guAAAAAAAAEnJlZ2lzdGVyX2V4ZW1wdGlvbgAAAAAABAAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAADGp1cmlzZGljdGlvbgAAABEAAAAAAAAAC3ZhbGlkX3VudGlsAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAHEqIEBkZXYgTGlzdHMgdGhlIGludGVyZmFjZXMgdGhpcyBkZXBsb3ltZW50IGltcGxlbWVudHMuCiAgICAgKiBAcmV0dXJuIE9uZSBTeW1ib2wgcGVyIGludGVyZmFjZSwgZS5nLiAibmZ0X2NvcmUiLgAAAAAAAAxjYXBhYmlsaXRpZXMAAAAAAAAAAQAAA+oAAAAR",
+        "AAAAAgAAAC4qIEB0aXRsZSBMYWJlbENvZGUKICogQGRldiBXaGF0IGEgbGFiZWwgbmFtZXMuAAAAAAAAAAAACUxhYmVsQ29kZQAAAAAAAAMAAAABAAAAAAAAAARUaWVyAAAAAQAAABEAAAABAAAAGkFuIEVycm9yIG9yIEV4dEVycm9yIGNvZGUuAAAAAAAJRXJyb3JDb2RlAAAAAAAAAQAAAAQAAAABAAAAQEEgY29kZSBmcm9udGVuZHMgYWdyZWUgb24sIGUuZy4gYHNlYXNvbl9oaWdoYCBvciBgYW1lbml0eV9wb29sYC4AAAAGQ3VzdG9tAAAAAAABAAAAEQ==",
+        "AAAABQAAAE4qIEB0aXRsZSBMYWJlbHNTZXQKICogQGRldiBQdWJsaXNoZWQgd2hlbiB0aGUgYWRtaW4gY2hhbmdlcyBhIGxvY2FsZSdzIGxhYmVscy4AAAAAAAAAAAAJTGFiZWxzU2V0AAAAAAAAAQAAAApsYWJlbHNfc2V0AAAAAAACAAAAAAAAAAZsb2NhbGUAAAAAABEAAAABAAAAI0hvdyBtYW55IGxhYmVscyB0aGUgbG9jYWxlIGhhcyBub3cuAAAAAAVjb3VudAAAAAAAAAQAAAAAAAAAAg==",
+        "AAAAAAAAAD4qIEBkZXYgUmV0dXJucyB0aGUgbGFiZWwgb2YgYSBjb2RlIGluIGEgbG9jYWxlLCBpZiBpdCBoYXMgb25lLgAAAAAABWxhYmVsAAAAAAAAAgAAAAAAAAAGbG9jYWxlAAAAAAARAAAAAAAAAARjb2RlAAAH0AAAAAlMYWJlbENvZGUAAAAAAAABAAAD6AAAABA=",
+        "AAAAAAAAACcqIEBkZXYgUmV0dXJucyBldmVyeSBsYWJlbCBvZiBhIGxvY2FsZS4AAAAABmxhYmVscwAAAAAAAQAAAAAAAAAGbG9jYWxlAAAAAAARAAAAAQAAA+wAAAfQAAAACUxhYmVsQ29kZQAAAAAAABA=",
+        "AAAAAAAAAEgqIEBkZXYgTGlzdHMgdGhlIGxvY2FsZXMgdGhhdCBoYXZlIGxhYmVscywgaW4gdGhlIG9yZGVyIHRoZXkgd2VyZSBhZGRlZC4AAAAHbG9jYWxlcwAAAAAAAAAAAQAAA+oAAAAR",
+        "AAAAAAAAAQIqIEBkZXYgU2V0cyBsYWJlbHMgb2YgYSBsb2NhbGUsIGtlZXBpbmcgaXRzIG90aGVyIGxhYmVscy4gQW4gZW1wdHkgbGFiZWwKICAgICAqIHJlbW92ZXMgdGhlIGNvZGUncyBsYWJlbC4gQWRtaW4gb25seS4gRmFpbHMgd2l0aCBMYWJlbFRvb0xvbmcgZm9yIGEKICAgICAqIGxhYmVsIG92ZXIgTUFYX0xBQkVMX0xFTiBieXRlcywgYW5kIFRvb01hbnlMYWJlbHMgaWYgdGhlIGxvY2FsZSB3b3VsZAogICAgICogaGF2ZSBtb3JlIHRoYW4gTUFYX0xBQkVMUy4AAAAAAApzZXRfbGFiZWxzAAAAAAACAAAAAAAAAAZsb2NhbGUAAAAAABEAAAAAAAAABmxhYmVscwAAAAAD7AAAB9AAAAAJTGFiZWxDb2RlAAAAAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAABQAAAFMqIEB0aXRsZSBSZWxpZWZGdW5kZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIHByb3BlcnR5J3MgcmVsaWVmIGZ1bmQgaXMgdG9wcGVkIHVwLgAAAAAAAAAADFJlbGllZkZ1bmRlZAAAAAEAAAANcmVsaWVmX2Z1bmRlZAAAAAAAAAUAAAAAAAAACGhvdGVsX2lkAAAABAAAAAEAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAEAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAABxUaGUgZnVuZCdzIGJhbGFuY2UgYWZ0ZXIgaXQuAAAAB2JhbGFuY2UAAAAACwAAAAAAAAAC",
+        "AAAABQAAAGIqIEB0aXRsZSBSZWxpZWZXaXRoZHJhd24KICogQGRldiBQdWJsaXNoZWQgd2hlbiB0aGUgYWRtaW4gd2l0aGRyYXdzIGZyb20gYSBwcm9wZXJ0eSdzIHJlbGllZiBmdW5kLgAAAAAAAAAAAA9SZWxpZWZXaXRoZHJhd24AAAAAAQAAABByZWxpZWZfd2l0aGRyYXduAAAABQAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAcVGhlIGZ1bmQncyBiYWxhbmNlIGFmdGVyIGl0LgAAAAdiYWxhbmNlAAAAAAsAAAAAAAAAAg==",
+        "AAAAAQAAAFcqIEB0aXRsZSBGb3JjZU1hamV1cmVGbGFnCiAqIEBkZXYgQW4gb3JhY2xlJ3MgZmxhZyBjbG9zaW5nIGEgcHJvcGVydHkncyB3ZWVrIG9mIGEgeWVhci4AAAAAAAAAABBGb3JjZU1hamV1cmVGbGFnAAAAAwAAACJMZWRnZXIgdGltZXN0YW1wIGl0IHdhcyByYWlzZWQgYXQuAAAAAAAKZmxhZ2dlZF9hdAAAAAAABgAAABpUaGUgb3JhY2xlIHRoYXQgcmFpc2VkIGl0LgAAAAAABm9yYWNsZQAAAAAAEwAAABZXaHksIGUuZy4gYGh1cnJpY2FuZWAuAAAAAAAGcmVhc29uAAAAAAAR",
+        "AAAABQAAAH0qIEB0aXRsZSBGb3JjZU1hamV1cmVMaWZ0ZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhbiBvcmFjbGUgbGlmdHMgYSBmbGFnIHJhaXNlZCBpbiBlcnJvci4gU2V0dGxlbWVudHMKICogYWxyZWFkeSBtYWRlIHN0YW5kLgAAAAAAAAAAAAASRm9yY2VNYWpldXJlTGlmdGVkAAAAAAABAAAAFGZvcmNlX21hamV1cmVfbGlmdGVkAAAABAAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAAAAAAAAEeWVhcgAAAAQAAAAAAAAAAAAAAAR3ZWVrAAAABAAAAAAAAAAAAAAABm9yYWNsZQAAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAFcqIEB0aXRsZSBGb3JjZU1hamV1cmVGbGFnZ2VkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYW4gb3JhY2xlIGNsb3NlcyBhIHByb3BlcnR5J3Mgd2Vlay4AAAAAAAAAABNGb3JjZU1hamV1cmVGbGFnZ2VkAAAAAAEAAAAVZm9yY2VfbWFqZXVyZV9mbGFnZ2VkAAAAAAAABQAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAAAAAAAAEeWVhcgAAAAQAAAAAAAAAAAAAAAR3ZWVrAAAABAAAAAAAAAAAAAAABm9yYWNsZQAAAAAAEwAAAAAAAAAAAAAABnJlYXNvbgAAAAAAEQAAAAAAAAAC",
+        "AAAABQAAAFgqIEB0aXRsZSBGb3JjZU1hamV1cmVTZXR0bGVkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSB0b2tlbiBvZiBhIGNsb3NlZCB3ZWVrIGlzIHNldHRsZWQuAAAAAAAAABNGb3JjZU1hamV1cmVTZXR0bGVkAAAAAAEAAAAVZm9yY2VfbWFqZXVyZV9zZXR0bGVkAAAAAAAABwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAAAAAAAAEeWVhcgAAAAQAAAAAAAAAAAAAAAR3ZWVrAAAABAAAAAAAAAAcQ3JlZGl0ZWQgYSByZXBsYWNlbWVudCBzdGF5LgAAAAVvd25lcgAAAAAAABMAAAAAAAAAMFRoZSByZW50ZXIgcmVmdW5kZWQsIGlmIHRoZSB3ZWVrIHdhcyByZW50ZWQgb3V0LgAAAAZyZW50ZXIAAAAAA+gAAAATAAAAAAAAAERUaGUgcmVudCByZWZ1bmRlZCBmcm9tIHRoZSByZWxpZWYgZnVuZDsgMCBpZiB0aGUgd2VlayB3YXNuJ3QgcmVudGVkLgAAAAhyZWZ1bmRlZAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAG8qIEB0aXRsZSBSZXBsYWNlbWVudENyZWRpdFJlZGVlbWVkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gdGhlIGZyb250IGRlc2sgcmVkZWVtcyBhbiBvd25lcidzIHJlcGxhY2VtZW50IGNyZWRpdC4AAAAAAAAAABlSZXBsYWNlbWVudENyZWRpdFJlZGVlbWVkAAAAAAAAAQAAABtyZXBsYWNlbWVudF9jcmVkaXRfcmVkZWVtZWQAAAAABAAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAAAAAACYnkAAAAAABMAAAAAAAAAK0NyZWRpdHMgdGhlIG93bmVyIGhhcyBsZWZ0IGF0IHRoZSBwcm9wZXJ0eS4AAAAACXJlbWFpbmluZwAAAAAAAAQAAAAAAAAAAg==",
+        "AAAAAAAAAN8qIEBkZXYgTW92ZXMgYGFtb3VudGAgZnJvbSB0aGUgZnVuZGVyIGludG8gYSBwcm9wZXJ0eSdzIHJlbGllZiBmdW5kIGluIGEKICAgICAqIHBheW1lbnQgdG9rZW4uIEFueW9uZSBtYXkgZnVuZCBpdC4KICAgICAqIEBwYXJhbSBmdW5kZXIgV2hvIHBheXMgKG11c3Qgc2lnbiksIGUuZy4gdGhlIGhvdGVsIG9yIGl0cyBpbnN1cmVyLgogICAgICogQHJldHVybiBUaGUgZnVuZCdzIGJhbGFuY2UuAAAAAAtmdW5kX3JlbGllZgAAAAAEAAAAAAAAAAZmdW5kZXIAAAAAABMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAEMqIEBkZXYgUmV0dXJucyBhIHByb3BlcnR5J3MgcmVsaWVmIGZ1bmQgYmFsYW5jZSBpbiBhIHBheW1lbnQgdG9rZW4uAAAAAAtyZWxpZWZfZnVuZAAAAAACAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAEQqIEBkZXYgUmV0dXJucyB0aGUgZmxhZyBjbG9zaW5nIGEgcHJvcGVydHkncyB3ZWVrIG9mIGEgeWVhciwgaWYgYW55LgAAAA1mb3JjZV9tYWpldXJlAAAAAAAAAwAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAAAAAAR5ZWFyAAAABAAAAAAAAAAEd2VlawAAAAQAAAABAAAD6AAAB9AAAAAQRm9yY2VNYWpldXJlRmxhZw==",
+        "AAAAAAAAAMEqIEBkZXYgV2l0aGRyYXdzIGBhbW91bnRgIGZyb20gYSBwcm9wZXJ0eSdzIHJlbGllZiBmdW5kLiBBZG1pbiBvbmx5LgogICAgICogV29ya3Mgd2hpbGUgcGF1c2VkLiBGYWlscyB3aXRoIFJlbGllZlVuZnVuZGVkIGZvciBtb3JlIHRoYW4gdGhlIGZ1bmQKICAgICAqIGhvbGRzLgogICAgICogQHJldHVybiBUaGUgZnVuZCdzIGJhbGFuY2UuAAAAAAAAD3dpdGhkcmF3X3JlbGllZgAAAAAEAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAAQ0qIEBkZXYgQ2xvc2VzIGEgcHJvcGVydHkncyB3ZWVrIG9mIGEgeWVhciBmb3IgZm9yY2UgbWFqZXVyZS4gT3JhY2xlIHJvbGUKICAgICAqIChvciBhZG1pbikuIEZhaWxzIHdpdGggSW52YWxpZFdlZWsgb3V0c2lkZSAxLi49NTIsIGFuZCBJbnZhbGlkQW1vdW50CiAgICAgKiBmb3IgYSB5ZWFyIGFscmVhZHkgb3Zlci4KICAgICAqIEBwYXJhbSBvcmFjbGUgVGhlIG9yYWNsZSAobXVzdCBzaWduKS4KICAgICAqIEBwYXJhbSByZWFzb24gV2h5LCBlLmcuIGBodXJyaWNhbmVgLgAAAAAAABJmbGFnX2ZvcmNlX21hamV1cmUAAAAAAAUAAAAAAAAABm9yYWNsZQAAAAAAEwAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAAAAAAR5ZWFyAAAABAAAAAAAAAAEd2VlawAAAAQAAAAAAAAABnJlYXNvbgAAAAAAEQAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAOYqIEBkZXYgTGlmdHMgYSBmbGFnIHJhaXNlZCBpbiBlcnJvciwgcmVvcGVuaW5nIHRoZSB3ZWVrOyB0b2tlbnMgYWxyZWFkeQogICAgICogc2V0dGxlZCBzdGF5IHNldHRsZWQuIE9yYWNsZSByb2xlIChvciBhZG1pbikuIEZhaWxzIHdpdGgKICAgICAqIE5vdEZvcmNlTWFqZXVyZSBpZiB0aGUgd2VlayBpc24ndCBmbGFnZ2VkLgogICAgICogQHBhcmFtIG9yYWNsZSBUaGUgb3JhY2xlIChtdXN0IHNpZ24pLgAAAAAAEmxpZnRfZm9yY2VfbWFqZXVyZQAAAAAABAAAAAAAAAAGb3JhY2xlAAAAAAATAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAABHllYXIAAAAEAAAAAAAAAAR3ZWVrAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEMqIEBkZXYgUmV0dXJucyBob3cgbWFueSByZXBsYWNlbWVudCBzdGF5cyBhIHByb3BlcnR5IG93ZXMgYW4gb3duZXIuAAAAABNyZXBsYWNlbWVudF9jcmVkaXRzAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAAAQ=",
+        "AAAAAAAAAdUqIEBkZXYgU2V0dGxlcyBhIHRva2VuIG9mIGEgd2VlayBjbG9zZWQgdGhpcyB5ZWFyOiByZWZ1bmRzIGl0cyByZW50ZXIsCiAgICAgKiBpZiBhbnksIHRoZSBmdWxsIHJlbnQgZnJvbSB0aGUgcmVsaWVmIGZ1bmQsIGFuZCBjcmVkaXRzIGl0cyBvd25lciBhCiAgICAgKiByZXBsYWNlbWVudCBzdGF5LiBBbnlvbmUgbWF5IGNhbGwgaXQsIG9uY2UgcGVyIHRva2VuIGFuZCB5ZWFyLiBGYWlscwogICAgICogd2l0aCBOb3RGb3JjZU1hamV1cmUgdW5sZXNzIHRoZSB0b2tlbidzIHdlZWsgaXMgZmxhZ2dlZCB0aGlzIHllYXIsCiAgICAgKiBJbnZhbGlkVXNhZ2VTdGF0ZSBvbmNlIHRoZSB3ZWVrIGhhcyBiZWd1biwgQWxyZWFkeVNldHRsZWQgaWYgdGhlCiAgICAgKiB0b2tlbiBpcyBhbHJlYWR5IHNldHRsZWQsIGFuZCBSZWxpZWZVbmZ1bmRlZCBpZiB0aGUgcmVsaWVmIGZ1bmQgY2FuJ3QKICAgICAqIGNvdmVyIHRoZSByZWZ1bmQuAAAAAAAAFHNldHRsZV9mb3JjZV9tYWpldXJlAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAFoqIEBkZXYgUmV0dXJucyB0aGUgbGFzdCB5ZWFyIGEgdG9rZW4gd2FzIHNldHRsZWQgZm9yIGZvcmNlIG1hamV1cmUsIGlmCiAgICAgKiBpdCBoYXMgYmVlbi4AAAAAABVmb3JjZV9tYWpldXJlX3NldHRsZWQAAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6AAAAAQ=",
+        "AAAAAAAAATcqIEBkZXYgUmVkZWVtcyBvbmUgb2YgYW4gb3duZXIncyByZXBsYWNlbWVudCBjcmVkaXRzIGF0IGEgcHJvcGVydHksIG9uY2UKICAgICAqIHRoZSBmcm9udCBkZXNrIGhhcyBib29rZWQgdGhlIHJlcGxhY2VtZW50IHN0YXkuIE1hbmFnZXIgcm9sZSAob3IKICAgICAqIGFkbWluKS4gRmFpbHMgd2l0aCBOb1JlcGxhY2VtZW50Q3JlZGl0IHdpdGhvdXQgb25lLgogICAgICogQHBhcmFtIGNhbGxlciBUaGUgZnJvbnQgZGVzayAobXVzdCBzaWduKS4KICAgICAqIEByZXR1cm4gQ3JlZGl0cyB0aGUgb3duZXIgaGFzIGxlZnQgYXQgdGhlIHByb3BlcnR5LgAAAAAZcmVkZWVtX3JlcGxhY2VtZW50X2NyZWRpdAAAAAAAAAMAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAhob3RlbF9pZAAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAABQAAAKkqIEB0aXRsZSBOb3RpZmljYXRpb25QcmVmc0NoYW5nZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbmV2ZXIgYW4gYWRkcmVzcyB1cGRhdGVzIGl0cyBwcmVmZXJlbmNlcy4KICogVG9waWNzOiAoIm5vdGlmaWNhdGlvbl9wcmVmc19jaGFuZ2VkIiwgb3duZXIpLiBEYXRhOiB0aGUgbmV3IGJpdG1hc2suAAAAAAAAAAAAABhOb3RpZmljYXRpb25QcmVmc0NoYW5nZWQAAAABAAAAGm5vdGlmaWNhdGlvbl9wcmVmc19jaGFuZ2VkAAAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAAAAAAAARtYXNrAAAABAAAAAAAAAAC",
+        "AAAAAAAAAH4qIEBkZXYgUmV0dXJucyB0aGUgbm90aWZpY2F0aW9uIHByZWZlcmVuY2VzIG9mIGBvd25lcmAuCiAgICAgKiBAcmV0dXJuIFRoZSBzdG9yZWQgYml0bWFzaywgb3IgMCBpZiB0aGUgYWRkcmVzcyBuZXZlciBvcHRlZCBpbi4AAAAAABZnZXRfbm90aWZpY2F0aW9uX3ByZWZzAAAAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAABA==",
+        "AAAAAAAAANQqIEBkZXYgUmVwbGFjZXMgdGhlIG5vdGlmaWNhdGlvbiBwcmVmZXJlbmNlcyBvZiBgb3duZXJgLgogICAgICogQHBhcmFtIG93bmVyIFRoZSBhZGRyZXNzIHdob3NlIHByZWZlcmVuY2VzIGFyZSB1cGRhdGVkIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIG1hc2sgQSBjb21iaW5hdGlvbiBvZiB0aGUgTk9USUZZXyogYml0cy4gMCBvcHRzIG91dCBvZiBldmVyeXRoaW5nLgAAABZzZXRfbm90aWZpY2F0aW9uX3ByZWZzAAAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAABG1hc2sAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAABAAAAV4qIEB0aXRsZSBFcnJvcgogKiBAZGV2IEV2ZXJ5IHdheSBhIGNhbGwgY2FuIGZhaWwuIEVuZHBvaW50cyByZXR1cm4gUmVzdWx0PFQsIEVycm9yPiBzbyB0aGF0CiAqIGNsaWVudCBTREtzIGNhbiB0ZWxsIGZhaWx1cmUgY2F1c2VzIGFwYXJ0IGJ5IGNvZGUgaW5zdGVhZCBvZiBwYXJzaW5nIHBhbmljcy4KICogQ29kZXMgYXJlIHBhcnQgb2YgdGhlIHB1YmxpYyBpbnRlcmZhY2U6IG5ldmVyIHJlbnVtYmVyLCBvbmx5IGFwcGVuZC4KICogVGhlIGNvbnRyYWN0IHNwZWMgY2FwcyBhbiBlcnJvciBlbnVtIGF0IDUwIGNhc2VzLCBhbmQgdGhpcyBvbmUgaXMgZnVsbDoKICogbmV3IGZhaWx1cmVzIGdvIGluIEV4dEVycm9yLgAAAAAAAAAAAAVFcnJvcgAAAAAAADIAAAAAAAAAEkFscmVhZHlJbml0aWFsaXplZAAAAAAAAQAAAAAAAAAOTm90SW5pdGlhbGl6ZWQAAAAAAAIAAAAAAAAADVRva2VuTm90Rm91bmQAAAAAAAAKAAAAAAAAAAhOb3RPd25lcgAAAAsAAAAAAAAAEEludmFsaWRSZWNpcGllbnQAAAAMAAAAAAAAABFOb1BlbmRpbmdUcmFuc2ZlcgAAAAAAABQAAAAAAAAAFlBlbmRpbmdUcmFuc2ZlckV4cGlyZWQAAAAAABUAAAAAAAAADkFscmVhZHlJblRydXN0AAAAAAAeAAAAAAAAAApOb3RJblRydXN0AAAAAAAfAAAAAAAAAApOb3RUcnVzdGVlAAAAAAAgAAAAAAAAAA5Ob3RCZW5lZmljaWFyeQAAAAAAIQAAAAAAAAAXVHJhbnNmZXJOb3RBY2tub3dsZWRnZWQAAAAAIgAAAAAAAAAUTm9QZW5kaW5nQmVuZWZpY2lhcnkAAAAjAAAAAAAAABJUaW1lbG9ja05vdEVsYXBzZWQAAAAAACQAAAAAAAAADFVua25vd25BbGlhcwAAACgAAAAAAAAAF1Vua25vd25Ob3RpZmljYXRpb25CaXRzAAAAADIAAAAAAAAAGURhaWx5Um9vdEFscmVhZHlDb21taXR0ZWQAAAAAAAA8AAAAAAAAAA5FbmRwb2ludFN1bnNldAAAAAAARgAAAAAAAAALTm90QXBwcm92ZWQAAAAAUAAAAAAAAAANQmF0Y2hUb29MYXJnZQAAAAAAAFoAAAAAAAAAEFRva2VuSWRDb2xsaXNpb24AAABkAAAAAAAAABVSZW50UG9vbE5vdENvbmZpZ3VyZWQAAAAAAABuAAAAAAAAABFJbnZhbGlkUmVudFBvbGljeQAAAAAAAG8AAAAAAAAADUludmFsaWRBbW91bnQAAAAAAABwAAAAAAAAABdJbnN1ZmZpY2llbnRSZW50QmFsYW5jZQAAAABxAAAAAAAAAApSZW50Tm90RHVlAAAAAAByAAAAAAAAAA9Qcm9wZXJ0eVJldGlyZWQAAAAAeAAAAAAAAAAOQWxyZWFkeVJldGlyZWQAAAAAAHkAAAAAAAAACk5vdFJldGlyZWQAAAAAAHoAAAAAAAAAFlJlZGVtcHRpb25XaW5kb3dDbG9zZWQAAAAAAHsAAAAAAAAAFFJlZGVtcHRpb25XaW5kb3dPcGVuAAAAfAAAAAAAAAAVUmVkZW1wdGlvblVuZGVyZnVuZGVkAAAAAAAAfQAAAAAAAAANV3JvbmdQcm9wZXJ0eQAAAAAAAH4AAAAAAAAADk5vUGVuZGluZ0FkbWluAAAAAACCAAAAAAAAAAtNaXNzaW5nUm9sZQAAAACMAAAAAAAAAA5Db250cmFjdFBhdXNlZAAAAAAAlgAAAAAAAAALSW52YWxpZFdlZWsAAAAAoAAAAAAAAAASRHVwbGljYXRlVGltZXNoYXJlAAAAAAChAAAAAAAAABBJbnZhbGlkTGVhc2VUZXJtAAAAogAAAAAAAAAMTGVhc2VFeHBpcmVkAAAAowAAAAAAAAANSG90ZWxOb3RGb3VuZAAAAAAAAKoAAAAAAAAADkR1cGxpY2F0ZUhvdGVsAAAAAACrAAAAAAAAAAxSb29tTm90Rm91bmQAAAC0AAAAAAAAAA1EdXBsaWNhdGVSb29tAAAAAAAAtQAAAAAAAAALUm9vbVJldGlyZWQAAAAAtgAAAAAAAAARSW52YWxpZFVzYWdlU3RhdGUAAAAAAAC+AAAAAAAAABNDaGVja0luTm90Q29uZmlybWVkAAAAAL8AAAAAAAAAC0ludmFsaWRTd2FwAAAAAMgAAAAAAAAACU5vdExpc3RlZAAAAAAAANIAAAAAAAAADUxpc3RpbmdDbG9zZWQAAAAAAADT",
+        "AAAAAQAAAGIqIEB0aXRsZSBUb2tlbgogKiBAZGV2IEEgdG9rZW4ncyBkZXRhaWxzIHRvZ2V0aGVyIHdpdGggaXRzIGN1cnJlbnQgb3duZXIsIHJldHVybmVkIGJ5IGdldF90b2tlbigpLgAAAAAAAAAAAAVUb2tlbgAAAAAAAAMAAAAAAAAABGluZm8AAAfQAAAADVRpbWVzaGFyZUluZm8AAAAAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAG",
+        "AAAAAgAAAKgqIEB0aXRsZSBFeHRLZXkKICogQGRldiBNb3JlIHN0b3JhZ2Uga2V5cywgaW4gdGhlIHNhbWUgcm9sZSBhcyBEYXRhS2V5LiBUaGUgY29udHJhY3Qgc3BlYyBjYXBzCiAqIGFuIGVudW0gYXQgNTAgY2FzZXMgYW5kIERhdGFLZXkgaXMgZnVsbCwgc28gbmV3ZXIga2V5cyBhcmUgYWRkZWQgaGVyZS4AAAAAAAAABkV4dEtleQAAAAAAMgAAAAAAAAAAAAAADVZvdWNoZXJTaWduZXIAAAAAAAABAAAAAAAAAAdWb3VjaGVyAAAAAAEAAAPuAAAAIAAAAAAAAAAAAAAACUFsbG93bGlzdAAAAAAAAAEAAAAAAAAADkFsbG93bGlzdENsYWltAAAAAAACAAAD7gAAACAAAAATAAAAAQAAAAAAAAAIVG9rZW5VcmkAAAABAAAABgAAAAEAAAAAAAAADk1ldGFkYXRhRnJvemVuAAAAAAABAAAABgAAAAEAAAAAAAAACU1pbnRQcmljZQAAAAAAAAEAAAAEAAAAAAAAAAAAAAAGUGF5ZWVzAAAAAAABAAAAAAAAAAdQYXlhYmxlAAAAAAIAAAATAAAAEwAAAAAAAAAAAAAACkNvb2xpbmdPZmYAAAAAAAEAAAAAAAAACFB1cmNoYXNlAAAAAQAAAAYAAAABAAAAAAAAAAlTdXBwbHlDYXAAAAAAAAABAAAABAAAAAEAAAAAAAAAC0hvdGVsU3VwcGx5AAAAAAEAAAAEAAAAAQAAAAAAAAAKU2hhcmVSb3VuZAAAAAAAAQAAAAYAAAABAAAAAAAAAAtNZXRyaWNzTGFuZQAAAAADAAAABgAAABEAAAAEAAAAAQAAAAAAAAAKWWVhckNsYWltcwAAAAAAAQAAA+4AAAAgAAAAAAAAAAAAAAAMQmlsbGluZ1NpbmNlAAAAAAAAAAAAAAAMQ2FwcGVkSG90ZWxzAAAAAAAAAAAAAAANU3VwcGx5UmVjb3VudAAAAAAAAAEAAAAAAAAADFJlc2VydmVTaGFyZQAAAAEAAAAEAAAAAQAAAAAAAAAHUmVzZXJ2ZQAAAAABAAAABAAAAAEAAAAAAAAADFJlc2VydmVTcGVuZAAAAAIAAAAEAAAABAAAAAAAAAAAAAAADVJlc2VydmVIb3RlbHMAAAAAAAABAAAAAAAAAAlEaXJlY3RvcnkAAAAAAAABAAAABAAAAAEAAAAAAAAADkRpcmVjdG9yeUVudHJ5AAAAAAACAAAABAAAABMAAAABAAAAAAAAAApDaGVja2luS2V5AAAAAAABAAAABgAAAAAAAAAAAAAAD1JlY2VpcHRzRW5hYmxlZAAAAAABAAAAAAAAAAdSZWNlaXB0AAAAAAIAAAAGAAAABAAAAAEAAAAAAAAADFJlY2VpcHRDb3VudAAAAAEAAAAGAAAAAQAAAAAAAAAMUGFydHlSZWNlaXB0AAAAAgAAABMAAAAEAAAAAQAAAAAAAAARUGFydHlSZWNlaXB0Q291bnQAAAAAAAABAAAAEwAAAAEAAAAAAAAAC1dpdGhob2xkaW5nAAAAAAEAAAAEAAAAAQAAAAAAAAARV2l0aGhvbGRpbmdFeGVtcHQAAAAAAAACAAAAEwAAABEAAAABAAAAAAAAAAhXaXRoaGVsZAAAAAMAAAATAAAABAAAABMAAAABAAAAAAAAAApNaW5vclVuaXRzAAAAAAABAAAABAAAAAEAAAAAAAAADVNlYWxlZEF1Y3Rpb24AAAAAAAABAAAABgAAAAEAAAAAAAAACVNlYWxlZEJpZAAAAAAAAAIAAAAGAAAAEwAAAAEAAAAAAAAACkhlbGRTaGFyZXMAAAAAAAEAAAATAAAAAQAAAAAAAAAJUHJpY2VNYXJrAAAAAAAAAQAAAAYAAAABAAAAAAAAAAlBcHByYWlzYWwAAAAAAAABAAAABgAAAAEAAAAAAAAABFRpZXIAAAABAAAABgAAAAEAAAAAAAAAD0NvbGxlY3Rpb25PZmZlcgAAAAABAAAABAAAAAAAAAAAAAAAFENvbGxlY3Rpb25PZmZlckNvdW50AAAAAQAAAAAAAAAJVGllckluZGV4AAAAAAAAAgAAAAQAAAARAAAAAQAAAAAAAAAJV2Vla0luZGV4AAAAAAAAAQAAAAQAAAABAAAAAAAAAAlTd2FwQ2hhaW4AAAAAAAABAAAABAAAAAAAAAAAAAAADlN3YXBDaGFpbkNvdW50AAAAAAABAAAAAAAAAAdDaGFpbk9mAAAAAAEAAAAGAAAAAQAAAAAAAAAKU3dhcEludGVudAAAAAAAAQAAAAYAAAAAAAAAAAAAAAtTd2FwSW50ZW50cwA=",
+        "AAAAAgAAAKgqIEB0aXRsZSBEYXRhS2V5CiAqIEBkZXYgV2UgdXNlIHRoaXMgZW51bSB0byBjcmVhdGUgb3JnYW5pemVkLCB1bmlxdWUga2V5cyBmb3Igb3VyIGNvbnRyYWN0J3Mgc3RvcmFnZS4KICogVGhpcyBpcyBhIGNvbW1vbiBwYXR0ZXJuIGluIFNvcm9iYW4gdG8gYXZvaWQgImtleSBjb2xsaXNpb25zIi4AAAAAAAAAB0RhdGFLZXkAAAAAMQAAAAEAAAAAAAAABEluZm8AAAABAAAABgAAAAEAAAAAAAAABU93bmVyAAAAAAAAAQAAAAYAAAABAAAAAAAAAAtOb3RpZnlQcmVmcwAAAAABAAAAEwAAAAEAAAAAAAAABVRydXN0AAAAAAAAAQAAAAYAAAABAAAAAAAAAAhUcnVzdEFjawAAAAEAAAAGAAAAAQAAAAAAAAASUGVuZGluZ0JlbmVmaWNpYXJ5AAAAAAABAAAABgAAAAEAAAAAAAAABUFsaWFzAAAAAAAAAgAAABMAAAPuAAAAIAAAAAEAAAAAAAAAEENvbmZpcm1UcmFuc2ZlcnMAAAABAAAAEwAAAAEAAAAAAAAAD1BlbmRpbmdUcmFuc2ZlcgAAAAABAAAABgAAAAEAAAAAAAAABFNpbmsAAAABAAAAEwAAAAAAAAAAAAAADlVwZ3JhZGVIaXN0b3J5AAAAAAABAAAAAAAAAAlFdmVudExhbmUAAAAAAAABAAAABAAAAAEAAAAAAAAACURhaWx5Um9vdAAAAAAAAAEAAAAGAAAAAQAAAAAAAAAHTWV0cmljcwAAAAACAAAABgAAABEAAAAAAAAAAAAAAAxEZXByZWNhdGlvbnMAAAABAAAAAAAAAAtPd25lZFRva2VucwAAAAABAAAAEwAAAAEAAAAAAAAACEFwcHJvdmFsAAAAAQAAAAYAAAABAAAAAAAAAAhPcGVyYXRvcgAAAAIAAAATAAAAEwAAAAEAAAAAAAAACkRhaWx5TGFuZXMAAAAAAAEAAAAGAAAAAQAAAAAAAAAMTWV0cmljc0luZGV4AAAAAQAAAAYAAAABAAAAAAAAAAZNaW50ZXIAAAAAAAEAAAATAAAAAAAAAAAAAAAHTWludGVycwAAAAAAAAAAAAAAAAZCdXJuZWQAAAAAAAAAAAAAAAAAClJlbnRQb2xpY3kAAAAAAAEAAAAAAAAAC1JlbnRCYWxhbmNlAAAAAAEAAAATAAAAAQAAAAAAAAAHUmVudER1ZQAAAAACAAAAEwAAAAQAAAABAAAAAAAAAApSZXRpcmVtZW50AAAAAAABAAAABAAAAAAAAAAAAAAADFBlbmRpbmdBZG1pbgAAAAEAAAAAAAAABFJvbGUAAAACAAAH0AAAAARSb2xlAAAAEwAAAAEAAAAAAAAABFNsb3QAAAABAAAD7gAAACAAAAABAAAAAAAAAAVIb3RlbAAAAAAAAAEAAAAEAAAAAQAAAAAAAAAJSG90ZWxOYW1lAAAAAAAAAQAAABAAAAAAAAAAAAAAAApIb3RlbENvdW50AAAAAAABAAAAAAAAAARSb29tAAAAAgAAAAQAAAAQAAAAAQAAAAAAAAAFUm9vbXMAAAAAAAABAAAABAAAAAEAAAAAAAAABVVzYWdlAAAAAAAAAQAAAAYAAAABAAAAAAAAAAZSZW50YWwAAAAAAAEAAAAGAAAAAAAAAAAAAAATTWFpbnRlbmFuY2VTY2hlZHVsZQAAAAABAAAAAAAAAAtNYWludGVuYW5jZQAAAAABAAAABgAAAAEAAAAAAAAABkZyb3plbgAAAAAAAQAAAAYAAAABAAAAAAAAAAhDbGF3YmFjawAAAAEAAAAGAAAAAQAAAAAAAAAHTGlzdGluZwAAAAABAAAABgAAAAEAAAAAAAAAB0F1Y3Rpb24AAAAAAQAAAAYAAAABAAAAAAAAAAxEdXRjaEF1Y3Rpb24AAAABAAAABgAAAAEAAAAAAAAABU9mZmVyAAAAAAAAAgAAAAYAAAATAAAAAQAAAAAAAAAFVHJhZGUAAAAAAAABAAAABgAAAAEAAAAAAAAAB1JveWFsdHkAAAAAAQAAAAQAAAABAAAAAAAAAAhGcmFjdGlvbgAAAAEAAAAGAAAAAQAAAAAAAAAGU2hhcmVzAAAAAAACAAAABgAAABM=",
+        "AAAAAgAAAG8qIEB0aXRsZSBFeHRLZXkyCiAqIEBkZXYgWWV0IG1vcmUgc3RvcmFnZSBrZXlzLCBpbiB0aGUgc2FtZSByb2xlIGFzIERhdGFLZXkgYW5kIEV4dEtleSwgd2hpY2gKICogYXJlIGJvdGggZnVsbC4AAAAAAAAAAAdFeHRLZXkyAAAAAB0AAAABAAAAAAAAAAhCcmFuZGluZwAAAAEAAAAEAAAAAQAAAAAAAAAGTGFiZWxzAAAAAAABAAAAEQAAAAAAAAAAAAAAB0xvY2FsZXMAAAAAAAAAAAAAAAAPUHJlcGF5RGlzY291bnRzAAAAAAEAAAAAAAAAB1ByZXBhaWQAAAAAAgAAAAYAAAAEAAAAAQAAAAAAAAAMUHJlcGFpZFllYXJzAAAAAQAAAAYAAAAAAAAAAAAAAA1MYXRlRmVlUG9saWN5AAAAAAAAAQAAAAAAAAALRGVsaW5xdWVuY3kAAAAAAQAAAAYAAAAAAAAAAAAAAAxHcmFjZVBlcmlvZHMAAAABAAAAAAAAAAxQZW5kaW5nR3JhY2UAAAABAAAH0AAAAAlHcmFjZVJ1bGUAAAAAAAABAAAAAAAAAAlEZXB0aEJvb2sAAAAAAAADAAAABAAAA+gAAAARAAAAEwAAAAEAAAAAAAAAB0J1eWJhY2sAAAAAAgAAAAQAAAARAAAAAAAAAAAAAAAIQnV5YmFja3MAAAAAAAAAAAAAABJGaXJzdFJlZnVzYWxIb3RlbHMAAAAAAAEAAAAAAAAADEZpcnN0UmVmdXNhbAAAAAEAAAAGAAAAAQAAAAAAAAANT3BlcmF0b3JVbnRpbAAAAAAAAAIAAAATAAAAEwAAAAEAAAAAAAAAB0NoYXJpdHkAAAAAAQAAABMAAAABAAAAAAAAAAxDaGFyaXR5U3BsaXQAAAABAAAABAAAAAEAAAAAAAAAB0RvbmF0ZWQAAAAAAwAAABMAAAAEAAAAEwAAAAEAAAAAAAAADEhvdGVsRG9uYXRlZAAAAAMAAAAEAAAABAAAABMAAAAAAAAAAAAAAAhGZWVJbmRleAAAAAAAAAAAAAAADkZlZUluZGV4ZWRZZWFyAAAAAAAAAAAAAAAAAA1GZWVCaWxsZWRZZWFyAAAAAAAAAQAAAAAAAAAMRm9yY2VNYWpldXJlAAAAAwAAAAQAAAAEAAAABAAAAAEAAAAAAAAAE0ZvcmNlTWFqZXVyZVNldHRsZWQAAAAAAQAAAAYAAAABAAAAAAAAAApSZWxpZWZGdW5kAAAAAAACAAAABAAAABMAAAABAAAAAAAAABJSZXBsYWNlbWVudENyZWRpdHMAAAAAAAIAAAATAAAABAAAAAEAAAAAAAAACFJlbWluZGVkAAAAAQAAAAYAAAAAAAAAAAAAAAxTdGF0c1ByaXZhY3k=",
+        "AAAABAAAAWUqIEB0aXRsZSBFeHRFcnJvcgogKiBAZGV2IE1vcmUgd2F5cyBhIGNhbGwgY2FuIGZhaWwsIHdpdGggY29kZXMgb2YgdGhlaXIgb3duIGFmdGVyIEVycm9yJ3MuIEEKICogZnVuY3Rpb24gY2FuIG9ubHkgcmV0dXJuIG9uZSBlcnJvciB0eXBlLCBzbyBlbmRwb2ludHMga2VlcCByZXR1cm5pbmcKICogRXJyb3IgYW5kIHJhaXNlIHRoZXNlIHdpdGggZmFpbCgpOiB0aGUgaG9zdCByZXBvcnRzIGEgcmFpc2VkIGNvZGUgZXhhY3RseQogKiBsaWtlIGEgcmV0dXJuZWQgb25lLCBhbmQgY2xpZW50cyBsb29rIGJvdGggZW51bXMgdXAgYnkgY29kZS4gSXQgaXMgZnVsbAogKiB0b286IG5ld2VyIGZhaWx1cmVzIGdvIGluIEV4dEVycm9yMi4AAAAAAAAAAAAACEV4dEVycm9yAAAAMgAAAAAAAAAQU3VwcGx5Q2FwUmVhY2hlZAAAASwAAAAAAAAACU5vQXVjdGlvbgAAAAAAATYAAAAAAAAADkF1Y3Rpb25SdW5uaW5nAAAAAAE3AAAAAAAAAAxBdWN0aW9uRW5kZWQAAAE4AAAAAAAAAAdOb09mZmVyAAAAAUAAAAAAAAAADE9mZmVyRXhwaXJlZAAAAUEAAAAAAAAAB05vVHJhZGUAAAABSgAAAAAAAAARTm90RnJhY3Rpb25hbGl6ZWQAAAAAAAFUAAAAAAAAABJJbnN1ZmZpY2llbnRTaGFyZXMAAAAAAVUAAAAAAAAAD05vVm91Y2hlclNpZ25lcgAAAAFeAAAAAAAAAA5Wb3VjaGVyRXhwaXJlZAAAAAABXwAAAAAAAAAPVm91Y2hlclJlZGVlbWVkAAAAAWAAAAAAAAAACldyb25nQnV5ZXIAAAAAAWEAAAAAAAAAC05vQWxsb3dsaXN0AAAAAWgAAAAAAAAADk5vdEFsbG93bGlzdGVkAAAAAAFpAAAAAAAAABBBbGxvd2xpc3RDbGFpbWVkAAABagAAAAAAAAAOTWV0YWRhdGFGcm96ZW4AAAAAAXIAAAAAAAAADVRvb01hbnlQYXllZXMAAAAAAAF8AAAAAAAAAAxJbnZhbGlkU3BsaXQAAAF9AAAAAAAAAApOb1B1cmNoYXNlAAAAAAGGAAAAAAAAAA9Db29saW5nT2ZmRW5kZWQAAAABhwAAAAAAAAARQ29vbGluZ09mZlJ1bm5pbmcAAAAAAAGIAAAAAAAAAAtUb2tlbkZyb3plbgAAAAGQAAAAAAAAAAxSZW50YWxBY3RpdmUAAAGaAAAAAAAAABNJbnN1ZmZpY2llbnRSZXNlcnZlAAAAAaQAAAAAAAAAD05vU3BlbmRQcm9wb3NhbAAAAAGlAAAAAAAAAAxOb0NoZWNraW5LZXkAAAGuAAAAAAAAABJDaGVja2luQ29kZUV4cGlyZWQAAAAAAa8AAAAAAAAAD0NoZWNraW5Db2RlVXNlZAAAAAGwAAAAAAAAAA5BbW91bnRPdmVyZmxvdwAAAAABuAAAAAAAAAAOTmVnYXRpdmVBbW91bnQAAAAAAbkAAAAAAAAACkludmFsaWRCcHMAAAAAAboAAAAAAAAAEEludmFsaWRNaW5vclVuaXQAAAG7AAAAAAAAAAxOb0NvbW1pdG1lbnQAAAHCAAAAAAAAABJDb21taXRtZW50TWlzbWF0Y2gAAAAAAcMAAAAAAAAAEU5vQ29sbGVjdGlvbk9mZmVyAAAAAAABzAAAAAAAAAAQQ3JpdGVyaWFNaXNtYXRjaAAAAc0AAAAAAAAAC05vU3dhcENoYWluAAAAAdYAAAAAAAAADE5vU3dhcEludGVudAAAAeAAAAAAAAAADkludGVudEJvb2tGdWxsAAAAAAHhAAAAAAAAAA9JbnZhbGlkQnJhbmRpbmcAAAAB6gAAAAAAAAAMTGFiZWxUb29Mb25nAAAB9AAAAAAAAAANVG9vTWFueUxhYmVscwAAAAAAAfUAAAAAAAAAEk1haW50ZW5hbmNlQXJyZWFycwAAAAAB/gAAAAAAAAAUTm9QZW5kaW5nR3JhY2VQZXJpb2QAAAIIAAAAAAAAAAlOb0J1eWJhY2sAAAAAAAISAAAAAAAAAA5JbnZhbGlkQnV5YmFjawAAAAACEwAAAAAAAAAOQnV5YmFja05vdE9wZW4AAAAAAhQAAAAAAAAAEEJ1eWJhY2tFeGhhdXN0ZWQAAAIVAAAAAAAAAA9Ub29NYW55QnV5YmFja3MAAAACFg==",
+        "AAAABAAAAH0qIEB0aXRsZSBFeHRFcnJvcjIKICogQGRldiBGYWlsdXJlcyBhZGRlZCBvbmNlIEV4dEVycm9yIHdhcyBmdWxsLCByYWlzZWQgd2l0aCBmYWlsKCkgbGlrZSBpdCwKICogd2l0aCBjb2RlcyBhZnRlciBFeHRFcnJvcidzLgAAAAAAAAAAAAAJRXh0RXJyb3IyAAAAAAAADwAAAAAAAAAOTm9GaXJzdFJlZnVzYWwAAAAAAhwAAAAAAAAAEEZpcnN0UmVmdXNhbE9wZW4AAAIdAAAAAAAAABJGaXJzdFJlZnVzYWxMYXBzZWQAAAAAAh4AAAAAAAAACk5vdENoYXJpdHkAAAAAAiYAAAAAAAAAEER1cGxpY2F0ZUNoYXJpdHkAAAInAAAAAAAAAApOb0ZlZUluZGV4AAAAAAIwAAAAAAAAABFGZWVBbHJlYWR5SW5kZXhlZAAAAAAAAjEAAAAAAAAAClN0YWxlSW5kZXgAAAAAAjIAAAAAAAAACkZlZUluZGV4ZWQAAAAAAjMAAAAAAAAADUZlZVllYXJCaWxsZWQAAAAAAAI0AAAAAAAAAA9Ob3RGb3JjZU1hamV1cmUAAAACOgAAAAAAAAAKV2Vla0Nsb3NlZAAAAAACOwAAAAAAAAAOQWxyZWFkeVNldHRsZWQAAAAAAjwAAAAAAAAADlJlbGllZlVuZnVuZGVkAAAAAAI9AAAAAAAAABNOb1JlcGxhY2VtZW50Q3JlZGl0AAAAAj4=",
+        "AAAAAQAAALQqIEB0aXRsZSBUaW1lc2hhcmVJbmZvCiAqIEBkZXYgVGhpcyBzdHJ1Y3QgaG9sZHMgdGhlIHNwZWNpZmljIGRldGFpbHMgZm9yIGEgc2luZ2xlIHRpbWVzaGFyZSB0b2tlbi4KICogSXQncyAiQ2xvbmUiIHNvIHdlIGNhbiBjb3B5IGl0LCBhbmQgIkRlYnVnL0VxL1BhcnRpYWxFcSIgZm9yIHRlc3RpbmcvbG9nZ2luZy4AAAAAAAAADVRpbWVzaGFyZUluZm8AAAAAAAAFAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAAEGxlYXNlX2VuZF9sZWRnZXIAAAPoAAAABAAAAAAAAAAEcm9vbQAAABAAAAAAAAAABHdlZWsAAAAEAAAAAAAAAAR5ZWFyAAAD6AAAAAQ=",
+        "AAAAAAAAAr4qIEBkZXYgTWludHMgYSBuZXcgdGltZXNoYXJlIHRva2VuIGFuZCBhc3NpZ25zIGl0IHRvIGFuIG93bmVyLiBFYWNoIHdlZWsgb2YKICAgICAqIGEgcm9vbSBpcyBtaW50ZWQgb25jZSAoc2VlIHNsb3RzLnJzKSwgdXAgdG8gdGhlIGhvdGVsJ3Mgc3VwcGx5IGNhcCAoc2VlCiAgICAgKiBzdXBwbHkucnMpLgogICAgICogT25seSB0aGUgY29udHJhY3QgQURNSU4gY2FuIGNhbGwgdGhpcyBmdW5jdGlvbi4KICAgICAqIEBwYXJhbSB0byBUaGUgYWRkcmVzcyB0aGF0IHdpbGwgcmVjZWl2ZSB0aGUgbmV3IHRva2VuLgogICAgICogQHBhcmFtIGhvdGVsX2lkIFRoZSBob3RlbCdzIElEIGluIHRoZSByZWdpc3RyeSAoc2VlIGhvdGVscy5ycykuCiAgICAgKiBAcGFyYW0gcm9vbSBUaGUgcm9vbSwgZnJvbSB0aGUgaG90ZWwncyBpbnZlbnRvcnkgKHNlZSByb29tcy5ycykuCiAgICAgKiBAcGFyYW0gd2VlayBUaGUgd2VlayBvZiB0aGUgeWVhciAoMS01MikuCiAgICAgKiBAcGFyYW0geWVhciBUaGUgeWVhciB0aGUgcmlnaHQgc3RhcnRzIGluLCBvciBOb25lIGZvciBldmVyeSB5ZWFyLgogICAgICogQHBhcmFtIGxlYXNlX2VuZF9sZWRnZXIgVGhlIGxlZGdlciBzZXF1ZW5jZSB0aGUgcmlnaHQgZXhwaXJlcyBhdCwgb3IgTm9uZS4KICAgICAqIEByZXR1cm4gVGhlIHVuaXF1ZSB0b2tlbiBJRCBvZiB0aGUgbmV3bHkgbWludGVkIHRpbWVzaGFyZS4AAAAAAARtaW50AAAABgAAAAAAAAACdG8AAAAAABMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAAEcm9vbQAAABAAAAAAAAAABHdlZWsAAAAEAAAAAAAAAAR5ZWFyAAAD6AAAAAQAAAAAAAAAEGxlYXNlX2VuZF9sZWRnZXIAAAPoAAAABAAAAAEAAAPpAAAABgAAAAM=",
+        "AAAAAAAAAUgqIEBkZXYgTWludHMgYSBuZXcgdGltZXNoYXJlIHRva2VuIG9uIGJlaGFsZiBvZiBhIGhvbGRlciBvZiB0aGUgTWludGVyIHJvbGUKICAgICAqIChzZWUgcm9sZXMucnMpLCBlLmcuIGEgZnJvbnQtZGVzayBzeXN0ZW0uIElEcyBjb21lIGZyb20gdGhlIG1pbnRlcidzIG93biBibG9jay4KICAgICAqIEBwYXJhbSBtaW50ZXIgVGhlIG1pbnRlciAobXVzdCBzaWduKS4KICAgICAqIEBwYXJhbSBpbmZvIFRoZSB0b2tlbidzIGRldGFpbHMsIGFzIGZvciBtaW50KCkuCiAgICAgKiBAcmV0dXJuIFRoZSB1bmlxdWUgdG9rZW4gSUQgb2YgdGhlIG5ld2x5IG1pbnRlZCB0aW1lc2hhcmUuAAAAB21pbnRfYnkAAAAAAwAAAAAAAAAGbWludGVyAAAAAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAEaW5mbwAAB9AAAAANVGltZXNoYXJlSW5mbwAAAAAAAAEAAAPpAAAABgAAAAM=",
+        "AAAAAQAAAGQqIEB0aXRsZSBNaW50ZXJTdGF0ZQogKiBAZGV2IEEgbWludGVyJ3MgY3VycmVudCBJRCBibG9jayBhbmQgaG93IG1hbnkgdG9rZW5zIGl0IGhhcyBtaW50ZWQgaW4gdG90YWwuAAAAAAAAAAtNaW50ZXJTdGF0ZQAAAAADAAAAIUxhc3QgSUQgb2YgdGhlIGJsb2NrIChpbmNsdXNpdmUpLgAAAAAAAANlbmQAAAAABgAAAAAAAAAGbWludGVkAAAAAAAGAAAAIE5leHQgSUQgdG8gaXNzdWUgZnJvbSB0aGUgYmxvY2suAAAABG5leHQAAAAG",
+        "AAAAAAAAAEwqIEBkZXYgUmV0dXJucyBhIG1pbnRlcidzIGN1cnJlbnQgSUQgYmxvY2sgYW5kIG1pbnQgY291bnQsIGlmIGl0IGhhcyBtaW50ZWQuAAAADG1pbnRlcl9zdGF0ZQAAAAEAAAAAAAAABm1pbnRlcgAAAAAAEwAAAAEAAAPoAAAH0AAAAAtNaW50ZXJTdGF0ZQA=",
+        "AAAAAAAAAVsqIEBkZXYgRXh0ZW5kcyB0aGUgVFRMIG9mIGBvd25lcmAncyB0b2tlbiBlbnRyaWVzLCBhbmQgb2YgdGhlIGluZGV4ZXMgdGhhdAogICAgICogcmVmZXIgdG8gdGhlbSwgdG8gYXQgbGVhc3QgYGV4dGVuZF90b2AgbGVkZ2VycyAoY2FwcGVkIGF0IHRoZSBuZXR3b3JrJ3MKICAgICAqIG1heGltdW0gVFRMKS4KICAgICAqIEBwYXJhbSBwYWdlIFdoaWNoIE1BWF9CVU1QX1BBR0Utc2l6ZWQgc2xpY2Ugb2YgdG9rZW5zX29mX293bmVyKCkgdG8gY292ZXIsIGZyb20gMC4KICAgICAqIEByZXR1cm4gVGhlIG5leHQgcGFnZSB0byBwYXNzLCBvciBOb25lIG9uY2UgdGhlIHdob2xlIHBvcnRmb2xpbyBpcyBjb3ZlcmVkLgAAAAAOYnVtcF9wb3J0Zm9saW8AAAAAAAMAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAEcGFnZQAAAAQAAAAAAAAACWV4dGVuZF90bwAAAAAAAAQAAAABAAAD6QAAA+gAAAAEAAAAAw==",
+        "AAAAAAAAALAqIEBkZXYgRXh0ZW5kcyB0aGUgVFRMIG9mIGFsbCBvZiBhIHRva2VuJ3MgZW50cmllcyB0byBhdCBsZWFzdCBgZXh0ZW5kX3RvYAogICAgICogbGVkZ2VycyAoY2FwcGVkIGF0IHRoZSBuZXR3b3JrJ3MgbWF4aW11bSBUVEwpLgogICAgICogQHBhcmFtIHRva2VuX2lkIFRoZSB0b2tlbiB0byBrZWVwIGFsaXZlLgAAABBleHRlbmRfdG9rZW5fdHRsAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAAlleHRlbmRfdG8AAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAS8qIEBkZXYgQSBwdWJsaWMsIHJlYWQtb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIGRldGFpbHMgb2YgYSB0aW1lc2hhcmUuCiAgICAgKiBBbnlvbmUgY2FuIGNhbGwgdGhpcyBmdW5jdGlvbiB3aXRob3V0IGF1dGhlbnRpY2F0aW9uLgogICAgICogQHBhcmFtIHRva2VuX2lkIFRoZSBJRCBvZiB0aGUgdG9rZW4gdG8gcXVlcnkuCiAgICAgKiBAcmV0dXJuIFRoZSBUaW1lc2hhcmVJbmZvIHN0cnVjdCAoaG90ZWwsIHJvb20sIHdlZWsgYW5kIHRlcm0pLiBUaGUgdGVybQogICAgICogbWF5IGhhdmUgZW5kZWQ7IHNlZSBpc19hY3RpdmUoKS4AAAAACGdldF9pbmZvAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAfQAAAADVRpbWVzaGFyZUluZm8AAAAAAAAD",
+        "AAAAAAAAASoqIEBkZXYgVHJhbnNmZXJzIGEgdGltZXNoYXJlIHRva2VuIGZyb20gdGhlIGN1cnJlbnQgb3duZXIgdG8gYSBuZXcgb3duZXIuCiAgICAgKiBPbmx5IHRoZSBjdXJyZW50IG93bmVyIG9mIHRoZSB0b2tlbiBjYW4gYXV0aG9yaXplIHRoaXMuCiAgICAgKiBAcGFyYW0gZnJvbSBUaGUgY3VycmVudCBvd25lcidzIGFkZHJlc3MgKHdobyBtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIHRvIFRoZSBuZXcgb3duZXIncyBhZGRyZXNzLgogICAgICogQHBhcmFtIHRva2VuX2lkIFRoZSBJRCBvZiB0aGUgdG9rZW4gdG8gdHJhbnNmZXIuAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAKUqIEBkZXYgQSBwdWJsaWMsIHJlYWQtb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIGN1cnJlbnQgb3duZXIgb2YgYSB0aW1lc2hhcmUuCiAgICAgKiBAcGFyYW0gdG9rZW5faWQgVGhlIElEIG9mIHRoZSB0b2tlbiB0byBxdWVyeS4KICAgICAqIEByZXR1cm4gVGhlIG93bmVyJ3MgQWRkcmVzcy4AAAAAAAAJZ2V0X293bmVyAAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAAK4qIEBkZXYgUmV0dXJucyBhIHRva2VuJ3MgZGV0YWlscyBhbmQgaXRzIG93bmVyIGluIGEgc2luZ2xlIGNhbGwuCiAgICAgKiBAcGFyYW0gdG9rZW5faWQgVGhlIElEIG9mIHRoZSB0b2tlbiB0byBxdWVyeS4KICAgICAqIEByZXR1cm4gVGhlIFRva2VuIHN0cnVjdCAodG9rZW5faWQsIG93bmVyLCBpbmZvKS4AAAAAAAlnZXRfdG9rZW4AAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6QAAB9AAAAAFVG9rZW4AAAAAAAAD",
+        "AAAAAAAAAOEqIEBkZXYgSW5pdGlhbGl6ZXMgdGhlIGNvbnRyYWN0IGJ5IHNldHRpbmcgdGhlIGFkbWluaXN0cmF0b3IuCiAgICAgKiBUaGlzIGZ1bmN0aW9uIHNob3VsZCBvbmx5IGJlIGNhbGxlZCBPTkNFIHdoZW4gdGhlIGNvbnRyYWN0IGlzIGRlcGxveWVkLgogICAgICogQHBhcmFtIGFkbWluIFRoZSBhZGRyZXNzIG9mIHRoZSBwZXJzb24vYWNjb3VudCB3aG8gd2lsbCBiZSB0aGUgImhvdGVsIGFkbWluIi4AAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAQAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAW4qIEBkZXYgTWludHMgc2V2ZXJhbCB0b2tlbnMgdG8gb25lIG93bmVyIHVuZGVyIGEgc2luZ2xlIGFkbWluIHNpZ25hdHVyZSwKICAgICAqIGUuZy4gd2hlbiBvbmJvYXJkaW5nIGV2ZXJ5IHdlZWsgb2YgYSByb29tLgogICAgICogQHBhcmFtIHRvIFRoZSBhZGRyZXNzIHRoYXQgd2lsbCByZWNlaXZlIHRoZSBuZXcgdG9rZW5zLgogICAgICogQHBhcmFtIGluZm9zIFRoZSBkZXRhaWxzIG9mIGVhY2ggdG9rZW4sIGF0IG1vc3QgTUFYX01JTlRfQkFUQ0ggb2YgdGhlbS4KICAgICAqIEByZXR1cm4gVGhlIG5ldyB0b2tlbiBJRHMsIGluIHRoZSBvcmRlciBvZiBgaW5mb3NgLiBUaGV5IGluY3JlYXNlIGJ1dCBuZWVkIG5vdCBiZSBjb25zZWN1dGl2ZS4AAAAAAAptaW50X2JhdGNoAAAAAAACAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAFaW5mb3MAAAAAAAPqAAAH0AAAAA1UaW1lc2hhcmVJbmZvAAAAAAAAAQAAA+kAAAPqAAAABgAAAAM=",
+        "AAAAAAAAAVkqIEBkZXYgTGlzdHMgdG9rZW5zIGZvciBzYWxlLCByZXBsYWNpbmcgYW55IGVhcmxpZXIgbGlzdGluZ3Mgb2YgdGhlbS4KICAgICAqIEBwYXJhbSBjYWxsZXIgVGhlIG93bmVyIG9yIG9uZSBvZiBpdHMgb3BlcmF0b3JzIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIGl0ZW1zICh0b2tlbiBJRCwgcHJpY2UpIHBhaXJzLCBhdCBtb3N0IE1BWF9MSVNUX0JBVENIIG9mIHRoZW0uCiAgICAgKiBAcGFyYW0gcGF5bWVudF90b2tlbiBUaGUgdG9rZW4gdGhlIHByaWNlcyBhcmUgcGFpZCBpbi4KICAgICAqIEByZXR1cm4gQSBjb2RlIHBlciBpdGVtOiBCVUxLX09LIG9yIHRoZSBlcnJvciBpdCBmYWlsZWQgd2l0aC4AAAAAAAAJbGlzdF9tYW55AAAAAAAABAAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAABWl0ZW1zAAAAAAAD6gAAA+0AAAACAAAABgAAAAsAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAQAAA+kAAAPqAAAABAAAAAM=",
+        "AAAAAAAAAOkqIEBkZXYgV2l0aGRyYXdzIHRoZSBsaXN0aW5ncyBvZiB0b2tlbnMuCiAgICAgKiBAcGFyYW0gY2FsbGVyIFRoZSBvd25lciBvciBvbmUgb2YgaXRzIG9wZXJhdG9ycyAobXVzdCBzaWduKS4KICAgICAqIEBwYXJhbSB0b2tlbl9pZHMgQXQgbW9zdCBNQVhfTElTVF9CQVRDSCB0b2tlbnMuCiAgICAgKiBAcmV0dXJuIEEgY29kZSBwZXIgdG9rZW46IEJVTEtfT0sgb3IgdGhlIGVycm9yIGl0IGZhaWxlZCB3aXRoLgAAAAAAAAtjYW5jZWxfbWFueQAAAAADAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAJdG9rZW5faWRzAAAAAAAD6gAAAAYAAAABAAAD6QAAA+oAAAAEAAAAAw==",
+        "AAAAAAAAASMqIEBkZXYgQ2hhbmdlcyB0aGUgcHJpY2VzIG9mIGxpc3RlZCB0b2tlbnMsIGtlZXBpbmcgdGhlaXIgcGF5bWVudCB0b2tlbnMuCiAgICAgKiBAcGFyYW0gY2FsbGVyIFRoZSBvd25lciBvciBvbmUgb2YgaXRzIG9wZXJhdG9ycyAobXVzdCBzaWduKS4KICAgICAqIEBwYXJhbSBpdGVtcyAodG9rZW4gSUQsIG5ldyBwcmljZSkgcGFpcnMsIGF0IG1vc3QgTUFYX0xJU1RfQkFUQ0ggb2YgdGhlbS4KICAgICAqIEByZXR1cm4gQSBjb2RlIHBlciBpdGVtOiBCVUxLX09LIG9yIHRoZSBlcnJvciBpdCBmYWlsZWQgd2l0aC4AAAAADHJlcHJpY2VfbWFueQAAAAMAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAVpdGVtcwAAAAAAA+oAAAPtAAAAAgAAAAYAAAALAAAAAQAAA+kAAAPqAAAABAAAAAM=",
+        "AAAABQAAAFgqIEB0aXRsZSBCdXJuCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSB0b2tlbiBpcyBkZXN0cm95ZWQsIHdoaWNoZXZlciBlbmRwb2ludCBidXJuZWQgaXQuAAAAAAAAAARCdXJuAAAAAQAAAARidXJuAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAI=",
+        "AAAAAAAAAO8qIEBkZXYgRGVzdHJveXMgYSB0b2tlbi4gRmFpbHMgd2l0aCBBbHJlYWR5SW5UcnVzdCBmb3IgdG9rZW5zIGhlbGQgaW4gdHJ1c3QsCiAgICAgKiBUb2tlbkZyb3plbiBmb3IgZnJvemVuIG9uZXMsIGFuZCBSZW50YWxBY3RpdmUgZm9yIHJlbnRlZCBvbmVzLgogICAgICogQHBhcmFtIG93bmVyIFRoZSBjdXJyZW50IG93bmVyIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIHRva2VuX2lkIFRoZSB0b2tlbiB0byBidXJuLgAAAAAEYnVybgAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAKsqIEBkZXYgRGVzdHJveXMgYW55IHRva2VuLCBpbmNsdWRpbmcgb25lIGhlbGQgaW4gdHJ1c3QuIEFkbWluIG9ubHkuCiAgICAgKiBGYWlscyB3aXRoIFJlbnRhbEFjdGl2ZSB3aGlsZSB0aGUgdG9rZW4gaXMgcmVudGVkIG91dC4KICAgICAqIEBwYXJhbSB0b2tlbl9pZCBUaGUgdG9rZW4gdG8gYnVybi4AAAAACmFkbWluX2J1cm4AAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAgAAAFEqIEB0aXRsZSBMYXRlRmVlcwogKiBAZGV2IFdoZXRoZXIgbGF0ZSBmZWVzIGFyZSBjaGFyZ2VkLCBhcyByZXBvcnRlZCBieSBjb25maWcoKS4AAAAAAAAAAAAACExhdGVGZWVzAAAAAgAAAAAAAAAAAAAACERpc2FibGVkAAAAAQAAAAAAAAAHRW5hYmxlZAAAAAABAAAH0AAAAA1MYXRlRmVlUG9saWN5AAAA",
+        "AAAAAQAAAFYqIEB0aXRsZSBEZWxpbnF1ZW5jeQogKiBAZGV2IFRoZSBkZWxpbnF1ZW5jeSBsZXZlbCBhIGtlZXBlciBsYXN0IHJlY29yZGVkIGZvciBhIHRva2VuLgAAAAAAAAAAAAtEZWxpbnF1ZW5jeQAAAAACAAAAOkhvdyBtYW55IG9mIHRoZSBwb2xpY3kncyB0aHJlc2hvbGRzIHRoZSB0b2tlbiBoYWQgY3Jvc3NlZC4AAAAAAAVsZXZlbAAAAAAAAAQAAAAXVGhlIG9sZGVzdCB1bnBhaWQgeWVhci4AAAAABHllYXIAAAAE",
+        "AAAAAQAAAFoqIEB0aXRsZSBMYXRlRmVlUG9saWN5CiAqIEBkZXYgV2hhdCBvd25lcnMgcGF5IGZvciBzZXR0bGluZyBhIHllYXIncyBtYWludGVuYW5jZSBmZWUgbGF0ZS4AAAAAAAAAAAANTGF0ZUZlZVBvbGljeQAAAAAAAAUAAAAgQ2hhcmdlZCBvbmNlIHRoZSBmZWUgaXMgb3ZlcmR1ZS4AAAAEZmxhdAAAAAsAAAAzVGhlIG1vc3QgdGhlIGxhdGUgZmVlIGNhbiByZWFjaCwgZmxhdCBmZWUgaW5jbHVkZWQuAAAAAANtYXgAAAAACwAAACtTZWNvbmRzIHBlciBpbnRlcmVzdCBwZXJpb2Q7IG11c3Qgbm90IGJlIDAuAAAAAAZwZXJpb2QAAAAAAAYAAABQSW50ZXJlc3QgcGVyIHdob2xlIHBlcmlvZCBvdmVyZHVlLCBpbiBiYXNpcyBwb2ludHMgb2YgdGhlIGZlZTsgYXQKbW9zdCBGVUxMX0JQUy4AAAAIcmF0ZV9icHMAAAAEAAAAVFNlY29uZHMgb3ZlcmR1ZSBhdCB3aGljaCBhIHRva2VuIHJlYWNoZXMgZWFjaCBkZWxpbnF1ZW5jeSBsZXZlbCwgaW4KYXNjZW5kaW5nIG9yZGVyLgAAAAp0aHJlc2hvbGRzAAAAAAPqAAAABg==",
+        "AAAABQAAAHYqIEB0aXRsZSBEZWxpbnF1ZW5jeUVzY2FsYXRlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgdG9rZW4gaXMgZm91bmQgcGFzdCBtb3JlIGRlbGlucXVlbmN5IHRocmVzaG9sZHMgdGhhbgogKiBiZWZvcmUuAAAAAAAAAAAAFERlbGlucXVlbmN5RXNjYWxhdGVkAAAAAQAAABVkZWxpbnF1ZW5jeV9lc2NhbGF0ZWQAAAAAAAAFAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAF1RoZSBvbGRlc3QgdW5wYWlkIHllYXIuAAAAAAR5ZWFyAAAABAAAAAAAAAAAAAAABWxldmVsAAAAAAAABAAAAAAAAAAjU2Vjb25kcyB0aGF0IHllYXIncyBmZWUgaXMgb3ZlcmR1ZS4AAAAAB292ZXJkdWUAAAAABgAAAAAAAAA1VGhlIGxhdGUgZmVlcyBhY2NydWVkIHNvIGZhciwgb3ZlciBldmVyeSB1bnBhaWQgeWVhci4AAAAAAAAIbGF0ZV9mZWUAAAALAAAAAAAAAAI=",
+        "AAAAAAAAAJQqIEBkZXYgUmV0dXJucyB0aGUgbGF0ZSBmZWVzIGEgdG9rZW4gb3dlcyBvbiB0b3Agb2YgaXRzIHVucGFpZCBtYWludGVuYW5jZQogICAgICogZmVlcyBpbiB0aGlzIGxlZGdlcjsgMCBpZiBpdCBpcyBwYWlkIHVwIG9yIG5vdGhpbmcgaXMgb3ZlcmR1ZSB5ZXQuAAAACGxhdGVfZmVlAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAAEcqIEBkZXYgUmV0dXJucyB0aGUgZGVsaW5xdWVuY3kgbGV2ZWwgbGFzdCByZWNvcmRlZCBmb3IgYSB0b2tlbiwgaWYgYW55LgAAAAALZGVsaW5xdWVuY3kAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAfQAAAAC0RlbGlucXVlbmN5AA==",
+        "AAAAAAAAAD0qIEBkZXYgUmV0dXJucyB0aGUgbGF0ZSBmZWUgcG9saWN5LCBpZiBsYXRlIGZlZXMgYXJlIGNoYXJnZWQuAAAAAAAAD2xhdGVfZmVlX3BvbGljeQAAAAAAAAAAAQAAA+gAAAfQAAAADUxhdGVGZWVQb2xpY3kAAAA=",
+        "AAAAAAAAATkqIEBkZXYgUmVjb3JkcyBob3cgbWFueSBkZWxpbnF1ZW5jeSB0aHJlc2hvbGRzIGEgdG9rZW4gaGFzIGNyb3NzZWQgc2luY2UKICAgICAqIGl0cyBvbGRlc3QgdW5wYWlkIHllYXIgZmVsbCBkdWUsIHB1Ymxpc2hpbmcgRGVsaW5xdWVuY3lFc2NhbGF0ZWQgaWYgaXQgaXMKICAgICAqIG1vcmUgdGhhbiBsYXN0IHJlY29yZGVkLgogICAgICogQW55b25lIG1heSBjYWxsIGl0LCBlLmcuIGEga2VlcGVyIHN3ZWVwaW5nIGRlbGlucXVlbnRfdG9rZW5zKCkuCiAgICAgKiBAcmV0dXJuIFRoZSB0b2tlbidzIGxldmVsOyAwIGlmIGl0IGlzbid0IG92ZXJkdWUuAAAAAAAAEWNoZWNrX2RlbGlucXVlbmN5AAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAP8qIEBkZXYgU2V0cyB0aGUgbGF0ZSBmZWUgcG9saWN5LCBvciB0dXJucyBsYXRlIGZlZXMgb2ZmIHdpdGggTm9uZS4gQWRtaW4KICAgICAqIG9ubHkuIEZhaWxzIHdpdGggSW52YWxpZEFtb3VudCBmb3IgbmVnYXRpdmUgYW1vdW50cywgYSByYXRlIG92ZXIKICAgICAqIEZVTExfQlBTLCBhIDAgcGVyaW9kLCBvciBtb3JlIHRoYW4gTUFYX0RFTElOUVVFTkNZX0xFVkVMUyB0aHJlc2hvbGRzIG9yCiAgICAgKiB0aHJlc2hvbGRzIG91dCBvZiBvcmRlci4AAAAAE3NldF9sYXRlX2ZlZV9wb2xpY3kAAAAAAQAAAAAAAAAGcG9saWN5AAAAAAPoAAAH0AAAAA1MYXRlRmVlUG9saWN5AAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAgAAAKgqIEB0aXRsZSBSZW50UG9vbAogKiBAZGV2IFdoZXRoZXIgdGhlIHJlbnQgcG9vbCBpcyBjb25maWd1cmVkLCBhcyByZXBvcnRlZCBieSBjb25maWcoKS4gKEEgcGxhaW4KICogT3B0aW9uPFJlbnRQb2xpY3k+IGZpZWxkIGNhbid0IGJlIGNvbnZlcnRlZCB0byBhbiBTY1ZhbCBieSB0aGUgU0RLLikAAAAAAAAACFJlbnRQb29sAAAAAgAAAAAAAAAAAAAACERpc2FibGVkAAAAAQAAAAAAAAAHRW5hYmxlZAAAAAABAAAH0AAAAApSZW50UG9saWN5AAA=",
+        "AAAAAQAAAFYqIEB0aXRsZSBSZW50UG9saWN5CiAqIEBkZXYgSG93IHRoZSByZW50IHBvb2wgaXMgZnVuZGVkIGFuZCB3aGF0IGtlZXBlcnMgYXJlIHBhaWQgZm9yLgAAAAAAAAAAAApSZW50UG9saWN5AAAAAAAFAAAAJ1RUTCwgaW4gbGVkZ2VycywgZW50cmllcyBhcmUgYnVtcGVkIHRvLgAAAAAJZXh0ZW5kX3RvAAAAAAAABAAAAC1QYWlkIHRvIHRoZSBrZWVwZXIgZm9yIGVhY2ggdG9rZW4ga2VwdCBhbGl2ZS4AAAAAAAANZmVlX3Blcl90b2tlbgAAAAAAAAsAAAA1QmFsYW5jZSB1bmRlciB3aGljaCBvd25lcnMgYXJlIHdhcm5lZCBhZnRlciBhIGNoYXJnZS4AAAAAAAALbG93X2JhbGFuY2UAAAAACwAAADBUVEwsIGluIGxlZGdlcnMsIHVuZGVyIHdoaWNoIGVudHJpZXMgYXJlIGJ1bXBlZC4AAAAJdGhyZXNob2xkAAAAAAAABAAAACtUb2tlbiBiYWxhbmNlcyBhcmUgaGVsZCBpbi4gRml4ZWQgb25jZSBzZXQuAAAAAAV0b2tlbgAAAAAAABM=",
+        "AAAABQAAAGAqIEB0aXRsZSBSZW50Q2hhcmdlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEga2VlcGVyIGlzIHBhaWQgZnJvbSBhbiBvd25lcidzIGJhbGFuY2UgZm9yIGEgYnVtcC4AAAAAAAAAC1JlbnRDaGFyZ2VkAAAAAAEAAAAMcmVudF9jaGFyZ2VkAAAABAAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAAAAAAGa2VlcGVyAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAHYmFsYW5jZQAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAFIqIEB0aXRsZSBSZW50RGVwb3NpdGVkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYW4gb3duZXIgYWRkcyB0byB0aGVpciByZW50IGJhbGFuY2UuAAAAAAAAAAAADVJlbnREZXBvc2l0ZWQAAAAAAAABAAAADnJlbnRfZGVwb3NpdGVkAAAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAAAAAAdiYWxhbmNlAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAF0qIEB0aXRsZSBSZW50V2l0aGRyYXduCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYW4gb3duZXIgdGFrZXMgYmFjayBwYXJ0IG9mIHRoZWlyIHJlbnQgYmFsYW5jZS4AAAAAAAAAAAAADVJlbnRXaXRoZHJhd24AAAAAAAABAAAADnJlbnRfd2l0aGRyYXduAAAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAAAAAAdiYWxhbmNlAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAHEqIEB0aXRsZSBSZW50TG93QmFsYW5jZQogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgY2hhcmdlIGxlYXZlcyBhbiBvd25lcidzIGJhbGFuY2UgdW5kZXIgdGhlIHBvbGljeSdzIGxvd19iYWxhbmNlLgAAAAAAAAAAAAAOUmVudExvd0JhbGFuY2UAAAAAAAEAAAAQcmVudF9sb3dfYmFsYW5jZQAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAAAAAAB2JhbGFuY2UAAAAACwAAAAAAAAAC",
+        "AAAAAAAAAYEqIEBkZXYgQnVtcHMgb25lIHBhZ2Ugb2YgYG93bmVyYCdzIHBvcnRmb2xpbyB0byB0aGUgcG9saWN5J3MgZXh0ZW5kX3RvIGFuZAogICAgICogcGF5cyBga2VlcGVyYCBmZWVfcGVyX3Rva2VuIHBlciB0b2tlbiBmcm9tIHRoZSBvd25lcidzIHJlbnQgYmFsYW5jZS4KICAgICAqIEZhaWxzIHdpdGggUmVudE5vdER1ZSBpZiB0aGUgcGFnZSB3YXMgcGFpZCBmb3IgbGVzcyB0aGFuCiAgICAgKiBgZXh0ZW5kX3RvIC0gdGhyZXNob2xkYCBsZWRnZXJzIGFnby4KICAgICAqIEBwYXJhbSBwYWdlIEFzIGZvciBidW1wX3BvcnRmb2xpbygpLgogICAgICogQHJldHVybiBUaGUgbmV4dCBwYWdlIHRvIHBhc3MsIG9yIE5vbmUgb25jZSB0aGUgd2hvbGUgcG9ydGZvbGlvIGlzIGNvdmVyZWQuAAAAAAAAC2tlZXBlcl9idW1wAAAAAAMAAAAAAAAABmtlZXBlcgAAAAAAEwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAARwYWdlAAAABAAAAAEAAAPpAAAD6AAAAAQAAAAD",
+        "AAAAAAAAAEcqIEBkZXYgUmV0dXJucyB0aGUgcmVudCBwb29sJ3MgcG9saWN5LCBpZiB0aGUgYWRtaW4gaGFzIGNvbmZpZ3VyZWQgb25lLgAAAAALcmVudF9wb2xpY3kAAAAAAAAAAAEAAAPoAAAH0AAAAApSZW50UG9saWN5AAA=",
+        "AAAAAAAAAG8qIEBkZXYgTW92ZXMgYGFtb3VudGAgb2YgdGhlIHBvbGljeSB0b2tlbiBmcm9tIGBvd25lcmAgaW50byB0aGVpciByZW50IGJhbGFuY2UuCiAgICAgKiBAcmV0dXJuIFRoZSBuZXcgYmFsYW5jZS4AAAAADGRlcG9zaXRfcmVudAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAAC0qIEBkZXYgUmV0dXJucyBgb3duZXJgJ3MgdW51c2VkIHJlbnQgYmFsYW5jZS4AAAAAAAAMcmVudF9iYWxhbmNlAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAGMqIEBkZXYgUGF5cyBgYW1vdW50YCBvZiBgb3duZXJgJ3MgdW51c2VkIHJlbnQgYmFsYW5jZSBiYWNrIHRvIHRoZW0uCiAgICAgKiBAcmV0dXJuIFRoZSBuZXcgYmFsYW5jZS4AAAAADXdpdGhkcmF3X3JlbnQAAAAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAMoqIEBkZXYgQ29uZmlndXJlcyB0aGUgcmVudCBwb29sLiBBZG1pbiBvbmx5LiBUaGUgdG9rZW4gY2Fubm90IGNoYW5nZSBvbmNlCiAgICAgKiBzZXQsIHNpbmNlIGJhbGFuY2VzIGFyZSBoZWxkIGluIGl0LgogICAgICogQHBhcmFtIHBvbGljeSB0aHJlc2hvbGQgbXVzdCBiZSBiZWxvdyBleHRlbmRfdG87IGFtb3VudHMgbXVzdCBub3QgYmUgbmVnYXRpdmUuAAAAAAAPc2V0X3JlbnRfcG9saWN5AAAAAAEAAAAAAAAABnBvbGljeQAAAAAH0AAAAApSZW50UG9saWN5AAAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAABQAAADwqIEB0aXRsZSBTd2FwCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gdHdvIG93bmVycyBzd2FwIHRva2Vucy4AAAAAAAAABFN3YXAAAAABAAAABHN3YXAAAAAEAAAAAAAAAAd0b2tlbl9hAAAAAAYAAAABAAAAAAAAAAd0b2tlbl9iAAAAAAYAAAABAAAAAAAAAAdvd25lcl9hAAAAABMAAAAAAAAAAAAAAAdvd25lcl9iAAAAABMAAAAAAAAAAg==",
+        "AAAAAAAAARMqIEBkZXYgR2l2ZXMgYHRva2VuX2FgIHRvIGBvd25lcl9iYCBhbmQgYHRva2VuX2JgIHRvIGBvd25lcl9hYCBhdG9taWNhbGx5LgogICAgICogRmFpbHMgd2l0aCBJbnZhbGlkU3dhcCBpZiBib3RoIHNpZGVzIGFyZSB0aGUgc2FtZSBvd25lciBvciB0b2tlbi4KICAgICAqIEBwYXJhbSBvd25lcl9hIFRoZSBjdXJyZW50IG93bmVyIG9mIHRva2VuX2EgKG11c3Qgc2lnbikuCiAgICAgKiBAcGFyYW0gb3duZXJfYiBUaGUgY3VycmVudCBvd25lciBvZiB0b2tlbl9iIChtdXN0IHNpZ24pLgAAAAAEc3dhcAAAAAQAAAAAAAAAB293bmVyX2EAAAAAEwAAAAAAAAAHdG9rZW5fYQAAAAAGAAAAAAAAAAdvd25lcl9iAAAAABMAAAAAAAAAB3Rva2VuX2IAAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAIYqIEBkZXYgUmV0dXJucyB3aGV0aGVyIGEgdG9rZW4ncyB0ZXJtIGlzIHN0aWxsIHJ1bm5pbmcuCiAgICAgKiBAcmV0dXJuIGZhbHNlIG9uY2UgdGhlIGxlZGdlciBoYXMgcmVhY2hlZCB0aGUgdG9rZW4ncyBsZWFzZV9lbmRfbGVkZ2VyLgAAAAAACWlzX2FjdGl2ZQAAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPpAAAAAQAAAAM=",
+        "AAAABQAAAF4qIEB0aXRsZSBBZG1pbkNoYW5nZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiB0aGUgcHJvcG9zZWQgc3VjY2Vzc29yIGFjY2VwdHMgYW5kIGJlY29tZXMgYWRtaW4uAAAAAAAAAAAADEFkbWluQ2hhbmdlZAAAAAEAAAANYWRtaW5fY2hhbmdlZAAAAAAAAAIAAAAAAAAACHByZXZpb3VzAAAAEwAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAEAAAAC",
+        "AAAABQAAAE0qIEB0aXRsZSBBZG1pblByb3Bvc2VkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gdGhlIGFkbWluIHByb3Bvc2VzIGEgc3VjY2Vzc29yLgAAAAAAAAAAAAANQWRtaW5Qcm9wb3NlZAAAAAAAAAEAAAAOYWRtaW5fcHJvcG9zZWQAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAEAAAAAAAAACHByb3Bvc2VkAAAAEwAAAAAAAAAC",
+        "AAAAAAAAAEQqIEBkZXYgQ29tcGxldGVzIHRoZSBoYW5kb3Zlci4gTXVzdCBiZSBzaWduZWQgYnkgdGhlIHByb3Bvc2VkIGFkbWluLgAAAAxhY2NlcHRfYWRtaW4AAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEEqIEBkZXYgUmV0dXJucyB0aGUgcHJvcG9zZWQgbmV4dCBhZG1pbiwgaWYgYSBoYW5kb3ZlciBpcyBwZW5kaW5nLgAAAAAAAA1wZW5kaW5nX2FkbWluAAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAH4qIEBkZXYgUHJvcG9zZXMgYG5ld19hZG1pbmAgYXMgdGhlIG5leHQgYWRtaW4uIEFkbWluIG9ubHkuCiAgICAgKiBAcGFyYW0gbmV3X2FkbWluIEJlY29tZXMgYWRtaW4gb25jZSBpdCBjYWxscyBhY2NlcHRfYWRtaW4oKS4AAAAAAA1wcm9wb3NlX2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAABQAAAAAAAAAAAAAACEFsaWFzU2V0AAAAAQAAAAlhbGlhc19zZXQAAAAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAAAAAAAAluYW1lX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAAAAAAABGFkZHIAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADUFsaWFzUmVzb2x2ZWQAAAAAAAABAAAADmFsaWFzX3Jlc29sdmVkAAAAAAADAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAluYW1lX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAI=",
+        "AAAAAAAAAQUqIEBkZXYgUmVnaXN0ZXJzIChvciByZXBsYWNlcykgYW4gYWxpYXMgaW4gYG93bmVyYCdzIGFkZHJlc3MgYm9vay4KICAgICAqIEBwYXJhbSBvd25lciBUaGUgYWRkcmVzcyBib29rJ3Mgb3duZXIgKG11c3Qgc2lnbikuCiAgICAgKiBAcGFyYW0gbmFtZV9oYXNoIEEgaGFzaCBvZiB0aGUgYWxpYXMgbmFtZSwgc28gbmFtZXMgYXJlbid0IHN0b3JlZCBpbiBjbGVhci4KICAgICAqIEBwYXJhbSBhZGRyIFRoZSBhZGRyZXNzIHRoZSBhbGlhcyByZXNvbHZlcyB0by4AAAAAAAAJc2V0X2FsaWFzAAAAAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAluYW1lX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAEYWRkcgAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAG4qIEBkZXYgUmVtb3ZlcyBhbiBhbGlhcyBmcm9tIGBvd25lcmAncyBhZGRyZXNzIGJvb2suCiAgICAgKiBAcGFyYW0gb3duZXIgVGhlIGFkZHJlc3MgYm9vaydzIG93bmVyIChtdXN0IHNpZ24pLgAAAAAADHJlbW92ZV9hbGlhcwAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAJbmFtZV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAHcqIEBkZXYgTG9va3MgdXAgYW4gYWxpYXMgaW4gYG93bmVyYCdzIGFkZHJlc3MgYm9vay4KICAgICAqIEByZXR1cm4gVGhlIGFkZHJlc3MsIG9yIE5vbmUgaWYgdGhlIGFsaWFzIGlzIG5vdCByZWdpc3RlcmVkLgAAAAANcmVzb2x2ZV9hbGlhcwAAAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAJbmFtZV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6AAAABM=",
+        "AAAAAAAAARAqIEBkZXYgVHJhbnNmZXJzIGEgdG9rZW4gdG8gdGhlIGFkZHJlc3MgcmVnaXN0ZXJlZCB1bmRlciBvbmUgb2YgYGZyb21gJ3MgYWxpYXNlcy4KICAgICAqIEBwYXJhbSBmcm9tIFRoZSBjdXJyZW50IG93bmVyJ3MgYWRkcmVzcyAod2hvIG11c3Qgc2lnbikuCiAgICAgKiBAcGFyYW0gbmFtZV9oYXNoIFRoZSBhbGlhcyB0byByZXNvbHZlIGluIGBmcm9tYCdzIGFkZHJlc3MgYm9vay4KICAgICAqIEBwYXJhbSB0b2tlbl9pZCBUaGUgSUQgb2YgdGhlIHRva2VuIHRvIHRyYW5zZmVyLgAAABF0cmFuc2Zlcl90b19hbGlhcwAAAAAAAAMAAAAAAAAABGZyb20AAAATAAAAAAAAAAluYW1lX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAQAAAIUqIEB0aXRsZSBEZXB0aEJvb2sKICogQGRldiBBIGJvb2sgYXMgc3RvcmVkOiBpdHMgbGlzdGluZyBjb3VudCBhbmQgdGhlIGNoZWFwZXN0IGxpc3RpbmdzLCBhcwogKiAocHJpY2UsIHRva2VuIElEKSBpbiBhc2NlbmRpbmcgb3JkZXIuAAAAAAAAAAAAAAlEZXB0aEJvb2sAAAAAAAACAAAAAAAAAAhjaGVhcGVzdAAAA+oAAAPtAAAAAgAAAAsAAAAGAAAAAAAAAAhsaXN0aW5ncwAAAAQ=",
+        "AAAAAQAAAEwqIEB0aXRsZSBNYXJrZXREZXB0aAogKiBAZGV2IFRoZSBsaXN0aW5ncyBvZiBhIHByb3BlcnR5J3MgdGllciBpbiBvbmUgYXNzZXQuAAAAAAAAAAtNYXJrZXREZXB0aAAAAAADAAAAQlRoZSB0cmFja2VkIGxpc3RpbmdzJyB0b2tlbiBJRHMsIGNoZWFwZXN0IGZpcnN0LCB0aWVzIGJ5IHRva2VuIElELgAAAAAACGNoZWFwZXN0AAAD6gAAAAYAAABBVGhlIGxvd2VzdCBsaXN0ZWQgcHJpY2U7IE5vbmUgd2l0aG91dCBsaXN0aW5ncywgb3Igd2hpbGUgdW5rbm93bi4AAAAAAAAFZmxvb3IAAAAAAAPoAAAACwAAABtIb3cgbWFueSB0b2tlbnMgYXJlIGxpc3RlZC4AAAAACGxpc3RpbmdzAAAABA==",
+        "AAAAAAAAAP8qIEBkZXYgUmV0dXJucyB0aGUgbG93ZXN0IHByaWNlIGEgdG9rZW4gb2YgYSBwcm9wZXJ0eSdzIHRpZXIgaXMgbGlzdGVkIGF0CiAgICAgKiBpbiBhbiBhc3NldDsgTm9uZSB3aXRob3V0IGxpc3RpbmdzLCB3aGlsZSB0aGUgZmxvb3IgaXMgdW5rbm93biwgb3IKICAgICAqIHdoaWxlIHRoZSBzdGF0cyBwcml2YWN5IGhpZGVzIHRoZSBib29rLgogICAgICogQHBhcmFtIHRpZXIgVGhlIHRpZXIsIG9yIE5vbmUgZm9yIHRva2VucyB3aXRob3V0IG9uZS4AAAAAC2Zsb29yX3ByaWNlAAAAAAMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAAEdGllcgAAA+gAAAARAAAAAAAAAAVhc3NldAAAAAAAABMAAAABAAAD6AAAAAs=",
+        "AAAAAAAAAM8qIEBkZXYgUmV0dXJucyBob3cgbWFueSB0b2tlbnMgb2YgYSBwcm9wZXJ0eSdzIHRpZXIgYXJlIGxpc3RlZCBpbiBhbgogICAgICogYXNzZXQsIGFzIGV4YWN0IGFzIHRoZSBzdGF0cyBwcml2YWN5IGFsbG93cywgYW5kIHRoZSBjaGVhcGVzdCBvZiB0aGVtLgogICAgICogQHBhcmFtIHRpZXIgVGhlIHRpZXIsIG9yIE5vbmUgZm9yIHRva2VucyB3aXRob3V0IG9uZS4AAAAADG1hcmtldF9kZXB0aAAAAAMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAAEdGllcgAAA+gAAAARAAAAAAAAAAVhc3NldAAAAAAAABMAAAABAAAH0AAAAAtNYXJrZXREZXB0aAA=",
+        "AAAAAQAAAEMqIEB0aXRsZSBEdXRjaEF1Y3Rpb24KICogQGRldiBBIHRva2VuIG9mZmVyZWQgYXQgYSBkZWNsaW5pbmcgcHJpY2UuAAAAAAAAAAAMRHV0Y2hBdWN0aW9uAAAABgAAACVIb3cgbXVjaCB0aGUgcHJpY2UgZmFsbHMgZWFjaCBsZWRnZXIuAAAAAAAAEGRlY2F5X3Blcl9sZWRnZXIAAAALAAAAIVRoZSBwcmljZSBuZXZlciBmYWxscyBiZWxvdyB0aGlzLgAAAAAAAAtmbG9vcl9wcmljZQAAAAALAAAAG1Rva2VuIHRoZSBwcmljZSBpcyBwYWlkIGluLgAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAA0VGhlIGFkbWluIHdobyBvZmZlcmVkIHRoZSB0b2tlbiwgYW5kIGlzIHBhaWQgZm9yIGl0LgAAAAZzZWxsZXIAAAAAABMAAAAuTGVkZ2VyIHNlcXVlbmNlIHRoZSBwcmljZSBzdGFydHMgZmFsbGluZyBmcm9tLgAAAAAADHN0YXJ0X2xlZGdlcgAAAAQAAAAAAAAAC3N0YXJ0X3ByaWNlAAAAAAs=",
+        "AAAABQAAAGIqIEB0aXRsZSBEdXRjaEF1Y3Rpb25TdGFydGVkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gdGhlIGFkbWluIG9mZmVycyBhIHRva2VuIGF0IGEgZGVjbGluaW5nIHByaWNlLgAAAAAAAAAAABNEdXRjaEF1Y3Rpb25TdGFydGVkAAAAAAEAAAAVZHV0Y2hfYXVjdGlvbl9zdGFydGVkAAAAAAAABQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAAAAAAAAtzdGFydF9wcmljZQAAAAALAAAAAAAAAAAAAAALZmxvb3JfcHJpY2UAAAAACwAAAAAAAAAAAAAAEGRlY2F5X3Blcl9sZWRnZXIAAAALAAAAAAAAAAI=",
+        "AAAAAAAAAIkqIEBkZXYgQnV5cyBhIHRva2VuIGF0IGl0cyBjdXJyZW50IER1dGNoIGF1Y3Rpb24gcHJpY2UuCiAgICAgKiBAcGFyYW0gYnV5ZXIgVGhlIGJ1eWVyIChtdXN0IHNpZ24gYW5kIHBheSkuCiAgICAgKiBAcmV0dXJuIFRoZSBwcmljZSBwYWlkLgAAAAAAAAlidXlfZHV0Y2gAAAAAAAACAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAEYqIEBkZXYgUmV0dXJucyB3aGF0IGJ1eV9kdXRjaCB3b3VsZCBjaGFyZ2UgZm9yIGEgdG9rZW4gaW4gdGhpcyBsZWRnZXIuAAAAAAALZHV0Y2hfcHJpY2UAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAAEAqIEBkZXYgUmV0dXJucyBhIHRva2VuJ3MgRHV0Y2ggYXVjdGlvbiwgaWYgaXQgaXMgb2ZmZXJlZCBpbiBvbmUuAAAADWR1dGNoX2F1Y3Rpb24AAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6AAAB9AAAAAMRHV0Y2hBdWN0aW9u",
+        "AAAAAAAAAQMqIEBkZXYgT2ZmZXJzIGEgdG9rZW4gdGhlIGFkbWluIGhvbGRzIGF0IGEgcHJpY2UgZGVjbGluaW5nIGZyb20gbm93LgogICAgICogQWRtaW4gb25seS4gUmVwbGFjZXMgYW4gZWFybGllciBEdXRjaCBhdWN0aW9uIG9mIHRoZSB0b2tlbi4KICAgICAqIEBwYXJhbSBmbG9vcl9wcmljZSBNdXN0IG5vdCBiZSBuZWdhdGl2ZSwgbm9yIGFib3ZlIHN0YXJ0X3ByaWNlLgogICAgICogQHBhcmFtIGRlY2F5X3Blcl9sZWRnZXIgTXVzdCBub3QgYmUgbmVnYXRpdmUuAAAAABNzdGFydF9kdXRjaF9hdWN0aW9uAAAAAAUAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAAC3N0YXJ0X3ByaWNlAAAAAAsAAAAAAAAAC2Zsb29yX3ByaWNlAAAAAAsAAAAAAAAAEGRlY2F5X3Blcl9sZWRnZXIAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAgAAAE0qIEB0aXRsZSBHcmFjZVJ1bGUKICogQGRldiBBIHdpbmRvdyB3aG9zZSBsZW5ndGggdGhlIGdyYWNlIHBlcmlvZCB0YWJsZSBzZXRzLgAAAAAAAAAAAAAJR3JhY2VSdWxlAAAAAAAABAAAAAAAAABNQSByZWNpcGllbnQgYWNjZXB0aW5nIGEgcGVuZGluZyB0cmFuc2ZlcjsgUEVORElOR19UUkFOU0ZFUl9XSU5ET1cgYnkgZGVmYXVsdC4AAAAAAAAPUGVuZGluZ1RyYW5zZmVyAAAAAAAAAABMUmV2ZWFsaW5nIHNlYWxlZCBiaWRzIGFmdGVyIGJpZGRpbmcgY2xvc2VzOyBTRUFMRURfUkVWRUFMX1dJTkRPVyBieSBkZWZhdWx0LgAAAAxTZWFsZWRSZXZlYWwAAAAAAAAAY1BheWluZyBhIHllYXIncyBtYWludGVuYW5jZSBmZWUgYmVmb3JlIGl0IGlzIG92ZXJkdWUsIGZyb20gMSBKYW51YXJ5OwpNQUlOVEVOQU5DRV9HUkFDRSBieSBkZWZhdWx0LgAAAAALRGVsaW5xdWVuY3kAAAAAAAAAAEZUaGUgaG90ZWwgbWF0Y2hpbmcgYW4gYWNjZXB0ZWQgb2ZmZXI7IEZJUlNUX1JFRlVTQUxfV0lORE9XIGJ5IGRlZmF1bHQuAAAAAAAMRmlyc3RSZWZ1c2Fs",
+        "AAAAAQAAAFsqIEB0aXRsZSBQZW5kaW5nR3JhY2VQZXJpb2QKICogQGRldiBBIGdyYWNlIHBlcmlvZCBjaGFuZ2Ugd2FpdGluZyBmb3IgaXRzIHRpbWVsb2NrIHRvIHBhc3MuAAAAAAAAAAASUGVuZGluZ0dyYWNlUGVyaW9kAAAAAAACAAAAL0xlZGdlciB0aW1lc3RhbXAgZnJvbSB3aGljaCBpdCBjYW4gYmUgZXhlY3V0ZWQuAAAAAAxlZmZlY3RpdmVfYXQAAAAGAAAAAAAAAAdzZWNvbmRzAAAAAAY=",
+        "AAAABQAAAFYqIEB0aXRsZSBHcmFjZVBlcmlvZENoYW5nZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIHByb3Bvc2VkIGdyYWNlIHBlcmlvZCBpcyBhcHBsaWVkLgAAAAAAAAAAABJHcmFjZVBlcmlvZENoYW5nZWQAAAAAAAEAAAAUZ3JhY2VfcGVyaW9kX2NoYW5nZWQAAAACAAAAAAAAAARydWxlAAAH0AAAAAlHcmFjZVJ1bGUAAAAAAAABAAAAAAAAAAdzZWNvbmRzAAAAAAYAAAAAAAAAAg==",
+        "AAAABQAAAGcqIEB0aXRsZSBHcmFjZVBlcmlvZFByb3Bvc2VkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gdGhlIGFkbWluIHByb3Bvc2VzIGEgbmV3IGxlbmd0aCBmb3IgYSBncmFjZSBwZXJpb2QuAAAAAAAAAAATR3JhY2VQZXJpb2RQcm9wb3NlZAAAAAABAAAAFWdyYWNlX3BlcmlvZF9wcm9wb3NlZAAAAAAAAAMAAAAAAAAABHJ1bGUAAAfQAAAACUdyYWNlUnVsZQAAAAAAAAEAAAAAAAAAB3NlY29uZHMAAAAABgAAAAAAAAAAAAAADGVmZmVjdGl2ZV9hdAAAAAYAAAAAAAAAAg==",
+        "AAAAAAAAAD8qIEBkZXYgUmV0dXJucyBldmVyeSBncmFjZSBwZXJpb2QgaW4gZm9yY2UsIGluIHNlY29uZHMgYnkgcnVsZS4AAAAABnBvbGljeQAAAAAAAAAAAAEAAAPsAAAH0AAAAAlHcmFjZVJ1bGUAAAAAAAAG",
+        "AAAAAAAAAHAqIEBkZXYgV2l0aGRyYXdzIGEgcHJvcG9zZWQgZ3JhY2UgcGVyaW9kLiBBZG1pbiBvbmx5LiBGYWlscyB3aXRoCiAgICAgKiBOb1BlbmRpbmdHcmFjZVBlcmlvZCB3aXRob3V0IGEgcHJvcG9zYWwuAAAAE2NhbmNlbF9ncmFjZV9wZXJpb2QAAAAAAQAAAAAAAAAEcnVsZQAAB9AAAAAJR3JhY2VSdWxlAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAMcqIEBkZXYgQXBwbGllcyBhIHByb3Bvc2VkIGdyYWNlIHBlcmlvZCB3aG9zZSB0aW1lbG9jayBoYXMgcGFzc2VkLiBBbnlvbmUKICAgICAqIG1heSBjYWxsIGl0LiBGYWlscyB3aXRoIE5vUGVuZGluZ0dyYWNlUGVyaW9kIHdpdGhvdXQgYSBwcm9wb3NhbCwgYW5kCiAgICAgKiBUaW1lbG9ja05vdEVsYXBzZWQgYmVmb3JlIGl0cyBlZmZlY3RpdmVfYXQuAAAAABRleGVjdXRlX2dyYWNlX3BlcmlvZAAAAAEAAAAAAAAABHJ1bGUAAAfQAAAACUdyYWNlUnVsZQAAAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEMqIEBkZXYgUmV0dXJucyB0aGUgZ3JhY2UgcGVyaW9kIGNoYW5nZSBwcm9wb3NlZCBmb3IgYSBydWxlLCBpZiBhbnkuAAAAABRwZW5kaW5nX2dyYWNlX3BlcmlvZAAAAAEAAAAAAAAABHJ1bGUAAAfQAAAACUdyYWNlUnVsZQAAAAAAAAEAAAPoAAAH0AAAABJQZW5kaW5nR3JhY2VQZXJpb2QAAA==",
+        "AAAAAAAAALcqIEBkZXYgUHJvcG9zZXMgYSBuZXcgbGVuZ3RoIGZvciBhIGdyYWNlIHBlcmlvZC4gSXQgY2FuIGJlIGV4ZWN1dGVkIG9uY2UKICAgICAqIEdSQUNFX1BPTElDWV9ERUxBWSBoYXMgcGFzc2VkLiBBIG5ldyBwcm9wb3NhbCByZXBsYWNlcyBhbnkgcGVuZGluZyBvbmUKICAgICAqIGZvciB0aGUgcnVsZS4gQWRtaW4gb25seS4AAAAAFHByb3Bvc2VfZ3JhY2VfcGVyaW9kAAAAAgAAAAAAAAAEcnVsZQAAB9AAAAAJR3JhY2VSdWxlAAAAAAAAAAAAAAdzZWNvbmRzAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAABQAAAI4qIEB0aXRsZSBNaW5vclVuaXRTZXQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIHByb3BlcnR5J3MgbWlub3IgdW5pdCBmb3IgYSBwYXltZW50IGFzc2V0IGNoYW5nZXMuCiAqIEEgdW5pdCBvZiAxIGlzIHRoZSBhc3NldCdzIG93biBiYXNlIHVuaXQuAAAAAAAAAAAADE1pbm9yVW5pdFNldAAAAAEAAAAObWlub3JfdW5pdF9zZXQAAAAAAAMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAEAAAAAAAAABWFzc2V0AAAAAAAAEwAAAAEAAAAAAAAABHVuaXQAAAALAAAAAAAAAAI=",
+        "AAAAAAAAAG4qIEBkZXYgUmV0dXJucyB0aGUgbWlub3IgdW5pdHMgYSBwcm9wZXJ0eSBzZXQsIGJ5IHBheW1lbnQgYXNzZXQuIEFzc2V0cwogICAgICogbm90IGxpc3RlZCB1c2UgdGhlaXIgYmFzZSB1bml0LgAAAAAAC21pbm9yX3VuaXRzAAAAAAEAAAAAAAAACGhvdGVsX2lkAAAABAAAAAEAAAPsAAAAEwAAAAs=",
+        "AAAAAAAAAPMqIEBkZXYgU2V0cyB0aGUgc21hbGxlc3QgYW1vdW50IGEgcHJvcGVydHkncyBwYXltZW50cyBpbiBhbiBhc3NldCBhcmUKICAgICAqIHdvcmtlZCBvdXQgaW4uIE1hbmFnZXIgcm9sZSAob3IgYWRtaW4pLgogICAgICogQHBhcmFtIGNhbGxlciBUaGUgbWFuYWdlciAobXVzdCBzaWduKS4KICAgICAqIEBwYXJhbSB1bml0IEluIHRoZSBhc3NldCdzIGJhc2UgdW5pdHM7IG11c3QgYmUgcG9zaXRpdmUsIGFuZCAxIGNsZWFycyBpdC4AAAAADnNldF9taW5vcl91bml0AAAAAAAEAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAAAAAAR1bml0AAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAABQAAAD4qIEB0aXRsZSBQYXVzZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiB0aGUgY29udHJhY3QgaXMgcGF1c2VkLgAAAAAAAAAAAAZQYXVzZWQAAAAAAAEAAAAGcGF1c2VkAAAAAAABAAAAAAAAAAJieQAAAAAAEwAAAAEAAAAC",
+        "AAAABQAAAEIqIEB0aXRsZSBVbnBhdXNlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIHRoZSBjb250cmFjdCBpcyB1bnBhdXNlZC4AAAAAAAAAAAAIVW5wYXVzZWQAAAABAAAACHVucGF1c2VkAAAAAQAAAAAAAAACYnkAAAAAABMAAAABAAAAAg==",
+        "AAAAAAAAAHAqIEBkZXYgU3RvcHMgbWludHMsIHRyYW5zZmVycyBhbmQgcGF5bWVudHMuIFBhdXNlciByb2xlIChvciBhZG1pbikuCiAgICAgKiBAcGFyYW0gY2FsbGVyIFRoZSBwYXVzZXIgKG11c3Qgc2lnbikuAAAABXBhdXNlAAAAAAAAAQAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAC4qIEBkZXYgUmV0dXJucyB3aGV0aGVyIHRoZSBjb250cmFjdCBpcyBwYXVzZWQuAAAAAAAGcGF1c2VkAAAAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAAGsqIEBkZXYgUmVzdW1lcyBhY3Rpdml0eSBhZnRlciBwYXVzZSgpLiBQYXVzZXIgcm9sZSAob3IgYWRtaW4pLgogICAgICogQHBhcmFtIGNhbGxlciBUaGUgcGF1c2VyIChtdXN0IHNpZ24pLgAAAAAHdW5wYXVzZQAAAAABAAAAAAAAAAZjYWxsZXIAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAgAAAEUqIEB0aXRsZSBSb2xlCiAqIEBkZXYgQSBkZWxlZ2F0ZWQgcGVybWlzc2lvbjsgc2VlIHRoZSBtb2R1bGUgY29tbWVudC4AAAAAAAAAAAAABFJvbGUAAAAFAAAAAAAAAAAAAAAGTWludGVyAAAAAAAAAAAAAAAAAAdNYW5hZ2VyAAAAAAAAAAAAAAAABlBhdXNlcgAAAAAAAAAAAAAAAAAKQ29tcGxpYW5jZQAAAAAAAAAAAAAAAAAGT3JhY2xlAAA=",
+        "AAAABQAAAEQqIEB0aXRsZSBSb2xlR3JhbnRlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIHRoZSBhZG1pbiBncmFudHMgYSByb2xlLgAAAAAAAAALUm9sZUdyYW50ZWQAAAAAAQAAAAxyb2xlX2dyYW50ZWQAAAACAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAI=",
+        "AAAABQAAAEUqIEB0aXRsZSBSb2xlUmV2b2tlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIHRoZSBhZG1pbiByZXZva2VzIGEgcm9sZS4AAAAAAAAAAAAAC1JvbGVSZXZva2VkAAAAAAEAAAAMcm9sZV9yZXZva2VkAAAAAgAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAAC",
+        "AAAAAAAAAHkqIEBkZXYgUmV0dXJucyB3aGV0aGVyIGBhY2NvdW50YCB3YXMgZ3JhbnRlZCBgcm9sZWAuIChUaGUgYWRtaW4gaG9sZHMgZXZlcnkKICAgICAqIHJvbGUgaW1wbGljaXRseSB3aXRob3V0IGJlaW5nIGxpc3RlZC4pAAAAAAAACGhhc19yb2xlAAAAAgAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAE=",
+        "AAAAAAAAAC4qIEBkZXYgR3JhbnRzIGByb2xlYCB0byBgYWNjb3VudGAuIEFkbWluIG9ubHkuAAAAAAAKZ3JhbnRfcm9sZQAAAAAAAgAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAADQqIEBkZXYgVGFrZXMgYHJvbGVgIGF3YXkgZnJvbSBgYWNjb3VudGAuIEFkbWluIG9ubHkuAAAAC3Jldm9rZV9yb2xlAAAAAAIAAAAAAAAABHJvbGUAAAfQAAAABFJvbGUAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAABQAAAFEqIEB0aXRsZSBSb29tQWRkZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIHJvb20gaXMgYWRkZWQgdG8gYSBob3RlbCdzIGludmVudG9yeS4AAAAAAAAAAAAACVJvb21BZGRlZAAAAAAAAAEAAAAKcm9vbV9hZGRlZAAAAAAAAgAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAAAAAAAAEcm9vbQAAABAAAAAAAAAAAg==",
+        "AAAABQAAAFcqIEB0aXRsZSBSb29tUmV0aXJlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgcm9vbSBpcyB0YWtlbiBvdXQgb2YgYSBob3RlbCdzIGludmVudG9yeS4AAAAAAAAAAAtSb29tUmV0aXJlZAAAAAABAAAADHJvb21fcmV0aXJlZAAAAAIAAAAAAAAACGhvdGVsX2lkAAAABAAAAAEAAAAAAAAABHJvb20AAAAQAAAAAAAAAAI=",
+        "AAAAAAAAAL8qIEBkZXYgQWRkcyBhIHJvb20gdG8gYSBob3RlbCdzIGludmVudG9yeS4gTWFuYWdlciByb2xlIChvciBhZG1pbikuCiAgICAgKiBAcGFyYW0gY2FsbGVyIFRoZSBtYW5hZ2VyIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIHJvb20gVGhlIHJvb20ncyBuYW1lLCBlLmcuICJSb29tIDMwNSI7IHVuaXF1ZSB3aXRoaW4gdGhlIGhvdGVsLgAAAAAIYWRkX3Jvb20AAAADAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAAEcm9vbQAAABAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAG4qIEBkZXYgUmV0dXJucyB0aGUgcm9vbXMgaW4gYSBob3RlbCdzIGludmVudG9yeSAocmV0aXJlZCByb29tcyBleGNsdWRlZCksCiAgICAgKiBpbiB0aGUgb3JkZXIgdGhleSB3ZXJlIGFkZGVkLgAAAAAACmxpc3Rfcm9vbXMAAAAAAAEAAAAAAAAACGhvdGVsX2lkAAAABAAAAAEAAAPqAAAAEA==",
+        "AAAAAAAAAMsqIEBkZXYgVGFrZXMgYSByb29tIG91dCBvZiBhIGhvdGVsJ3MgaW52ZW50b3J5IGZvciBnb29kOiBubyBtb3JlIHRva2VucyBjYW4KICAgICAqIGJlIG1pbnRlZCBmb3IgaXQuIEV4aXN0aW5nIHRva2VucyBhcmUgdW5hZmZlY3RlZC4gTWFuYWdlciByb2xlIChvciBhZG1pbikuCiAgICAgKiBAcGFyYW0gY2FsbGVyIFRoZSBtYW5hZ2VyIChtdXN0IHNpZ24pLgAAAAALcmV0aXJlX3Jvb20AAAAAAwAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAABHJvb20AAAAQAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAXUqIEBkZXYgVHJhbnNmZXJzIGEgdG9rZW4gZXZlbiBpZiB0aGUgcmVjaXBpZW50IGlzIGEgc2luayBhZGRyZXNzLgogICAgICogUmVxdWlyZXMgdGhlIG93bmVyJ3Mgc2lnbmF0dXJlIEFORCB0aGUgYWRtaW4ncywgc28gYSBkZWxpYmVyYXRlIGJ1cm4tYnktdHJhbnNmZXIKICAgICAqIGlzIGFsd2F5cyBhIHR3by1wYXJ0eSBkZWNpc2lvbi4KICAgICAqIEBwYXJhbSBmcm9tIFRoZSBjdXJyZW50IG93bmVyJ3MgYWRkcmVzcyAod2hvIG11c3Qgc2lnbikuCiAgICAgKiBAcGFyYW0gdG8gVGhlIHJlY2lwaWVudCwgd2hpY2ggbWF5IGJlIGEgc2luayBhZGRyZXNzLgogICAgICogQHBhcmFtIHRva2VuX2lkIFRoZSBJRCBvZiB0aGUgdG9rZW4gdG8gdHJhbnNmZXIuAAAAAAAADmZvcmNlX3RyYW5zZmVyAAAAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEQqIEBkZXYgUmV0dXJucyB0cnVlIGlmIHRva2VucyB3b3VsZCBiZSByZWplY3RlZCB3aGVuIHNlbnQgdG8gYGFkZHJgLgAAAA9pc19zaW5rX2FkZHJlc3MAAAAAAQAAAAAAAAAEYWRkcgAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAFEqIEBkZXYgRmxhZ3MgYW4gYWRkcmVzcyBhcyBhIGtub3duIHNpbmsgdGhhdCBtdXN0IG5vdCByZWNlaXZlIHRva2Vucy4gQWRtaW4gb25seS4AAAAAAAAQYWRkX3NpbmtfYWRkcmVzcwAAAAEAAAAAAAAABGFkZHIAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAADkqIEBkZXYgUmVtb3ZlcyBhbiBhZGRyZXNzIGZyb20gdGhlIHNpbmsgbGlzdC4gQWRtaW4gb25seS4AAAAAAAATcmVtb3ZlX3NpbmtfYWRkcmVzcwAAAAABAAAAAAAAAARhZGRyAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAIQqIEBkZXYgUmV0dXJucyB0aGUgdG9rZW4gbWludGVkIGZvciBhIHdlZWsgb2YgYSByb29tLCBpZiB0aGVyZSBpcyBvbmUuCiAgICAgKiBAcGFyYW0geWVhciBUaGUgeWVhciwgb3IgTm9uZSBmb3IgdGhlIHBlcnBldHVhbCB0b2tlbi4AAAANdG9rZW5fYnlfc2xvdAAAAAAAAAQAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAAEcm9vbQAAABAAAAAAAAAABHdlZWsAAAAEAAAAAAAAAAR5ZWFyAAAD6AAAAAQAAAABAAAD6AAAAAY=",
+        "AAAAAQAAAGMqIEB0aXRsZSBUcnVzdAogKiBAZGV2IFRoZSBwYXJ0aWVzIG9mIGEgdG9rZW4gaGVsZCBpbiB0cnVzdC4gVGhlIHRydXN0ZWUgaXMgYWxzbyB0aGUgdG9rZW4ncyBvd25lci4AAAAAAAAAAAVUcnVzdAAAAAAAAAIAAAAAAAAAC2JlbmVmaWNpYXJ5AAAAABMAAAAAAAAAB3RydXN0ZWUAAAAAEw==",
+        "AAAABQAAAAAAAAAAAAAADFRydXN0Q3JlYXRlZAAAAAEAAAANdHJ1c3RfY3JlYXRlZAAAAAAAAAMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAAB3RydXN0ZWUAAAAAEwAAAAAAAAAAAAAAC2JlbmVmaWNpYXJ5AAAAABMAAAAAAAAAAg==",
+        "AAAAAQAAAFoqIEB0aXRsZSBQZW5kaW5nQmVuZWZpY2lhcnkKICogQGRldiBBIGJlbmVmaWNpYXJ5IGNoYW5nZSB3YWl0aW5nIGZvciBpdHMgdGltZWxvY2sgdG8gcGFzcy4AAAAAAAAAAAASUGVuZGluZ0JlbmVmaWNpYXJ5AAAAAAACAAAAAAAAAAtiZW5lZmljaWFyeQAAAAATAAAAAAAAAAxlZmZlY3RpdmVfYXQAAAAG",
+        "AAAABQAAAAAAAAAAAAAAEkJlbmVmaWNpYXJ5Q2hhbmdlZAAAAAAAAQAAABNiZW5lZmljaWFyeV9jaGFuZ2VkAAAAAAIAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAAC2JlbmVmaWNpYXJ5AAAAABMAAAAAAAAAAg==",
+        "AAAAAAAAADQqIEBkZXYgUmV0dXJucyB0aGUgdHJ1c3QgYSB0b2tlbiBpcyBoZWxkIGluLCBpZiBhbnkuAAAACWdldF90cnVzdAAAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPoAAAH0AAAAAVUcnVzdAAAAA==",
+        "AAAAAAAAAVgqIEBkZXYgUGxhY2VzIGEgdG9rZW4gaW4gdHJ1c3QuIFRpdGxlIG1vdmVzIHRvIHRoZSB0cnVzdGVlLCB3aG8gYmVjb21lcyB0aGUKICAgICAqIG93bmVyLCBhbmQgYSBUcmFuc2ZlciBldmVudCBpcyBwdWJsaXNoZWQuCiAgICAgKiBAcGFyYW0gb3duZXIgVGhlIGN1cnJlbnQgb3duZXIgKG11c3Qgc2lnbikuCiAgICAgKiBAcGFyYW0gdG9rZW5faWQgVGhlIHRva2VuIHRvIHBsYWNlIGluIHRydXN0LgogICAgICogQHBhcmFtIHRydXN0ZWUgVGhlIGFkZHJlc3MgdGhhdCB3aWxsIGhvbGQgdGl0bGUuCiAgICAgKiBAcGFyYW0gYmVuZWZpY2lhcnkgVGhlIGFkZHJlc3MgdGhlIHRva2VuIGlzIGhlbGQgZm9yLgAAAA5wbGFjZV9pbl90cnVzdAAAAAAABAAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAAB3RydXN0ZWUAAAAAEwAAAAAAAAALYmVuZWZpY2lhcnkAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAALoqIEBkZXYgUHJvcG9zZXMgYSBuZXcgYmVuZWZpY2lhcnkuIEl0IGNhbiBiZSBleGVjdXRlZCBvbmNlIEJFTkVGSUNJQVJZX1RJTUVMT0NLIGhhcyBwYXNzZWQuCiAgICAgKiBBIG5ldyBwcm9wb3NhbCByZXBsYWNlcyBhbnkgcGVuZGluZyBvbmUuCiAgICAgKiBAcGFyYW0gdHJ1c3RlZSBUaGUgdHJ1c3RlZSAobXVzdCBzaWduKS4AAAAAABNwcm9wb3NlX2JlbmVmaWNpYXJ5AAAAAAMAAAAAAAAAB3RydXN0ZWUAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAAtiZW5lZmljaWFyeQAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAQcqIEBkZXYgUmVjb3JkcyB0aGUgYmVuZWZpY2lhcnkncyBjb25zZW50IHRvIHRoZSB0cnVzdGVlIHRyYW5zZmVycmluZyB0aGUgdG9rZW4gdG8gYHRvYC4KICAgICAqIEBwYXJhbSBiZW5lZmljaWFyeSBUaGUgY3VycmVudCBiZW5lZmljaWFyeSAobXVzdCBzaWduKS4KICAgICAqIEBwYXJhbSB0b2tlbl9pZCBUaGUgdG9rZW4gaGVsZCBpbiB0cnVzdC4KICAgICAqIEBwYXJhbSB0byBUaGUgb25seSByZWNpcGllbnQgdGhlIHRydXN0ZWUgbWF5IHRyYW5zZmVyIHRvLgAAAAAUYWNrbm93bGVkZ2VfdHJhbnNmZXIAAAADAAAAAAAAAAtiZW5lZmljaWFyeQAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAAAnRvAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAFwqIEBkZXYgQXBwbGllcyBhIHBlbmRpbmcgYmVuZWZpY2lhcnkgY2hhbmdlIHdob3NlIHRpbWVsb2NrIGhhcyBwYXNzZWQuIEFueW9uZSBjYW4gY2FsbCB0aGlzLgAAABpleGVjdXRlX2JlbmVmaWNpYXJ5X2NoYW5nZQAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAQAAADsqIEB0aXRsZSBVc2FnZQogKiBAZGV2IEEgdG9rZW4ncyB1c2FnZSByZWNvcmQgZm9yIG9uZSB5ZWFyLgAAAAAAAAAABVVzYWdlAAAAAAAAAwAAAC5XaGV0aGVyIHRoZSBmcm9udCBkZXNrIGNvbmZpcm1lZCB0aGUgY2hlY2staW4uAAAAAAAJY29uZmlybWVkAAAAAAAAAQAAAAAAAAAFc3RhdGUAAAAAAAfQAAAAClVzYWdlU3RhdGUAAAAAACRUaGUgY2FsZW5kYXIgeWVhciB0aGUgcmVjb3JkIGlzIGZvci4AAAAEeWVhcgAAAAQ=",
+        "AAAABQAAAE4qIEB0aXRsZSBDaGVja2VkSW4KICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIGd1ZXN0IGNoZWNrcyBpbiBvbiBhIHRva2VuJ3Mgd2Vlay4AAAAAAAAAAAAJQ2hlY2tlZEluAAAAAAAAAQAAAApjaGVja2VkX2luAAAAAAACAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAVndWVzdAAAAAAAABMAAAAAAAAAAg==",
+        "AAAAAgAAAEIqIEB0aXRsZSBVc2FnZVN0YXRlCiAqIEBkZXYgV2hlcmUgYSB0b2tlbidzIHdlZWsgc3RhbmRzIHRoaXMgeWVhci4AAAAAAAAAAAAKVXNhZ2VTdGF0ZQAAAAAAAwAAAAAAAAAAAAAABlVudXNlZAAAAAAAAAAAAAAAAAAJQ2hlY2tlZEluAAAAAAAAAAAAAAAAAAAIQ29uc3VtZWQ=",
+        "AAAABQAAAF4qIEB0aXRsZSBDaGVja2VkT3V0CiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBndWVzdCBjaGVja3Mgb3V0LCB1c2luZyB1cCB0aGUgd2VlayBmb3IgdGhlIHllYXIuAAAAAAAAAAAACkNoZWNrZWRPdXQAAAAAAAEAAAALY2hlY2tlZF9vdXQAAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAFZ3Vlc3QAAAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAFQqIEB0aXRsZSBDaGVja0luQ29uZmlybWVkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gdGhlIGZyb250IGRlc2sgY29uZmlybXMgYSBjaGVjay1pbi4AAAAAAAAAEENoZWNrSW5Db25maXJtZWQAAAABAAAAEmNoZWNrX2luX2NvbmZpcm1lZAAAAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAACYnkAAAAAABMAAAAAAAAAAg==",
+        "AAAAAAAAADsqIEBkZXYgUmV0dXJucyBhIHRva2VuJ3MgdXNhZ2UgcmVjb3JkIGZvciB0aGUgY3VycmVudCB5ZWFyLgAAAAAFdXNhZ2UAAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6QAAB9AAAAAFVXNhZ2UAAAAAAAAD",
+        "AAAAAAAAALsqIEBkZXYgQ2hlY2tzIGluIG9uIHRoaXMgeWVhcidzIHdlZWsgb2YgYSB0b2tlbi4gRmFpbHMgd2l0aAogICAgICogSW52YWxpZFVzYWdlU3RhdGUgdW5sZXNzIHRoZSB3ZWVrIGlzIHVudXNlZCB0aGlzIHllYXIuCiAgICAgKiBAcGFyYW0gZ3Vlc3QgVGhlIG93bmVyLCBvciB0aGlzIHllYXIncyByZW50ZXIgKG11c3Qgc2lnbikuAAAAAAhjaGVja19pbgAAAAIAAAAAAAAABWd1ZXN0AAAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAM4qIEBkZXYgQ2hlY2tzIG91dCBvZiBhIGNvbmZpcm1lZCBzdGF5OyB0aGUgd2VlayBpcyB0aGVuIHVzZWQgdXAgZm9yIHRoZQogICAgICogeWVhci4gRmFpbHMgd2l0aCBDaGVja0luTm90Q29uZmlybWVkIGJlZm9yZSB0aGUgZnJvbnQgZGVzayBjb25maXJtZWQuCiAgICAgKiBAcGFyYW0gZ3Vlc3QgVGhlIGd1ZXN0IHdobyBjaGVja2VkIGluIChtdXN0IHNpZ24pLgAAAAAACWNoZWNrX291dAAAAAAAAAIAAAAAAAAABWd1ZXN0AAAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAH8qIEBkZXYgQ29uZmlybXMgYSBndWVzdCdzIGNoZWNrLWluIGF0IHRoZSBmcm9udCBkZXNrLiBNYW5hZ2VyIHJvbGUgKG9yIGFkbWluKS4KICAgICAqIEBwYXJhbSBjYWxsZXIgVGhlIGZyb250IGRlc2sgKG11c3Qgc2lnbikuAAAAABBjb25maXJtX2NoZWNrX2luAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAQAAAFMqIEB0aXRsZSBDaGFpbkxlZwogKiBAZGV2IE9uZSBvd25lcidzIHBhcnQgb2YgYSBzd2FwIGNoYWluOiB0aGUgdG9rZW4gdGhleSBnaXZlIHVwLgAAAAAAAAAACENoYWluTGVnAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAY=",
+        "AAAAAQAAAI0qIEB0aXRsZSBTd2FwQ2hhaW4KICogQGRldiBBIHByb3Bvc2VkIGN5Y2xlIG9mIHN3YXBzLiBFYWNoIGxlZydzIHRva2VuIGdvZXMgdG8gdGhlIG93bmVyIG9mIHRoZQogKiBuZXh0IGxlZywgYW5kIHRoZSBsYXN0IGxlZydzIHRvIHRoZSBmaXJzdC4AAAAAAAAAAAAACVN3YXBDaGFpbgAAAAAAAAIAAAA9V2hldGhlciBlYWNoIGxlZydzIHRva2VuIGlzIGVzY3Jvd2VkLCBpbiB0aGUgb3JkZXIgb2YgYGxlZ3NgLgAAAAAAAAZqb2luZWQAAAAAA+oAAAABAAAAAAAAAARsZWdzAAAD6gAAB9AAAAAIQ2hhaW5MZWc=",
+        "AAAABQAAAFcqIEB0aXRsZSBDaGFpbkpvaW5lZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGFuIG93bmVyIGVzY3Jvd3MgdGhlaXIgbGVnIG9mIGEgc3dhcCBjaGFpbi4AAAAAAAAAAAtDaGFpbkpvaW5lZAAAAAABAAAADGNoYWluX2pvaW5lZAAAAAIAAAAAAAAACGNoYWluX2lkAAAABAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAC",
+        "AAAABQAAAHsqIEB0aXRsZSBDaGFpblNldHRsZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiB0aGUgbGFzdCBsZWcgam9pbnMgYW5kIGV2ZXJ5IHRva2VuIG1vdmVzIG9uLCBuZXh0IHRvCiAqIHRoZWlyIFRyYW5zZmVyIGV2ZW50cy4AAAAAAAAAAAxDaGFpblNldHRsZWQAAAABAAAADWNoYWluX3NldHRsZWQAAAAAAAABAAAAAAAAAAhjaGFpbl9pZAAAAAQAAAABAAAAAg==",
+        "AAAABQAAAEcqIEB0aXRsZSBDaGFpblByb3Bvc2VkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBzd2FwIGNoYWluIGlzIHByb3Bvc2VkLgAAAAAAAAAADUNoYWluUHJvcG9zZWQAAAAAAAABAAAADmNoYWluX3Byb3Bvc2VkAAAAAAACAAAAAAAAAAhjaGFpbl9pZAAAAAQAAAABAAAAAAAAAARsZWdzAAAD6gAAB9AAAAAIQ2hhaW5MZWcAAAAAAAAAAg==",
+        "AAAABQAAAE4qIEB0aXRsZSBDaGFpbldpdGhkcmF3bgogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgcGFydHkgY2FsbHMgb2ZmIGEgc3dhcCBjaGFpbi4AAAAAAAAAAAAOQ2hhaW5XaXRoZHJhd24AAAAAAAEAAAAPY2hhaW5fd2l0aGRyYXduAAAAAAIAAAAAAAAACGNoYWluX2lkAAAABAAAAAEAAAAAAAAAAmJ5AAAAAAATAAAAAAAAAAI=",
+        "AAAAAAAAARgqIEBkZXYgRXNjcm93cyB0aGUgY2FsbGVyJ3MgbGVnIG9mIGEgc3dhcCBjaGFpbiwgc2V0dGxpbmcgdGhlIGNoYWluIGlmIGl0CiAgICAgKiB3YXMgdGhlIGxhc3QuIEpvaW5pbmcgdHdpY2UgZG9lcyBub3RoaW5nLiBGYWlscyB3aXRoIE5vU3dhcENoYWluIGZvciBhbgogICAgICogdW5rbm93biBvciBjbG9zZWQgY2hhaW4sIGFuZCBOb3RPd25lciB1bmxlc3MgdGhlIGNhbGxlciBoYXMgYSBsZWcuCiAgICAgKiBAcGFyYW0gb3duZXIgVGhlIG93bmVyIG9mIHRoZSBsZWcgKG11c3Qgc2lnbikuAAAACmpvaW5fY2hhaW4AAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIY2hhaW5faWQAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEAqIEBkZXYgUmV0dXJucyBhIHN3YXAgY2hhaW4sIHVubGVzcyBpdCBzZXR0bGVkIG9yIHdhcyB3aXRoZHJhd24uAAAACnN3YXBfY2hhaW4AAAAAAAEAAAAAAAAACGNoYWluX2lkAAAABAAAAAEAAAPoAAAH0AAAAAlTd2FwQ2hhaW4AAAA=",
+        "AAAAAAAAAFkqIEBkZXYgUmV0dXJucyBob3cgbWFueSBzd2FwIGNoYWlucyBoYXZlIGJlZW4gcHJvcG9zZWQsIGkuZS4gdGhlIGhpZ2hlc3QKICAgICAqIGNoYWluIElELgAAAAAAAAtjaGFpbl9jb3VudAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAV0qIEBkZXYgUHJvcG9zZXMgYSBzd2FwIGNoYWluIGFuZCBqb2lucyB0aGUgcHJvcG9zZXIncyBsZWcuIEZhaWxzIHdpdGgKICAgICAqIEludmFsaWRTd2FwIHVubGVzcyB0aGVyZSBhcmUgMiB0byBNQVhfQ0hBSU5fTEVHUyBsZWdzIG9mIGRpc3RpbmN0IG93bmVycwogICAgICogYW5kIHRva2VucywgYW5kIE5vdE93bmVyIHVubGVzcyB0aGUgcHJvcG9zZXIgaGFzIGEgbGVnIGFuZCBldmVyeSBvd25lcgogICAgICogaG9sZHMgdGhlaXIgdG9rZW4uCiAgICAgKiBAcGFyYW0gcHJvcG9zZXIgVGhlIG93bmVyIG9mIG9uZSBvZiB0aGUgbGVncyAobXVzdCBzaWduKS4KICAgICAqIEByZXR1cm4gVGhlIGNoYWluJ3MgSUQuAAAAAAAADXByb3Bvc2VfY2hhaW4AAAAAAAACAAAAAAAAAAhwcm9wb3NlcgAAABMAAAAAAAAABGxlZ3MAAAPqAAAH0AAAAAhDaGFpbkxlZwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAMgqIEBkZXYgQ2FsbHMgb2ZmIGEgc3dhcCBjaGFpbiwgcmV0dXJuaW5nIGV2ZXJ5IGVzY3Jvd2VkIHRva2VuIHRvIGl0cwogICAgICogb3duZXIuIEZhaWxzIHdpdGggTm9Td2FwQ2hhaW4gZm9yIGFuIHVua25vd24gb3IgY2xvc2VkIGNoYWluLgogICAgICogQHBhcmFtIGNhbGxlciBUaGUgb3duZXIgb2Ygb25lIG9mIHRoZSBsZWdzIChtdXN0IHNpZ24pLgAAAA53aXRoZHJhd19jaGFpbgAAAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAhjaGFpbl9pZAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAQAAAFsqIEB0aXRsZSBDb250cmFjdENvbmZpZwogKiBAZGV2IFNuYXBzaG90IG9mIGV2ZXJ5dGhpbmcgdGhhdCBzaGFwZXMgdGhlIGNvbnRyYWN0J3MgYmVoYXZpb3IuAAAAAAAAAAAOQ29udHJhY3RDb25maWcAAAAAAB8AAAATVGhlIGhvdGVsIG9wZXJhdG9yLgAAAAAFYWRtaW4AAAAAAAATAAAAMlNlY29uZHMgYmVmb3JlIGEgdHJ1c3QgYmVuZWZpY2lhcnkgY2hhbmdlIGFwcGxpZXMuAAAAAAAUYmVuZWZpY2lhcnlfdGltZWxvY2sAAAAGAAAALERpc3BsYXkgc2V0dGluZ3Mgb2YgcHJvcGVydGllcywgYnkgaG90ZWwgSUQuAAAACWJyYW5kaW5ncwAAAAAAA+wAAAAEAAAH0AAAAAhCcmFuZGluZwAAAC1PcGVuIGJ1eWJhY2tzLCBpbiB0aGUgb3JkZXIgdGhleSB3ZXJlIG9wZW5lZC4AAAAAAAAIYnV5YmFja3MAAAPqAAAH0AAAAAdCdXliYWNrAAAAAD9DaGFyaXR5IHNwbGl0cyBvZiByb3lhbHRpZXMsIGJ5IGhvdGVsIElEIChzZWUgYGNoYXJpdHlfc3BsaXRgKS4AAAAADmNoYXJpdHlfc3BsaXRzAAAAAAPsAAAABAAAA+oAAAPtAAAAAgAAABMAAAAEAAAARFRoZSBjb29saW5nLW9mZiB0ZXJtcyBvZiBuZXcgcHJpbWFyeSBzYWxlczsgYSBwZXJpb2Qgb2YgMCB3aGlsZSBvZmYuAAAAC2Nvb2xpbmdfb2ZmAAAAB9AAAAAKQ29vbGluZ09mZgAAAAAAJ0RlcHJlY2F0ZWQgZW5kcG9pbnRzIGFuZCB0aGVpciBzdW5zZXRzLgAAAAAMZGVwcmVjYXRpb25zAAAD7AAAABEAAAfQAAAAC0RlcHJlY2F0aW9uAAAAADZIb3cgdGhlIG1haW50ZW5hbmNlIGZlZSBmb2xsb3dzIGFuIGluZGV4LCBvbmNlIGxpbmtlZC4AAAAAAAlmZWVfaW5kZXgAAAAAAAfQAAAAC0ZlZUluZGV4aW5nAAAAAEFQcm9wZXJ0aWVzIHdob3NlIHNhbGVzIHRoZSBob3RlbCBoYXMgYSByaWdodCBvZiBmaXJzdCByZWZ1c2FsIG9uLgAAAAAAABRmaXJzdF9yZWZ1c2FsX2hvdGVscwAAA+oAAAAEAAAAOkdyYWNlIHBlcmlvZHMgaW4gZm9yY2UsIGluIHNlY29uZHMgYnkgcnVsZSAoc2VlIGBwb2xpY3lgKS4AAAAAAA1ncmFjZV9wZXJpb2RzAAAAAAAD7AAAB9AAAAAJR3JhY2VSdWxlAAAAAAAABgAAAEdIaWdoZXN0IHRva2VuIElEIHJlc2VydmVkIHNvIGZhcjsgSURzIGFib3ZlIGl0IGFyZSB1bnVzZWQgKHNlZSBpZHMucnMpLgAAAAANbGFzdF90b2tlbl9pZAAAAAAAAAYAAAA1VGhlIGxhdGUgZmVlIHBvbGljeSBvZiBvdmVyZHVlIG1haW50ZW5hbmNlLCBvbmNlIHNldC4AAAAAAAAJbGF0ZV9mZWVzAAAAAAAH0AAAAAhMYXRlRmVlcwAAAC1Mb2NhbGVzIHdpdGggbG9jYWxpemVkIGxhYmVscyAoc2VlIGBsYWJlbHNgKS4AAAAAAAAHbG9jYWxlcwAAAAPqAAAAEQAAAC5UaGUgYW5udWFsIG1haW50ZW5hbmNlIGZlZSBzY2hlZHVsZSwgb25jZSBzZXQuAAAAAAALbWFpbnRlbmFuY2UAAAAH0AAAABJNYWludGVuYW5jZUJpbGxpbmcAAAAAACtNaW5vciB1bml0cyBvZiBwYXltZW50IGFzc2V0cywgYnkgaG90ZWwgSUQuAAAAAAttaW5vcl91bml0cwAAAAPsAAAABAAAA+wAAAATAAAACwAAADFXaGV0aGVyIG1pbnRzLCB0cmFuc2ZlcnMgYW5kIHBheW1lbnRzIGFyZSBwYXVzZWQuAAAAAAAABnBhdXNlZAAAAAAAAQAAAGJUaGUgcmV2ZW51ZSBzcGxpdDogZWFjaCBwYXllZSBhbmQgaXRzIHNoYXJlIGluIGJhc2lzIHBvaW50czsgZW1wdHkKd2hpbGUgcHJvY2VlZHMgZ28gdG8gdGhlIGFkbWluLgAAAAAABnBheWVlcwAAAAAD6gAAA+0AAAACAAAAEwAAAAQAAAAxUHJvcG9zZWQgbmV4dCBhZG1pbiwgd2hpbGUgYSBoYW5kb3ZlciBpcyBwZW5kaW5nLgAAAAAAAA1wZW5kaW5nX2FkbWluAAAAAAAD6AAAABMAAAA3U2Vjb25kcyBhIHJlY2lwaWVudCBoYXMgdG8gYWNjZXB0IGEgdHdvLXBoYXNlIHRyYW5zZmVyLgAAAAAXcGVuZGluZ190cmFuc2Zlcl93aW5kb3cAAAAABgAAAFRNYWludGVuYW5jZSBwcmVwYXltZW50IGRpc2NvdW50cyBpbiBiYXNpcyBwb2ludHMsIGVudHJ5IG4gLSAxIGZvcgpwcmVwYXlpbmcgbiB5ZWFycy4AAAAQcHJlcGF5X2Rpc2NvdW50cwAAA+oAAAAEAAAAMldoZXRoZXIgc2FsZXMgYW5kIHRyYWRlcyBhcmUgcmVjb3JkZWQgYXMgcmVjZWlwdHMuAAAAAAAIcmVjZWlwdHMAAAABAAAAKFRoZSByZW50IHBvb2wncyBwb2xpY3ksIG9uY2UgY29uZmlndXJlZC4AAAAJcmVudF9wb29sAAAAAAAH0AAAAAhSZW50UG9vbAAAAEhTaGFyZXMgb2YgbWFpbnRlbmFuY2UgZmVlcyBoZWxkIGluIHJlc2VydmUsIGluIGJhc2lzIHBvaW50cyBieSBob3RlbCBJRC4AAAAOcmVzZXJ2ZV9zaGFyZXMAAAAAA+wAAAAEAAAABAAAADVMYXlvdXQgdmVyc2lvbiBvZiB0aGUgc3RvcmVkIGRhdGEgKHNlZSBtaWdyYXRpb24ucnMpLgAAAAAAAA5zY2hlbWFfdmVyc2lvbgAAAAAABAAAAD9Ib3cgZXhhY3QgdGhlIHB1YmxpYyBzdGF0cyBlbmRwb2ludHMgYXJlIChzZWUgYHN0YXRzX3ByaXZhY3lgKS4AAAAADXN0YXRzX3ByaXZhY3kAAAAAAAfQAAAADFN0YXRzUHJpdmFjeQAAABlTdXBwbHkgY2FwcywgYnkgaG90ZWwgSUQuAAAAAAAAC3N1cHBseV9jYXBzAAAAA+wAAAAEAAAABAAAADlUVEwsIGluIGxlZGdlcnMsIHRob3NlIGF1dG9tYXRpYyBidW1wcyBleHRlbmQgYSB0b2tlbiB0by4AAAAAAAATdG9rZW5fdHRsX2V4dGVuZF90bwAAAAAEAAAARVRUTCwgaW4gbGVkZ2VycywgdW5kZXIgd2hpY2ggbWludHMsIHRyYW5zZmVycyBhbmQgcmVhZHMgYnVtcCBhIHRva2VuLgAAAAAAABN0b2tlbl90dGxfdGhyZXNob2xkAAAAAAQAAAAjQ3JhdGUgdmVyc2lvbiBvZiB0aGUgcnVubmluZyBidWlsZC4AAAAAB3ZlcnNpb24AAAAAEAAAAD9UaGUgZWQyNTUxOSBrZXkgc2FsZSB2b3VjaGVycyBhcmUgc2lnbmVkIHdpdGgsIG9uY2UgcmVnaXN0ZXJlZC4AAAAADnZvdWNoZXJfc2lnbmVyAAAAAAPoAAAD7gAAACAAAAAuVGF4IHdpdGhob2xkaW5nIHJ1bGVzIG9mIHJlc2FsZXMsIGJ5IGhvdGVsIElELgAAAAAADHdpdGhob2xkaW5ncwAAA+wAAAAEAAAH0AAAAA9XaXRoaG9sZGluZ1J1bGUA",
+        "AAAAAAAAAJQqIEBkZXYgUmV0dXJucyB0aGUgZnVsbCBlZmZlY3RpdmUgY29uZmlndXJhdGlvbiBvZiB0aGlzIGRlcGxveW1lbnQuCiAgICAgKiBAcmV0dXJuIFRoZSBDb250cmFjdENvbmZpZyBzdHJ1Y3QsIG9yIE5vdEluaXRpYWxpemVkIGJlZm9yZSBpbml0aWFsaXplKCkuAAAABmNvbmZpZwAAAAAAAAAAAAEAAAPpAAAH0AAAAA5Db250cmFjdENvbmZpZwAAAAAAAw==",
+        "AAAAAQAAAEcqIEB0aXRsZSBUcmFkZQogKiBAZGV2IEFuIG9wZW4gdHJhZGUsIGtleWVkIGJ5IHRoZSBlc2Nyb3dlZCB0b2tlbidzIElELgAAAAAAAAAABVRyYWRlAAAAAAAAAwAAAC5XaG8gbWF5IGFjY2VwdCwgYnkgZ2l2aW5nIHVwIHRoZSB3YW50ZWQgdG9rZW4uAAAAAAAMY291bnRlcnBhcnR5AAAAEwAAADRXaG8gZXNjcm93ZWQgdGhlIHRva2VuLCBhbmQgcmVjZWl2ZXMgdGhlIHdhbnRlZCBvbmUuAAAABW93bmVyAAAAAAAAEwAAAAAAAAAPd2FudGVkX3Rva2VuX2lkAAAAAAY=",
+        "AAAABQAAAFEqIEB0aXRsZSBUcmFkZU9wZW5lZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGFuIG93bmVyIGVzY3Jvd3MgYSB0b2tlbiBmb3IgYSB0cmFkZS4AAAAAAAAAAAAAC1RyYWRlT3BlbmVkAAAAAAEAAAAMdHJhZGVfb3BlbmVkAAAABAAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAAAAAAMY291bnRlcnBhcnR5AAAAEwAAAAAAAAAAAAAAD3dhbnRlZF90b2tlbl9pZAAAAAAGAAAAAAAAAAI=",
+        "AAAABQAAAE8qIEB0aXRsZSBUcmFkZUFjY2VwdGVkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBjb3VudGVycGFydHkgY29tcGxldGVzIGEgdHJhZGUuAAAAAAAAAAANVHJhZGVBY2NlcHRlZAAAAAAAAAEAAAAOdHJhZGVfYWNjZXB0ZWQAAAAAAAQAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAAD3dhbnRlZF90b2tlbl9pZAAAAAAGAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAAAAAAMY291bnRlcnBhcnR5AAAAEwAAAAAAAAAC",
+        "AAAABQAAAE4qIEB0aXRsZSBUcmFkZVdpdGhkcmF3bgogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGVpdGhlciBwYXJ0eSBjYWxscyBvZmYgYSB0cmFkZS4AAAAAAAAAAAAOVHJhZGVXaXRoZHJhd24AAAAAAAEAAAAPdHJhZGVfd2l0aGRyYXduAAAAAAIAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAAAmJ5AAAAAAATAAAAAAAAAAI=",
+        "AAAAAAAAADMqIEBkZXYgUmV0dXJucyB0aGUgb3BlbiB0cmFkZSBvZiBhbiBlc2Nyb3dlZCB0b2tlbi4AAAAABXRyYWRlAAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAfQAAAABVRyYWRlAAAA",
+        "AAAAAAAAAPAqIEBkZXYgRXNjcm93cyBhIHRva2VuIGluIGV4Y2hhbmdlIGZvciBhbm90aGVyLiBGYWlscyB3aXRoIEludmFsaWRTd2FwIGlmCiAgICAgKiB0aGUgdG9rZW4gb3IgdGhlIHBhcnR5IGlzIHRoZSBzYW1lIG9uIGJvdGggc2lkZXMuCiAgICAgKiBAcGFyYW0gb3duZXIgVGhlIGN1cnJlbnQgb3duZXIgKG11c3Qgc2lnbikuCiAgICAgKiBAcGFyYW0gY291bnRlcnBhcnR5IFRoZSBob2xkZXIgb2YgdGhlIHdhbnRlZCB0b2tlbi4AAAAKb3Blbl90cmFkZQAAAAAABAAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAADGNvdW50ZXJwYXJ0eQAAABMAAAAAAAAAD3dhbnRlZF90b2tlbl9pZAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAANIqIEBkZXYgQ29tcGxldGVzIGEgdHJhZGU6IHRoZSB3YW50ZWQgdG9rZW4gZ29lcyB0byB0aGUgb3BlbmVyIGFuZCB0aGUKICAgICAqIGVzY3Jvd2VkIG9uZSB0byB0aGUgY291bnRlcnBhcnR5LgogICAgICogQHBhcmFtIGNvdW50ZXJwYXJ0eSBUaGUgdHJhZGUncyBjb3VudGVycGFydHkgKG11c3Qgc2lnbik7IG11c3QgaG9sZCB0aGUKICAgICAqIHdhbnRlZCB0b2tlbi4AAAAAAAxhY2NlcHRfdHJhZGUAAAACAAAAAAAAAAxjb3VudGVycGFydHkAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAIYqIEBkZXYgQ2FsbHMgb2ZmIGEgdHJhZGUsIHJldHVybmluZyB0aGUgZXNjcm93ZWQgdG9rZW4gdG8gdGhlIG9wZW5lci4KICAgICAqIEBwYXJhbSBjYWxsZXIgVGhlIG9wZW5lciBvciB0aGUgY291bnRlcnBhcnR5IChtdXN0IHNpZ24pLgAAAAAADndpdGhkcmF3X3RyYWRlAAAAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAABQAAADgqIEB0aXRsZSBNaW50CiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSB0b2tlbiBpcyBjcmVhdGVkLgAAAAAAAAAETWludAAAAAEAAAAEbWludAAAAAMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAAAAAABGluZm8AAAfQAAAADVRpbWVzaGFyZUluZm8AAAAAAAAAAAAAAg==",
+        "AAAABQAAAGAqIEB0aXRsZSBUcmFuc2ZlcgogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuZXZlciBhIHRva2VuIGNoYW5nZXMgb3duZXIsIHdoaWNoZXZlciBlbmRwb2ludCBtb3ZlZCBpdC4AAAAAAAAACFRyYW5zZmVyAAAAAQAAAAh0cmFuc2ZlcgAAAAMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAABGZyb20AAAATAAAAAAAAAAAAAAACdG8AAAAAABMAAAAAAAAAAg==",
+        "AAAAAQAAAHIqIEB0aXRsZSBFdmVudENoYWluCiAqIEBkZXYgSGVhZCBvZiB0aGUgZXZlbnQgaGFzaCBjaGFpbiAob3Igb2Ygb25lIGxhbmUpIGFuZCB0aGUgbnVtYmVyIG9mIGV2ZW50cyBmb2xkZWQgaW50byBpdC4AAAAAAAAAAAAKRXZlbnRDaGFpbgAAAAAAAgAAAAAAAAAFY291bnQAAAAAAAAGAAAAAAAAAARoZWFkAAAD7gAAACA=",
+        "AAAABQAAAAAAAAAAAAAAEkRhaWx5Um9vdENvbW1pdHRlZAAAAAAAAQAAABRkYWlseV9yb290X2NvbW1pdHRlZAAAAAMAAAAAAAAAA2RheQAAAAAGAAAAAQAAAAAAAAAEcm9vdAAAA+4AAAAgAAAAAAAAAAAAAAAFY291bnQAAAAAAAAGAAAAAAAAAAI=",
+        "AAAAAAAAADoqIEBkZXYgUmV0dXJucyB0aGUgY2hhaW4gaGVhZCBjb21taXR0ZWQgZm9yIGBkYXlgLCBpZiBhbnkuAAAAAAAKZGFpbHlfcm9vdAAAAAAAAQAAAAAAAAADZGF5AAAAAAYAAAABAAAD6AAAB9AAAAAKRXZlbnRDaGFpbgAA",
+        "AAAAAAAAADoqIEBkZXYgUmV0dXJucyB0aGUgbGl2ZSBoZWFkIG9mIGV2ZXJ5IGxhbmUsIGluIGxhbmUgb3JkZXIuAAAAAAALZXZlbnRfbGFuZXMAAAAAAAAAAAEAAAPqAAAH0AAAAApFdmVudENoYWluAAA=",
+        "AAAAAAAAAHwqIEBkZXYgUmV0dXJucyB0aGUgbGFuZSBoZWFkcyBjb21taXR0ZWQgZm9yIGBkYXlgLCBpbiBsYW5lIG9yZGVyLCBpZiBhbnkuCiAgICAgKiBSZXBsYXlpbmcgYSBkYXkncyBldmVudHMgc3RhcnRzIGZyb20gdGhlc2UuAAAAEGRhaWx5X3Jvb3RfbGFuZXMAAAABAAAAAAAAAANkYXkAAAAABgAAAAEAAAPoAAAD6gAAA+4AAAAg",
+        "AAAAAAAAADEqIEBkZXYgUmV0dXJucyB0aGUgbGl2ZSAodW5jb21taXR0ZWQpIGNoYWluIGhlYWQuAAAAAAAAEGV2ZW50X2NoYWluX2hlYWQAAAAAAAAAAQAAB9AAAAAKRXZlbnRDaGFpbgAA",
+        "AAAAAAAAAMQqIEBkZXYgU25hcHNob3RzIHRoZSBjdXJyZW50IGV2ZW50IGNoYWluIGhlYWQgdW5kZXIgdG9kYXkncyBkYXkgbnVtYmVyCiAgICAgKiAobGVkZ2VyIHRpbWVzdGFtcCAvIERBWV9TRUNPTkRTKS4gQW55b25lIGNhbiBjYWxsIHRoaXMsIGF0IG1vc3Qgb25jZSBwZXIgZGF5LgogICAgICogQHJldHVybiBUaGUgY29tbWl0dGVkIGRheSBudW1iZXIuAAAAEWNvbW1pdF9kYWlseV9yb290AAAAAAAAAAAAAAEAAAPpAAAABgAAAAM=",
+        "AAAABQAAAEYqIEB0aXRsZSBUb2tlbkZyb3plbgogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIHRoZSBhZG1pbiBmcmVlemVzIGEgdG9rZW4uAAAAAAAAAAAAC1Rva2VuRnJvemVuAAAAAAEAAAAMdG9rZW5fZnJvemVuAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAI=",
+        "AAAABQAAAEoqIEB0aXRsZSBUb2tlblVuZnJvemVuCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gdGhlIGFkbWluIHVuZnJlZXplcyBhIHRva2VuLgAAAAAAAAAAAA1Ub2tlblVuZnJvemVuAAAAAAAAAQAAAA50b2tlbl91bmZyb3plbgAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAI=",
+        "AAAAAAAAAE8qIEBkZXYgRnJlZXplcyBhIHRva2VuLiBBZG1pbiBvbmx5LgogICAgICogQHBhcmFtIHRva2VuX2lkIFRoZSB0b2tlbiB0byBmcmVlemUuAAAAAAZmcmVlemUAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAFgqIEBkZXYgTGlmdHMgYSB0b2tlbidzIGZyZWV6ZS4gQWRtaW4gb25seS4KICAgICAqIEBwYXJhbSB0b2tlbl9pZCBUaGUgdG9rZW4gdG8gdW5mcmVlemUuAAAACHVuZnJlZXplAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAACkqIEBkZXYgUmV0dXJucyB3aGV0aGVyIGEgdG9rZW4gaXMgZnJvemVuLgAAAAAAAAlpc19mcm96ZW4AAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAQ==",
+        "AAAAAQAAAC0qIEB0aXRsZSBIb3RlbAogKiBAZGV2IEEgcmVnaXN0ZXJlZCBwcm9wZXJ0eS4AAAAAAAAAAAAABUhvdGVsAAAAAAAAAgAAAAAAAAACaWQAAAAAAAQAAAAAAAAABG5hbWUAAAAQ",
+        "AAAABQAAAEsqIEB0aXRsZSBIb3RlbFVwZGF0ZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIHJlZ2lzdGVyZWQgaG90ZWwgaXMgcmVuYW1lZC4AAAAAAAAAAAxIb3RlbFVwZGF0ZWQAAAABAAAADWhvdGVsX3VwZGF0ZWQAAAAAAAACAAAAAAAAAAhob3RlbF9pZAAAAAQAAAABAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAC",
+        "AAAABQAAAFEqIEB0aXRsZSBIb3RlbFJlZ2lzdGVyZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIGhvdGVsIGlzIGFkZGVkIHRvIHRoZSByZWdpc3RyeS4AAAAAAAAAAAAAD0hvdGVsUmVnaXN0ZXJlZAAAAAABAAAAEGhvdGVsX3JlZ2lzdGVyZWQAAAACAAAAAAAAAAhob3RlbF9pZAAAAAQAAAABAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAC",
+        "AAAAAAAAACIqIEBkZXYgUmV0dXJucyBhIHJlZ2lzdGVyZWQgaG90ZWwuAAAAAAAJZ2V0X2hvdGVsAAAAAAAAAQAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAA+kAAAfQAAAABUhvdGVsAAAAAAAAAw==",
+        "AAAAAAAAANcqIEBkZXYgTGlzdHMgcmVnaXN0ZXJlZCBob3RlbHMgd2l0aCBJRHMgYWJvdmUgYHN0YXJ0X2FmdGVyYCwgaW4gSUQgb3JkZXIuCiAgICAgKiBAcGFyYW0gc3RhcnRfYWZ0ZXIgMCBmb3IgdGhlIGZpcnN0IHBhZ2UsIHRoZW4gdGhlIGxhc3QgSUQgb2YgdGhlIHByZXZpb3VzIHBhZ2UuCiAgICAgKiBAcGFyYW0gbGltaXQgVGhlIHBhZ2Ugc2l6ZSwgY2FwcGVkIGF0IE1BWF9QQUdFLgAAAAALbGlzdF9ob3RlbHMAAAAAAgAAAAAAAAALc3RhcnRfYWZ0ZXIAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAABUhvdGVsAAAA",
+        "AAAAAAAAAIsqIEBkZXYgUmVuYW1lcyBhIHJlZ2lzdGVyZWQgaG90ZWwuIE1hbmFnZXIgcm9sZSAob3IgYWRtaW4pLgogICAgICogQHBhcmFtIGNhbGxlciBUaGUgbWFuYWdlciAobXVzdCBzaWduKS4KICAgICAqIEByZXR1cm4gVGhlIHVwZGF0ZWQgaG90ZWwuAAAAAAx1cGRhdGVfaG90ZWwAAAADAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAAEbmFtZQAAABAAAAABAAAD6QAAB9AAAAAFSG90ZWwAAAAAAAAD",
+        "AAAAAAAAANMqIEBkZXYgQWRkcyBhIGhvdGVsIHRvIHRoZSByZWdpc3RyeS4gTWFuYWdlciByb2xlIChvciBhZG1pbikuCiAgICAgKiBAcGFyYW0gY2FsbGVyIFRoZSBtYW5hZ2VyIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIG5hbWUgVGhlIGhvdGVsJ3MgbmFtZSwgdW5pcXVlIGFtb25nIHJlZ2lzdGVyZWQgaG90ZWxzLgogICAgICogQHJldHVybiBUaGUgbmV3IGhvdGVsJ3MgSUQuAAAAAA5yZWdpc3Rlcl9ob3RlbAAAAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAARuYW1lAAAAEAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAABQAAAFoqIEB0aXRsZSBTYWxlCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBsaXN0ZWQgdG9rZW4gaXMgYm91Z2h0LCBuZXh0IHRvIGl0cyBUcmFuc2ZlciBldmVudC4AAAAAAAAAAAAEU2FsZQAAAAEAAAAEc2FsZQAAAAUAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAEAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAEcqIEB0aXRsZSBMaXN0ZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhbiBvd25lciBsaXN0cyBhIHRva2VuIGZvciBzYWxlLgAAAAAAAAAABkxpc3RlZAAAAAAAAQAAAAZsaXN0ZWQAAAAAAAQAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAAAAAAI=",
+        "AAAAAQAAADIqIEB0aXRsZSBMaXN0aW5nCiAqIEBkZXYgQSB0b2tlbiBvZmZlcmVkIGZvciBzYWxlLgAAAAAAAAAAAAdMaXN0aW5nAAAAAAMAAAAbVG9rZW4gdGhlIHByaWNlIGlzIHBhaWQgaW4uAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAAAAAAFcHJpY2UAAAAAAAALAAAAM1RoZSBvd25lciB3aG8gbGlzdGVkIHRoZSB0b2tlbiwgYW5kIGlzIHBhaWQgZm9yIGl0LgAAAAAGc2VsbGVyAAAAAAAT",
+        "AAAABQAAAE4qIEB0aXRsZSBMaXN0aW5nQ2FuY2VsbGVkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBzZWxsZXIgd2l0aGRyYXdzIGEgbGlzdGluZy4AAAAAAAAAAAAQTGlzdGluZ0NhbmNlbGxlZAAAAAEAAAARbGlzdGluZ19jYW5jZWxsZWQAAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAg==",
+        "AAAAAAAAALkqIEBkZXYgQnV5cyBhIGxpc3RlZCB0b2tlbjogcGF5cyB0aGUgc2VsbGVyIGl0cyBwcmljZSBhbmQgdGFrZXMgb3duZXJzaGlwLgogICAgICogRmFpbHMgd2l0aCBOb3RPd25lciBpZiB0aGUgc2VsbGVyIG5vIGxvbmdlciBvd25zIGl0LgogICAgICogQHBhcmFtIGJ1eWVyIFRoZSBidXllciAobXVzdCBzaWduIGFuZCBwYXkpLgAAAAAAAANidXkAAAAAAgAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAADQqIEBkZXYgUmV0dXJucyBhIHRva2VuJ3MgbGlzdGluZywgaWYgaXQgaXMgZm9yIHNhbGUuAAAAB2xpc3RpbmcAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAfQAAAAB0xpc3RpbmcA",
+        "AAAAAAAAAPsqIEBkZXYgTGlzdHMgYSB0b2tlbiBmb3Igc2FsZSwgcmVwbGFjaW5nIGFueSBlYXJsaWVyIGxpc3Rpbmcgb2YgaXQuCiAgICAgKiBAcGFyYW0gb3duZXIgVGhlIGN1cnJlbnQgb3duZXIgKG11c3Qgc2lnbik7IHJlY2VpdmVzIHRoZSBwcmljZS4KICAgICAqIEBwYXJhbSBwcmljZSBUaGUgcHJpY2U7IG11c3Qgbm90IGJlIG5lZ2F0aXZlLgogICAgICogQHBhcmFtIHBheW1lbnRfdG9rZW4gVGhlIHRva2VuIHRoZSBwcmljZSBpcyBwYWlkIGluLgAAAAANbGlzdF9mb3Jfc2FsZQAAAAAAAAQAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAFYqIEBkZXYgV2l0aGRyYXdzIGEgdG9rZW4ncyBsaXN0aW5nLgogICAgICogQHBhcmFtIG93bmVyIFRoZSBjdXJyZW50IG93bmVyIChtdXN0IHNpZ24pLgAAAAAADmNhbmNlbF9saXN0aW5nAAAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAQAAADwqIEB0aXRsZSBPZmZlcgogKiBAZGV2IEEgYnV5ZXIncyBlc2Nyb3dlZCBvZmZlciBmb3IgYSB0b2tlbi4AAAAAAAAABU9mZmVyAAAAAAAAAwAAAAAAAAAGYW1vdW50AAAAAAALAAAAOUxlZGdlciB0aW1lc3RhbXAgYWZ0ZXIgd2hpY2ggdGhlIG9mZmVyIGNhbid0IGJlIGFjY2VwdGVkLgAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAHFRva2VuIHRoZSBhbW91bnQgaXMgcGFpZCBpbi4AAAANcGF5bWVudF90b2tlbgAAAAAAABM=",
+        "AAAABQAAAE0qIEB0aXRsZSBPZmZlck1hZGUKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIGJ1eWVyIG1ha2VzIG9yIHJlcGxhY2VzIGFuIG9mZmVyLgAAAAAAAAAAAAAJT2ZmZXJNYWRlAAAAAAAAAQAAAApvZmZlcl9tYWRlAAAAAAAFAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAVidXllcgAAAAAAABMAAAABAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAAAAAAAAg==",
+        "AAAABQAAAGQqIEB0aXRsZSBPZmZlckFjY2VwdGVkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYW4gb3duZXIgYWNjZXB0cyBhbiBvZmZlciwgbmV4dCB0byBpdHMgVHJhbnNmZXIgZXZlbnQuAAAAAAAAAA1PZmZlckFjY2VwdGVkAAAAAAAAAQAAAA5vZmZlcl9hY2NlcHRlZAAAAAAABAAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAEsqIEB0aXRsZSBPZmZlckNhbmNlbGxlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgYnV5ZXIgdGFrZXMgYmFjayBhbiBvZmZlci4AAAAAAAAAAA5PZmZlckNhbmNlbGxlZAAAAAAAAQAAAA9vZmZlcl9jYW5jZWxsZWQAAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAAAI=",
+        "AAAAAAAAAEIqIEBkZXYgUmV0dXJucyBhIGJ1eWVyJ3Mgb2ZmZXIgZm9yIGEgdG9rZW4sIGlmIHRoZXkgaGF2ZSBtYWRlIG9uZS4AAAAAAAVvZmZlcgAAAAAAAAIAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAA+gAAAfQAAAABU9mZmVyAAAA",
+        "AAAAAAAAAUAqIEBkZXYgT2ZmZXJzIHRvIGJ1eSBhIHRva2VuLCBtb3ZpbmcgdGhlIGFtb3VudCBpbnRvIHRoZSBjb250cmFjdC4gRmFpbHMKICAgICAqIHdpdGggT2ZmZXJFeHBpcmVkIGlmIHRoZSBvZmZlciB3b3VsZCBhbHJlYWR5IGhhdmUgZXhwaXJlZC4KICAgICAqIEBwYXJhbSBidXllciBUaGUgYnV5ZXIgKG11c3Qgc2lnbiBhbmQgcGF5KS4KICAgICAqIEBwYXJhbSBhbW91bnQgVGhlIG9mZmVyOyBtdXN0IGJlIHBvc2l0aXZlLgogICAgICogQHBhcmFtIGV4cGlyZXNfYXQgTGVkZ2VyIHRpbWVzdGFtcCB1bnRpbCB3aGljaCB0aGUgb2ZmZXIgY2FuIGJlIGFjY2VwdGVkLgAAAAptYWtlX29mZmVyAAAAAAAFAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAY4qIEBkZXYgQWNjZXB0cyBhIGJ1eWVyJ3Mgb2ZmZXI6IHRoZSBvd25lciBpcyBwYWlkIHRoZSBlc2Nyb3dlZCBhbW91bnQgYW5kCiAgICAgKiB0aGUgYnV5ZXIgcmVjZWl2ZXMgdGhlIHRva2VuLiBGYWlscyB3aXRoIE9mZmVyRXhwaXJlZCBvbmNlIGl0IGV4cGlyZWQuCiAgICAgKiBXaGVyZSB0aGUgaG90ZWwgaGFzIGEgcmlnaHQgb2YgZmlyc3QgcmVmdXNhbCwgdGhlIHRva2VuIGFuZCB0aGUKICAgICAqIGVzY3Jvd2VkIGFtb3VudCBhcmUgaGVsZCB1bnRpbCB0aGUgaG90ZWwgbWF0Y2hlcyB0aGUgb2ZmZXIgb3IgaXRzCiAgICAgKiB3aW5kb3cgbGFwc2VzIGluc3RlYWQgKHNlZSBmaXJzdF9yZWZ1c2FsKCkpLgogICAgICogQHBhcmFtIG93bmVyIFRoZSBjdXJyZW50IG93bmVyIChtdXN0IHNpZ24pLgAAAAAADGFjY2VwdF9vZmZlcgAAAAMAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAAVidXllcgAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAMcqIEBkZXYgVGFrZXMgYmFjayBhbiBvZmZlciBhbmQgaXRzIGVzY3Jvd2VkIGFtb3VudCwgZXhwaXJlZCBvciBub3QuIFdvcmtzCiAgICAgKiB3aGlsZSBwYXVzZWQsIHNvIGEgcGF1c2UgbmV2ZXIgbG9ja3MgYSBidXllcidzIGZ1bmRzIGluIGVzY3Jvdy4KICAgICAqIEBwYXJhbSBidXllciBUaGUgYnV5ZXIgd2hvIG1hZGUgaXQgKG11c3Qgc2lnbikuAAAAAAxjYW5jZWxfb2ZmZXIAAAACAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAQAAAEUqIEB0aXRsZSBGZWVRdW90ZQogKiBAZGV2IE9uZSB5ZWFyJ3MgbWFpbnRlbmFuY2UgZmVlIGluIGEgcHJlcGF5bWVudC4AAAAAAAAAAAAACEZlZVF1b3RlAAAAAgAAACZUaGUgZmVlIGFmdGVyIHRoZSBwcmVwYXltZW50IGRpc2NvdW50LgAAAAAAA2ZlZQAAAAALAAAAAAAAAAR5ZWFyAAAABA==",
+        "AAAAAQAAAF0qIEB0aXRsZSBQcmVwYXltZW50CiAqIEBkZXYgQSBwcmVwYWlkIHllYXIncyBmZWUsIGhlbGQgYnkgdGhlIGNvbnRyYWN0IHVudGlsIHRoZSB5ZWFyIGJlZ2lucy4AAAAAAAAAAAAAClByZXBheW1lbnQAAAAAAAIAAAAAAAAABmFtb3VudAAAAAAACwAAABpUb2tlbiB0aGUgZmVlIHdhcyBwYWlkIGluLgAAAAAABXRva2VuAAAAAAAAEw==",
+        "AAAAAQAAAF8qIEB0aXRsZSBQcmVwYWlkWWVhcnMKICogQGRldiBUaGUgeWVhcnMgb2YgYSB0b2tlbiB3aG9zZSBwcmVwYWlkIGZlZXMgdGhlIGNvbnRyYWN0IHN0aWxsIGhvbGRzLgAAAAAAAAAADFByZXBhaWRZZWFycwAAAAIAAAAAAAAABWZpcnN0AAAAAAAABAAAAAAAAAAEbGFzdAAAAAQ=",
+        "AAAABQAAAGMqIEB0aXRsZSBNYWludGVuYW5jZVByZXBhaWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhbiBvd25lciBwcmVwYXlzIG1haW50ZW5hbmNlIGZvciBhIHJ1biBvZiB5ZWFycy4AAAAAAAAAABJNYWludGVuYW5jZVByZXBhaWQAAAAAAAEAAAATbWFpbnRlbmFuY2VfcHJlcGFpZAAAAAAFAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAAAAAAApmaXJzdF95ZWFyAAAAAAAEAAAAAAAAAAAAAAAJbGFzdF95ZWFyAAAAAAAABAAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAJwqIEB0aXRsZSBQcmVwYXltZW50UmVmdW5kZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiB0aGUgcHJlcGFpZCBmZWVzIG9mIHllYXJzIHRoYXQgaGF2ZW4ndCBiZWd1biBhcmUKICogcmVmdW5kZWQgYmVjYXVzZSB0aGUgdG9rZW4gd2FzIGJ1cm5lZCBvciBjbGF3ZWQgYmFjay4AAAAAAAAAElByZXBheW1lbnRSZWZ1bmRlZAAAAAAAAQAAABNwcmVwYXltZW50X3JlZnVuZGVkAAAAAAMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAAAAAAAEoqIEBkZXYgUmV0dXJucyB0aGUgcHJlcGFpZCBmZWUgdGhlIGNvbnRyYWN0IGhvbGRzIGZvciBvbmUgeWVhciBvZiBhIHRva2VuLgAAAAAACnByZXBheW1lbnQAAAAAAAIAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAEeWVhcgAAAAQAAAABAAAD6AAAB9AAAAAKUHJlcGF5bWVudAAA",
+        "AAAAAAAAAW4qIEBkZXYgUHJldmlld3MgYSBwcmVwYXltZW50OiB0aGUgeWVhcnMgaXQgd291bGQgY292ZXIsIGZyb20gdGhlIHRva2VuJ3MKICAgICAqIGZpcnN0IHVucGFpZCB5ZWFyLCBhbmQgZWFjaCB5ZWFyJ3MgZGlzY291bnRlZCBmZWUuIEZhaWxzIHdpdGgKICAgICAqIEludmFsaWRBbW91bnQgd2l0aG91dCBhIGZlZSBzY2hlZHVsZSBvciB1bmxlc3MgYHllYXJzYCBpcyBpbgogICAgICogMS4uPU1BWF9QUkVQQVlfWUVBUlMsIGFuZCBNYWludGVuYW5jZUFycmVhcnMgd2hpbGUgeWVhcnMgYmVmb3JlIHRoZQogICAgICogY3VycmVudCBvbmUgYXJlIG93ZWQ6IHRob3NlIGFyZSBwYWlkIHdpdGggYHBheV9tYWludGVuYW5jZWAsIHVuZGlzY291bnRlZC4AAAAAAAxmZWVfc2NoZWR1bGUAAAACAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAABXllYXJzAAAAAAAABAAAAAEAAAPpAAAD6gAAB9AAAAAIRmVlUXVvdGUAAAAD",
+        "AAAAAAAAAF8qIEBkZXYgUmV0dXJucyB0aGUgeWVhcnMgb2YgYSB0b2tlbiB3aG9zZSBwcmVwYWlkIGZlZXMgdGhlIGNvbnRyYWN0IHN0aWxsCiAgICAgKiBob2xkcywgaWYgYW55LgAAAAANcHJlcGFpZF95ZWFycwAAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPoAAAH0AAAAAxQcmVwYWlkWWVhcnM=",
+        "AAAAAAAAAHAqIEBkZXYgUmVsZWFzZXMgdGhlIHByZXBhaWQgZmVlcyBvZiBhIHRva2VuJ3MgeWVhcnMgdGhhdCBoYXZlIGJlZ3VuIHRvCiAgICAgKiB0aGUgY29sbGVjdG9yLiBBbnlvbmUgbWF5IGNhbGwgaXQuAAAAD3JlbGVhc2VfcHJlcGFpZAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAC0qIEBkZXYgUmV0dXJucyB0aGUgcHJlcGF5bWVudCBkaXNjb3VudCBjdXJ2ZS4AAAAAAAAQcHJlcGF5X2Rpc2NvdW50cwAAAAAAAAABAAAD6gAAAAQ=",
+        "AAAAAAAAAZoqIEBkZXYgUHJlcGF5cyBtYWludGVuYW5jZSBmb3IgYHllYXJzYCB5ZWFycyBmcm9tIHRoZSB0b2tlbidzIGZpcnN0IHVucGFpZAogICAgICogeWVhciwgYXMgcXVvdGVkIGJ5IGBmZWVfc2NoZWR1bGVgLiBUaGUgY3VycmVudCB5ZWFyLCBpZiBjb3ZlcmVkLCBpcyBwYWlkCiAgICAgKiB0byB0aGUgY29sbGVjdG9yIHN0cmFpZ2h0IGF3YXkuIEZhaWxzIGxpa2UgYGZlZV9zY2hlZHVsZWAsIGFuZCB3aXRoCiAgICAgKiBJbnZhbGlkQW1vdW50IHVubGVzcyB0aGUgc2NoZWR1bGUgYmlsbHMgZmVlcyBpbiBgdG9rZW5fYWRkcmVzc2AuCiAgICAgKiBAcGFyYW0gb3duZXIgVGhlIGN1cnJlbnQgb3duZXIgKG11c3Qgc2lnbiBhbmQgcGF5KS4KICAgICAqIEByZXR1cm4gVGhlIHllYXJzIHBhaWQgZm9yIGFuZCB0aGVpciBmZWVzLgAAAAAAEnByZXBheV9tYWludGVuYW5jZQAAAAAABAAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAADXRva2VuX2FkZHJlc3MAAAAAAAATAAAAAAAAAAV5ZWFycwAAAAAAAAQAAAABAAAD6QAAA+oAAAfQAAAACEZlZVF1b3RlAAAAAw==",
+        "AAAAAAAAAQwqIEBkZXYgU2V0cyB0aGUgcHJlcGF5bWVudCBkaXNjb3VudCBjdXJ2ZS4gQWRtaW4gb25seS4KICAgICAqIEBwYXJhbSBkaXNjb3VudHMgRW50cnkgbiAtIDEgaXMgdGhlIGRpc2NvdW50LCBpbiBiYXNpcyBwb2ludHMgb2YgZWFjaAogICAgICogeWVhcidzIGZlZSwgZm9yIHByZXBheWluZyBuIHllYXJzLiBBdCBtb3N0IE1BWF9QUkVQQVlfWUVBUlMgZW50cmllcyBvZgogICAgICogYXQgbW9zdCBGVUxMX0RJU0NPVU5UX0JQUzsgZW1wdHkgZ2l2ZXMgbm8gZGlzY291bnQuAAAAFHNldF9wcmVwYXlfZGlzY291bnRzAAAAAQAAAAAAAAAJZGlzY291bnRzAAAAAAAD6gAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAQAAAFkqIEB0aXRsZSBSZW50YWwKICogQGRldiBBIHRva2VuJ3MgcmVudGFsIGxpc3RpbmcgZm9yIG9uZSB5ZWFyLCBhbmQgaXRzIHJlbnRlciBvbmNlIHRha2VuLgAAAAAAAAAAAAAGUmVudGFsAAAAAAAGAAAANFRoZSBvd25lciB3aG8gbGlzdGVkIHRoZSB3ZWVrLCBhbmQgaXMgcGFpZCB0aGUgcmVudC4AAAAGbGlzdGVyAAAAAAATAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAmV2hvIHJlbnRlZCB0aGUgd2Vlaywgb25jZSBzb21lb25lIGhhcy4AAAAAAAZyZW50ZXIAAAAAA+gAAAATAAAAQExlZGdlciB0aW1lc3RhbXAgYWZ0ZXIgd2hpY2ggdGhlIGxpc3RpbmcgY2FuIG5vIGxvbmdlciBiZSB0YWtlbi4AAAANcmVudGVyX3dpbmRvdwAAAAAAAAYAAAAaVG9rZW4gdGhlIHJlbnQgaXMgcGFpZCBpbi4AAAAAAAV0b2tlbgAAAAAAABMAAAApVGhlIGNhbGVuZGFyIHllYXIgdGhlIHdlZWsgaXMgcmVudGVkIGZvci4AAAAAAAAEeWVhcgAAAAQ=",
+        "AAAABQAAAEQqIEB0aXRsZSBSZW50ZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIHJlbnRlciB0YWtlcyBhIGxpc3RlZCB3ZWVrLgAAAAAAAAAGUmVudGVkAAAAAAABAAAABnJlbnRlZAAAAAAAAwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAGcmVudGVyAAAAAAATAAAAAQAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAEwqIEB0aXRsZSBSZW50YWxMaXN0ZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhbiBvd25lciBsaXN0cyBhIHdlZWsgZm9yIHJlbnQuAAAAAAAAAAxSZW50YWxMaXN0ZWQAAAABAAAADXJlbnRhbF9saXN0ZWQAAAAAAAAEAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAAAAAAAA1yZW50ZXJfd2luZG93AAAAAAAABgAAAAAAAAAC",
+        "AAAABQAAAF4qIEB0aXRsZSBSZW50YWxDYW5jZWxsZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhbiBvd25lciB3aXRoZHJhd3MgYSBsaXN0aW5nIG5vYm9keSBoYXMgdGFrZW4uAAAAAAAAAAAAD1JlbnRhbENhbmNlbGxlZAAAAAABAAAAEHJlbnRhbF9jYW5jZWxsZWQAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAg==",
+        "AAAAAAAAALQqIEBkZXYgUmVudHMgYSBsaXN0ZWQgd2VlaywgcGF5aW5nIHRoZSBwcmljZSB0byB0aGUgbGlzdGVyLiBUaGUgcmVudGVyIG1heQogICAgICogdGhlbiBjaGVjayBpbiBpbnN0ZWFkIG9mIHRoZSBvd25lciB0aGlzIHllYXIuCiAgICAgKiBAcGFyYW0gcmVudGVyIFRoZSByZW50ZXIgKG11c3Qgc2lnbiBhbmQgcGF5KS4AAAAEcmVudAAAAAIAAAAAAAAABnJlbnRlcgAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAfQAAAABlJlbnRhbAAAAAAAAw==",
+        "AAAAAAAAAEYqIEBkZXYgUmV0dXJucyBhIHRva2VuJ3MgcmVudGFsIGZvciB0aGUgY3VycmVudCB5ZWFyLCBsaXN0ZWQgb3IgdGFrZW4uAAAAAAAGcmVudGFsAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6AAAB9AAAAAGUmVudGFsAAA=",
+        "AAAAAAAAAF8qIEBkZXYgV2l0aGRyYXdzIGEgbGlzdGluZyBub2JvZHkgaGFzIHRha2VuLgogICAgICogQHBhcmFtIG93bmVyIFRoZSBjdXJyZW50IG93bmVyIChtdXN0IHNpZ24pLgAAAAANY2FuY2VsX3JlbnRhbAAAAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAYAqIEBkZXYgTGlzdHMgdGhpcyB5ZWFyJ3Mgd2VlayBvZiBhIHRva2VuIGZvciByZW50LCByZXBsYWNpbmcgYW55IGxpc3RpbmcKICAgICAqIG5vYm9keSBoYXMgdGFrZW4uIEZhaWxzIHdpdGggTGlzdGluZ0Nsb3NlZCBvbmNlIGEgcmVudGVyIGhhcyB0YWtlbiBpdC4KICAgICAqIEBwYXJhbSBvd25lciBUaGUgY3VycmVudCBvd25lciAobXVzdCBzaWduKTsgcmVjZWl2ZXMgdGhlIHJlbnQuCiAgICAgKiBAcGFyYW0gdG9rZW4gVGhlIHRva2VuIHRoZSByZW50IGlzIHBhaWQgaW4uCiAgICAgKiBAcGFyYW0gcHJpY2UgVGhlIHJlbnQuCiAgICAgKiBAcGFyYW0gcmVudGVyX3dpbmRvdyBMZWRnZXIgdGltZXN0YW1wIHVudGlsIHdoaWNoIHRoZSBsaXN0aW5nIGNhbiBiZSB0YWtlbi4AAAANbGlzdF9mb3JfcmVudAAAAAAAAAUAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAANcmVudGVyX3dpbmRvdwAAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAABQAAAEoqIEB0aXRsZSBCaWRSZXZlYWxlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgYmlkZGVyIHJldmVhbHMgYSBzZWFsZWQgYmlkLgAAAAAAAAAAAAtCaWRSZXZlYWxlZAAAAAABAAAADGJpZF9yZXZlYWxlZAAAAAMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAABmJpZGRlcgAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAFoqIEB0aXRsZSBCaWRDb21taXR0ZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIGJpZGRlciBjb21taXRzIChvciByZWNvbW1pdHMpIGEgc2VhbGVkIGJpZC4AAAAAAAAAAAAMQmlkQ29tbWl0dGVkAAAAAQAAAA1iaWRfY29tbWl0dGVkAAAAAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAQAAAAI=",
+        "AAAAAQAAAEkqIEB0aXRsZSBTZWFsZWRBdWN0aW9uCiAqIEBkZXYgQSB0b2tlbidzIHNlYWxlZC1iaWQgc2Vjb25kLXByaWNlIGF1Y3Rpb24uAAAAAAAAAAAAAA1TZWFsZWRBdWN0aW9uAAAAAAAACgAAACFFdmVyeW9uZSB3aG8gY29tbWl0dGVkLCBpbiBvcmRlci4AAAAAAAAHYmlkZGVycwAAAAPqAAAAEwAAAEVQb3N0ZWQgd2l0aCBlYWNoIGNvbW1pdG1lbnQ7IGZvcmZlaXRlZCB0byB0aGUgc2VsbGVyIGlmIG5vdCByZXZlYWxlZC4AAAAAAAAEYm9uZAAAAAsAAAA9TGVkZ2VyIHRpbWVzdGFtcCBhdCB3aGljaCBjb21taXRtZW50cyBjbG9zZSBhbmQgcmV2ZWFscyBvcGVuLgAAAAAAAA5jb21taXRfZW5kc19hdAAAAAAABgAAAAAAAAAIaGlnaF9iaWQAAAALAAAAQVRoZSBoaWdoZXN0IHJldmVhbGVkIGJpZGRlciBzbyBmYXIsIHdob3NlIGJpZCB0aGUgY29udHJhY3QgaG9sZHMuAAAAAAAAC2hpZ2hfYmlkZGVyAAAAA+gAAAATAAAAIVRva2VuIGJpZHMgYW5kIGJvbmRzIGFyZSBwYWlkIGluLgAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAADZUaGUgbG93ZXN0IGJpZCB0aGF0IGNvdW50cywgYW5kIHRoZSBsb3dlc3QgcHJpY2UgcGFpZC4AAAAAAAdyZXNlcnZlAAAAAAsAAAAoTGVkZ2VyIHRpbWVzdGFtcCBhdCB3aGljaCByZXZlYWxzIGNsb3NlLgAAAA5yZXZlYWxfZW5kc19hdAAAAAAABgAAADRUaGUgc2Vjb25kLWhpZ2hlc3QgcmV2ZWFsZWQgYmlkLCAwIGlmIHRoZXJlIGlzIG5vbmUuAAAACnNlY29uZF9iaWQAAAAAAAsAAAA9VGhlIG93bmVyIHdobyBzdGFydGVkIHRoZSBhdWN0aW9uLCBhbmQgaXMgcGFpZCBmb3IgdGhlIHRva2VuLgAAAAAAAAZzZWxsZXIAAAAAABM=",
+        "AAAABQAAAJcqIEB0aXRsZSBTZWFsZWRBdWN0aW9uTGFwc2VkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBzZWFsZWQgYXVjdGlvbiBlbmRzIGJlY2F1c2UgaXRzIHRva2VuIGNoYW5nZWQgb3duZXIsCiAqIG9yIGNvdWxkbid0IGJlIGhhbmRlZCBvdmVyIGF0IHNldHRsZW1lbnQuAAAAAAAAAAATU2VhbGVkQXVjdGlvbkxhcHNlZAAAAAABAAAAFXNlYWxlZF9hdWN0aW9uX2xhcHNlZAAAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAC",
+        "AAAABQAAAH4qIEB0aXRsZSBTZWFsZWRBdWN0aW9uU2V0dGxlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgc2VhbGVkIGF1Y3Rpb24gaXMgc2V0dGxlZDsgd2lubmVyIGlzIE5vbmUgaWYgbm8gYmlkCiAqIG1ldCB0aGUgcmVzZXJ2ZS4AAAAAAAAAAAAUU2VhbGVkQXVjdGlvblNldHRsZWQAAAABAAAAFnNlYWxlZF9hdWN0aW9uX3NldHRsZWQAAAAAAAQAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAABndpbm5lcgAAAAAD6AAAABMAAAAAAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAALEJvbmRzIG9mIHVucmV2ZWFsZWQgYmlkcyBwYWlkIHRvIHRoZSBzZWxsZXIuAAAACWZvcmZlaXRlZAAAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAFYqIEB0aXRsZSBTZWFsZWRBdWN0aW9uU3RhcnRlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGFuIG93bmVyIHN0YXJ0cyBhIHNlYWxlZCBhdWN0aW9uLgAAAAAAAAAAABRTZWFsZWRBdWN0aW9uU3RhcnRlZAAAAAEAAAAWc2VhbGVkX2F1Y3Rpb25fc3RhcnRlZAAAAAAABwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAAAAAAAAdyZXNlcnZlAAAAAAsAAAAAAAAAAAAAAARib25kAAAACwAAAAAAAAAAAAAADmNvbW1pdF9lbmRzX2F0AAAAAAAGAAAAAAAAAAAAAAAOcmV2ZWFsX2VuZHNfYXQAAAAAAAYAAAAAAAAAAg==",
+        "AAAAAAAAATgqIEBkZXYgQ29tbWl0cyBhIHNlYWxlZCBiaWQsIHBvc3RpbmcgdGhlIGJvbmQgdGhlIGZpcnN0IHRpbWUuIEZhaWxzIHdpdGgKICAgICAqIEF1Y3Rpb25FbmRlZCBvbmNlIGNvbW1pdG1lbnRzIGhhdmUgY2xvc2VkLCBhbmQgQmF0Y2hUb29MYXJnZSBwYXN0CiAgICAgKiBNQVhfU0VBTEVEX0JJRERFUlMgYmlkZGVycy4KICAgICAqIEBwYXJhbSBiaWRkZXIgVGhlIGJpZGRlciAobXVzdCBzaWduIGFuZCBwYXkgdGhlIGJvbmQpLgogICAgICogQHBhcmFtIGNvbW1pdG1lbnQgc2hhMjU2IG9mIHRoZSBYRFIgb2YgKGJpZGRlciwgYW1vdW50LCBzYWx0KS4AAAAKY29tbWl0X2JpZAAAAAAAAwAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAY4qIEBkZXYgUmV2ZWFscyBhIGNvbW1pdHRlZCBiaWQgYW5kIHJldHVybnMgaXRzIGJvbmQuIEEgYmlkIHRoYXQgaXMgdGhlCiAgICAgKiBoaWdoZXN0IHNvIGZhciBpcyBlc2Nyb3dlZCwgcmVmdW5kaW5nIHRoZSBvbmUgaXQgYmVhdHMuIEZhaWxzIHdpdGgKICAgICAqIEF1Y3Rpb25SdW5uaW5nIGJlZm9yZSB0aGUgcmV2ZWFsIHdpbmRvdywgQXVjdGlvbkVuZGVkIGFmdGVyIGl0LAogICAgICogTm9Db21taXRtZW50IHdpdGhvdXQgYSBjb21taXRtZW50IGFuZCBDb21taXRtZW50TWlzbWF0Y2ggaWYgdGhlIGJpZAogICAgICogYW5kIHNhbHQgZG9uJ3QgaGFzaCB0byBpdC4KICAgICAqIEBwYXJhbSBiaWRkZXIgVGhlIGJpZGRlciAobXVzdCBzaWduLCBhbmQgcGF5IHRoZSBiaWQgaWYgaXQgbGVhZHMpLgAAAAAACnJldmVhbF9iaWQAAAAAAAQAAAAAAAAABmJpZGRlcgAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAZ8qIEBkZXYgU2V0dGxlcyBhIHNlYWxlZCBhdWN0aW9uIG9uY2UgcmV2ZWFscyBoYXZlIGNsb3NlZDogdGhlIGhpZ2hlc3QKICAgICAqIGJpZGRlciBnZXRzIHRoZSB0b2tlbiBmb3IgdGhlIHNlY29uZC1oaWdoZXN0IGJpZCAoYXQgbGVhc3QgdGhlCiAgICAgKiByZXNlcnZlKSwgYW5kIHVucmV2ZWFsZWQgYm9uZHMgZ28gdG8gdGhlIHNlbGxlci4gQW55b25lIG1heSBjYWxsIGl0LgogICAgICogRmFpbHMgd2l0aCBBdWN0aW9uUnVubmluZyB1bnRpbCByZXZlYWxzIGNsb3NlLiBJZiB0aGUgdG9rZW4gY2FuJ3QgbW92ZQogICAgICogdG8gdGhlIHdpbm5lciwgdGhlIGF1Y3Rpb24gbGFwc2VzIGFuZCB0aGUgYmlkIGlzIHJlZnVuZGVkIGluc3RlYWQuCiAgICAgKiBAcmV0dXJuIFRoZSBwcmljZSBwYWlkLCAwIGlmIG5vYm9keSB3b24uAAAAAA1zZXR0bGVfc2VhbGVkAAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAAFkqIEBkZXYgUmV0dXJucyBhIHRva2VuJ3Mgc2VhbGVkIGF1Y3Rpb24sIGlmIG9uZSBpcyBydW5uaW5nIG9yIGF3YWl0aW5nCiAgICAgKiBzZXR0bGVtZW50LgAAAAAAAA5zZWFsZWRfYXVjdGlvbgAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAfQAAAADVNlYWxlZEF1Y3Rpb24AAAA=",
+        "AAAAAAAAAZ0qIEBkZXYgU3RhcnRzIGEgc2VhbGVkIGF1Y3Rpb24gb2YgYSB0b2tlbi4gRmFpbHMgd2l0aCBBdWN0aW9uUnVubmluZyB3aGlsZQogICAgICogb25lIGlzIHJ1bm5pbmcuCiAgICAgKiBAcGFyYW0gc2VsbGVyIFRoZSBjdXJyZW50IG93bmVyIChtdXN0IHNpZ24pOyByZWNlaXZlcyB0aGUgcHJpY2UuCiAgICAgKiBAcGFyYW0gcmVzZXJ2ZSBUaGUgbG93ZXN0IGJpZCB0aGF0IGNvdW50czsgbXVzdCBub3QgYmUgbmVnYXRpdmUuCiAgICAgKiBAcGFyYW0gYm9uZCBQb3N0ZWQgd2l0aCBlYWNoIGNvbW1pdG1lbnQ7IG11c3Qgbm90IGJlIG5lZ2F0aXZlLgogICAgICogQHBhcmFtIGR1cmF0aW9uIFNlY29uZHMgY29tbWl0bWVudHMgYXJlIHRha2VuLCBiZWZvcmUgdGhlIHJldmVhbAogICAgICogd2luZG93OyBtdXN0IG5vdCBiZSAwLgAAAAAAABRzdGFydF9zZWFsZWRfYXVjdGlvbgAAAAYAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAAAAAAHcmVzZXJ2ZQAAAAALAAAAAAAAAARib25kAAAACwAAAAAAAAAIZHVyYXRpb24AAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAQAAAHIqIEB0aXRsZSBTaGFyZUJhbGFuY2UKICogQGRldiBBIGhvbGRlcidzIHNoYXJlcyBvZiBhIHRva2VuLCBpbiB0aGUgZnJhY3Rpb25hbGl6YXRpb24gcm91bmQgdGhleSB3ZXJlCiAqIGlzc3VlZCBpbi4AAAAAAAAAAAAMU2hhcmVCYWxhbmNlAAAAAgAAAAAAAAAGYW1vdW50AAAAAAAEAAAAAAAAAAVyb3VuZAAAAAAAAAQ=",
+        "AAAABQAAAE0qIEB0aXRsZSBTaGFyZVRyYW5zZmVyCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gc2hhcmVzIG9mIGEgdG9rZW4gY2hhbmdlIGhhbmRzLgAAAAAAAAAAAAANU2hhcmVUcmFuc2ZlcgAAAAAAAAEAAAAOc2hhcmVfdHJhbnNmZXIAAAAAAAQAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAABGZyb20AAAATAAAAAQAAAAAAAAACdG8AAAAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAFMqIEB0aXRsZSBGcmFjdGlvbmFsaXplZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGFuIG93bmVyIHNwbGl0cyBhIHRva2VuIGludG8gc2hhcmVzLgAAAAAAAAAADkZyYWN0aW9uYWxpemVkAAAAAAABAAAADmZyYWN0aW9uYWxpemVkAAAAAAADAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAAAAAAAZzaGFyZXMAAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAGMqIEB0aXRsZSBTaGFyZXNSZWRlZW1lZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIHRoZSBob2xkZXIgb2YgYWxsIG9mIGEgdG9rZW4ncyBzaGFyZXMgdGFrZXMgaXQgYmFjay4AAAAAAAAAAA5TaGFyZXNSZWRlZW1lZAAAAAAAAQAAAA9zaGFyZXNfcmVkZWVtZWQAAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAI=",
+        "AAAAAAAAAEUqIEBkZXYgUmV0dXJucyBob3cgbWFueSBzaGFyZXMgYSBmcmFjdGlvbmFsaXplZCB0b2tlbiB3YXMgc3BsaXQgaW50by4AAAAAAAAIZnJhY3Rpb24AAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6AAAAAQ=",
+        "AAAAAAAAAOkqIEBkZXYgU3BsaXRzIGEgdG9rZW4gaW50byBzaGFyZXMsIGFsbCBjcmVkaXRlZCB0byBpdHMgb3duZXIsIGFuZCBtb3ZlcwogICAgICogdGhlIHRva2VuIGludG8gdGhlIGNvbnRyYWN0LgogICAgICogQHBhcmFtIG93bmVyIFRoZSBjdXJyZW50IG93bmVyIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIHNoYXJlcyBIb3cgbWFueSBzaGFyZXMgdG8gc3BsaXQgaXQgaW50bzsgbXVzdCBiZSBhdCBsZWFzdCAyLgAAAAAAAA1mcmFjdGlvbmFsaXplAAAAAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAABnNoYXJlcwAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAPMqIEBkZXYgUmVjb25zdGl0dXRlcyBzb2xlIG93bmVyc2hpcDogdGhlIGhvbGRlciBvZiBldmVyeSBzaGFyZSBnZXRzIHRoZQogICAgICogdG9rZW4gYmFjayBhbmQgdGhlIHNoYXJlcyBhcmUgcmV0aXJlZC4gRmFpbHMgd2l0aCBJbnN1ZmZpY2llbnRTaGFyZXMKICAgICAqIHdoaWxlIGFueW9uZSBlbHNlIGhvbGRzIHNvbWUuCiAgICAgKiBAcGFyYW0gaG9sZGVyIFRoZSBob2xkZXIgb2YgYWxsIHNoYXJlcyAobXVzdCBzaWduKS4AAAAADXJlZGVlbV9zaGFyZXMAAAAAAAACAAAAAAAAAAZob2xkZXIAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAD0qIEBkZXYgUmV0dXJucyBob3cgbWFueSBvZiBhIHRva2VuJ3Mgc2hhcmVzIGFuIGFkZHJlc3MgaG9sZHMuAAAAAAAADXNoYXJlX2JhbGFuY2UAAAAAAAACAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAABmhvbGRlcgAAAAAAEwAAAAEAAAAE",
+        "AAAAAAAAAQAqIEBkZXYgTW92ZXMgc2hhcmVzIG9mIGEgZnJhY3Rpb25hbGl6ZWQgdG9rZW4uIEZhaWxzIHdpdGgKICAgICAqIE5vdEZyYWN0aW9uYWxpemVkIGlmIHRoZSB0b2tlbiBpc24ndCBmcmFjdGlvbmFsaXplZCwgSW52YWxpZEFtb3VudCBmb3IKICAgICAqIG5vIHNoYXJlcywgYW5kIEluc3VmZmljaWVudFNoYXJlcyBpZiBgZnJvbWAgaG9sZHMgZmV3ZXIgdGhhbiBgYW1vdW50YC4KICAgICAqIEBwYXJhbSBmcm9tIFRoZSBob2xkZXIgKG11c3Qgc2lnbikuAAAADnRyYW5zZmVyX3NoYXJlAAAAAAAEAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAGYW1vdW50AAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAADMqIEBkZXYgUmV0dXJucyBhIGhvdGVsJ3Mgc3VwcGx5IGNhcCwgaWYgaXQgaGFzIG9uZS4AAAAACnN1cHBseV9jYXAAAAAAAAEAAAAAAAAACGhvdGVsX2lkAAAABAAAAAEAAAPoAAAABA==",
+        "AAAAAAAAADUqIEBkZXYgUmV0dXJucyBldmVyeSBob3RlbCdzIHN1cHBseSBjYXAsIGJ5IGhvdGVsIElELgAAAAAAAAtzdXBwbHlfY2FwcwAAAAAAAAAAAQAAA+wAAAAEAAAABA==",
+        "AAAAAAAAANQqIEBkZXYgQ291bnRzIHRoZSBuZXh0IGBsaW1pdGAgKGNhcHBlZCBhdCBNQVhfUEFHRSkgdG9rZW4gSURzIGludG8gdGhlaXIKICAgICAqIGhvdGVscycgc3VwcGxpZXMsIHdoaWxlIGEgcmVjb3VudCBzdGFydGVkIGJ5IG1pZ3JhdGUoKSBpcyBydW5uaW5nLgogICAgICogQWRtaW4gb25seS4KICAgICAqIEByZXR1cm4gV2hldGhlciBldmVyeSB0b2tlbiBpcyBjb3VudGVkLgAAAAxjb3VudF9zdXBwbHkAAAABAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6QAAAAEAAAAD",
+        "AAAAAAAAAG4qIEBkZXYgUmV0dXJucyBob3cgbWFueSBsaXZlIHRva2VucyBhIGhvdGVsIGhhcywgYXMgZXhhY3QgYXMgdGhlIHN0YXRzCiAgICAgKiBwcml2YWN5IGFsbG93cyAoc2VlIHByaXZhY3kucnMpLgAAAAAADGhvdGVsX3N1cHBseQAAAAEAAAAAAAAACGhvdGVsX2lkAAAABAAAAAEAAAAE",
+        "AAAAAAAAAMQqIEBkZXYgQ2FwcyBob3cgbWFueSBsaXZlIHRva2VucyBhIGhvdGVsIG1heSBoYXZlLiBBZG1pbiBvbmx5LiBBIGNhcAogICAgICogdW5kZXIgdGhlIGN1cnJlbnQgc3VwcGx5IG9ubHkgc3RvcHMgZnVydGhlciBtaW50cy4KICAgICAqIEBwYXJhbSBtYXhfdG9rZW5zIFRoZSBtb3N0IGxpdmUgdG9rZW5zOyB1MzI6Ok1BWCBmb3Igbm8gbGltaXQuAAAADnNldF9zdXBwbHlfY2FwAAAAAAACAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAACm1heF90b2tlbnMAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAACUqIEBkZXYgUmV0dXJucyB0aGUgY29sbGVjdGlvbidzIG5hbWUuAAAAAAAABG5hbWUAAAAAAAAAAQAAABA=",
+        "AAAAAAAAACcqIEBkZXYgUmV0dXJucyB0aGUgY29sbGVjdGlvbidzIHN5bWJvbC4AAAAABnN5bWJvbAAAAAAAAAAAAAEAAAAQ",
+        "AAAAAAAAAEEqIEBkZXYgUmV0dXJucyBob3cgbWFueSB0b2tlbnMgYGFjY291bnRgIG93bnMsIGxpa2UgYmFsYW5jZV9vZigpLgAAAAAAAAdiYWxhbmNlAAAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAAE",
+        "AAAAAAAAADYqIEBkZXYgUmV0dXJucyB0aGUgb3duZXIgb2YgYSB0b2tlbiwgbGlrZSBnZXRfb3duZXIoKS4AAAAAAAhvd25lcl9vZgAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAAXAqIEBkZXYgUmV0dXJucyB3aG8gaXMgb3dlZCBhIHJveWFsdHkgb24gYSBzYWxlIG9mIGEgdG9rZW4gYXQKICAgICAqIGBzYWxlX3ByaWNlYCwgYW5kIGhvdyBtdWNoOiB0aGUgaG90ZWwncyBwYXlvdXQgYWRkcmVzcywgb3IgdGhlIGNvbnRyYWN0CiAgICAgKiB3aXRoIDAgaWYgdGhlIGhvdGVsIHRha2VzIG5vbmUuIFRoZSBhbW91bnQgaXMgcm91bmRlZCBhcyBhIHNhbGUgcGFpZAogICAgICogaW4gdGhlIGFzc2V0J3MgYmFzZSB1bml0IHdvdWxkIGJlLiBGYWlscyB3aXRoIEludmFsaWRBbW91bnQgZm9yIGEKICAgICAqIG5lZ2F0aXZlIHByaWNlLCBhbmQgQW1vdW50T3ZlcmZsb3cgZm9yIG9uZSB0b28gbGFyZ2UgdG8gdGFrZSBhIHNoYXJlIG9mLgAAAAxyb3lhbHR5X2luZm8AAAACAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAACnNhbGVfcHJpY2UAAAAAAAsAAAABAAAD6QAAA+0AAAACAAAAEwAAAAsAAAAD",
+        "AAAAAAAAAS8qIEBkZXYgQXBwcm92ZXMgYG9wZXJhdG9yYCBmb3IgYWxsIG9mIGBvd25lcmAncyB0b2tlbnMgdGhyb3VnaCBsZWRnZXIKICAgICAqIGBsaXZlX3VudGlsX2xlZGdlcmAsIG9yIHJldm9rZXMgaXQgd2l0aCAwLiBFaXRoZXIgcmVwbGFjZXMgb3IsIHdpdGggMCwKICAgICAqIHJldm9rZXMgYSBzZXRfYXBwcm92YWxfZm9yX2FsbCgpIGFwcHJvdmFsIHRvby4gRmFpbHMgd2l0aAogICAgICogSW52YWxpZEFtb3VudCBmb3IgYSBsZWRnZXIgYWxyZWFkeSBwYXN0LgogICAgICogQHBhcmFtIG93bmVyIFRoZSBvd25lciAobXVzdCBzaWduKS4AAAAAD2FwcHJvdmVfZm9yX2FsbAAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAAAAAARbGl2ZV91bnRpbF9sZWRnZXIAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAQAAADMqIEB0aXRsZSBBdWN0aW9uCiAqIEBkZXYgQSB0b2tlbidzIHJ1bm5pbmcgYXVjdGlvbi4AAAAAAAAAAAdBdWN0aW9uAAAAAAYAAAApTGVkZ2VyIHRpbWVzdGFtcCBhdCB3aGljaCBiaWRkaW5nIGNsb3Nlcy4AAAAAAAAHZW5kc19hdAAAAAAGAAAAAAAAAAhoaWdoX2JpZAAAAAsAAAA4VGhlIGhpZ2hlc3QgYmlkZGVyIHNvIGZhciwgd2hvc2UgYmlkIHRoZSBjb250cmFjdCBob2xkcy4AAAALaGlnaF9iaWRkZXIAAAAD6AAAABMAAAAXVG9rZW4gYmlkcyBhcmUgbWFkZSBpbi4AAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAIFRoZSBsb3dlc3QgYWNjZXB0YWJsZSBmaXJzdCBiaWQuAAAAB3Jlc2VydmUAAAAACwAAAD9UaGUgb3duZXIgd2hvIHN0YXJ0ZWQgdGhlIGF1Y3Rpb24sIGFuZCBpcyBwYWlkIHRoZSB3aW5uaW5nIGJpZC4AAAAABnNlbGxlcgAAAAAAEw==",
+        "AAAABQAAAE0qIEB0aXRsZSBCaWRQbGFjZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIGJpZCBiZWNvbWVzIGFuIGF1Y3Rpb24ncyBoaWdoZXN0LgAAAAAAAAAAAAAJQmlkUGxhY2VkAAAAAAAAAQAAAApiaWRfcGxhY2VkAAAAAAADAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAZiaWRkZXIAAAAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAIsqIEB0aXRsZSBBdWN0aW9uTGFwc2VkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYW4gYXVjdGlvbiBlbmRzIGJlY2F1c2UgaXRzIHRva2VuIGNoYW5nZWQgb3duZXIsIG9yCiAqIGNvdWxkbid0IGJlIGhhbmRlZCBvdmVyIGF0IHNldHRsZW1lbnQuAAAAAAAAAAANQXVjdGlvbkxhcHNlZAAAAAAAAAEAAAAOYXVjdGlvbl9sYXBzZWQAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAC",
+        "AAAABQAAAGkqIEB0aXRsZSBBdWN0aW9uU2V0dGxlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGFuIGVuZGVkIGF1Y3Rpb24gaXMgc2V0dGxlZDsgd2lubmVyIGlzIE5vbmUgaWYgbm9ib2R5IGJpZC4AAAAAAAAAAAAADkF1Y3Rpb25TZXR0bGVkAAAAAAABAAAAD2F1Y3Rpb25fc2V0dGxlZAAAAAADAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAATAAAAAAAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAEkqIEB0aXRsZSBBdWN0aW9uU3RhcnRlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGFuIG93bmVyIGF1Y3Rpb25zIGEgdG9rZW4uAAAAAAAAAAAAAA5BdWN0aW9uU3RhcnRlZAAAAAAAAQAAAA9hdWN0aW9uX3N0YXJ0ZWQAAAAABQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAAAAAAAAdyZXNlcnZlAAAAAAsAAAAAAAAAAAAAAAdlbmRzX2F0AAAAAAYAAAAAAAAAAg==",
+        "AAAAAAAAAUYqIEBkZXYgQmlkcyBvbiBhIHJ1bm5pbmcgYXVjdGlvbi4gVGhlIGFtb3VudCBpcyBoZWxkIGJ5IHRoZSBjb250cmFjdCwgYW5kCiAgICAgKiB0aGUgYmlkIGl0IGJlYXRzIGlzIHJlZnVuZGVkLiBGYWlscyB3aXRoIEludmFsaWRBbW91bnQgdW5sZXNzIHRoZSBiaWQKICAgICAqIGJlYXRzIHRoZSBoaWdoIGJpZCAob3IgbWVldHMgdGhlIHJlc2VydmUsIGZvciB0aGUgZmlyc3QgYmlkKSwgYW5kIHdpdGgKICAgICAqIEF1Y3Rpb25FbmRlZCBvbmNlIGJpZGRpbmcgaGFzIGNsb3NlZC4KICAgICAqIEBwYXJhbSBiaWRkZXIgVGhlIGJpZGRlciAobXVzdCBzaWduIGFuZCBwYXkpLgAAAAAAA2JpZAAAAAADAAAAAAAAAAZiaWRkZXIAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEsqIEBkZXYgUmV0dXJucyBhIHRva2VuJ3MgYXVjdGlvbiwgaWYgb25lIGlzIHJ1bm5pbmcgb3IgYXdhaXRpbmcgc2V0dGxlbWVudC4AAAAAB2F1Y3Rpb24AAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAfQAAAAB0F1Y3Rpb24A",
+        "AAAAAAAAAXEqIEBkZXYgQXVjdGlvbnMgYSB0b2tlbiwgcmVwbGFjaW5nIGFuIGVhcmxpZXIgYXVjdGlvbiBvZiBpdCBub2JvZHkgYmlkIG9uLgogICAgICogRmFpbHMgd2l0aCBBdWN0aW9uUnVubmluZyB3aGlsZSBhbiBhdWN0aW9uIHdpdGggYmlkcyBpcyBydW5uaW5nLgogICAgICogQHBhcmFtIHNlbGxlciBUaGUgY3VycmVudCBvd25lciAobXVzdCBzaWduKTsgcmVjZWl2ZXMgdGhlIHdpbm5pbmcgYmlkLgogICAgICogQHBhcmFtIHJlc2VydmUgVGhlIGxvd2VzdCBhY2NlcHRhYmxlIGZpcnN0IGJpZDsgbXVzdCBub3QgYmUgbmVnYXRpdmUuCiAgICAgKiBAcGFyYW0gZHVyYXRpb24gU2Vjb25kcyB1bnRpbCBiaWRkaW5nIGNsb3NlczsgbXVzdCBub3QgYmUgMC4AAAAAAAANc3RhcnRfYXVjdGlvbgAAAAAAAAUAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAAAAAAHcmVzZXJ2ZQAAAAALAAAAAAAAAAhkdXJhdGlvbgAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAASEqIEBkZXYgU2V0dGxlcyBhbiBlbmRlZCBhdWN0aW9uOiBwYXlzIHRoZSBzZWxsZXIgdGhlIGhpZ2ggYmlkIGFuZCBnaXZlcwogICAgICogdGhlIGhpZ2ggYmlkZGVyIHRoZSB0b2tlbi4gQW55b25lIG1heSBjYWxsIGl0LiBGYWlscyB3aXRoCiAgICAgKiBBdWN0aW9uUnVubmluZyB3aGlsZSBiaWRkaW5nIGlzIG9wZW4uIElmIHRoZSB0b2tlbiBjYW4ndCBtb3ZlIHRvIHRoZQogICAgICogaGlnaCBiaWRkZXIsIHRoZSBhdWN0aW9uIGxhcHNlcyBhbmQgdGhlIGhpZ2ggYmlkIGlzIHJlZnVuZGVkIGluc3RlYWQuAAAAAAAADnNldHRsZV9hdWN0aW9uAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAQAAAF4qIEB0aXRsZSBCdXliYWNrCiAqIEBkZXYgVGhlIGhvdGVsJ3Mgc3RhbmRpbmcgYmlkIGZvciB0aGUgdG9rZW5zIG9mIG9uZSBvZiBhIHByb3BlcnR5J3MgdGllcnMuAAAAAAAAAAAAB0J1eWJhY2sAAAAACQAAAD1XaGF0IGlzIGxlZnQgb2YgdGhlIGZ1bmRzIHRoZSBjb250cmFjdCBob2xkcyBmb3IgdGhlIGJ1eWJhY2suAAAAAAAABmJ1ZGdldAAAAAAACwAAAGZSZWNlaXZlcyB0aGUgdG9rZW5zIGJvdWdodCBiYWNrIGFuZCwgb24gY2xvc2luZywgdGhlIHJlc3Qgb2YgdGhlCmJ1ZGdldDogdGhlIGFkbWluIHdobyBsYXN0IGZ1bmRlZCBpdC4AAAAAAAVidXllcgAAAAAAABMAAAAvTGVkZ2VyIHRpbWVzdGFtcCBhZnRlciB3aGljaCBvd25lcnMgY2FuJ3Qgc2VsbC4AAAAACWNsb3Nlc19hdAAAAAAAAAYAAABAV2hldGhlciBpdCBwYXVzZWQgaXRzZWxmIGJlY2F1c2UgdGhlIGJ1ZGdldCBjYW4ndCBwYXkgdGhlIHByaWNlLgAAAAlleGhhdXN0ZWQAAAAAAAABAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAsTGVkZ2VyIHRpbWVzdGFtcCBmcm9tIHdoaWNoIG93bmVycyBjYW4gc2VsbC4AAAAIb3BlbnNfYXQAAAAGAAAALlRva2VuIHRoZSBwcmljZSBpcyBwYWlkIGluLiBGaXhlZCBvbmNlIGZ1bmRlZC4AAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAACZQYWlkIGZvciBlYWNoIHRva2VuIHNvbGQgdG8gdGhlIGhvdGVsLgAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAEdGllcgAAABE=",
+        "AAAABQAAAG8qIEB0aXRsZSBTb2xkVG9Ib3RlbAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGFuIG93bmVyIHNlbGxzIGEgdG9rZW4gdG8gYSBidXliYWNrLCBuZXh0IHRvIGl0cwogKiBUcmFuc2ZlciBldmVudC4AAAAAAAAAAAtTb2xkVG9Ib3RlbAAAAAABAAAADXNvbGRfdG9faG90ZWwAAAAAAAAGAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAZzZWxsZXIAAAAAABMAAAABAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAAAAAAAAR0aWVyAAAAEQAAAAAAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAbV2hhdCBpcyBsZWZ0IG9mIHRoZSBidWRnZXQuAAAAAAZidWRnZXQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAEkqIEB0aXRsZSBCdXliYWNrQ2xvc2VkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gdGhlIGFkbWluIGNsb3NlcyBhIGJ1eWJhY2suAAAAAAAAAAAAAA1CdXliYWNrQ2xvc2VkAAAAAAAAAQAAAA5idXliYWNrX2Nsb3NlZAAAAAAAAwAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAAAAAAAAEdGllcgAAABEAAAABAAAAIVRoZSBidWRnZXQgcmVmdW5kZWQgdG8gdGhlIGJ1eWVyLgAAAAAAAAhyZWZ1bmRlZAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAFMqIEB0aXRsZSBCdXliYWNrRnVuZGVkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gdGhlIGFkbWluIG9wZW5zIG9yIHRvcHMgdXAgYSBidXliYWNrLgAAAAAAAAAADUJ1eWJhY2tGdW5kZWQAAAAAAAABAAAADmJ1eWJhY2tfZnVuZGVkAAAAAAAHAAAAAAAAAAhob3RlbF9pZAAAAAQAAAABAAAAAAAAAAR0aWVyAAAAEQAAAAEAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAAAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAAAAAAGYnVkZ2V0AAAAAAALAAAAAAAAAAAAAAAIb3BlbnNfYXQAAAAGAAAAAAAAAAAAAAAJY2xvc2VzX2F0AAAAAAAABgAAAAAAAAAC",
+        "AAAABQAAAGIqIEB0aXRsZSBCdXliYWNrRXhoYXVzdGVkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBzYWxlIGxlYXZlcyBhIGJ1eWJhY2sncyBidWRnZXQgYmVsb3cgaXRzIHByaWNlLgAAAAAAAAAAABBCdXliYWNrRXhoYXVzdGVkAAAAAQAAABFidXliYWNrX2V4aGF1c3RlZAAAAAAAAAMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAEAAAAAAAAABHRpZXIAAAARAAAAAQAAAAAAAAAGYnVkZ2V0AAAAAAALAAAAAAAAAAI=",
+        "AAAAAAAAAD4qIEBkZXYgUmV0dXJucyB0aGUgYnV5YmFjayBvcGVuIGZvciBhIHByb3BlcnR5J3MgdGllciwgaWYgYW55LgAAAAAAB2J1eWJhY2sAAAAAAgAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAAAAAAR0aWVyAAAAEQAAAAEAAAPoAAAH0AAAAAdCdXliYWNrAA==",
+        "AAAAAAAAAFoqIEBkZXYgTGlzdHMgdGhlIG9wZW4gYnV5YmFja3MsIGFzIChob3RlbCBJRCwgdGllciksIGluIHRoZSBvcmRlciB0aGV5CiAgICAgKiB3ZXJlIG9wZW5lZC4AAAAAAAhidXliYWNrcwAAAAAAAAABAAAD6gAAA+0AAAACAAAABAAAABE=",
+        "AAAAAAAAAdIqIEBkZXYgT3BlbnMgYSBidXliYWNrIGZvciBhIHByb3BlcnR5J3MgdGllciwgb3IgdG9wcyB1cCBhbmQgdXBkYXRlcyB0aGUKICAgICAqIG9wZW4gb25lLCBtb3ZpbmcgYGFtb3VudGAgZnJvbSB0aGUgYWRtaW4gaW50byBpdHMgYnVkZ2V0LiBBZG1pbiBvbmx5LgogICAgICogRmFpbHMgd2l0aCBJbnZhbGlkQnV5YmFjayBmb3IgYSBwcmljZSB0aGF0IGlzbid0IHBvc2l0aXZlLCBhIHdpbmRvdwogICAgICogdGhhdCBjbG9zZXMgYmVmb3JlIGl0IG9wZW5zLCBvciBhbm90aGVyIHBheW1lbnQgdG9rZW4gdGhhbiB0aGUgb3BlbgogICAgICogYnV5YmFjaydzLCBhbmQgVG9vTWFueUJ1eWJhY2tzIGlmIE1BWF9CVVlCQUNLUyBhcmUgb3Blbi4KICAgICAqIEBwYXJhbSBhbW91bnQgQWRkZWQgdG8gdGhlIGJ1ZGdldDsgbXVzdCBub3QgYmUgbmVnYXRpdmUuCiAgICAgKiBAcmV0dXJuIFRoZSBidXliYWNrIGFzIHVwZGF0ZWQuAAAAAAAMZnVuZF9idXliYWNrAAAABwAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAAAAAAR0aWVyAAAAEQAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAhvcGVuc19hdAAAAAYAAAAAAAAACWNsb3Nlc19hdAAAAAAAAAYAAAABAAAD6QAAB9AAAAAHQnV5YmFjawAAAAAD",
+        "AAAAAAAAAK0qIEBkZXYgQ2xvc2VzIGEgYnV5YmFjayBhbmQgcmVmdW5kcyB3aGF0IGlzIGxlZnQgb2YgaXRzIGJ1ZGdldCB0byBpdHMKICAgICAqIGJ1eWVyLiBBZG1pbiBvbmx5LiBXb3JrcyB3aGlsZSBwYXVzZWQuIEZhaWxzIHdpdGggTm9CdXliYWNrIGlmIG5vbmUgaXMKICAgICAqIG9wZW4gZm9yIHRoZSB0aWVyLgAAAAAAAA1jbG9zZV9idXliYWNrAAAAAAAAAgAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAAAAAAR0aWVyAAAAEQAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAbIqIEBkZXYgU2VsbHMgYSB0b2tlbiB0byB0aGUgYnV5YmFjayBvZiBpdHMgcHJvcGVydHkgYW5kIHRpZXI6IHRoZSBvd25lcgogICAgICogaXMgcGFpZCB0aGUgcHJpY2UgZnJvbSB0aGUgYnVkZ2V0IGFuZCB0aGUgYnV5ZXIgcmVjZWl2ZXMgdGhlIHRva2VuLgogICAgICogRmFpbHMgd2l0aCBOb0J1eWJhY2sgaWYgbm9uZSBpcyBvcGVuIGZvciB0aGUgdG9rZW4ncyB0aWVyLAogICAgICogQnV5YmFja05vdE9wZW4gb3V0c2lkZSBpdHMgd2luZG93LCBhbmQgQnV5YmFja0V4aGF1c3RlZCB3aGlsZSBpdHMKICAgICAqIGJ1ZGdldCBjYW4ndCBwYXkgdGhlIHByaWNlLgogICAgICogQHBhcmFtIG93bmVyIFRoZSBjdXJyZW50IG93bmVyIChtdXN0IHNpZ24pOyByZWNlaXZlcyB0aGUgcHJpY2UuCiAgICAgKiBAcmV0dXJuIFdoYXQgaXMgbGVmdCBvZiB0aGUgYnVkZ2V0LgAAAAAADXNlbGxfdG9faG90ZWwAAAAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPpAAAACwAAAAM=",
+        "AAAABQAAAF0qIEB0aXRsZSBDaGFyaXR5UmVtb3ZlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIHRoZSBhZG1pbiByZW1vdmVzIGEgY2hhcml0eSBmcm9tIHRoZSByZWdpc3Rlci4AAAAAAAAAAAAADkNoYXJpdHlSZW1vdmVkAAAAAAABAAAAD2NoYXJpdHlfcmVtb3ZlZAAAAAABAAAAAAAAAAdjaGFyaXR5AAAAABMAAAABAAAAAg==",
+        "AAAABQAAAGQqIEB0aXRsZSBSb3lhbHR5RG9uYXRlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgcmVzYWxlIHBheXMgcGFydCBvZiBhIGhvdGVsJ3Mgcm95YWx0eSB0byBhIGNoYXJpdHkuAAAAAAAAAA5Sb3lhbHR5RG9uYXRlZAAAAAAAAQAAAA9yb3lhbHR5X2RvbmF0ZWQAAAAABQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAHY2hhcml0eQAAAAATAAAAAQAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAAAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAFAqIEB0aXRsZSBDaGFyaXR5U3BsaXRTZXQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIGhvdGVsJ3MgY2hhcml0eSBzcGxpdCBjaGFuZ2VzLgAAAAAAAAAPQ2hhcml0eVNwbGl0U2V0AAAAAAEAAAARY2hhcml0eV9zcGxpdF9zZXQAAAAAAAACAAAAAAAAAAhob3RlbF9pZAAAAAQAAAABAAAAAAAAAAVzcGxpdAAAAAAAA+oAAAPtAAAAAgAAABMAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAF8qIEB0aXRsZSBDaGFyaXR5UmVnaXN0ZXJlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIHRoZSBhZG1pbiByZWdpc3RlcnMgYSBjaGFyaXR5IG9yIHJlbmFtZXMgb25lLgAAAAAAAAAAEUNoYXJpdHlSZWdpc3RlcmVkAAAAAAAAAQAAABJjaGFyaXR5X3JlZ2lzdGVyZWQAAAAAAAIAAAAAAAAAB2NoYXJpdHkAAAAAEwAAAAEAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAI=",
+        "AAAAAAAAAEAqIEBkZXYgUmV0dXJucyB0aGUgbmFtZSBhIGNoYXJpdHkgaXMgcmVnaXN0ZXJlZCB1bmRlciwgaWYgaXQgaXMuAAAAB2NoYXJpdHkAAAAAAQAAAAAAAAAHY2hhcml0eQAAAAATAAAAAQAAA+gAAAAQ",
+        "AAAAAAAAAF0qIEBkZXYgUmV0dXJucyB3aGF0IGEgY2hhcml0eSB3YXMgZG9uYXRlZCBpbiBhIGNhbGVuZGFyIHllYXIgKFVUQykgaW4gYQogICAgICogcGF5bWVudCB0b2tlbi4AAAAAAAAHZG9uYXRlZAAAAAADAAAAAAAAAAdjaGFyaXR5AAAAABMAAAAAAAAABHllYXIAAAAEAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAEQqIEBkZXYgUmV0dXJucyBhIGhvdGVsJ3MgY2hhcml0eSBzcGxpdDsgZW1wdHkgaWYgaXQgZG9uYXRlcyBub3RoaW5nLgAAAA1jaGFyaXR5X3NwbGl0AAAAAAAAAQAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAA+oAAAPtAAAAAgAAABMAAAAE",
+        "AAAAAAAAAGwqIEBkZXYgUmV0dXJucyB3aGF0IGEgaG90ZWwgZG9uYXRlZCBvdXQgb2YgaXRzIHJveWFsdGllcyBpbiBhIGNhbGVuZGFyCiAgICAgKiB5ZWFyIChVVEMpIGluIGEgcGF5bWVudCB0b2tlbi4AAAANaG90ZWxfZG9uYXRlZAAAAAAAAAMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAAEeWVhcgAAAAQAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAKQqIEBkZXYgUmVtb3ZlcyBhIGNoYXJpdHkgZnJvbSB0aGUgcmVnaXN0ZXIuIEFkbWluIG9ubHkuIFNwbGl0cyB0aGF0IG5hbWUKICAgICAqIGl0IGFyZSBrZXB0LCBidXQgaXRzIHNoYXJlIHN0YXlzIHdpdGggdGhlIGhvdGVsIHVudGlsIGl0IGlzIHJlZ2lzdGVyZWQKICAgICAqIGFnYWluLgAAAA5yZW1vdmVfY2hhcml0eQAAAAAAAQAAAAAAAAAHY2hhcml0eQAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAFgqIEBkZXYgUmVnaXN0ZXJzIGEgY2hhcml0eSB0aGF0IGhvdGVscyBtYXkgZG9uYXRlIHRvLCBvciByZW5hbWVzIG9uZS4KICAgICAqIEFkbWluIG9ubHkuAAAAEHJlZ2lzdGVyX2NoYXJpdHkAAAACAAAAAAAAAAdjaGFyaXR5AAAAABMAAAAAAAAABG5hbWUAAAAQAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAeAqIEBkZXYgU2V0cyB0aGUgc2hhcmUgb2YgYSBob3RlbCdzIHJveWFsdGllcyBkb25hdGVkIHRvIGVhY2ggb2YgaXRzCiAgICAgKiBjaGFyaXRpZXMuIE1hbmFnZXIgcm9sZSAob3IgYWRtaW4pLiBGYWlscyB3aXRoIE5vdENoYXJpdHkgZm9yIGEgY2hhcml0eQogICAgICogdGhhdCBpc24ndCByZWdpc3RlcmVkLCBEdXBsaWNhdGVDaGFyaXR5IGZvciBvbmUgbmFtZWQgdHdpY2UsCiAgICAgKiBUb29NYW55UGF5ZWVzIHBhc3QgTUFYX0NIQVJJVElFUywgYW5kIEludmFsaWRTcGxpdCBpZiB0aGUgc2hhcmVzIGFkZCB1cAogICAgICogdG8gbW9yZSB0aGFuIEZVTExfQlBTLgogICAgICogQHBhcmFtIGNhbGxlciBUaGUgbWFuYWdlciAobXVzdCBzaWduKS4KICAgICAqIEBwYXJhbSBzcGxpdCBFYWNoIGNoYXJpdHkgYW5kIGl0cyBzaGFyZSBpbiBiYXNpcyBwb2ludHMgb2YgdGhlIHJveWFsdHk7CiAgICAgKiBlbXB0eSB0byBkb25hdGUgbm90aGluZy4AAAARc2V0X2NoYXJpdHlfc3BsaXQAAAAAAAADAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAAFc3BsaXQAAAAAAAPqAAAD7QAAAAIAAAATAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAQAAAFkqIEB0aXRsZSBDaGVja2luS2V5CiAqIEBkZXYgVGhlIGtleSBhIGd1ZXN0J3MgZGV2aWNlIHNpZ25zIGEgdG9rZW4ncyBjaGVjay1pbiBjb2RlcyB3aXRoLgAAAAAAAAAAAAAKQ2hlY2tpbktleQAAAAAAAwAAABdXaG8gcmVnaXN0ZXJlZCB0aGUga2V5LgAAAAAFZ3Vlc3QAAAAAAAATAAAAN1RoZSBoaWdoZXN0IG5vbmNlIHVzZWQgc28gZmFyLCAwIGJlZm9yZSB0aGUgZmlyc3QgY29kZS4AAAAABW5vbmNlAAAAAAAABgAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAACA=",
+        "AAAAAQAAAFMqIEB0aXRsZSBDaGVja2luQ29kZQogKiBAZGV2IFdoYXQgYSBndWVzdCdzIFFSIGNvZGUgY2FycmllcywgbmV4dCB0byBpdHMgc2lnbmF0dXJlLgAAAAAAAAAAC0NoZWNraW5Db2RlAAAAAAQAAAA0TGVkZ2VyIHRpbWVzdGFtcCBhZnRlciB3aGljaCB0aGUgY29kZSBjYW4ndCBiZSB1c2VkLgAAAApleHBpcmVzX2F0AAAAAAAGAAAAOE11c3QgYmUgaGlnaGVyIHRoYW4gYW55IG5vbmNlIHVzZWQgZm9yIHRoZSB0b2tlbiBiZWZvcmUuAAAABW5vbmNlAAAAAAAABgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAHlRoZSBjYWxlbmRhciB5ZWFyIG9mIHRoZSBzdGF5LgAAAAAABHllYXIAAAAE",
+        "AAAAAAAAAF0qIEBkZXYgUmV0dXJucyB0aGUga2V5IGEgdG9rZW4ncyBjaGVjay1pbiBjb2RlcyBhcmUgc2lnbmVkIHdpdGgsIGlmIG9uZSBpcwogICAgICogcmVnaXN0ZXJlZC4AAAAAAAALY2hlY2tpbl9rZXkAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAfQAAAACkNoZWNraW5LZXkAAA==",
+        "AAAAAAAAAOcqIEBkZXYgUmVnaXN0ZXJzIHRoZSBlZDI1NTE5IHB1YmxpYyBrZXkgYSBndWVzdCdzIGRldmljZSBzaWducyBhIHRva2VuJ3MKICAgICAqIGNoZWNrLWluIGNvZGVzIHdpdGgsIHJlcGxhY2luZyBhbnkgZWFybGllciBvbmUuIE5vbmNlcyBhbHJlYWR5IHVzZWQgc3RheQogICAgICogdXNlZC4KICAgICAqIEBwYXJhbSBndWVzdCBUaGUgb3duZXIsIG9yIHRoaXMgeWVhcidzIHJlbnRlciAobXVzdCBzaWduKS4AAAAAD3NldF9jaGVja2luX2tleQAAAAADAAAAAAAAAAVndWVzdAAAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAACAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAhAqIEBkZXYgQ2hlY2tzIGEgZ3Vlc3QgaW4gd2l0aCBhIHNjYW5uZWQgUVIgY29kZSwgY29uZmlybWVkIGF0IG9uY2UuIE1hbmFnZXIKICAgICAqIHJvbGUgKG9yIGFkbWluKS4gRmFpbHMgd2l0aCBOb0NoZWNraW5LZXkgYmVmb3JlIHRoZSBndWVzdCByZWdpc3RlcmVkIGEKICAgICAqIGtleSwgQ2hlY2tpbkNvZGVFeHBpcmVkIGZvciBhIGNvZGUgcGFzdCBpdHMgZXhwaXJ5IG9yIGZvciBhbm90aGVyIHllYXIsCiAgICAgKiBDaGVja2luQ29kZVVzZWQgdW5sZXNzIGl0cyBub25jZSBpcyBuZXcsIGFuZCBJbnZhbGlkVXNhZ2VTdGF0ZSB1bmxlc3MgdGhlCiAgICAgKiB3ZWVrIGlzIHVudXNlZCB0aGlzIHllYXIuIEEgYmFkIHNpZ25hdHVyZSB0cmFwcy4KICAgICAqIEBwYXJhbSBjYWxsZXIgVGhlIGtpb3NrIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIHNpZ25hdHVyZSBUaGUgZ3Vlc3QgZGV2aWNlJ3MgZWQyNTUxOSBzaWduYXR1cmUgb2YgdGhlIGNvZGUuCiAgICAgKiBAcmV0dXJuIFRoZSBndWVzdCB3aG8gY2hlY2tlZCBpbi4AAAATdmVyaWZ5X2NoZWNraW5fY29kZQAAAAADAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAABGNvZGUAAAfQAAAAC0NoZWNraW5Db2RlAAAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAABAAAD6QAAABMAAAAD",
+        "AAAAAAAAAE0qIEBkZXYgUmV0dXJucyB0aGUgYnl0ZXMgYSBjaGVjay1pbiBjb2RlJ3Mgc2lnbmF0dXJlIG11c3QgY292ZXIsIGZvciBzaWduZXJzLgAAAAAAABRjaGVja2luX2NvZGVfbWVzc2FnZQAAAAEAAAAAAAAABGNvZGUAAAfQAAAAC0NoZWNraW5Db2RlAAAAAAEAAAAO",
+        "AAAAAAAAAJQqIEBkZXYgQWRkcyB0b2tlbnMgbWludGVkIGJlZm9yZSB0aGUgaW5kZXhlcyBleGlzdGVkIHRvIHRoZW0uIEFueW9uZSBtYXkKICAgICAqIGNhbGwgaXQ7IHVua25vd24gSURzIGFyZSBza2lwcGVkLCBhbmQgYXQgbW9zdCBNQVhfUEFHRSBhcmUgZXhhbWluZWQuAAAADGluZGV4X3Rva2VucwAAAAEAAAAAAAAACXRva2VuX2lkcwAAAAAAA+oAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAKoqIEBkZXYgTGlzdHMgYSBwcm9wZXJ0eSdzIHRva2VucyBvZiBhIHRpZXIuCiAgICAgKiBAcGFyYW0gc3RhcnQgVGhlIGluZGV4IG9mIHRoZSBmaXJzdCB0b2tlbiwgMCBmb3IgdGhlIGZpcnN0IHBhZ2UuCiAgICAgKiBAcGFyYW0gbGltaXQgVGhlIHBhZ2Ugc2l6ZSwgY2FwcGVkIGF0IE1BWF9QQUdFLgAAAAAADnRva2Vuc19ieV90aWVyAAAAAAAEAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAABHRpZXIAAAARAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAABg==",
+        "AAAAAAAAAMAqIEBkZXYgTGlzdHMgdGhlIHRva2VucyBvZiBhIHdlZWsgb2YgdGhlIHllYXIsIGF0IGV2ZXJ5IHByb3BlcnR5LgogICAgICogQHBhcmFtIHN0YXJ0IFRoZSBpbmRleCBvZiB0aGUgZmlyc3QgdG9rZW4sIDAgZm9yIHRoZSBmaXJzdCBwYWdlLgogICAgICogQHBhcmFtIGxpbWl0IFRoZSBwYWdlIHNpemUsIGNhcHBlZCBhdCBNQVhfUEFHRS4AAAAOdG9rZW5zX2J5X3dlZWsAAAAAAAMAAAAAAAAABHdlZWsAAAAEAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAABg==",
+        "AAAAAQAAAHAqIEB0aXRsZSBTd2FwSW50ZW50CiAqIEBkZXYgQW4gb3duZXIncyBzdGFuZGluZyBvZmZlciB0byBzd2FwIGFuIGVzY3Jvd2VkIHRva2VuIGZvciBhbnkgdG9rZW4KICogbWVldGluZyBgd2FudGAuAAAAAAAAAApTd2FwSW50ZW50AAAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAEd2FudAAAB9AAAAAIQ3JpdGVyaWE=",
+        "AAAABQAAAEoqIEB0aXRsZSBJbnRlbnRQb3N0ZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhbiBvd25lciBwb3N0cyBhIHN3YXAgaW50ZW50LgAAAAAAAAAAAAxJbnRlbnRQb3N0ZWQAAAABAAAADWludGVudF9wb3N0ZWQAAAAAAAADAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAAAAAAAR3YW50AAAH0AAAAAhDcml0ZXJpYQAAAAAAAAAC",
+        "AAAABQAAANsqIEB0aXRsZSBJbnRlbnRzTWF0Y2hlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGBtYXRjaF9zd2Fwc2Agc2V0dGxlcyBhIHBhaXIgb3IgY3ljbGUgb2YgaW50ZW50cywgbmV4dAogKiB0byB0aGVpciBUcmFuc2ZlciBldmVudHMuIEVhY2ggdG9rZW4gd2VudCB0byB0aGUgb3duZXIgb2YgdGhlIG5leHQgb25lLAogKiBhbmQgdGhlIGxhc3QgdG8gdGhlIG93bmVyIG9mIHRoZSBmaXJzdC4AAAAAAAAAAA5JbnRlbnRzTWF0Y2hlZAAAAAAAAQAAAA9pbnRlbnRzX21hdGNoZWQAAAAAAQAAAAAAAAAJdG9rZW5faWRzAAAAAAAD6gAAAAYAAAAAAAAAAg==",
+        "AAAABQAAAFIqIEB0aXRsZSBJbnRlbnRDYW5jZWxsZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhbiBvd25lciB0YWtlcyBiYWNrIGEgc3dhcCBpbnRlbnQuAAAAAAAAAAAAD0ludGVudENhbmNlbGxlZAAAAAABAAAAEGludGVudF9jYW5jZWxsZWQAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAg==",
+        "AAAAAAAAAOAqIEBkZXYgU2V0dGxlcyB1cCB0byBgbGltaXRgIChhdCBtb3N0IE1BWF9NQVRDSEVTKSBwYWlycyBvciB0aHJlZS13YXkKICAgICAqIGN5Y2xlcyBvZiBpbnRlbnRzIHRoYXQgc2F0aXNmeSBlYWNoIG90aGVyLCBieSB0aGUgcnVsZXMgaW4gdGhlIG1vZHVsZQogICAgICogbm90ZXMuIEFueW9uZSBtYXkgY2FsbCBpdC4KICAgICAqIEByZXR1cm4gSG93IG1hbnkgc3dhcHMgd2VyZSBzZXR0bGVkLgAAAAttYXRjaF9zd2FwcwAAAAABAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAUsqIEBkZXYgUG9zdHMgYSBzd2FwIGludGVudCwgbW92aW5nIHRoZSB0b2tlbiBpbnRvIHRoZSBjb250cmFjdCB1bnRpbCBpdAogICAgICogaXMgbWF0Y2hlZCBvciBjYW5jZWxsZWQuIEZhaWxzIHdpdGggSW52YWxpZFdlZWsgb3IgSG90ZWxOb3RGb3VuZCBmb3IKICAgICAqIGJhZCBjcml0ZXJpYSwgYW5kIEludGVudEJvb2tGdWxsIG9uY2UgTUFYX1NXQVBfSU5URU5UUyBhcmUgb3Blbi4KICAgICAqIEBwYXJhbSBvd25lciBUaGUgdG9rZW4ncyBjdXJyZW50IG93bmVyIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIHdhbnQgV2hhdCB0aGUgb3duZXIgd291bGQgdGFrZSBmb3IgaXQuAAAAAAtwb3N0X2ludGVudAAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAEd2FudAAAB9AAAAAIQ3JpdGVyaWEAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAADkqIEBkZXYgUmV0dXJucyBhIHRva2VuJ3Mgb3BlbiBzd2FwIGludGVudCwgaWYgaXQgaGFzIG9uZS4AAAAAAAALc3dhcF9pbnRlbnQAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAfQAAAAClN3YXBJbnRlbnQAAA==",
+        "AAAAAAAAAD0qIEBkZXYgTGlzdHMgdGhlIHRva2VucyB3aXRoIG9wZW4gc3dhcCBpbnRlbnRzLCBvbGRlc3QgZmlyc3QuAAAAAAAADHN3YXBfaW50ZW50cwAAAAAAAAABAAAD6gAAAAY=",
+        "AAAAAAAAAMwqIEBkZXYgVGFrZXMgYmFjayBhIHN3YXAgaW50ZW50IGFuZCBpdHMgdG9rZW4uIEZhaWxzIHdpdGggTm9Td2FwSW50ZW50CiAgICAgKiB1bmxlc3MgdGhlIHRva2VuIGhhcyBhbiBvcGVuIGludGVudCwgYW5kIE5vdE93bmVyIGZvciBzb21lb25lIGVsc2Uncy4KICAgICAqIEBwYXJhbSBvd25lciBUaGUgb3duZXIgd2hvIHBvc3RlZCBpdCAobXVzdCBzaWduKS4AAAANY2FuY2VsX2ludGVudAAAAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAALQqIEBkZXYgUmV0dXJucyB0aGUgaW52b2NhdGlvbiBjb3VudHMgcmVjb3JkZWQgZm9yIGBkYXlgIChsZWRnZXIgdGltZXN0YW1wIC8gREFZX1NFQ09ORFMpLgogICAgICogQHJldHVybiBBIG1hcCBmcm9tIGVuZHBvaW50IG5hbWUgdG8gbnVtYmVyIG9mIHN1Y2Nlc3NmdWwgY2FsbHMuIEVtcHR5IG9uY2UgZXhwaXJlZC4AAAAHbWV0cmljcwAAAAABAAAAAAAAAANkYXkAAAAABgAAAAEAAAPsAAAAEQAAAAQ=",
+        "AAAAAQAAAFMqIEB0aXRsZSBQZW5kaW5nVHJhbnNmZXIKICogQGRldiBBIHRyYW5zZmVyIHdhaXRpbmcgZm9yIHRoZSByZWNpcGllbnQncyBhY2NlcHRhbmNlLgAAAAAAAAAAD1BlbmRpbmdUcmFuc2ZlcgAAAAADAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABM=",
+        "AAAABQAAAAAAAAAAAAAAEFRyYW5zZmVyUHJvcG9zZWQAAAABAAAAEXRyYW5zZmVyX3Byb3Bvc2VkAAAAAAAABAAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAAAAAAAAg==",
+        "AAAAAAAAAJUqIEBkZXYgQ29tcGxldGVzIGEgcGVuZGluZyB0cmFuc2Zlci4gTXVzdCBiZSBzaWduZWQgYnkgdGhlIHJlY2lwaWVudCBiZWZvcmUgaXQgZXhwaXJlcy4KICAgICAqIEBwYXJhbSB0b2tlbl9pZCBUaGUgSUQgb2YgdGhlIHRva2VuIGJlaW5nIHRyYW5zZmVycmVkLgAAAAAAAA9hY2NlcHRfdHJhbnNmZXIAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAE4qIEBkZXYgUmV0dXJucyB0aGUgcGVuZGluZyB0cmFuc2ZlciBvZiBhIHRva2VuLCBpZiBhbnksIGluY2x1ZGluZyBsYXBzZWQgb25lcy4AAAAAABRnZXRfcGVuZGluZ190cmFuc2ZlcgAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPoAAAH0AAAAA9QZW5kaW5nVHJhbnNmZXIA",
+        "AAAAAAAAAIYqIEBkZXYgVHVybnMgdHdvLXBoYXNlIHRyYW5zZmVycyBvbiBvciBvZmYgZm9yIHRva2VucyBzZW50IGJ5IGBvd25lcmAuCiAgICAgKiBAcGFyYW0gb3duZXIgVGhlIG93bmVyIGNoYW5naW5nIHRoZSBzZXR0aW5nIChtdXN0IHNpZ24pLgAAAAAAGXNldF90cmFuc2Zlcl9jb25maXJtYXRpb24AAAAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAB2VuYWJsZWQAAAAAAQAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAQAAAF8qIEB0aXRsZSBTdGF0c1ByaXZhY3kKICogQGRldiBIb3cgZXhhY3QgdGhlIHB1YmxpYyBzdGF0cyBlbmRwb2ludHMgYXJlOyBzZWUgdGhlIG1vZHVsZSBjb21tZW50LgAAAAAAAAAADFN0YXRzUHJpdmFjeQAAAAIAAAAaQ291bnRzIGJlbG93IGl0IHJlYWQgYXMgMC4AAAAAAAltaW5fY291bnQAAAAAAAAEAAAAOENvdW50cyBhcmUgcm91bmRlZCBkb3duIHRvIGEgbXVsdGlwbGUgb2YgaXQ7IGF0IGxlYXN0IDEuAAAACHJvdW5kX3RvAAAABA==",
+        "AAAABQAAAFoqIEB0aXRsZSBTdGF0c1ByaXZhY3lTZXQKICogQGRldiBQdWJsaXNoZWQgd2hlbiB0aGUgYWRtaW4gY2hhbmdlcyBob3cgZXhhY3QgdGhlIHN0YXRzIGFyZS4AAAAAAAAAAAAPU3RhdHNQcml2YWN5U2V0AAAAAAEAAAARc3RhdHNfcHJpdmFjeV9zZXQAAAAAAAACAAAAAAAAAAltaW5fY291bnQAAAAAAAAEAAAAAAAAAAAAAAAIcm91bmRfdG8AAAAEAAAAAAAAAAI=",
+        "AAAAAAAAADgqIEBkZXYgUmV0dXJucyBob3cgZXhhY3QgdGhlIHB1YmxpYyBzdGF0cyBlbmRwb2ludHMgYXJlLgAAAA1zdGF0c19wcml2YWN5AAAAAAAAAAAAAAEAAAfQAAAADFN0YXRzUHJpdmFjeQ==",
+        "AAAAAAAAAHgqIEBkZXYgU2V0cyBob3cgZXhhY3QgdGhlIHB1YmxpYyBzdGF0cyBlbmRwb2ludHMgYXJlLiBBZG1pbiBvbmx5LiBGYWlscwogICAgICogd2l0aCBJbnZhbGlkQW1vdW50IGZvciBhIGByb3VuZF90b2Agb2YgMC4AAAARc2V0X3N0YXRzX3ByaXZhY3kAAAAAAAABAAAAAAAAAAdwcml2YWN5AAAAB9AAAAAMU3RhdHNQcml2YWN5AAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAQAAAGcqIEB0aXRsZSBQdXJjaGFzZQogKiBAZGV2IEEgcHJpbWFyeSBzYWxlIHdob3NlIHByb2NlZWRzIGFyZSBlc2Nyb3dlZCB1bnRpbCBpdHMgY29vbGluZy1vZmYgcGVyaW9kIGVuZHMuAAAAAAAAAAAIUHVyY2hhc2UAAAAGAAAAAAAAAAVidXllcgAAAAAAABMAAAAtTGFzdCBsZWRnZXIgc2VxdWVuY2UgdGhlIGJ1eWVyIG1heSBjYW5jZWwgYXQuAAAAAAAADGNhbmNlbF91bnRpbAAAAAQAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAAAAAAVwcmljZQAAAAAAAAsAAABHU2hhcmUgb2YgdGhlIHByaWNlIHJlZnVuZGVkIG9uIGNhbmNlbGxhdGlvbiwgYXMgY29uZmlndXJlZCBhdCB0aGUgc2FsZS4AAAAACnJlZnVuZF9icHMAAAAAAAQAAAAhV2hvIGlzIHBhaWQgb25jZSB0aGUgcGVyaW9kIGVuZHMuAAAAAAAABnNlbGxlcgAAAAAAEw==",
+        "AAAAAQAAAGYqIEB0aXRsZSBDb29saW5nT2ZmCiAqIEBkZXYgSG93IGxvbmcgYnV5ZXJzIGhhdmUgdG8gY2FuY2VsIGEgcHJpbWFyeSBwdXJjaGFzZSwgYW5kIHdoYXQgdGhleSBnZXQgYmFjay4AAAAAAAAAAAAKQ29vbGluZ09mZgAAAAAAAgAAADlMZWRnZXJzIGFmdGVyIHRoZSBzYWxlIGR1cmluZyB3aGljaCB0aGUgYnV5ZXIgbWF5IGNhbmNlbC4AAAAAAAAGcGVyaW9kAAAAAAAEAAAAPVNoYXJlIG9mIHRoZSBwcmljZSByZWZ1bmRlZCBvbiBjYW5jZWxsYXRpb24sIGluIGJhc2lzIHBvaW50cy4AAAAAAAAKcmVmdW5kX2JwcwAAAAAABA==",
+        "AAAABQAAAGcqIEB0aXRsZSBQdXJjaGFzZVJlbGVhc2VkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBwdXJjaGFzZSdzIGVzY3Jvd2VkIHByb2NlZWRzIGFyZSBwYWlkIHRvIHRoZSBzZWxsZXIuAAAAAAAAAAAQUHVyY2hhc2VSZWxlYXNlZAAAAAEAAAARcHVyY2hhc2VfcmVsZWFzZWQAAAAAAAADAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAGUqIEB0aXRsZSBQdXJjaGFzZUNhbmNlbGxlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgYnV5ZXIgY2FuY2VscyBhIHB1cmNoYXNlLCBuZXh0IHRvIHRoZSBCdXJuIGV2ZW50LgAAAAAAAAAAAAARUHVyY2hhc2VDYW5jZWxsZWQAAAAAAAABAAAAEnB1cmNoYXNlX2NhbmNlbGxlZAAAAAAAAwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAAAAAAAAGcmVmdW5kAAAAAAALAAAAAAAAAAI=",
+        "AAAAAAAAAFoqIEBkZXYgUmV0dXJucyB0aGUgZXNjcm93ZWQgcHVyY2hhc2Ugb2YgYSB0b2tlbiwgdW50aWwgaXQgaXMgcmVsZWFzZWQgb3IKICAgICAqIGNhbmNlbGxlZC4AAAAAAAhwdXJjaGFzZQAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPoAAAH0AAAAAhQdXJjaGFzZQ==",
+        "AAAAAAAAAEIqIEBkZXYgUmV0dXJucyB0aGUgY29vbGluZy1vZmYgdGVybXMgb2YgbmV3IHByaW1hcnkgc2FsZXMsIGlmIGFueS4AAAAAAAtjb29saW5nX29mZgAAAAAAAAAAAQAAA+gAAAfQAAAACkNvb2xpbmdPZmYAAA==",
+        "AAAAAAAAAZcqIEBkZXYgQ2FuY2VscyBhIHB1cmNoYXNlIHdpdGhpbiBpdHMgY29vbGluZy1vZmYgcGVyaW9kOiBidXJucyB0aGUgdG9rZW4KICAgICAqIGFuZCByZWZ1bmRzIHRoZSBidXllci4gRmFpbHMgd2l0aCBOb1B1cmNoYXNlIGlmIHRoZXJlIGlzIG5vIGVzY3Jvd2VkCiAgICAgKiBwdXJjaGFzZSwgQ29vbGluZ09mZkVuZGVkIGFmdGVyIHRoZSBwZXJpb2QsIGxpa2UgYGJ1cm5gIGZvciB0b2tlbnMKICAgICAqIGhlbGQgaW4gdHJ1c3Qgb3IgZnJvemVuLCBhbmQgQW1vdW50T3ZlcmZsb3cgaWYgdGhlIHJlZnVuZCBvdmVyZmxvd3MuCiAgICAgKiBAcGFyYW0gYnV5ZXIgVGhlIGJ1eWVyLCB3aG8gbXVzdCBzdGlsbCBvd24gdGhlIHRva2VuIChtdXN0IHNpZ24pLgogICAgICogQHJldHVybiBUaGUgYW1vdW50IHJlZnVuZGVkLgAAAAAPY2FuY2VsX3B1cmNoYXNlAAAAAAIAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAARQqIEBkZXYgU2V0cyB0aGUgY29vbGluZy1vZmYgcGVyaW9kIGZvciBmdXR1cmUgcHJpbWFyeSBzYWxlcy4gQWRtaW4gb25seS4KICAgICAqIFB1cmNoYXNlcyBhbHJlYWR5IG1hZGUga2VlcCB0aGVpciB0ZXJtcy4KICAgICAqIEBwYXJhbSBwZXJpb2QgTGVkZ2VycyBidXllcnMgaGF2ZSB0byBjYW5jZWw7IDAgdHVybnMgY29vbGluZy1vZmYgb2ZmLgogICAgICogQHBhcmFtIHJlZnVuZF9icHMgU2hhcmUgb2YgdGhlIHByaWNlIHJlZnVuZGVkLCBhdCBtb3N0IEZVTExfUkVGVU5EX0JQUy4AAAAPc2V0X2Nvb2xpbmdfb2ZmAAAAAAIAAAAAAAAABnBlcmlvZAAAAAAABAAAAAAAAAAKcmVmdW5kX2JwcwAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAKwqIEBkZXYgUGF5cyB0aGUgc2VsbGVyIGEgcHVyY2hhc2UncyBlc2Nyb3dlZCBwcm9jZWVkcyBvbmNlIGl0cyBjb29saW5nLW9mZgogICAgICogcGVyaW9kIGlzIG92ZXIuIEFueW9uZSBtYXkgY2FsbCBpdC4gRmFpbHMgd2l0aCBDb29saW5nT2ZmUnVubmluZyBkdXJpbmcKICAgICAqIHRoZSBwZXJpb2QuAAAAEHJlbGVhc2VfcHVyY2hhc2UAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAQAAAHsqIEB0aXRsZSBGaXJzdFJlZnVzYWwKICogQGRldiBBbiBhY2NlcHRlZCBvZmZlciB3YWl0aW5nIG91dCB0aGUgaG90ZWwncyByaWdodCBvZiBmaXJzdCByZWZ1c2FsLAogKiBrZXllZCBieSB0aGUgdG9rZW4ncyBJRC4AAAAAAAAAAAxGaXJzdFJlZnVzYWwAAAAEAAAASVdobyBtYWRlIHRoZSBvZmZlciwgYW5kIHJlY2VpdmVzIHRoZSB0b2tlbiBpZiB0aGUgaG90ZWwgZG9lc24ndCBtYXRjaCBpdC4AAAAAAAAFYnV5ZXIAAAAAAAATAAAAPkxlZGdlciB0aW1lc3RhbXAgYWZ0ZXIgd2hpY2ggdGhlIGhvdGVsIGNhbiBubyBsb25nZXIgbWF0Y2ggaXQuAAAAAAAHZW5kc19hdAAAAAAGAAAAE1RoZSBlc2Nyb3dlZCBvZmZlci4AAAAABW9mZmVyAAAAAAAH0AAAAAVPZmZlcgAAAAAAADxUaGUgb3duZXIgd2hvIGFjY2VwdGVkIHRoZSBvZmZlciwgYW5kIGlzIHBhaWQgZm9yIHRoZSB0b2tlbi4AAAAGc2VsbGVyAAAAAAAT",
+        "AAAABQAAAHcqIEB0aXRsZSBGaXJzdFJlZnVzYWxTZXQKICogQGRldiBQdWJsaXNoZWQgd2hlbiB0aGUgYWRtaW4gdHVybnMgdGhlIHJpZ2h0IG9mIGZpcnN0IHJlZnVzYWwgb24gb3Igb2ZmCiAqIGZvciBhIHByb3BlcnR5LgAAAAAAAAAAD0ZpcnN0UmVmdXNhbFNldAAAAAABAAAAEWZpcnN0X3JlZnVzYWxfc2V0AAAAAAAAAgAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAAAAAAAAHZW5hYmxlZAAAAAABAAAAAAAAAAI=",
+        "AAAABQAAAGcqIEB0aXRsZSBGaXJzdFJlZnVzYWxPcGVuZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhbiBhY2NlcHRlZCBvZmZlciBlbnRlcnMgdGhlIGhvdGVsJ3MgcmVmdXNhbCB3aW5kb3cuAAAAAAAAAAASRmlyc3RSZWZ1c2FsT3BlbmVkAAAAAAABAAAAFGZpcnN0X3JlZnVzYWxfb3BlbmVkAAAABQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAHZW5kc19hdAAAAAAGAAAAAAAAAAI=",
+        "AAAABQAAAHkqIEB0aXRsZSBGaXJzdFJlZnVzYWxFeGVyY2lzZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiB0aGUgaG90ZWwgbWF0Y2hlcyBhbiBhY2NlcHRlZCBvZmZlciwgbmV4dCB0byBpdHMKICogVHJhbnNmZXIgZXZlbnQuAAAAAAAAAAAAABVGaXJzdFJlZnVzYWxFeGVyY2lzZWQAAAAAAAABAAAAF2ZpcnN0X3JlZnVzYWxfZXhlcmNpc2VkAAAAAAQAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAwVGhlIGJ1eWVyIHdob3NlIG9mZmVyIHdhcyBtYXRjaGVkLCBhbmQgcmVmdW5kZWQuAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAAAAAAAGYqIEBkZXYgUmV0dXJucyBhIHRva2VuJ3MgYWNjZXB0ZWQgb2ZmZXIgd2FpdGluZyBvdXQgdGhlIGhvdGVsJ3MgcmlnaHQgb2YKICAgICAqIGZpcnN0IHJlZnVzYWwsIGlmIGFueS4AAAAAAA1maXJzdF9yZWZ1c2FsAAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAfQAAAADEZpcnN0UmVmdXNhbA==",
+        "AAAAAAAAAKkqIEBkZXYgVHVybnMgdGhlIGhvdGVsJ3MgcmlnaHQgb2YgZmlyc3QgcmVmdXNhbCBvbiBvciBvZmYgZm9yIGEgcHJvcGVydHkuCiAgICAgKiBPZmZlcnMgYWNjZXB0ZWQgYmVmb3JlIGl0IHdhcyB0dXJuZWQgb2ZmIHN0aWxsIHdhaXQgb3V0IHRoZWlyIHdpbmRvdy4KICAgICAqIEFkbWluIG9ubHkuAAAAAAAAEXNldF9maXJzdF9yZWZ1c2FsAAAAAAAAAgAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAAAAAAdlbmFibGVkAAAAAAEAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAHgqIEBkZXYgTGlzdHMgdGhlIHByb3BlcnRpZXMgd2hvc2Ugc2FsZXMgdGhlIGhvdGVsIGhhcyBhIHJpZ2h0IG9mIGZpcnN0CiAgICAgKiByZWZ1c2FsIG9uLCBpbiB0aGUgb3JkZXIgaXQgd2FzIHR1cm5lZCBvbi4AAAAUZmlyc3RfcmVmdXNhbF9ob3RlbHMAAAAAAAAAAQAAA+oAAAAE",
+        "AAAAAAAAAccqIEBkZXYgU2V0dGxlcyBhbiBhY2NlcHRlZCBvZmZlciB0aGUgaG90ZWwgZGlkbid0IG1hdGNoOiB0aGUgc2VsbGVyIGlzCiAgICAgKiBwYWlkIHRoZSBlc2Nyb3dlZCBhbW91bnQgKHJveWFsdHkgaW5jbHVkZWQpIGFuZCB0aGUgYnV5ZXIgcmVjZWl2ZXMgdGhlCiAgICAgKiB0b2tlbi4gQW55b25lIG1heSBjYWxsIGl0IG9uY2UgdGhlIHJlZnVzYWwgd2luZG93IGhhcyBwYXNzZWQ7IHRoZQogICAgICogYWRtaW4gbWF5IGNhbGwgaXQgZWFybGllciB0byB3YWl2ZSB0aGUgcmlnaHQuIEZhaWxzIHdpdGgKICAgICAqIE5vRmlyc3RSZWZ1c2FsIHdpdGhvdXQgb25lLCBhbmQgRmlyc3RSZWZ1c2FsT3BlbiB3aGlsZSB0aGUgd2luZG93IGlzCiAgICAgKiBvcGVuIGFuZCB0aGUgYWRtaW4gaGFzbid0IHNpZ25lZC4KICAgICAqIEBwYXJhbSBjYWxsZXIgV2hvZXZlciBzZXR0bGVzIGl0IChtdXN0IHNpZ24pLgAAAAAWY29tcGxldGVfZmlyc3RfcmVmdXNhbAAAAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAUMqIEBkZXYgTWF0Y2hlcyBhbiBhY2NlcHRlZCBvZmZlciB3aGlsZSBpdHMgcmVmdXNhbCB3aW5kb3cgaXMgb3BlbjogdGhlCiAgICAgKiBzZWxsZXIgaXMgcGFpZCB0aGUgb2ZmZXJlZCBhbW91bnQgYnkgdGhlIGFkbWluIChyb3lhbHR5IGluY2x1ZGVkKSwgdGhlCiAgICAgKiBhZG1pbiByZWNlaXZlcyB0aGUgdG9rZW4gYW5kIHRoZSBidXllciBpcyByZWZ1bmRlZC4gQWRtaW4gb25seS4gRmFpbHMKICAgICAqIHdpdGggTm9GaXJzdFJlZnVzYWwgd2l0aG91dCBvbmUsIGFuZCBGaXJzdFJlZnVzYWxMYXBzZWQgb25jZSBpdHMKICAgICAqIHdpbmRvdyBoYXMgcGFzc2VkLgAAAAAWZXhlcmNpc2VfZmlyc3RfcmVmdXNhbAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAgAAADoqIEB0aXRsZSBTcGVuZFN0YXRlCiAqIEBkZXYgV2hlcmUgYSBzcGVuZCBwcm9wb3NhbCBzdGFuZHMuAAAAAAAAAAAAClNwZW5kU3RhdGUAAAAAAAMAAAAAAAAAAAAAAAdQZW5kaW5nAAAAAAAAAAAAAAAACEV4ZWN1dGVkAAAAAAAAAAAAAAAJQ2FuY2VsbGVkAAAA",
+        "AAAAAQAAADsqIEB0aXRsZSBSZXNlcnZlRnVuZAogKiBAZGV2IEEgaG90ZWwncyBtYWludGVuYW5jZSByZXNlcnZlLgAAAAAAAAAAC1Jlc2VydmVGdW5kAAAAAAYAAAAoV2hhdCB0aGUgY29udHJhY3QgaG9sZHMgZm9yIHRoZSByZXNlcnZlLgAAAAdiYWxhbmNlAAAAAAsAAABBV2hhdCBwZW5kaW5nIHByb3Bvc2FscyB3aWxsIHBheSBvdXQ7IG5ldmVyIG1vcmUgdGhhbiB0aGUgYmFsYW5jZS4AAAAAAAAJY29tbWl0dGVkAAAAAAAACwAAAC9FdmVyeXRoaW5nIGV2ZXIgcGFpZCBpbiwgZnJvbSBmZWVzIGFuZCB0b3AtdXBzLgAAAAAGZnVuZGVkAAAAAAALAAAAQE51bWJlciBvZiBzcGVuZCBwcm9wb3NhbHMgbWFkZSBzbyBmYXI7IHRoZXkgYXJlIG51bWJlcmVkIGZyb20gMS4AAAAJcHJvcG9zYWxzAAAAAAAABAAAAC9FdmVyeXRoaW5nIGV2ZXIgcGFpZCBvdXQgYnkgZXhlY3V0ZWQgcHJvcG9zYWxzLgAAAAAFc3BlbnQAAAAAAAALAAAAIVRoZSB0b2tlbiB0aGUgcmVzZXJ2ZSBpcyBoZWxkIGluLgAAAAAAAAV0b2tlbgAAAAAAABM=",
+        "AAAABQAAAEoqIEB0aXRsZSBSZXNlcnZlU3BlbnQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIHNwZW5kIHByb3Bvc2FsIGlzIGV4ZWN1dGVkLgAAAAAAAAAAAAxSZXNlcnZlU3BlbnQAAAABAAAADXJlc2VydmVfc3BlbnQAAAAAAAAEAAAAAAAAAAhob3RlbF9pZAAAAAQAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAEAAAAAAAAAAAAAAAJcmVjaXBpZW50AAAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAAAQAAAJ8qIEB0aXRsZSBSZXNlcnZlUmVwb3J0CiAqIEBkZXYgQSBob3RlbCdzIHJlc2VydmUgc2hhcmUgYW5kIGZ1bmQsIGFzIHJldHVybmVkIGJ5IHJlc2VydmVfcmVwb3J0KCkuCiAqIFRoZSBmdW5kJ3MgZmllbGRzIGFyZSAwIHVudGlsIHRoZSByZXNlcnZlIGlzIGZpcnN0IGZ1bmRlZC4AAAAAAAAAAA1SZXNlcnZlUmVwb3J0AAAAAAAABwAAAAAAAAAHYmFsYW5jZQAAAAALAAAAAAAAAAljb21taXR0ZWQAAAAAAAALAAAAAAAAAAZmdW5kZWQAAAAAAAsAAAAAAAAACXByb3Bvc2FscwAAAAAAAAQAAAAAAAAACXNoYXJlX2JwcwAAAAAAAAQAAAAAAAAABXNwZW50AAAAAAAACwAAACdOb25lIHVudGlsIHRoZSByZXNlcnZlIGlzIGZpcnN0IGZ1bmRlZC4AAAAABXRva2VuAAAAAAAD6AAAABM=",
+        "AAAAAQAAAEsqIEB0aXRsZSBTcGVuZFByb3Bvc2FsCiAqIEBkZXYgQSBwcm9wb3NlZCBwYXltZW50IG91dCBvZiBhIGhvdGVsJ3MgcmVzZXJ2ZS4AAAAAAAAAAA1TcGVuZFByb3Bvc2FsAAAAAAAABQAAAAAAAAAGYW1vdW50AAAAAAALAAAAOExlZGdlciB0aW1lc3RhbXAgZnJvbSB3aGljaCB0aGUgcGF5bWVudCBjYW4gYmUgZXhlY3V0ZWQuAAAADWV4ZWN1dGFibGVfYXQAAAAAAAAGAAAAPVdoYXQgdGhlIHBheW1lbnQgaXMgZm9yLCBlLmcuICJSb29mIHJlcGFpciwgaW52b2ljZSAyMDMxLTE3Ii4AAAAAAAAEbWVtbwAAABAAAAAAAAAACXJlY2lwaWVudAAAAAAAABMAAAAAAAAABXN0YXRlAAAAAAAH0AAAAApTcGVuZFN0YXRlAAA=",
+        "AAAABQAAAFIqIEB0aXRsZSBSZXNlcnZlRnVuZGVkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gbW9uZXkgaXMgYWRkZWQgdG8gYSBob3RlbCdzIHJlc2VydmUuAAAAAAAAAAAADVJlc2VydmVGdW5kZWQAAAAAAAABAAAADnJlc2VydmVfZnVuZGVkAAAAAAADAAAAAAAAAAhob3RlbF9pZAAAAAQAAAABAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAGMqIEB0aXRsZSBSZXNlcnZlU3BlbmRQcm9wb3NlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgbWFuYWdlciBwcm9wb3NlcyBhIHBheW1lbnQgb3V0IG9mIGEgcmVzZXJ2ZS4AAAAAAAAAABRSZXNlcnZlU3BlbmRQcm9wb3NlZAAAAAEAAAAWcmVzZXJ2ZV9zcGVuZF9wcm9wb3NlZAAAAAAABgAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABAAAAAAAAAAAAAAACXJlY2lwaWVudAAAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAAAAAARtZW1vAAAAEAAAAAAAAAAAAAAADWV4ZWN1dGFibGVfYXQAAAAAAAAGAAAAAAAAAAI=",
+        "AAAABQAAAFwqIEB0aXRsZSBSZXNlcnZlU3BlbmRDYW5jZWxsZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIHBlbmRpbmcgc3BlbmQgcHJvcG9zYWwgaXMgY2FuY2VsbGVkLgAAAAAAAAAVUmVzZXJ2ZVNwZW5kQ2FuY2VsbGVkAAAAAAAAAQAAABdyZXNlcnZlX3NwZW5kX2NhbmNlbGxlZAAAAAACAAAAAAAAAAhob3RlbF9pZAAAAAQAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAEAAAAAAAAAAI=",
+        "AAAAAAAAAMoqIEBkZXYgUGF5cyBpbnRvIGEgaG90ZWwncyByZXNlcnZlLCBlLmcuIGEgc3BlY2lhbCBhc3Nlc3NtZW50LiBGYWlscyB3aXRoCiAgICAgKiBJbnZhbGlkQW1vdW50IHVubGVzcyB0aGUgYW1vdW50IGlzIHBvc2l0aXZlIGFuZCBpbiB0aGUgcmVzZXJ2ZSdzIHRva2VuLgogICAgICogQHBhcmFtIGZyb20gVGhlIHBheWVyIChtdXN0IHNpZ24gYW5kIHBheSkuAAAAAAAMZnVuZF9yZXNlcnZlAAAABAAAAAAAAAAEZnJvbQAAABMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEkqIEBkZXYgUmV0dXJucyBhIHNwZW5kIHByb3Bvc2FsIG9mIGEgaG90ZWwncyByZXNlcnZlLCB3aGF0ZXZlciBpdHMgc3RhdGUuAAAAAAAADXJlc2VydmVfc3BlbmQAAAAAAAACAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAQAAAABAAAD6AAAB9AAAAANU3BlbmRQcm9wb3NhbAAAAA==",
+        "AAAAAAAAAGcqIEBkZXYgUmV0dXJucyBhIGhvdGVsJ3MgcmVzZXJ2ZSBzaGFyZSBhbmQgd2hhdCBpdHMgcmVzZXJ2ZSB0b29rIGluLAogICAgICogcGFpZCBvdXQgYW5kIGhhcyBjb21taXR0ZWQuAAAAAA5yZXNlcnZlX3JlcG9ydAAAAAAAAQAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAA+kAAAfQAAAADVJlc2VydmVSZXBvcnQAAAAAAAAD",
+        "AAAAAAAAADQqIEBkZXYgUmV0dXJucyBldmVyeSByZXNlcnZlIHNoYXJlIHNldCwgYnkgaG90ZWwgSUQuAAAADnJlc2VydmVfc2hhcmVzAAAAAAAAAAAAAQAAA+wAAAAEAAAABA==",
+        "AAAAAAAAANoqIEBkZXYgU2V0cyB0aGUgc2hhcmUgb2YgYSBob3RlbCdzIG1haW50ZW5hbmNlIGZlZXMgaGVsZCBpbiBpdHMgcmVzZXJ2ZS4KICAgICAqIE1hbmFnZXIgcm9sZSAob3IgYWRtaW4pLgogICAgICogQHBhcmFtIGNhbGxlciBUaGUgbWFuYWdlciAobXVzdCBzaWduKS4KICAgICAqIEBwYXJhbSBzaGFyZV9icHMgQXQgbW9zdCBGVUxMX1NIQVJFX0JQUzsgMCByZXNlcnZlcyBub3RoaW5nLgAAAAAAEXNldF9yZXNlcnZlX3NoYXJlAAAAAAAAAwAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAACXNoYXJlX2JwcwAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAG8qIEBkZXYgQ2FuY2VscyBhIHBlbmRpbmcgc3BlbmQgcHJvcG9zYWwuIE1hbmFnZXIgcm9sZSAob3IgYWRtaW4pLgogICAgICogQHBhcmFtIGNhbGxlciBUaGUgbWFuYWdlciAobXVzdCBzaWduKS4AAAAAFGNhbmNlbF9yZXNlcnZlX3NwZW5kAAAAAwAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAJMqIEBkZXYgUGF5cyBvdXQgYSBwZW5kaW5nIHNwZW5kIHByb3Bvc2FsIG9uY2UgUkVTRVJWRV9TUEVORF9ERUxBWSBoYXMKICAgICAqIHBhc3NlZC4gQW55b25lIG1heSBjYWxsIGl0LiBGYWlscyB3aXRoIFRpbWVsb2NrTm90RWxhcHNlZCBiZWZvcmUgdGhlbi4AAAAAFWV4ZWN1dGVfcmVzZXJ2ZV9zcGVuZAAAAAAAAAIAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAALcHJvcG9zYWxfaWQAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAVEqIEBkZXYgUHJvcG9zZXMgcGF5aW5nIGFuIGFtb3VudCBvdXQgb2YgYSBob3RlbCdzIHJlc2VydmUuIE1hbmFnZXIgcm9sZQogICAgICogKG9yIGFkbWluKS4gRmFpbHMgd2l0aCBJbnN1ZmZpY2llbnRSZXNlcnZlIGlmIHRoZSByZXNlcnZlLCBsZXNzIHdoYXQKICAgICAqIHBlbmRpbmcgcHJvcG9zYWxzIGNvbW1pdCwgY2FuJ3QgY292ZXIgaXQuCiAgICAgKiBAcGFyYW0gY2FsbGVyIFRoZSBtYW5hZ2VyIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIG1lbW8gV2hhdCB0aGUgcGF5bWVudCBpcyBmb3IuCiAgICAgKiBAcmV0dXJuIFRoZSBwcm9wb3NhbCdzIElEIHdpdGhpbiB0aGUgaG90ZWwuAAAAAAAAFXByb3Bvc2VfcmVzZXJ2ZV9zcGVuZAAAAAAAAAUAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABG1lbW8AAAAQAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAABQAAAFAqIEB0aXRsZSBVcGdyYWRlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIHRoZSBhZG1pbiByZXBsYWNlcyB0aGUgY29udHJhY3QncyBjb2RlLgAAAAAAAAAIVXBncmFkZWQAAAABAAAACHVwZ3JhZGVkAAAAAgAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAAAAAAAAZsZWRnZXIAAAAAAAQAAAAAAAAAAg==",
+        "AAAAAQAAADgqIEB0aXRsZSBWZXJzaW9uSW5mbwogKiBAZGV2IFJldHVybmVkIGJ5IHZlcnNpb25faW5mbygpLgAAAAAAAAALVmVyc2lvbkluZm8AAAAAAwAAAAAAAAAIdXBncmFkZXMAAAPqAAAH0AAAAA1VcGdyYWRlUmVjb3JkAAAAAAAAAAAAAAd2ZXJzaW9uAAAAABAAAAAAAAAACXdhc21faGFzaAAAAAAAA+gAAAPuAAAAIA==",
+        "AAAAAQAAAEUqIEB0aXRsZSBVcGdyYWRlUmVjb3JkCiAqIEBkZXYgT25lIGVudHJ5IGluIHRoZSBvbi1jaGFpbiB1cGdyYWRlIGxvZy4AAAAAAAAAAAAADVVwZ3JhZGVSZWNvcmQAAAAAAAADAAAAAAAAAAZsZWRnZXIAAAAAAAQAAAAAAAAACXNjaGVkdWxlcgAAAAAAABMAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAg",
+        "AAAAAAAAAM8qIEBkZXYgUmVwbGFjZXMgdGhlIGNvbnRyYWN0J3MgY29kZSwga2VlcGluZyBpdHMgYWRkcmVzcyBhbmQgc3RhdGUuIEFkbWluIG9ubHkuCiAgICAgKiBUaGUgbmV3IGNvZGUgcnVucyBmcm9tIHRoZSBuZXh0IGludm9jYXRpb24gb24uCiAgICAgKiBAcGFyYW0gbmV3X3dhc21faGFzaCBIYXNoIG9mIHdhc20gYWxyZWFkeSB1cGxvYWRlZCB0byB0aGUgbmV0d29yay4AAAAAB3VwZ3JhZGUAAAAAAQAAAAAAAAANbmV3X3dhc21faGFzaAAAAAAAA+4AAAAgAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAFcqIEBkZXYgUmV0dXJucyB0aGUgc2VtYW50aWMgdmVyc2lvbiwgdGhlIGhhc2ggb2YgdGhlIHJ1bm5pbmcgY29kZSwgYW5kIHRoZSB1cGdyYWRlIGxvZy4AAAAADHZlcnNpb25faW5mbwAAAAAAAAABAAAH0AAAAAtWZXJzaW9uSW5mbwA=",
+        "AAAAAAAAANgqIEBkZXYgTGlzdHMgdGhlIGxlZGdlciBrZXlzIHRoYXQgbXVzdCBiZSBsaXZlIHRvIHVzZSBgdG9rZW5faWRgLgogICAgICogQHJldHVybiBPbmUgWERSLWVuY29kZWQgTGVkZ2VyS2V5IHBlciBlbnRyeSwgcmVhZHkgdG8gYmFzZTY0IGludG8gYQogICAgICogcmVzdG9yZSBmb290cHJpbnQuIFJlc3RvcmluZyBhIGtleSB0aGF0IGlzIGFscmVhZHkgbGl2ZSBpcyBoYXJtbGVzcy4AAAAMcmVzdG9yZV9oaW50AAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+oAAAAO",
+        "AAAAAQAAAEAqIEB0aXRsZSBCcmFuZGluZwogKiBAZGV2IEhvdyBhIHByb3BlcnR5J3MgZnJvbnRlbmRzIHByZXNlbnQgaXQuAAAAAAAAAAhCcmFuZGluZwAAAAQAAAA8Q29sb3JzIGFzIDB4UlJHR0JCLCBwcmltYXJ5IGZpcnN0OyBhdCBtb3N0IE1BWF9CUkFORF9DT0xPUlMuAAAABmNvbG9ycwAAAAAD6gAAAAQAAAAAAAAADGRpc3BsYXlfbmFtZQAAABAAAAAAAAAACGxvZ29fdXJpAAAAEAAAAHVTSEEtMjU2IG9mIHRoZSBzdXBwb3J0IGNvbnRhY3QsIGUuZy4gYW4gZW1haWwgYWRkcmVzcywgc28gZnJvbnRlbmRzCmNhbiBjaGVjayBvbmUgd2l0aG91dCB0aGUgY29udHJhY3QgcHVibGlzaGluZyBpdC4AAAAAAAAUc3VwcG9ydF9jb250YWN0X2hhc2gAAAPuAAAAIA==",
+        "AAAABQAAAFgqIEB0aXRsZSBCcmFuZGluZ1NldAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgcHJvcGVydHkncyBicmFuZGluZyBjaGFuZ2VzIG9yIGlzIGNsZWFyZWQuAAAAAAAAAAtCcmFuZGluZ1NldAAAAAABAAAADGJyYW5kaW5nX3NldAAAAAIAAAAAAAAACGhvdGVsX2lkAAAABAAAAAEAAAAAAAAAB2NsZWFyZWQAAAAAAQAAAAAAAAAC",
+        "AAAAAAAAADQqIEBkZXYgUmV0dXJucyBhIHByb3BlcnR5J3MgYnJhbmRpbmcsIGlmIGl0IGhhcyBvbmUuAAAACGJyYW5kaW5nAAAAAQAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAA+gAAAfQAAAACEJyYW5kaW5n",
+        "AAAAAAAAAEgqIEBkZXYgUmV0dXJucyB0aGUgYnJhbmRpbmcgb2YgZXZlcnkgcHJvcGVydHkgdGhhdCBoYXMgb25lLCBieSBob3RlbCBJRC4AAAAJYnJhbmRpbmdzAAAAAAAAAAAAAAEAAAPsAAAABAAAB9AAAAAIQnJhbmRpbmc=",
+        "AAAAAAAAAOoqIEBkZXYgU2V0cyBhIHByb3BlcnR5J3MgYnJhbmRpbmcsIHJlcGxhY2luZyBhbnkgZWFybGllciBvbmUuIE1hbmFnZXIgcm9sZQogICAgICogKG9yIGFkbWluKS4gRmFpbHMgd2l0aCBJbnZhbGlkQnJhbmRpbmcgZm9yIG1vcmUgdGhhbiBNQVhfQlJBTkRfQ09MT1JTCiAgICAgKiBjb2xvcnMgb3Igb25lIGFib3ZlIDB4RkZGRkZGLgogICAgICogQHBhcmFtIGNhbGxlciBUaGUgbWFuYWdlciAobXVzdCBzaWduKS4AAAAAAAxzZXRfYnJhbmRpbmcAAAADAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAAIYnJhbmRpbmcAAAfQAAAACEJyYW5kaW5nAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAGsqIEBkZXYgQ2xlYXJzIGEgcHJvcGVydHkncyBicmFuZGluZy4gTWFuYWdlciByb2xlIChvciBhZG1pbikuCiAgICAgKiBAcGFyYW0gY2FsbGVyIFRoZSBtYW5hZ2VyIChtdXN0IHNpZ24pLgAAAAAOY2xlYXJfYnJhbmRpbmcAAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAABQAAAEYqIEB0aXRsZSBDbGF3YmFjawogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIHRoZSBhZG1pbiBjbGF3cyBhIHRva2VuIGJhY2suAAAAAAAAAAAACENsYXdiYWNrAAAAAQAAAAhjbGF3YmFjawAAAAQAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAABGZyb20AAAATAAAAAAAAAAAAAAACdG8AAAAAA+gAAAATAAAAAAAAAAAAAAALcmVhc29uX2NvZGUAAAAABAAAAAAAAAAC",
+        "AAAAAQAAAD0qIEB0aXRsZSBDbGF3YmFja1JlY29yZAogKiBAZGV2IFRoZSBsYXN0IGNsYXdiYWNrIG9mIGEgdG9rZW4uAAAAAAAAAAAAAA5DbGF3YmFja1JlY29yZAAAAAAABAAAACNUaGUgb3duZXIgdGhlIHRva2VuIHdhcyB0YWtlbiBmcm9tLgAAAAAEZnJvbQAAABMAAAAgTGVkZ2VyIHNlcXVlbmNlIG9mIHRoZSBjbGF3YmFjay4AAAAGbGVkZ2VyAAAAAAAEAAAAAAAAAAtyZWFzb25fY29kZQAAAAAEAAAAKldobyByZWNlaXZlZCBpdCwgb3IgTm9uZSBpZiBpdCB3YXMgYnVybmVkLgAAAAAAAnRvAAAAAAPoAAAAEw==",
+        "AAAAAAAAALcqIEBkZXYgVGFrZXMgYSB0b2tlbiBmcm9tIGl0cyBvd25lci4gQWRtaW4gb25seS4KICAgICAqIEBwYXJhbSB0byBUaGUgbmV3IG93bmVyLCBvciBOb25lIHRvIGJ1cm4gdGhlIHRva2VuLgogICAgICogQHBhcmFtIHJlYXNvbl9jb2RlIFdoeSwgaW4gdGhlIGhvdGVsJ3Mgb3duIGNvZGVzOyByZWNvcmRlZCBvbi1jaGFpbi4AAAAACGNsYXdiYWNrAAAAAwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAAJ0bwAAAAAD6AAAABMAAAAAAAAAC3JlYXNvbl9jb2RlAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEwqIEBkZXYgUmV0dXJucyB0aGUgcmVjb3JkIG9mIGEgdG9rZW4ncyBsYXN0IGNsYXdiYWNrLCBpZiBpdCB3YXMgY2xhd2VkIGJhY2suAAAAD2NsYXdiYWNrX3JlY29yZAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6AAAB9AAAAAOQ2xhd2JhY2tSZWNvcmQAAA==",
+        "AAAABQAAAFgqIEB0aXRsZSBUaWVyU2V0CiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSB0b2tlbidzIHRpZXIgY2hhbmdlczsgTm9uZSB3aGVuIGl0IGlzIGNsZWFyZWQuAAAAAAAAAAdUaWVyU2V0AAAAAAEAAAAIdGllcl9zZXQAAAACAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAR0aWVyAAAD6AAAABEAAAAAAAAAAg==",
+        "AAAABQAAAE0qIEB0aXRsZSBJbmZvVXBkYXRlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgbWFuYWdlciBjaGFuZ2VzIGEgdG9rZW4ncyBpbmZvLgAAAAAAAAAAAAALSW5mb1VwZGF0ZWQAAAAAAQAAAAxpbmZvX3VwZGF0ZWQAAAADAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAhvbGRfaW5mbwAAB9AAAAANVGltZXNoYXJlSW5mbwAAAAAAAAAAAAAAAAAACG5ld19pbmZvAAAH0AAAAA1UaW1lc2hhcmVJbmZvAAAAAAAAAAAAAAI=",
+        "AAAABQAAAEsqIEB0aXRsZSBUb2tlblVyaVNldAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgdG9rZW4ncyBtZXRhZGF0YSBVUkkgY2hhbmdlcy4AAAAAAAAAAAtUb2tlblVyaVNldAAAAAABAAAADXRva2VuX3VyaV9zZXQAAAAAAAACAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAN1cmkAAAAAEAAAAAAAAAAC",
+        "AAAABQAAAGAqIEB0aXRsZSBNZXRhZGF0YUZyb3plbgogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgdG9rZW4ncyBpbmZvLCBVUkkgYW5kIHRpZXIgYXJlIGxvY2tlZCBmb3IgZ29vZC4AAAAAAAAADk1ldGFkYXRhRnJvemVuAAAAAAABAAAAD21ldGFkYXRhX2Zyb3plbgAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAg==",
+        "AAAAAAAAAC0qIEBkZXYgUmV0dXJucyBhIHRva2VuJ3MgdGllciwgaWYgaXQgaGFzIG9uZS4AAAAAAAAEdGllcgAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPoAAAAEQ==",
+        "AAAAAAAAAO0qIEBkZXYgU2V0cyBvciBjbGVhcnMgYSB0b2tlbidzIHRpZXIuIE1hbmFnZXIgcm9sZSAob3IgYWRtaW4pLiBGYWlscyB3aXRoCiAgICAgKiBNZXRhZGF0YUZyb3plbiBvbmNlIHRoZSBtZXRhZGF0YSBpcyBmcm96ZW4uCiAgICAgKiBAcGFyYW0gY2FsbGVyIFRoZSBtYW5hZ2VyIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIHRpZXIgVGhlIHRpZXIsIGUuZy4gIlBsYXRpbnVtIiwgb3IgTm9uZSB0byBjbGVhciBpdC4AAAAAAAAIc2V0X3RpZXIAAAADAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAEdGllcgAAA+gAAAARAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAADYqIEBkZXYgUmV0dXJucyBhIHRva2VuJ3MgbWV0YWRhdGEgVVJJLCBpZiBvbmUgd2FzIHNldC4AAAAAAAl0b2tlbl91cmkAAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6QAAA+gAAAAQAAAAAw==",
+        "AAAAAAAAARQqIEBkZXYgUmVwbGFjZXMgYSB0b2tlbidzIGluZm8sIGUuZy4gYWZ0ZXIgYSByb29tIGlzIHJlbnVtYmVyZWQuIE1hbmFnZXIKICAgICAqIHJvbGUgKG9yIGFkbWluKS4gVGhlIG5ldyBpbmZvIGlzIGNoZWNrZWQgbGlrZSBhIG1pbnQncywgYW5kIGZyZWVzIHRoZQogICAgICogb2xkIHdlZWsgZm9yIHNhbGUuIEZhaWxzIHdpdGggTWV0YWRhdGFGcm96ZW4gb25jZSB0aGUgbWV0YWRhdGEgaXMgZnJvemVuLgogICAgICogQHBhcmFtIGNhbGxlciBUaGUgbWFuYWdlciAobXVzdCBzaWduKS4AAAALdXBkYXRlX2luZm8AAAAAAwAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAACG5ld19pbmZvAAAH0AAAAA1UaW1lc2hhcmVJbmZvAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAARYqIEBkZXYgTGlua3MgYSB0b2tlbiB0byBpdHMgb2ZmLWNoYWluIG1ldGFkYXRhLCByZXBsYWNpbmcgYW55IGVhcmxpZXIgVVJJLgogICAgICogTWFuYWdlciByb2xlIChvciBhZG1pbikuIEZhaWxzIHdpdGggTWV0YWRhdGFGcm96ZW4gb25jZSB0aGUgbWV0YWRhdGEKICAgICAqIGlzIGZyb3plbi4KICAgICAqIEBwYXJhbSBjYWxsZXIgVGhlIG1hbmFnZXIgKG11c3Qgc2lnbikuCiAgICAgKiBAcGFyYW0gdXJpIFRoZSBtZXRhZGF0YSdzIGxvY2F0aW9uLCBlLmcuICJpcGZzOi8vPGNpZD4iLgAAAAAADXNldF90b2tlbl91cmkAAAAAAAADAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAADdXJpAAAAABAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAKgqIEBkZXYgTG9ja3MgYSB0b2tlbidzIGluZm8sIFVSSSBhbmQgdGllciBmb3IgZ29vZC4gTWFuYWdlciByb2xlIChvciBhZG1pbikuCiAgICAgKiBGcmVlemluZyBmcm96ZW4gbWV0YWRhdGEgZG9lcyBub3RoaW5nLgogICAgICogQHBhcmFtIGNhbGxlciBUaGUgbWFuYWdlciAobXVzdCBzaWduKS4AAAAPZnJlZXplX21ldGFkYXRhAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAADQqIEBkZXYgUmV0dXJucyB3aGV0aGVyIGEgdG9rZW4ncyBtZXRhZGF0YSBpcyBmcm96ZW4uAAAAEmlzX21ldGFkYXRhX2Zyb3plbgAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAE=",
+        "AAAAAQAAAEAqIEB0aXRsZSBSZWNlaXB0CiAqIEBkZXYgVGhlIHRlcm1zIG9mIGEgY29tcGxldGVkIHNhbGUgb3IgdHJhZGUuAAAAAAAAAAdSZWNlaXB0AAAAAAgAAAAXV2hvIHJlY2VpdmVkIHRoZSB0b2tlbi4AAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAEa2luZAAAB9AAAAALUmVjZWlwdEtpbmQAAAAAEE5vbmUgZm9yIHRyYWRlcy4AAAANcGF5bWVudF90b2tlbgAAAAAAA+gAAAATAAAANFdoYXQgdGhlIGJ1eWVyIHBhaWQsIHJveWFsdHkgaW5jbHVkZWQ7IDAgZm9yIHRyYWRlcy4AAAAFcHJpY2UAAAAAAAALAAAALFdobyBnYXZlIGl0IHVwOyB0aGUgYWRtaW4gZm9yIHByaW1hcnkgc2FsZXMuAAAABnNlbGxlcgAAAAAAEwAAACNMZWRnZXIgdGltZXN0YW1wIG9mIHRoZSBzZXR0bGVtZW50LgAAAAAJdGltZXN0YW1wAAAAAAAABgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAMUZvciB0cmFkZXMsIHRoZSB0b2tlbiB0aGUgYnV5ZXIgZ2F2ZSBpbiBleGNoYW5nZS4AAAAAAAAKdHJhZGVkX2ZvcgAAAAAD6AAAAAY=",
+        "AAAAAgAAADcqIEB0aXRsZSBSZWNlaXB0S2luZAogKiBAZGV2IEhvdyBhIHRva2VuIGNoYW5nZWQgaGFuZHMuAAAAAAAAAAALUmVjZWlwdEtpbmQAAAAAAwAAAAAAAABVQm91Z2h0IGZyb20gaXRzIG93bmVyLCBvbiB0aGUgbWFya2V0LCBieSBvZmZlciwgYXQgYXVjdGlvbiBvciBieSB0aGUKaG90ZWwncyBidXliYWNrLgAAAAAAAAZSZXNhbGUAAAAAAAAAAABAQm91Z2h0IGZyb20gdGhlIGhvdGVsIHdoZW4gaXQgd2FzIG1pbnRlZCwgb3IgYXQgYSBEdXRjaCBhdWN0aW9uLgAAAAtQcmltYXJ5U2FsZQAAAAAAAAAAHEV4Y2hhbmdlZCBmb3IgYW5vdGhlciB0b2tlbi4AAAAFVHJhZGUAAAA=",
+        "AAAABQAAAFQqIEB0aXRsZSBSZWNlaXB0SXNzdWVkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBzZXR0bGVtZW50IGlzIHJlY29yZGVkIGFzIGEgcmVjZWlwdC4AAAAAAAAADVJlY2VpcHRJc3N1ZWQAAAAAAAABAAAADnJlY2VpcHRfaXNzdWVkAAAAAAACAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAZudW1iZXIAAAAAAAQAAAAAAAAAAg==",
+        "AAAAAAAAAF8qIEBkZXYgUmV0dXJucyBvbmUgb2YgYSB0b2tlbidzIHJlY2VpcHRzLCBudW1iZXJlZCBmcm9tIDAgaW4gdGhlIG9yZGVyCiAgICAgKiB0aGV5IHdlcmUgaXNzdWVkLgAAAAAHcmVjZWlwdAAAAAACAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAABm51bWJlcgAAAAAABAAAAAEAAAPoAAAH0AAAAAdSZWNlaXB0AA==",
+        "AAAAAAAAANUqIEBkZXYgTGlzdHMgdGhlIHJlY2VpcHRzIG5hbWluZyBhbiBhZGRyZXNzIGFzIGJ1eWVyIG9yIHNlbGxlciwgb2xkZXN0CiAgICAgKiBmaXJzdC4KICAgICAqIEBwYXJhbSBzdGFydCBUaGUgaW5kZXggb2YgdGhlIGZpcnN0IHJlY2VpcHQsIDAgZm9yIHRoZSBmaXJzdCBwYWdlLgogICAgICogQHBhcmFtIGxpbWl0IFRoZSBwYWdlIHNpemUsIGNhcHBlZCBhdCBNQVhfUEFHRS4AAAAAAAALcmVjZWlwdHNfb2YAAAAAAwAAAAAAAAAFcGFydHkAAAAAAAATAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAAAdSZWNlaXB0AA==",
+        "AAAAAAAAAGwqIEBkZXYgVHVybnMgcmVjZWlwdHMgZm9yIGZ1dHVyZSBzZXR0bGVtZW50cyBvbiBvciBvZmYuIEFkbWluIG9ubHkuCiAgICAgKiBSZWNlaXB0cyBhbHJlYWR5IGlzc3VlZCBhcmUga2VwdC4AAAAMc2V0X3JlY2VpcHRzAAAAAQAAAAAAAAAHZW5hYmxlZAAAAAABAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAC0qIEBkZXYgUmV0dXJucyBob3cgbWFueSByZWNlaXB0cyBhIHRva2VuIGhhcy4AAAAAAAANcmVjZWlwdF9jb3VudAAAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAE",
+        "AAAAAAAAADwqIEBkZXYgUmV0dXJucyB3aGV0aGVyIHNldHRsZW1lbnRzIGFyZSByZWNvcmRlZCBhcyByZWNlaXB0cy4AAAAQcmVjZWlwdHNfZW5hYmxlZAAAAAAAAAABAAAAAQ==",
+        "AAAAAAAAAEQqIEBkZXYgUmV0dXJucyBob3cgbWFueSByZWNlaXB0cyBuYW1lIGFuIGFkZHJlc3MgYXMgYnV5ZXIgb3Igc2VsbGVyLgAAABNwYXJ0eV9yZWNlaXB0X2NvdW50AAAAAAEAAAAAAAAABXBhcnR5AAAAAAAAEwAAAAEAAAAE",
+        "AAAABQAAAE4qIEB0aXRsZSBQYXllZXNTZXQKICogQGRldiBQdWJsaXNoZWQgd2hlbiB0aGUgYWRtaW4gY2hhbmdlcyB0aGUgcmV2ZW51ZSBzcGxpdC4AAAAAAAAAAAAJUGF5ZWVzU2V0AAAAAAAAAQAAAApwYXllZXNfc2V0AAAAAAABAAAAAAAAAAZwYXllZXMAAAAAA+oAAAPtAAAAAgAAABMAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAF8qIEB0aXRsZSBSZXZlbnVlV2l0aGRyYXduCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBwYXllZSB3aXRoZHJhd3MgdGhlaXIgc2hhcmUgb2YgdGhlIHByb2NlZWRzLgAAAAAAAAAAEFJldmVudWVXaXRoZHJhd24AAAABAAAAEXJldmVudWVfd2l0aGRyYXduAAAAAAAAAwAAAAAAAAAFcGF5ZWUAAAAAAAATAAAAAQAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAAAAAAAEQqIEBkZXYgUmV0dXJucyB0aGUgcmV2ZW51ZSBzcGxpdDsgZW1wdHkgaWYgcHJvY2VlZHMgZ28gdG8gdGhlIGFkbWluLgAAAAZwYXllZXMAAAAAAAAAAAABAAAD6gAAA+0AAAACAAAAEwAAAAQ=",
+        "AAAAAAAAAL8qIEBkZXYgUGF5cyBhIHBheWVlIGV2ZXJ5dGhpbmcgdGhleSBhcmUgb3dlZCBpbiBhIHBheW1lbnQgdG9rZW4uIEZhaWxzCiAgICAgKiB3aXRoIEludmFsaWRBbW91bnQgaWYgdGhhdCBpcyBub3RoaW5nLgogICAgICogQHBhcmFtIHBheWVlIFRoZSBwYXllZSAobXVzdCBzaWduKS4KICAgICAqIEByZXR1cm4gVGhlIGFtb3VudCBwYWlkLgAAAAAId2l0aGRyYXcAAAACAAAAAAAAAAVwYXllZQAAAAAAABMAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAATEqIEBkZXYgU2V0cyB0aGUgcmV2ZW51ZSBzcGxpdC4gQWRtaW4gb25seS4gQmFsYW5jZXMgYWxyZWFkeSBjcmVkaXRlZCBhcmUKICAgICAqIGtlcHQuIEZhaWxzIHdpdGggSW52YWxpZFNwbGl0IHVubGVzcyB0aGUgc2hhcmVzIGFkZCB1cCB0bwogICAgICogU1BMSVRfVE9UQUxfQlBTLCBhbmQgVG9vTWFueVBheWVlcyBwYXN0IE1BWF9QQVlFRVMuCiAgICAgKiBAcGFyYW0gcGF5ZWVzIEVhY2ggcGF5ZWUgYW5kIGl0cyBzaGFyZSBpbiBiYXNpcyBwb2ludHM7IGVtcHR5IHRvIHBheQogICAgICogdGhlIGFkbWluIGRpcmVjdGx5IGFnYWluLgAAAAAAAApzZXRfcGF5ZWVzAAAAAAABAAAAAAAAAAZwYXllZXMAAAAAA+oAAAPtAAAAAgAAABMAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAADcqIEBkZXYgUmV0dXJucyB3aGF0IGEgcGF5ZWUgaXMgb3dlZCBpbiBhIHBheW1lbnQgdG9rZW4uAAAAAAx3aXRoZHJhd2FibGUAAAACAAAAAAAAAAVwYXllZQAAAAAAABMAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAQAAAAs=",
+        "AAAAAQAAAE8qIEB0aXRsZSBWb3VjaGVyCiAqIEBkZXYgVGhlIGFkbWluJ3Mgc2lnbmVkIHByb21pc2UgdG8gbWludCBhIHdlZWsgZm9yIGEgcHJpY2UuAAAAAAAAAAAHVm91Y2hlcgAAAAAFAAAANVRoZSBvbmx5IGJ1eWVyIHdobyBtYXkgcmVkZWVtIGl0LCBvciBOb25lIGZvciBhbnlvbmUuAAAAAAAABWJ1eWVyAAAAAAAD6AAAABMAAAAyTGVkZ2VyIHRpbWVzdGFtcCBhZnRlciB3aGljaCBpdCBjYW4ndCBiZSByZWRlZW1lZC4AAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAElRoZSB0b2tlbiB0byBtaW50LgAAAAAABGluZm8AAAfQAAAADVRpbWVzaGFyZUluZm8AAAAAAAAbVG9rZW4gdGhlIHByaWNlIGlzIHBhaWQgaW4uAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAAAAAAFcHJpY2UAAAAAAAAL",
+        "AAAABQAAAF4qIEB0aXRsZSBWb3VjaGVyUmVkZWVtZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIHZvdWNoZXIgaXMgcmVkZWVtZWQsIG5leHQgdG8gdGhlIE1pbnQgZXZlbnQuAAAAAAAAAAAAD1ZvdWNoZXJSZWRlZW1lZAAAAAABAAAAEHZvdWNoZXJfcmVkZWVtZWQAAAAEAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAVidXllcgAAAAAAABMAAAABAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAAAAAAC",
+        "AAAAAAAAAcwqIEBkZXYgUmVkZWVtcyBhIHZvdWNoZXI6IHRoZSBidXllciBwYXlzIGl0cyBwcmljZSB0byB0aGUgYWRtaW4gYW5kIGlzCiAgICAgKiBtaW50ZWQgaXRzIHRva2VuLiBGYWlscyB3aXRoIE5vVm91Y2hlclNpZ25lciBiZWZvcmUgYSBzaWduZXIgaXMKICAgICAqIHJlZ2lzdGVyZWQsIFdyb25nQnV5ZXIgaWYgdGhlIHZvdWNoZXIgbmFtZXMgYW5vdGhlciBidXllciwgYW5kCiAgICAgKiBWb3VjaGVyRXhwaXJlZCBvciBWb3VjaGVyUmVkZWVtZWQgb25jZSBpdCBjYW4ndCBiZSB1c2VkLiBBIGJhZCBzaWduYXR1cmUKICAgICAqIHRyYXBzLgogICAgICogQHBhcmFtIGJ1eWVyIFRoZSBidXllciAobXVzdCBzaWduIGFuZCBwYXkpLgogICAgICogQHBhcmFtIHNpZ25hdHVyZSBUaGUgc2lnbmVyJ3MgZWQyNTUxOSBzaWduYXR1cmUgb2YgdGhlIHZvdWNoZXIuCiAgICAgKiBAcmV0dXJuIFRoZSBuZXcgdG9rZW4gSUQuAAAADnJlZGVlbV92b3VjaGVyAAAAAAADAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAAB3ZvdWNoZXIAAAAH0AAAAAdWb3VjaGVyAAAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAABAAAD6QAAAAYAAAAD",
+        "AAAAAAAAAEYqIEBkZXYgUmV0dXJucyB0aGUga2V5IHZvdWNoZXJzIGFyZSBzaWduZWQgd2l0aCwgaWYgb25lIGlzIHJlZ2lzdGVyZWQuAAAAAAAOdm91Y2hlcl9zaWduZXIAAAAAAAAAAAABAAAD6AAAA+4AAAAg",
+        "AAAAAAAAAEcqIEBkZXYgUmV0dXJucyB0aGUgYnl0ZXMgYSB2b3VjaGVyJ3Mgc2lnbmF0dXJlIG11c3QgY292ZXIsIGZvciBzaWduZXJzLgAAAAAPdm91Y2hlcl9tZXNzYWdlAAAAAAEAAAAAAAAAB3ZvdWNoZXIAAAAH0AAAAAdWb3VjaGVyAAAAAAEAAAAO",
+        "AAAAAAAAAHMqIEBkZXYgUmVnaXN0ZXJzIHRoZSBlZDI1NTE5IHB1YmxpYyBrZXkgdm91Y2hlcnMgbXVzdCBiZSBzaWduZWQgd2l0aCwKICAgICAqIHJlcGxhY2luZyBhbnkgZWFybGllciBvbmUuIEFkbWluIG9ubHkuAAAAABJzZXRfdm91Y2hlcl9zaWduZXIAAAAAAAEAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAAAgAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAQAAADMqIEB0aXRsZSBBbGxvd2xpc3QKICogQGRldiBBbiBhbGxvd2xpc3Qgc2FsZSBwaGFzZS4AAAAAAAAAAAlBbGxvd2xpc3QAAAAAAAADAAAAG1Rva2VuIHRoZSBwcmljZSBpcyBwYWlkIGluLgAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAABXByaWNlAAAAAAAACwAAACZNZXJrbGUgcm9vdCBvZiB0aGUgZWxpZ2libGUgYWRkcmVzc2VzLgAAAAAABHJvb3QAAAPuAAAAIA==",
+        "AAAABQAAAGMqIEB0aXRsZSBBbGxvd2xpc3RNaW50ZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhbiBhbGxvd2xpc3RlZCBidXllciBtaW50cywgbmV4dCB0byB0aGUgTWludCBldmVudC4AAAAAAAAAAA9BbGxvd2xpc3RNaW50ZWQAAAAAAQAAABBhbGxvd2xpc3RfbWludGVkAAAAAwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAI=",
+        "AAAAAAAAADAqIEBkZXYgUmV0dXJucyB0aGUgb3BlbiBhbGxvd2xpc3QgcGhhc2UsIGlmIGFueS4AAAAJYWxsb3dsaXN0AAAAAAAAAAAAAAEAAAPoAAAH0AAAAAlBbGxvd2xpc3QAAAA=",
+        "AAAAAAAAAKkqIEBkZXYgT3BlbnMgYW4gYWxsb3dsaXN0IHBoYXNlLCByZXBsYWNpbmcgYW55IGVhcmxpZXIgb25lLiBBZG1pbiBvbmx5LgogICAgICogQHBhcmFtIGFsbG93bGlzdCBUaGUgcm9vdCwgcHJpY2UgYW5kIHBheW1lbnQgdG9rZW47IHRoZSBwcmljZSBtdXN0IG5vdAogICAgICogYmUgbmVnYXRpdmUuAAAAAAAADXNldF9hbGxvd2xpc3QAAAAAAAABAAAAAAAAAAlhbGxvd2xpc3QAAAAAAAfQAAAACUFsbG93bGlzdAAAAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAeUqIEBkZXYgTWludHMgYSB3ZWVrIHRvIGFuIGFsbG93bGlzdGVkIGJ1eWVyIGZvciB0aGUgcGhhc2UncyBwcmljZSwgcGFpZCB0bwogICAgICogdGhlIGFkbWluLiBGYWlscyB3aXRoIE5vQWxsb3dsaXN0IGlmIG5vIHBoYXNlIGlzIG9wZW4sIE5vdEFsbG93bGlzdGVkCiAgICAgKiBpZiB0aGUgcHJvb2YgZG9lc24ndCBsZWFkIHRvIHRoZSByb290LCBhbmQgQWxsb3dsaXN0Q2xhaW1lZCBpZiB0aGUgYnV5ZXIKICAgICAqIGFscmVhZHkgbWludGVkIGluIHRoaXMgcGhhc2UuCiAgICAgKiBAcGFyYW0gYnV5ZXIgVGhlIGJ1eWVyIChtdXN0IHNpZ24gYW5kIHBheSkuCiAgICAgKiBAcGFyYW0gcHJvb2YgVGhlIHNpYmxpbmcgaGFzaGVzIGZyb20gdGhlIGJ1eWVyJ3MgbGVhZiB1cCB0byB0aGUgcm9vdC4KICAgICAqIEBwYXJhbSBpbmZvIFRoZSB3ZWVrIHRvIG1pbnQ7IGl0IG11c3QgYmUgdW5zb2xkLgogICAgICogQHJldHVybiBUaGUgbmV3IHRva2VuIElELgAAAAAAAA5hbGxvd2xpc3RfbWludAAAAAAAAwAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAVwcm9vZgAAAAAAA+oAAAPuAAAAIAAAAAAAAAAEaW5mbwAAB9AAAAANVGltZXNoYXJlSW5mbwAAAAAAAAEAAAPpAAAABgAAAAM=",
+        "AAAAAAAAACwqIEBkZXYgRW5kcyB0aGUgYWxsb3dsaXN0IHBoYXNlLiBBZG1pbiBvbmx5LgAAAA9jbG9zZV9hbGxvd2xpc3QAAAAAAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAABQAAAAAAAAAAAAAACEFwcHJvdmFsAAAAAQAAAAhhcHByb3ZhbAAAAAMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADkFwcHJvdmFsRm9yQWxsAAAAAAABAAAAEGFwcHJvdmFsX2Zvcl9hbGwAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAAAAAAAAhvcGVyYXRvcgAAABMAAAABAAAAAAAAAAhhcHByb3ZlZAAAAAEAAAAAAAAAAg==",
+        "AAAAAAAAARsqIEBkZXYgTGV0cyBgc3BlbmRlcmAgdHJhbnNmZXIgYHRva2VuX2lkYCBvbiB0aGUgb3duZXIncyBiZWhhbGYsIHJlcGxhY2luZwogICAgICogYW55IGVhcmxpZXIgYXBwcm92YWwgZm9yIHRoYXQgdG9rZW4uCiAgICAgKiBAcGFyYW0gb3duZXIgVGhlIGN1cnJlbnQgb3duZXIgKG11c3Qgc2lnbikuCiAgICAgKiBAcGFyYW0gc3BlbmRlciBUaGUgYWRkcmVzcyBhbGxvd2VkIHRvIGNhbGwgdHJhbnNmZXJfZnJvbS4KICAgICAqIEBwYXJhbSB0b2tlbl9pZCBUaGUgdG9rZW4gYmVpbmcgYXBwcm92ZWQuAAAAAAdhcHByb3ZlAAAAAAMAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAHc3BlbmRlcgAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEMqIEBkZXYgUmV0dXJucyB0aGUgYWRkcmVzcyBhcHByb3ZlZCB0byB0cmFuc2ZlciBgdG9rZW5faWRgLCBpZiBhbnkuAAAAAAxnZXRfYXBwcm92ZWQAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6AAAABM=",
+        "AAAAAAAAAUUqIEBkZXYgVHJhbnNmZXJzIGEgdG9rZW4gb24gYmVoYWxmIG9mIGl0cyBvd25lci4gQSBzaW5nbGUtdG9rZW4gYXBwcm92YWwgaXMKICAgICAqIHVzZWQgdXA7IGFuIG9wZXJhdG9yIGFwcHJvdmFsIHN0YXlzIGluIHBsYWNlLgogICAgICogQHBhcmFtIHNwZW5kZXIgVGhlIGFwcHJvdmVkIGFkZHJlc3Mgb3IgYW4gb3BlcmF0b3Igb2YgYGZyb21gIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIGZyb20gVGhlIGN1cnJlbnQgb3duZXIuCiAgICAgKiBAcGFyYW0gdG8gVGhlIG5ldyBvd25lci4KICAgICAqIEBwYXJhbSB0b2tlbl9pZCBUaGUgdG9rZW4gdG8gdHJhbnNmZXIuAAAAAAAADXRyYW5zZmVyX2Zyb20AAAAAAAAEAAAAAAAAAAdzcGVuZGVyAAAAABMAAAAAAAAABGZyb20AAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAJAqIEBkZXYgUmV0dXJucyB0cnVlIGlmIGBvcGVyYXRvcmAgbWF5IHRyYW5zZmVyIGFueSBvZiBgb3duZXJgJ3MgdG9rZW5zLAogICAgICogdGhyb3VnaCBgc2V0X2FwcHJvdmFsX2Zvcl9hbGxgIG9yIGFuIHVuZXhwaXJlZCBgYXBwcm92ZV9mb3JfYWxsYC4AAAATaXNfYXBwcm92ZWRfZm9yX2FsbAAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAEAAAAB",
+        "AAAAAAAAATUqIEBkZXYgQXBwcm92ZXMgb3IgcmV2b2tlcyBgb3BlcmF0b3JgIGZvciBhbGwgb2YgYG93bmVyYCdzIHRva2VucywKICAgICAqIHJlcGxhY2luZyBhbiBhcHByb3ZhbF9mb3JfYWxsKCkgZXhwaXJ5LgogICAgICogQHBhcmFtIG93bmVyIFRoZSBvd25lciAobXVzdCBzaWduKS4KICAgICAqIEBwYXJhbSBvcGVyYXRvciBUaGUgYWRkcmVzcyBhbGxvd2VkIHRvIGNhbGwgdHJhbnNmZXJfZnJvbSBmb3IgYW55IG9mIHRoZSBvd25lcidzIHRva2Vucy4KICAgICAqIEBwYXJhbSBhcHByb3ZlZCB0cnVlIHRvIGFwcHJvdmUsIGZhbHNlIHRvIHJldm9rZS4AAAAAAAAUc2V0X2FwcHJvdmFsX2Zvcl9hbGwAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAAAAAAIYXBwcm92ZWQAAAABAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAQAAAEoqIEB0aXRsZSBEaXJlY3RvcnlFbnRyeQogKiBAZGV2IEFuIG93bmVyIGxpc3RlZCBpbiBhIHByb3BlcnR5J3MgZGlyZWN0b3J5LgAAAAAAAAAAAA5EaXJlY3RvcnlFbnRyeQAAAAAAAwAAAERIYXNoIG9mIHRoZSBvd25lcidzIGNvbnRhY3QgZGV0YWlscyBhbmQgYSBzYWx0LCBib3RoIGtlcHQgb2ZmLWNoYWluLgAAAAxjb250YWN0X2hhc2gAAAPuAAAAIAAAAAAAAAAFb3duZXIAAAAAAAATAAAAW0Fza3MgbGlzdCBidWlsZGVycyB0byB1c2UgdGhlIGNvbnRhY3QgZm9yIHByb3BlcnR5IG5vdGljZXMgb25seSBhbmQKbmV2ZXIgc2hhcmUgaXQgb253YXJkcy4AAAAAB3ByaXZhdGUAAAAAAQ==",
+        "AAAABQAAAGoqIEB0aXRsZSBEaXJlY3RvcnlDaGFuZ2VkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYW4gb3duZXIgam9pbnMsIHVwZGF0ZXMgb3IgbGVhdmVzIGEgcHJvcGVydHkncyBkaXJlY3RvcnkuAAAAAAAAAAAAEERpcmVjdG9yeUNoYW5nZWQAAAABAAAAEWRpcmVjdG9yeV9jaGFuZ2VkAAAAAAAAAwAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAAAAAAGbGlzdGVkAAAAAAABAAAAAAAAAAI=",
+        "AAAAAAAAAMcqIEBkZXYgTGlzdHMgYSBwcm9wZXJ0eSdzIGRpcmVjdG9yeSBlbnRyaWVzLCBpbiB0aGUgb3JkZXIgb3duZXJzIGpvaW5lZC4KICAgICAqIEBwYXJhbSBzdGFydCBUaGUgaW5kZXggb2YgdGhlIGZpcnN0IGVudHJ5LCAwIGZvciB0aGUgZmlyc3QgcGFnZS4KICAgICAqIEBwYXJhbSBsaW1pdCBUaGUgcGFnZSBzaXplLCBjYXBwZWQgYXQgTUFYX1BBR0UuAAAAAAlkaXJlY3RvcnkAAAAAAAADAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAADkRpcmVjdG9yeUVudHJ5AAA=",
+        "AAAAAAAAAP8qIEBkZXYgTGlzdHMgYW4gb3duZXIgaW4gdGhlIGRpcmVjdG9yeSBvZiBhIHRva2VuJ3MgcHJvcGVydHksIG9yIHVwZGF0ZXMKICAgICAqIHRoZWlyIGVudHJ5IHRoZXJlLgogICAgICogQHBhcmFtIG93bmVyIFRoZSB0b2tlbidzIG93bmVyIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIHRva2VuX2lkIEFueSB0b2tlbiBvZiB0aGUgcHJvcGVydHkgdGhlIG93bmVyIGhvbGRzLgogICAgICogQHJldHVybiBUaGUgcHJvcGVydHkncyBob3RlbCBJRC4AAAAADmpvaW5fZGlyZWN0b3J5AAAAAAAEAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAMY29udGFjdF9oYXNoAAAD7gAAACAAAAAAAAAAB3ByaXZhdGUAAAAAAQAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAEUqIEBkZXYgUmV0dXJucyBhbiBvd25lcidzIGVudHJ5IGluIGEgcHJvcGVydHkncyBkaXJlY3RvcnksIGlmIGxpc3RlZC4AAAAAAAAPZGlyZWN0b3J5X2VudHJ5AAAAAAIAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAA+gAAAfQAAAADkRpcmVjdG9yeUVudHJ5AAA=",
+        "AAAAAAAAAJwqIEBkZXYgUmVtb3ZlcyBhbiBvd25lciBmcm9tIGEgcHJvcGVydHkncyBkaXJlY3RvcnkuIExlYXZpbmcgYSBkaXJlY3RvcnkKICAgICAqIG9uZSBpc24ndCBpbiBkb2VzIG5vdGhpbmcuCiAgICAgKiBAcGFyYW0gb3duZXIgVGhlIGxpc3RlZCBvd25lciAobXVzdCBzaWduKS4AAAAPbGVhdmVfZGlyZWN0b3J5AAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAKAqIEBkZXYgUmVtb3ZlcyBhbiBvd25lciB3aG8gaG9sZHMgbm8gdG9rZW4gb2YgdGhlIHByb3BlcnR5IGFueSBtb3JlIGZyb20KICAgICAqIGl0cyBkaXJlY3RvcnkuIEFueW9uZSBtYXkgY2FsbCBpdC4KICAgICAqIEByZXR1cm4gV2hldGhlciB0aGUgb3duZXIgd2FzIHJlbW92ZWQuAAAAD3BydW5lX2RpcmVjdG9yeQAAAAACAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAPpAAAAAQAAAAM=",
+        "AAAAAQAAAEwqIEB0aXRsZSBGZWVJbmRleAogKiBAZGV2IEhvdyB0aGUgbWFpbnRlbmFuY2UgZmVlIGZvbGxvd3MgYW4gb3JhY2xlJ3MgaW5kZXguAAAAAAAAAAhGZWVJbmRleAAAAAgAAAAqVGhlIGZlZSB3aGVuIHRoZSBpbmRleCByZWFkcyBgYmFzZV9pbmRleGAuAAAAAAAIYmFzZV9mZWUAAAALAAAAQEEgcmVhZGluZyBvZiB0aGUgaW5kZXgsIGluIHRoZSBvcmFjbGUncyB1bml0czsgbXVzdCBiZSBwb3NpdGl2ZS4AAAAKYmFzZV9pbmRleAAAAAAACwAAAB9UaGUgaW5kZXgncyBmZWVkIG9uIHRoZSBvcmFjbGUuAAAAAARmZWVkAAAAEQAAADBTZWNvbmRzIGFmdGVyIHdoaWNoIGEgcmVhZGluZyBpcyB0b28gb2xkIHRvIHVzZS4AAAAHbWF4X2FnZQAAAAAGAAAAI1RoZSBoaWdoZXN0IHRoZSBpbmRleGVkIGZlZSBtYXkgZ28uAAAAAAdtYXhfZmVlAAAAAAsAAAA3VGhlIG1vc3QgdGhlIGZlZSBtYXkgbW92ZSBwZXIgeWVhciwgaW4gdGhlIGZlZSdzIHRva2VuLgAAAAAIbWF4X3N0ZXAAAAALAAAAIlRoZSBsb3dlc3QgdGhlIGluZGV4ZWQgZmVlIG1heSBnby4AAAAAAAdtaW5fZmVlAAAAAAsAAAAnVGhlIFNFUC00MCBvcmFjbGUgcHVibGlzaGluZyB0aGUgaW5kZXguAAAAAAZvcmFjbGUAAAAAABM=",
+        "AAAAAQAAADMqIEB0aXRsZSBQcmljZURhdGEKICogQGRldiBBIFNFUC00MCBvcmFjbGUgcmVhZGluZy4AAAAAAAAAAAlQcmljZURhdGEAAAAAAAACAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAgTGVkZ2VyIHRpbWVzdGFtcCBvZiB0aGUgcmVhZGluZy4AAAAJdGltZXN0YW1wAAAAAAAABg==",
+        "AAAAAgAAAGMqIEB0aXRsZSBGZWVJbmRleGluZwogKiBAZGV2IFdoZXRoZXIgdGhlIG1haW50ZW5hbmNlIGZlZSBmb2xsb3dzIGFuIGluZGV4LCBhcyByZXBvcnRlZCBieSBjb25maWcoKS4AAAAAAAAAAAtGZWVJbmRleGluZwAAAAACAAAAAAAAAAAAAAAGTWFudWFsAAAAAAABAAAAAAAAAAdJbmRleGVkAAAAAAEAAAfQAAAACEZlZUluZGV4",
+        "AAAAAgAAAGMqIEB0aXRsZSBPcmFjbGVBc3NldAogKiBAZGV2IEEgU0VQLTQwIG9yYWNsZSBmZWVkOiBhIFN0ZWxsYXIgYXNzZXQgb3IgYW55IG90aGVyIHN5bWJvbCwgZS5nLiBgQ1BJYC4AAAAAAAAAAAtPcmFjbGVBc3NldAAAAAACAAAAAQAAAAAAAAAHU3RlbGxhcgAAAAABAAAAEwAAAAEAAAAAAAAABU90aGVyAAAAAAAAAQAAABE=",
+        "AAAABQAAAGUqIEB0aXRsZSBNYWludGVuYW5jZUZlZUluZGV4ZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiB0aGUgbWFpbnRlbmFuY2UgZmVlIGlzIHJlcHJpY2VkIGZyb20gdGhlIGluZGV4LgAAAAAAAAAAAAAVTWFpbnRlbmFuY2VGZWVJbmRleGVkAAAAAAAAAQAAABdtYWludGVuYW5jZV9mZWVfaW5kZXhlZAAAAAAEAAAAAAAAAAR5ZWFyAAAABAAAAAEAAAAXVGhlIGluZGV4IHJlYWRpbmcgdXNlZC4AAAAABWluZGV4AAAAAAAACwAAAAAAAAAAAAAACHByZXZpb3VzAAAACwAAAAAAAAAAAAAAA2ZlZQAAAAALAAAAAAAAAAI=",
+        "AAAAAAAAAEQqIEBkZXYgUmV0dXJucyBob3cgdGhlIG1haW50ZW5hbmNlIGZlZSBmb2xsb3dzIGFuIGluZGV4LCBpZiBpdCBkb2VzLgAAAAlmZWVfaW5kZXgAAAAAAAAAAAAAAQAAA+gAAAfQAAAACEZlZUluZGV4",
+        "AAAAAAAAAO0qIEBkZXYgTGlua3MgdGhlIG1haW50ZW5hbmNlIGZlZSB0byBhbiBvcmFjbGUncyBpbmRleCwgb3IgdW5saW5rcyBpdCB3aXRoCiAgICAgKiBOb25lLiBBZG1pbiBvbmx5LiBGYWlscyB3aXRoIEludmFsaWRBbW91bnQgZm9yIG5lZ2F0aXZlIGZlZXMsIGEgYmFzZQogICAgICogaW5kZXggdGhhdCBpc24ndCBwb3NpdGl2ZSwgYSBuZWdhdGl2ZSBzdGVwLCBvciBhIGZsb29yIGFib3ZlIHRoZQogICAgICogY2VpbGluZy4AAAAAAAANc2V0X2ZlZV9pbmRleAAAAAAAAAEAAAAAAAAABWluZGV4AAAAAAAD6AAAB9AAAAAIRmVlSW5kZXgAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAHcqIEBkZXYgUmV0dXJucyB0aGUgbGFzdCB5ZWFyIG1haW50ZW5hbmNlIGZlZXMgd2VyZSBjb2xsZWN0ZWQgaW4sIHdoaWNoCiAgICAgKiBmaXhlcyB0aGF0IHllYXIncyBmZWUsIGlmIHRoZXkgaGF2ZSBiZWVuLgAAAAAPZmVlX2JpbGxlZF95ZWFyAAAAAAAAAAABAAAD6AAAAAQ=",
+        "AAAAAAAAAGQqIEBkZXYgUmV0dXJucyB0aGUgbGFzdCB5ZWFyIHRoZSBtYWludGVuYW5jZSBmZWUgd2FzIHJlcHJpY2VkIGZyb20gdGhlCiAgICAgKiBpbmRleCwgaWYgaXQgaGFzIGJlZW4uAAAAEGZlZV9pbmRleGVkX3llYXIAAAAAAAAAAQAAA+gAAAAE",
+        "AAAAAAAAAcsqIEBkZXYgUmVwcmljZXMgdGhlIG1haW50ZW5hbmNlIGZlZSBmcm9tIHRoZSBvcmFjbGUncyBsYXRlc3QgcmVhZGluZyBvZgogICAgICogdGhlIGluZGV4LCB3aXRoaW4gdGhlIGNhcHMuIEFueW9uZSBtYXkgY2FsbCBpdCwgb25jZSBwZXIgY2FsZW5kYXIgeWVhci4KICAgICAqIEZhaWxzIHdpdGggTm9GZWVJbmRleCBpZiB0aGUgZmVlIGlzbid0IGluZGV4ZWQsIEludmFsaWRBbW91bnQgd2l0aG91dCBhCiAgICAgKiBmZWUgc2NoZWR1bGUsIEZlZUFscmVhZHlJbmRleGVkIGlmIHRoaXMgeWVhcidzIGZlZSBpcyBzZXQsCiAgICAgKiBGZWVZZWFyQmlsbGVkIG9uY2UgdGhpcyB5ZWFyJ3MgZmVlcyBhcmUgYmVpbmcgY29sbGVjdGVkLCBhbmQKICAgICAqIFN0YWxlSW5kZXggd2l0aG91dCBhIHJlYWRpbmcgbmV3ZXIgdGhhbiB0aGUgaW5kZXgncyBtYXggYWdlLgogICAgICogQHJldHVybiBUaGUgbmV3IGZlZS4AAAAAFWluZGV4X21haW50ZW5hbmNlX2ZlZQAAAAAAAAAAAAABAAAD6QAAAAsAAAAD",
+        "AAAABQAAAGMqIEB0aXRsZSBTY2hlbWFNaWdyYXRlZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIG1pZ3JhdGUoKSBicmluZ3MgdGhlIHN0b3JlZCBsYXlvdXQgdG8gYSBuZXcgdmVyc2lvbi4AAAAAAAAAAA5TY2hlbWFNaWdyYXRlZAAAAAAAAQAAAA9zY2hlbWFfbWlncmF0ZWQAAAAAAgAAAAAAAAAEZnJvbQAAAAQAAAAAAAAAAAAAAAJ0bwAAAAAABAAAAAAAAAAC",
+        "AAAAAAAAALsqIEBkZXYgUnVucyBldmVyeSBtaWdyYXRpb24gc3RlcCBiZXR3ZWVuIHRoZSBzdG9yZWQgbGF5b3V0IHZlcnNpb24gYW5kCiAgICAgKiBTQ0hFTUFfVkVSU0lPTi4gQWRtaW4gb25seTsgYSBuby1vcCBvbmNlIHRoZSBsYXlvdXQgaXMgY3VycmVudC4KICAgICAqIEByZXR1cm4gVGhlIGxheW91dCB2ZXJzaW9uIG5vdyBzdG9yZWQuAAAAAAdtaWdyYXRlAAAAAAAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAADUqIEBkZXYgUmV0dXJucyB0aGUgbGF5b3V0IHZlcnNpb24gb2YgdGhlIHN0b3JlZCBkYXRhLgAAAAAAAA5zY2hlbWFfdmVyc2lvbgAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAQAqIEBkZXYgTW92ZXMgdGhlIGxpc3RlZCBlbnRyaWVzIGZyb20gdGhlaXIgdmVyc2lvbiAxIGxvY2F0aW9uIChpbnN0YW5jZQogICAgICogc3RvcmFnZSkgdG8gcGVyc2lzdGVudCBzdG9yYWdlLCBza2lwcGluZyBrZXlzIHRoYXQgYXJlbid0IHRoZXJlLiBBZG1pbiBvbmx5LgogICAgICogQHBhcmFtIGtleXMgQXQgbW9zdCBNQVhfTUlOVF9CQVRDSCBrZXlzIHBlciBjYWxsLgogICAgICogQHJldHVybiBIb3cgbWFueSBlbnRyaWVzIHdlcmUgbW92ZWQuAAAAD21pZ3JhdGVfZW50cmllcwAAAAABAAAAAAAAAARrZXlzAAAD6gAAB9AAAAAHRGF0YUtleQAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAQAAAEAqIEB0aXRsZSBNaW50UHJpY2UKICogQGRldiBXaGF0IGEgaG90ZWwncyB1bnNvbGQgd2Vla3Mgc2VsbCBmb3IuAAAAAAAAAAlNaW50UHJpY2UAAAAAAAACAAAAG1Rva2VuIHRoZSBwcmljZSBpcyBwYWlkIGluLgAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAABXByaWNlAAAAAAAACw==",
+        "AAAABQAAAGMqIEB0aXRsZSBNaW50UHVyY2hhc2VkCiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBidXllciBwYXlzIGZvciBhIG5ldyB0b2tlbiwgbmV4dCB0byB0aGUgTWludCBldmVudC4AAAAAAAAAAA1NaW50UHVyY2hhc2VkAAAAAAAAAQAAAA5taW50X3B1cmNoYXNlZAAAAAAABAAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAAAg==",
+        "AAAAAAAAAVEqIEBkZXYgQnV5cyBhbiB1bnNvbGQgd2VlazogcGF5cyB0aGUgaG90ZWwncyBwcmljZSBhbmQgbWludHMgdGhlIHdlZWsgdG8KICAgICAqIHRoZSBidXllci4gRmFpbHMgd2l0aCBOb3RMaXN0ZWQgaWYgdGhlIGhvdGVsJ3Mgd2Vla3MgYXJlbid0IG9uIHNhbGUuCiAgICAgKiBAcGFyYW0gYnV5ZXIgVGhlIGJ1eWVyIChtdXN0IHNpZ24gYW5kIHBheSkuCiAgICAgKiBAcGFyYW0gcm9vbSBUaGUgcm9vbSwgZnJvbSB0aGUgaG90ZWwncyBpbnZlbnRvcnkuCiAgICAgKiBAcGFyYW0gd2VlayBUaGUgd2VlayBvZiB0aGUgeWVhciAoMS01MikuCiAgICAgKiBAcmV0dXJuIFRoZSBuZXcgdG9rZW4gSUQuAAAAAAAACGJ1eV9taW50AAAABAAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAABHJvb20AAAAQAAAAAAAAAAR3ZWVrAAAABAAAAAEAAAPpAAAABgAAAAM=",
+        "AAAAAAAAAEkqIEBkZXYgUmV0dXJucyB3aGF0IGEgaG90ZWwncyB1bnNvbGQgd2Vla3Mgc2VsbCBmb3IsIGlmIHRoZXkgYXJlIG9uIHNhbGUuAAAAAAAACm1pbnRfcHJpY2UAAAAAAAEAAAAAAAAACGhvdGVsX2lkAAAABAAAAAEAAAPoAAAH0AAAAAlNaW50UHJpY2UAAAA=",
+        "AAAAAAAAAQwqIEBkZXYgUHV0cyBhIGhvdGVsJ3MgdW5zb2xkIHdlZWtzIG9uIHNhbGUsIG9yIGNoYW5nZXMgdGhlaXIgcHJpY2UuCiAgICAgKiBNYW5hZ2VyIHJvbGUgKG9yIGFkbWluKS4KICAgICAqIEBwYXJhbSBjYWxsZXIgVGhlIG1hbmFnZXIgKG11c3Qgc2lnbikuCiAgICAgKiBAcGFyYW0gcHJpY2UgVGhlIHByaWNlIG9mIGEgd2VlazsgbXVzdCBub3QgYmUgbmVnYXRpdmUuCiAgICAgKiBAcGFyYW0gcGF5bWVudF90b2tlbiBUaGUgdG9rZW4gdGhlIHByaWNlIGlzIHBhaWQgaW4uAAAADnNldF9taW50X3ByaWNlAAAAAAAEAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAG0qIEBkZXYgVGFrZXMgYSBob3RlbCdzIHdlZWtzIG9mZiBzYWxlLiBNYW5hZ2VyIHJvbGUgKG9yIGFkbWluKS4KICAgICAqIEBwYXJhbSBjYWxsZXIgVGhlIG1hbmFnZXIgKG11c3Qgc2lnbikuAAAAAAAAEGNsZWFyX21pbnRfcHJpY2UAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACGhvdGVsX2lkAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAQAAADoqIEB0aXRsZSBEZWFkbGluZQogKiBAZGV2IEFuIHVwY29taW5nIGRlYWRsaW5lIG9mIGEgdG9rZW4uAAAAAAAAAAAACERlYWRsaW5lAAAAAwAAAB1MZWRnZXIgdGltZXN0YW1wIGl0IGZhbGxzIGF0LgAAAAAAAAJhdAAAAAAABgAAAAAAAAAEa2luZAAAB9AAAAAMRGVhZGxpbmVLaW5kAAAAK1dobyB0byBub3RpZnk6IHRoZSBvd25lciwgbGlzdGVyIG9yIHNlbGxlci4AAAAABXBhcnR5AAAAAAAAEw==",
+        "AAAAAgAAAE0qIEB0aXRsZSBEZWFkbGluZUtpbmQKICogQGRldiBXaGF0IGEgZGVhZGxpbmUgaXMgZm9yOyBzZWUgdGhlIG1vZHVsZSBjb21tZW50LgAAAAAAAAAAAAAMRGVhZGxpbmVLaW5kAAAAAwAAAAAAAAAAAAAABkZlZUR1ZQAAAAAAAAAAAAAAAAANQm9va2luZ1dpbmRvdwAAAAAAAAAAAAAAAAAADUF1Y3Rpb25FbmRpbmcAAAA=",
+        "AAAABQAAAG8qIEB0aXRsZSBEZWFkbGluZUFwcHJvYWNoaW5nCiAqIEBkZXYgUHVibGlzaGVkIG9uY2Ugd2hlbiBhIHRva2VuJ3MgZGVhZGxpbmUgaXMgbGVzcyB0aGFuIFJFTUlOREVSX0xFQUQKICogYXdheS4AAAAAAAAAABNEZWFkbGluZUFwcHJvYWNoaW5nAAAAAAEAAAAUZGVhZGxpbmVfYXBwcm9hY2hpbmcAAAAEAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAARraW5kAAAH0AAAAAxEZWFkbGluZUtpbmQAAAABAAAAAAAAAAVwYXJ0eQAAAAAAABMAAAAAAAAAAAAAAAJhdAAAAAAABgAAAAAAAAAC",
+        "AAAAAAAAAEEqIEBkZXYgTGlzdHMgYSB0b2tlbidzIHVwY29taW5nIGRlYWRsaW5lcywgaW4gRGVhZGxpbmVLaW5kIG9yZGVyLgAAAAAAAAlkZWFkbGluZXMAAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6QAAA+oAAAfQAAAACERlYWRsaW5lAAAAAw==",
+        "AAAAAAAAAPUqIEBkZXYgUHVibGlzaGVzIERlYWRsaW5lQXBwcm9hY2hpbmcgZm9yIGVhY2ggb2YgYSB0b2tlbidzIGRlYWRsaW5lcyBsZXNzCiAgICAgKiB0aGFuIFJFTUlOREVSX0xFQUQgYXdheSB0aGF0IGhhc24ndCBiZWVuIHJlbWluZGVkIHlldC4gQW55b25lIG1heSBjYWxsCiAgICAgKiBpdCwgZS5nLiBhIGtlZXBlciBzd2VlcGluZyB0b2tlbnMoKS4KICAgICAqIEByZXR1cm4gSG93IG1hbnkgcmVtaW5kZXJzIHdlcmUgcHVibGlzaGVkLgAAAAAAAA5zZW5kX3JlbWluZGVycwAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAQAAAEAqIEB0aXRsZSBSb3lhbHR5CiAqIEBkZXYgQSBob3RlbCdzIGN1dCBvZiByZXNhbGVzIG9mIGl0cyB0b2tlbnMuAAAAAAAAAAdSb3lhbHR5AAAAAAIAAAArVGhlIGN1dCwgaW4gYmFzaXMgcG9pbnRzIG9mIHRoZSBzYWxlIHByaWNlLgAAAAADYnBzAAAAAAQAAAAXUmVjZWl2ZXMgdGhlIHJveWFsdGllcy4AAAAABnBheW91dAAAAAAAEw==",
+        "AAAABQAAAEUqIEB0aXRsZSBSb3lhbHR5U2V0CiAqIEBkZXYgUHVibGlzaGVkIHdoZW4gYSBob3RlbCdzIHJveWFsdHkgY2hhbmdlcy4AAAAAAAAAAAAAClJveWFsdHlTZXQAAAAAAAEAAAALcm95YWx0eV9zZXQAAAAAAwAAAAAAAAAIaG90ZWxfaWQAAAAEAAAAAQAAAAAAAAAGcGF5b3V0AAAAAAATAAAAAAAAAAAAAAADYnBzAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAE4qIEB0aXRsZSBSb3lhbHR5UGFpZAogKiBAZGV2IFB1Ymxpc2hlZCB3aGVuIGEgcmVzYWxlIHBheXMgYSBob3RlbCBpdHMgcm95YWx0eS4AAAAAAAAAAAALUm95YWx0eVBhaWQAAAAAAQAAAAxyb3lhbHR5X3BhaWQAAAAEAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAhob3RlbF9pZAAAAAQAAAABAAAAAAAAAAZwYXlvdXQAAAAAABMAAAAAAAAANldoYXQgdGhlIHBheW91dCByZWNlaXZlZCwgYWZ0ZXIgYW55IGNoYXJpdHkgZG9uYXRpb25zLgAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAAAAAAAEwqIEBkZXYgUmV0dXJucyB0aGUgcm95YWx0eSBvd2VkIG9uIHJlc2FsZXMgb2YgYSB0b2tlbiwgaWYgaXRzIGhvdGVsIHNldCBvbmUuAAAAC2dldF9yb3lhbHR5AAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPpAAAD6AAAB9AAAAAHUm95YWx0eQAAAAAD",
+        "AAAAAAAAAOQqIEBkZXYgU2V0cyBhIGhvdGVsJ3Mgcm95YWx0eS4gTWFuYWdlciByb2xlIChvciBhZG1pbikuCiAgICAgKiBAcGFyYW0gY2FsbGVyIFRoZSBtYW5hZ2VyIChtdXN0IHNpZ24pLgogICAgICogQHBhcmFtIHBheW91dCBSZWNlaXZlcyB0aGUgcm95YWx0aWVzLgogICAgICogQHBhcmFtIGJwcyBUaGUgY3V0IGluIGJhc2lzIHBvaW50cywgYXQgbW9zdCBNQVhfUk9ZQUxUWV9CUFM7IDAgdGFrZXMgbm9uZS4AAAALc2V0X3JveWFsdHkAAAAABAAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAhob3RlbF9pZAAAAAQAAAAAAAAABnBheW91dAAAAAAAEwAAAAAAAAADYnBzAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAQAAADwqIEB0aXRsZSBBcHByYWlzYWwKICogQGRldiBBIG1hbmFnZXIncyBhcHByYWlzYWwgb2YgYSB0b2tlbi4AAAAAAAAACUFwcHJhaXNhbAAAAAAAAAMAAAAiTGVkZ2VyIHRpbWVzdGFtcCBvZiB0aGUgYXBwcmFpc2FsLgAAAAAADGFwcHJhaXNlZF9hdAAAAAYAAAAAAAAABWFzc2V0AAAAAAAAEwAAAAAAAAAFdmFsdWUAAAAAAAAL",
+        "AAAAAQAAADUqIEB0aXRsZSBQcmljZU1hcmsKICogQGRldiBXaGF0IGEgdG9rZW4gaGFzIHNvbGQgZm9yLgAAAAAAAAAAAAAJUHJpY2VNYXJrAAAAAAAABAAAAEJUaGUgYXNzZXQgb2YgdGhlIGxhc3Qgc2FsZS4gQSBzYWxlIGluIGFub3RoZXIgYXNzZXQgc3RhcnRzIGFmcmVzaC4AAAAAAAVhc3NldAAAAAAAABMAAAA0VGhlIHRpbWUtd2VpZ2h0ZWQgYXZlcmFnZSBwcmljZSB1cCB0byB0aGUgbGFzdCBzYWxlLgAAAAdhdmVyYWdlAAAAAAsAAAAUVGhlIGxhc3Qgc2FsZSBwcmljZS4AAAAFcHJpY2UAAAAAAAALAAAAIkxlZGdlciB0aW1lc3RhbXAgb2YgdGhlIGxhc3Qgc2FsZS4AAAAAAAdzb2xkX2F0AAAAAAY=",
+        "AAAABQAAAEYqIEB0aXRsZSBBcHByYWlzZWQKICogQGRldiBQdWJsaXNoZWQgd2hlbiBhIG1hbmFnZXIgYXBwcmFpc2VzIGEgdG9rZW4uAAAAAAAAAAAACUFwcHJhaXNlZAAAAAAAAAEAAAAJYXBwcmFpc2VkAAAAAAAAAwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAAAAAAAAAAAFdmFsdWUAAAAAAAALAAAAAAAAAAI=",
+        "AAAAAAAAADIqIEBkZXYgUmV0dXJucyBhIHRva2VuJ3MgYXBwcmFpc2FsLCBpZiBpdCBoYXMgb25lLgAAAAAACWFwcHJhaXNhbAAAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPoAAAH0AAAAAlBcHByYWlzYWwAAAA=",
+        "AAAAAAAAADkqIEBkZXYgUmV0dXJucyB3aGF0IGEgdG9rZW4gaGFzIHNvbGQgZm9yLCBpZiBpdCBoYXMgc29sZC4AAAAAAAAKcHJpY2VfbWFyawAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAfQAAAACVByaWNlTWFyawAAAA==",
+        "AAAAAAAAAKwqIEBkZXYgUmV0dXJucyBhIHRva2VuJ3MgdmFsdWUgaW4gYW4gYXNzZXQ6IGl0cyBhcHByYWlzYWwgb3IgaXRzCiAgICAgKiB0aW1lLXdlaWdodGVkIGF2ZXJhZ2Ugc2FsZSBwcmljZSwgd2hpY2hldmVyIGlzIG1vcmUgcmVjZW50LCBvciAwIGlmCiAgICAgKiBuZWl0aGVyIGlzIGluIHRoYXQgYXNzZXQuAAAAC3Rva2VuX3ZhbHVlAAAAAAIAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAP0qIEBkZXYgQXBwcmFpc2VzIGEgdG9rZW4sIHJlcGxhY2luZyBhbnkgZWFybGllciBhcHByYWlzYWwuIE1hbmFnZXIgcm9sZQogICAgICogKG9yIGFkbWluKS4gSXQgdmFsdWVzIHRoZSB0b2tlbiB1bnRpbCB0aGUgdG9rZW4gbmV4dCBzZWxscy4KICAgICAqIEBwYXJhbSBjYWxsZXIgVGhlIG1hbmFnZXIgKG11c3Qgc2lnbikuCiAgICAgKiBAcGFyYW0gdmFsdWUgSW4gdGhlIGFzc2V0J3MgYmFzZSB1bml0czsgbXVzdCBub3QgYmUgbmVnYXRpdmUuAAAAAAAADXNldF9hcHByYWlzYWwAAAAAAAAEAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAAAAAAV2YWx1ZQAAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAALUqIEBkZXYgUmV0dXJucyB0aGUgdmFsdWUgaW4gYW4gYXNzZXQgb2YgZXZlcnl0aGluZyBhbiBvd25lciBob2xkczogdGhlaXIKICAgICAqIHRva2VucywgYW5kIHRoZWlyIHBhcnQgb2YgZWFjaCBmcmFjdGlvbmFsaXplZCB0b2tlbiB0aGV5IGhvbGQgc2hhcmVzCiAgICAgKiBvZiwgcm91bmRlZCBoYWxmIHRvIGV2ZW4uAAAAAAAAD3BvcnRmb2xpb192YWx1ZQAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAABWFzc2V0AAAAAAAAEwAAAAEAAAAL" ]),
+      options
+    )
+  }
+  public readonly fromJSON = {
+    collection_offer: this.txFromJSON<Option<CollectionOffer>>,
+        fill_collection_offer: this.txFromJSON<Result<void>>,
+        make_collection_offer: this.txFromJSON<Result<u32>>,
+        collection_offer_count: this.txFromJSON<u32>,
+        cancel_collection_offer: this.txFromJSON<Result<void>>,
+        redeem: this.txFromJSON<Result<void>>,
+        retirement: this.txFromJSON<Option<Retirement>>,
+        sweep_property: this.txFromJSON<Result<Retirement>>,
+        fund_retirement: this.txFromJSON<Result<Retirement>>,
+        retire_property: this.txFromJSON<Result<void>>,
+        deprecations: this.txFromJSON<Map<string, Deprecation>>,
+        deprecate_endpoint: this.txFromJSON<Result<void>>,
+        undeprecate_endpoint: this.txFromJSON<Result<void>>,
+        tokens: this.txFromJSON<TokenPage>,
+        balance_of: this.txFromJSON<u32>,
+        total_supply: this.txFromJSON<u64>,
+        tokens_of_owner: this.txFromJSON<Array<u64>>,
+        pay_maintenance: this.txFromJSON<Result<MaintenanceStatus>>,
+        delinquent_tokens: this.txFromJSON<TokenPage>,
+        maintenance_status: this.txFromJSON<Result<MaintenanceStatus>>,
+        maintenance_schedule: this.txFromJSON<Option<MaintenanceSchedule>>,
+        set_maintenance_schedule: this.txFromJSON<Result<void>>,
+        withheld: this.txFromJSON<i128>,
+        is_exempt: this.txFromJSON<boolean>,
+        withholding: this.txFromJSON<Option<WithholdingRule>>,
+        withholdings: this.txFromJSON<Map<u32, WithholdingRule>>,
+        set_withholding: this.txFromJSON<Result<void>>,
+        revoke_exemption: this.txFromJSON<Result<void>>,
+        register_exemption: this.txFromJSON<Result<void>>,
+        capabilities: this.txFromJSON<Array<string>>,
+        label: this.txFromJSON<Option<string>>,
+        labels: this.txFromJSON<Map<LabelCode, string>>,
+        locales: this.txFromJSON<Array<string>>,
+        set_labels: this.txFromJSON<Result<void>>,
+        fund_relief: this.txFromJSON<Result<i128>>,
+        relief_fund: this.txFromJSON<i128>,
+        force_majeure: this.txFromJSON<Option<ForceMajeureFlag>>,
+        withdraw_relief: this.txFromJSON<Result<i128>>,
+        flag_force_majeure: this.txFromJSON<Result<void>>,
+        lift_force_majeure: this.txFromJSON<Result<void>>,
+        replacement_credits: this.txFromJSON<u32>,
+        settle_force_majeure: this.txFromJSON<Result<void>>,
+        force_majeure_settled: this.txFromJSON<Option<u32>>,
+        redeem_replacement_credit: this.txFromJSON<Result<u32>>,
+        get_notification_prefs: this.txFromJSON<u32>,
+        set_notification_prefs: this.txFromJSON<Result<void>>,
+        mint: this.txFromJSON<Result<u64>>,
+        mint_by: this.txFromJSON<Result<u64>>,
+        minter_state: this.txFromJSON<Option<MinterState>>,
+        bump_portfolio: this.txFromJSON<Result<Option<u32>>>,
+        extend_token_ttl: this.txFromJSON<Result<void>>,
+        get_info: this.txFromJSON<Result<TimeshareInfo>>,
+        transfer: this.txFromJSON<Result<void>>,
+        get_owner: this.txFromJSON<Result<string>>,
+        get_token: this.txFromJSON<Result<Token>>,
+        initialize: this.txFromJSON<Result<void>>,
+        mint_batch: this.txFromJSON<Result<Array<u64>>>,
+        list_many: this.txFromJSON<Result<Array<u32>>>,
+        cancel_many: this.txFromJSON<Result<Array<u32>>>,
+        reprice_many: this.txFromJSON<Result<Array<u32>>>,
+        burn: this.txFromJSON<Result<void>>,
+        admin_burn: this.txFromJSON<Result<void>>,
+        late_fee: this.txFromJSON<Result<i128>>,
+        delinquency: this.txFromJSON<Option<Delinquency>>,
+        late_fee_policy: this.txFromJSON<Option<LateFeePolicy>>,
+        check_delinquency: this.txFromJSON<Result<u32>>,
+        set_late_fee_policy: this.txFromJSON<Result<void>>,
+        keeper_bump: this.txFromJSON<Result<Option<u32>>>,
+        rent_policy: this.txFromJSON<Option<RentPolicy>>,
+        deposit_rent: this.txFromJSON<Result<i128>>,
+        rent_balance: this.txFromJSON<i128>,
+        withdraw_rent: this.txFromJSON<Result<i128>>,
+        set_rent_policy: this.txFromJSON<Result<void>>,
+        swap: this.txFromJSON<Result<void>>,
+        is_active: this.txFromJSON<Result<boolean>>,
+        accept_admin: this.txFromJSON<Result<void>>,
+        pending_admin: this.txFromJSON<Option<string>>,
+        propose_admin: this.txFromJSON<Result<void>>,
+        set_alias: this.txFromJSON<Result<void>>,
+        remove_alias: this.txFromJSON<Result<void>>,
+        resolve_alias: this.txFromJSON<Option<string>>,
+        transfer_to_alias: this.txFromJSON<Result<void>>,
+        floor_price: this.txFromJSON<Option<i128>>,
+        market_depth: this.txFromJSON<MarketDepth>,
+        buy_dutch: this.txFromJSON<Result<i128>>,
+        dutch_price: this.txFromJSON<Result<i128>>,
+        dutch_auction: this.txFromJSON<Option<DutchAuction>>,
+        start_dutch_auction: this.txFromJSON<Result<void>>,
+        policy: this.txFromJSON<Map<GraceRule, u64>>,
+        cancel_grace_period: this.txFromJSON<Result<void>>,
+        execute_grace_period: this.txFromJSON<Result<void>>,
+        pending_grace_period: this.txFromJSON<Option<PendingGracePeriod>>,
+        propose_grace_period: this.txFromJSON<Result<void>>,
+        minor_units: this.txFromJSON<Map<string, i128>>,
+        set_minor_unit: this.txFromJSON<Result<void>>,
+        pause: this.txFromJSON<Result<void>>,
+        paused: this.txFromJSON<boolean>,
+        unpause: this.txFromJSON<Result<void>>,
+        has_role: this.txFromJSON<boolean>,
+        grant_role: this.txFromJSON<Result<void>>,
+        revoke_role: this.txFromJSON<Result<void>>,
+        add_room: this.txFromJSON<Result<void>>,
+        list_rooms: this.txFromJSON<Array<string>>,
+        retire_room: this.txFromJSON<Result<void>>,
+        force_transfer: this.txFromJSON<Result<void>>,
+        is_sink_address: this.txFromJSON<boolean>,
+        add_sink_address: this.txFromJSON<Result<void>>,
+        remove_sink_address: this.txFromJSON<Result<void>>,
+        token_by_slot: this.txFromJSON<Option<u64>>,
+        get_trust: this.txFromJSON<Option<Trust>>,
+        place_in_trust: this.txFromJSON<Result<void>>,
+        propose_beneficiary: this.txFromJSON<Result<void>>,
+        acknowledge_transfer: this.txFromJSON<Result<void>>,
+        execute_beneficiary_change: this.txFromJSON<Result<void>>,
+        usage: this.txFromJSON<Result<Usage>>,
+        check_in: this.txFromJSON<Result<void>>,
+        check_out: this.txFromJSON<Result<void>>,
+        confirm_check_in: this.txFromJSON<Result<void>>,
+        join_chain: this.txFromJSON<Result<void>>,
+        swap_chain: this.txFromJSON<Option<SwapChain>>,
+        chain_count: this.txFromJSON<u32>,
+        propose_chain: this.txFromJSON<Result<u32>>,
+        withdraw_chain: this.txFromJSON<Result<void>>,
+        config: this.txFromJSON<Result<ContractConfig>>,
+        trade: this.txFromJSON<Option<Trade>>,
+        open_trade: this.txFromJSON<Result<void>>,
+        accept_trade: this.txFromJSON<Result<void>>,
+        withdraw_trade: this.txFromJSON<Result<void>>,
+        daily_root: this.txFromJSON<Option<EventChain>>,
+        event_lanes: this.txFromJSON<Array<EventChain>>,
+        daily_root_lanes: this.txFromJSON<Option<Array<Buffer>>>,
+        event_chain_head: this.txFromJSON<EventChain>,
+        commit_daily_root: this.txFromJSON<Result<u64>>,
+        freeze: this.txFromJSON<Result<void>>,
+        unfreeze: this.txFromJSON<Result<void>>,
+        is_frozen: this.txFromJSON<boolean>,
+        get_hotel: this.txFromJSON<Result<Hotel>>,
+        list_hotels: this.txFromJSON<Array<Hotel>>,
+        update_hotel: this.txFromJSON<Result<Hotel>>,
+        register_hotel: this.txFromJSON<Result<u32>>,
+        buy: this.txFromJSON<Result<void>>,
+        listing: this.txFromJSON<Option<Listing>>,
+        list_for_sale: this.txFromJSON<Result<void>>,
+        cancel_listing: this.txFromJSON<Result<void>>,
+        offer: this.txFromJSON<Option<Offer>>,
+        make_offer: this.txFromJSON<Result<void>>,
+        accept_offer: this.txFromJSON<Result<void>>,
+        cancel_offer: this.txFromJSON<Result<void>>,
+        prepayment: this.txFromJSON<Option<Prepayment>>,
+        fee_schedule: this.txFromJSON<Result<Array<FeeQuote>>>,
+        prepaid_years: this.txFromJSON<Option<PrepaidYears>>,
+        release_prepaid: this.txFromJSON<Result<void>>,
+        prepay_discounts: this.txFromJSON<Array<u32>>,
+        prepay_maintenance: this.txFromJSON<Result<Array<FeeQuote>>>,
+        set_prepay_discounts: this.txFromJSON<Result<void>>,
+        rent: this.txFromJSON<Result<Rental>>,
+        rental: this.txFromJSON<Option<Rental>>,
+        cancel_rental: this.txFromJSON<Result<void>>,
+        list_for_rent: this.txFromJSON<Result<void>>,
+        commit_bid: this.txFromJSON<Result<void>>,
+        reveal_bid: this.txFromJSON<Result<void>>,
+        settle_sealed: this.txFromJSON<Result<i128>>,
+        sealed_auction: this.txFromJSON<Option<SealedAuction>>,
+        start_sealed_auction: this.txFromJSON<Result<void>>,
+        fraction: this.txFromJSON<Option<u32>>,
+        fractionalize: this.txFromJSON<Result<void>>,
+        redeem_shares: this.txFromJSON<Result<void>>,
+        share_balance: this.txFromJSON<u32>,
+        transfer_share: this.txFromJSON<Result<void>>,
+        supply_cap: this.txFromJSON<Option<u32>>,
+        supply_caps: this.txFromJSON<Map<u32, u32>>,
+        count_supply: this.txFromJSON<Result<boolean>>,
+        hotel_supply: this.txFromJSON<u32>,
+        set_supply_cap: this.txFromJSON<Result<void>>,
+        name: this.txFromJSON<string>,
+        symbol: this.txFromJSON<string>,
+        balance: this.txFromJSON<u32>,
+        owner_of: this.txFromJSON<Result<string>>,
+        royalty_info: this.txFromJSON<Result<readonly [string, i128]>>,
+        approve_for_all: this.txFromJSON<Result<void>>,
+        bid: this.txFromJSON<Result<void>>,
+        auction: this.txFromJSON<Option<Auction>>,
+        start_auction: this.txFromJSON<Result<void>>,
+        settle_auction: this.txFromJSON<Result<void>>,
+        buyback: this.txFromJSON<Option<Buyback>>,
+        buybacks: this.txFromJSON<Array<readonly [u32, string]>>,
+        fund_buyback: this.txFromJSON<Result<Buyback>>,
+        close_buyback: this.txFromJSON<Result<void>>,
+        sell_to_hotel: this.txFromJSON<Result<i128>>,
+        charity: this.txFromJSON<Option<string>>,
+        donated: this.txFromJSON<i128>,
+        charity_split: this.txFromJSON<Array<readonly [string, u32]>>,
+        hotel_donated: this.txFromJSON<i128>,
+        remove_charity: this.txFromJSON<Result<void>>,
+        register_charity: this.txFromJSON<Result<void>>,
+        set_charity_split: this.txFromJSON<Result<void>>,
+        checkin_key: this.txFromJSON<Option<CheckinKey>>,
+        set_checkin_key: this.txFromJSON<Result<void>>,
+        verify_checkin_code: this.txFromJSON<Result<string>>,
+        checkin_code_message: this.txFromJSON<Buffer>,
+        index_tokens: this.txFromJSON<Result<void>>,
+        tokens_by_tier: this.txFromJSON<Array<u64>>,
+        tokens_by_week: this.txFromJSON<Array<u64>>,
+        match_swaps: this.txFromJSON<Result<u32>>,
+        post_intent: this.txFromJSON<Result<void>>,
+        swap_intent: this.txFromJSON<Option<SwapIntent>>,
+        swap_intents: this.txFromJSON<Array<u64>>,
+        cancel_intent: this.txFromJSON<Result<void>>,
+        metrics: this.txFromJSON<Map<string, u32>>,
+        accept_transfer: this.txFromJSON<Result<void>>,
+        get_pending_transfer: this.txFromJSON<Option<PendingTransfer>>,
+        set_transfer_confirmation: this.txFromJSON<Result<void>>,
+        stats_privacy: this.txFromJSON<StatsPrivacy>,
+        set_stats_privacy: this.txFromJSON<Result<void>>,
+        purchase: this.txFromJSON<Option<Purchase>>,
+        cooling_off: this.txFromJSON<Option<CoolingOff>>,
+        cancel_purchase: this.txFromJSON<Result<i128>>,
+        set_cooling_off: this.txFromJSON<Result<void>>,
+        release_purchase: this.txFromJSON<Result<void>>,
+        first_refusal: this.txFromJSON<Option<FirstRefusal>>,
+        set_first_refusal: this.txFromJSON<Result<void>>,
+        first_refusal_hotels: this.txFromJSON<Array<u32>>,
+        complete_first_refusal: this.txFromJSON<Result<void>>,
+        exercise_first_refusal: this.txFromJSON<Result<void>>,
+        fund_reserve: this.txFromJSON<Result<void>>,
+        reserve_spend: this.txFromJSON<Option<SpendProposal>>,
+        reserve_report: this.txFromJSON<Result<ReserveReport>>,
+        reserve_shares: this.txFromJSON<Map<u32, u32>>,
+        set_reserve_share: this.txFromJSON<Result<void>>,
+        cancel_reserve_spend: this.txFromJSON<Result<void>>,
+        execute_reserve_spend: this.txFromJSON<Result<void>>,
+        propose_reserve_spend: this.txFromJSON<Result<u32>>,
+        upgrade: this.txFromJSON<Result<void>>,
+        version_info: this.txFromJSON<VersionInfo>,
+        restore_hint: this.txFromJSON<Array<Buffer>>,
+        branding: this.txFromJSON<Option<Branding>>,
+        brandings: this.txFromJSON<Map<u32, Branding>>,
+        set_branding: this.txFromJSON<Result<void>>,
+        clear_branding: this.txFromJSON<Result<void>>,
+        clawback: this.txFromJSON<Result<void>>,
+        clawback_record: this.txFromJSON<Option<ClawbackRecord>>,
+        tier: this.txFromJSON<Option<string>>,
+        set_tier: this.txFromJSON<Result<void>>,
+        token_uri: this.txFromJSON<Result<Option<string>>>,
+        update_info: this.txFromJSON<Result<void>>,
+        set_token_uri: this.txFromJSON<Result<void>>,
+        freeze_metadata: this.txFromJSON<Result<void>>,
+        is_metadata_frozen: this.txFromJSON<boolean>,
+        receipt: this.txFromJSON<Option<Receipt>>,
+        receipts_of: this.txFromJSON<Array<Receipt>>,
+        set_receipts: this.txFromJSON<Result<void>>,
+        receipt_count: this.txFromJSON<u32>,
+        receipts_enabled: this.txFromJSON<boolean>,
+        party_receipt_count: this.txFromJSON<u32>,
+        payees: this.txFromJSON<Array<readonly [string, u32]>>,
+        withdraw: this.txFromJSON<Result<i128>>,
+        set_payees: this.txFromJSON<Result<void>>,
+        withdrawable: this.txFromJSON<i128>,
+        redeem_voucher: this.txFromJSON<Result<u64>>,
+        voucher_signer: this.txFromJSON<Option<Buffer>>,
+        voucher_message: this.txFromJSON<Buffer>,
+        set_voucher_signer: this.txFromJSON<Result<void>>,
+        allowlist: this.txFromJSON<Option<Allowlist>>,
+        set_allowlist: this.txFromJSON<Result<void>>,
+        allowlist_mint: this.txFromJSON<Result<u64>>,
+        close_allowlist: this.txFromJSON<Result<void>>,
+        approve: this.txFromJSON<Result<void>>,
+        get_approved: this.txFromJSON<Option<string>>,
+        transfer_from: this.txFromJSON<Result<void>>,
+        is_approved_for_all: this.txFromJSON<boolean>,
+        set_approval_for_all: this.txFromJSON<Result<void>>,
+        directory: this.txFromJSON<Array<DirectoryEntry>>,
+        join_directory: this.txFromJSON<Result<u32>>,
+        directory_entry: this.txFromJSON<Option<DirectoryEntry>>,
+        leave_directory: this.txFromJSON<Result<void>>,
+        prune_directory: this.txFromJSON<Result<boolean>>,
+        fee_index: this.txFromJSON<Option<FeeIndex>>,
+        set_fee_index: this.txFromJSON<Result<void>>,
+        fee_billed_year: this.txFromJSON<Option<u32>>,
+        fee_indexed_year: this.txFromJSON<Option<u32>>,
+        index_maintenance_fee: this.txFromJSON<Result<i128>>,
+        migrate: this.txFromJSON<Result<u32>>,
+        schema_version: this.txFromJSON<u32>,
+        migrate_entries: this.txFromJSON<Result<u32>>,
+        buy_mint: this.txFromJSON<Result<u64>>,
+        mint_price: this.txFromJSON<Option<MintPrice>>,
+        set_mint_price: this.txFromJSON<Result<void>>,
+        clear_mint_price: this.txFromJSON<Result<void>>,
+        deadlines: this.txFromJSON<Result<Array<Deadline>>>,
+        send_reminders: this.txFromJSON<Result<u32>>,
+        get_royalty: this.txFromJSON<Result<Option<Royalty>>>,
+        set_royalty: this.txFromJSON<Result<void>>,
+        appraisal: this.txFromJSON<Option<Appraisal>>,
+        price_mark: this.txFromJSON<Option<PriceMark>>,
+        token_value: this.txFromJSON<i128>,
+        set_appraisal: this.txFromJSON<Result<void>>,
+        portfolio_value: this.txFromJSON<i128>
+  }
+}
\ No newline at end of file
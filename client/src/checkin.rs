@@ -0,0 +1,74 @@
+//! QR check-in codes.
+//!
+//! The guest registers their device's ed25519 key with `set_checkin_key`. To
+//! check in, the device signs a [`CheckinCode`] with [`sign`] and shows the
+//! text from [`encode`] as a QR code. The kiosk [`decode`]s the scan and passes
+//! the code and signature to `verify_checkin_code`.
+//!
+//! The text is `tsci1:<token_id>:<year>:<nonce>:<expires_at>:<signature>`, the
+//! numbers in decimal and the signature in lowercase hex, which QR byte mode
+//! holds in well under 200 bytes.
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, Env};
+
+const PREFIX: &str = "tsci1";
+
+/// What a guest's QR code carries, next to its signature. Mirrors the
+/// contract's `CheckinCode`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckinCode {
+    pub token_id: u64,
+    /// The calendar year of the stay.
+    pub year: u32,
+    /// Must be higher than any nonce used for the token before.
+    pub nonce: u64,
+    /// Ledger timestamp after which the code can't be used.
+    pub expires_at: u64,
+}
+
+/// The bytes a code's signature covers, as the contract's
+/// `checkin_code_message` returns them: the XDR of (contract address, code).
+pub fn message(env: &Env, contract: &Address, code: &CheckinCode) -> Bytes {
+    (contract.clone(), code.clone()).to_xdr(env)
+}
+
+/// Signs a code for the contract at `contract` with the guest's device key.
+pub fn sign(env: &Env, contract: &Address, code: &CheckinCode, key: &SigningKey) -> [u8; 64] {
+    let message: Vec<u8> = message(env, contract, code).iter().collect();
+    key.sign(&message).to_bytes()
+}
+
+/// Encodes a signed code as the text of a QR code.
+pub fn encode(code: &CheckinCode, signature: &[u8; 64]) -> String {
+    let hex: String = signature.iter().map(|byte| format!("{byte:02x}")).collect();
+    format!(
+        "{PREFIX}:{}:{}:{}:{}:{hex}",
+        code.token_id, code.year, code.nonce, code.expires_at
+    )
+}
+
+/// Decodes a scanned QR code. Returns None unless it is a well-formed code
+/// from [`encode`]; whether it is genuine is for the contract to check.
+pub fn decode(text: &str) -> Option<(CheckinCode, [u8; 64])> {
+    let mut parts = text.trim().split(':');
+    if parts.next()? != PREFIX {
+        return None;
+    }
+    let code = CheckinCode {
+        token_id: parts.next()?.parse().ok()?,
+        year: parts.next()?.parse().ok()?,
+        nonce: parts.next()?.parse().ok()?,
+        expires_at: parts.next()?.parse().ok()?,
+    };
+    let hex = parts.next()?;
+    if parts.next().is_some() || hex.len() != 128 || !hex.is_ascii() {
+        return None;
+    }
+    let mut signature = [0; 64];
+    for (byte, pair) in signature.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+    Some((code, signature))
+}
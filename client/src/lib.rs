@@ -0,0 +1,12 @@
+//! Client-side helpers for the hotel timeshare contract.
+//!
+//! Most calls need nothing beyond the generated contract client (or the
+//! TypeScript package in `bindings/typescript`). The helpers here cover what a
+//! wallet or kiosk does off-chain around a call:
+//!
+//! - [`checkin`]: signs QR check-in codes and encodes them for display.
+//!
+//! Like the conformance suite, this crate carries its own copies of the wire
+//! types it needs rather than depending on the contract crate.
+
+pub mod checkin;
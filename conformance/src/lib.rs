@@ -0,0 +1,393 @@
+//! Behavioral conformance suite for hotel timeshare token contracts.
+//!
+//! Any implementation of the timeshare interface (this repository's contract, a
+//! fork, or a rewrite) can add this crate as a dev-dependency and run:
+//!
+//! ```ignore
+//! #[test]
+//! fn conformance() {
+//!     timeshare_conformance::run_all(&|env| env.register(MyContract, ()));
+//! }
+//! ```
+//!
+//! The suite only talks to the contract through [`TimeshareClient`], generated
+//! from the [`TimeshareInterface`] trait, and carries its own copies of the
+//! wire types, error codes and events. It never depends on the implementation
+//! under test, so it checks the interface rather than one code base.
+//!
+//! Each check registers a fresh contract instance in a fresh `Env`, so checks
+//! are independent and can also be run one by one.
+
+#![no_std]
+
+use soroban_sdk::{
+    contractclient, contracterror, contractevent, contracttype,
+    testutils::{Address as _, Events},
+    xdr::ToXdr,
+    Address, Env, Event, String,
+};
+
+// --- INTERFACE ---
+
+/// Details of one timeshare token. Field names are part of the wire format.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimeshareInfo {
+    pub hotel_id: u32,
+    pub room: String,
+    pub week: u32,
+    pub year: Option<u32>,
+    pub lease_end_ledger: Option<u32>,
+}
+
+/// Error codes every implementation must use for these failures.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TimeshareError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    TokenNotFound = 10,
+    NotOwner = 11,
+    InvalidRecipient = 12,
+    HotelNotFound = 170,
+    RoomNotFound = 180,
+}
+
+/// Published on mint with topics ("mint", token_id).
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mint {
+    #[topic]
+    pub token_id: u64,
+    pub owner: Address,
+    pub info: TimeshareInfo,
+}
+
+/// Published on every ownership change with topics ("transfer", token_id).
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transfer {
+    #[topic]
+    pub token_id: u64,
+    pub from: Address,
+    pub to: Address,
+}
+
+/// The core timeshare interface covered by the suite.
+#[contractclient(name = "TimeshareClient")]
+pub trait TimeshareInterface {
+    fn initialize(env: Env, admin: Address) -> Result<(), TimeshareError>;
+    fn register_hotel(env: Env, caller: Address, name: String) -> Result<u32, TimeshareError>;
+    fn add_room(
+        env: Env,
+        caller: Address,
+        hotel_id: u32,
+        room: String,
+    ) -> Result<(), TimeshareError>;
+    fn mint(
+        env: Env,
+        to: Address,
+        hotel_id: u32,
+        room: String,
+        week: u32,
+        year: Option<u32>,
+        lease_end_ledger: Option<u32>,
+    ) -> Result<u64, TimeshareError>;
+    fn transfer(env: Env, from: Address, to: Address, token_id: u64) -> Result<(), TimeshareError>;
+    fn get_info(env: Env, token_id: u64) -> Result<TimeshareInfo, TimeshareError>;
+    fn get_owner(env: Env, token_id: u64) -> Result<Address, TimeshareError>;
+}
+
+/// Registers a fresh, uninitialized instance of the contract under test.
+pub type Register<'a> = &'a dyn Fn(&Env) -> Address;
+
+// --- SUITE ---
+
+/// Runs every check. Panics with the failing check's assertion message.
+pub fn run_all(register: Register) {
+    initialize_only_once(register);
+    mint_before_initialize_fails(register);
+    mint_assigns_sequential_ids(register);
+    mint_requires_admin_auth(register);
+    mint_publishes_event(register);
+    transfer_moves_ownership(register);
+    transfer_requires_owner_auth(register);
+    transfer_publishes_event(register);
+    transfer_by_non_owner_fails(register);
+    transfer_to_own_contract_fails(register);
+    unknown_token_fails(register);
+    mint_for_unknown_hotel_fails(register);
+    mint_for_unknown_room_fails(register);
+}
+
+pub fn initialize_only_once(register: Register) {
+    let env = Env::default();
+    let (client, admin) = setup(&env, register);
+
+    assert_eq!(
+        client.try_initialize(&admin),
+        Err(Ok(TimeshareError::AlreadyInitialized)),
+        "second initialize must fail with AlreadyInitialized"
+    );
+}
+
+pub fn mint_before_initialize_fails(register: Register) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = TimeshareClient::new(&env, &register(&env));
+    let info = sample_info(&env);
+
+    assert_eq!(
+        client.try_mint(
+            &Address::generate(&env),
+            &info.hotel_id,
+            &info.room,
+            &info.week,
+            &info.year,
+            &info.lease_end_ledger
+        ),
+        Err(Ok(TimeshareError::NotInitialized)),
+        "mint before initialize must fail with NotInitialized"
+    );
+}
+
+pub fn mint_assigns_sequential_ids(register: Register) {
+    let env = Env::default();
+    let (client, _admin) = setup(&env, register);
+    let owner = Address::generate(&env);
+
+    let first = mint(&env, &client, &owner);
+    // The next week of the same room: each week is sold once.
+    let info = sample_info(&env);
+    let second = client.mint(
+        &owner,
+        &info.hotel_id,
+        &info.room,
+        &(info.week + 1),
+        &info.year,
+        &info.lease_end_ledger,
+    );
+    assert_eq!(first, 1, "first token ID must be 1");
+    assert_eq!(second, 2, "token IDs must be sequential");
+    assert_eq!(client.get_owner(&first), owner);
+    assert_eq!(client.get_info(&first), sample_info(&env));
+}
+
+pub fn mint_requires_admin_auth(register: Register) {
+    let env = Env::default();
+    let (client, admin) = setup(&env, register);
+
+    mint(&env, &client, &Address::generate(&env));
+    assert!(
+        env.auths().iter().any(|(addr, _)| *addr == admin),
+        "mint must require the admin's authorization"
+    );
+}
+
+pub fn mint_publishes_event(register: Register) {
+    let env = Env::default();
+    let (client, _admin) = setup(&env, register);
+    let owner = Address::generate(&env);
+
+    let token_id = mint(&env, &client, &owner);
+    assert_published(
+        &env,
+        &client.address,
+        &Mint {
+            token_id,
+            owner,
+            info: sample_info(&env),
+        },
+    );
+}
+
+pub fn transfer_moves_ownership(register: Register) {
+    let env = Env::default();
+    let (client, _admin) = setup(&env, register);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint(&env, &client, &alice);
+
+    client.transfer(&alice, &bob, &token_id);
+    assert_eq!(
+        client.get_owner(&token_id),
+        bob,
+        "transfer must change the owner"
+    );
+    assert_eq!(
+        client.get_info(&token_id),
+        sample_info(&env),
+        "transfer must keep the info"
+    );
+}
+
+pub fn transfer_requires_owner_auth(register: Register) {
+    let env = Env::default();
+    let (client, _admin) = setup(&env, register);
+    let alice = Address::generate(&env);
+    let token_id = mint(&env, &client, &alice);
+
+    client.transfer(&alice, &Address::generate(&env), &token_id);
+    assert!(
+        env.auths().iter().any(|(addr, _)| *addr == alice),
+        "transfer must require the owner's authorization"
+    );
+}
+
+pub fn transfer_publishes_event(register: Register) {
+    let env = Env::default();
+    let (client, _admin) = setup(&env, register);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint(&env, &client, &alice);
+
+    client.transfer(&alice, &bob, &token_id);
+    assert_published(
+        &env,
+        &client.address,
+        &Transfer {
+            token_id,
+            from: alice,
+            to: bob,
+        },
+    );
+}
+
+pub fn transfer_by_non_owner_fails(register: Register) {
+    let env = Env::default();
+    let (client, _admin) = setup(&env, register);
+    let alice = Address::generate(&env);
+    let mallory = Address::generate(&env);
+    let token_id = mint(&env, &client, &alice);
+
+    assert_eq!(
+        client.try_transfer(&mallory, &mallory, &token_id),
+        Err(Ok(TimeshareError::NotOwner)),
+        "transfer by a non-owner must fail with NotOwner"
+    );
+    assert_eq!(client.get_owner(&token_id), alice);
+}
+
+pub fn transfer_to_own_contract_fails(register: Register) {
+    let env = Env::default();
+    let (client, _admin) = setup(&env, register);
+    let alice = Address::generate(&env);
+    let token_id = mint(&env, &client, &alice);
+
+    assert_eq!(
+        client.try_transfer(&alice, &client.address, &token_id),
+        Err(Ok(TimeshareError::InvalidRecipient)),
+        "transfer to the contract itself must fail with InvalidRecipient"
+    );
+}
+
+pub fn unknown_token_fails(register: Register) {
+    let env = Env::default();
+    let (client, _admin) = setup(&env, register);
+    let alice = Address::generate(&env);
+
+    assert_eq!(
+        client.try_get_info(&404),
+        Err(Ok(TimeshareError::TokenNotFound))
+    );
+    assert_eq!(
+        client.try_get_owner(&404),
+        Err(Ok(TimeshareError::TokenNotFound))
+    );
+    assert_eq!(
+        client.try_transfer(&alice, &alice, &404),
+        Err(Ok(TimeshareError::TokenNotFound))
+    );
+}
+
+pub fn mint_for_unknown_hotel_fails(register: Register) {
+    let env = Env::default();
+    let (client, _admin) = setup(&env, register);
+    let info = sample_info(&env);
+
+    assert_eq!(
+        client.try_mint(
+            &Address::generate(&env),
+            &(info.hotel_id + 1),
+            &info.room,
+            &info.week,
+            &info.year,
+            &info.lease_end_ledger,
+        ),
+        Err(Ok(TimeshareError::HotelNotFound)),
+        "mint for an unregistered hotel must fail with HotelNotFound"
+    );
+}
+
+pub fn mint_for_unknown_room_fails(register: Register) {
+    let env = Env::default();
+    let (client, _admin) = setup(&env, register);
+    let info = sample_info(&env);
+
+    assert_eq!(
+        client.try_mint(
+            &Address::generate(&env),
+            &info.hotel_id,
+            &String::from_str(&env, "Room 404"),
+            &info.week,
+            &info.year,
+            &info.lease_end_ledger,
+        ),
+        Err(Ok(TimeshareError::RoomNotFound)),
+        "mint for a room not in the hotel's inventory must fail with RoomNotFound"
+    );
+}
+
+// --- HELPERS ---
+
+/// ID of the hotel `setup` registers, with the room of `sample_info`.
+const HOTEL_ID: u32 = 1;
+
+fn setup<'a>(env: &Env, register: Register) -> (TimeshareClient<'a>, Address) {
+    env.mock_all_auths();
+    let client = TimeshareClient::new(env, &register(env));
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    assert_eq!(
+        client.register_hotel(&admin, &String::from_str(env, "Grand Hotel")),
+        HOTEL_ID,
+        "the first hotel registered must get ID 1"
+    );
+    client.add_room(&admin, &HOTEL_ID, &sample_info(env).room);
+    (client, admin)
+}
+
+fn sample_info(env: &Env) -> TimeshareInfo {
+    TimeshareInfo {
+        hotel_id: HOTEL_ID,
+        room: String::from_str(env, "Room 305"),
+        week: 28,
+        year: None,
+        lease_end_ledger: None,
+    }
+}
+
+fn mint(env: &Env, client: &TimeshareClient, to: &Address) -> u64 {
+    let info = sample_info(env);
+    client.mint(
+        to,
+        &info.hotel_id,
+        &info.room,
+        &info.week,
+        &info.year,
+        &info.lease_end_ledger,
+    )
+}
+
+/// Asserts that the last invocation published `expected` from `contract`.
+fn assert_published(env: &Env, contract: &Address, expected: &impl Event) {
+    let topics = expected.topics(env);
+    let data = expected.data(env).to_xdr(env);
+    let found = env
+        .events()
+        .all()
+        .iter()
+        .any(|(addr, t, d)| addr == *contract && t == topics && d.to_xdr(env) == data);
+    assert!(found, "expected event {:?} was not published", topics);
+}
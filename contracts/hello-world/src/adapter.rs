@@ -0,0 +1,137 @@
+// Entry points for external NFT marketplaces.
+//
+// Marketplaces that trade NFTs on Soroban call a common set of entry points
+// on the token contract: to check ownership, to be approved as an operator,
+// to move a sold token, and to look up the royalty owed on a sale. Several
+// are already here under the names and argument order they expect:
+// `transfer`, `transfer_from`, `get_approved` and `is_approved_for_all`.
+// This module adds the rest that doesn't collide with existing endpoints:
+//
+// - `balance` and `owner_of`, aliases of `balance_of` and `get_owner`;
+// - `approve_for_all`, an operator approval that expires after a ledger,
+//   next to the permanent `set_approval_for_all` (see approvals.rs);
+// - `name` and `symbol` of the collection;
+// - `royalty_info`, the hotel's royalty on a sale price.
+//
+// Some shapes differ from what those marketplaces may assume, since changing
+// them would break existing clients: token IDs are u64, and `approve` and
+// `token_uri` keep their original shapes (no expiry ledger, and an optional
+// URI). The conformance test in test.rs calls the module through a client of
+// just these entry points, as a marketplace would.
+
+use soroban_sdk::{contractimpl, Address, Env, String};
+
+use crate::{
+    approvals, enter, events, money, or_fail, owner_of, Amount, ApprovalForAll, Bps, Error,
+    ExtKey2, HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/// The collection name shown by marketplaces.
+pub const COLLECTION_NAME: &str = "Hotel Timeshare";
+
+/// The collection symbol shown by marketplaces.
+pub const COLLECTION_SYMBOL: &str = "STAY";
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Returns how many tokens `account` owns, like balance_of().
+     */
+    pub fn balance(env: Env, account: Address) -> u32 {
+        Self::balance_of(env, account)
+    }
+
+    /**
+     * @dev Returns the owner of a token, like get_owner().
+     */
+    pub fn owner_of(env: Env, token_id: u64) -> Result<Address, Error> {
+        owner_of(&env, token_id)
+    }
+
+    /**
+     * @dev Approves `operator` for all of `owner`'s tokens through ledger
+     * `live_until_ledger`, or revokes it with 0. Either replaces or, with 0,
+     * revokes a set_approval_for_all() approval too. Fails with
+     * InvalidAmount for a ledger already past.
+     * @param owner The owner (must sign).
+     */
+    pub fn approve_for_all(
+        env: Env,
+        owner: Address,
+        operator: Address,
+        live_until_ledger: u32,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "approve_for_all")?;
+
+        if live_until_ledger != 0 && live_until_ledger < env.ledger().sequence() {
+            return Err(Error::InvalidAmount);
+        }
+        approvals::revoke_operator(&env, &owner, &operator);
+        if live_until_ledger != 0 {
+            env.storage().persistent().set(
+                &ExtKey2::OperatorUntil(owner.clone(), operator.clone()),
+                &live_until_ledger,
+            );
+        }
+
+        events::emit(
+            &env,
+            &ApprovalForAll {
+                owner,
+                operator,
+                approved: live_until_ledger != 0,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the collection's name.
+     */
+    pub fn name(env: Env) -> String {
+        String::from_str(&env, COLLECTION_NAME)
+    }
+
+    /**
+     * @dev Returns the collection's symbol.
+     */
+    pub fn symbol(env: Env) -> String {
+        String::from_str(&env, COLLECTION_SYMBOL)
+    }
+
+    /**
+     * @dev Returns who is owed a royalty on a sale of a token at
+     * `sale_price`, and how much: the hotel's payout address, or the contract
+     * with 0 if the hotel takes none. The amount is rounded as a sale paid
+     * in the asset's base unit would be. Fails with InvalidAmount for a
+     * negative price, and AmountOverflow for one too large to take a share of.
+     */
+    pub fn royalty_info(
+        env: Env,
+        token_id: u64,
+        sale_price: i128,
+    ) -> Result<(Address, i128), Error> {
+        if sale_price < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let Some(royalty) = Self::get_royalty(env.clone(), token_id)? else {
+            return Ok((env.current_contract_address(), 0));
+        };
+        let amount = or_fail(
+            &env,
+            Amount::of(sale_price)
+                .and_then(|price| money::share(price, Bps::of(royalty.bps)?, Amount::ONE)),
+        );
+        Ok((royalty.payout, amount.get()))
+    }
+}
+
+/// Whether `operator` holds an unexpired `approve_for_all` approval from
+/// `owner`.
+pub(crate) fn is_operator(env: &Env, owner: Address, operator: Address) -> bool {
+    env.storage()
+        .persistent()
+        .get::<_, u32>(&ExtKey2::OperatorUntil(owner, operator))
+        .is_some_and(|live_until_ledger| env.ledger().sequence() <= live_until_ledger)
+}
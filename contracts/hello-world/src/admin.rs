@@ -0,0 +1,95 @@
+// Admin handover.
+//
+// The admin is changed in two steps: the current admin proposes a successor
+// with `propose_admin`, and the handover only happens when the successor signs
+// `accept_admin`. A mistyped or unreachable address therefore never becomes
+// admin, and the current admin stays in charge until the new key is proven to
+// work. Proposing again replaces the pending proposal.
+
+use soroban_sdk::{contractevent, contractimpl, Address, Env};
+
+use crate::{
+    enter, events, require_admin, DataKey, Error, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, ADMIN,
+};
+
+/**
+ * @title AdminProposed
+ * @dev Published when the admin proposes a successor.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposed {
+    #[topic]
+    pub admin: Address,
+    pub proposed: Address,
+}
+
+/**
+ * @title AdminChanged
+ * @dev Published when the proposed successor accepts and becomes admin.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminChanged {
+    #[topic]
+    pub previous: Address,
+    #[topic]
+    pub admin: Address,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Proposes `new_admin` as the next admin. Admin only.
+     * @param new_admin Becomes admin once it calls accept_admin().
+     */
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+        enter(&env, "propose_admin")?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingAdmin, &new_admin);
+        events::emit(
+            &env,
+            &AdminProposed {
+                admin,
+                proposed: new_admin,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Completes the handover. Must be signed by the proposed admin.
+     */
+    pub fn accept_admin(env: Env) -> Result<(), Error> {
+        let new_admin = Self::pending_admin(env.clone()).ok_or(Error::NoPendingAdmin)?;
+        new_admin.require_auth();
+        enter(&env, "accept_admin")?;
+
+        let previous: Address = env
+            .storage()
+            .instance()
+            .get(&ADMIN)
+            .ok_or(Error::NotInitialized)?;
+        env.storage().instance().set(&ADMIN, &new_admin);
+        env.storage().persistent().remove(&DataKey::PendingAdmin);
+        events::emit(
+            &env,
+            &AdminChanged {
+                previous,
+                admin: new_admin,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the proposed next admin, if a handover is pending.
+     */
+    pub fn pending_admin(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::PendingAdmin)
+    }
+}
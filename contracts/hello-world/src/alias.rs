@@ -0,0 +1,117 @@
+// Per-owner address book.
+//
+// Owners register aliases (a hash of a human-readable name, e.g. "mum") for
+// addresses they send to regularly, then transfer by alias instead of pasting a
+// raw address. Every alias-based transfer echoes the resolved address in an
+// event so wallets can show exactly where the token went.
+
+use soroban_sdk::{contractevent, contractimpl, Address, BytesN, Env};
+
+use crate::{
+    enter, events, transfer_token, DataKey, Error, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AliasSet {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub name_hash: BytesN<32>,
+    pub addr: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AliasResolved {
+    #[topic]
+    pub token_id: u64,
+    pub name_hash: BytesN<32>,
+    pub to: Address,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Registers (or replaces) an alias in `owner`'s address book.
+     * @param owner The address book's owner (must sign).
+     * @param name_hash A hash of the alias name, so names aren't stored in clear.
+     * @param addr The address the alias resolves to.
+     */
+    pub fn set_alias(
+        env: Env,
+        owner: Address,
+        name_hash: BytesN<32>,
+        addr: Address,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "set_alias")?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Alias(owner.clone(), name_hash.clone()), &addr);
+
+        events::emit(
+            &env,
+            &AliasSet {
+                owner,
+                name_hash,
+                addr,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Removes an alias from `owner`'s address book.
+     * @param owner The address book's owner (must sign).
+     */
+    pub fn remove_alias(env: Env, owner: Address, name_hash: BytesN<32>) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "remove_alias")?;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Alias(owner, name_hash));
+        Ok(())
+    }
+
+    /**
+     * @dev Looks up an alias in `owner`'s address book.
+     * @return The address, or None if the alias is not registered.
+     */
+    pub fn resolve_alias(env: Env, owner: Address, name_hash: BytesN<32>) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Alias(owner, name_hash))
+    }
+
+    /**
+     * @dev Transfers a token to the address registered under one of `from`'s aliases.
+     * @param from The current owner's address (who must sign).
+     * @param name_hash The alias to resolve in `from`'s address book.
+     * @param token_id The ID of the token to transfer.
+     */
+    pub fn transfer_to_alias(
+        env: Env,
+        from: Address,
+        name_hash: BytesN<32>,
+        token_id: u64,
+    ) -> Result<(), Error> {
+        enter(&env, "transfer_to_alias")?;
+        let to = Self::resolve_alias(env.clone(), from.clone(), name_hash.clone())
+            .ok_or(Error::UnknownAlias)?;
+
+        transfer_token(&env, &from, &to, token_id)?;
+
+        events::emit(
+            &env,
+            &AliasResolved {
+                token_id,
+                name_hash,
+                to,
+            },
+        );
+        Ok(())
+    }
+}
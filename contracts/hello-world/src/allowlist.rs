@@ -0,0 +1,164 @@
+// Allowlist pre-sale.
+//
+// Before general sale the hotel can open an allowlist phase with
+// `set_allowlist`: a price, and the Merkle root of the eligible addresses
+// rather than the addresses themselves, so thousands of buyers cost one entry.
+// An eligible buyer calls `allowlist_mint` with their Merkle proof and the
+// week they want, pays the price to the admin, and is minted the token.
+//
+// Leaves are sha256(xdr(buyer)). Inner nodes hash the sorted pair of their
+// children, sha256(min || max), so proofs need no left/right flags. Each
+// address mints once per root: setting a new root starts a new phase.
+
+use soroban_sdk::{
+    contractevent, contractimpl, contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec,
+};
+
+use crate::{
+    enter, enter_by, events, fail, ids, issue_token, pause, refunds, require_admin, Error,
+    ExtError, ExtKey, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, TimeshareInfo, ADMIN,
+};
+
+/**
+ * @title Allowlist
+ * @dev An allowlist sale phase.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Allowlist {
+    /// Merkle root of the eligible addresses.
+    pub root: BytesN<32>,
+    pub price: i128,
+    /// Token the price is paid in.
+    pub payment_token: Address,
+}
+
+/**
+ * @title AllowlistMinted
+ * @dev Published when an allowlisted buyer mints, next to the Mint event.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowlistMinted {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub buyer: Address,
+    pub price: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Opens an allowlist phase, replacing any earlier one. Admin only.
+     * @param allowlist The root, price and payment token; the price must not
+     * be negative.
+     */
+    pub fn set_allowlist(env: Env, allowlist: Allowlist) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "set_allowlist")?;
+
+        if allowlist.price < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage()
+            .persistent()
+            .set(&ExtKey::Allowlist, &allowlist);
+        Ok(())
+    }
+
+    /**
+     * @dev Ends the allowlist phase. Admin only.
+     */
+    pub fn close_allowlist(env: Env) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "close_allowlist")?;
+
+        env.storage().persistent().remove(&ExtKey::Allowlist);
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the open allowlist phase, if any.
+     */
+    pub fn allowlist(env: Env) -> Option<Allowlist> {
+        env.storage().persistent().get(&ExtKey::Allowlist)
+    }
+
+    /**
+     * @dev Mints a week to an allowlisted buyer for the phase's price, paid to
+     * the admin. Fails with NoAllowlist if no phase is open, NotAllowlisted
+     * if the proof doesn't lead to the root, and AllowlistClaimed if the buyer
+     * already minted in this phase.
+     * @param buyer The buyer (must sign and pay).
+     * @param proof The sibling hashes from the buyer's leaf up to the root.
+     * @param info The week to mint; it must be unsold.
+     * @return The new token ID.
+     */
+    pub fn allowlist_mint(
+        env: Env,
+        buyer: Address,
+        proof: Vec<BytesN<32>>,
+        info: TimeshareInfo,
+    ) -> Result<u64, Error> {
+        buyer.require_auth();
+        enter_by(&env, "allowlist_mint", &buyer)?;
+        pause::check(&env)?;
+
+        // 1. Only listed buyers, once each.
+        let allowlist =
+            Self::allowlist(env.clone()).unwrap_or_else(|| fail(&env, ExtError::NoAllowlist));
+        if !verify(&env, &proof, &allowlist.root, &buyer) {
+            fail(&env, ExtError::NotAllowlisted);
+        }
+        let claim = ExtKey::AllowlistClaim(allowlist.root, buyer.clone());
+        if env.storage().persistent().has(&claim) {
+            fail(&env, ExtError::AllowlistClaimed);
+        }
+
+        // 2. Take payment and mint from the admin's ID block.
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&ADMIN)
+            .ok_or(Error::NotInitialized)?;
+        let token_id = ids::allocate(&env, &admin, 1)?.get_unchecked(0);
+        issue_token(&env, token_id, &buyer, info)?;
+        refunds::collect(
+            &env,
+            token_id,
+            &allowlist.payment_token,
+            &buyer,
+            &admin,
+            allowlist.price,
+        );
+        env.storage().persistent().set(&claim, &true);
+
+        events::emit(
+            &env,
+            &AllowlistMinted {
+                token_id,
+                buyer,
+                price: allowlist.price,
+            },
+        );
+        Ok(token_id)
+    }
+}
+
+/// Whether `proof` leads from `buyer`'s leaf to `root`.
+fn verify(env: &Env, proof: &Vec<BytesN<32>>, root: &BytesN<32>, buyer: &Address) -> bool {
+    let mut node: BytesN<32> = env.crypto().sha256(&buyer.clone().to_xdr(env)).into();
+    for sibling in proof.iter() {
+        let (low, high) = if node.to_array() <= sibling.to_array() {
+            (node, sibling)
+        } else {
+            (sibling, node)
+        };
+        let mut pair = Bytes::from(low);
+        pair.append(&Bytes::from(high));
+        node = env.crypto().sha256(&pair).into();
+    }
+    node == *root
+}
@@ -0,0 +1,78 @@
+// Amount and basis-point newtypes.
+//
+// Inside the contract, prices, fees and balances are Amounts and shares of
+// them are Bps, so one can't be passed where the other is meant. An Amount is
+// never negative and a Bps never more than a whole, and every operation on
+// them is checked: instead of wrapping around, they fail with AmountOverflow,
+// NegativeAmount or InvalidBps. The payment paths (marketplace settlements
+// and royalties, tax withholding, maintenance fees and prepayments, reserves,
+// refunds and the revenue splitter) do their arithmetic with them through
+// money.rs and fail the call with `or_fail` (lib.rs).
+//
+// Endpoints and stored structs keep plain i128 and u32, so the interface is
+// unchanged.
+
+use crate::ExtError;
+
+/// Basis points in a whole: a share of FULL_BPS is the full amount.
+pub const FULL_BPS: u32 = 10_000;
+
+/// An amount of a payment asset, in its base units; never negative.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Amount(i128);
+
+/// A share in basis points; never more than FULL_BPS.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Bps(u32);
+
+impl Amount {
+    pub const ZERO: Amount = Amount(0);
+    pub const ONE: Amount = Amount(1);
+
+    /// Fails with NegativeAmount below 0.
+    pub fn of(value: i128) -> Result<Amount, ExtError> {
+        if value < 0 {
+            return Err(ExtError::NegativeAmount);
+        }
+        Ok(Amount(value))
+    }
+
+    pub fn get(self) -> i128 {
+        self.0
+    }
+
+    pub fn checked_add(self, other: Amount) -> Result<Amount, ExtError> {
+        self.0
+            .checked_add(other.0)
+            .map(Amount)
+            .ok_or(ExtError::AmountOverflow)
+    }
+
+    /// Fails with NegativeAmount if `other` is more than this.
+    pub fn checked_sub(self, other: Amount) -> Result<Amount, ExtError> {
+        Amount::of(self.0 - other.0)
+    }
+
+    pub fn checked_mul(self, times: u32) -> Result<Amount, ExtError> {
+        self.0
+            .checked_mul(times as i128)
+            .map(Amount)
+            .ok_or(ExtError::AmountOverflow)
+    }
+}
+
+impl Bps {
+    pub const FULL: Bps = Bps(FULL_BPS);
+
+    /// Fails with InvalidBps above FULL_BPS.
+    pub fn of(value: u32) -> Result<Bps, ExtError> {
+        if value > FULL_BPS {
+            return Err(ExtError::InvalidBps);
+        }
+        Ok(Bps(value))
+    }
+
+    pub fn get(self) -> u32 {
+        self.0
+    }
+}
@@ -0,0 +1,163 @@
+// Single-token and operator approvals.
+//
+// An owner can approve one spender per token, typically a marketplace contract,
+// which may then move that token with `transfer_from` without the owner
+// signing the transfer itself. The approval is cleared whenever the token
+// changes owner (see `move_token`), so it never carries over to a new owner.
+//
+// An owner can also approve operators, e.g. a property manager, which may move
+// every token the owner holds, now or later, until the owner revokes them or,
+// if approved with `approve_for_all` (see adapter.rs), the approval expires.
+// Either call replaces an approval made with the other, and revoking with
+// either ends both.
+
+use soroban_sdk::{contractevent, contractimpl, Address, Env};
+
+use crate::{
+    adapter, check_owner, enter, events, move_token, sinks, DataKey, Error, ExtKey2,
+    HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Approval {
+    #[topic]
+    pub token_id: u64,
+    pub owner: Address,
+    pub spender: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalForAll {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub operator: Address,
+    pub approved: bool,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Lets `spender` transfer `token_id` on the owner's behalf, replacing
+     * any earlier approval for that token.
+     * @param owner The current owner (must sign).
+     * @param spender The address allowed to call transfer_from.
+     * @param token_id The token being approved.
+     */
+    pub fn approve(env: Env, owner: Address, spender: Address, token_id: u64) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "approve")?;
+
+        check_owner(&env, token_id, &owner)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Approval(token_id), &spender);
+
+        events::emit(
+            &env,
+            &Approval {
+                token_id,
+                owner,
+                spender,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the address approved to transfer `token_id`, if any.
+     */
+    pub fn get_approved(env: Env, token_id: u64) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Approval(token_id))
+    }
+
+    /**
+     * @dev Approves or revokes `operator` for all of `owner`'s tokens,
+     * replacing an approval_for_all() expiry.
+     * @param owner The owner (must sign).
+     * @param operator The address allowed to call transfer_from for any of the owner's tokens.
+     * @param approved true to approve, false to revoke.
+     */
+    pub fn set_approval_for_all(
+        env: Env,
+        owner: Address,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "set_approval_for_all")?;
+
+        revoke_operator(&env, &owner, &operator);
+        if approved {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Operator(owner.clone(), operator.clone()), &true);
+        }
+
+        events::emit(
+            &env,
+            &ApprovalForAll {
+                owner,
+                operator,
+                approved,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns true if `operator` may transfer any of `owner`'s tokens,
+     * through `set_approval_for_all` or an unexpired `approve_for_all`.
+     */
+    pub fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Operator(owner.clone(), operator.clone()))
+            || adapter::is_operator(&env, owner, operator)
+    }
+
+    /**
+     * @dev Transfers a token on behalf of its owner. A single-token approval is
+     * used up; an operator approval stays in place.
+     * @param spender The approved address or an operator of `from` (must sign).
+     * @param from The current owner.
+     * @param to The new owner.
+     * @param token_id The token to transfer.
+     */
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        token_id: u64,
+    ) -> Result<(), Error> {
+        spender.require_auth();
+        enter(&env, "transfer_from")?;
+
+        check_owner(&env, token_id, &from)?;
+        if Self::get_approved(env.clone(), token_id) != Some(spender.clone())
+            && !Self::is_approved_for_all(env.clone(), from.clone(), spender)
+        {
+            return Err(Error::NotApproved);
+        }
+        sinks::check_recipient(&env, &to)?;
+
+        move_token(&env, &from, &to, token_id)
+    }
+}
+
+pub(crate) fn clear(env: &Env, token_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Approval(token_id));
+}
+
+/// Removes every approval of `operator` for `owner`'s tokens, permanent or
+/// expiring.
+pub(crate) fn revoke_operator(env: &Env, owner: &Address, operator: &Address) {
+    let persistent = env.storage().persistent();
+    persistent.remove(&DataKey::Operator(owner.clone(), operator.clone()));
+    persistent.remove(&ExtKey2::OperatorUntil(owner.clone(), operator.clone()));
+}
@@ -0,0 +1,96 @@
+// Restoration guidance for archived entries.
+//
+// Persistent and instance entries whose TTL runs out are archived. Contract code
+// never sees that happen: when a transaction's footprint lists an archived key,
+// the network restores it before the contract runs (or rejects the
+// transaction), and a key missing from the footprint aborts the invocation with
+// a host error. So the contract cannot report "archived" from inside a call;
+// instead `restore_hint` tells clients exactly which ledger keys a token depends
+// on, to put in a RestoreFootprint operation (or in the footprint of the retried
+// transaction) when simulation or submission reports archived entries.
+//
+// The hint never reads the token's own entries, since those may be the archived
+// ones. It lists every key that can hold data about the token; restoring a key
+// that does not exist or is still live does nothing.
+
+use soroban_sdk::{contractimpl, xdr::ToXdr, Bytes, Env, Executable, Vec};
+
+use crate::{
+    storage, HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+// XDR discriminants used to build LedgerKeys (see Stellar-ledger-entries.x).
+const LEDGER_ENTRY_CONTRACT_DATA: u32 = 6;
+const LEDGER_ENTRY_CONTRACT_CODE: u32 = 7;
+const SCV_LEDGER_KEY_CONTRACT_INSTANCE: u32 = 20;
+const DURABILITY_PERSISTENT: u32 = 1;
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Lists the ledger keys that must be live to use `token_id`.
+     * @return One XDR-encoded LedgerKey per entry, ready to base64 into a
+     * restore footprint. Restoring a key that is already live is harmless.
+     */
+    pub fn restore_hint(env: Env, token_id: u64) -> Vec<Bytes> {
+        let mut keys = Vec::new(&env);
+        keys.push_back(instance_key(&env));
+        if let Some(code) = code_key(&env) {
+            keys.push_back(code);
+        }
+        for key in storage::token_keys(token_id) {
+            keys.push_back(persistent_key(&env, key));
+        }
+        for key in storage::ext_token_keys(&env, token_id) {
+            keys.push_back(persistent_key(&env, key));
+        }
+        for key in storage::more_token_keys(&env, token_id) {
+            keys.push_back(persistent_key(&env, key));
+        }
+        keys
+    }
+}
+
+/// LedgerKey of this contract's instance entry.
+fn instance_key(env: &Env) -> Bytes {
+    let mut key = contract_data_prefix(env);
+    key.append(&Bytes::from_array(
+        env,
+        &SCV_LEDGER_KEY_CONTRACT_INSTANCE.to_be_bytes(),
+    ));
+    key.append(&Bytes::from_array(
+        env,
+        &DURABILITY_PERSISTENT.to_be_bytes(),
+    ));
+    key
+}
+
+/// LedgerKey of this contract's persistent entry under `key`.
+fn persistent_key(env: &Env, key: impl ToXdr) -> Bytes {
+    let mut ledger_key = contract_data_prefix(env);
+    ledger_key.append(&key.to_xdr(env));
+    ledger_key.append(&Bytes::from_array(
+        env,
+        &DURABILITY_PERSISTENT.to_be_bytes(),
+    ));
+    ledger_key
+}
+
+/// LedgerKey of the wasm this contract runs, if it runs wasm.
+fn code_key(env: &Env) -> Option<Bytes> {
+    let Some(Executable::Wasm(hash)) = env.current_contract_address().executable() else {
+        return None;
+    };
+    let mut key = Bytes::from_array(env, &LEDGER_ENTRY_CONTRACT_CODE.to_be_bytes());
+    key.append(&Bytes::from(&hash));
+    Some(key)
+}
+
+/// The `CONTRACT_DATA` arm tag followed by this contract's ScAddress.
+fn contract_data_prefix(env: &Env) -> Bytes {
+    let mut key = Bytes::from_array(env, &LEDGER_ENTRY_CONTRACT_DATA.to_be_bytes());
+    // An Address encodes as an ScVal: a 4-byte type tag, then the ScAddress.
+    let address = env.current_contract_address().to_xdr(env);
+    key.append(&address.slice(4..));
+    key
+}
@@ -0,0 +1,296 @@
+// English auctions.
+//
+// An owner puts a token up with `start_auction`: a reserve price in a payment
+// token and a duration in seconds. Bidders `bid` ever higher amounts, which
+// the contract holds in escrow; each new high bid refunds the one it beats.
+// Once the auction has ended anyone may `settle_auction`: the high bid goes to
+// the seller (less the hotel's royalty, see royalties.rs) and the token to the
+// high bidder, through `move_token` like any transfer. An auction nobody bid
+// on settles with nothing changing hands.
+//
+// The token stays with the seller until settlement. If it changes owner any
+// other way first (sale, transfer, clawback, burn), the auction lapses and the
+// high bid is refunded, so escrowed bids never outlive their auction. The same
+// goes for a token that can't be handed over at settlement (frozen, in trust,
+// its property retired or its lease expired): the auction lapses and the high
+// bidder gets their bid back rather than leaving it locked in escrow.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, token, Address, Env};
+
+use crate::{
+    check_owner, enter, events, fail, freeze, move_token, pause, receipts, royalties, DataKey,
+    Error, ExtError, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, ReceiptKind,
+};
+
+/**
+ * @title Auction
+ * @dev A token's running auction.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Auction {
+    /// The owner who started the auction, and is paid the winning bid.
+    pub seller: Address,
+    /// Token bids are made in.
+    pub payment_token: Address,
+    /// The lowest acceptable first bid.
+    pub reserve: i128,
+    /// Ledger timestamp at which bidding closes.
+    pub ends_at: u64,
+    /// The highest bidder so far, whose bid the contract holds.
+    pub high_bidder: Option<Address>,
+    pub high_bid: i128,
+}
+
+/**
+ * @title AuctionStarted
+ * @dev Published when an owner auctions a token.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionStarted {
+    #[topic]
+    pub token_id: u64,
+    pub seller: Address,
+    pub payment_token: Address,
+    pub reserve: i128,
+    pub ends_at: u64,
+}
+
+/**
+ * @title BidPlaced
+ * @dev Published when a bid becomes an auction's highest.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BidPlaced {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub bidder: Address,
+    pub amount: i128,
+}
+
+/**
+ * @title AuctionSettled
+ * @dev Published when an ended auction is settled; winner is None if nobody bid.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionSettled {
+    #[topic]
+    pub token_id: u64,
+    pub winner: Option<Address>,
+    pub price: i128,
+}
+
+/**
+ * @title AuctionLapsed
+ * @dev Published when an auction ends because its token changed owner, or
+ * couldn't be handed over at settlement.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionLapsed {
+    #[topic]
+    pub token_id: u64,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Auctions a token, replacing an earlier auction of it nobody bid on.
+     * Fails with AuctionRunning while an auction with bids is running.
+     * @param seller The current owner (must sign); receives the winning bid.
+     * @param reserve The lowest acceptable first bid; must not be negative.
+     * @param duration Seconds until bidding closes; must not be 0.
+     */
+    pub fn start_auction(
+        env: Env,
+        seller: Address,
+        token_id: u64,
+        payment_token: Address,
+        reserve: i128,
+        duration: u64,
+    ) -> Result<(), Error> {
+        seller.require_auth();
+        enter(&env, "start_auction")?;
+        pause::check(&env)?;
+
+        check_owner(&env, token_id, &seller)?;
+        freeze::check(&env, token_id);
+        if reserve < 0 || duration == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if Self::auction(env.clone(), token_id).is_some_and(|a| a.high_bidder.is_some()) {
+            fail(&env, ExtError::AuctionRunning);
+        }
+        let ends_at = env.ledger().timestamp().saturating_add(duration);
+        set_auction(
+            &env,
+            token_id,
+            &Auction {
+                seller: seller.clone(),
+                payment_token: payment_token.clone(),
+                reserve,
+                ends_at,
+                high_bidder: None,
+                high_bid: 0,
+            },
+        );
+
+        events::emit(
+            &env,
+            &AuctionStarted {
+                token_id,
+                seller,
+                payment_token,
+                reserve,
+                ends_at,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Bids on a running auction. The amount is held by the contract, and
+     * the bid it beats is refunded. Fails with InvalidAmount unless the bid
+     * beats the high bid (or meets the reserve, for the first bid), and with
+     * AuctionEnded once bidding has closed.
+     * @param bidder The bidder (must sign and pay).
+     */
+    pub fn bid(env: Env, bidder: Address, token_id: u64, amount: i128) -> Result<(), Error> {
+        bidder.require_auth();
+        enter(&env, "bid")?;
+        pause::check(&env)?;
+
+        let mut auction =
+            Self::auction(env.clone(), token_id).unwrap_or_else(|| fail(&env, ExtError::NoAuction));
+        if env.ledger().timestamp() >= auction.ends_at {
+            fail(&env, ExtError::AuctionEnded);
+        }
+        let beaten = match &auction.high_bidder {
+            Some(high_bidder) if amount > auction.high_bid => Some(high_bidder.clone()),
+            None if amount >= auction.reserve => None,
+            _ => return Err(Error::InvalidAmount),
+        };
+
+        let payment = token::Client::new(&env, &auction.payment_token);
+        payment.transfer(&bidder, env.current_contract_address(), &amount);
+        if let Some(beaten) = beaten {
+            payment.transfer(&env.current_contract_address(), &beaten, &auction.high_bid);
+        }
+        auction.high_bidder = Some(bidder.clone());
+        auction.high_bid = amount;
+        set_auction(&env, token_id, &auction);
+
+        events::emit(
+            &env,
+            &BidPlaced {
+                token_id,
+                bidder,
+                amount,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Settles an ended auction: pays the seller the high bid and gives
+     * the high bidder the token. Anyone may call it. Fails with
+     * AuctionRunning while bidding is open. If the token can't move to the
+     * high bidder, the auction lapses and the high bid is refunded instead.
+     */
+    pub fn settle_auction(env: Env, token_id: u64) -> Result<(), Error> {
+        enter(&env, "settle_auction")?;
+        pause::check(&env)?;
+
+        let auction =
+            Self::auction(env.clone(), token_id).unwrap_or_else(|| fail(&env, ExtError::NoAuction));
+        if env.ledger().timestamp() < auction.ends_at {
+            fail(&env, ExtError::AuctionRunning);
+        }
+        // Removed first, so that move_token doesn't see it lapse.
+        remove_auction(&env, token_id);
+        if let Some(winner) = &auction.high_bidder {
+            // The token moves first; if it can't, nothing else has been
+            // written and the bid goes back to the bidder.
+            if Self::is_frozen(env.clone(), token_id)
+                || move_token(&env, &auction.seller, winner, token_id).is_err()
+            {
+                refund(&env, &auction);
+                events::emit(&env, &AuctionLapsed { token_id });
+                return Ok(());
+            }
+            royalties::pay(
+                &env,
+                token_id,
+                &auction.payment_token,
+                &env.current_contract_address(),
+                &auction.seller,
+                auction.high_bid,
+            )?;
+            receipts::record_sale(
+                &env,
+                ReceiptKind::Resale,
+                token_id,
+                winner,
+                &auction.seller,
+                auction.high_bid,
+                &auction.payment_token,
+            );
+        }
+
+        events::emit(
+            &env,
+            &AuctionSettled {
+                token_id,
+                winner: auction.high_bidder,
+                price: auction.high_bid,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns a token's auction, if one is running or awaiting settlement.
+     */
+    pub fn auction(env: Env, token_id: u64) -> Option<Auction> {
+        env.storage().persistent().get(&DataKey::Auction(token_id))
+    }
+}
+
+/// Ends a token's auction when the token changes owner outside of settlement,
+/// refunding the high bid.
+pub(crate) fn lapse(env: &Env, token_id: u64) {
+    let Some(auction) = HotelTimeshareContract::auction(env.clone(), token_id) else {
+        return;
+    };
+    remove_auction(env, token_id);
+    refund(env, &auction);
+    events::emit(env, &AuctionLapsed { token_id });
+}
+
+/// Returns an auction's high bid, if any, to its bidder.
+fn refund(env: &Env, auction: &Auction) {
+    if let Some(high_bidder) = &auction.high_bidder {
+        token::Client::new(env, &auction.payment_token).transfer(
+            &env.current_contract_address(),
+            high_bidder,
+            &auction.high_bid,
+        );
+    }
+}
+
+fn set_auction(env: &Env, token_id: u64, auction: &Auction) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Auction(token_id), auction);
+}
+
+fn remove_auction(env: &Env, token_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Auction(token_id));
+}
@@ -0,0 +1,128 @@
+// Per-property branding.
+//
+// One deployment can serve several booking frontends, each under its own
+// property's brand. A manager sets a property's Branding with `set_branding`:
+// the name to display, a logo URI, a few color hints and a hash of the support
+// contact. Frontends read it with `branding`, or every property's at once from
+// `config`. Like token URIs (see metadata.rs), the strings are stored as given
+// and the contract doesn't resolve or validate them.
+
+use soroban_sdk::{
+    contractevent, contractimpl, contracttype, Address, BytesN, Env, Map, String, Vec,
+};
+
+use crate::{
+    enter, events, fail, hotels, roles, Error, ExtError, ExtKey2, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, Role,
+};
+
+/// Most color hints a property's branding can have.
+pub const MAX_BRAND_COLORS: u32 = 4;
+
+/**
+ * @title Branding
+ * @dev How a property's frontends present it.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Branding {
+    pub display_name: String,
+    pub logo_uri: String,
+    /// Colors as 0xRRGGBB, primary first; at most MAX_BRAND_COLORS.
+    pub colors: Vec<u32>,
+    /// SHA-256 of the support contact, e.g. an email address, so frontends
+    /// can check one without the contract publishing it.
+    pub support_contact_hash: BytesN<32>,
+}
+
+/**
+ * @title BrandingSet
+ * @dev Published when a property's branding changes or is cleared.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BrandingSet {
+    #[topic]
+    pub hotel_id: u32,
+    pub cleared: bool,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Sets a property's branding, replacing any earlier one. Manager role
+     * (or admin). Fails with InvalidBranding for more than MAX_BRAND_COLORS
+     * colors or one above 0xFFFFFF.
+     * @param caller The manager (must sign).
+     */
+    pub fn set_branding(
+        env: Env,
+        caller: Address,
+        hotel_id: u32,
+        branding: Branding,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "set_branding")?;
+
+        hotels::check(&env, hotel_id)?;
+        if branding.colors.len() > MAX_BRAND_COLORS
+            || branding.colors.iter().any(|color| color > 0xFF_FFFF)
+        {
+            fail(&env, ExtError::InvalidBranding);
+        }
+        env.storage()
+            .persistent()
+            .set(&ExtKey2::Branding(hotel_id), &branding);
+
+        events::emit(
+            &env,
+            &BrandingSet {
+                hotel_id,
+                cleared: false,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Clears a property's branding. Manager role (or admin).
+     * @param caller The manager (must sign).
+     */
+    pub fn clear_branding(env: Env, caller: Address, hotel_id: u32) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "clear_branding")?;
+
+        env.storage()
+            .persistent()
+            .remove(&ExtKey2::Branding(hotel_id));
+
+        events::emit(
+            &env,
+            &BrandingSet {
+                hotel_id,
+                cleared: true,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns a property's branding, if it has one.
+     */
+    pub fn branding(env: Env, hotel_id: u32) -> Option<Branding> {
+        env.storage().persistent().get(&ExtKey2::Branding(hotel_id))
+    }
+
+    /**
+     * @dev Returns the branding of every property that has one, by hotel ID.
+     */
+    pub fn brandings(env: Env) -> Map<u32, Branding> {
+        let mut brandings = Map::new(&env);
+        for hotel_id in 1..=hotels::hotel_count(&env) {
+            if let Some(branding) = Self::branding(env.clone(), hotel_id) {
+                brandings.set(hotel_id, branding);
+            }
+        }
+        brandings
+    }
+}
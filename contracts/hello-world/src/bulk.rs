@@ -0,0 +1,157 @@
+// Bulk listing management.
+//
+// Brokers managing hundreds of weeks change listings in batches of up to
+// MAX_LIST_BATCH tokens: `list_many`, `reprice_many` and `cancel_many` work
+// like `list_for_sale` and `cancel_listing` on each token in turn, for the
+// owner or one of its operators (see approvals.rs). Listings are always made
+// in the owner's name, so the owner is paid for the sales.
+//
+// One bad token doesn't sink the batch. Each call returns a code per item, in
+// order: BULK_OK if the change was made, or else the code of the error the
+// single-token call would have failed with (e.g. NotOwner, NotListed,
+// TokenFrozen), and the rest of the batch goes ahead.
+
+use soroban_sdk::{contractimpl, Address, Env, Vec};
+
+use crate::{
+    check_owner, enter, events, market, pause, Error, ExtError, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, Listing, ListingCancelled,
+};
+
+/// Most tokens a bulk listing call changes at once, so a batch stays well
+/// within per-transaction resource limits.
+pub const MAX_LIST_BATCH: u32 = 100;
+
+/// The per-item code of a change that was made.
+pub const BULK_OK: u32 = 0;
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Lists tokens for sale, replacing any earlier listings of them.
+     * @param caller The owner or one of its operators (must sign).
+     * @param items (token ID, price) pairs, at most MAX_LIST_BATCH of them.
+     * @param payment_token The token the prices are paid in.
+     * @return A code per item: BULK_OK or the error it failed with.
+     */
+    pub fn list_many(
+        env: Env,
+        caller: Address,
+        owner: Address,
+        items: Vec<(u64, i128)>,
+        payment_token: Address,
+    ) -> Result<Vec<u32>, Error> {
+        check_caller(&env, &caller, &owner, items.len())?;
+        enter(&env, "list_many")?;
+        pause::check(&env)?;
+
+        let mut results = Vec::new(&env);
+        for (token_id, price) in items.iter() {
+            let result = check_listable(&env, &owner, token_id, price).map(|()| {
+                market::list(
+                    &env,
+                    token_id,
+                    Listing {
+                        seller: owner.clone(),
+                        price,
+                        payment_token: payment_token.clone(),
+                    },
+                )
+            });
+            results.push_back(code(result));
+        }
+        Ok(results)
+    }
+
+    /**
+     * @dev Changes the prices of listed tokens, keeping their payment tokens.
+     * @param caller The owner or one of its operators (must sign).
+     * @param items (token ID, new price) pairs, at most MAX_LIST_BATCH of them.
+     * @return A code per item: BULK_OK or the error it failed with.
+     */
+    pub fn reprice_many(
+        env: Env,
+        caller: Address,
+        owner: Address,
+        items: Vec<(u64, i128)>,
+    ) -> Result<Vec<u32>, Error> {
+        check_caller(&env, &caller, &owner, items.len())?;
+        enter(&env, "reprice_many")?;
+        pause::check(&env)?;
+
+        let mut results = Vec::new(&env);
+        for (token_id, price) in items.iter() {
+            let result = check_listable(&env, &owner, token_id, price).and_then(|()| {
+                let listing =
+                    Self::listing(env.clone(), token_id).ok_or(Error::NotListed as u32)?;
+                market::list(&env, token_id, Listing { price, ..listing });
+                Ok(())
+            });
+            results.push_back(code(result));
+        }
+        Ok(results)
+    }
+
+    /**
+     * @dev Withdraws the listings of tokens.
+     * @param caller The owner or one of its operators (must sign).
+     * @param token_ids At most MAX_LIST_BATCH tokens.
+     * @return A code per token: BULK_OK or the error it failed with.
+     */
+    pub fn cancel_many(
+        env: Env,
+        caller: Address,
+        owner: Address,
+        token_ids: Vec<u64>,
+    ) -> Result<Vec<u32>, Error> {
+        check_caller(&env, &caller, &owner, token_ids.len())?;
+        enter(&env, "cancel_many")?;
+
+        let mut results = Vec::new(&env);
+        for token_id in token_ids.iter() {
+            let result = check_owner(&env, token_id, &owner)
+                .map_err(|error| error as u32)
+                .and_then(|()| {
+                    Self::listing(env.clone(), token_id).ok_or(Error::NotListed as u32)?;
+                    market::clear(&env, token_id);
+                    events::emit(&env, &ListingCancelled { token_id });
+                    Ok(())
+                });
+            results.push_back(code(result));
+        }
+        Ok(results)
+    }
+}
+
+/// Requires `caller`'s signature, that it is `owner` or one of its operators,
+/// and that the batch isn't too large.
+fn check_caller(env: &Env, caller: &Address, owner: &Address, len: u32) -> Result<(), Error> {
+    caller.require_auth();
+    if caller != owner
+        && !HotelTimeshareContract::is_approved_for_all(env.clone(), owner.clone(), caller.clone())
+    {
+        return Err(Error::NotApproved);
+    }
+    if len > MAX_LIST_BATCH {
+        return Err(Error::BatchTooLarge);
+    }
+    Ok(())
+}
+
+/// Checks what `list_for_sale` would for one token, failing with the error's
+/// code (a frozen token fails instead of aborting the batch).
+fn check_listable(env: &Env, owner: &Address, token_id: u64, price: i128) -> Result<(), u32> {
+    check_owner(env, token_id, owner).map_err(|error| error as u32)?;
+    if HotelTimeshareContract::is_frozen(env.clone(), token_id) {
+        return Err(ExtError::TokenFrozen as u32);
+    }
+    if price < 0 {
+        return Err(Error::InvalidAmount as u32);
+    }
+    Ok(())
+}
+
+/// The per-item code of an outcome.
+fn code(result: Result<(), u32>) -> u32 {
+    result.err().unwrap_or(BULK_OK)
+}
@@ -0,0 +1,128 @@
+// Burning tokens.
+//
+// When a timeshare agreement ends the deed is destroyed: the token's info and
+// owner entries and everything attached to it (trust, pending transfer,
+// approval, listing, auction, trade) are removed, the owner's index and the
+// total supply shrink, and a `Burn` event is published. Burned token IDs are
+// never reused.
+//
+// Owners may burn their own tokens unless they are held in trust, since the
+// beneficiary has an interest in them, or frozen (see freeze.rs). The admin
+// can burn any token. No token is burned, by anyone, while its week is rented
+// out to a renter who hasn't stayed yet (see rental.rs).
+
+use soroban_sdk::{contractevent, contractimpl, log, Address, Env};
+
+use crate::{
+    approvals, auction, chains, check_owner, checkin, dutch, enter, enumeration, escrow, events,
+    filters, freeze, ids, intents, late, market, metadata, owner_of, pending, prepay, refusal,
+    rental, require_admin, sealed, shares, slots, supply, trust, valuation, DataKey, Error,
+    HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/**
+ * @title Burn
+ * @dev Published when a token is destroyed, whichever endpoint burned it.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Burn {
+    #[topic]
+    pub token_id: u64,
+    pub owner: Address,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Destroys a token. Fails with AlreadyInTrust for tokens held in trust,
+     * TokenFrozen for frozen ones, and RentalActive for rented ones.
+     * @param owner The current owner (must sign).
+     * @param token_id The token to burn.
+     */
+    pub fn burn(env: Env, owner: Address, token_id: u64) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "burn")?;
+
+        check_burnable(&env, &owner, token_id)?;
+        burn_token(&env, &owner, token_id);
+        Ok(())
+    }
+
+    /**
+     * @dev Destroys any token, including one held in trust. Admin only.
+     * Fails with RentalActive while the token is rented out.
+     * @param token_id The token to burn.
+     */
+    pub fn admin_burn(env: Env, token_id: u64) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "admin_burn")?;
+
+        let owner = owner_of(&env, token_id)?;
+        burn_token(&env, &owner, token_id);
+        Ok(())
+    }
+}
+
+/// The checks an owner's burn makes: `owner` holds the token, and it is
+/// neither held in trust (AlreadyInTrust) nor frozen (TokenFrozen).
+pub(crate) fn check_burnable(env: &Env, owner: &Address, token_id: u64) -> Result<(), Error> {
+    check_owner(env, token_id, owner)?;
+    if env.storage().persistent().has(&DataKey::Trust(token_id)) {
+        return Err(Error::AlreadyInTrust);
+    }
+    freeze::check(env, token_id);
+    Ok(())
+}
+
+pub(crate) fn burn_token(env: &Env, owner: &Address, token_id: u64) {
+    // 1. Remove everything attached to the token, unless a renter still holds
+    // this year's week.
+    rental::check_not_rented(env, token_id);
+    trust::dissolve(env, token_id);
+    prepay::close(env, token_id, owner);
+    pending::clear(env, token_id);
+    approvals::clear(env, token_id);
+    market::clear(env, token_id);
+    auction::lapse(env, token_id);
+    sealed::lapse(env, token_id);
+    dutch::clear(env, token_id);
+    escrow::remove_trade(env, token_id);
+    refusal::lapse(env, token_id);
+    chains::clear(env, token_id);
+    intents::clear(env, token_id);
+    shares::clear(env, token_id);
+    supply::record_burn(env, token_id);
+    filters::clear(env, token_id);
+    metadata::clear(env, token_id);
+    checkin::clear(env, token_id);
+    valuation::clear(env, token_id);
+    late::clear(env, token_id);
+    slots::release(env, token_id);
+
+    // 2. Remove the token itself and update the supply accounting.
+    env.storage().persistent().remove(&DataKey::Info(token_id));
+    env.storage().persistent().remove(&DataKey::Owner(token_id));
+    env.storage().persistent().remove(&DataKey::Usage(token_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Rental(token_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Maintenance(token_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Frozen(token_id));
+    enumeration::remove(env, owner, token_id);
+    ids::record_burn(env);
+
+    // 3. Log the burn and publish the burn event for indexers.
+    log!(env, "Burned token #{} of {}", token_id, owner);
+    events::emit(
+        env,
+        &Burn {
+            token_id,
+            owner: owner.clone(),
+        },
+    );
+}
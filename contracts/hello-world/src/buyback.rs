@@ -0,0 +1,364 @@
+// Buyback program.
+//
+// A hotel that promises owners a guaranteed exit backs it with a standing bid:
+// the admin funds a buyback for one of a property's tiers with `fund_buyback`
+// (a price, a budget moved into the contract, and the window it is open in),
+// and any owner of a token of that tier can `sell_to_hotel` while it is open.
+// The owner is paid the price from the budget and the token goes to the admin
+// who last funded the buyback, in the same call. As the hotel is the buyer,
+// no royalty is taken.
+//
+// Each sale comes out of the budget. Once what is left can't pay the price,
+// the buyback pauses itself (BuybackExhausted) until the admin tops it up
+// with another `fund_buyback`, which may also change the price and window but
+// not the payment token. `close_buyback` ends it and refunds what is left of
+// the budget, and works while the contract is paused, so a pause never locks
+// the hotel's funds in. At most MAX_BUYBACKS are open at a time; `buybacks`
+// lists them.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, token, Address, Env, Symbol, Vec};
+
+use crate::{
+    check_owner, enter, events, fail, freeze, hotels, move_token, or_fail, pause, receipts,
+    require_admin, Amount, Error, ExtError, ExtKey2, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, ReceiptKind,
+};
+
+/// Most buybacks open at a time.
+pub const MAX_BUYBACKS: u32 = 20;
+
+/**
+ * @title Buyback
+ * @dev The hotel's standing bid for the tokens of one of a property's tiers.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Buyback {
+    pub hotel_id: u32,
+    pub tier: Symbol,
+    /// Token the price is paid in. Fixed once funded.
+    pub payment_token: Address,
+    /// Paid for each token sold to the hotel.
+    pub price: i128,
+    /// What is left of the funds the contract holds for the buyback.
+    pub budget: i128,
+    /// Ledger timestamp from which owners can sell.
+    pub opens_at: u64,
+    /// Ledger timestamp after which owners can't sell.
+    pub closes_at: u64,
+    /// Receives the tokens bought back and, on closing, the rest of the
+    /// budget: the admin who last funded it.
+    pub buyer: Address,
+    /// Whether it paused itself because the budget can't pay the price.
+    pub exhausted: bool,
+}
+
+/**
+ * @title BuybackFunded
+ * @dev Published when the admin opens or tops up a buyback.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuybackFunded {
+    #[topic]
+    pub hotel_id: u32,
+    #[topic]
+    pub tier: Symbol,
+    pub payment_token: Address,
+    pub price: i128,
+    pub budget: i128,
+    pub opens_at: u64,
+    pub closes_at: u64,
+}
+
+/**
+ * @title SoldToHotel
+ * @dev Published when an owner sells a token to a buyback, next to its
+ * Transfer event.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SoldToHotel {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub seller: Address,
+    pub hotel_id: u32,
+    pub tier: Symbol,
+    pub price: i128,
+    /// What is left of the budget.
+    pub budget: i128,
+}
+
+/**
+ * @title BuybackExhausted
+ * @dev Published when a sale leaves a buyback's budget below its price.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuybackExhausted {
+    #[topic]
+    pub hotel_id: u32,
+    #[topic]
+    pub tier: Symbol,
+    pub budget: i128,
+}
+
+/**
+ * @title BuybackClosed
+ * @dev Published when the admin closes a buyback.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuybackClosed {
+    #[topic]
+    pub hotel_id: u32,
+    #[topic]
+    pub tier: Symbol,
+    /// The budget refunded to the buyer.
+    pub refunded: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Opens a buyback for a property's tier, or tops up and updates the
+     * open one, moving `amount` from the admin into its budget. Admin only.
+     * Fails with InvalidBuyback for a price that isn't positive, a window
+     * that closes before it opens, or another payment token than the open
+     * buyback's, and TooManyBuybacks if MAX_BUYBACKS are open.
+     * @param amount Added to the budget; must not be negative.
+     * @return The buyback as updated.
+     */
+    #[allow(clippy::too_many_arguments)]
+    pub fn fund_buyback(
+        env: Env,
+        hotel_id: u32,
+        tier: Symbol,
+        payment_token: Address,
+        price: i128,
+        amount: i128,
+        opens_at: u64,
+        closes_at: u64,
+    ) -> Result<Buyback, Error> {
+        let admin = require_admin(&env)?;
+        enter(&env, "fund_buyback")?;
+        pause::check(&env)?;
+
+        hotels::check(&env, hotel_id)?;
+        if amount < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if price <= 0 || closes_at < opens_at {
+            fail(&env, ExtError::InvalidBuyback);
+        }
+        let budget = match Self::buyback(env.clone(), hotel_id, tier.clone()) {
+            Some(open) if open.payment_token != payment_token => {
+                fail(&env, ExtError::InvalidBuyback)
+            }
+            Some(open) => Amount::of(open.budget),
+            None => {
+                let mut open = Self::buybacks(env.clone());
+                if open.len() >= MAX_BUYBACKS {
+                    fail(&env, ExtError::TooManyBuybacks);
+                }
+                open.push_back((hotel_id, tier.clone()));
+                env.storage().persistent().set(&ExtKey2::Buybacks, &open);
+                Ok(Amount::ZERO)
+            }
+        };
+        let budget = or_fail(
+            &env,
+            budget.and_then(|budget| budget.checked_add(Amount::of(amount)?)),
+        );
+        if amount > 0 {
+            token::Client::new(&env, &payment_token).transfer(
+                &admin,
+                env.current_contract_address(),
+                &amount,
+            );
+        }
+
+        let buyback = Buyback {
+            hotel_id,
+            tier: tier.clone(),
+            payment_token: payment_token.clone(),
+            price,
+            budget: budget.get(),
+            opens_at,
+            closes_at,
+            buyer: admin,
+            exhausted: budget.get() < price,
+        };
+        store(&env, &buyback);
+        events::emit(
+            &env,
+            &BuybackFunded {
+                hotel_id,
+                tier,
+                payment_token,
+                price,
+                budget: buyback.budget,
+                opens_at,
+                closes_at,
+            },
+        );
+        Ok(buyback)
+    }
+
+    /**
+     * @dev Closes a buyback and refunds what is left of its budget to its
+     * buyer. Admin only. Works while paused. Fails with NoBuyback if none is
+     * open for the tier.
+     */
+    pub fn close_buyback(env: Env, hotel_id: u32, tier: Symbol) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "close_buyback")?;
+
+        let buyback = Self::buyback(env.clone(), hotel_id, tier.clone())
+            .unwrap_or_else(|| fail(&env, ExtError::NoBuyback));
+        env.storage()
+            .persistent()
+            .remove(&ExtKey2::Buyback(hotel_id, tier.clone()));
+        let mut open = Self::buybacks(env.clone());
+        if let Some(at) = open.first_index_of((hotel_id, tier.clone())) {
+            open.remove(at);
+        }
+        env.storage().persistent().set(&ExtKey2::Buybacks, &open);
+        if buyback.budget > 0 {
+            token::Client::new(&env, &buyback.payment_token).transfer(
+                &env.current_contract_address(),
+                &buyback.buyer,
+                &buyback.budget,
+            );
+        }
+
+        events::emit(
+            &env,
+            &BuybackClosed {
+                hotel_id,
+                tier,
+                refunded: buyback.budget,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Sells a token to the buyback of its property and tier: the owner
+     * is paid the price from the budget and the buyer receives the token.
+     * Fails with NoBuyback if none is open for the token's tier,
+     * BuybackNotOpen outside its window, and BuybackExhausted while its
+     * budget can't pay the price.
+     * @param owner The current owner (must sign); receives the price.
+     * @return What is left of the budget.
+     */
+    pub fn sell_to_hotel(env: Env, owner: Address, token_id: u64) -> Result<i128, Error> {
+        owner.require_auth();
+        enter(&env, "sell_to_hotel")?;
+        pause::check(&env)?;
+
+        check_owner(&env, token_id, &owner)?;
+        freeze::check(&env, token_id);
+        let info = Self::get_info(env.clone(), token_id)?;
+        let mut buyback = Self::tier(env.clone(), token_id)
+            .and_then(|tier| Self::buyback(env.clone(), info.hotel_id, tier))
+            .unwrap_or_else(|| fail(&env, ExtError::NoBuyback));
+        let now = env.ledger().timestamp();
+        if now < buyback.opens_at || now > buyback.closes_at {
+            fail(&env, ExtError::BuybackNotOpen);
+        }
+        if buyback.exhausted {
+            fail(&env, ExtError::BuybackExhausted);
+        }
+
+        // 1. The price comes out of the budget, which pauses the buyback once
+        // it can't pay another.
+        let budget = or_fail(
+            &env,
+            Amount::of(buyback.budget)
+                .and_then(|budget| budget.checked_sub(Amount::of(buyback.price)?)),
+        );
+        buyback.budget = budget.get();
+        buyback.exhausted = buyback.budget < buyback.price;
+        store(&env, &buyback);
+
+        // 2. The owner is paid and the token goes to the buyer.
+        token::Client::new(&env, &buyback.payment_token).transfer(
+            &env.current_contract_address(),
+            &owner,
+            &buyback.price,
+        );
+        move_token(&env, &owner, &buyback.buyer, token_id)?;
+        receipts::record_sale(
+            &env,
+            ReceiptKind::Resale,
+            token_id,
+            &buyback.buyer,
+            &owner,
+            buyback.price,
+            &buyback.payment_token,
+        );
+
+        events::emit(
+            &env,
+            &SoldToHotel {
+                token_id,
+                seller: owner,
+                hotel_id: buyback.hotel_id,
+                tier: buyback.tier.clone(),
+                price: buyback.price,
+                budget: buyback.budget,
+            },
+        );
+        if buyback.exhausted {
+            events::emit(
+                &env,
+                &BuybackExhausted {
+                    hotel_id: buyback.hotel_id,
+                    tier: buyback.tier,
+                    budget: buyback.budget,
+                },
+            );
+        }
+        Ok(buyback.budget)
+    }
+
+    /**
+     * @dev Returns the buyback open for a property's tier, if any.
+     */
+    pub fn buyback(env: Env, hotel_id: u32, tier: Symbol) -> Option<Buyback> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::Buyback(hotel_id, tier))
+    }
+
+    /**
+     * @dev Lists the open buybacks, as (hotel ID, tier), in the order they
+     * were opened.
+     */
+    pub fn buybacks(env: Env) -> Vec<(u32, Symbol)> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::Buybacks)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+}
+
+/// Returns every open buyback, in the order they were opened.
+pub(crate) fn all(env: &Env) -> Vec<Buyback> {
+    let mut all = Vec::new(env);
+    for (hotel_id, tier) in HotelTimeshareContract::buybacks(env.clone()).iter() {
+        if let Some(buyback) = HotelTimeshareContract::buyback(env.clone(), hotel_id, tier) {
+            all.push_back(buyback);
+        }
+    }
+    all
+}
+
+fn store(env: &Env, buyback: &Buyback) {
+    env.storage().persistent().set(
+        &ExtKey2::Buyback(buyback.hotel_id, buyback.tier.clone()),
+        buyback,
+    );
+}
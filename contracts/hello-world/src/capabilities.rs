@@ -0,0 +1,104 @@
+// Interface discovery.
+//
+// Generic tooling (wallets, aggregator marketplaces) calls `capabilities()` to
+// find out which interfaces a deployment implements instead of probing for
+// endpoints. Add an entry here whenever a new interface ships.
+
+use soroban_sdk::{contractimpl, Env, Symbol, Vec};
+
+use crate::{HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient};
+
+/// Interfaces implemented by this build.
+pub const CAPABILITIES: &[&str] = &[
+    "nft_core",       // initialize, mint, transfer, get_info
+    "trust",          // place_in_trust and beneficiary-acknowledged transfers
+    "alias",          // per-owner address book, transfer_to_alias
+    "two_phase",      // opt-in accept_transfer flow
+    "sink_guard",     // transfers to burn/sink addresses rejected, force_transfer
+    "notify_prefs",   // notification preference registry
+    "config",         // config()
+    "version_info",   // version_info() and the upgrade log
+    "daily_roots",    // event hash chain and commit_daily_root()
+    "metrics",        // per-day endpoint invocation counters
+    "deprecation",    // deprecate_endpoint / deprecations() with sunset ledgers
+    "enumeration",    // balance_of / tokens_of_owner, total_supply / tokens
+    "burn",           // burn / admin_burn
+    "mint_batch",     // mint_batch, up to MAX_MINT_BATCH tokens per call
+    "restore_hint",   // ledger keys to restore for an archived token
+    "approvals",      // approve / get_approved / transfer_from, operator approvals
+    "bump_portfolio", // batched TTL extension of an owner's tokens
+    "retirement",     // retire_property / redeem / sweep_property
+    "migration",      // schema_version / migrate / migrate_entries
+    "admin_handover", // propose_admin / accept_admin
+    "roles",          // grant_role / revoke_role / has_role, mint_by for minters
+    "pause",          // pause / unpause by the Pauser role
+    "upgrade",        // upgrade(new_wasm_hash), recorded in the upgrade log
+    "unique_slots",   // weeks 1..=52, one token per (hotel, room, week, year), token_by_slot
+    "lease_terms",    // year and lease_end_ledger in TimeshareInfo, is_active
+    "hotel_registry", // register_hotel / update_hotel / list_hotels, mint by hotel ID
+    "room_inventory", // add_room / retire_room / list_rooms, mints checked against rooms
+    "check_in",       // check_in / confirm_check_in / check_out, usage() per year
+    "swap",           // swap(owner_a, token_a, owner_b, token_b), signed by both
+    "rental",         // list_for_rent / rent / cancel_rental, renters check in
+    "maintenance",    // pay_maintenance / maintenance_status / delinquent_tokens
+    "freeze",         // freeze / unfreeze / is_frozen, frozen tokens stay put
+    "clawback",       // clawback(token_id, to, reason_code), clawback_record
+    "marketplace",    // list_for_sale / cancel_listing / buy, paid through a token contract
+    "auction",        // start_auction / bid / settle_auction, bids escrowed by the contract
+    "dutch_auction",  // start_dutch_auction / buy_dutch / dutch_price, price decays per ledger
+    "offers",         // make_offer / cancel_offer / accept_offer, amounts escrowed by the contract
+    "escrow_trade",   // open_trade / accept_trade / withdraw_trade, token held by the contract
+    "royalties",      // set_royalty / get_royalty, paid on buy, accept_offer and auctions
+    "fractional",     // fractionalize / transfer_share / redeem_shares, token held by the contract
+    "vouchers",       // redeem_voucher, lazy minting against admin-signed ed25519 vouchers
+    "allowlist",      // set_allowlist / allowlist_mint, Merkle-proof pre-sale, one mint per address
+    "token_uri",      // set_token_uri / token_uri, off-chain (IPFS) metadata per token
+    "metadata",       // update_info / freeze_metadata, manager corrections until frozen for good
+    "paid_mint",      // set_mint_price / buy_mint, primary sales paid through a token contract
+    "revenue_split",  // set_payees / withdraw, hotel proceeds split between payees
+    "cooling_off",    // set_cooling_off / cancel_purchase / release_purchase, escrowed primary sales
+    "supply_cap",     // set_supply_cap / hotel_supply / count_supply, enforced on every mint
+    "token_ttl",      // extend_token_ttl, automatic bumps on mint/transfer/get_info
+    "rent_pool",      // prepaid keeper bumps: deposit_rent / withdraw_rent / keeper_bump
+    "reserve_fund",   // set_reserve_share / fund_reserve / reserve spend proposals / reserve_report
+    "directory",      // join_directory / leave_directory / directory, opt-in owner contact hashes
+    "checkin_code",   // set_checkin_key / verify_checkin_code, kiosk check-in with guest-signed QR codes
+    "receipts",       // set_receipts / receipt / receipts_of, records of sales, primary sales and trades
+    "withholding",    // set_withholding / register_exemption / withheld, tax withheld from resales
+    "minor_units",    // set_minor_unit / minor_units, payment shares rounded to whole minor units
+    "sealed_auction", // start_sealed_auction / commit_bid / reveal_bid / settle_sealed, second-price
+    "bulk_listing",   // list_many / reprice_many / cancel_many with per-item codes, by owners or operators
+    "valuation",      // set_appraisal / token_value / portfolio_value, appraisals and time-weighted sale prices
+    "collection_offer", // set_tier / make_collection_offer / fill_collection_offer, offers on any matching token
+    "filters",        // tokens_by_tier / tokens_by_week / index_tokens, attribute indexes
+    "swap_chain",     // propose_chain / join_chain / withdraw_chain, escrowed multi-party swaps
+    "swap_intents",   // post_intent / cancel_intent / match_swaps, an order book of swap wants
+    "branding",       // set_branding / branding / brandings, per-property display settings
+    "localization",   // set_labels / labels / locales, localized labels of tiers, errors and custom codes
+    "prepay",         // set_prepay_discounts / fee_schedule / prepay_maintenance / release_prepaid
+    "late_fees",      // set_late_fee_policy / late_fee / check_delinquency on overdue maintenance
+    "grace_periods",  // policy / propose_grace_period / execute_grace_period, timelocked windows
+    "market_depth",   // floor_price / market_depth per property, tier and asset
+    "buyback",        // fund_buyback / sell_to_hotel standing bids per tier
+    "marketplace_adapter", // balance / owner_of / approve_for_all / name / symbol / royalty_info (u64 IDs)
+    "charity",        // register_charity / set_charity_split / donated, royalty shares paid to charities
+    "fee_index",      // set_fee_index / index_maintenance_fee, yearly repricing from a SEP-40 oracle
+    "force_majeure",  // flag_force_majeure / settle_force_majeure / fund_relief, rent refunds and replacement credits
+    "reminders",      // deadlines / send_reminders, DeadlineApproaching events for fees, rentals and auctions
+    "stats_privacy",  // set_stats_privacy / stats_privacy, thresholded hotel_supply and market_depth counts
+];
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Lists the interfaces this deployment implements.
+     * @return One Symbol per interface, e.g. "nft_core".
+     */
+    pub fn capabilities(env: Env) -> Vec<Symbol> {
+        let mut caps = Vec::new(&env);
+        for name in CAPABILITIES {
+            caps.push_back(Symbol::new(&env, name));
+        }
+        caps
+    }
+}
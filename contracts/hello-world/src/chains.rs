@@ -0,0 +1,294 @@
+// Escrowed swap chains.
+//
+// A swap between two owners needs each to want the other's week. Many
+// exchanges only work around a cycle: A wants B's week, B wants C's and C
+// wants A's. `propose_chain` sets out such a cycle as a list of legs, each an
+// owner and the token they give up, and every leg's token goes to the owner
+// of the next leg (the last to the first).
+//
+// Each owner signs their own leg with `join_chain`, which escrows their token
+// in the contract; the proposer's leg is joined with the proposal. When the
+// last leg joins, every token moves on in the same call, so the chain settles
+// whole or not at all. Until then any party can call `withdraw_chain`, which
+// returns every escrowed token to its owner and closes the chain.
+//
+// Like escrowed trades (see escrow.rs), every leg goes through `move_token`,
+// and a token that leaves the contract some other way (clawback, admin burn)
+// drops out of its chain: its leg counts as unjoined again, so a stale chain
+// can never release a token escrowed again later.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env, Vec};
+
+use crate::{
+    check_owner, enter, events, fail, move_token, owner_of, receipts, storage, Error, ExtError,
+    ExtKey, HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/// Most legs a swap chain can have.
+pub const MAX_CHAIN_LEGS: u32 = 5;
+
+/**
+ * @title ChainLeg
+ * @dev One owner's part of a swap chain: the token they give up.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChainLeg {
+    pub owner: Address,
+    pub token_id: u64,
+}
+
+/**
+ * @title SwapChain
+ * @dev A proposed cycle of swaps. Each leg's token goes to the owner of the
+ * next leg, and the last leg's to the first.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapChain {
+    pub legs: Vec<ChainLeg>,
+    /// Whether each leg's token is escrowed, in the order of `legs`.
+    pub joined: Vec<bool>,
+}
+
+/**
+ * @title ChainProposed
+ * @dev Published when a swap chain is proposed.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChainProposed {
+    #[topic]
+    pub chain_id: u32,
+    pub legs: Vec<ChainLeg>,
+}
+
+/**
+ * @title ChainJoined
+ * @dev Published when an owner escrows their leg of a swap chain.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChainJoined {
+    #[topic]
+    pub chain_id: u32,
+    #[topic]
+    pub owner: Address,
+}
+
+/**
+ * @title ChainSettled
+ * @dev Published when the last leg joins and every token moves on, next to
+ * their Transfer events.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChainSettled {
+    #[topic]
+    pub chain_id: u32,
+}
+
+/**
+ * @title ChainWithdrawn
+ * @dev Published when a party calls off a swap chain.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChainWithdrawn {
+    #[topic]
+    pub chain_id: u32,
+    pub by: Address,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Proposes a swap chain and joins the proposer's leg. Fails with
+     * InvalidSwap unless there are 2 to MAX_CHAIN_LEGS legs of distinct owners
+     * and tokens, and NotOwner unless the proposer has a leg and every owner
+     * holds their token.
+     * @param proposer The owner of one of the legs (must sign).
+     * @return The chain's ID.
+     */
+    pub fn propose_chain(env: Env, proposer: Address, legs: Vec<ChainLeg>) -> Result<u32, Error> {
+        proposer.require_auth();
+        enter(&env, "propose_chain")?;
+
+        if legs.len() < 2 || legs.len() > MAX_CHAIN_LEGS {
+            return Err(Error::InvalidSwap);
+        }
+        for (i, leg) in legs.iter().enumerate() {
+            for other in legs.iter().skip(i + 1) {
+                if leg.owner == other.owner || leg.token_id == other.token_id {
+                    return Err(Error::InvalidSwap);
+                }
+            }
+            check_owner(&env, leg.token_id, &leg.owner)?;
+        }
+        let Some(index) = legs.iter().position(|leg| leg.owner == proposer) else {
+            return Err(Error::NotOwner);
+        };
+
+        let chain_id = Self::chain_count(env.clone()) + 1;
+        storage::set_shared(&env, &ExtKey::SwapChainCount, &chain_id);
+        let mut joined = Vec::new(&env);
+        for _ in legs.iter() {
+            joined.push_back(false);
+        }
+        let chain = SwapChain {
+            legs: legs.clone(),
+            joined,
+        };
+        events::emit(&env, &ChainProposed { chain_id, legs });
+        join(&env, chain_id, chain, index as u32)?;
+        Ok(chain_id)
+    }
+
+    /**
+     * @dev Escrows the caller's leg of a swap chain, settling the chain if it
+     * was the last. Joining twice does nothing. Fails with NoSwapChain for an
+     * unknown or closed chain, and NotOwner unless the caller has a leg.
+     * @param owner The owner of the leg (must sign).
+     */
+    pub fn join_chain(env: Env, owner: Address, chain_id: u32) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "join_chain")?;
+
+        let chain = load(&env, chain_id);
+        let index = leg_of(&chain, &owner)?;
+        if chain.joined.get_unchecked(index) {
+            return Ok(());
+        }
+        join(&env, chain_id, chain, index)
+    }
+
+    /**
+     * @dev Calls off a swap chain, returning every escrowed token to its
+     * owner. Fails with NoSwapChain for an unknown or closed chain.
+     * @param caller The owner of one of the legs (must sign).
+     */
+    pub fn withdraw_chain(env: Env, caller: Address, chain_id: u32) -> Result<(), Error> {
+        caller.require_auth();
+        enter(&env, "withdraw_chain")?;
+
+        let chain = load(&env, chain_id);
+        leg_of(&chain, &caller)?;
+        close(&env, chain_id, &chain);
+        let contract = env.current_contract_address();
+        for (leg, joined) in chain.legs.iter().zip(chain.joined.iter()) {
+            if joined && owner_of(&env, leg.token_id).ok() == Some(contract.clone()) {
+                move_token(&env, &contract, &leg.owner, leg.token_id)?;
+            }
+        }
+
+        events::emit(
+            &env,
+            &ChainWithdrawn {
+                chain_id,
+                by: caller,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns a swap chain, unless it settled or was withdrawn.
+     */
+    pub fn swap_chain(env: Env, chain_id: u32) -> Option<SwapChain> {
+        env.storage().persistent().get(&ExtKey::SwapChain(chain_id))
+    }
+
+    /**
+     * @dev Returns how many swap chains have been proposed, i.e. the highest
+     * chain ID.
+     */
+    pub fn chain_count(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::SwapChainCount)
+            .unwrap_or(0)
+    }
+}
+
+/// Drops a token from its swap chain when it leaves the contract: its leg
+/// counts as unjoined again.
+pub(crate) fn clear(env: &Env, token_id: u64) {
+    let key = ExtKey::ChainOf(token_id);
+    let Some(chain_id) = env.storage().persistent().get::<_, u32>(&key) else {
+        return;
+    };
+    env.storage().persistent().remove(&key);
+    let Some(mut chain) = HotelTimeshareContract::swap_chain(env.clone(), chain_id) else {
+        return;
+    };
+    if let Some(index) = chain.legs.iter().position(|leg| leg.token_id == token_id) {
+        chain.joined.set(index as u32, false);
+        set_chain(env, chain_id, &chain);
+    }
+}
+
+/// Escrows leg `index`'s token, then settles the chain if every leg is in.
+fn join(env: &Env, chain_id: u32, mut chain: SwapChain, index: u32) -> Result<(), Error> {
+    let leg = chain.legs.get_unchecked(index);
+    let contract = env.current_contract_address();
+    move_token(env, &leg.owner, &contract, leg.token_id)?;
+    env.storage()
+        .persistent()
+        .set(&ExtKey::ChainOf(leg.token_id), &chain_id);
+    chain.joined.set(index, true);
+    events::emit(
+        env,
+        &ChainJoined {
+            chain_id,
+            owner: leg.owner,
+        },
+    );
+    if chain.joined.contains(false) {
+        set_chain(env, chain_id, &chain);
+        return Ok(());
+    }
+
+    // Every token is escrowed: each moves on to the next leg's owner.
+    close(env, chain_id, &chain);
+    let count = chain.legs.len();
+    for (i, leg) in chain.legs.iter().enumerate() {
+        let next = chain.legs.get_unchecked((i as u32 + 1) % count);
+        move_token(env, &contract, &next.owner, leg.token_id)?;
+        receipts::record_trade(env, leg.token_id, &leg.owner, &next.owner, next.token_id);
+    }
+    events::emit(env, &ChainSettled { chain_id });
+    Ok(())
+}
+
+/// Removes a chain and its tokens' links to it, before they leave escrow.
+fn close(env: &Env, chain_id: u32, chain: &SwapChain) {
+    for leg in chain.legs.iter() {
+        let key = ExtKey::ChainOf(leg.token_id);
+        if env.storage().persistent().get::<_, u32>(&key) == Some(chain_id) {
+            env.storage().persistent().remove(&key);
+        }
+    }
+    env.storage()
+        .persistent()
+        .remove(&ExtKey::SwapChain(chain_id));
+}
+
+fn leg_of(chain: &SwapChain, owner: &Address) -> Result<u32, Error> {
+    chain
+        .legs
+        .iter()
+        .position(|leg| leg.owner == *owner)
+        .map(|index| index as u32)
+        .ok_or(Error::NotOwner)
+}
+
+fn load(env: &Env, chain_id: u32) -> SwapChain {
+    HotelTimeshareContract::swap_chain(env.clone(), chain_id)
+        .unwrap_or_else(|| fail(env, ExtError::NoSwapChain))
+}
+
+fn set_chain(env: &Env, chain_id: u32, chain: &SwapChain) {
+    env.storage()
+        .persistent()
+        .set(&ExtKey::SwapChain(chain_id), chain);
+}
@@ -0,0 +1,296 @@
+// Charity donations out of royalties.
+//
+// Hotels that run a give-back program can pass part of their royalty (see
+// royalties.rs) on to charity. The admin registers the charities that may
+// receive donations; a manager then sets a hotel's charity split, the share of
+// each royalty payment, in basis points, that goes to each of its charities.
+// The contract's resale paths pay the split at settlement, straight from the
+// buyer, and the hotel's payout receives what is left of the royalty.
+//
+// Donations are added up per calendar year and payment token, both per
+// charity (`donated`) and per hotel (`hotel_donated`), so a program's claims
+// can be checked against the ledger. A charity that is removed from the
+// register stops receiving donations, and its share stays with the hotel.
+// Royalties that external marketplaces pay the hotel themselves (see
+// adapter.rs) aren't split.
+
+use soroban_sdk::{contractevent, contractimpl, token, vec, Address, Env, Map, String, Vec};
+
+use crate::{
+    enter, events, fail, hotels, money, or_fail, require_admin, roles, usage, Amount, Bps, Error,
+    ExtError, ExtError2, ExtKey2, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, Role, FULL_BPS,
+};
+
+/// Most charities a hotel's split may name, to bound the cost of each resale.
+pub const MAX_CHARITIES: u32 = 5;
+
+/**
+ * @title CharityRegistered
+ * @dev Published when the admin registers a charity or renames one.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CharityRegistered {
+    #[topic]
+    pub charity: Address,
+    pub name: String,
+}
+
+/**
+ * @title CharityRemoved
+ * @dev Published when the admin removes a charity from the register.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CharityRemoved {
+    #[topic]
+    pub charity: Address,
+}
+
+/**
+ * @title CharitySplitSet
+ * @dev Published when a hotel's charity split changes.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CharitySplitSet {
+    #[topic]
+    pub hotel_id: u32,
+    pub split: Vec<(Address, u32)>,
+}
+
+/**
+ * @title RoyaltyDonated
+ * @dev Published when a resale pays part of a hotel's royalty to a charity.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoyaltyDonated {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub charity: Address,
+    pub hotel_id: u32,
+    pub payment_token: Address,
+    pub amount: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Registers a charity that hotels may donate to, or renames one.
+     * Admin only.
+     */
+    pub fn register_charity(env: Env, charity: Address, name: String) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "register_charity")?;
+
+        env.storage()
+            .persistent()
+            .set(&ExtKey2::Charity(charity.clone()), &name);
+        events::emit(&env, &CharityRegistered { charity, name });
+        Ok(())
+    }
+
+    /**
+     * @dev Removes a charity from the register. Admin only. Splits that name
+     * it are kept, but its share stays with the hotel until it is registered
+     * again.
+     */
+    pub fn remove_charity(env: Env, charity: Address) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "remove_charity")?;
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&ExtKey2::Charity(charity.clone()))
+        {
+            fail(&env, ExtError2::NotCharity);
+        }
+        env.storage()
+            .persistent()
+            .remove(&ExtKey2::Charity(charity.clone()));
+        events::emit(&env, &CharityRemoved { charity });
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the name a charity is registered under, if it is.
+     */
+    pub fn charity(env: Env, charity: Address) -> Option<String> {
+        env.storage().persistent().get(&ExtKey2::Charity(charity))
+    }
+
+    /**
+     * @dev Sets the share of a hotel's royalties donated to each of its
+     * charities. Manager role (or admin). Fails with NotCharity for a charity
+     * that isn't registered, DuplicateCharity for one named twice,
+     * TooManyPayees past MAX_CHARITIES, and InvalidSplit if the shares add up
+     * to more than FULL_BPS.
+     * @param caller The manager (must sign).
+     * @param split Each charity and its share in basis points of the royalty;
+     * empty to donate nothing.
+     */
+    pub fn set_charity_split(
+        env: Env,
+        caller: Address,
+        hotel_id: u32,
+        split: Vec<(Address, u32)>,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "set_charity_split")?;
+
+        hotels::check(&env, hotel_id)?;
+        if split.len() > MAX_CHARITIES {
+            fail(&env, ExtError::TooManyPayees);
+        }
+        let mut total: u32 = 0;
+        for (index, (charity, bps)) in split.iter().enumerate() {
+            if Self::charity(env.clone(), charity.clone()).is_none() {
+                fail(&env, ExtError2::NotCharity);
+            }
+            if split.iter().take(index).any(|(other, _)| other == charity) {
+                fail(&env, ExtError2::DuplicateCharity);
+            }
+            total = total.saturating_add(bps);
+        }
+        if Bps::of(total).is_err() {
+            fail(&env, ExtError::InvalidSplit);
+        }
+        if split.is_empty() {
+            env.storage()
+                .persistent()
+                .remove(&ExtKey2::CharitySplit(hotel_id));
+        } else {
+            env.storage()
+                .persistent()
+                .set(&ExtKey2::CharitySplit(hotel_id), &split);
+        }
+
+        events::emit(&env, &CharitySplitSet { hotel_id, split });
+        Ok(())
+    }
+
+    /**
+     * @dev Returns a hotel's charity split; empty if it donates nothing.
+     */
+    pub fn charity_split(env: Env, hotel_id: u32) -> Vec<(Address, u32)> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::CharitySplit(hotel_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /**
+     * @dev Returns what a charity was donated in a calendar year (UTC) in a
+     * payment token.
+     */
+    pub fn donated(env: Env, charity: Address, year: u32, payment_token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::Donated(charity, year, payment_token))
+            .unwrap_or(0)
+    }
+
+    /**
+     * @dev Returns what a hotel donated out of its royalties in a calendar
+     * year (UTC) in a payment token.
+     */
+    pub fn hotel_donated(env: Env, hotel_id: u32, year: u32, payment_token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::HotelDonated(hotel_id, year, payment_token))
+            .unwrap_or(0)
+    }
+}
+
+/// Pays each registered charity in a hotel's split its share of a resale's
+/// royalty `cut`, from `from`. The cut is divided with money::split in the
+/// property's minor unit, so the shares, and what the hotel keeps, add up to
+/// it exactly.
+/// @return The total donated, which the hotel's payout doesn't receive.
+pub(crate) fn donate(
+    env: &Env,
+    token_id: u64,
+    hotel_id: u32,
+    payment_token: &Address,
+    from: &Address,
+    cut: Amount,
+) -> Amount {
+    // 1. The hotel keeps the shares of charities no longer registered.
+    let mut charities = Vec::new(env);
+    let mut weights = vec![env, FULL_BPS];
+    for (charity, bps) in HotelTimeshareContract::charity_split(env.clone(), hotel_id).iter() {
+        if HotelTimeshareContract::charity(env.clone(), charity.clone()).is_some() {
+            charities.push_back(charity);
+            weights.push_back(bps);
+            weights.set(0, weights.get_unchecked(0) - bps);
+        }
+    }
+    if charities.is_empty() || cut == Amount::ZERO {
+        return Amount::ZERO;
+    }
+    let unit = money::minor_unit(env, hotel_id, payment_token);
+    let parts = or_fail(env, money::split(env, cut, &weights, unit));
+
+    // 2. Each charity is paid its part, and the totals are kept.
+    let payment = token::Client::new(env, payment_token);
+    let year = usage::current_year(env);
+    let mut donated = Amount::ZERO;
+    for (charity, amount) in charities.iter().zip(parts.iter().skip(1)) {
+        if amount <= 0 {
+            continue;
+        }
+        payment.transfer(from, &charity, &amount);
+        add(
+            env,
+            &ExtKey2::Donated(charity.clone(), year, payment_token.clone()),
+            amount,
+        );
+        donated = or_fail(env, donated.checked_add(or_fail(env, Amount::of(amount))));
+
+        events::emit(
+            env,
+            &RoyaltyDonated {
+                token_id,
+                charity,
+                hotel_id,
+                payment_token: payment_token.clone(),
+                amount,
+            },
+        );
+    }
+    if donated > Amount::ZERO {
+        add(
+            env,
+            &ExtKey2::HotelDonated(hotel_id, year, payment_token.clone()),
+            donated.get(),
+        );
+    }
+    donated
+}
+
+/// Returns the charity split of every hotel that set one, by hotel ID.
+pub(crate) fn all_splits(env: &Env) -> Map<u32, Vec<(Address, u32)>> {
+    let mut all = Map::new(env);
+    for hotel_id in 1..=hotels::hotel_count(env) {
+        let split = HotelTimeshareContract::charity_split(env.clone(), hotel_id);
+        if !split.is_empty() {
+            all.set(hotel_id, split);
+        }
+    }
+    all
+}
+
+/// Adds `amount` to the running total under `key`. Fails with AmountOverflow
+/// if the total would overflow.
+fn add(env: &Env, key: &ExtKey2, amount: i128) {
+    let total: i128 = env.storage().persistent().get(key).unwrap_or(0);
+    let total = or_fail(
+        env,
+        Amount::of(total).and_then(|total| total.checked_add(Amount::of(amount)?)),
+    );
+    env.storage().persistent().set(key, &total.get());
+}
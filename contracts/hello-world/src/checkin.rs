@@ -0,0 +1,172 @@
+// QR-code check-in.
+//
+// Rather than sending a check_in transaction, a guest shows the hotel's kiosk a
+// QR code. The guest first registers the ed25519 key their device signs codes
+// with (`set_checkin_key`). A code is a CheckinCode (token, year, nonce,
+// expiry) and the device's signature over the XDR of (this contract's address,
+// code), as with vouchers; the timeshare-client crate builds and encodes them.
+// The kiosk, signing as the front desk (Manager role), submits a scanned code
+// to `verify_checkin_code`, which checks the guest in with the check-in
+// already confirmed (see usage.rs).
+//
+// Each token keeps the highest nonce used so far, and a code must carry a
+// higher one, so a scanned code can't be replayed without storing every nonce.
+// A key only works while whoever registered it is still the token's guest.
+
+use soroban_sdk::{contractimpl, contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+use crate::{
+    enter, events, fail, rental, roles, term, usage, CheckInConfirmed, CheckedIn, Error, ExtError,
+    ExtKey, HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient, Role,
+    UsageState,
+};
+
+/**
+ * @title CheckinCode
+ * @dev What a guest's QR code carries, next to its signature.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckinCode {
+    pub token_id: u64,
+    /// The calendar year of the stay.
+    pub year: u32,
+    /// Must be higher than any nonce used for the token before.
+    pub nonce: u64,
+    /// Ledger timestamp after which the code can't be used.
+    pub expires_at: u64,
+}
+
+/**
+ * @title CheckinKey
+ * @dev The key a guest's device signs a token's check-in codes with.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckinKey {
+    /// Who registered the key.
+    pub guest: Address,
+    pub public_key: BytesN<32>,
+    /// The highest nonce used so far, 0 before the first code.
+    pub nonce: u64,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Registers the ed25519 public key a guest's device signs a token's
+     * check-in codes with, replacing any earlier one. Nonces already used stay
+     * used.
+     * @param guest The owner, or this year's renter (must sign).
+     */
+    pub fn set_checkin_key(
+        env: Env,
+        guest: Address,
+        token_id: u64,
+        public_key: BytesN<32>,
+    ) -> Result<(), Error> {
+        guest.require_auth();
+        enter(&env, "set_checkin_key")?;
+
+        rental::check_guest(&env, token_id, &guest)?;
+        let nonce = Self::checkin_key(env.clone(), token_id).map_or(0, |key| key.nonce);
+        env.storage().persistent().set(
+            &ExtKey::CheckinKey(token_id),
+            &CheckinKey {
+                guest,
+                public_key,
+                nonce,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the key a token's check-in codes are signed with, if one is
+     * registered.
+     */
+    pub fn checkin_key(env: Env, token_id: u64) -> Option<CheckinKey> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::CheckinKey(token_id))
+    }
+
+    /**
+     * @dev Returns the bytes a check-in code's signature must cover, for signers.
+     */
+    pub fn checkin_code_message(env: Env, code: CheckinCode) -> Bytes {
+        (env.current_contract_address(), code).to_xdr(&env)
+    }
+
+    /**
+     * @dev Checks a guest in with a scanned QR code, confirmed at once. Manager
+     * role (or admin). Fails with NoCheckinKey before the guest registered a
+     * key, CheckinCodeExpired for a code past its expiry or for another year,
+     * CheckinCodeUsed unless its nonce is new, and InvalidUsageState unless the
+     * week is unused this year. A bad signature traps.
+     * @param caller The kiosk (must sign).
+     * @param signature The guest device's ed25519 signature of the code.
+     * @return The guest who checked in.
+     */
+    pub fn verify_checkin_code(
+        env: Env,
+        caller: Address,
+        code: CheckinCode,
+        signature: BytesN<64>,
+    ) -> Result<Address, Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "verify_checkin_code")?;
+
+        // 1. Only a fresh code, signed by the key of the token's guest.
+        let token_id = code.token_id;
+        let mut key = Self::checkin_key(env.clone(), token_id)
+            .unwrap_or_else(|| fail(&env, ExtError::NoCheckinKey));
+        let message = Self::checkin_code_message(env.clone(), code.clone());
+        env.crypto()
+            .ed25519_verify(&key.public_key, &message, &signature);
+        if env.ledger().timestamp() > code.expires_at || code.year != usage::current_year(&env) {
+            fail(&env, ExtError::CheckinCodeExpired);
+        }
+        if code.nonce <= key.nonce {
+            fail(&env, ExtError::CheckinCodeUsed);
+        }
+        rental::check_guest(&env, token_id, &key.guest)?;
+        term::check_token(&env, token_id)?;
+
+        // 2. Check in and confirm, as check_in and confirm_check_in would.
+        let mut usage = Self::usage(env.clone(), token_id)?;
+        if usage.state != UsageState::Unused {
+            return Err(Error::InvalidUsageState);
+        }
+        usage.state = UsageState::CheckedIn;
+        usage.confirmed = true;
+        usage::set_usage(&env, token_id, &usage);
+        key.nonce = code.nonce;
+        env.storage()
+            .persistent()
+            .set(&ExtKey::CheckinKey(token_id), &key);
+
+        events::emit(
+            &env,
+            &CheckedIn {
+                token_id,
+                guest: key.guest.clone(),
+            },
+        );
+        events::emit(
+            &env,
+            &CheckInConfirmed {
+                token_id,
+                by: caller,
+            },
+        );
+        Ok(key.guest)
+    }
+}
+
+/// Drops a token's check-in key, when the token is burned.
+pub(crate) fn clear(env: &Env, token_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&ExtKey::CheckinKey(token_id));
+}
@@ -0,0 +1,110 @@
+// Admin clawback.
+//
+// When an owner breaches the resort contract, the admin takes the token back
+// with `clawback`: it is reassigned to `to` (typically the hotel) or, without
+// a recipient, burned. Unlike `force_transfer` it doesn't need the owner's
+// signature, and it works on tokens that are frozen (see freeze.rs) or held in
+// trust, since those are the tokens disputes are about. The freeze and the
+// trust are lifted, and a trade or fractionalization the contract held the
+// token for is closed, dropping its shares. Prepaid maintenance for years that
+// haven't begun is refunded to the owner (see prepay.rs). Only a pause stops
+// it.
+//
+// Each clawback stores a ClawbackRecord with the admin's reason code under the
+// token's ID, which outlives a burn, and publishes a Clawback event next to the
+// usual Transfer or Burn, so the action is auditable on-chain. Reason codes
+// are the hotel's own; the contract doesn't interpret them.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env};
+
+use crate::{
+    burn, enter, events, owner_of, pause, prepay, reassign, require_admin, trust, DataKey, Error,
+    HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/**
+ * @title ClawbackRecord
+ * @dev The last clawback of a token.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClawbackRecord {
+    /// The owner the token was taken from.
+    pub from: Address,
+    /// Who received it, or None if it was burned.
+    pub to: Option<Address>,
+    pub reason_code: u32,
+    /// Ledger sequence of the clawback.
+    pub ledger: u32,
+}
+
+/**
+ * @title Clawback
+ * @dev Published when the admin claws a token back.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Clawback {
+    #[topic]
+    pub token_id: u64,
+    pub from: Address,
+    pub to: Option<Address>,
+    pub reason_code: u32,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Takes a token from its owner. Admin only.
+     * @param to The new owner, or None to burn the token.
+     * @param reason_code Why, in the hotel's own codes; recorded on-chain.
+     */
+    pub fn clawback(
+        env: Env,
+        token_id: u64,
+        to: Option<Address>,
+        reason_code: u32,
+    ) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "clawback")?;
+        pause::check(&env)?;
+
+        let from = owner_of(&env, token_id)?;
+        trust::dissolve(&env, token_id);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Frozen(token_id));
+        prepay::close(&env, token_id, &from);
+        match &to {
+            Some(to) => reassign(&env, &from, to, token_id),
+            None => burn::burn_token(&env, &from, token_id),
+        }
+
+        env.storage().persistent().set(
+            &DataKey::Clawback(token_id),
+            &ClawbackRecord {
+                from: from.clone(),
+                to: to.clone(),
+                reason_code,
+                ledger: env.ledger().sequence(),
+            },
+        );
+        events::emit(
+            &env,
+            &Clawback {
+                token_id,
+                from,
+                to,
+                reason_code,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the record of a token's last clawback, if it was clawed back.
+     */
+    pub fn clawback_record(env: Env, token_id: u64) -> Option<ClawbackRecord> {
+        env.storage().persistent().get(&DataKey::Clawback(token_id))
+    }
+}
@@ -0,0 +1,322 @@
+// Collection offers.
+//
+// Weeks of the same tier at the same property are close to interchangeable,
+// so a buyer often wants "any Platinum week at property 3" rather than one
+// particular token. `make_collection_offer` escrows an amount against
+// Criteria (a property, a tier and a range of weeks, each optional but the
+// weeks), and the owner of any token that meets them can sell it into the
+// offer with `fill_collection_offer`: the owner is paid the escrowed amount,
+// less the hotel's royalty, and the buyer receives the token in the same call.
+//
+// The criteria are checked on-chain against the token's info and tier (see
+// metadata.rs) when the offer is filled. Each offer buys one token. Offers
+// are numbered from 1 and, like offers on single tokens (see offers.rs), only
+// ever pay out to a filling owner or back to the buyer: an expired offer
+// waits for the buyer to cancel it.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, token, Address, Env, Symbol};
+
+use crate::{
+    check_owner, enter, events, fail, freeze, hotels, migration, move_token, pause, receipts,
+    royalties, storage, Error, ExtError, ExtKey, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, ReceiptKind, TimeshareInfo,
+    WEEKS_PER_YEAR,
+};
+
+/**
+ * @title Criteria
+ * @dev What a token must be to fill a collection offer.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Criteria {
+    /// The property, or None for any.
+    pub hotel_id: Option<u32>,
+    /// The tier, or None for any tier or none.
+    pub tier: Option<Symbol>,
+    /// The earliest week accepted, from 1.
+    pub first_week: u32,
+    /// The latest week accepted, up to WEEKS_PER_YEAR.
+    pub last_week: u32,
+}
+
+/**
+ * @title CollectionOffer
+ * @dev A buyer's escrowed offer for any one token meeting its criteria.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectionOffer {
+    pub buyer: Address,
+    pub criteria: Criteria,
+    /// Token the amount is paid in.
+    pub payment_token: Address,
+    pub amount: i128,
+    /// Ledger timestamp after which the offer can't be filled.
+    pub expires_at: u64,
+}
+
+/**
+ * @title CollectionOfferMade
+ * @dev Published when a buyer makes a collection offer.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectionOfferMade {
+    #[topic]
+    pub offer_id: u32,
+    #[topic]
+    pub buyer: Address,
+    pub criteria: Criteria,
+    pub payment_token: Address,
+    pub amount: i128,
+    pub expires_at: u64,
+}
+
+/**
+ * @title CollectionOfferCancelled
+ * @dev Published when a buyer takes back a collection offer.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectionOfferCancelled {
+    #[topic]
+    pub offer_id: u32,
+}
+
+/**
+ * @title CollectionOfferFilled
+ * @dev Published when an owner sells a token into a collection offer, next
+ * to its Transfer event.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectionOfferFilled {
+    #[topic]
+    pub offer_id: u32,
+    #[topic]
+    pub token_id: u64,
+    pub buyer: Address,
+    pub seller: Address,
+    pub amount: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Offers to buy any one token meeting `criteria`, moving the amount
+     * into the contract. Fails with InvalidWeek unless the weeks are a range
+     * within 1..=WEEKS_PER_YEAR, and OfferExpired if the offer would already
+     * have expired.
+     * @param buyer The buyer (must sign and pay).
+     * @param amount The offer; must be positive.
+     * @param expires_at Ledger timestamp until which the offer can be filled.
+     * @return The offer's ID.
+     */
+    pub fn make_collection_offer(
+        env: Env,
+        buyer: Address,
+        criteria: Criteria,
+        payment_token: Address,
+        amount: i128,
+        expires_at: u64,
+    ) -> Result<u32, Error> {
+        buyer.require_auth();
+        enter(&env, "make_collection_offer")?;
+        pause::check(&env)?;
+
+        check_criteria(&env, &criteria)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if expires_at < env.ledger().timestamp() {
+            fail(&env, ExtError::OfferExpired);
+        }
+        token::Client::new(&env, &payment_token).transfer(
+            &buyer,
+            env.current_contract_address(),
+            &amount,
+        );
+        let offer_id = Self::collection_offer_count(env.clone()) + 1;
+        storage::set_shared(&env, &ExtKey::CollectionOfferCount, &offer_id);
+        env.storage().persistent().set(
+            &ExtKey::CollectionOffer(offer_id),
+            &CollectionOffer {
+                buyer: buyer.clone(),
+                criteria: criteria.clone(),
+                payment_token: payment_token.clone(),
+                amount,
+                expires_at,
+            },
+        );
+
+        events::emit(
+            &env,
+            &CollectionOfferMade {
+                offer_id,
+                buyer,
+                criteria,
+                payment_token,
+                amount,
+                expires_at,
+            },
+        );
+        Ok(offer_id)
+    }
+
+    /**
+     * @dev Takes back a collection offer and its escrowed amount, expired or
+     * not. Fails with NotOwner for someone else's offer. Works while paused,
+     * so a pause never locks a buyer's funds.
+     * @param buyer The buyer who made it (must sign).
+     */
+    pub fn cancel_collection_offer(env: Env, buyer: Address, offer_id: u32) -> Result<(), Error> {
+        buyer.require_auth();
+        enter(&env, "cancel_collection_offer")?;
+
+        let offer = load(&env, offer_id);
+        if offer.buyer != buyer {
+            return Err(Error::NotOwner);
+        }
+        remove(&env, offer_id);
+        token::Client::new(&env, &offer.payment_token).transfer(
+            &env.current_contract_address(),
+            &buyer,
+            &offer.amount,
+        );
+
+        events::emit(&env, &CollectionOfferCancelled { offer_id });
+        Ok(())
+    }
+
+    /**
+     * @dev Sells a token into a collection offer: the owner is paid the
+     * escrowed amount and the buyer receives the token. Fails with
+     * NoCollectionOffer for an unknown offer, OfferExpired once it expired,
+     * and CriteriaMismatch unless the token meets the offer's criteria.
+     * @param owner The token's current owner (must sign).
+     */
+    pub fn fill_collection_offer(
+        env: Env,
+        owner: Address,
+        offer_id: u32,
+        token_id: u64,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "fill_collection_offer")?;
+        pause::check(&env)?;
+
+        check_owner(&env, token_id, &owner)?;
+        freeze::check(&env, token_id);
+        let offer = load(&env, offer_id);
+        if env.ledger().timestamp() > offer.expires_at {
+            fail(&env, ExtError::OfferExpired);
+        }
+        if !matches(&env, &offer.criteria, token_id) {
+            fail(&env, ExtError::CriteriaMismatch);
+        }
+        remove(&env, offer_id);
+        royalties::pay(
+            &env,
+            token_id,
+            &offer.payment_token,
+            &env.current_contract_address(),
+            &owner,
+            offer.amount,
+        )?;
+        move_token(&env, &owner, &offer.buyer, token_id)?;
+        receipts::record_sale(
+            &env,
+            ReceiptKind::Resale,
+            token_id,
+            &offer.buyer,
+            &owner,
+            offer.amount,
+            &offer.payment_token,
+        );
+
+        events::emit(
+            &env,
+            &CollectionOfferFilled {
+                offer_id,
+                token_id,
+                buyer: offer.buyer,
+                seller: owner,
+                amount: offer.amount,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns a collection offer, unless it was filled or cancelled.
+     */
+    pub fn collection_offer(env: Env, offer_id: u32) -> Option<CollectionOffer> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::CollectionOffer(offer_id))
+    }
+
+    /**
+     * @dev Returns how many collection offers have been made, i.e. the
+     * highest offer ID.
+     */
+    pub fn collection_offer_count(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::CollectionOfferCount)
+            .unwrap_or(0)
+    }
+}
+
+/// Whether a token meets `criteria`.
+pub(crate) fn matches(env: &Env, criteria: &Criteria, token_id: u64) -> bool {
+    let Some(info) = migration::read_info(env, token_id) else {
+        return false;
+    };
+    meets(
+        criteria,
+        &info,
+        &HotelTimeshareContract::tier(env.clone(), token_id),
+    )
+}
+
+/// Whether a token of this info and tier meets `criteria`.
+pub(crate) fn meets(criteria: &Criteria, info: &TimeshareInfo, tier: &Option<Symbol>) -> bool {
+    if criteria
+        .hotel_id
+        .is_some_and(|hotel_id| hotel_id != info.hotel_id)
+    {
+        return false;
+    }
+    if criteria.tier.is_some() && *tier != criteria.tier {
+        return false;
+    }
+    (criteria.first_week..=criteria.last_week).contains(&info.week)
+}
+
+/// Fails with InvalidWeek unless the weeks are a range within the year, and
+/// HotelNotFound for an unknown property.
+pub(crate) fn check_criteria(env: &Env, criteria: &Criteria) -> Result<(), Error> {
+    if criteria.first_week == 0
+        || criteria.first_week > criteria.last_week
+        || criteria.last_week > WEEKS_PER_YEAR
+    {
+        return Err(Error::InvalidWeek);
+    }
+    if let Some(hotel_id) = criteria.hotel_id {
+        hotels::check(env, hotel_id)?;
+    }
+    Ok(())
+}
+
+fn load(env: &Env, offer_id: u32) -> CollectionOffer {
+    HotelTimeshareContract::collection_offer(env.clone(), offer_id)
+        .unwrap_or_else(|| fail(env, ExtError::NoCollectionOffer))
+}
+
+fn remove(env: &Env, offer_id: u32) {
+    env.storage()
+        .persistent()
+        .remove(&ExtKey::CollectionOffer(offer_id));
+}
@@ -0,0 +1,151 @@
+// Effective configuration of a deployment, in one read.
+//
+// Integrators otherwise have to know every storage key and compiled-in constant
+// to introspect a deployment. New global settings should be added to
+// `ContractConfig` as they are introduced.
+
+use soroban_sdk::{contractimpl, contracttype, Address, BytesN, Env, Map, String, Symbol, Vec};
+
+use crate::{
+    buyback, charity, grace, Branding, Buyback, CoolingOff, Deprecation, Error, FeeIndexing,
+    GraceRule, HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+    LateFees, MaintenanceBilling, RentPool, StatsPrivacy, WithholdingRule, ADMIN,
+    BENEFICIARY_TIMELOCK, TOKEN_TTL_EXTEND_TO, TOKEN_TTL_THRESHOLD,
+};
+
+/**
+ * @title ContractConfig
+ * @dev Snapshot of everything that shapes the contract's behavior.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractConfig {
+    /// Crate version of the running build.
+    pub version: String,
+    /// The hotel operator.
+    pub admin: Address,
+    /// Proposed next admin, while a handover is pending.
+    pub pending_admin: Option<Address>,
+    /// Whether mints, transfers and payments are paused.
+    pub paused: bool,
+    /// Layout version of the stored data (see migration.rs).
+    pub schema_version: u32,
+    /// Highest token ID reserved so far; IDs above it are unused (see ids.rs).
+    pub last_token_id: u64,
+    /// Seconds before a trust beneficiary change applies.
+    pub beneficiary_timelock: u64,
+    /// Seconds a recipient has to accept a two-phase transfer.
+    pub pending_transfer_window: u64,
+    /// Deprecated endpoints and their sunsets.
+    pub deprecations: Map<Symbol, Deprecation>,
+    /// TTL, in ledgers, under which mints, transfers and reads bump a token.
+    pub token_ttl_threshold: u32,
+    /// TTL, in ledgers, those automatic bumps extend a token to.
+    pub token_ttl_extend_to: u32,
+    /// The rent pool's policy, once configured.
+    pub rent_pool: RentPool,
+    /// The annual maintenance fee schedule, once set.
+    pub maintenance: MaintenanceBilling,
+    /// The ed25519 key sale vouchers are signed with, once registered.
+    pub voucher_signer: Option<BytesN<32>>,
+    /// The revenue split: each payee and its share in basis points; empty
+    /// while proceeds go to the admin.
+    pub payees: Vec<(Address, u32)>,
+    /// The cooling-off terms of new primary sales; a period of 0 while off.
+    pub cooling_off: CoolingOff,
+    /// Supply caps, by hotel ID.
+    pub supply_caps: Map<u32, u32>,
+    /// Shares of maintenance fees held in reserve, in basis points by hotel ID.
+    pub reserve_shares: Map<u32, u32>,
+    /// Whether sales and trades are recorded as receipts.
+    pub receipts: bool,
+    /// Tax withholding rules of resales, by hotel ID.
+    pub withholdings: Map<u32, WithholdingRule>,
+    /// Minor units of payment assets, by hotel ID.
+    pub minor_units: Map<u32, Map<Address, i128>>,
+    /// Display settings of properties, by hotel ID.
+    pub brandings: Map<u32, Branding>,
+    /// Locales with localized labels (see `labels`).
+    pub locales: Vec<Symbol>,
+    /// Maintenance prepayment discounts in basis points, entry n - 1 for
+    /// prepaying n years.
+    pub prepay_discounts: Vec<u32>,
+    /// The late fee policy of overdue maintenance, once set.
+    pub late_fees: LateFees,
+    /// Grace periods in force, in seconds by rule (see `policy`).
+    pub grace_periods: Map<GraceRule, u64>,
+    /// Open buybacks, in the order they were opened.
+    pub buybacks: Vec<Buyback>,
+    /// Properties whose sales the hotel has a right of first refusal on.
+    pub first_refusal_hotels: Vec<u32>,
+    /// Charity splits of royalties, by hotel ID (see `charity_split`).
+    pub charity_splits: Map<u32, Vec<(Address, u32)>>,
+    /// How the maintenance fee follows an index, once linked.
+    pub fee_index: FeeIndexing,
+    /// How exact the public stats endpoints are (see `stats_privacy`).
+    pub stats_privacy: StatsPrivacy,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Returns the full effective configuration of this deployment.
+     * @return The ContractConfig struct, or NotInitialized before initialize().
+     */
+    pub fn config(env: Env) -> Result<ContractConfig, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&ADMIN)
+            .ok_or(Error::NotInitialized)?;
+
+        Ok(ContractConfig {
+            version: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            admin,
+            pending_admin: Self::pending_admin(env.clone()),
+            paused: Self::paused(env.clone()),
+            schema_version: Self::schema_version(env.clone()),
+            last_token_id: crate::ids::reserved(&env),
+            beneficiary_timelock: BENEFICIARY_TIMELOCK,
+            pending_transfer_window: grace::period(&env, GraceRule::PendingTransfer),
+            deprecations: Self::deprecations(env.clone()),
+            token_ttl_threshold: TOKEN_TTL_THRESHOLD,
+            token_ttl_extend_to: TOKEN_TTL_EXTEND_TO,
+            rent_pool: match Self::rent_policy(env.clone()) {
+                Some(policy) => RentPool::Enabled(policy),
+                None => RentPool::Disabled,
+            },
+            maintenance: match Self::maintenance_schedule(env.clone()) {
+                Some(schedule) => MaintenanceBilling::Enabled(schedule),
+                None => MaintenanceBilling::Disabled,
+            },
+            voucher_signer: Self::voucher_signer(env.clone()),
+            payees: Self::payees(env.clone()),
+            cooling_off: Self::cooling_off(env.clone()).unwrap_or(CoolingOff {
+                period: 0,
+                refund_bps: 0,
+            }),
+            supply_caps: Self::supply_caps(env.clone()),
+            reserve_shares: Self::reserve_shares(env.clone()),
+            receipts: Self::receipts_enabled(env.clone()),
+            withholdings: Self::withholdings(env.clone()),
+            minor_units: crate::money::all_minor_units(&env),
+            brandings: Self::brandings(env.clone()),
+            locales: Self::locales(env.clone()),
+            prepay_discounts: Self::prepay_discounts(env.clone()),
+            late_fees: match Self::late_fee_policy(env.clone()) {
+                Some(policy) => LateFees::Enabled(policy),
+                None => LateFees::Disabled,
+            },
+            grace_periods: Self::policy(env.clone()),
+            buybacks: buyback::all(&env),
+            first_refusal_hotels: Self::first_refusal_hotels(env.clone()),
+            charity_splits: charity::all_splits(&env),
+            fee_index: match Self::fee_index(env.clone()) {
+                Some(index) => FeeIndexing::Indexed(index),
+                None => FeeIndexing::Manual,
+            },
+            stats_privacy: Self::stats_privacy(env.clone()),
+        })
+    }
+}
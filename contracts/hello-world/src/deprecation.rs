@@ -0,0 +1,115 @@
+// Endpoint deprecation and sunset.
+//
+// The admin can mark an endpoint deprecated with a sunset ledger and an
+// optional replacement. Until the sunset, every call to it still succeeds but
+// publishes an `EndpointDeprecated` event, so integrators get machine-readable
+// notice in their transaction results. From the sunset ledger on, the endpoint
+// fails with `Error::EndpointSunset` instead of silently changing behavior.
+//
+// The check runs in `enter`, i.e. for state-changing endpoints. Deprecated read
+// endpoints are still listed by `deprecations()` for integrators to consult.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Env, Map, Symbol};
+
+use crate::{
+    enter, events, require_admin, DataKey, Error, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/**
+ * @title Deprecation
+ * @dev Deprecation notice for one endpoint.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Deprecation {
+    /// First ledger in which calls fail with EndpointSunset.
+    pub sunset_ledger: u32,
+    /// Endpoint to migrate to, if any.
+    pub replacement: Option<Symbol>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EndpointDeprecated {
+    #[topic]
+    pub endpoint: Symbol,
+    pub sunset_ledger: u32,
+    pub replacement: Option<Symbol>,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Marks an endpoint deprecated (or updates its notice). Admin only.
+     * @param endpoint The endpoint's name, e.g. "transfer_to_alias".
+     * @param sunset_ledger The ledger from which the endpoint stops working.
+     * @param replacement The endpoint integrators should migrate to, if any.
+     */
+    pub fn deprecate_endpoint(
+        env: Env,
+        endpoint: Symbol,
+        sunset_ledger: u32,
+        replacement: Option<Symbol>,
+    ) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "deprecate_endpoint")?;
+
+        let mut all = Self::deprecations(env.clone());
+        all.set(
+            endpoint,
+            Deprecation {
+                sunset_ledger,
+                replacement,
+            },
+        );
+        env.storage().persistent().set(&DataKey::Deprecations, &all);
+        Ok(())
+    }
+
+    /**
+     * @dev Withdraws a deprecation notice. Admin only.
+     */
+    pub fn undeprecate_endpoint(env: Env, endpoint: Symbol) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "undeprecate_endpoint")?;
+
+        let mut all = Self::deprecations(env.clone());
+        all.remove(endpoint);
+        env.storage().persistent().set(&DataKey::Deprecations, &all);
+        Ok(())
+    }
+
+    /**
+     * @dev Lists every deprecated endpoint with its notice.
+     */
+    pub fn deprecations(env: Env) -> Map<Symbol, Deprecation> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Deprecations)
+            .unwrap_or_else(|| Map::new(&env))
+    }
+}
+
+/// Fails once `endpoint` is past its sunset; warns with an event before that.
+pub(crate) fn check(env: &Env, endpoint: &Symbol) -> Result<(), Error> {
+    let all: Option<Map<Symbol, Deprecation>> =
+        env.storage().persistent().get(&DataKey::Deprecations);
+    let Some(notice) = all.and_then(|all| all.get(endpoint.clone())) else {
+        return Ok(());
+    };
+
+    if env.ledger().sequence() >= notice.sunset_ledger {
+        return Err(Error::EndpointSunset);
+    }
+
+    events::emit(
+        env,
+        &EndpointDeprecated {
+            endpoint: endpoint.clone(),
+            sunset_ledger: notice.sunset_ledger,
+            replacement: notice.replacement,
+        },
+    );
+    Ok(())
+}
@@ -0,0 +1,209 @@
+// Floor prices and market depth.
+//
+// Appraisers, lenders and collection buyers want a cheap price signal for a
+// kind of week rather than for one token. Every fixed-price listing (see
+// market.rs) counts in a book for its property, its tier (None for tokens
+// without one) and the asset it is priced in, kept up to date as listings
+// are made, repriced or withdrawn, as tokens change owner, and as a listed
+// token's info or tier changes. `market_depth` returns a book's listing count
+// and floor, and `floor_price` just the floor. As in valuation.rs, prices in
+// different assets are never compared: each asset has its own book.
+//
+// A book counts all of its listings but tracks the prices of only the
+// MAX_DEPTH_TRACKED cheapest, so it stays one small entry. A new listing
+// dearer than every tracked one is counted without being tracked, unless the
+// book tracks all of its listings. Should every tracked listing go while
+// untracked ones remain, the floor is unknown and reads as None until those
+// are gone too.
+//
+// Listing counts are public stats: `market_depth` reports them as exact as
+// the stats privacy allows (see privacy.rs), and a book whose count reads as
+// 0 shows no floor or listings either.
+
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Symbol, Vec};
+
+use crate::{
+    migration, privacy, ExtKey2, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, Listing, TimeshareInfo,
+};
+
+/// Most listings a book tracks the prices of.
+pub const MAX_DEPTH_TRACKED: u32 = 100;
+
+/**
+ * @title MarketDepth
+ * @dev The listings of a property's tier in one asset.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketDepth {
+    /// How many tokens are listed.
+    pub listings: u32,
+    /// The lowest listed price; None without listings, or while unknown.
+    pub floor: Option<i128>,
+    /// The tracked listings' token IDs, cheapest first, ties by token ID.
+    pub cheapest: Vec<u64>,
+}
+
+/**
+ * @title DepthBook
+ * @dev A book as stored: its listing count and the cheapest listings, as
+ * (price, token ID) in ascending order.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepthBook {
+    pub listings: u32,
+    pub cheapest: Vec<(i128, u64)>,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Returns how many tokens of a property's tier are listed in an
+     * asset, as exact as the stats privacy allows, and the cheapest of them.
+     * @param tier The tier, or None for tokens without one.
+     */
+    pub fn market_depth(
+        env: Env,
+        hotel_id: u32,
+        tier: Option<Symbol>,
+        asset: Address,
+    ) -> MarketDepth {
+        let book = load(&env, &ExtKey2::DepthBook(hotel_id, tier, asset));
+        let listings = privacy::blur(&env, book.listings);
+        let mut cheapest = Vec::new(&env);
+        if listings == 0 {
+            return MarketDepth {
+                listings,
+                floor: None,
+                cheapest,
+            };
+        }
+        for (_, token_id) in book.cheapest.iter() {
+            cheapest.push_back(token_id);
+        }
+        MarketDepth {
+            listings,
+            floor: book.cheapest.first().map(|(price, _)| price),
+            cheapest,
+        }
+    }
+
+    /**
+     * @dev Returns the lowest price a token of a property's tier is listed at
+     * in an asset; None without listings, while the floor is unknown, or
+     * while the stats privacy hides the book.
+     * @param tier The tier, or None for tokens without one.
+     */
+    pub fn floor_price(
+        env: Env,
+        hotel_id: u32,
+        tier: Option<Symbol>,
+        asset: Address,
+    ) -> Option<i128> {
+        let book = load(&env, &ExtKey2::DepthBook(hotel_id, tier, asset));
+        if privacy::blur(&env, book.listings) == 0 {
+            return None;
+        }
+        book.cheapest.first().map(|(price, _)| price)
+    }
+}
+
+/// Counts a new listing in its token's book.
+pub(crate) fn add(env: &Env, token_id: u64, listing: &Listing) {
+    if let Some(key) = key_of(env, token_id, listing) {
+        insert(env, &key, token_id, listing.price);
+    }
+}
+
+/// Takes a listing that is withdrawn, replaced or sold out of its book.
+pub(crate) fn remove(env: &Env, token_id: u64, listing: &Listing) {
+    if let Some(key) = key_of(env, token_id, listing) {
+        take(env, &key, token_id);
+    }
+}
+
+/// Moves a listing between books when its token's info or tier changes.
+pub(crate) fn reindex(
+    env: &Env,
+    token_id: u64,
+    (old_info, old_tier): (&TimeshareInfo, &Option<Symbol>),
+    (new_info, new_tier): (&TimeshareInfo, &Option<Symbol>),
+) {
+    let Some(listing) = HotelTimeshareContract::listing(env.clone(), token_id) else {
+        return;
+    };
+    if old_info.hotel_id == new_info.hotel_id && old_tier == new_tier {
+        return;
+    }
+    let asset = listing.payment_token;
+    take(
+        env,
+        &ExtKey2::DepthBook(old_info.hotel_id, old_tier.clone(), asset.clone()),
+        token_id,
+    );
+    insert(
+        env,
+        &ExtKey2::DepthBook(new_info.hotel_id, new_tier.clone(), asset),
+        token_id,
+        listing.price,
+    );
+}
+
+fn key_of(env: &Env, token_id: u64, listing: &Listing) -> Option<ExtKey2> {
+    let info = migration::read_info(env, token_id)?;
+    Some(ExtKey2::DepthBook(
+        info.hotel_id,
+        HotelTimeshareContract::tier(env.clone(), token_id),
+        listing.payment_token.clone(),
+    ))
+}
+
+fn insert(env: &Env, key: &ExtKey2, token_id: u64, price: i128) {
+    let mut book = load(env, key);
+    let all_tracked = book.cheapest.len() == book.listings;
+    book.listings += 1;
+    let at = book
+        .cheapest
+        .iter()
+        .position(|entry| entry > (price, token_id))
+        .map_or(book.cheapest.len(), |at| at as u32);
+    if at < book.cheapest.len() || all_tracked {
+        book.cheapest.insert(at, (price, token_id));
+        if book.cheapest.len() > MAX_DEPTH_TRACKED {
+            book.cheapest.pop_back();
+        }
+    }
+    store(env, key, &book);
+}
+
+fn take(env: &Env, key: &ExtKey2, token_id: u64) {
+    let mut book = load(env, key);
+    if book.listings == 0 {
+        return;
+    }
+    book.listings -= 1;
+    if let Some(at) = book.cheapest.iter().position(|(_, id)| id == token_id) {
+        book.cheapest.remove(at as u32);
+    }
+    store(env, key, &book);
+}
+
+fn load(env: &Env, key: &ExtKey2) -> DepthBook {
+    env.storage()
+        .persistent()
+        .get(key)
+        .unwrap_or_else(|| DepthBook {
+            listings: 0,
+            cheapest: Vec::new(env),
+        })
+}
+
+fn store(env: &Env, key: &ExtKey2, book: &DepthBook) {
+    if book.listings == 0 {
+        env.storage().persistent().remove(key);
+    } else {
+        env.storage().persistent().set(key, book);
+    }
+}
@@ -0,0 +1,201 @@
+// Owner directory.
+//
+// HOA-style mailing lists for a property should only ever include owners who
+// asked to be on them. An owner opts in with `join_directory`, publishing a
+// salted hash of their contact details (the salt and the details stay off-
+// chain, with whoever builds the list) and a privacy flag; the entry is listed
+// under the property of a token they own. `directory` pages through a
+// property's entries so the list can be built off-chain.
+//
+// Opting out with `leave_directory` deletes the entry rather than flagging it,
+// so nothing of an owner who left remains to be read. An owner who no longer
+// holds any token of the property can be pruned by anyone with
+// `prune_directory`.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, BytesN, Env, Vec};
+
+use crate::{
+    check_owner, enter, events, migration, Error, ExtKey, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, MAX_PAGE,
+};
+
+/**
+ * @title DirectoryEntry
+ * @dev An owner listed in a property's directory.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DirectoryEntry {
+    pub owner: Address,
+    /// Hash of the owner's contact details and a salt, both kept off-chain.
+    pub contact_hash: BytesN<32>,
+    /// Asks list builders to use the contact for property notices only and
+    /// never share it onwards.
+    pub private: bool,
+}
+
+/**
+ * @title DirectoryChanged
+ * @dev Published when an owner joins, updates or leaves a property's directory.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DirectoryChanged {
+    #[topic]
+    pub hotel_id: u32,
+    #[topic]
+    pub owner: Address,
+    pub listed: bool,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Lists an owner in the directory of a token's property, or updates
+     * their entry there.
+     * @param owner The token's owner (must sign).
+     * @param token_id Any token of the property the owner holds.
+     * @return The property's hotel ID.
+     */
+    pub fn join_directory(
+        env: Env,
+        owner: Address,
+        token_id: u64,
+        contact_hash: BytesN<32>,
+        private: bool,
+    ) -> Result<u32, Error> {
+        owner.require_auth();
+        enter(&env, "join_directory")?;
+
+        check_owner(&env, token_id, &owner)?;
+        let hotel_id = migration::read_info(&env, token_id)
+            .ok_or(Error::TokenNotFound)?
+            .hotel_id;
+        let key = ExtKey::DirectoryEntry(hotel_id, owner.clone());
+        if !env.storage().persistent().has(&key) {
+            let mut owners = owners(&env, hotel_id);
+            owners.push_back(owner.clone());
+            env.storage()
+                .persistent()
+                .set(&ExtKey::Directory(hotel_id), &owners);
+        }
+        env.storage().persistent().set(
+            &key,
+            &DirectoryEntry {
+                owner: owner.clone(),
+                contact_hash,
+                private,
+            },
+        );
+
+        events::emit(
+            &env,
+            &DirectoryChanged {
+                hotel_id,
+                owner,
+                listed: true,
+            },
+        );
+        Ok(hotel_id)
+    }
+
+    /**
+     * @dev Removes an owner from a property's directory. Leaving a directory
+     * one isn't in does nothing.
+     * @param owner The listed owner (must sign).
+     */
+    pub fn leave_directory(env: Env, owner: Address, hotel_id: u32) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "leave_directory")?;
+
+        remove(&env, hotel_id, &owner);
+        Ok(())
+    }
+
+    /**
+     * @dev Removes an owner who holds no token of the property any more from
+     * its directory. Anyone may call it.
+     * @return Whether the owner was removed.
+     */
+    pub fn prune_directory(env: Env, hotel_id: u32, owner: Address) -> Result<bool, Error> {
+        enter(&env, "prune_directory")?;
+
+        let holds = Self::tokens_of_owner(env.clone(), owner.clone())
+            .iter()
+            .any(|token_id| {
+                migration::read_info(&env, token_id).is_some_and(|info| info.hotel_id == hotel_id)
+            });
+        if holds {
+            return Ok(false);
+        }
+        Ok(remove(&env, hotel_id, &owner))
+    }
+
+    /**
+     * @dev Returns an owner's entry in a property's directory, if listed.
+     */
+    pub fn directory_entry(env: Env, hotel_id: u32, owner: Address) -> Option<DirectoryEntry> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::DirectoryEntry(hotel_id, owner))
+    }
+
+    /**
+     * @dev Lists a property's directory entries, in the order owners joined.
+     * @param start The index of the first entry, 0 for the first page.
+     * @param limit The page size, capped at MAX_PAGE.
+     */
+    pub fn directory(env: Env, hotel_id: u32, start: u32, limit: u32) -> Vec<DirectoryEntry> {
+        let owners = owners(&env, hotel_id);
+        let end = owners.len().min(start.saturating_add(limit.min(MAX_PAGE)));
+
+        let mut entries = Vec::new(&env);
+        for index in start..end {
+            let owner = owners.get_unchecked(index);
+            if let Some(entry) = Self::directory_entry(env.clone(), hotel_id, owner) {
+                entries.push_back(entry);
+            }
+        }
+        entries
+    }
+}
+
+/// The owners listed in a property's directory.
+fn owners(env: &Env, hotel_id: u32) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&ExtKey::Directory(hotel_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Deletes an owner's entry. Returns whether there was one.
+fn remove(env: &Env, hotel_id: u32, owner: &Address) -> bool {
+    let key = ExtKey::DirectoryEntry(hotel_id, owner.clone());
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage().persistent().remove(&key);
+    let mut owners = owners(env, hotel_id);
+    if let Some(index) = owners.first_index_of(owner) {
+        owners.remove(index);
+    }
+    if owners.is_empty() {
+        env.storage()
+            .persistent()
+            .remove(&ExtKey::Directory(hotel_id));
+    } else {
+        env.storage()
+            .persistent()
+            .set(&ExtKey::Directory(hotel_id), &owners);
+    }
+
+    events::emit(
+        env,
+        &DirectoryChanged {
+            hotel_id,
+            owner: owner.clone(),
+            listed: false,
+        },
+    );
+    true
+}
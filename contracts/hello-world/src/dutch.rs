@@ -0,0 +1,174 @@
+// Dutch auctions.
+//
+// For new inventory the hotel sells at a declining price instead of guessing
+// one: the admin offers a token it holds with `start_dutch_auction`, and its
+// price falls from the start price by a fixed decay every ledger until it
+// reaches the floor:
+//
+//     price = max(floor_price, start_price - decay_per_ledger * (ledger - start_ledger))
+//
+// The first buyer to accept the current price with `buy_dutch` pays it and
+// receives the token in the same call, published as a Sale like marketplace
+// purchases (see market.rs). As with listings, the auction lapses if the token
+// changes owner any other way.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env};
+
+use crate::{
+    check_owner, enter, events, freeze, move_token, pause, refunds, require_admin, DataKey, Error,
+    HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient, Sale,
+};
+
+/**
+ * @title DutchAuction
+ * @dev A token offered at a declining price.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DutchAuction {
+    /// The admin who offered the token, and is paid for it.
+    pub seller: Address,
+    /// Token the price is paid in.
+    pub payment_token: Address,
+    pub start_price: i128,
+    /// The price never falls below this.
+    pub floor_price: i128,
+    /// How much the price falls each ledger.
+    pub decay_per_ledger: i128,
+    /// Ledger sequence the price starts falling from.
+    pub start_ledger: u32,
+}
+
+/**
+ * @title DutchAuctionStarted
+ * @dev Published when the admin offers a token at a declining price.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DutchAuctionStarted {
+    #[topic]
+    pub token_id: u64,
+    pub payment_token: Address,
+    pub start_price: i128,
+    pub floor_price: i128,
+    pub decay_per_ledger: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Offers a token the admin holds at a price declining from now.
+     * Admin only. Replaces an earlier Dutch auction of the token.
+     * @param floor_price Must not be negative, nor above start_price.
+     * @param decay_per_ledger Must not be negative.
+     */
+    pub fn start_dutch_auction(
+        env: Env,
+        token_id: u64,
+        payment_token: Address,
+        start_price: i128,
+        floor_price: i128,
+        decay_per_ledger: i128,
+    ) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+        enter(&env, "start_dutch_auction")?;
+
+        check_owner(&env, token_id, &admin)?;
+        freeze::check(&env, token_id);
+        if floor_price < 0 || floor_price > start_price || decay_per_ledger < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage().persistent().set(
+            &DataKey::DutchAuction(token_id),
+            &DutchAuction {
+                seller: admin,
+                payment_token: payment_token.clone(),
+                start_price,
+                floor_price,
+                decay_per_ledger,
+                start_ledger: env.ledger().sequence(),
+            },
+        );
+
+        events::emit(
+            &env,
+            &DutchAuctionStarted {
+                token_id,
+                payment_token,
+                start_price,
+                floor_price,
+                decay_per_ledger,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Buys a token at its current Dutch auction price.
+     * @param buyer The buyer (must sign and pay).
+     * @return The price paid.
+     */
+    pub fn buy_dutch(env: Env, buyer: Address, token_id: u64) -> Result<i128, Error> {
+        buyer.require_auth();
+        enter(&env, "buy_dutch")?;
+        pause::check(&env)?;
+
+        let auction = Self::dutch_auction(env.clone(), token_id).ok_or(Error::NotListed)?;
+        freeze::check(&env, token_id);
+        let price = current_price(&env, &auction);
+        move_token(&env, &auction.seller, &buyer, token_id)?;
+        refunds::collect(
+            &env,
+            token_id,
+            &auction.payment_token,
+            &buyer,
+            &auction.seller,
+            price,
+        );
+
+        events::emit(
+            &env,
+            &Sale {
+                token_id,
+                buyer,
+                seller: auction.seller,
+                price,
+                payment_token: auction.payment_token,
+            },
+        );
+        Ok(price)
+    }
+
+    /**
+     * @dev Returns a token's Dutch auction, if it is offered in one.
+     */
+    pub fn dutch_auction(env: Env, token_id: u64) -> Option<DutchAuction> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DutchAuction(token_id))
+    }
+
+    /**
+     * @dev Returns what buy_dutch would charge for a token in this ledger.
+     */
+    pub fn dutch_price(env: Env, token_id: u64) -> Result<i128, Error> {
+        let auction = Self::dutch_auction(env.clone(), token_id).ok_or(Error::NotListed)?;
+        Ok(current_price(&env, &auction))
+    }
+}
+
+/// Removes a token's Dutch auction when the token changes owner.
+pub(crate) fn clear(env: &Env, token_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DutchAuction(token_id));
+}
+
+fn current_price(env: &Env, auction: &DutchAuction) -> i128 {
+    let elapsed = env.ledger().sequence().saturating_sub(auction.start_ledger);
+    let decay = auction.decay_per_ledger.saturating_mul(elapsed as i128);
+    auction
+        .start_price
+        .saturating_sub(decay)
+        .max(auction.floor_price)
+}
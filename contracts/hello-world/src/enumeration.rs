@@ -0,0 +1,104 @@
+// Owner and supply enumeration.
+//
+// A per-owner index of token IDs, kept in step with `Owner(token_id)` by mint
+// and `move_token`, so wallets can list a user's timeshares without scanning
+// every token ID.
+//
+// Explorers list all tokens with `total_supply()` and the paginated `tokens()`.
+// Token IDs have gaps (burned tokens, unused IDs in reserved blocks, see
+// ids.rs), so `tokens()` walks IDs up to the highest reserved one, skips IDs
+// without an owner and reports where the next page starts.
+
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Vec};
+
+use crate::{
+    ids, migration, DataKey, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient,
+};
+
+/// Most token IDs `tokens()` examines per call, to bound its cost.
+pub const MAX_PAGE: u32 = 100;
+
+/**
+ * @title TokenPage
+ * @dev One page of existing token IDs, in ascending order.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenPage {
+    pub token_ids: Vec<u64>,
+    /// Pass as `start_after` to get the next page; None once all IDs are covered.
+    pub next_start_after: Option<u64>,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Returns how many tokens `owner` currently holds.
+     */
+    pub fn balance_of(env: Env, owner: Address) -> u32 {
+        Self::tokens_of_owner(env, owner).len()
+    }
+
+    /**
+     * @dev Lists the IDs of the tokens `owner` currently holds.
+     * @return Token IDs in the order the owner received them.
+     */
+    pub fn tokens_of_owner(env: Env, owner: Address) -> Vec<u64> {
+        migration::read(&env, &DataKey::OwnedTokens(owner)).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /**
+     * @dev Returns the number of tokens currently in existence.
+     */
+    pub fn total_supply(env: Env) -> u64 {
+        ids::supply(&env)
+    }
+
+    /**
+     * @dev Lists existing token IDs above `start_after`, skipping burned ones.
+     * At most `limit` (capped at MAX_PAGE) IDs are examined per call, so a page
+     * can hold fewer IDs than requested even when more follow.
+     * @param start_after 0 for the first page, then the previous page's next_start_after.
+     * @param limit The page size.
+     */
+    pub fn tokens(env: Env, start_after: u64, limit: u32) -> TokenPage {
+        let last = ids::reserved(&env);
+        let end = last.min(start_after.saturating_add(limit.min(MAX_PAGE) as u64));
+
+        let mut token_ids = Vec::new(&env);
+        for token_id in start_after + 1..=end {
+            if migration::has(&env, &DataKey::Owner(token_id)) {
+                token_ids.push_back(token_id);
+            }
+        }
+
+        TokenPage {
+            token_ids,
+            next_start_after: if end < last { Some(end) } else { None },
+        }
+    }
+}
+
+/// Records that `owner` now holds `token_id`.
+pub(crate) fn add(env: &Env, owner: &Address, token_id: u64) {
+    let mut tokens = HotelTimeshareContract::tokens_of_owner(env.clone(), owner.clone());
+    tokens.push_back(token_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::OwnedTokens(owner.clone()), &tokens);
+}
+
+/// Records that `owner` no longer holds `token_id`.
+pub(crate) fn remove(env: &Env, owner: &Address, token_id: u64) {
+    let key = DataKey::OwnedTokens(owner.clone());
+    let mut tokens = HotelTimeshareContract::tokens_of_owner(env.clone(), owner.clone());
+    if let Some(i) = tokens.first_index_of(token_id) {
+        tokens.remove(i);
+    }
+    if tokens.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &tokens);
+    }
+}
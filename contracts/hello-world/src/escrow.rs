@@ -0,0 +1,210 @@
+// Escrowed token-for-token trades.
+//
+// `swap` needs both owners to sign the same call. When they can't, one side
+// opens a trade instead: `open_trade` moves its token into the contract's
+// custody and names the token it wants and who holds it. The counterparty
+// completes the trade with `accept_trade`, which moves their token to the
+// opener and releases the escrowed one to them in the same call. Until then
+// either party can call `withdraw_trade`, returning the escrowed token to the
+// opener.
+//
+// Trades are keyed by the escrowed token's ID. Both legs go through
+// `move_token` like any transfer. If the escrowed token leaves the contract
+// some other way (clawback, admin burn), the trade is closed with it, so a
+// stale trade can never release a token escrowed again later.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env};
+
+use crate::{
+    check_owner, enter, events, fail, move_token, owner_of, receipts, DataKey, Error, ExtError,
+    HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/**
+ * @title Trade
+ * @dev An open trade, keyed by the escrowed token's ID.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trade {
+    /// Who escrowed the token, and receives the wanted one.
+    pub owner: Address,
+    /// Who may accept, by giving up the wanted token.
+    pub counterparty: Address,
+    pub wanted_token_id: u64,
+}
+
+/**
+ * @title TradeOpened
+ * @dev Published when an owner escrows a token for a trade.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TradeOpened {
+    #[topic]
+    pub token_id: u64,
+    pub owner: Address,
+    pub counterparty: Address,
+    pub wanted_token_id: u64,
+}
+
+/**
+ * @title TradeAccepted
+ * @dev Published when a counterparty completes a trade.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TradeAccepted {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub wanted_token_id: u64,
+    pub owner: Address,
+    pub counterparty: Address,
+}
+
+/**
+ * @title TradeWithdrawn
+ * @dev Published when either party calls off a trade.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TradeWithdrawn {
+    #[topic]
+    pub token_id: u64,
+    pub by: Address,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Escrows a token in exchange for another. Fails with InvalidSwap if
+     * the token or the party is the same on both sides.
+     * @param owner The current owner (must sign).
+     * @param counterparty The holder of the wanted token.
+     */
+    pub fn open_trade(
+        env: Env,
+        owner: Address,
+        token_id: u64,
+        counterparty: Address,
+        wanted_token_id: u64,
+    ) -> Result<(), Error> {
+        if owner == counterparty || token_id == wanted_token_id {
+            return Err(Error::InvalidSwap);
+        }
+        owner.require_auth();
+        enter(&env, "open_trade")?;
+
+        check_owner(&env, token_id, &owner)?;
+        move_token(&env, &owner, &env.current_contract_address(), token_id)?;
+        env.storage().persistent().set(
+            &DataKey::Trade(token_id),
+            &Trade {
+                owner: owner.clone(),
+                counterparty: counterparty.clone(),
+                wanted_token_id,
+            },
+        );
+
+        events::emit(
+            &env,
+            &TradeOpened {
+                token_id,
+                owner,
+                counterparty,
+                wanted_token_id,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Completes a trade: the wanted token goes to the opener and the
+     * escrowed one to the counterparty.
+     * @param counterparty The trade's counterparty (must sign); must hold the
+     * wanted token.
+     */
+    pub fn accept_trade(env: Env, counterparty: Address, token_id: u64) -> Result<(), Error> {
+        counterparty.require_auth();
+        enter(&env, "accept_trade")?;
+
+        let trade =
+            Self::trade(env.clone(), token_id).unwrap_or_else(|| fail(&env, ExtError::NoTrade));
+        if counterparty != trade.counterparty {
+            return Err(Error::NotOwner);
+        }
+        let contract = env.current_contract_address();
+        check_owner(&env, trade.wanted_token_id, &counterparty)?;
+        check_owner(&env, token_id, &contract)?;
+        remove_trade(&env, token_id);
+        move_token(&env, &counterparty, &trade.owner, trade.wanted_token_id)?;
+        move_token(&env, &contract, &counterparty, token_id)?;
+        receipts::record_trade(
+            &env,
+            trade.wanted_token_id,
+            &counterparty,
+            &trade.owner,
+            token_id,
+        );
+        receipts::record_trade(
+            &env,
+            token_id,
+            &trade.owner,
+            &counterparty,
+            trade.wanted_token_id,
+        );
+
+        events::emit(
+            &env,
+            &TradeAccepted {
+                token_id,
+                wanted_token_id: trade.wanted_token_id,
+                owner: trade.owner,
+                counterparty,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Calls off a trade, returning the escrowed token to the opener.
+     * @param caller The opener or the counterparty (must sign).
+     */
+    pub fn withdraw_trade(env: Env, caller: Address, token_id: u64) -> Result<(), Error> {
+        caller.require_auth();
+        enter(&env, "withdraw_trade")?;
+
+        let trade =
+            Self::trade(env.clone(), token_id).unwrap_or_else(|| fail(&env, ExtError::NoTrade));
+        if caller != trade.owner && caller != trade.counterparty {
+            return Err(Error::NotOwner);
+        }
+        remove_trade(&env, token_id);
+        let contract = env.current_contract_address();
+        if owner_of(&env, token_id).ok() == Some(contract.clone()) {
+            move_token(&env, &contract, &trade.owner, token_id)?;
+        }
+
+        events::emit(
+            &env,
+            &TradeWithdrawn {
+                token_id,
+                by: caller,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the open trade of an escrowed token.
+     */
+    pub fn trade(env: Env, token_id: u64) -> Option<Trade> {
+        env.storage().persistent().get(&DataKey::Trade(token_id))
+    }
+}
+
+/// Removes a token's trade record.
+pub(crate) fn remove_trade(env: &Env, token_id: u64) {
+    env.storage().persistent().remove(&DataKey::Trade(token_id));
+}
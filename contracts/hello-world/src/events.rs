@@ -0,0 +1,205 @@
+// Event publishing and the on-chain event hash chain.
+//
+// Every contract event is published through `emit`, which also folds the
+// event's topics and data into a running sha256 chain. To keep unrelated
+// transactions from contending on a single head (see storage.rs), the chain is
+// split into EVENT_LANES lanes. An event goes to lane
+//
+//     lane = sha256(xdr(topics))[0] % EVENT_LANES
+//
+// and updates that lane's head, starting from 32 zero bytes:
+//
+//     head' = sha256(head || xdr(topics) || xdr(data))
+//
+// The chain head is the hash of all lane heads in lane order,
+// sha256(head_0 || ... || head_15), and its count the total number of events.
+//
+// At most once per day anyone may call `commit_daily_root()` to snapshot the
+// chain head and the lane heads under the current day, so a light client can
+// check an indexer's event stream (or a daily summary built from it) by
+// replaying the events between two commits lane by lane and comparing heads,
+// without trusting the indexer or replaying the whole ledger. Events in
+// different lanes are not ordered relative to each other by the chain.
+
+use soroban_sdk::{
+    contractevent, contractimpl, contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, Event, Vec,
+};
+
+use crate::{
+    enter, storage, DataKey, Error, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, TimeshareInfo,
+};
+
+/// Length of a commitment period, in seconds of ledger time.
+pub const DAY_SECONDS: u64 = 24 * 60 * 60;
+
+/// Number of independent lanes the event chain is split into.
+pub const EVENT_LANES: u32 = 16;
+
+/**
+ * @title EventChain
+ * @dev Head of the event hash chain (or of one lane) and the number of events folded into it.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventChain {
+    pub head: BytesN<32>,
+    pub count: u64,
+}
+
+// --- TOKEN LIFECYCLE EVENTS ---
+// Topics are stable: indexers and wallets key on ("mint", token_id) and
+// ("transfer", token_id).
+
+/**
+ * @title Mint
+ * @dev Published when a token is created.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mint {
+    #[topic]
+    pub token_id: u64,
+    pub owner: Address,
+    pub info: TimeshareInfo,
+}
+
+/**
+ * @title Transfer
+ * @dev Published whenever a token changes owner, whichever endpoint moved it.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transfer {
+    #[topic]
+    pub token_id: u64,
+    pub from: Address,
+    pub to: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DailyRootCommitted {
+    #[topic]
+    pub day: u64,
+    pub root: BytesN<32>,
+    pub count: u64,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Snapshots the current event chain head under today's day number
+     * (ledger timestamp / DAY_SECONDS). Anyone can call this, at most once per day.
+     * @return The committed day number.
+     */
+    pub fn commit_daily_root(env: Env) -> Result<u64, Error> {
+        let day = env.ledger().timestamp() / DAY_SECONDS;
+        let key = DataKey::DailyRoot(day);
+        if env.storage().persistent().has(&key) {
+            return Err(Error::DailyRootAlreadyCommitted);
+        }
+        enter(&env, "commit_daily_root")?;
+
+        // Committing is itself an event, so it is covered by the next day's root.
+        let lanes = lanes(&env);
+        let chain = combine(&env, &lanes);
+        env.storage().persistent().set(&key, &chain);
+        let mut heads = Vec::new(&env);
+        for lane in lanes.iter() {
+            heads.push_back(lane.head);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::DailyLanes(day), &heads);
+
+        emit(
+            &env,
+            &DailyRootCommitted {
+                day,
+                root: chain.head,
+                count: chain.count,
+            },
+        );
+        Ok(day)
+    }
+
+    /**
+     * @dev Returns the chain head committed for `day`, if any.
+     */
+    pub fn daily_root(env: Env, day: u64) -> Option<EventChain> {
+        env.storage().persistent().get(&DataKey::DailyRoot(day))
+    }
+
+    /**
+     * @dev Returns the lane heads committed for `day`, in lane order, if any.
+     * Replaying a day's events starts from these.
+     */
+    pub fn daily_root_lanes(env: Env, day: u64) -> Option<Vec<BytesN<32>>> {
+        env.storage().persistent().get(&DataKey::DailyLanes(day))
+    }
+
+    /**
+     * @dev Returns the live (uncommitted) chain head.
+     */
+    pub fn event_chain_head(env: Env) -> EventChain {
+        combine(&env, &lanes(&env))
+    }
+
+    /**
+     * @dev Returns the live head of every lane, in lane order.
+     */
+    pub fn event_lanes(env: Env) -> Vec<EventChain> {
+        lanes(&env)
+    }
+}
+
+fn lane(env: &Env, lane: u32) -> EventChain {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventLane(lane))
+        .unwrap_or_else(|| EventChain {
+            head: BytesN::from_array(env, &[0; 32]),
+            count: 0,
+        })
+}
+
+fn lanes(env: &Env) -> Vec<EventChain> {
+    let mut lanes = Vec::new(env);
+    for i in 0..EVENT_LANES {
+        lanes.push_back(lane(env, i));
+    }
+    lanes
+}
+
+fn combine(env: &Env, lanes: &Vec<EventChain>) -> EventChain {
+    let mut preimage = Bytes::new(env);
+    let mut count = 0;
+    for lane in lanes.iter() {
+        preimage.append(&Bytes::from(&lane.head));
+        count += lane.count;
+    }
+    EventChain {
+        head: env.crypto().sha256(&preimage).into(),
+        count,
+    }
+}
+
+/// Publishes `event` and folds it into its lane of the event hash chain. All
+/// contract events must go through here, or the committed roots won't match
+/// the stream.
+pub(crate) fn emit(env: &Env, event: &impl Event) {
+    event.publish(env);
+
+    let topics = event.topics(env).to_xdr(env);
+    let index = env.crypto().sha256(&topics).to_array()[0] as u32 % EVENT_LANES;
+
+    let mut chain = lane(env, index);
+    let mut preimage = Bytes::from(&chain.head);
+    preimage.append(&topics);
+    preimage.append(&event.data(env).to_xdr(env));
+    chain.head = env.crypto().sha256(&preimage).into();
+    chain.count += 1;
+
+    storage::set_shared(env, &DataKey::EventLane(index), &chain);
+}
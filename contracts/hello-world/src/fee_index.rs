@@ -0,0 +1,235 @@
+// Index-linked maintenance fees.
+//
+// Instead of repricing the maintenance fee (see maintenance.rs) by hand, the
+// admin can link it to an inflation or utility index published by a SEP-40
+// price oracle. The FeeIndex names the oracle and feed and the fee at a base
+// reading of the index; the fee follows the index in proportion from there.
+//
+// Once a calendar year (UTC), a keeper calls `index_maintenance_fee` early in
+// the year to reprice the schedule from the oracle's latest reading. Every
+// owner pays a year's fee at the same price: once the first maintenance
+// payment or prepayment of the year is taken, the fee is fixed until the next
+// one, and a late reprice is refused. The admin's caps bound each step: the
+// fee moves by at most a fixed amount per year, so a fee at or near 0 can
+// rise as readily as a large one, and stays between a floor and a ceiling. A
+// reading older than the index's max age is refused. While the fee is
+// indexed the admin can still change the schedule's token and collector, but
+// not its fee.
+
+use soroban_sdk::{
+    contractclient, contractevent, contractimpl, contracttype, Address, Env, Symbol,
+};
+
+use crate::{
+    enter, events, fail, maintenance, require_admin, usage, Error, ExtError2, ExtKey2,
+    HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/**
+ * @title OracleAsset
+ * @dev A SEP-40 oracle feed: a Stellar asset or any other symbol, e.g. `CPI`.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OracleAsset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/**
+ * @title PriceData
+ * @dev A SEP-40 oracle reading.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    /// Ledger timestamp of the reading.
+    pub timestamp: u64,
+}
+
+/**
+ * @title IndexOracle
+ * @dev The part of the SEP-40 oracle interface indexing reads.
+ */
+#[contractclient(name = "IndexOracleClient")]
+pub trait IndexOracle {
+    fn lastprice(env: Env, asset: OracleAsset) -> Option<PriceData>;
+}
+
+/**
+ * @title FeeIndex
+ * @dev How the maintenance fee follows an oracle's index.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeIndex {
+    /// The SEP-40 oracle publishing the index.
+    pub oracle: Address,
+    /// The index's feed on the oracle.
+    pub feed: Symbol,
+    /// The fee when the index reads `base_index`.
+    pub base_fee: i128,
+    /// A reading of the index, in the oracle's units; must be positive.
+    pub base_index: i128,
+    /// The most the fee may move per year, in the fee's token.
+    pub max_step: i128,
+    /// The lowest the indexed fee may go.
+    pub min_fee: i128,
+    /// The highest the indexed fee may go.
+    pub max_fee: i128,
+    /// Seconds after which a reading is too old to use.
+    pub max_age: u64,
+}
+
+/**
+ * @title FeeIndexing
+ * @dev Whether the maintenance fee follows an index, as reported by config().
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeIndexing {
+    Manual,
+    Indexed(FeeIndex),
+}
+
+/**
+ * @title MaintenanceFeeIndexed
+ * @dev Published when the maintenance fee is repriced from the index.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceFeeIndexed {
+    #[topic]
+    pub year: u32,
+    /// The index reading used.
+    pub index: i128,
+    pub previous: i128,
+    pub fee: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Links the maintenance fee to an oracle's index, or unlinks it with
+     * None. Admin only. Fails with InvalidAmount for negative fees, a base
+     * index that isn't positive, a negative step, or a floor above the
+     * ceiling.
+     */
+    pub fn set_fee_index(env: Env, index: Option<FeeIndex>) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "set_fee_index")?;
+
+        let Some(index) = index else {
+            env.storage().persistent().remove(&ExtKey2::FeeIndex);
+            return Ok(());
+        };
+        if index.base_fee < 0
+            || index.base_index <= 0
+            || index.max_step < 0
+            || index.min_fee < 0
+            || index.min_fee > index.max_fee
+        {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage().persistent().set(&ExtKey2::FeeIndex, &index);
+        Ok(())
+    }
+
+    /**
+     * @dev Returns how the maintenance fee follows an index, if it does.
+     */
+    pub fn fee_index(env: Env) -> Option<FeeIndex> {
+        env.storage().persistent().get(&ExtKey2::FeeIndex)
+    }
+
+    /**
+     * @dev Returns the last year the maintenance fee was repriced from the
+     * index, if it has been.
+     */
+    pub fn fee_indexed_year(env: Env) -> Option<u32> {
+        env.storage().persistent().get(&ExtKey2::FeeIndexedYear)
+    }
+
+    /**
+     * @dev Reprices the maintenance fee from the oracle's latest reading of
+     * the index, within the caps. Anyone may call it, once per calendar year.
+     * Fails with NoFeeIndex if the fee isn't indexed, InvalidAmount without a
+     * fee schedule, FeeAlreadyIndexed if this year's fee is set,
+     * FeeYearBilled once this year's fees are being collected, and
+     * StaleIndex without a reading newer than the index's max age.
+     * @return The new fee.
+     */
+    pub fn index_maintenance_fee(env: Env) -> Result<i128, Error> {
+        enter(&env, "index_maintenance_fee")?;
+
+        let Some(index) = Self::fee_index(env.clone()) else {
+            fail(&env, ExtError2::NoFeeIndex);
+        };
+        let mut schedule = Self::maintenance_schedule(env.clone()).ok_or(Error::InvalidAmount)?;
+        let year = usage::current_year(&env);
+        if Self::fee_indexed_year(env.clone()).is_some_and(|indexed| indexed >= year) {
+            fail(&env, ExtError2::FeeAlreadyIndexed);
+        }
+        if Self::fee_billed_year(env.clone()).is_some_and(|billed| billed >= year) {
+            fail(&env, ExtError2::FeeYearBilled);
+        }
+        let reading = IndexOracleClient::new(&env, &index.oracle)
+            .lastprice(&OracleAsset::Other(index.feed.clone()))
+            .filter(|reading| {
+                reading.price > 0
+                    && env.ledger().timestamp().saturating_sub(reading.timestamp) <= index.max_age
+            });
+        let Some(reading) = reading else {
+            fail(&env, ExtError2::StaleIndex);
+        };
+
+        let previous = schedule.fee;
+        let target = index
+            .base_fee
+            .checked_mul(reading.price)
+            .ok_or(Error::InvalidAmount)?
+            / index.base_index;
+        let fee = target
+            .clamp(
+                previous.saturating_sub(index.max_step),
+                previous.saturating_add(index.max_step),
+            )
+            .clamp(index.min_fee, index.max_fee);
+        schedule.fee = fee;
+        maintenance::set_schedule(&env, &schedule);
+        env.storage()
+            .persistent()
+            .set(&ExtKey2::FeeIndexedYear, &year);
+
+        events::emit(
+            &env,
+            &MaintenanceFeeIndexed {
+                year,
+                index: reading.price,
+                previous,
+                fee,
+            },
+        );
+        Ok(fee)
+    }
+
+    /**
+     * @dev Returns the last year maintenance fees were collected in, which
+     * fixes that year's fee, if they have been.
+     */
+    pub fn fee_billed_year(env: Env) -> Option<u32> {
+        env.storage().persistent().get(&ExtKey2::FeeBilledYear)
+    }
+}
+
+/// Records that this year's maintenance fees are being collected, fixing the
+/// fee until the next year.
+pub(crate) fn bill(env: &Env) {
+    let year = usage::current_year(env);
+    if HotelTimeshareContract::fee_billed_year(env.clone()) != Some(year) {
+        env.storage()
+            .persistent()
+            .set(&ExtKey2::FeeBilledYear, &year);
+    }
+}
@@ -0,0 +1,156 @@
+// Attribute filters.
+//
+// Marketplaces filter tokens by tier or by week without an off-chain index:
+// `tokens_by_tier` lists a property's tokens of a tier, and `tokens_by_week`
+// every token of a week of the year. Both read indexes kept up to date by
+// mint, `update_info`, `set_tier` and burn, one Vec of token IDs per tier of
+// a property and per week, in the order tokens joined them.
+//
+// Tokens minted before the indexes existed join them the next time their
+// info or tier changes, or when anyone calls `index_tokens` with their IDs.
+// Indexing an indexed token again does nothing.
+
+use soroban_sdk::{contractimpl, Env, Symbol, Vec};
+
+use crate::{
+    enter, migration, Error, ExtKey, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, TimeshareInfo, MAX_PAGE,
+};
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Lists a property's tokens of a tier.
+     * @param start The index of the first token, 0 for the first page.
+     * @param limit The page size, capped at MAX_PAGE.
+     */
+    pub fn tokens_by_tier(
+        env: Env,
+        hotel_id: u32,
+        tier: Symbol,
+        start: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        page(&env, &ExtKey::TierIndex(hotel_id, tier), start, limit)
+    }
+
+    /**
+     * @dev Lists the tokens of a week of the year, at every property.
+     * @param start The index of the first token, 0 for the first page.
+     * @param limit The page size, capped at MAX_PAGE.
+     */
+    pub fn tokens_by_week(env: Env, week: u32, start: u32, limit: u32) -> Vec<u64> {
+        page(&env, &ExtKey::WeekIndex(week), start, limit)
+    }
+
+    /**
+     * @dev Adds tokens minted before the indexes existed to them. Anyone may
+     * call it; unknown IDs are skipped, and at most MAX_PAGE are examined.
+     */
+    pub fn index_tokens(env: Env, token_ids: Vec<u64>) -> Result<(), Error> {
+        enter(&env, "index_tokens")?;
+
+        for token_id in token_ids.iter().take(MAX_PAGE as usize) {
+            if let Some(info) = migration::read_info(&env, token_id) {
+                index(&env, token_id, &info, &Self::tier(env.clone(), token_id));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Adds a token to the indexes of its week and, if it has one, its tier.
+pub(crate) fn index(env: &Env, token_id: u64, info: &TimeshareInfo, tier: &Option<Symbol>) {
+    add(env, &ExtKey::WeekIndex(info.week), token_id);
+    if let Some(tier) = tier {
+        add(
+            env,
+            &ExtKey::TierIndex(info.hotel_id, tier.clone()),
+            token_id,
+        );
+    }
+}
+
+/// Takes a token out of the indexes `index` added it to.
+pub(crate) fn unindex(env: &Env, token_id: u64, info: &TimeshareInfo, tier: &Option<Symbol>) {
+    remove(env, &ExtKey::WeekIndex(info.week), token_id);
+    if let Some(tier) = tier {
+        remove(
+            env,
+            &ExtKey::TierIndex(info.hotel_id, tier.clone()),
+            token_id,
+        );
+    }
+}
+
+/// Moves a token between indexes when its info or tier changes. It keeps its
+/// place in the indexes whose key is unchanged.
+pub(crate) fn reindex(
+    env: &Env,
+    token_id: u64,
+    (old_info, old_tier): (&TimeshareInfo, &Option<Symbol>),
+    (new_info, new_tier): (&TimeshareInfo, &Option<Symbol>),
+) {
+    if old_info.week != new_info.week {
+        remove(env, &ExtKey::WeekIndex(old_info.week), token_id);
+    }
+    if old_info.hotel_id != new_info.hotel_id || old_tier != new_tier {
+        if let Some(tier) = old_tier {
+            remove(
+                env,
+                &ExtKey::TierIndex(old_info.hotel_id, tier.clone()),
+                token_id,
+            );
+        }
+    }
+    index(env, token_id, new_info, new_tier);
+}
+
+/// Takes a token out of every index, when it is burned.
+pub(crate) fn clear(env: &Env, token_id: u64) {
+    if let Some(info) = migration::read_info(env, token_id) {
+        unindex(
+            env,
+            token_id,
+            &info,
+            &HotelTimeshareContract::tier(env.clone(), token_id),
+        );
+    }
+}
+
+fn page(env: &Env, key: &ExtKey, start: u32, limit: u32) -> Vec<u64> {
+    let tokens = read(env, key);
+    let end = tokens.len().min(start.saturating_add(limit.min(MAX_PAGE)));
+    if start >= end {
+        return Vec::new(env);
+    }
+    tokens.slice(start..end)
+}
+
+fn read(env: &Env, key: &ExtKey) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn add(env: &Env, key: &ExtKey, token_id: u64) {
+    let mut tokens = read(env, key);
+    if !tokens.contains(token_id) {
+        tokens.push_back(token_id);
+        env.storage().persistent().set(key, &tokens);
+    }
+}
+
+fn remove(env: &Env, key: &ExtKey, token_id: u64) {
+    let mut tokens = read(env, key);
+    let Some(i) = tokens.first_index_of(token_id) else {
+        return;
+    };
+    tokens.remove(i);
+    if tokens.is_empty() {
+        env.storage().persistent().remove(key);
+    } else {
+        env.storage().persistent().set(key, &tokens);
+    }
+}
@@ -0,0 +1,509 @@
+// Force-majeure closures.
+//
+// When a property has to close for a week (a hurricane, a flood), oracles the
+// admin trusts flag it: an address holding the Oracle role (see roles.rs)
+// calls `flag_force_majeure` for a (property, year, week), with a reason
+// code, and can `lift_force_majeure` a flag raised in error. A flagged week
+// can't be rented or checked into.
+//
+// Each token of a flagged week is then settled once, by anyone calling
+// `settle_force_majeure` during the year, so a keeper can sweep them all
+// without the owners doing anything:
+//
+// - a renter who booked the week (see rental.rs) is refunded the full rent
+//   from the property's relief fund, and the rental is cancelled;
+// - the token's owner accrues a replacement credit at the property, which the
+//   front desk (Manager role) redeems with `redeem_replacement_credit` when
+//   it books the replacement stay.
+//
+// Relief funds are held by the contract per property and payment token.
+// Anyone can `fund_relief` (the hotel, or its insurer directly); only the
+// admin can `withdraw_relief`, which works while paused. A settlement whose
+// refund the fund can't cover fails until it is topped up. Every step
+// publishes an event, so insurers can reconcile claims from the event trail.
+// Weeks that have already begun (checked in) are not covered.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, token, Address, Env, Symbol};
+
+use crate::{
+    enter, events, fail, hotels, or_fail, owner_of, pause, require_admin, roles, slots, usage,
+    Amount, DataKey, Error, ExtError2, ExtKey2, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, Rental, Role, UsageState,
+};
+
+/**
+ * @title ForceMajeureFlag
+ * @dev An oracle's flag closing a property's week of a year.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForceMajeureFlag {
+    /// The oracle that raised it.
+    pub oracle: Address,
+    /// Why, e.g. `hurricane`.
+    pub reason: Symbol,
+    /// Ledger timestamp it was raised at.
+    pub flagged_at: u64,
+}
+
+/**
+ * @title ForceMajeureFlagged
+ * @dev Published when an oracle closes a property's week.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForceMajeureFlagged {
+    #[topic]
+    pub hotel_id: u32,
+    pub year: u32,
+    pub week: u32,
+    pub oracle: Address,
+    pub reason: Symbol,
+}
+
+/**
+ * @title ForceMajeureLifted
+ * @dev Published when an oracle lifts a flag raised in error. Settlements
+ * already made stand.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForceMajeureLifted {
+    #[topic]
+    pub hotel_id: u32,
+    pub year: u32,
+    pub week: u32,
+    pub oracle: Address,
+}
+
+/**
+ * @title ForceMajeureSettled
+ * @dev Published when a token of a closed week is settled.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForceMajeureSettled {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub hotel_id: u32,
+    pub year: u32,
+    pub week: u32,
+    /// Credited a replacement stay.
+    pub owner: Address,
+    /// The renter refunded, if the week was rented out.
+    pub renter: Option<Address>,
+    /// The rent refunded from the relief fund; 0 if the week wasn't rented.
+    pub refunded: i128,
+}
+
+/**
+ * @title ReliefFunded
+ * @dev Published when a property's relief fund is topped up.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReliefFunded {
+    #[topic]
+    pub hotel_id: u32,
+    #[topic]
+    pub funder: Address,
+    pub payment_token: Address,
+    pub amount: i128,
+    /// The fund's balance after it.
+    pub balance: i128,
+}
+
+/**
+ * @title ReliefWithdrawn
+ * @dev Published when the admin withdraws from a property's relief fund.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReliefWithdrawn {
+    #[topic]
+    pub hotel_id: u32,
+    pub payment_token: Address,
+    pub to: Address,
+    pub amount: i128,
+    /// The fund's balance after it.
+    pub balance: i128,
+}
+
+/**
+ * @title ReplacementCreditRedeemed
+ * @dev Published when the front desk redeems an owner's replacement credit.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReplacementCreditRedeemed {
+    #[topic]
+    pub hotel_id: u32,
+    #[topic]
+    pub owner: Address,
+    pub by: Address,
+    /// Credits the owner has left at the property.
+    pub remaining: u32,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Closes a property's week of a year for force majeure. Oracle role
+     * (or admin). Fails with InvalidWeek outside 1..=52, and InvalidAmount
+     * for a year already over.
+     * @param oracle The oracle (must sign).
+     * @param reason Why, e.g. `hurricane`.
+     */
+    pub fn flag_force_majeure(
+        env: Env,
+        oracle: Address,
+        hotel_id: u32,
+        year: u32,
+        week: u32,
+        reason: Symbol,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Oracle, &oracle)?;
+        enter(&env, "flag_force_majeure")?;
+
+        hotels::check(&env, hotel_id)?;
+        if week == 0 || week > slots::WEEKS_PER_YEAR {
+            return Err(Error::InvalidWeek);
+        }
+        if year < usage::current_year(&env) {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage().persistent().set(
+            &ExtKey2::ForceMajeure(hotel_id, year, week),
+            &ForceMajeureFlag {
+                oracle: oracle.clone(),
+                reason: reason.clone(),
+                flagged_at: env.ledger().timestamp(),
+            },
+        );
+
+        events::emit(
+            &env,
+            &ForceMajeureFlagged {
+                hotel_id,
+                year,
+                week,
+                oracle,
+                reason,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Lifts a flag raised in error, reopening the week; tokens already
+     * settled stay settled. Oracle role (or admin). Fails with
+     * NotForceMajeure if the week isn't flagged.
+     * @param oracle The oracle (must sign).
+     */
+    pub fn lift_force_majeure(
+        env: Env,
+        oracle: Address,
+        hotel_id: u32,
+        year: u32,
+        week: u32,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Oracle, &oracle)?;
+        enter(&env, "lift_force_majeure")?;
+
+        let key = ExtKey2::ForceMajeure(hotel_id, year, week);
+        if !env.storage().persistent().has(&key) {
+            fail(&env, ExtError2::NotForceMajeure);
+        }
+        env.storage().persistent().remove(&key);
+
+        events::emit(
+            &env,
+            &ForceMajeureLifted {
+                hotel_id,
+                year,
+                week,
+                oracle,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the flag closing a property's week of a year, if any.
+     */
+    pub fn force_majeure(
+        env: Env,
+        hotel_id: u32,
+        year: u32,
+        week: u32,
+    ) -> Option<ForceMajeureFlag> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::ForceMajeure(hotel_id, year, week))
+    }
+
+    /**
+     * @dev Settles a token of a week closed this year: refunds its renter,
+     * if any, the full rent from the relief fund, and credits its owner a
+     * replacement stay. Anyone may call it, once per token and year. Fails
+     * with NotForceMajeure unless the token's week is flagged this year,
+     * InvalidUsageState once the week has begun, AlreadySettled if the
+     * token is already settled, and ReliefUnfunded if the relief fund can't
+     * cover the refund.
+     */
+    pub fn settle_force_majeure(env: Env, token_id: u64) -> Result<(), Error> {
+        enter(&env, "settle_force_majeure")?;
+        pause::check(&env)?;
+
+        let info = Self::get_info(env.clone(), token_id)?;
+        let year = usage::current_year(&env);
+        if !flagged(&env, token_id) {
+            fail(&env, ExtError2::NotForceMajeure);
+        }
+        if Self::force_majeure_settled(env.clone(), token_id) == Some(year) {
+            fail(&env, ExtError2::AlreadySettled);
+        }
+        if Self::usage(env.clone(), token_id)?.state != UsageState::Unused {
+            return Err(Error::InvalidUsageState);
+        }
+        env.storage()
+            .persistent()
+            .set(&ExtKey2::ForceMajeureSettled(token_id), &year);
+
+        // 1. A renter gets the rent back from the relief fund.
+        let (renter, refunded) = match Self::rental(env.clone(), token_id) {
+            Some(Rental {
+                renter: Some(renter),
+                token: payment_token,
+                price,
+                ..
+            }) => {
+                let balance = Self::relief_fund(env.clone(), info.hotel_id, payment_token.clone());
+                let balance = Amount::of(balance)
+                    .and_then(|balance| balance.checked_sub(Amount::of(price)?))
+                    .unwrap_or_else(|_| fail(&env, ExtError2::ReliefUnfunded));
+                set_relief(&env, info.hotel_id, &payment_token, balance);
+                token::Client::new(&env, &payment_token).transfer(
+                    &env.current_contract_address(),
+                    &renter,
+                    &price,
+                );
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::Rental(token_id));
+                (Some(renter), price)
+            }
+            _ => (None, 0),
+        };
+
+        // 2. The owner is owed a replacement stay.
+        let owner = owner_of(&env, token_id)?;
+        let credits = Self::replacement_credits(env.clone(), owner.clone(), info.hotel_id);
+        env.storage().persistent().set(
+            &ExtKey2::ReplacementCredits(owner.clone(), info.hotel_id),
+            &(credits + 1),
+        );
+
+        events::emit(
+            &env,
+            &ForceMajeureSettled {
+                token_id,
+                hotel_id: info.hotel_id,
+                year,
+                week: info.week,
+                owner,
+                renter,
+                refunded,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the last year a token was settled for force majeure, if
+     * it has been.
+     */
+    pub fn force_majeure_settled(env: Env, token_id: u64) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::ForceMajeureSettled(token_id))
+    }
+
+    /**
+     * @dev Moves `amount` from the funder into a property's relief fund in a
+     * payment token. Anyone may fund it.
+     * @param funder Who pays (must sign), e.g. the hotel or its insurer.
+     * @return The fund's balance.
+     */
+    pub fn fund_relief(
+        env: Env,
+        funder: Address,
+        hotel_id: u32,
+        payment_token: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        funder.require_auth();
+        enter(&env, "fund_relief")?;
+        pause::check(&env)?;
+
+        hotels::check(&env, hotel_id)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let balance = Self::relief_fund(env.clone(), hotel_id, payment_token.clone());
+        let balance = or_fail(
+            &env,
+            Amount::of(balance).and_then(|balance| balance.checked_add(Amount::of(amount)?)),
+        );
+        token::Client::new(&env, &payment_token).transfer(
+            &funder,
+            env.current_contract_address(),
+            &amount,
+        );
+        set_relief(&env, hotel_id, &payment_token, balance);
+
+        events::emit(
+            &env,
+            &ReliefFunded {
+                hotel_id,
+                funder,
+                payment_token,
+                amount,
+                balance: balance.get(),
+            },
+        );
+        Ok(balance.get())
+    }
+
+    /**
+     * @dev Withdraws `amount` from a property's relief fund. Admin only.
+     * Works while paused. Fails with ReliefUnfunded for more than the fund
+     * holds.
+     * @return The fund's balance.
+     */
+    pub fn withdraw_relief(
+        env: Env,
+        hotel_id: u32,
+        payment_token: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        require_admin(&env)?;
+        enter(&env, "withdraw_relief")?;
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let balance = Self::relief_fund(env.clone(), hotel_id, payment_token.clone());
+        let balance = Amount::of(balance)
+            .and_then(|balance| balance.checked_sub(Amount::of(amount)?))
+            .unwrap_or_else(|_| fail(&env, ExtError2::ReliefUnfunded));
+        set_relief(&env, hotel_id, &payment_token, balance);
+        token::Client::new(&env, &payment_token).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount,
+        );
+
+        events::emit(
+            &env,
+            &ReliefWithdrawn {
+                hotel_id,
+                payment_token,
+                to,
+                amount,
+                balance: balance.get(),
+            },
+        );
+        Ok(balance.get())
+    }
+
+    /**
+     * @dev Returns a property's relief fund balance in a payment token.
+     */
+    pub fn relief_fund(env: Env, hotel_id: u32, payment_token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::ReliefFund(hotel_id, payment_token))
+            .unwrap_or(0)
+    }
+
+    /**
+     * @dev Returns how many replacement stays a property owes an owner.
+     */
+    pub fn replacement_credits(env: Env, owner: Address, hotel_id: u32) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::ReplacementCredits(owner, hotel_id))
+            .unwrap_or(0)
+    }
+
+    /**
+     * @dev Redeems one of an owner's replacement credits at a property, once
+     * the front desk has booked the replacement stay. Manager role (or
+     * admin). Fails with NoReplacementCredit without one.
+     * @param caller The front desk (must sign).
+     * @return Credits the owner has left at the property.
+     */
+    pub fn redeem_replacement_credit(
+        env: Env,
+        caller: Address,
+        owner: Address,
+        hotel_id: u32,
+    ) -> Result<u32, Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "redeem_replacement_credit")?;
+
+        let credits = Self::replacement_credits(env.clone(), owner.clone(), hotel_id);
+        if credits == 0 {
+            fail(&env, ExtError2::NoReplacementCredit);
+        }
+        let remaining = credits - 1;
+        let key = ExtKey2::ReplacementCredits(owner.clone(), hotel_id);
+        if remaining == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &remaining);
+        }
+
+        events::emit(
+            &env,
+            &ReplacementCreditRedeemed {
+                hotel_id,
+                owner,
+                by: caller,
+                remaining,
+            },
+        );
+        Ok(remaining)
+    }
+}
+
+/// Requires that this year's week of `token_id` isn't closed for force
+/// majeure. Fails with WeekClosed.
+pub(crate) fn check_open(env: &Env, token_id: u64) {
+    if flagged(env, token_id) {
+        fail(env, ExtError2::WeekClosed);
+    }
+}
+
+/// Whether this year's week of `token_id` is flagged.
+fn flagged(env: &Env, token_id: u64) -> bool {
+    let year = usage::current_year(env);
+    HotelTimeshareContract::get_info(env.clone(), token_id).is_ok_and(|info| {
+        info.year.is_none_or(|term| term == year)
+            && env.storage().persistent().has(&ExtKey2::ForceMajeure(
+                info.hotel_id,
+                year,
+                info.week,
+            ))
+    })
+}
+
+fn set_relief(env: &Env, hotel_id: u32, payment_token: &Address, balance: Amount) {
+    env.storage().persistent().set(
+        &ExtKey2::ReliefFund(hotel_id, payment_token.clone()),
+        &balance.get(),
+    );
+}
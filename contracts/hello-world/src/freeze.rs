@@ -0,0 +1,86 @@
+// Freezing individual tokens.
+//
+// When a token is caught up in fraud or a legal dispute, the admin `freeze`s
+// it until the matter is settled: it can't change owner (every transfer path
+// goes through `move_token`), be listed or rented out, or be burned by its
+// owner. Unlike a pause, nothing else is affected. Frozen tokens fail with
+// TokenFrozen. The admin can still take a frozen token back with `clawback`
+// (see clawback.rs).
+
+use soroban_sdk::{contractevent, contractimpl, Env};
+
+use crate::{
+    enter, events, fail, owner_of, require_admin, DataKey, Error, ExtError, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/**
+ * @title TokenFrozen
+ * @dev Published when the admin freezes a token.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenFrozen {
+    #[topic]
+    pub token_id: u64,
+}
+
+/**
+ * @title TokenUnfrozen
+ * @dev Published when the admin unfreezes a token.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenUnfrozen {
+    #[topic]
+    pub token_id: u64,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Freezes a token. Admin only.
+     * @param token_id The token to freeze.
+     */
+    pub fn freeze(env: Env, token_id: u64) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "freeze")?;
+
+        owner_of(&env, token_id)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Frozen(token_id), &true);
+        events::emit(&env, &TokenFrozen { token_id });
+        Ok(())
+    }
+
+    /**
+     * @dev Lifts a token's freeze. Admin only.
+     * @param token_id The token to unfreeze.
+     */
+    pub fn unfreeze(env: Env, token_id: u64) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "unfreeze")?;
+
+        owner_of(&env, token_id)?;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Frozen(token_id));
+        events::emit(&env, &TokenUnfrozen { token_id });
+        Ok(())
+    }
+
+    /**
+     * @dev Returns whether a token is frozen.
+     */
+    pub fn is_frozen(env: Env, token_id: u64) -> bool {
+        env.storage().persistent().has(&DataKey::Frozen(token_id))
+    }
+}
+
+/// Fails with TokenFrozen while `token_id` is frozen.
+pub(crate) fn check(env: &Env, token_id: u64) {
+    if HotelTimeshareContract::is_frozen(env.clone(), token_id) {
+        fail(env, ExtError::TokenFrozen);
+    }
+}
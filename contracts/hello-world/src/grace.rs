@@ -0,0 +1,216 @@
+// Grace periods.
+//
+// Several flows give someone a fixed window before the contract moves on: a
+// recipient accepting a pending transfer (pending.rs), bidders revealing
+// sealed bids (sealed.rs), owners paying a year's maintenance fee before it
+// is overdue and late fees and delinquency start (late.rs), the hotel
+// matching an accepted offer under its right of first refusal (refusal.rs).
+// Their lengths sit in one table, keyed by GraceRule, so they are set the
+// same way and read from one place: `policy` returns every rule's period in
+// seconds, and the modules ask `ends` when a window that opens at a given
+// time closes.
+//
+// Each rule starts at its compiled-in default. The admin can only change one
+// through a timelock: `propose_grace_period` records the new length, and
+// anyone can `execute_grace_period` GRACE_POLICY_DELAY seconds later, so
+// owners see a shorter window coming before it applies. Windows already open
+// keep the end they were given. The admin can cancel a proposal until then.
+//
+// Windows whose length is chosen per use, such as an offer's expiry or the
+// cooling-off period that comes with its refund share, keep their own
+// settings.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Env, Map};
+
+use crate::{
+    enter, events, fail, require_admin, Error, ExtError, ExtKey2, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, FIRST_REFUSAL_WINDOW,
+    PENDING_TRANSFER_WINDOW, SEALED_REVEAL_WINDOW,
+};
+
+/// Seconds between proposing a grace period change and being able to apply it.
+pub const GRACE_POLICY_DELAY: u64 = 7 * 24 * 60 * 60;
+
+/// Default seconds after 1 January before a year's maintenance fee is overdue.
+pub const MAINTENANCE_GRACE: u64 = 30 * 24 * 60 * 60;
+
+/**
+ * @title GraceRule
+ * @dev A window whose length the grace period table sets.
+ */
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GraceRule {
+    /// A recipient accepting a pending transfer; PENDING_TRANSFER_WINDOW by default.
+    PendingTransfer,
+    /// Revealing sealed bids after bidding closes; SEALED_REVEAL_WINDOW by default.
+    SealedReveal,
+    /// Paying a year's maintenance fee before it is overdue, from 1 January;
+    /// MAINTENANCE_GRACE by default.
+    Delinquency,
+    /// The hotel matching an accepted offer; FIRST_REFUSAL_WINDOW by default.
+    FirstRefusal,
+}
+
+/**
+ * @title PendingGracePeriod
+ * @dev A grace period change waiting for its timelock to pass.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingGracePeriod {
+    pub seconds: u64,
+    /// Ledger timestamp from which it can be executed.
+    pub effective_at: u64,
+}
+
+/**
+ * @title GracePeriodProposed
+ * @dev Published when the admin proposes a new length for a grace period.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GracePeriodProposed {
+    #[topic]
+    pub rule: GraceRule,
+    pub seconds: u64,
+    pub effective_at: u64,
+}
+
+/**
+ * @title GracePeriodChanged
+ * @dev Published when a proposed grace period is applied.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GracePeriodChanged {
+    #[topic]
+    pub rule: GraceRule,
+    pub seconds: u64,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Proposes a new length for a grace period. It can be executed once
+     * GRACE_POLICY_DELAY has passed. A new proposal replaces any pending one
+     * for the rule. Admin only.
+     */
+    pub fn propose_grace_period(env: Env, rule: GraceRule, seconds: u64) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "propose_grace_period")?;
+
+        let effective_at = env.ledger().timestamp().saturating_add(GRACE_POLICY_DELAY);
+        env.storage().persistent().set(
+            &ExtKey2::PendingGrace(rule),
+            &PendingGracePeriod {
+                seconds,
+                effective_at,
+            },
+        );
+
+        events::emit(
+            &env,
+            &GracePeriodProposed {
+                rule,
+                seconds,
+                effective_at,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Applies a proposed grace period whose timelock has passed. Anyone
+     * may call it. Fails with NoPendingGracePeriod without a proposal, and
+     * TimelockNotElapsed before its effective_at.
+     */
+    pub fn execute_grace_period(env: Env, rule: GraceRule) -> Result<(), Error> {
+        enter(&env, "execute_grace_period")?;
+
+        let pending = Self::pending_grace_period(env.clone(), rule)
+            .unwrap_or_else(|| fail(&env, ExtError::NoPendingGracePeriod));
+        if env.ledger().timestamp() < pending.effective_at {
+            return Err(Error::TimelockNotElapsed);
+        }
+        let mut periods = stored(&env);
+        periods.set(rule, pending.seconds);
+        env.storage()
+            .persistent()
+            .set(&ExtKey2::GracePeriods, &periods);
+        env.storage()
+            .persistent()
+            .remove(&ExtKey2::PendingGrace(rule));
+
+        events::emit(
+            &env,
+            &GracePeriodChanged {
+                rule,
+                seconds: pending.seconds,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Withdraws a proposed grace period. Admin only. Fails with
+     * NoPendingGracePeriod without a proposal.
+     */
+    pub fn cancel_grace_period(env: Env, rule: GraceRule) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "cancel_grace_period")?;
+
+        if Self::pending_grace_period(env.clone(), rule).is_none() {
+            fail(&env, ExtError::NoPendingGracePeriod);
+        }
+        env.storage()
+            .persistent()
+            .remove(&ExtKey2::PendingGrace(rule));
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the grace period change proposed for a rule, if any.
+     */
+    pub fn pending_grace_period(env: Env, rule: GraceRule) -> Option<PendingGracePeriod> {
+        env.storage().persistent().get(&ExtKey2::PendingGrace(rule))
+    }
+
+    /**
+     * @dev Returns every grace period in force, in seconds by rule.
+     */
+    pub fn policy(env: Env) -> Map<GraceRule, u64> {
+        let mut periods = Map::new(&env);
+        for rule in [
+            GraceRule::PendingTransfer,
+            GraceRule::SealedReveal,
+            GraceRule::Delinquency,
+            GraceRule::FirstRefusal,
+        ] {
+            periods.set(rule, period(&env, rule));
+        }
+        periods
+    }
+}
+
+/// The length of a rule's grace period, in seconds.
+pub(crate) fn period(env: &Env, rule: GraceRule) -> u64 {
+    stored(env).get(rule).unwrap_or(match rule {
+        GraceRule::PendingTransfer => PENDING_TRANSFER_WINDOW,
+        GraceRule::SealedReveal => SEALED_REVEAL_WINDOW,
+        GraceRule::Delinquency => MAINTENANCE_GRACE,
+        GraceRule::FirstRefusal => FIRST_REFUSAL_WINDOW,
+    })
+}
+
+/// When a rule's window that opens at `start` closes.
+pub(crate) fn ends(env: &Env, rule: GraceRule, start: u64) -> u64 {
+    start.saturating_add(period(env, rule))
+}
+
+fn stored(env: &Env) -> Map<GraceRule, u64> {
+    env.storage()
+        .persistent()
+        .get(&ExtKey2::GracePeriods)
+        .unwrap_or_else(|| Map::new(env))
+}
@@ -0,0 +1,205 @@
+// Hotel registry.
+//
+// Tokens reference their property by the ID it was given in this registry
+// rather than by a free-text name, so a typo or a look-alike name can't mint
+// tokens for a property that doesn't exist. Managers (see roles.rs) register
+// hotels and rename them; IDs are assigned in order from 1 and never reused,
+// and no two hotels share a name.
+//
+// Tokens minted before the registry existed name their hotel instead. The first
+// read of such a token links it to the hotel registered under that name,
+// registering the name if needed (see migration.rs).
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env, String, Vec};
+
+use crate::{
+    enter, events, roles, DataKey, Error, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, Role, MAX_PAGE,
+};
+
+/**
+ * @title Hotel
+ * @dev A registered property.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Hotel {
+    pub id: u32,
+    pub name: String,
+}
+
+/**
+ * @title HotelRegistered
+ * @dev Published when a hotel is added to the registry.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HotelRegistered {
+    #[topic]
+    pub hotel_id: u32,
+    pub name: String,
+}
+
+/**
+ * @title HotelUpdated
+ * @dev Published when a registered hotel is renamed.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HotelUpdated {
+    #[topic]
+    pub hotel_id: u32,
+    pub name: String,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Adds a hotel to the registry. Manager role (or admin).
+     * @param caller The manager (must sign).
+     * @param name The hotel's name, unique among registered hotels.
+     * @return The new hotel's ID.
+     */
+    pub fn register_hotel(env: Env, caller: Address, name: String) -> Result<u32, Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "register_hotel")?;
+
+        register(&env, name)
+    }
+
+    /**
+     * @dev Renames a registered hotel. Manager role (or admin).
+     * @param caller The manager (must sign).
+     * @return The updated hotel.
+     */
+    pub fn update_hotel(
+        env: Env,
+        caller: Address,
+        hotel_id: u32,
+        name: String,
+    ) -> Result<Hotel, Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "update_hotel")?;
+
+        let mut hotel = Self::get_hotel(env.clone(), hotel_id)?;
+        if name == hotel.name {
+            return Ok(hotel);
+        }
+        check_name_free(&env, &name)?;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::HotelName(hotel.name));
+        env.storage()
+            .persistent()
+            .set(&DataKey::HotelName(name.clone()), &hotel_id);
+        hotel.name = name;
+        set_hotel(&env, &hotel);
+
+        events::emit(
+            &env,
+            &HotelUpdated {
+                hotel_id,
+                name: hotel.name.clone(),
+            },
+        );
+        Ok(hotel)
+    }
+
+    /**
+     * @dev Returns a registered hotel.
+     */
+    pub fn get_hotel(env: Env, hotel_id: u32) -> Result<Hotel, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Hotel(hotel_id))
+            .ok_or(Error::HotelNotFound)
+    }
+
+    /**
+     * @dev Lists registered hotels with IDs above `start_after`, in ID order.
+     * @param start_after 0 for the first page, then the last ID of the previous page.
+     * @param limit The page size, capped at MAX_PAGE.
+     */
+    pub fn list_hotels(env: Env, start_after: u32, limit: u32) -> Vec<Hotel> {
+        let last = hotel_count(&env);
+        let end = last.min(start_after.saturating_add(limit.min(MAX_PAGE)));
+
+        let mut hotels = Vec::new(&env);
+        for hotel_id in start_after + 1..=end {
+            if let Ok(hotel) = Self::get_hotel(env.clone(), hotel_id) {
+                hotels.push_back(hotel);
+            }
+        }
+        hotels
+    }
+}
+
+/// Fails with HotelNotFound unless `hotel_id` is registered.
+pub(crate) fn check(env: &Env, hotel_id: u32) -> Result<(), Error> {
+    if !env.storage().persistent().has(&DataKey::Hotel(hotel_id)) {
+        return Err(Error::HotelNotFound);
+    }
+    Ok(())
+}
+
+/// The ID of the hotel registered as `name`, registering it if there is none.
+/// Links tokens minted before the registry to it.
+pub(crate) fn link(env: &Env, name: String) -> u32 {
+    match env
+        .storage()
+        .persistent()
+        .get(&DataKey::HotelName(name.clone()))
+    {
+        Some(hotel_id) => hotel_id,
+        None => add(env, name),
+    }
+}
+
+fn register(env: &Env, name: String) -> Result<u32, Error> {
+    check_name_free(env, &name)?;
+    Ok(add(env, name))
+}
+
+fn add(env: &Env, name: String) -> u32 {
+    let hotel_id = hotel_count(env) + 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::HotelCount, &hotel_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::HotelName(name.clone()), &hotel_id);
+    set_hotel(
+        env,
+        &Hotel {
+            id: hotel_id,
+            name: name.clone(),
+        },
+    );
+
+    events::emit(env, &HotelRegistered { hotel_id, name });
+    hotel_id
+}
+
+fn check_name_free(env: &Env, name: &String) -> Result<(), Error> {
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::HotelName(name.clone()))
+    {
+        return Err(Error::DuplicateHotel);
+    }
+    Ok(())
+}
+
+pub(crate) fn hotel_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HotelCount)
+        .unwrap_or(0)
+}
+
+fn set_hotel(env: &Env, hotel: &Hotel) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Hotel(hotel.id), hotel);
+}
@@ -0,0 +1,175 @@
+// Token ID allocation and mint accounting.
+//
+// Token IDs are handed out in blocks of ID_BLOCK_SIZE. Each minter draws IDs
+// from its own block, kept under `DataKey::Minter(address)` together with the
+// number of tokens it has minted. The global COUNTER only records the highest
+// ID reserved by any block and is written once per block, so mints by
+// different minters touch disjoint keys (see storage.rs) and only contend when
+// one of them starts a new block.
+//
+// Consequences:
+// - IDs are unique and increase per minter, but interleave across minters and
+//   may leave unused IDs at the end of a block; `tokens()` skips those.
+// - The total supply is the sum of every minter's count minus the number of
+//   burned tokens, so no single supply entry is written by every mint.
+//
+// Before issuing an ID, allocation checks that no token is stored under it and
+// fails with `Error::TokenIdCollision` otherwise, so a bug in block bookkeeping
+// can never overwrite an existing deed.
+//
+// Version 1 deployments (see migration.rs) kept COUNTER in instance storage as
+// the number of tokens minted, all by the admin, with IDs 1 to COUNTER. Until
+// `migrate()` adopts it, both the highest reserved ID and the supply count it.
+
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Vec};
+
+use crate::{
+    migration, storage, DataKey, Error, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, ADMIN, COUNTER,
+};
+
+/// Number of token IDs reserved for a minter at a time.
+pub const ID_BLOCK_SIZE: u64 = 100;
+
+/**
+ * @title MinterState
+ * @dev A minter's current ID block and how many tokens it has minted in total.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinterState {
+    /// Next ID to issue from the block.
+    pub next: u64,
+    /// Last ID of the block (inclusive).
+    pub end: u64,
+    pub minted: u64,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Returns a minter's current ID block and mint count, if it has minted.
+     */
+    pub fn minter_state(env: Env, minter: Address) -> Option<MinterState> {
+        env.storage().persistent().get(&DataKey::Minter(minter))
+    }
+}
+
+/// Issues `count` fresh token IDs to `minter`, reserving new blocks as needed.
+pub(crate) fn allocate(env: &Env, minter: &Address, count: u32) -> Result<Vec<u64>, Error> {
+    let key = DataKey::Minter(minter.clone());
+    let mut state = match HotelTimeshareContract::minter_state(env.clone(), minter.clone()) {
+        Some(state) => state,
+        None => {
+            register_minter(env, minter);
+            MinterState {
+                next: 1,
+                end: 0,
+                minted: 0,
+            }
+        }
+    };
+
+    let mut ids = Vec::new(env);
+    for _ in 0..count {
+        if state.next > state.end {
+            let reserved = reserved(env);
+            state.next = reserved + 1;
+            state.end = reserved + ID_BLOCK_SIZE;
+            storage::set_shared(env, &COUNTER, &state.end);
+        }
+        let token_id = state.next;
+        if migration::has(env, &DataKey::Info(token_id)) {
+            return Err(Error::TokenIdCollision);
+        }
+        ids.push_back(token_id);
+        state.next += 1;
+        state.minted += 1;
+    }
+
+    storage::set_shared(env, &key, &state);
+    Ok(ids)
+}
+
+/// Highest token ID reserved by any block.
+pub(crate) fn reserved(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&COUNTER)
+        .unwrap_or_else(|| legacy_minted(env))
+}
+
+/// Number of tokens currently in existence: minted by anyone, minus burned.
+pub(crate) fn supply(env: &Env) -> u64 {
+    let minters: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Minters)
+        .unwrap_or_else(|| Vec::new(env));
+    let mut minted = legacy_minted(env);
+    for minter in minters.iter() {
+        if let Some(state) = HotelTimeshareContract::minter_state(env.clone(), minter) {
+            minted += state.minted;
+        }
+    }
+    let burned: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Burned)
+        .unwrap_or(0);
+    minted - burned
+}
+
+/// Counts one burned token.
+pub(crate) fn record_burn(env: &Env) {
+    let burned: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Burned)
+        .unwrap_or(0);
+    storage::set_shared(env, &DataKey::Burned, &(burned + 1));
+}
+
+/// Moves a version 1 COUNTER to persistent storage and counts the tokens it
+/// covers as the admin's mints. Blocks reserved since the upgrade are kept.
+pub(crate) fn adopt_legacy(env: &Env) {
+    let legacy = legacy_minted(env);
+    if legacy == 0 {
+        return;
+    }
+    env.storage().instance().remove(&COUNTER);
+    let reserved: u64 = env.storage().persistent().get(&COUNTER).unwrap_or(0);
+    storage::set_shared(env, &COUNTER, &reserved.max(legacy));
+
+    let Some(admin) = env.storage().instance().get::<_, Address>(&ADMIN) else {
+        return;
+    };
+    let mut state = match HotelTimeshareContract::minter_state(env.clone(), admin.clone()) {
+        Some(state) => state,
+        None => {
+            register_minter(env, &admin);
+            MinterState {
+                next: 1,
+                end: 0,
+                minted: 0,
+            }
+        }
+    };
+    state.minted += legacy;
+    storage::set_shared(env, &DataKey::Minter(admin), &state);
+}
+
+/// Tokens minted under a version 1 COUNTER not yet adopted.
+fn legacy_minted(env: &Env) -> u64 {
+    env.storage().instance().get(&COUNTER).unwrap_or(0)
+}
+
+fn register_minter(env: &Env, minter: &Address) {
+    let mut minters: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Minters)
+        .unwrap_or_else(|| Vec::new(env));
+    minters.push_back(minter.clone());
+    storage::set_shared(env, &DataKey::Minters, &minters);
+}
@@ -0,0 +1,296 @@
+// Swap intents and matching.
+//
+// Owners who would swap their week but have no counterparty in mind post a
+// swap intent instead: "I have this token, I want any week meeting these
+// Criteria" (see collection.rs). `post_intent` escrows the token in the
+// contract, and anyone may call `match_swaps` to find intents that satisfy
+// each other and execute them, so no owner has to be online when their match
+// turns up.
+//
+// Matching is deterministic. Intents are considered in the order they were
+// posted. For the oldest unmatched intent, the oldest intent it can pair with
+// (each token meets the other's wants) is taken; failing that, the oldest
+// three-way cycle, in which each token meets the wants of the next intent's
+// owner. Matched intents settle at once, as escrowed trades do, and the search
+// goes on with the next oldest unmatched intent.
+//
+// Work per call is bounded twice over: the book holds at most
+// MAX_SWAP_INTENTS open intents, and one call settles at most MAX_MATCHES
+// swaps. An owner can take an intent back with `cancel_intent`, and a token
+// that leaves escrow some other way (clawback, admin burn) leaves the book.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, vec, Address, Env, Vec};
+
+use crate::{
+    collection, enter, events, fail, migration, move_token, pause, receipts, storage, Criteria,
+    Error, ExtError, ExtKey, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient,
+};
+
+/// Most intents the book holds at once.
+pub const MAX_SWAP_INTENTS: u32 = 32;
+/// Most swaps one `match_swaps` call settles.
+pub const MAX_MATCHES: u32 = 5;
+
+/**
+ * @title SwapIntent
+ * @dev An owner's standing offer to swap an escrowed token for any token
+ * meeting `want`.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapIntent {
+    pub owner: Address,
+    pub token_id: u64,
+    pub want: Criteria,
+}
+
+/**
+ * @title IntentPosted
+ * @dev Published when an owner posts a swap intent.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IntentPosted {
+    #[topic]
+    pub token_id: u64,
+    pub owner: Address,
+    pub want: Criteria,
+}
+
+/**
+ * @title IntentCancelled
+ * @dev Published when an owner takes back a swap intent.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IntentCancelled {
+    #[topic]
+    pub token_id: u64,
+}
+
+/**
+ * @title IntentsMatched
+ * @dev Published when `match_swaps` settles a pair or cycle of intents, next
+ * to their Transfer events. Each token went to the owner of the next one,
+ * and the last to the owner of the first.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IntentsMatched {
+    pub token_ids: Vec<u64>,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Posts a swap intent, moving the token into the contract until it
+     * is matched or cancelled. Fails with InvalidWeek or HotelNotFound for
+     * bad criteria, and IntentBookFull once MAX_SWAP_INTENTS are open.
+     * @param owner The token's current owner (must sign).
+     * @param want What the owner would take for it.
+     */
+    pub fn post_intent(
+        env: Env,
+        owner: Address,
+        token_id: u64,
+        want: Criteria,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "post_intent")?;
+        pause::check(&env)?;
+
+        collection::check_criteria(&env, &want)?;
+        let mut book = Self::swap_intents(env.clone());
+        if book.len() >= MAX_SWAP_INTENTS {
+            fail(&env, ExtError::IntentBookFull);
+        }
+        move_token(&env, &owner, &env.current_contract_address(), token_id)?;
+        env.storage().persistent().set(
+            &ExtKey::SwapIntent(token_id),
+            &SwapIntent {
+                owner: owner.clone(),
+                token_id,
+                want: want.clone(),
+            },
+        );
+        book.push_back(token_id);
+        storage::set_shared(&env, &ExtKey::SwapIntents, &book);
+
+        events::emit(
+            &env,
+            &IntentPosted {
+                token_id,
+                owner,
+                want,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Takes back a swap intent and its token. Fails with NoSwapIntent
+     * unless the token has an open intent, and NotOwner for someone else's.
+     * @param owner The owner who posted it (must sign).
+     */
+    pub fn cancel_intent(env: Env, owner: Address, token_id: u64) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "cancel_intent")?;
+
+        let intent = Self::swap_intent(env.clone(), token_id)
+            .unwrap_or_else(|| fail(&env, ExtError::NoSwapIntent));
+        if intent.owner != owner {
+            return Err(Error::NotOwner);
+        }
+        remove(&env, token_id);
+        move_token(&env, &env.current_contract_address(), &owner, token_id)?;
+
+        events::emit(&env, &IntentCancelled { token_id });
+        Ok(())
+    }
+
+    /**
+     * @dev Settles up to `limit` (at most MAX_MATCHES) pairs or three-way
+     * cycles of intents that satisfy each other, by the rules in the module
+     * notes. Anyone may call it.
+     * @return How many swaps were settled.
+     */
+    pub fn match_swaps(env: Env, limit: u32) -> Result<u32, Error> {
+        enter(&env, "match_swaps")?;
+        pause::check(&env)?;
+
+        let intents: Vec<SwapIntent> = Self::swap_intents(env.clone())
+            .iter()
+            .filter_map(|token_id| Self::swap_intent(env.clone(), token_id))
+            .fold(Vec::new(&env), |mut intents, intent| {
+                intents.push_back(intent);
+                intents
+            });
+        let count = intents.len();
+
+        // gives[i] has bit j set when intent i's token meets intent j's wants.
+        let mut gives = [0u32; MAX_SWAP_INTENTS as usize];
+        for (i, intent) in intents.iter().enumerate() {
+            let Some(info) = migration::read_info(&env, intent.token_id) else {
+                continue;
+            };
+            let tier = Self::tier(env.clone(), intent.token_id);
+            for (j, other) in intents.iter().enumerate() {
+                if other.owner != intent.owner && collection::meets(&other.want, &info, &tier) {
+                    gives[i] |= 1 << j;
+                }
+            }
+        }
+        let gives = |i: u32| gives[i as usize];
+
+        let mut matched = 0u32;
+        let mut swaps = 0;
+        for i in 0..count {
+            if swaps >= limit.min(MAX_MATCHES) {
+                break;
+            }
+            let open = !matched & !(1 << i);
+            if matched & (1 << i) != 0 || gives(i) & open == 0 {
+                continue;
+            }
+            // takes has bit k set when intent k's token meets intent i's wants.
+            let takes = (0..count)
+                .filter(|&k| gives(k) & (1 << i) != 0)
+                .fold(0u32, |takes, k| takes | 1 << k);
+            let pair = gives(i) & takes & open;
+            let cycle = if pair != 0 {
+                Some(vec![&env, i, lowest(pair)])
+            } else {
+                bits(gives(i) & open).find_map(|j| {
+                    let thirds = gives(j) & takes & open & !(1 << j);
+                    (thirds != 0).then(|| vec![&env, i, j, lowest(thirds)])
+                })
+            };
+            let Some(cycle) = cycle else {
+                continue;
+            };
+
+            let mut cycle_intents = Vec::new(&env);
+            for index in cycle.iter() {
+                matched |= 1 << index;
+                cycle_intents.push_back(intents.get_unchecked(index));
+            }
+            settle(&env, &cycle_intents)?;
+            swaps += 1;
+        }
+        Ok(swaps)
+    }
+
+    /**
+     * @dev Returns a token's open swap intent, if it has one.
+     */
+    pub fn swap_intent(env: Env, token_id: u64) -> Option<SwapIntent> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::SwapIntent(token_id))
+    }
+
+    /**
+     * @dev Lists the tokens with open swap intents, oldest first.
+     */
+    pub fn swap_intents(env: Env) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::SwapIntents)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+}
+
+/// Takes a token's intent out of the book when it leaves the contract.
+pub(crate) fn clear(env: &Env, token_id: u64) {
+    if env
+        .storage()
+        .persistent()
+        .has(&ExtKey::SwapIntent(token_id))
+    {
+        remove(env, token_id);
+    }
+}
+
+/// Closes matched intents and moves each token to the next intent's owner.
+fn settle(env: &Env, intents: &Vec<SwapIntent>) -> Result<(), Error> {
+    let mut token_ids = Vec::new(env);
+    for intent in intents.iter() {
+        remove(env, intent.token_id);
+        token_ids.push_back(intent.token_id);
+    }
+    let contract = env.current_contract_address();
+    for (i, intent) in intents.iter().enumerate() {
+        let next = intents.get_unchecked((i as u32 + 1) % intents.len());
+        move_token(env, &contract, &next.owner, intent.token_id)?;
+        receipts::record_trade(
+            env,
+            intent.token_id,
+            &intent.owner,
+            &next.owner,
+            next.token_id,
+        );
+    }
+    events::emit(env, &IntentsMatched { token_ids });
+    Ok(())
+}
+
+fn remove(env: &Env, token_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&ExtKey::SwapIntent(token_id));
+    let mut book = HotelTimeshareContract::swap_intents(env.clone());
+    if let Some(i) = book.first_index_of(token_id) {
+        book.remove(i);
+        storage::set_shared(env, &ExtKey::SwapIntents, &book);
+    }
+}
+
+/// The indices of the set bits of `mask`, lowest first.
+fn bits(mask: u32) -> impl Iterator<Item = u32> {
+    (0..u32::BITS).filter(move |&bit| mask & (1 << bit) != 0)
+}
+
+fn lowest(mask: u32) -> u32 {
+    mask.trailing_zeros()
+}
@@ -0,0 +1,253 @@
+// Late fees on maintenance.
+//
+// The admin can charge owners who pay their maintenance fee (see
+// maintenance.rs) late. A year's fee falls overdue the Delinquency grace
+// period (see grace.rs) after 1 January (UTC). Under the LateFeePolicy, from
+// then on it owes a flat late fee plus interest of a share of the fee for
+// every whole period overdue, up to a cap. Each unpaid year accrues its own late fee from its own due date, so a
+// token two years behind owes two. Nothing is stored as it accrues:
+// `late_fee` works it out from the ledger timestamp, and `pay_maintenance`
+// charges it on top of the fees.
+//
+// The policy's thresholds, in seconds overdue, grade how delinquent a token
+// is, counting from the oldest unpaid year's due date. A keeper calls
+// `check_delinquency` to record the level a token has reached, which
+// publishes DelinquencyEscalated the first time it crosses each threshold;
+// paying the arrears resets it.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Env, Vec};
+
+use crate::{
+    enter, events, grace, money, or_fail, require_admin, usage, Amount, Bps, Error, ExtKey2,
+    GraceRule, HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+    MaintenanceStatus,
+};
+
+/// Most delinquency thresholds a policy may set.
+pub const MAX_DELINQUENCY_LEVELS: u32 = 5;
+
+/**
+ * @title LateFeePolicy
+ * @dev What owners pay for settling a year's maintenance fee late.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LateFeePolicy {
+    /// Charged once the fee is overdue.
+    pub flat: i128,
+    /// Interest per whole period overdue, in basis points of the fee; at
+    /// most FULL_BPS.
+    pub rate_bps: u32,
+    /// Seconds per interest period; must not be 0.
+    pub period: u64,
+    /// The most the late fee can reach, flat fee included.
+    pub max: i128,
+    /// Seconds overdue at which a token reaches each delinquency level, in
+    /// ascending order.
+    pub thresholds: Vec<u64>,
+}
+
+/**
+ * @title LateFees
+ * @dev Whether late fees are charged, as reported by config().
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LateFees {
+    Disabled,
+    Enabled(LateFeePolicy),
+}
+
+/**
+ * @title Delinquency
+ * @dev The delinquency level a keeper last recorded for a token.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Delinquency {
+    /// The oldest unpaid year.
+    pub year: u32,
+    /// How many of the policy's thresholds the token had crossed.
+    pub level: u32,
+}
+
+/**
+ * @title DelinquencyEscalated
+ * @dev Published when a token is found past more delinquency thresholds than
+ * before.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelinquencyEscalated {
+    #[topic]
+    pub token_id: u64,
+    /// The oldest unpaid year.
+    pub year: u32,
+    pub level: u32,
+    /// Seconds that year's fee is overdue.
+    pub overdue: u64,
+    /// The late fees accrued so far, over every unpaid year.
+    pub late_fee: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Sets the late fee policy, or turns late fees off with None. Admin
+     * only. Fails with InvalidAmount for negative amounts, a rate over
+     * FULL_BPS, a 0 period, or more than MAX_DELINQUENCY_LEVELS thresholds or
+     * thresholds out of order.
+     */
+    pub fn set_late_fee_policy(env: Env, policy: Option<LateFeePolicy>) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "set_late_fee_policy")?;
+
+        let Some(policy) = policy else {
+            env.storage().persistent().remove(&ExtKey2::LateFeePolicy);
+            return Ok(());
+        };
+        if policy.flat < 0
+            || policy.max < 0
+            || Bps::of(policy.rate_bps).is_err()
+            || policy.period == 0
+            || policy.thresholds.len() > MAX_DELINQUENCY_LEVELS
+            || !ascending(&policy.thresholds)
+        {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage()
+            .persistent()
+            .set(&ExtKey2::LateFeePolicy, &policy);
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the late fee policy, if late fees are charged.
+     */
+    pub fn late_fee_policy(env: Env) -> Option<LateFeePolicy> {
+        env.storage().persistent().get(&ExtKey2::LateFeePolicy)
+    }
+
+    /**
+     * @dev Returns the late fees a token owes on top of its unpaid maintenance
+     * fees in this ledger; 0 if it is paid up or nothing is overdue yet.
+     */
+    pub fn late_fee(env: Env, token_id: u64) -> Result<i128, Error> {
+        let status = Self::maintenance_status(env.clone(), token_id)?;
+        Ok(owed(&env, &status).get())
+    }
+
+    /**
+     * @dev Records how many delinquency thresholds a token has crossed since
+     * its oldest unpaid year fell due, publishing DelinquencyEscalated if it is
+     * more than last recorded.
+     * Anyone may call it, e.g. a keeper sweeping delinquent_tokens().
+     * @return The token's level; 0 if it isn't overdue.
+     */
+    pub fn check_delinquency(env: Env, token_id: u64) -> Result<u32, Error> {
+        enter(&env, "check_delinquency")?;
+
+        let status = Self::maintenance_status(env.clone(), token_id)?;
+        if !status.delinquent {
+            return Ok(0);
+        }
+        let oldest = status.paid_through + 1;
+        let (Some(policy), Some((overdue, _))) =
+            (Self::late_fee_policy(env.clone()), accrued(&env, oldest))
+        else {
+            return Ok(0);
+        };
+        let level = policy
+            .thresholds
+            .iter()
+            .filter(|threshold| overdue >= *threshold)
+            .count() as u32;
+        let recorded = Self::delinquency(env.clone(), token_id)
+            .filter(|recorded| recorded.year == oldest)
+            .map_or(0, |recorded| recorded.level);
+        if level > recorded {
+            env.storage().persistent().set(
+                &ExtKey2::Delinquency(token_id),
+                &Delinquency {
+                    year: oldest,
+                    level,
+                },
+            );
+            events::emit(
+                &env,
+                &DelinquencyEscalated {
+                    token_id,
+                    year: oldest,
+                    level,
+                    overdue,
+                    late_fee: owed(&env, &status).get(),
+                },
+            );
+        }
+        Ok(level)
+    }
+
+    /**
+     * @dev Returns the delinquency level last recorded for a token, if any.
+     */
+    pub fn delinquency(env: Env, token_id: u64) -> Option<Delinquency> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::Delinquency(token_id))
+    }
+}
+
+/// The late fees of every unpaid year of a token, each accrued from its own
+/// due date; zero unless it is delinquent.
+pub(crate) fn owed(env: &Env, status: &MaintenanceStatus) -> Amount {
+    let mut total = Amount::ZERO;
+    if status.delinquent {
+        for year in status.paid_through + 1..=status.year {
+            if let Some((_, late_fee)) = accrued(env, year) {
+                total = or_fail(env, total.checked_add(late_fee));
+            }
+        }
+    }
+    total
+}
+
+/// How long `year`'s fee has been overdue and the late fee it has accrued,
+/// if there is a policy and it is overdue. Interest too large to count is
+/// past the cap anyway.
+fn accrued(env: &Env, year: u32) -> Option<(u64, Amount)> {
+    let policy = HotelTimeshareContract::late_fee_policy(env.clone())?;
+    let fee = HotelTimeshareContract::maintenance_schedule(env.clone())?.fee;
+    let due = grace::ends(env, GraceRule::Delinquency, usage::year_start(year));
+    let overdue = env
+        .ledger()
+        .timestamp()
+        .checked_sub(due)
+        .filter(|overdue| *overdue > 0)?;
+    let periods = u32::try_from(overdue / policy.period).unwrap_or(u32::MAX);
+    let max = or_fail(env, Amount::of(policy.max));
+    let late_fee = Amount::of(fee)
+        .and_then(|fee| money::share(fee, Bps::of(policy.rate_bps)?, Amount::ONE))
+        .and_then(|interest| interest.checked_mul(periods))
+        .and_then(|interest| interest.checked_add(Amount::of(policy.flat)?))
+        .map_or(max, |late_fee| late_fee.min(max));
+    Some((overdue, late_fee))
+}
+
+/// Whether each threshold is above the one before it.
+fn ascending(thresholds: &Vec<u64>) -> bool {
+    let mut previous = None;
+    for threshold in thresholds.iter() {
+        if previous.is_some_and(|previous| previous >= threshold) {
+            return false;
+        }
+        previous = Some(threshold);
+    }
+    true
+}
+
+/// Clears a token's delinquency once its arrears are paid, or it is burned.
+pub(crate) fn clear(env: &Env, token_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&ExtKey2::Delinquency(token_id));
+}
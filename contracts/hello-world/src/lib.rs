@@ -4,16 +4,176 @@
 // Import the necessary components from the Soroban SDK.
 use soroban_sdk::{
     contract,       // Macro to define a contract.
+    contracterror,  // Macro to define the contract's error enum.
     contractimpl,   // Macro to implement the contract.
     contracttype,   // Macro to define a custom data type.
     log,            // For logging messages from the contract.
+    panic_with_error, // Macro to fail a call with a contract error.
     Address,        // Soroban's data type for a user/contract address.
+    BytesN,         // Fixed-length byte array, used for hashes.
     Env,            // The contract's environment, gives access to storage, ledger, etc.
     String,         // Soroban's string type.
     Symbol,         // A short, efficient string type.
+    Vec,            // Soroban's growable vector type.
     symbol_short,   // Macro to create a Symbol.
 };
 
+mod adapter;
+mod admin;
+mod alias;
+mod amount;
+mod allowlist;
+mod approvals;
+mod archival;
+mod auction;
+mod branding;
+mod bulk;
+mod burn;
+mod buyback;
+mod capabilities;
+mod chains;
+mod charity;
+mod checkin;
+mod collection;
+mod clawback;
+mod config;
+mod deprecation;
+mod depth;
+mod directory;
+mod dutch;
+mod enumeration;
+mod escrow;
+mod events;
+mod fee_index;
+mod filters;
+mod force_majeure;
+mod freeze;
+mod grace;
+mod hotels;
+mod ids;
+mod intents;
+mod late;
+mod localization;
+mod maintenance;
+mod market;
+mod metadata;
+mod metrics;
+mod migration;
+mod mint_sale;
+mod money;
+mod notifications;
+mod offers;
+mod pause;
+mod pending;
+mod prepay;
+mod privacy;
+mod receipts;
+mod refusal;
+mod reminders;
+mod refunds;
+mod rent;
+mod reserve;
+mod rental;
+mod roles;
+mod royalties;
+mod rooms;
+mod retirement;
+mod sealed;
+mod shares;
+mod sinks;
+mod slots;
+mod splitter;
+mod storage;
+mod supply;
+mod swap;
+mod term;
+mod trust;
+mod ttl;
+mod usage;
+mod valuation;
+mod version;
+mod vouchers;
+mod withholding;
+pub use adapter::*;
+pub use admin::*;
+pub use alias::*;
+pub use amount::*;
+pub use allowlist::*;
+pub use approvals::*;
+pub use archival::*;
+pub use auction::*;
+pub use branding::*;
+pub use bulk::*;
+pub use burn::*;
+pub use buyback::*;
+pub use capabilities::*;
+pub use chains::*;
+pub use charity::*;
+pub use checkin::*;
+pub use collection::*;
+pub use clawback::*;
+pub use config::*;
+pub use deprecation::*;
+pub use depth::*;
+pub use directory::*;
+pub use dutch::*;
+pub use enumeration::*;
+pub use escrow::*;
+pub use events::*;
+pub use fee_index::*;
+pub use filters::*;
+pub use force_majeure::*;
+pub use freeze::*;
+pub use grace::*;
+pub use hotels::*;
+pub use ids::*;
+pub use intents::*;
+pub use late::*;
+pub use localization::*;
+pub use maintenance::*;
+pub use market::*;
+pub use metadata::*;
+pub use metrics::*;
+pub use migration::*;
+pub use mint_sale::*;
+pub use money::*;
+pub use notifications::*;
+pub use offers::*;
+pub use pause::*;
+pub use pending::*;
+pub use prepay::*;
+pub use privacy::*;
+pub use receipts::*;
+pub use refusal::*;
+pub use reminders::*;
+pub use refunds::*;
+pub use rent::*;
+pub use reserve::*;
+pub use rental::*;
+pub use roles::*;
+pub use royalties::*;
+pub use rooms::*;
+pub use retirement::*;
+pub use sealed::*;
+pub use shares::*;
+pub use sinks::*;
+pub use slots::*;
+pub use splitter::*;
+pub use storage::*;
+pub use supply::*;
+pub use swap::*;
+pub use term::*;
+pub use trust::*;
+pub use ttl::*;
+pub use usage::*;
+pub use valuation::*;
+pub use version::*;
+pub use vouchers::*;
+pub use withholding::*;
+
+#[cfg(test)]
+mod test;
+
 // --- 1. DEFINE CUSTOM DATA TYPES ---
 
 /**
@@ -24,9 +184,23 @@ use soroban_sdk::{
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TimeshareInfo {
-    pub hotel: String,  // e.g., "Grand Hotel"
+    pub hotel_id: u32,  // e.g., 1 (the hotel's ID in the registry, see hotels.rs)
     pub room: String,   // e.g., "Room 305"
     pub week: u32,      // e.g., 28 (for the 28th week of the year)
+    pub year: Option<u32>,             // e.g., 2027; None for every year (see term.rs)
+    pub lease_end_ledger: Option<u32>, // Ledger sequence the right expires at; None if it never does
+}
+
+/**
+ * @title Token
+ * @dev A token's details together with its current owner, returned by get_token().
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Token {
+    pub token_id: u64,
+    pub owner: Address,
+    pub info: TimeshareInfo,
 }
 
 /**
@@ -35,18 +209,374 @@ pub struct TimeshareInfo {
  * This is a common pattern in Soroban to avoid "key collisions".
  */
 #[contracttype]
+#[derive(Clone)]
 pub enum DataKey {
     Info(u64),  // Stores the TimeshareInfo for a specific token ID (u64)
     Owner(u64), // Stores the Address of the owner for a specific token ID (u64)
+    NotifyPrefs(Address), // Stores the notification preference bitmask of an address
+    Trust(u64),              // Stores the Trust (trustee, beneficiary) of a token held in trust
+    TrustAck(u64),           // Stores the recipient the beneficiary agreed the token may go to
+    PendingBeneficiary(u64), // Stores a timelocked beneficiary change
+    Alias(Address, BytesN<32>), // Stores the Address an owner's alias (name hash) resolves to
+    ConfirmTransfers(Address), // Stores whether an owner opted into two-phase transfers
+    PendingTransfer(u64),      // Stores a transfer waiting for the recipient's acceptance
+    Sink(Address),             // Flags an Address that must never receive tokens
+    UpgradeHistory,            // Stores the Vec<UpgradeRecord> log of past upgrades
+    EventLane(u32),            // Stores the running EventChain of one event lane (persistent)
+    DailyRoot(u64),            // Stores the EventChain committed for a day number
+    Metrics(u64, Symbol),      // Stores a day's call count (u32) for one endpoint (temporary)
+    Deprecations,              // Stores the Map<Symbol, Deprecation> of deprecated endpoints
+    OwnedTokens(Address),      // Stores the Vec<u64> of token IDs an Address holds
+    Approval(u64),             // Stores the Address approved to transfer a token on the owner's behalf
+    Operator(Address, Address), // Flags an operator (2nd) approved for all of an owner's (1st) tokens
+    DailyLanes(u64),           // Stores the Vec<BytesN<32>> of lane heads committed for a day number
+    MetricsIndex(u64),         // Stores the Vec<Symbol> of endpoints counted on a day (temporary)
+    Minter(Address),           // Stores a minter's MinterState (ID block, mint count)
+    Minters,                   // Stores the Vec<Address> of everyone who has minted
+    Burned,                    // Stores the number of tokens burned so far (u64)
+    RentPolicy,                // Stores the rent pool's RentPolicy
+    RentBalance(Address),      // Stores an owner's unused rent pool balance (i128)
+    RentDue(Address, u32),     // Stores the ledger an owner's portfolio page is next due for a paid bump (temporary)
+    Retirement(u32),           // Stores the Retirement record of a retired property (hotel ID)
+    PendingAdmin,              // Stores the Address proposed as the next admin
+    Role(Role, Address),       // Flags an Address granted a Role
+    Slot(BytesN<32>),          // Stores the token ID minted for a (hotel, room, week, year) slot hash
+    Hotel(u32),                // Stores a registered Hotel
+    HotelName(String),         // Stores the ID of the hotel registered under a name
+    HotelCount,                // Stores the number of hotels registered so far (u32)
+    Room(u32, String),         // Stores whether a room of a hotel is in service (bool; false once retired)
+    Rooms(u32),                // Stores the Vec<String> of a hotel's rooms in service
+    Usage(u64),                // Stores a token's Usage record (check-in state) for the latest year it was used
+    Rental(u64),               // Stores a token's Rental listing or active rental
+    MaintenanceSchedule,       // Stores the MaintenanceSchedule of annual fees
+    Maintenance(u64),          // Stores the last year a token's maintenance fee is paid through (u32)
+    Frozen(u64),               // Flags a token frozen by the admin
+    Clawback(u64),             // Stores the ClawbackRecord of a token's last clawback (kept after a burn)
+    Listing(u64),              // Stores a token's marketplace Listing
+    Auction(u64),              // Stores a token's running Auction and its escrowed high bid
+    DutchAuction(u64),         // Stores a token's declining-price DutchAuction
+    Offer(u64, Address),       // Stores a buyer's escrowed Offer for a token
+    Trade(u64),                // Stores the open Trade of a token escrowed by the contract
+    Royalty(u32),              // Stores a hotel's Royalty on resales
+    Fraction(u64),             // Stores how many shares a fractionalized token was split into
+    Shares(u64, Address),      // Stores an address's ShareBalance of a fractionalized token
+}
+
+/**
+ * @title ExtKey
+ * @dev More storage keys, in the same role as DataKey. The contract spec caps
+ * an enum at 50 cases and DataKey is full, so newer keys are added here.
+ */
+#[contracttype]
+#[derive(Clone)]
+pub enum ExtKey {
+    VoucherSigner,             // Stores the ed25519 public key mint vouchers are signed with
+    Voucher(BytesN<32>),       // Stores the token minted for a redeemed voucher, keyed by its hash
+    Allowlist,                 // Stores the open Allowlist sale phase
+    AllowlistClaim(BytesN<32>, Address), // Flags an address that minted in the allowlist phase with a root
+    TokenUri(u64),             // Stores the URI of a token's off-chain metadata
+    MetadataFrozen(u64),       // Flags a token whose info and URI can no longer change
+    MintPrice(u32),            // Stores the MintPrice of a hotel's unsold weeks (hotel ID)
+    Payees,                    // Stores the Vec<(Address, u32)> revenue split, shares in basis points
+    Payable(Address, Address), // Stores what a payee (1st) is owed in a payment token (2nd), as i128
+    CoolingOff,                // Stores the CoolingOff terms of new primary sales
+    Purchase(u64),             // Stores a primary sale's Purchase while its proceeds are escrowed
+    SupplyCap(u32),            // Stores the most live tokens a hotel may have (u32)
+    HotelSupply(u32),          // Stores how many live tokens a hotel has (u32)
+    ShareRound(u64),           // Stores how many times a token has been fractionalized (u32; kept after a burn)
+    MetricsLane(u64, Symbol, u32), // Stores a day's call count (u32) for one endpoint in one metrics lane past 0 (temporary)
+    YearClaims(BytesN<32>),    // Stores how many years of a room-week are claimed (u32), keyed by its perpetual slot hash
+    BillingSince,              // Stores the year maintenance billing last started (u32)
+    CappedHotels,              // Stores the IDs of hotels with a supply cap (Vec<u32>)
+    SupplyRecount,             // Stores the last token ID a running supply recount has counted (u64)
+    ReserveShare(u32),         // Stores the share of a hotel's maintenance fees held in its reserve (u32 basis points)
+    Reserve(u32),              // Stores a hotel's ReserveFund
+    ReserveSpend(u32, u32),    // Stores a SpendProposal of a hotel's reserve (hotel ID, proposal ID)
+    ReserveHotels,             // Stores the IDs of hotels a reserve share was set for (Vec<u32>)
+    Directory(u32),            // Stores the owners listed in a hotel's directory, in joining order (Vec<Address>)
+    DirectoryEntry(u32, Address), // Stores an owner's DirectoryEntry in a hotel's directory
+    CheckinKey(u64),           // Stores the CheckinKey a token's QR check-in codes are signed with
+    ReceiptsEnabled,           // Flags that sales and trades are recorded as receipts
+    Receipt(u64, u32),         // Stores a token's Receipt (token ID, receipt number)
+    ReceiptCount(u64),         // Stores how many receipts a token has (u32; kept after a burn)
+    PartyReceipt(Address, u32), // Stores a buyer's or seller's n-th receipt as (token ID, receipt number)
+    PartyReceiptCount(Address), // Stores how many receipts name an address as buyer or seller (u32)
+    Withholding(u32),          // Stores the WithholdingRule of a hotel's resales
+    WithholdingExempt(Address, Symbol), // Stores the timestamp a seller's exemption for a jurisdiction is valid through (u64)
+    Withheld(Address, u32, Address), // Stores what was withheld from a seller (1st) in a year (2nd) in a payment token (3rd), as i128
+    MinorUnits(u32),           // Stores a hotel's minor units by payment asset (Map<Address, i128>)
+    SealedAuction(u64),        // Stores a token's running SealedAuction
+    SealedBid(u64, Address),   // Stores a bidder's unrevealed commitment in a token's sealed auction (BytesN<32>)
+    HeldShares(Address),       // Stores the IDs of the tokens an address holds or held shares of (Vec<u64>)
+    PriceMark(u64),            // Stores the PriceMark of a token's sales
+    Appraisal(u64),            // Stores a manager's Appraisal of a token
+    Tier(u64),                 // Stores a token's tier (Symbol)
+    CollectionOffer(u32),      // Stores an open CollectionOffer (offer ID)
+    CollectionOfferCount,      // Stores how many collection offers have been made (u32)
+    TierIndex(u32, Symbol),    // Stores the IDs of a hotel's tokens of a tier (Vec<u64>)
+    WeekIndex(u32),            // Stores the IDs of the tokens of a week of the year (Vec<u64>)
+    SwapChain(u32),            // Stores an open SwapChain (chain ID)
+    SwapChainCount,            // Stores how many swap chains have been proposed (u32)
+    ChainOf(u64),              // Stores the ID of the swap chain a token is escrowed for (u32)
+    SwapIntent(u64),           // Stores a token's open SwapIntent
+    SwapIntents,               // Stores the IDs of the tokens with open swap intents, oldest first (Vec<u64>)
+}
+
+/**
+ * @title ExtKey2
+ * @dev Yet more storage keys, in the same role as DataKey and ExtKey, which
+ * are both full.
+ */
+#[contracttype]
+#[derive(Clone)]
+pub enum ExtKey2 {
+    Branding(u32),             // Stores a hotel's Branding
+    Labels(Symbol),            // Stores a locale's labels (Map<LabelCode, String>)
+    Locales,                   // Stores the locales that have labels (Vec<Symbol>)
+    PrepayDiscounts,           // Stores the Vec<u32> prepayment discount curve, in basis points by term
+    Prepaid(u64, u32),         // Stores the Prepayment held for one year of a token (token ID, year)
+    PrepaidYears(u64),         // Stores the PrepaidYears of a token whose fees the contract holds
+    LateFeePolicy,             // Stores the LateFeePolicy of overdue maintenance fees
+    Delinquency(u64),          // Stores the Delinquency level a keeper last recorded for a token
+    GracePeriods,              // Stores the grace periods changed from their defaults (Map<GraceRule, u64>)
+    PendingGrace(GraceRule),   // Stores a timelocked PendingGracePeriod
+    DepthBook(u32, Option<Symbol>, Address), // Stores the DepthBook of listings (hotel ID, tier, asset)
+    Buyback(u32, Symbol),      // Stores the Buyback open for a tier (hotel ID, tier)
+    Buybacks,                  // Stores the open buybacks (Vec<(u32, Symbol)>)
+    FirstRefusalHotels,        // Stores the IDs of properties with a hotel right of first refusal (Vec<u32>)
+    FirstRefusal(u64),         // Stores the FirstRefusal of a token in custody
+    OperatorUntil(Address, Address), // Stores the last ledger an approve_for_all operator (2nd) of an owner (1st) is approved through (u32)
+    Charity(Address),          // Stores the name (String) a charity is registered under
+    CharitySplit(u32),         // Stores the Vec<(Address, u32)> charity split of a hotel's royalties, in basis points
+    Donated(Address, u32, Address), // Stores what a charity (1st) was donated in a year (2nd) in a payment token (3rd), as i128
+    HotelDonated(u32, u32, Address), // Stores what a hotel (1st) donated of its royalties in a year (2nd) in a payment token (3rd), as i128
+    FeeIndex,                  // Stores the FeeIndex the maintenance fee follows
+    FeeIndexedYear,            // Stores the last year the maintenance fee was repriced from its index (u32)
+    FeeBilledYear,             // Stores the last year maintenance fees were collected in (u32)
+    ForceMajeure(u32, u32, u32), // Stores the ForceMajeureFlag closing a week (hotel ID, year, week)
+    ForceMajeureSettled(u64),  // Stores the last year a token was settled for force majeure (u32)
+    ReliefFund(u32, Address),  // Stores a hotel's relief fund balance in a payment token (i128)
+    ReplacementCredits(Address, u32), // Stores the replacement stays a hotel (2nd) owes an owner (1st), as u32
+    Reminded(u64),             // Stores the deadline last reminded of a token, by kind (Map<DeadlineKind, u64>)
+    StatsPrivacy,              // Stores the StatsPrivacy of the public stats endpoints
+}
+
+/**
+ * @title Error
+ * @dev Every way a call can fail. Endpoints return Result<T, Error> so that
+ * client SDKs can tell failure causes apart by code instead of parsing panics.
+ * Codes are part of the public interface: never renumber, only append.
+ * The contract spec caps an error enum at 50 cases, and this one is full:
+ * new failures go in ExtError.
+ */
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    // Setup
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    // Tokens
+    TokenNotFound = 10,
+    NotOwner = 11,
+    InvalidRecipient = 12, // The recipient is the contract, a burn address, or a flagged sink.
+    // Two-phase transfers
+    NoPendingTransfer = 20,
+    PendingTransferExpired = 21,
+    // Trusts
+    AlreadyInTrust = 30,
+    NotInTrust = 31,
+    NotTrustee = 32,
+    NotBeneficiary = 33,
+    TransferNotAcknowledged = 34,
+    NoPendingBeneficiary = 35,
+    TimelockNotElapsed = 36,
+    // Aliases
+    UnknownAlias = 40,
+    // Notification preferences
+    UnknownNotificationBits = 50,
+    // Daily event roots
+    DailyRootAlreadyCommitted = 60,
+    // Deprecation
+    EndpointSunset = 70,
+    // Approvals
+    NotApproved = 80,
+    // Batches
+    BatchTooLarge = 90,
+    // ID allocation
+    TokenIdCollision = 100,
+    // Rent pool
+    RentPoolNotConfigured = 110,
+    InvalidRentPolicy = 111,
+    InvalidAmount = 112,
+    InsufficientRentBalance = 113,
+    RentNotDue = 114,
+    // Property retirement
+    PropertyRetired = 120,
+    AlreadyRetired = 121,
+    NotRetired = 122,
+    RedemptionWindowClosed = 123,
+    RedemptionWindowOpen = 124,
+    RedemptionUnderfunded = 125,
+    WrongProperty = 126, // A swept token belongs to another property.
+    // Admin handover
+    NoPendingAdmin = 130,
+    // Roles
+    MissingRole = 140,
+    // Pause
+    ContractPaused = 150, // The contract is paused.
+    // Timeshare validation
+    InvalidWeek = 160, // The week is outside 1..=52.
+    DuplicateTimeshare = 161, // A token for the same hotel, room, week and year already exists.
+    InvalidLeaseTerm = 162, // Year 0, or a lease that has already ended.
+    LeaseExpired = 163,
+    // Hotel registry
+    HotelNotFound = 170,
+    DuplicateHotel = 171, // Another hotel is registered under the name.
+    // Room inventory
+    RoomNotFound = 180,
+    DuplicateRoom = 181,
+    RoomRetired = 182,
+    // Check-in
+    InvalidUsageState = 190, // The week isn't in the state the call requires (see usage.rs).
+    CheckInNotConfirmed = 191,
+    // Swaps
+    InvalidSwap = 200, // Both sides of a swap are the same owner or token.
+    // Listings
+    NotListed = 210,
+    ListingClosed = 211, // The listing was already taken, or is past its window.
+}
+
+/**
+ * @title ExtError
+ * @dev More ways a call can fail, with codes of their own after Error's. A
+ * function can only return one error type, so endpoints keep returning
+ * Error and raise these with fail(): the host reports a raised code exactly
+ * like a returned one, and clients look both enums up by code. It is full
+ * too: newer failures go in ExtError2.
+ */
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExtError {
+    // Supply caps
+    SupplyCapReached = 300,
+    // English auctions
+    NoAuction = 310,
+    AuctionRunning = 311, // Bidding is still open, or bids were placed.
+    AuctionEnded = 312,
+    // Offers
+    NoOffer = 320,
+    OfferExpired = 321,
+    // Trades
+    NoTrade = 330,
+    // Fractional shares
+    NotFractionalized = 340,
+    InsufficientShares = 341,
+    // Vouchers
+    NoVoucherSigner = 350,
+    VoucherExpired = 351,
+    VoucherRedeemed = 352,
+    WrongBuyer = 353, // The voucher names another buyer.
+    // Allowlist
+    NoAllowlist = 360,
+    NotAllowlisted = 361, // The Merkle proof doesn't lead to the root.
+    AllowlistClaimed = 362,
+    // Metadata
+    MetadataFrozen = 370,
+    // Revenue splits
+    TooManyPayees = 380,
+    InvalidSplit = 381, // The payees' shares don't add up to SPLIT_TOTAL_BPS.
+    // Cooling-off
+    NoPurchase = 390,
+    CoolingOffEnded = 391,
+    CoolingOffRunning = 392,
+    // Token freezes
+    TokenFrozen = 400,
+    // Rentals
+    RentalActive = 410, // The week is rented out this year and the renter hasn't stayed yet.
+    // Reserve funds
+    InsufficientReserve = 420, // The reserve, less pending proposals, can't cover the amount.
+    NoSpendProposal = 421,     // No pending spend proposal has that ID.
+    // QR check-in
+    NoCheckinKey = 430,
+    CheckinCodeExpired = 431, // Past its expiry, or for another year.
+    CheckinCodeUsed = 432,    // Its nonce isn't higher than the last one used.
+    // Payment math
+    AmountOverflow = 440,
+    NegativeAmount = 441,   // An amount, or what is left of one, would be below 0.
+    InvalidBps = 442,       // A share above FULL_BPS, or a split with no shares.
+    InvalidMinorUnit = 443, // A minor unit of 0.
+    // Sealed auctions
+    NoCommitment = 450,       // The bidder has no unrevealed commitment in the auction.
+    CommitmentMismatch = 451, // The revealed bid and salt don't hash to the commitment.
+    // Collection offers
+    NoCollectionOffer = 460,
+    CriteriaMismatch = 461,   // The token doesn't meet the offer's criteria.
+    // Swap chains
+    NoSwapChain = 470,
+    // Swap intents
+    NoSwapIntent = 480,
+    IntentBookFull = 481,     // MAX_SWAP_INTENTS intents are open.
+    // Branding
+    InvalidBranding = 490,    // Too many colors, or one above 0xFFFFFF.
+    // Localization
+    LabelTooLong = 500,       // A label is over MAX_LABEL_LEN bytes.
+    TooManyLabels = 501,      // The locale would have more than MAX_LABELS labels.
+    // Prepaid maintenance
+    MaintenanceArrears = 510, // Years before the current one are owed; pay them first.
+    // Grace periods
+    NoPendingGracePeriod = 520,
+    // Buybacks
+    NoBuyback = 530,
+    InvalidBuyback = 531,     // A price that isn't positive, an empty window, or another payment token.
+    BuybackNotOpen = 532,     // Outside the buyback's window.
+    BuybackExhausted = 533,   // The budget can't pay the price.
+    TooManyBuybacks = 534,    // MAX_BUYBACKS buybacks are open.
+}
+
+/**
+ * @title ExtError2
+ * @dev Failures added once ExtError was full, raised with fail() like it,
+ * with codes after ExtError's.
+ */
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExtError2 {
+    // Right of first refusal
+    NoFirstRefusal = 540,
+    FirstRefusalOpen = 541,   // The hotel can still match the offer.
+    FirstRefusalLapsed = 542, // The hotel can no longer match the offer.
+    // Charity donations
+    NotCharity = 550,         // The address isn't a registered charity.
+    DuplicateCharity = 551,   // The split names a charity twice.
+    // Index-linked fees
+    NoFeeIndex = 560,
+    FeeAlreadyIndexed = 561,  // This year's fee was already repriced from the index.
+    StaleIndex = 562,         // The oracle has no positive reading within the index's max age.
+    FeeIndexed = 563,         // The fee follows an index and can't be set by hand.
+    FeeYearBilled = 564,      // This year's fees are being collected; the fee is fixed until the next.
+    // Force majeure
+    NotForceMajeure = 570,    // The week isn't flagged for force majeure.
+    WeekClosed = 571,         // The week is closed for force majeure.
+    AlreadySettled = 572,     // The token was already settled for this year's closure.
+    ReliefUnfunded = 573,     // The relief fund can't cover the amount.
+    NoReplacementCredit = 574,
 }
 
 // --- 2. DEFINE CONSTANT STORAGE KEYS ---
 
 // A key for storing the Address of the contract administrator (the "hotel admin").
 const ADMIN: Symbol = symbol_short!("ADMIN");
-// A key for storing a counter that generates unique token IDs.
+// A key for storing the highest token ID reserved so far (see ids.rs).
+// It lives in persistent storage, outside the read-mostly instance (see storage.rs).
 const COUNTER: Symbol = symbol_short!("COUNTER");
 
+// The most tokens mint_batch() accepts at once, to stay well within per-transaction resource limits.
+// Each mint also writes its week's filter index (see filters.rs), which costs a 100-token batch its headroom.
+pub const MAX_MINT_BATCH: u32 = 80;
+
 
 // --- 3. DEFINE THE CONTRACT ---
 
@@ -68,6 +598,7 @@ pub struct HotelTimeshareContract;
  * 2. mint: Creates a new timeshare token (Admin only).
  * 3. transfer: Sends a token to a new owner (Owner only).
  * 4. get_info: Lets anyone see the details of a token.
+ * get_owner and get_token complete the read side; mint_batch mints in bulk.
  */
 #[contractimpl]
 impl HotelTimeshareContract {
@@ -77,56 +608,99 @@ impl HotelTimeshareContract {
      * This function should only be called ONCE when the contract is deployed.
      * @param admin The address of the person/account who will be the "hotel admin".
      */
-    pub fn initialize(env: Env, admin: Address) {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
         // We check if the ADMIN key already exists in storage.
-        // If it does, it means initialize() was already run, so we panic.
+        // If it does, it means initialize() was already run, so we fail.
         if env.storage().instance().has(&ADMIN) {
-            panic!("Contract already initialized");
+            return Err(Error::AlreadyInitialized);
         }
+        enter(&env, "initialize")?;
 
         // 1. Store the admin address in instance storage.
         env.storage().instance().set(&ADMIN, &admin);
         // 2. Initialize the token ID counter at 0.
-        env.storage().instance().set(&COUNTER, &0u64);
+        storage::set_shared(&env, &COUNTER, &0u64);
+        // 3. Record the storage layout this build writes (see migration.rs).
+        env.storage().instance().set(&migration::SCHEMA, &SCHEMA_VERSION);
+        Ok(())
     }
 
     /**
-     * @dev Mints a new timeshare token and assigns it to an owner.
+     * @dev Mints a new timeshare token and assigns it to an owner. Each week of
+     * a room is minted once (see slots.rs), up to the hotel's supply cap (see
+     * supply.rs).
      * Only the contract ADMIN can call this function.
      * @param to The address that will receive the new token.
-     * @param hotel The name of the hotel.
-     * @param room The room number.
+     * @param hotel_id The hotel's ID in the registry (see hotels.rs).
+     * @param room The room, from the hotel's inventory (see rooms.rs).
      * @param week The week of the year (1-52).
+     * @param year The year the right starts in, or None for every year.
+     * @param lease_end_ledger The ledger sequence the right expires at, or None.
      * @return The unique token ID of the newly minted timeshare.
      */
-    pub fn mint(env: Env, to: Address, hotel: String, room: String, week: u32) -> u64 {
-        // 1. Load the admin address from storage.
-        let admin: Address = env.storage().instance().get(&ADMIN).unwrap();
-        // 2. This is the Soroban way to check authentication:
-        // It requires that the 'admin' address has signed this transaction.
-        admin.require_auth();
-
-        // 3. Get the current token ID counter and increment it.
-        let mut token_id: u64 = env.storage().instance().get(&COUNTER).unwrap();
-        token_id += 1;
-
-        // 4. Create the TimeshareInfo struct with the provided data.
-        let info = TimeshareInfo { hotel, room, week };
-
-        // 5. Store the new data using our DataKey enum.
-        // Store the info (Hotel, Room, Week)
-        env.storage().instance().set(&DataKey::Info(token_id), &info);
-        // Store the owner
-        env.storage().instance().set(&DataKey::Owner(token_id), &to);
-
-        // 6. Save the new, incremented counter back to storage.
-        env.storage().instance().set(&COUNTER, &token_id);
-
-        // 7. Log a message (visible in the blockchain explorer).
-        log!(&env, "Minted timeshare #{} for {}", token_id, to);
-
-        // 8. Return the new token ID.
-        token_id
+    pub fn mint(
+        env: Env,
+        to: Address,
+        hotel_id: u32,
+        room: String,
+        week: u32,
+        year: Option<u32>,
+        lease_end_ledger: Option<u32>,
+    ) -> Result<u64, Error> {
+        // 1. Load the admin address from storage and require its signature.
+        let admin = require_admin(&env)?;
+        enter_by(&env, "mint", &admin)?;
+
+        // 2. Draw the next token ID from the minter's ID block.
+        let token_id = ids::allocate(&env, &admin, 1)?.get_unchecked(0);
+
+        // 3. Create the TimeshareInfo struct with the provided data.
+        let info = TimeshareInfo { hotel_id, room, week, year, lease_end_ledger };
+
+        // 4. Store the new token, log it and publish the mint event.
+        issue_token(&env, token_id, &to, info)?;
+
+        // 5. Return the new token ID.
+        Ok(token_id)
+    }
+
+    /**
+     * @dev Mints several tokens to one owner under a single admin signature,
+     * e.g. when onboarding every week of a room.
+     * @param to The address that will receive the new tokens.
+     * @param infos The details of each token, at most MAX_MINT_BATCH of them.
+     * @return The new token IDs, in the order of `infos`. They increase but need not be consecutive.
+     */
+    pub fn mint_batch(env: Env, to: Address, infos: Vec<TimeshareInfo>) -> Result<Vec<u64>, Error> {
+        // 1. Same authorization as mint, once for the whole batch.
+        let admin = require_admin(&env)?;
+        enter_by(&env, "mint_batch", &admin)?;
+        if infos.len() > MAX_MINT_BATCH {
+            return Err(Error::BatchTooLarge);
+        }
+
+        // 2. Draw all the IDs at once, then issue one token per ID.
+        let token_ids = ids::allocate(&env, &admin, infos.len())?;
+        for (token_id, info) in token_ids.iter().zip(infos.iter()) {
+            issue_token(&env, token_id, &to, info)?;
+        }
+        Ok(token_ids)
+    }
+
+    /**
+     * @dev Mints a new timeshare token on behalf of a holder of the Minter role
+     * (see roles.rs), e.g. a front-desk system. IDs come from the minter's own block.
+     * @param minter The minter (must sign).
+     * @param info The token's details, as for mint().
+     * @return The unique token ID of the newly minted timeshare.
+     */
+    pub fn mint_by(env: Env, minter: Address, to: Address, info: TimeshareInfo) -> Result<u64, Error> {
+        roles::require_role(&env, Role::Minter, &minter)?;
+        enter_by(&env, "mint_by", &minter)?;
+
+        let token_id = ids::allocate(&env, &minter, 1)?.get_unchecked(0);
+        issue_token(&env, token_id, &to, info)?;
+        Ok(token_id)
     }
 
     /**
@@ -136,45 +710,254 @@ impl HotelTimeshareContract {
      * @param to The new owner's address.
      * @param token_id The ID of the token to transfer.
      */
-    pub fn transfer(env: Env, from: Address, to: Address, token_id: u64) {
-        // 1. Require authorization from the 'from' address.
-        // This ensures the person calling this is who they say they are.
-        from.require_auth();
-
-        // 2. Get the key for this token's owner.
-        let owner_key = DataKey::Owner(token_id);
-
-        // 3. Check that the token exists.
-        if !env.storage().instance().has(&owner_key) {
-             panic!("Token does not exist");
-        }
+    pub fn transfer(env: Env, from: Address, to: Address, token_id: u64) -> Result<(), Error> {
+        enter(&env, "transfer")?;
+        transfer_token(&env, &from, &to, token_id)
+    }
 
-        // 4. Load the current owner from storage.
-        let current_owner: Address = env.storage().instance().get(&owner_key).unwrap();
+    /**
+     * @dev A public, read-only function to get the details of a timeshare.
+     * Anyone can call this function without authentication.
+     * @param token_id The ID of the token to query.
+     * @return The TimeshareInfo struct (hotel, room, week and term). The term
+     * may have ended; see is_active().
+     */
+    pub fn get_info(env: Env, token_id: u64) -> Result<TimeshareInfo, Error> {
+        // A missing entry means the token_id was never minted.
+        let info = migration::read_info(&env, token_id).ok_or(Error::TokenNotFound)?;
 
-        // 5. Verify that the 'from' address is indeed the 'current_owner'.
-        if current_owner != from {
-            panic!("'from' address is not the owner");
+        // Reading a token keeps it alive (see ttl.rs), unless its lease has ended.
+        if !term::expired(&env, &info) {
+            ttl::touch(&env, token_id);
         }
+        Ok(info)
+    }
 
-        // 6. If all checks pass, set the new owner.
-        env.storage().instance().set(&owner_key, &to);
-
-        // 7. Log the transfer.
-        log!(&env, "Transferred token #{} from {} to {}", token_id, from, to);
+    /**
+     * @dev A public, read-only function to get the current owner of a timeshare.
+     * @param token_id The ID of the token to query.
+     * @return The owner's Address.
+     */
+    pub fn get_owner(env: Env, token_id: u64) -> Result<Address, Error> {
+        owner_of(&env, token_id)
     }
 
     /**
-     * @dev A public, read-only function to get the details of a timeshare.
-     * Anyone can call this function without authentication.
+     * @dev Returns a token's details and its owner in a single call.
      * @param token_id The ID of the token to query.
-     * @return The TimeshareInfo struct (hotel, room, week).
+     * @return The Token struct (token_id, owner, info).
      */
-    pub fn get_info(env: Env, token_id: u64) -> TimeshareInfo {
-        let info_key = DataKey::Info(token_id);
+    pub fn get_token(env: Env, token_id: u64) -> Result<Token, Error> {
+        Ok(Token {
+            token_id,
+            owner: owner_of(&env, token_id)?,
+            info: Self::get_info(env, token_id)?,
+        })
+    }
+}
+
+
+// --- 5. INTERNAL HELPERS ---
+
+/**
+ * @dev Bookkeeping every state-changing endpoint starts with: refuses endpoints
+ * past their sunset (see deprecation.rs) and counts the call (see metrics.rs).
+ * @param endpoint The endpoint's name, exactly as exported.
+ */
+pub(crate) fn enter(env: &Env, endpoint: &str) -> Result<(), Error> {
+    enter_lane(env, endpoint, 0)
+}
+
+/**
+ * @dev enter() for the mint paths: counts the call in the signer's metrics
+ * lane, so concurrent mints don't all bump one counter (see metrics.rs).
+ * @param signer The address whose signature the call carries.
+ */
+pub(crate) fn enter_by(env: &Env, endpoint: &str, signer: &Address) -> Result<(), Error> {
+    enter_lane(env, endpoint, metrics::lane(env, signer))
+}
+
+fn enter_lane(env: &Env, endpoint: &str, lane: u32) -> Result<(), Error> {
+    let endpoint = Symbol::new(env, endpoint);
+    deprecation::check(env, &endpoint)?;
+    metrics::record(env, &endpoint, lane);
+    Ok(())
+}
 
-        // .unwrap() will panic if the token_id doesn't exist,
-        // which is the correct behavior (it can't return info that isn't there).
-        env.storage().instance().get(&info_key).unwrap()
+/**
+ * @dev Fails the call with an ExtError or ExtError2 code.
+ */
+pub(crate) fn fail(env: &Env, error: impl Into<soroban_sdk::Error>) -> ! {
+    panic_with_error!(env, error)
+}
+
+/**
+ * @dev Unwraps a checked result, failing the call with its ExtError code.
+ */
+pub(crate) fn or_fail<T>(env: &Env, result: Result<T, ExtError>) -> T {
+    result.unwrap_or_else(|error| fail(env, error))
+}
+
+/**
+ * @dev Loads the admin address and requires that it has signed this transaction.
+ * @return The admin address.
+ */
+pub(crate) fn require_admin(env: &Env) -> Result<Address, Error> {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&ADMIN)
+        .ok_or(Error::NotInitialized)?;
+    admin.require_auth();
+    Ok(admin)
+}
+
+/**
+ * @dev Loads the current owner of a token.
+ * @return The owner's address, or TokenNotFound.
+ */
+pub(crate) fn owner_of(env: &Env, token_id: u64) -> Result<Address, Error> {
+    migration::read(env, &DataKey::Owner(token_id)).ok_or(Error::TokenNotFound)
+}
+
+/**
+ * @dev Fails unless `claimed` is the current owner of an existing token.
+ */
+pub(crate) fn check_owner(env: &Env, token_id: u64, claimed: &Address) -> Result<(), Error> {
+    if owner_of(env, token_id)? != *claimed {
+        return Err(Error::NotOwner);
     }
-}
\ No newline at end of file
+    Ok(())
+}
+
+/**
+ * @dev The owner-initiated transfer behind transfer() and transfer_to_alias().
+ */
+pub(crate) fn transfer_token(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    token_id: u64,
+) -> Result<(), Error> {
+    // 1. Require authorization from the 'from' address.
+    // This ensures the person calling this is who they say they are.
+    from.require_auth();
+
+    // 2. Verify that the token exists and 'from' is its current owner.
+    check_owner(env, token_id, from)?;
+
+    // 3. Refuse recipients that would brick the deed (see force_transfer).
+    sinks::check_recipient(env, to)?;
+
+    // 4. Owners who opted into two-phase transfers only create a pending
+    // transfer here; the recipient completes it with accept_transfer().
+    if pending::confirmation_required(env, from) {
+        pending::propose(env, token_id, from, to);
+        return Ok(());
+    }
+
+    // 5. If all checks pass, move the token.
+    move_token(env, from, to, token_id)
+}
+
+/**
+ * @dev Stores a freshly minted token under `token_id`, indexes it under its
+ * owner, and publishes the mint event. `token_id` comes from ids::allocate().
+ */
+fn issue_token(env: &Env, token_id: u64, to: &Address, info: TimeshareInfo) -> Result<(), Error> {
+    // Nothing is minted while paused, nor for unknown or retired properties
+    // and rooms.
+    pause::check(env)?;
+    hotels::check(env, info.hotel_id)?;
+    retirement::check_active(env, info.hotel_id)?;
+    rooms::check(env, info.hotel_id, &info.room)?;
+    // Each week of a room is sold at most once per year, with a valid term.
+    term::validate(env, &info)?;
+    slots::claim(env, token_id, &info)?;
+    // Nor is any hotel sold past its supply cap.
+    supply::record_mint(env, token_id, info.hotel_id)?;
+
+    // Store the info (Hotel, Room, Week, term)
+    env.storage().persistent().set(&DataKey::Info(token_id), &info);
+    // Store the owner
+    env.storage().persistent().set(&DataKey::Owner(token_id), to);
+    enumeration::add(env, to, token_id);
+    filters::index(env, token_id, &info, &None);
+    // It owes maintenance from this year on.
+    maintenance::open(env, token_id);
+    ttl::touch(env, token_id);
+
+    // Log a message and publish the mint event for indexers.
+    log!(env, "Minted timeshare #{} for {}", token_id, to);
+    events::emit(env, &Mint { token_id, owner: to.clone(), info });
+    Ok(())
+}
+
+/**
+ * @dev Reassigns ownership of a token whose caller-specific checks (signatures,
+ * approvals) have already passed. Every path that changes a token's owner goes
+ * through here (only clawback skips the checks, calling reassign directly), so
+ * that per-token bookkeeping is cleaned up consistently. Fails with NotOwner
+ * unless `from` still owns the token, so a stale listing or record can never
+ * move it on someone else's behalf.
+ */
+pub(crate) fn move_token(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    token_id: u64,
+) -> Result<(), Error> {
+    // 1. Only the current owner's token moves. Nothing moves while paused,
+    // frozen tokens and tokens of retired properties and expired leases stay
+    // put, and tokens held in trust need the beneficiary's acknowledgment.
+    check_owner(env, token_id, from)?;
+    pause::check(env)?;
+    freeze::check(env, token_id);
+    retirement::check_token(env, token_id)?;
+    term::check_token(env, token_id)?;
+    trust::before_transfer(env, token_id, to)?;
+
+    reassign(env, from, to, token_id);
+    Ok(())
+}
+
+/**
+ * @dev The bookkeeping half of move_token, with none of its checks.
+ */
+pub(crate) fn reassign(env: &Env, from: &Address, to: &Address, token_id: u64) {
+    // 2. Any pending two-phase transfer is superseded, and approvals, sale
+    // listings, auctions (refunding the high bid) and rental listings given
+    // by the old owner lapse. A token leaving the contract's custody closes
+    // the trade or fractionalization it was held for, and drops it from its
+    // swap chain or intent; one held for the hotel's right of first refusal
+    // refunds the buyer.
+    pending::clear(env, token_id);
+    approvals::clear(env, token_id);
+    market::clear(env, token_id);
+    auction::lapse(env, token_id);
+    sealed::lapse(env, token_id);
+    dutch::clear(env, token_id);
+    rental::clear_listing(env, token_id);
+    escrow::remove_trade(env, token_id);
+    refusal::lapse(env, token_id);
+    chains::clear(env, token_id);
+    intents::clear(env, token_id);
+    shares::clear(env, token_id);
+
+    // 3. Set the new owner and update both owners' token lists.
+    env.storage().persistent().set(&DataKey::Owner(token_id), to);
+    enumeration::remove(env, from, token_id);
+    enumeration::add(env, to, token_id);
+    ttl::touch(env, token_id);
+
+    // 4. Log the transfer and publish the transfer event for indexers.
+    log!(env, "Transferred token #{} from {} to {}", token_id, from, to);
+    events::emit(
+        env,
+        &Transfer {
+            token_id,
+            from: from.clone(),
+            to: to.clone(),
+        },
+    );
+}
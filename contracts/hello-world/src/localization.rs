@@ -0,0 +1,137 @@
+// Localized labels.
+//
+// Lightweight frontends without an i18n pipeline of their own still need to
+// show "Platinum" in Spanish or explain error 460 in German. The admin keeps
+// a table of short labels per locale (e.g. `es`, `de_CH`) with `set_labels`,
+// keyed by LabelCode: a tier (see metadata.rs), an error code, or a code the
+// frontends define themselves, such as a season or an amenity the contract
+// doesn't model. `labels` returns a locale's whole table in one read.
+//
+// A locale holds at most MAX_LABELS labels of at most MAX_LABEL_LEN bytes, so
+// its table stays one small entry. Setting a label to the empty string
+// removes it, and a locale whose last label goes is dropped from `locales`.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Env, Map, String, Symbol, Vec};
+
+use crate::{
+    enter, events, fail, require_admin, storage, Error, ExtError, ExtKey2, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/// Most labels a locale can have.
+pub const MAX_LABELS: u32 = 200;
+/// Longest label, in bytes.
+pub const MAX_LABEL_LEN: u32 = 64;
+
+/**
+ * @title LabelCode
+ * @dev What a label names.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LabelCode {
+    Tier(Symbol),
+    /// An Error or ExtError code.
+    ErrorCode(u32),
+    /// A code frontends agree on, e.g. `season_high` or `amenity_pool`.
+    Custom(Symbol),
+}
+
+/**
+ * @title LabelsSet
+ * @dev Published when the admin changes a locale's labels.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LabelsSet {
+    #[topic]
+    pub locale: Symbol,
+    /// How many labels the locale has now.
+    pub count: u32,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Sets labels of a locale, keeping its other labels. An empty label
+     * removes the code's label. Admin only. Fails with LabelTooLong for a
+     * label over MAX_LABEL_LEN bytes, and TooManyLabels if the locale would
+     * have more than MAX_LABELS.
+     */
+    pub fn set_labels(
+        env: Env,
+        locale: Symbol,
+        labels: Map<LabelCode, String>,
+    ) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "set_labels")?;
+
+        let mut table = Self::labels(env.clone(), locale.clone());
+        for (code, label) in labels.iter() {
+            if label.len() > MAX_LABEL_LEN {
+                fail(&env, ExtError::LabelTooLong);
+            }
+            if label.is_empty() {
+                table.remove(code);
+            } else {
+                table.set(code, label);
+            }
+        }
+        if table.len() > MAX_LABELS {
+            fail(&env, ExtError::TooManyLabels);
+        }
+
+        let key = ExtKey2::Labels(locale.clone());
+        let mut locales = Self::locales(env.clone());
+        let listed = locales.first_index_of(&locale);
+        if table.is_empty() {
+            env.storage().persistent().remove(&key);
+            if let Some(i) = listed {
+                locales.remove(i);
+                storage::set_shared(&env, &ExtKey2::Locales, &locales);
+            }
+        } else {
+            env.storage().persistent().set(&key, &table);
+            if listed.is_none() {
+                locales.push_back(locale.clone());
+                storage::set_shared(&env, &ExtKey2::Locales, &locales);
+            }
+        }
+
+        events::emit(
+            &env,
+            &LabelsSet {
+                locale,
+                count: table.len(),
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns every label of a locale.
+     */
+    pub fn labels(env: Env, locale: Symbol) -> Map<LabelCode, String> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::Labels(locale))
+            .unwrap_or_else(|| Map::new(&env))
+    }
+
+    /**
+     * @dev Returns the label of a code in a locale, if it has one.
+     */
+    pub fn label(env: Env, locale: Symbol, code: LabelCode) -> Option<String> {
+        Self::labels(env, locale).get(code)
+    }
+
+    /**
+     * @dev Lists the locales that have labels, in the order they were added.
+     */
+    pub fn locales(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::Locales)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+}
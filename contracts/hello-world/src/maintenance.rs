@@ -0,0 +1,294 @@
+// Maintenance fees.
+//
+// Like a real timeshare, every token owes an annual maintenance fee. The admin
+// sets the fee schedule: the fee, the token it is billed in, and who collects
+// it. Owners pay with `pay_maintenance`, which pulls the fee from them through
+// the token's contract; each token records the calendar year it is paid
+// through (UTC, from the ledger timestamp, as in usage.rs).
+//
+// A token owes the fee for every year from the one it was minted in, or the
+// one billing last started in if later, up to the current year. Years left
+// unpaid stay owed: `pay_maintenance` settles all of them at once. Turning
+// billing off (a fee of 0) forgives what was owed. A token is delinquent while
+// a positive fee is billed and any year is unpaid. The record stays with the
+// token when it changes owner, so a buyer can see from `maintenance_status`
+// what is owed.
+//
+// A share of each fee may be held back for the property's reserve fund (see
+// reserve.rs); the collector gets the rest. Owners can also prepay several
+// years at a discount (see prepay.rs); a prepaid year counts as paid. Paying
+// after the due date may cost a late fee on top (see late.rs). The fee can
+// follow an inflation or utility index instead of being set by hand (see
+// fee_index.rs).
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env, Vec};
+
+use crate::{
+    check_owner, enter, events, fail, fee_index, ids, late, migration, or_fail, pause, prepay,
+    require_admin, reserve, usage, Amount, DataKey, Error, ExtError2, ExtKey,
+    HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient, TokenPage,
+    MAX_PAGE,
+};
+
+/**
+ * @title MaintenanceSchedule
+ * @dev The annual maintenance fee every token owes.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceSchedule {
+    /// Token the fee is billed in.
+    pub token: Address,
+    /// The fee per token and year. 0 bills nothing.
+    pub fee: i128,
+    /// Receives the fees.
+    pub collector: Address,
+}
+
+/**
+ * @title MaintenanceBilling
+ * @dev Whether a maintenance schedule is set, as reported by config(). (A plain
+ * Option<MaintenanceSchedule> field can't be converted to an ScVal by the SDK.)
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MaintenanceBilling {
+    Disabled,
+    Enabled(MaintenanceSchedule),
+}
+
+/**
+ * @title MaintenanceStatus
+ * @dev Whether a token's maintenance fee is paid through the current year.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceStatus {
+    /// The current calendar year.
+    pub year: u32,
+    /// The last year paid for.
+    pub paid_through: u32,
+    /// Years owed, up to and including the current one.
+    pub unpaid_years: u32,
+    pub paid: bool,
+    /// Unpaid while a positive fee is billed.
+    pub delinquent: bool,
+}
+
+/**
+ * @title MaintenancePaid
+ * @dev Published when a token's maintenance fee is paid through a year.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenancePaid {
+    #[topic]
+    pub token_id: u64,
+    pub owner: Address,
+    /// The year now paid through.
+    pub year: u32,
+    /// How many years the payment settled.
+    pub years: u32,
+    pub amount: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Sets the maintenance fee schedule. Admin only. Starting to bill a
+     * positive fee bills from the current year on; earlier years aren't owed.
+     * Fails with FeeIndexed for a change of fee while it follows an index.
+     * @param schedule fee must not be negative.
+     */
+    pub fn set_maintenance_schedule(env: Env, schedule: MaintenanceSchedule) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "set_maintenance_schedule")?;
+
+        if schedule.fee < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if Self::fee_index(env.clone()).is_some()
+            && Self::maintenance_schedule(env.clone())
+                .is_some_and(|current| current.fee != schedule.fee)
+        {
+            fail(&env, ExtError2::FeeIndexed);
+        }
+        set_schedule(&env, &schedule);
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the maintenance fee schedule, if the admin has set one.
+     */
+    pub fn maintenance_schedule(env: Env) -> Option<MaintenanceSchedule> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MaintenanceSchedule)
+    }
+
+    /**
+     * @dev Pays a token's maintenance fee for every unpaid year through the
+     * current one, plus the late fees they accrued. A token already paid
+     * through this year is charged nothing. Fails with InvalidAmount unless
+     * the schedule bills fees in `token_address`, or if the total overflows.
+     * @param owner The current owner (must sign and pay).
+     * @param token_address The token to pay in; must be the schedule's.
+     * @return The token's status after the payment.
+     */
+    pub fn pay_maintenance(
+        env: Env,
+        owner: Address,
+        token_id: u64,
+        token_address: Address,
+    ) -> Result<MaintenanceStatus, Error> {
+        owner.require_auth();
+        enter(&env, "pay_maintenance")?;
+        pause::check(&env)?;
+
+        check_owner(&env, token_id, &owner)?;
+        let schedule = Self::maintenance_schedule(env.clone())
+            .filter(|schedule| schedule.token == token_address)
+            .ok_or(Error::InvalidAmount)?;
+        prepay::release(&env, token_id);
+        let status = Self::maintenance_status(env.clone(), token_id)?;
+        if status.paid {
+            return Ok(status);
+        }
+
+        let amount = Amount::of(schedule.fee)
+            .and_then(|fee| fee.checked_mul(status.unpaid_years))
+            .and_then(|fees| fees.checked_add(late::owed(&env, &status)));
+        let amount = or_fail(&env, amount);
+        if amount > Amount::ZERO {
+            let info = migration::read_info(&env, token_id).ok_or(Error::TokenNotFound)?;
+            reserve::pay_fee(
+                &env,
+                info.hotel_id,
+                &token_address,
+                &owner,
+                &schedule.collector,
+                amount,
+            );
+            fee_index::bill(&env);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Maintenance(token_id), &status.year);
+        late::clear(&env, token_id);
+
+        events::emit(
+            &env,
+            &MaintenancePaid {
+                token_id,
+                owner,
+                year: status.year,
+                years: status.unpaid_years,
+                amount: amount.get(),
+            },
+        );
+        Ok(MaintenanceStatus {
+            year: status.year,
+            paid_through: status.year,
+            unpaid_years: 0,
+            paid: true,
+            delinquent: false,
+        })
+    }
+
+    /**
+     * @dev Returns whether a token's maintenance fee is paid through the
+     * current year, and how many years are owed.
+     */
+    pub fn maintenance_status(env: Env, token_id: u64) -> Result<MaintenanceStatus, Error> {
+        Self::get_owner(env.clone(), token_id)?;
+        Ok(status(&env, token_id, billing(&env)))
+    }
+
+    /**
+     * @dev Lists delinquent token IDs above `start_after`, examining at most
+     * `limit` (capped at MAX_PAGE) IDs per call, like tokens().
+     * @param start_after 0 for the first page, then the previous page's next_start_after.
+     */
+    pub fn delinquent_tokens(env: Env, start_after: u64, limit: u32) -> TokenPage {
+        let last = ids::reserved(&env);
+        let end = last.min(start_after.saturating_add(limit.min(MAX_PAGE) as u64));
+
+        let billing = billing(&env);
+        let mut token_ids = Vec::new(&env);
+        for token_id in start_after + 1..=end {
+            if migration::has(&env, &DataKey::Owner(token_id))
+                && status(&env, token_id, billing).delinquent
+            {
+                token_ids.push_back(token_id);
+            }
+        }
+
+        TokenPage {
+            token_ids,
+            next_start_after: if end < last { Some(end) } else { None },
+        }
+    }
+}
+
+/// Whether a positive fee is billed.
+fn billing(env: &Env) -> bool {
+    HotelTimeshareContract::maintenance_schedule(env.clone())
+        .is_some_and(|schedule| schedule.fee > 0)
+}
+
+/// Stores the fee schedule. Starting to bill a positive fee bills from the
+/// current year on.
+pub(crate) fn set_schedule(env: &Env, schedule: &MaintenanceSchedule) {
+    if schedule.fee > 0 && !billing(env) {
+        env.storage()
+            .persistent()
+            .set(&ExtKey::BillingSince, &usage::current_year(env));
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::MaintenanceSchedule, schedule);
+}
+
+/// Starts a new token's maintenance record: while a fee is billed, it owes from
+/// the year it is minted.
+pub(crate) fn open(env: &Env, token_id: u64) {
+    if billing(env) {
+        let year = usage::current_year(env);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Maintenance(token_id), &(year - 1));
+    }
+}
+
+/// The last year a token is paid through. Years before billing last started
+/// count as paid, as does every year before the current one for tokens that
+/// predate arrears (no BillingSince and no record), and so do prepaid years
+/// that follow the last paid one.
+fn paid_through(env: &Env, token_id: u64, year: u32) -> u32 {
+    let record: Option<u32> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Maintenance(token_id));
+    let since: Option<u32> = env.storage().persistent().get(&ExtKey::BillingSince);
+    let paid_through = match (record, since) {
+        (Some(record), Some(since)) => record.max(since - 1),
+        (Some(record), None) => record,
+        (None, Some(since)) => since - 1,
+        (None, None) => year - 1,
+    };
+    prepay::paid_through(env, token_id, paid_through)
+}
+
+fn status(env: &Env, token_id: u64, billing: bool) -> MaintenanceStatus {
+    let year = usage::current_year(env);
+    let paid_through = paid_through(env, token_id, year);
+    let unpaid_years = year.saturating_sub(paid_through);
+    let paid = unpaid_years == 0;
+    MaintenanceStatus {
+        year,
+        paid_through,
+        unpaid_years: if billing { unpaid_years } else { 0 },
+        paid,
+        delinquent: billing && !paid,
+    }
+}
@@ -0,0 +1,211 @@
+// Built-in marketplace.
+//
+// An owner lists a token with `list_for_sale` at a price in a payment token,
+// and anyone can `buy` it: the buyer pays the seller through the payment
+// token's contract and receives the token in the same call, so neither side
+// has to trust the other or an off-chain escrow. Primary sales work the same
+// way, with the hotel listing tokens minted to itself. The hotel's royalty, if
+// it set one, is taken out of the price (see royalties.rs).
+//
+// The token moves through `move_token` like any transfer, so frozen tokens
+// (also refused at listing), tokens of retired properties, expired leases and
+// tokens in trust can't be bought. A listing lapses when the token changes
+// owner by any path, and the seller can withdraw it with `cancel_listing`.
+// Listings count toward their property's floor price and market depth (see
+// depth.rs).
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env};
+
+use crate::{
+    check_owner, depth, enter, events, freeze, move_token, pause, receipts, royalties, DataKey,
+    Error, HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+    ReceiptKind,
+};
+
+/**
+ * @title Listing
+ * @dev A token offered for sale.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Listing {
+    /// The owner who listed the token, and is paid for it.
+    pub seller: Address,
+    pub price: i128,
+    /// Token the price is paid in.
+    pub payment_token: Address,
+}
+
+/**
+ * @title Listed
+ * @dev Published when an owner lists a token for sale.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Listed {
+    #[topic]
+    pub token_id: u64,
+    pub seller: Address,
+    pub price: i128,
+    pub payment_token: Address,
+}
+
+/**
+ * @title ListingCancelled
+ * @dev Published when a seller withdraws a listing.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ListingCancelled {
+    #[topic]
+    pub token_id: u64,
+}
+
+/**
+ * @title Sale
+ * @dev Published when a listed token is bought, next to its Transfer event.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sale {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub buyer: Address,
+    pub seller: Address,
+    pub price: i128,
+    pub payment_token: Address,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Lists a token for sale, replacing any earlier listing of it.
+     * @param owner The current owner (must sign); receives the price.
+     * @param price The price; must not be negative.
+     * @param payment_token The token the price is paid in.
+     */
+    pub fn list_for_sale(
+        env: Env,
+        owner: Address,
+        token_id: u64,
+        price: i128,
+        payment_token: Address,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "list_for_sale")?;
+        pause::check(&env)?;
+
+        check_owner(&env, token_id, &owner)?;
+        freeze::check(&env, token_id);
+        if price < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        list(
+            &env,
+            token_id,
+            Listing {
+                seller: owner,
+                price,
+                payment_token,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Withdraws a token's listing.
+     * @param owner The current owner (must sign).
+     */
+    pub fn cancel_listing(env: Env, owner: Address, token_id: u64) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "cancel_listing")?;
+
+        check_owner(&env, token_id, &owner)?;
+        Self::listing(env.clone(), token_id).ok_or(Error::NotListed)?;
+        clear(&env, token_id);
+
+        events::emit(&env, &ListingCancelled { token_id });
+        Ok(())
+    }
+
+    /**
+     * @dev Buys a listed token: pays the seller its price and takes ownership.
+     * Fails with NotOwner if the seller no longer owns it.
+     * @param buyer The buyer (must sign and pay).
+     */
+    pub fn buy(env: Env, buyer: Address, token_id: u64) -> Result<(), Error> {
+        buyer.require_auth();
+        enter(&env, "buy")?;
+        pause::check(&env)?;
+
+        let listing = Self::listing(env.clone(), token_id).ok_or(Error::NotListed)?;
+        check_owner(&env, token_id, &listing.seller)?;
+        freeze::check(&env, token_id);
+        royalties::pay(
+            &env,
+            token_id,
+            &listing.payment_token,
+            &buyer,
+            &listing.seller,
+            listing.price,
+        )?;
+        move_token(&env, &listing.seller, &buyer, token_id)?;
+        receipts::record_sale(
+            &env,
+            ReceiptKind::Resale,
+            token_id,
+            &buyer,
+            &listing.seller,
+            listing.price,
+            &listing.payment_token,
+        );
+
+        events::emit(
+            &env,
+            &Sale {
+                token_id,
+                buyer,
+                seller: listing.seller,
+                price: listing.price,
+                payment_token: listing.payment_token,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns a token's listing, if it is for sale.
+     */
+    pub fn listing(env: Env, token_id: u64) -> Option<Listing> {
+        env.storage().persistent().get(&DataKey::Listing(token_id))
+    }
+}
+
+/// Lists a token, replacing any earlier listing of it.
+pub(crate) fn list(env: &Env, token_id: u64, listing: Listing) {
+    clear(env, token_id);
+    depth::add(env, token_id, &listing);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Listing(token_id), &listing);
+    events::emit(
+        env,
+        &Listed {
+            token_id,
+            seller: listing.seller,
+            price: listing.price,
+            payment_token: listing.payment_token,
+        },
+    );
+}
+
+/// Removes a token's listing, when it is withdrawn or the token changes owner.
+pub(crate) fn clear(env: &Env, token_id: u64) {
+    if let Some(listing) = HotelTimeshareContract::listing(env.clone(), token_id) {
+        depth::remove(env, token_id, &listing);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Listing(token_id));
+    }
+}
@@ -0,0 +1,242 @@
+// Token metadata.
+//
+// A token's TimeshareInfo holds only what the contract needs: hotel, room,
+// week and term. Richer material (photos, floor plans, legal documents) lives
+// off-chain, typically on IPFS, and a manager links each token to it with
+// `set_token_uri`. Marketplaces read it with `token_uri`. The URI is stored as
+// given, e.g. "ipfs://<cid>"; the contract doesn't resolve or validate it.
+//
+// A manager can also give a token a tier with `set_tier`, e.g. Platinum for
+// the summer weeks of a sea-view room. Tiers are names the hotel chooses;
+// buyers can make offers on any token of a tier (see collection.rs).
+//
+// Hotels renovate and renumber rooms, so a manager can also correct a token's
+// info with `update_info`, under the same checks as a mint: the new week must
+// be unsold and its hotel and room active. Buyers who want a guarantee that
+// neither will change can have it locked with `freeze_metadata`, which can't
+// be undone. The lock covers the tier too. The URI, the tier and the lock are
+// removed when the token is burned.
+
+use soroban_sdk::{contractevent, contractimpl, Address, Env, String, Symbol};
+
+use crate::{
+    depth, enter, events, fail, filters, hotels, migration, owner_of, retirement, roles, rooms,
+    slots, supply, term, DataKey, Error, ExtError, ExtKey, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, Role, TimeshareInfo,
+};
+
+/**
+ * @title TokenUriSet
+ * @dev Published when a token's metadata URI changes.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenUriSet {
+    #[topic]
+    pub token_id: u64,
+    pub uri: String,
+}
+
+/**
+ * @title InfoUpdated
+ * @dev Published when a manager changes a token's info.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InfoUpdated {
+    #[topic]
+    pub token_id: u64,
+    pub old_info: TimeshareInfo,
+    pub new_info: TimeshareInfo,
+}
+
+/**
+ * @title TierSet
+ * @dev Published when a token's tier changes; None when it is cleared.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierSet {
+    #[topic]
+    pub token_id: u64,
+    pub tier: Option<Symbol>,
+}
+
+/**
+ * @title MetadataFrozen
+ * @dev Published when a token's info, URI and tier are locked for good.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataFrozen {
+    #[topic]
+    pub token_id: u64,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Links a token to its off-chain metadata, replacing any earlier URI.
+     * Manager role (or admin). Fails with MetadataFrozen once the metadata
+     * is frozen.
+     * @param caller The manager (must sign).
+     * @param uri The metadata's location, e.g. "ipfs://<cid>".
+     */
+    pub fn set_token_uri(
+        env: Env,
+        caller: Address,
+        token_id: u64,
+        uri: String,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "set_token_uri")?;
+
+        owner_of(&env, token_id)?;
+        check_unfrozen(&env, token_id);
+        env.storage()
+            .persistent()
+            .set(&ExtKey::TokenUri(token_id), &uri);
+
+        events::emit(&env, &TokenUriSet { token_id, uri });
+        Ok(())
+    }
+
+    /**
+     * @dev Returns a token's metadata URI, if one was set.
+     */
+    pub fn token_uri(env: Env, token_id: u64) -> Result<Option<String>, Error> {
+        migration::read_info(&env, token_id).ok_or(Error::TokenNotFound)?;
+        Ok(env.storage().persistent().get(&ExtKey::TokenUri(token_id)))
+    }
+
+    /**
+     * @dev Replaces a token's info, e.g. after a room is renumbered. Manager
+     * role (or admin). The new info is checked like a mint's, and frees the
+     * old week for sale. Fails with MetadataFrozen once the metadata is frozen.
+     * @param caller The manager (must sign).
+     */
+    pub fn update_info(
+        env: Env,
+        caller: Address,
+        token_id: u64,
+        new_info: TimeshareInfo,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "update_info")?;
+
+        let old_info = migration::read_info(&env, token_id).ok_or(Error::TokenNotFound)?;
+        check_unfrozen(&env, token_id);
+        hotels::check(&env, new_info.hotel_id)?;
+        retirement::check_active(&env, new_info.hotel_id)?;
+        rooms::check(&env, new_info.hotel_id, &new_info.room)?;
+        term::validate(&env, &new_info)?;
+        slots::release(&env, token_id);
+        slots::claim(&env, token_id, &new_info)?;
+        if new_info.hotel_id != old_info.hotel_id {
+            supply::record_burn(&env, token_id);
+            supply::record_mint(&env, token_id, new_info.hotel_id)?;
+        }
+        let tier = Self::tier(env.clone(), token_id);
+        filters::reindex(&env, token_id, (&old_info, &tier), (&new_info, &tier));
+        depth::reindex(&env, token_id, (&old_info, &tier), (&new_info, &tier));
+        env.storage()
+            .persistent()
+            .set(&DataKey::Info(token_id), &new_info);
+
+        events::emit(
+            &env,
+            &InfoUpdated {
+                token_id,
+                old_info,
+                new_info,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Sets or clears a token's tier. Manager role (or admin). Fails with
+     * MetadataFrozen once the metadata is frozen.
+     * @param caller The manager (must sign).
+     * @param tier The tier, e.g. "Platinum", or None to clear it.
+     */
+    pub fn set_tier(
+        env: Env,
+        caller: Address,
+        token_id: u64,
+        tier: Option<Symbol>,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "set_tier")?;
+
+        let info = migration::read_info(&env, token_id).ok_or(Error::TokenNotFound)?;
+        check_unfrozen(&env, token_id);
+        let old_tier = Self::tier(env.clone(), token_id);
+        filters::reindex(&env, token_id, (&info, &old_tier), (&info, &tier));
+        depth::reindex(&env, token_id, (&info, &old_tier), (&info, &tier));
+        match &tier {
+            Some(tier) => env
+                .storage()
+                .persistent()
+                .set(&ExtKey::Tier(token_id), tier),
+            None => env.storage().persistent().remove(&ExtKey::Tier(token_id)),
+        }
+
+        events::emit(&env, &TierSet { token_id, tier });
+        Ok(())
+    }
+
+    /**
+     * @dev Returns a token's tier, if it has one.
+     */
+    pub fn tier(env: Env, token_id: u64) -> Option<Symbol> {
+        env.storage().persistent().get(&ExtKey::Tier(token_id))
+    }
+
+    /**
+     * @dev Locks a token's info, URI and tier for good. Manager role (or admin).
+     * Freezing frozen metadata does nothing.
+     * @param caller The manager (must sign).
+     */
+    pub fn freeze_metadata(env: Env, caller: Address, token_id: u64) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "freeze_metadata")?;
+
+        owner_of(&env, token_id)?;
+        if Self::is_metadata_frozen(env.clone(), token_id) {
+            return Ok(());
+        }
+        env.storage()
+            .persistent()
+            .set(&ExtKey::MetadataFrozen(token_id), &true);
+
+        events::emit(&env, &MetadataFrozen { token_id });
+        Ok(())
+    }
+
+    /**
+     * @dev Returns whether a token's metadata is frozen.
+     */
+    pub fn is_metadata_frozen(env: Env, token_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&ExtKey::MetadataFrozen(token_id))
+    }
+}
+
+/// Removes a token's metadata, when it is burned.
+pub(crate) fn clear(env: &Env, token_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&ExtKey::TokenUri(token_id));
+    env.storage()
+        .persistent()
+        .remove(&ExtKey::MetadataFrozen(token_id));
+    env.storage().persistent().remove(&ExtKey::Tier(token_id));
+}
+
+fn check_unfrozen(env: &Env, token_id: u64) {
+    if HotelTimeshareContract::is_metadata_frozen(env.clone(), token_id) {
+        fail(env, ExtError::MetadataFrozen);
+    }
+}
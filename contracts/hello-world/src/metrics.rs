@@ -0,0 +1,109 @@
+// Per-endpoint invocation counters, bucketed per day.
+//
+// Lets the operator's monitoring spot anomalies (e.g. a burst of transfers)
+// straight from the contract, even while the indexer is down.
+//
+// Only successful invocations can be counted: when an invocation fails, the host
+// rolls back every storage write it made, including the counter bump. Failure
+// rates have to come from transaction results (RPC / Horizon) instead.
+//
+// Each endpoint has its own counter per day, so calls to different endpoints
+// don't contend on one entry (see storage.rs). Calls to the same endpoint
+// still would, so the mint paths spread theirs over METRICS_LANES counters,
+// picked by a hash of the signer (see `enter_by`):
+//
+//     lane = sha256(xdr(signer))[0] % METRICS_LANES
+//
+// Lane 0 is the endpoint's plain Metrics counter, which every other endpoint
+// counts into; `metrics` adds the lanes up. A per-day index lists the
+// endpoints counted that day; it is only written on an endpoint's first call
+// in a lane.
+//
+// Counters live in temporary storage and expire after METRICS_TTL_LEDGERS, so
+// monitoring data never accumulates in the contract's long-lived state.
+
+use soroban_sdk::{contractimpl, xdr::ToXdr, Address, Env, IntoVal, Map, Symbol, Val, Vec};
+
+use crate::{
+    DataKey, ExtKey, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, DAY_SECONDS,
+};
+
+/// How long a day's bucket is kept, in ledgers (~30 days at 5s per ledger).
+pub const METRICS_TTL_LEDGERS: u32 = 30 * 17_280;
+
+/// Number of counters a mint endpoint's daily count is spread over.
+pub const METRICS_LANES: u32 = 16;
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Returns the invocation counts recorded for `day` (ledger timestamp / DAY_SECONDS).
+     * @return A map from endpoint name to number of successful calls. Empty once expired.
+     */
+    pub fn metrics(env: Env, day: u64) -> Map<Symbol, u32> {
+        let mut counts = Map::new(&env);
+        for endpoint in endpoints(&env, day).iter() {
+            let mut total = None;
+            for lane in 0..METRICS_LANES {
+                let count: Option<u32> = env
+                    .storage()
+                    .temporary()
+                    .get(&counter_key(&env, day, &endpoint, lane));
+                if let Some(count) = count {
+                    total = Some(total.unwrap_or(0) + count);
+                }
+            }
+            if let Some(total) = total {
+                counts.set(endpoint, total);
+            }
+        }
+        counts
+    }
+}
+
+fn endpoints(env: &Env, day: u64) -> Vec<Symbol> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::MetricsIndex(day))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// The metrics lane of calls signed by `signer`.
+pub(crate) fn lane(env: &Env, signer: &Address) -> u32 {
+    let hash = env.crypto().sha256(&signer.clone().to_xdr(env));
+    hash.to_array()[0] as u32 % METRICS_LANES
+}
+
+/// Counts one invocation of `endpoint` in today's counter in `lane` (see
+/// `enter`).
+pub(crate) fn record(env: &Env, endpoint: &Symbol, lane: u32) {
+    let day = env.ledger().timestamp() / DAY_SECONDS;
+    let key = counter_key(env, day, endpoint, lane);
+    let count: u32 = env.storage().temporary().get(&key).unwrap_or(0);
+
+    if count == 0 {
+        let index_key = DataKey::MetricsIndex(day);
+        let mut index = endpoints(env, day);
+        if !index.contains(endpoint) {
+            index.push_back(endpoint.clone());
+            env.storage().temporary().set(&index_key, &index);
+        }
+        env.storage()
+            .temporary()
+            .extend_ttl(&index_key, METRICS_TTL_LEDGERS, METRICS_TTL_LEDGERS);
+    }
+
+    env.storage().temporary().set(&key, &(count + 1));
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, METRICS_TTL_LEDGERS, METRICS_TTL_LEDGERS);
+}
+
+fn counter_key(env: &Env, day: u64, endpoint: &Symbol, lane: u32) -> Val {
+    if lane == 0 {
+        DataKey::Metrics(day, endpoint.clone()).into_val(env)
+    } else {
+        ExtKey::MetricsLane(day, endpoint.clone(), lane).into_val(env)
+    }
+}
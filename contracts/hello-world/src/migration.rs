@@ -0,0 +1,194 @@
+// Versioned storage layout and migrations.
+//
+// The layout version is stored under SCHEMA in instance storage. initialize()
+// writes the current SCHEMA_VERSION; a deployment without the key predates
+// versioning and is on version 1. Whenever a change to `DataKey` or a stored
+// type would leave old entries unreadable, bump SCHEMA_VERSION, add a step to
+// `run_step`, and teach the read helpers below to recognize the old form.
+//
+// Versions:
+//
+//   1  every per-token, per-owner and per-day entry, plus the deprecation list
+//      and the upgrade log, in instance storage
+//   2  only ADMIN and SCHEMA in instance storage, everything else persistent
+//      (see storage.rs); COUNTER becomes the highest ID reserved (see ids.rs)
+//   3  TimeshareInfo gains `year` and `lease_end_ledger`; older entries read as
+//      perpetual (see term.rs)
+//   4  TimeshareInfo names its hotel by registry ID (`hotel_id`) instead of by
+//      name; older entries are linked to the registry on read (see hotels.rs)
+//   5  hotel supplies are counted; migrate() starts a recount of the tokens
+//      already minted, which `count_supply` finishes (see supply.rs)
+//
+// After an upgrade the admin calls `migrate()`, which runs the steps between
+// the stored version and SCHEMA_VERSION. Steps only rewrite the handful of
+// contract-wide entries; per-token and per-owner data is rewritten lazily by
+// `read`, the first time it is needed, so no call ever has to touch every
+// token. The admin can also rewrite listed legacy entries eagerly with
+// `migrate_entries` (e.g. tokens still held in trust, which lazy reads of Info
+// and Owner don't cover); the keys left are visible in the instance entry.
+
+use soroban_sdk::{
+    contractevent, contractimpl, symbol_short, Env, IntoVal, Map, String, Symbol, TryFromVal, Val,
+    Vec,
+};
+
+use crate::{
+    enter, events, hotels, ids, require_admin, supply, DataKey, Error, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, TimeshareInfo, MAX_MINT_BATCH,
+};
+
+/// Layout version written by this build.
+pub const SCHEMA_VERSION: u32 = 5;
+
+// Instance key of the stored layout version.
+pub(crate) const SCHEMA: Symbol = symbol_short!("SCHEMA");
+
+/**
+ * @title SchemaMigrated
+ * @dev Published when migrate() brings the stored layout to a new version.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaMigrated {
+    pub from: u32,
+    pub to: u32,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Returns the layout version of the stored data.
+     */
+    pub fn schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&SCHEMA).unwrap_or(1)
+    }
+
+    /**
+     * @dev Runs every migration step between the stored layout version and
+     * SCHEMA_VERSION. Admin only; a no-op once the layout is current.
+     * @return The layout version now stored.
+     */
+    pub fn migrate(env: Env) -> Result<u32, Error> {
+        require_admin(&env)?;
+        enter(&env, "migrate")?;
+
+        let from = Self::schema_version(env.clone());
+        if from >= SCHEMA_VERSION {
+            return Ok(from);
+        }
+        for version in from + 1..=SCHEMA_VERSION {
+            run_step(&env, version);
+        }
+        env.storage().instance().set(&SCHEMA, &SCHEMA_VERSION);
+        events::emit(
+            &env,
+            &SchemaMigrated {
+                from,
+                to: SCHEMA_VERSION,
+            },
+        );
+        Ok(SCHEMA_VERSION)
+    }
+
+    /**
+     * @dev Moves the listed entries from their version 1 location (instance
+     * storage) to persistent storage, skipping keys that aren't there. Admin only.
+     * @param keys At most MAX_MINT_BATCH keys per call.
+     * @return How many entries were moved.
+     */
+    pub fn migrate_entries(env: Env, keys: Vec<DataKey>) -> Result<u32, Error> {
+        require_admin(&env)?;
+        enter(&env, "migrate_entries")?;
+        if keys.len() > MAX_MINT_BATCH {
+            return Err(Error::BatchTooLarge);
+        }
+
+        let mut moved = 0;
+        for key in keys.iter() {
+            if upgrade(&env, &key) {
+                moved += 1;
+            }
+        }
+        Ok(moved)
+    }
+}
+
+/// Reads a persistent entry, falling back to its version 1 location and
+/// rewriting it into persistent storage when found there.
+pub(crate) fn read<V>(env: &Env, key: &DataKey) -> Option<V>
+where
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    if let Some(value) = env.storage().persistent().get(key) {
+        return Some(value);
+    }
+    let value: V = env.storage().instance().get(key)?;
+    move_to_persistent(env, key, &value);
+    Some(value)
+}
+
+/// Reads a token's info, rewriting it in the current form if it was stored
+/// before version 4.
+pub(crate) fn read_info(env: &Env, token_id: u64) -> Option<TimeshareInfo> {
+    let key = DataKey::Info(token_id);
+    let value: Val = read(env, &key)?;
+    // Structs are stored as maps from field name to value. Old forms are told
+    // apart by their fields, since decoding a map with other fields traps.
+    let fields = Map::<Symbol, Val>::try_from_val(env, &value).ok()?;
+    if fields.contains_key(symbol_short!("hotel_id")) {
+        return TimeshareInfo::try_from_val(env, &value).ok();
+    }
+    let field = |name: &str| fields.get(Symbol::new(env, name));
+    let hotel = String::try_from_val(env, &field("hotel")?).ok()?;
+    let term = |name: &str| match field(name) {
+        Some(value) => Option::<u32>::try_from_val(env, &value).ok(),
+        None => Some(None), // version 1 and 2: no term
+    };
+    let info = TimeshareInfo {
+        hotel_id: hotels::link(env, hotel),
+        room: String::try_from_val(env, &field("room")?).ok()?,
+        week: u32::try_from_val(env, &field("week")?).ok()?,
+        year: term("year")?,
+        lease_end_ledger: term("lease_end_ledger")?,
+    };
+    env.storage().persistent().set(&key, &info);
+    Some(info)
+}
+
+/// Rewrites `key` into persistent storage if it is still in its version 1
+/// location, before code that requires the persistent entry (TTL extension).
+/// Returns whether it was.
+pub(crate) fn upgrade(env: &Env, key: &DataKey) -> bool {
+    match env.storage().instance().get::<_, Val>(key) {
+        Some(value) => {
+            move_to_persistent(env, key, &value);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Whether a persistent entry exists, in its current or version 1 location.
+pub(crate) fn has(env: &Env, key: &DataKey) -> bool {
+    env.storage().persistent().has(key) || env.storage().instance().has(key)
+}
+
+/// Migration of contract-wide entries to layout `version`.
+fn run_step(env: &Env, version: u32) {
+    if version == 2 {
+        upgrade(env, &DataKey::Deprecations);
+        upgrade(env, &DataKey::UpgradeHistory);
+        ids::adopt_legacy(env);
+    }
+    if version == 5 {
+        supply::start_recount(env);
+    }
+}
+
+fn move_to_persistent<V>(env: &Env, key: &DataKey, value: &V)
+where
+    V: IntoVal<Env, Val>,
+{
+    env.storage().instance().remove(key);
+    env.storage().persistent().set(key, value);
+}
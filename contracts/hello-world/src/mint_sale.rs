@@ -0,0 +1,157 @@
+// Paid minting.
+//
+// A manager puts a hotel's unsold weeks on sale with `set_mint_price`: one
+// price, in XLM or any other Stellar Asset Contract token. Anyone can then buy
+// a week with `buy_mint`, which takes the price from the buyer through the
+// token's contract and mints the week to them in the same call, with no
+// off-chain payment or admin mint in between. Proceeds go to the admin, or
+// are split between its payees (see splitter.rs), once any cooling-off
+// period is over (see refunds.rs).
+//
+// Tokens bought this way are perpetual (no year, no lease end) and minted
+// like any other, so the week must be unsold and its hotel and room active.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env, String};
+
+use crate::{
+    enter, enter_by, events, hotels, ids, issue_token, pause, refunds, roles, Error, ExtKey,
+    HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient, Role,
+    TimeshareInfo, ADMIN,
+};
+
+/**
+ * @title MintPrice
+ * @dev What a hotel's unsold weeks sell for.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintPrice {
+    pub price: i128,
+    /// Token the price is paid in.
+    pub payment_token: Address,
+}
+
+/**
+ * @title MintPurchased
+ * @dev Published when a buyer pays for a new token, next to the Mint event.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintPurchased {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub buyer: Address,
+    pub price: i128,
+    pub payment_token: Address,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Puts a hotel's unsold weeks on sale, or changes their price.
+     * Manager role (or admin).
+     * @param caller The manager (must sign).
+     * @param price The price of a week; must not be negative.
+     * @param payment_token The token the price is paid in.
+     */
+    pub fn set_mint_price(
+        env: Env,
+        caller: Address,
+        hotel_id: u32,
+        price: i128,
+        payment_token: Address,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "set_mint_price")?;
+
+        hotels::check(&env, hotel_id)?;
+        if price < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage().persistent().set(
+            &ExtKey::MintPrice(hotel_id),
+            &MintPrice {
+                price,
+                payment_token,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Takes a hotel's weeks off sale. Manager role (or admin).
+     * @param caller The manager (must sign).
+     */
+    pub fn clear_mint_price(env: Env, caller: Address, hotel_id: u32) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "clear_mint_price")?;
+
+        env.storage()
+            .persistent()
+            .remove(&ExtKey::MintPrice(hotel_id));
+        Ok(())
+    }
+
+    /**
+     * @dev Returns what a hotel's unsold weeks sell for, if they are on sale.
+     */
+    pub fn mint_price(env: Env, hotel_id: u32) -> Option<MintPrice> {
+        env.storage().persistent().get(&ExtKey::MintPrice(hotel_id))
+    }
+
+    /**
+     * @dev Buys an unsold week: pays the hotel's price and mints the week to
+     * the buyer. Fails with NotListed if the hotel's weeks aren't on sale.
+     * @param buyer The buyer (must sign and pay).
+     * @param room The room, from the hotel's inventory.
+     * @param week The week of the year (1-52).
+     * @return The new token ID.
+     */
+    pub fn buy_mint(
+        env: Env,
+        buyer: Address,
+        hotel_id: u32,
+        room: String,
+        week: u32,
+    ) -> Result<u64, Error> {
+        buyer.require_auth();
+        enter_by(&env, "buy_mint", &buyer)?;
+        pause::check(&env)?;
+
+        let sale = Self::mint_price(env.clone(), hotel_id).ok_or(Error::NotListed)?;
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&ADMIN)
+            .ok_or(Error::NotInitialized)?;
+        let token_id = ids::allocate(&env, &admin, 1)?.get_unchecked(0);
+        let info = TimeshareInfo {
+            hotel_id,
+            room,
+            week,
+            year: None,
+            lease_end_ledger: None,
+        };
+        issue_token(&env, token_id, &buyer, info)?;
+        refunds::collect(
+            &env,
+            token_id,
+            &sale.payment_token,
+            &buyer,
+            &admin,
+            sale.price,
+        );
+
+        events::emit(
+            &env,
+            &MintPurchased {
+                token_id,
+                buyer,
+                price: sale.price,
+                payment_token: sale.payment_token,
+            },
+        );
+        Ok(token_id)
+    }
+}
@@ -0,0 +1,246 @@
+// Payment math.
+//
+// Every amount the contract carves out of a price or a fee is worked out here,
+// with checked i128 arithmetic and one rounding rule per kind of calculation,
+// instead of integer division in each module:
+//
+// - `share` takes a basis-point share of an amount: royalties, tax withheld,
+//   reserve shares, cooling-off refunds. `prorate` scales an amount by any
+//   fraction. Both round half to even (banker's rounding), so the rounding of
+//   many payments doesn't lean towards either side.
+// - `split` divides an amount between weighted recipients by the largest
+//   remainder method: everyone gets the floor of their quota, and the units
+//   left over go one each to the largest remainders, earliest recipient first
+//   on a tie. The parts always add up to the amount.
+//
+// Amounts are in an asset's base units (stroops, for 7-decimal assets). A
+// manager can set a property's minor unit for a payment asset with
+// `set_minor_unit`, e.g. 100_000 to charge whole cents of a 7-decimal
+// stablecoin. Shares and pro-rated amounts of that property's payments are
+// then whole minor units, and whoever takes the rest of the payment (the
+// seller, the collector) absorbs the difference. Splits hand out whole minor
+// units and give the first recipient whatever is left below one.
+//
+// Amounts and shares are the checked newtypes of amount.rs, so the helpers
+// fail with its typed errors rather than wrapping.
+
+use soroban_sdk::{contractevent, contractimpl, Address, Env, Map, Vec};
+
+use crate::{
+    enter, events, hotels, roles, Amount, Bps, Error, ExtError, ExtKey, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, Role, FULL_BPS,
+};
+
+/**
+ * @title MinorUnitSet
+ * @dev Published when a property's minor unit for a payment asset changes.
+ * A unit of 1 is the asset's own base unit.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinorUnitSet {
+    #[topic]
+    pub hotel_id: u32,
+    #[topic]
+    pub asset: Address,
+    pub unit: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Sets the smallest amount a property's payments in an asset are
+     * worked out in. Manager role (or admin).
+     * @param caller The manager (must sign).
+     * @param unit In the asset's base units; must be positive, and 1 clears it.
+     */
+    pub fn set_minor_unit(
+        env: Env,
+        caller: Address,
+        hotel_id: u32,
+        asset: Address,
+        unit: i128,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "set_minor_unit")?;
+
+        hotels::check(&env, hotel_id)?;
+        if unit <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let mut units = Self::minor_units(env.clone(), hotel_id);
+        if unit == 1 {
+            units.remove(asset.clone());
+        } else {
+            units.set(asset.clone(), unit);
+        }
+        if units.is_empty() {
+            env.storage()
+                .persistent()
+                .remove(&ExtKey::MinorUnits(hotel_id));
+        } else {
+            env.storage()
+                .persistent()
+                .set(&ExtKey::MinorUnits(hotel_id), &units);
+        }
+
+        events::emit(
+            &env,
+            &MinorUnitSet {
+                hotel_id,
+                asset,
+                unit,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the minor units a property set, by payment asset. Assets
+     * not listed use their base unit.
+     */
+    pub fn minor_units(env: Env, hotel_id: u32) -> Map<Address, i128> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::MinorUnits(hotel_id))
+            .unwrap_or(Map::new(&env))
+    }
+}
+
+/// Returns a property's minor unit for `asset`, 1 unless one was set.
+pub(crate) fn minor_unit(env: &Env, hotel_id: u32, asset: &Address) -> Amount {
+    let unit = HotelTimeshareContract::minor_units(env.clone(), hotel_id)
+        .get(asset.clone())
+        .unwrap_or(1);
+    Amount::of(unit).unwrap_or(Amount::ONE)
+}
+
+/// Returns the minor units of every property that set one, by hotel ID.
+pub(crate) fn all_minor_units(env: &Env) -> Map<u32, Map<Address, i128>> {
+    let mut all = Map::new(env);
+    for hotel_id in 1..=hotels::hotel_count(env) {
+        let units = HotelTimeshareContract::minor_units(env.clone(), hotel_id);
+        if !units.is_empty() {
+            all.set(hotel_id, units);
+        }
+    }
+    all
+}
+
+/// Returns `bps` of `amount` in whole `unit`s, rounded half to even, and
+/// never more than `amount`.
+pub(crate) fn share(amount: Amount, bps: Bps, unit: Amount) -> Result<Amount, ExtError> {
+    prorate(amount, bps.get() as i128, FULL_BPS as i128, unit)
+}
+
+/// Returns `amount * numerator / denominator` in whole `unit`s, rounded half
+/// to even. For a fraction of at most one, never more than `amount`. Fails
+/// with NegativeAmount for a negative fraction and AmountOverflow for a
+/// denominator of 0.
+pub(crate) fn prorate(
+    amount: Amount,
+    numerator: i128,
+    denominator: i128,
+    unit: Amount,
+) -> Result<Amount, ExtError> {
+    if numerator < 0 || denominator < 0 {
+        return Err(ExtError::NegativeAmount);
+    }
+    if denominator == 0 {
+        return Err(ExtError::AmountOverflow);
+    }
+    check_unit(unit)?;
+    let scaled = amount
+        .get()
+        .checked_mul(numerator)
+        .ok_or(ExtError::AmountOverflow)?;
+    let per_unit = denominator
+        .checked_mul(unit.get())
+        .ok_or(ExtError::AmountOverflow)?;
+    let result = units(unit, round_half_even(scaled, per_unit))?;
+    if numerator <= denominator && result > amount {
+        // Rounding up to a whole unit can't take more than there is.
+        return Ok(amount);
+    }
+    Ok(result)
+}
+
+/// Divides `amount` between recipients in proportion to `weights`, in whole
+/// `unit`s by the largest remainder method. The first recipient also gets
+/// whatever is left below one unit. Fails with InvalidBps if the weights are
+/// all 0.
+pub(crate) fn split(
+    env: &Env,
+    amount: Amount,
+    weights: &Vec<u32>,
+    unit: Amount,
+) -> Result<Vec<i128>, ExtError> {
+    let total: i128 = weights.iter().map(|weight| weight as i128).sum();
+    if total == 0 {
+        return Err(ExtError::InvalidBps);
+    }
+    check_unit(unit)?;
+    let whole = amount.get() / unit.get();
+    let dust = amount.get() % unit.get();
+
+    // 1. Everyone's floor, and what each quota had left over.
+    let mut parts = Vec::new(env);
+    let mut remainders = Vec::new(env);
+    let mut handed_out: i128 = 0;
+    for weight in weights.iter() {
+        let quota = whole
+            .checked_mul(weight as i128)
+            .ok_or(ExtError::AmountOverflow)?;
+        parts.push_back(quota / total);
+        remainders.push_back(quota % total);
+        handed_out += quota / total;
+    }
+
+    // 2. One unit each to the largest remainders until none is left.
+    for _ in 0..whole - handed_out {
+        let mut largest = 0;
+        for (index, remainder) in remainders.iter().enumerate() {
+            if remainder > remainders.get_unchecked(largest) {
+                largest = index as u32;
+            }
+        }
+        parts.set(largest, parts.get_unchecked(largest) + 1);
+        remainders.set(largest, -1);
+    }
+
+    let mut amounts = Vec::new(env);
+    for (index, part) in parts.iter().enumerate() {
+        let part = units(unit, part)?.get();
+        amounts.push_back(if index == 0 { part + dust } else { part });
+    }
+    Ok(amounts)
+}
+
+/// Fails with InvalidMinorUnit for a unit of 0.
+fn check_unit(unit: Amount) -> Result<(), ExtError> {
+    if unit == Amount::ZERO {
+        return Err(ExtError::InvalidMinorUnit);
+    }
+    Ok(())
+}
+
+/// Returns `count` whole `unit`s.
+fn units(unit: Amount, count: i128) -> Result<Amount, ExtError> {
+    Amount::of(
+        count
+            .checked_mul(unit.get())
+            .ok_or(ExtError::AmountOverflow)?,
+    )
+}
+
+/// Divides `n` by a positive `d`, rounding half to even.
+fn round_half_even(n: i128, d: i128) -> i128 {
+    let quotient = n / d;
+    let remainder = n % d;
+    // remainder > d - remainder compares 2 * remainder with d without overflow.
+    if remainder > d - remainder || (remainder == d - remainder && quotient % 2 != 0) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
@@ -0,0 +1,74 @@
+// Per-address notification preferences.
+//
+// The contract never sends notifications itself. It only stores which kinds of
+// alerts an address has opted into, as a compact bitmask, so that off-chain
+// services (e.g. an indexer driving emails or push) can read a single value per
+// address and stay in sync through the `NotificationPrefsChanged` event.
+
+use soroban_sdk::{contractevent, contractimpl, Address, Env};
+
+use crate::{
+    enter, events, DataKey, Error, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient,
+};
+
+// --- PREFERENCE BITS ---
+
+/// Reminder before a booked stay begins.
+pub const NOTIFY_BOOKING_REMINDER: u32 = 1 << 0;
+/// A maintenance fee is coming due.
+pub const NOTIFY_FEE_DUE: u32 = 1 << 1;
+/// The address has been outbid on an auction or offer.
+pub const NOTIFY_OUTBID: u32 = 1 << 2;
+
+/// Every bit currently understood by the contract.
+pub const NOTIFY_ALL: u32 = NOTIFY_BOOKING_REMINDER | NOTIFY_FEE_DUE | NOTIFY_OUTBID;
+
+/**
+ * @title NotificationPrefsChanged
+ * @dev Published whenever an address updates its preferences.
+ * Topics: ("notification_prefs_changed", owner). Data: the new bitmask.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotificationPrefsChanged {
+    #[topic]
+    pub owner: Address,
+    pub mask: u32,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Replaces the notification preferences of `owner`.
+     * @param owner The address whose preferences are updated (must sign).
+     * @param mask A combination of the NOTIFY_* bits. 0 opts out of everything.
+     */
+    pub fn set_notification_prefs(env: Env, owner: Address, mask: u32) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "set_notification_prefs")?;
+
+        // Reject bits we don't know about, so a typo doesn't silently store garbage.
+        if mask & !NOTIFY_ALL != 0 {
+            return Err(Error::UnknownNotificationBits);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::NotifyPrefs(owner.clone()), &mask);
+
+        events::emit(&env, &NotificationPrefsChanged { owner, mask });
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the notification preferences of `owner`.
+     * @return The stored bitmask, or 0 if the address never opted in.
+     */
+    pub fn get_notification_prefs(env: Env, owner: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::NotifyPrefs(owner))
+            .unwrap_or(0)
+    }
+}
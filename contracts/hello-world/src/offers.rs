@@ -0,0 +1,242 @@
+// Offers on unlisted tokens.
+//
+// A buyer who wants a token that isn't for sale makes an offer with
+// `make_offer`: the amount is moved into the contract, which holds it until
+// the token's owner accepts with `accept_offer` (payment and token change
+// hands in the same call) or the buyer takes it back with `cancel_offer`.
+// Each buyer has at most one offer per token; a new one replaces it and
+// refunds the old amount.
+//
+// Accepted offers pay the hotel's royalty like any resale (see royalties.rs).
+// On properties where the hotel has a right of first refusal, an accepted
+// offer first waits out the hotel's window to match it (see refusal.rs).
+// Offers are made to whoever owns the token, so they survive transfers, and
+// can't be accepted after they expire. The escrowed amount is only ever paid
+// to an accepting owner or back to the buyer: expired offers, and offers on
+// burned tokens, wait for the buyer to cancel them.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, token, Address, Env};
+
+use crate::{
+    check_owner, enter, events, fail, freeze, move_token, owner_of, pause, receipts, refusal,
+    royalties, DataKey, Error, ExtError, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, ReceiptKind,
+};
+
+/**
+ * @title Offer
+ * @dev A buyer's escrowed offer for a token.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Offer {
+    /// Token the amount is paid in.
+    pub payment_token: Address,
+    pub amount: i128,
+    /// Ledger timestamp after which the offer can't be accepted.
+    pub expires_at: u64,
+}
+
+/**
+ * @title OfferMade
+ * @dev Published when a buyer makes or replaces an offer.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferMade {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub buyer: Address,
+    pub payment_token: Address,
+    pub amount: i128,
+    pub expires_at: u64,
+}
+
+/**
+ * @title OfferCancelled
+ * @dev Published when a buyer takes back an offer.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferCancelled {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub buyer: Address,
+}
+
+/**
+ * @title OfferAccepted
+ * @dev Published when an owner accepts an offer, next to its Transfer event.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferAccepted {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub buyer: Address,
+    pub seller: Address,
+    pub amount: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Offers to buy a token, moving the amount into the contract. Fails
+     * with OfferExpired if the offer would already have expired.
+     * @param buyer The buyer (must sign and pay).
+     * @param amount The offer; must be positive.
+     * @param expires_at Ledger timestamp until which the offer can be accepted.
+     */
+    pub fn make_offer(
+        env: Env,
+        buyer: Address,
+        token_id: u64,
+        payment_token: Address,
+        amount: i128,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        buyer.require_auth();
+        enter(&env, "make_offer")?;
+        pause::check(&env)?;
+
+        owner_of(&env, token_id)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if expires_at < env.ledger().timestamp() {
+            fail(&env, ExtError::OfferExpired);
+        }
+        token::Client::new(&env, &payment_token).transfer(
+            &buyer,
+            env.current_contract_address(),
+            &amount,
+        );
+        if let Some(old) = Self::offer(env.clone(), token_id, buyer.clone()) {
+            refund(&env, &buyer, &old);
+        }
+        env.storage().persistent().set(
+            &DataKey::Offer(token_id, buyer.clone()),
+            &Offer {
+                payment_token: payment_token.clone(),
+                amount,
+                expires_at,
+            },
+        );
+
+        events::emit(
+            &env,
+            &OfferMade {
+                token_id,
+                buyer,
+                payment_token,
+                amount,
+                expires_at,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Takes back an offer and its escrowed amount, expired or not. Works
+     * while paused, so a pause never locks a buyer's funds in escrow.
+     * @param buyer The buyer who made it (must sign).
+     */
+    pub fn cancel_offer(env: Env, buyer: Address, token_id: u64) -> Result<(), Error> {
+        buyer.require_auth();
+        enter(&env, "cancel_offer")?;
+
+        let offer = Self::offer(env.clone(), token_id, buyer.clone())
+            .unwrap_or_else(|| fail(&env, ExtError::NoOffer));
+        remove_offer(&env, token_id, &buyer);
+        refund(&env, &buyer, &offer);
+
+        events::emit(&env, &OfferCancelled { token_id, buyer });
+        Ok(())
+    }
+
+    /**
+     * @dev Accepts a buyer's offer: the owner is paid the escrowed amount and
+     * the buyer receives the token. Fails with OfferExpired once it expired.
+     * Where the hotel has a right of first refusal, the token and the
+     * escrowed amount are held until the hotel matches the offer or its
+     * window lapses instead (see first_refusal()).
+     * @param owner The current owner (must sign).
+     */
+    pub fn accept_offer(
+        env: Env,
+        owner: Address,
+        token_id: u64,
+        buyer: Address,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "accept_offer")?;
+        pause::check(&env)?;
+
+        check_owner(&env, token_id, &owner)?;
+        freeze::check(&env, token_id);
+        let offer = Self::offer(env.clone(), token_id, buyer.clone())
+            .unwrap_or_else(|| fail(&env, ExtError::NoOffer));
+        if env.ledger().timestamp() > offer.expires_at {
+            fail(&env, ExtError::OfferExpired);
+        }
+        remove_offer(&env, token_id, &buyer);
+        if refusal::applies(&env, token_id) {
+            return refusal::hold(&env, token_id, &owner, &buyer, offer);
+        }
+        royalties::pay(
+            &env,
+            token_id,
+            &offer.payment_token,
+            &env.current_contract_address(),
+            &owner,
+            offer.amount,
+        )?;
+        move_token(&env, &owner, &buyer, token_id)?;
+        receipts::record_sale(
+            &env,
+            ReceiptKind::Resale,
+            token_id,
+            &buyer,
+            &owner,
+            offer.amount,
+            &offer.payment_token,
+        );
+
+        events::emit(
+            &env,
+            &OfferAccepted {
+                token_id,
+                buyer,
+                seller: owner,
+                amount: offer.amount,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns a buyer's offer for a token, if they have made one.
+     */
+    pub fn offer(env: Env, token_id: u64, buyer: Address) -> Option<Offer> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Offer(token_id, buyer))
+    }
+}
+
+fn refund(env: &Env, buyer: &Address, offer: &Offer) {
+    token::Client::new(env, &offer.payment_token).transfer(
+        &env.current_contract_address(),
+        buyer,
+        &offer.amount,
+    );
+}
+
+fn remove_offer(env: &Env, token_id: u64, buyer: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Offer(token_id, buyer.clone()));
+}
@@ -0,0 +1,87 @@
+// Emergency stop.
+//
+// A holder of the Pauser role (or the admin) can `pause` the contract when a
+// bug or exploit is found. While paused, every mint, every change of a token's
+// owner and every endpoint that moves funds fails with ContractPaused; reads
+// and administration keep working so the problem can be fixed, after which
+// `unpause` resumes activity. New payment endpoints must call `check`, except
+// those that only hand callers back their own escrow (`cancel_offer`).
+//
+// The flag lives in instance storage next to ADMIN: it is read by every
+// guarded call and written only in an emergency.
+
+use soroban_sdk::{contractevent, contractimpl, symbol_short, Address, Env, Symbol};
+
+use crate::{
+    enter, events, roles, Error, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, Role,
+};
+
+// Instance key of the paused flag.
+const PAUSED: Symbol = symbol_short!("PAUSED");
+
+/**
+ * @title Paused
+ * @dev Published when the contract is paused.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Paused {
+    #[topic]
+    pub by: Address,
+}
+
+/**
+ * @title Unpaused
+ * @dev Published when the contract is unpaused.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unpaused {
+    #[topic]
+    pub by: Address,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Stops mints, transfers and payments. Pauser role (or admin).
+     * @param caller The pauser (must sign).
+     */
+    pub fn pause(env: Env, caller: Address) -> Result<(), Error> {
+        roles::require_role(&env, Role::Pauser, &caller)?;
+        enter(&env, "pause")?;
+
+        env.storage().instance().set(&PAUSED, &true);
+        events::emit(&env, &Paused { by: caller });
+        Ok(())
+    }
+
+    /**
+     * @dev Resumes activity after pause(). Pauser role (or admin).
+     * @param caller The pauser (must sign).
+     */
+    pub fn unpause(env: Env, caller: Address) -> Result<(), Error> {
+        roles::require_role(&env, Role::Pauser, &caller)?;
+        enter(&env, "unpause")?;
+
+        env.storage().instance().remove(&PAUSED);
+        events::emit(&env, &Unpaused { by: caller });
+        Ok(())
+    }
+
+    /**
+     * @dev Returns whether the contract is paused.
+     */
+    pub fn paused(env: Env) -> bool {
+        env.storage().instance().has(&PAUSED)
+    }
+}
+
+/// Fails with ContractPaused while the contract is paused.
+pub(crate) fn check(env: &Env) -> Result<(), Error> {
+    if HotelTimeshareContract::paused(env.clone()) {
+        return Err(Error::ContractPaused);
+    }
+    Ok(())
+}
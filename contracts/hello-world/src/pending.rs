@@ -0,0 +1,124 @@
+// Two-phase (confirmed) transfers.
+//
+// Cautious owners can opt in so that `transfer` no longer moves the token
+// immediately. Instead it records a pending transfer that the recipient must
+// accept within its grace period (PENDING_TRANSFER_WINDOW unless changed, see
+// grace.rs). If nobody accepts, the pending
+// transfer simply lapses and the token never left its owner, so deeds can't be
+// sent to a mistyped or unclaimable address.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env};
+
+use crate::{
+    check_owner, enter, events, grace, move_token, DataKey, Error, GraceRule,
+    HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/// How long (in seconds of ledger time) a recipient has to accept a pending
+/// transfer, unless the grace period table says otherwise.
+pub const PENDING_TRANSFER_WINDOW: u64 = 3 * 24 * 60 * 60;
+
+/**
+ * @title PendingTransfer
+ * @dev A transfer waiting for the recipient's acceptance.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingTransfer {
+    pub from: Address,
+    pub to: Address,
+    pub expires_at: u64, // Ledger timestamp after which it can no longer be accepted.
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferProposed {
+    #[topic]
+    pub token_id: u64,
+    pub from: Address,
+    pub to: Address,
+    pub expires_at: u64,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Turns two-phase transfers on or off for tokens sent by `owner`.
+     * @param owner The owner changing the setting (must sign).
+     */
+    pub fn set_transfer_confirmation(env: Env, owner: Address, enabled: bool) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "set_transfer_confirmation")?;
+
+        let key = DataKey::ConfirmTransfers(owner);
+        if enabled {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the pending transfer of a token, if any, including lapsed ones.
+     */
+    pub fn get_pending_transfer(env: Env, token_id: u64) -> Option<PendingTransfer> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingTransfer(token_id))
+    }
+
+    /**
+     * @dev Completes a pending transfer. Must be signed by the recipient before it expires.
+     * @param token_id The ID of the token being transferred.
+     */
+    pub fn accept_transfer(env: Env, token_id: u64) -> Result<(), Error> {
+        let pending: PendingTransfer =
+            Self::get_pending_transfer(env.clone(), token_id).ok_or(Error::NoPendingTransfer)?;
+        pending.to.require_auth();
+        enter(&env, "accept_transfer")?;
+
+        if env.ledger().timestamp() > pending.expires_at {
+            return Err(Error::PendingTransferExpired);
+        }
+
+        // The owner could have moved the token some other way in the meantime.
+        check_owner(&env, token_id, &pending.from)?;
+
+        move_token(&env, &pending.from, &pending.to, token_id)
+    }
+}
+
+pub(crate) fn confirmation_required(env: &Env, owner: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::ConfirmTransfers(owner.clone()))
+}
+
+/// Records a pending transfer, replacing any earlier one for the same token.
+pub(crate) fn propose(env: &Env, token_id: u64, from: &Address, to: &Address) {
+    let pending = PendingTransfer {
+        from: from.clone(),
+        to: to.clone(),
+        expires_at: grace::ends(env, GraceRule::PendingTransfer, env.ledger().timestamp()),
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::PendingTransfer(token_id), &pending);
+
+    events::emit(
+        env,
+        &TransferProposed {
+            token_id,
+            from: pending.from,
+            to: pending.to,
+            expires_at: pending.expires_at,
+        },
+    );
+}
+
+pub(crate) fn clear(env: &Env, token_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PendingTransfer(token_id));
+}
@@ -0,0 +1,368 @@
+// Prepaid maintenance.
+//
+// Owners can pay maintenance fees (see maintenance.rs) for several years
+// ahead with `prepay_maintenance`, at a discount: the admin sets a curve of
+// discounts in basis points by term, where entry n - 1 applies to each year of
+// an n-year prepayment (terms past the end of the curve get its last entry).
+// `fee_schedule` previews the years a prepayment would cover and what each
+// would cost. Prepayments cover the current year and later ones only: a token
+// that owes earlier years pays them with `pay_maintenance` first.
+//
+// The contract holds each prepaid year's fee, recorded per (token, year),
+// until the year begins; a prepaid year counts as paid in
+// `maintenance_status`. The fee of a year that has begun is released to the
+// collector (less the reserve's share, see reserve.rs) by the next
+// `pay_maintenance`, by anyone calling `release_prepaid`, or when the token
+// is burned or clawed back. At that point years that haven't begun are
+// refunded to the owner losing the token. Prepaid years stay with the token
+// through sales and transfers, like the paid-year record.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, token, Address, Env, Vec};
+
+use crate::{
+    check_owner, enter, events, fail, fee_index, migration, money, or_fail, pause, require_admin,
+    reserve, usage, Amount, Bps, DataKey, Error, ExtError, ExtKey2, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/// Most years of maintenance that can be paid at once.
+pub const MAX_PREPAY_YEARS: u32 = 10;
+
+/// Basis points in a whole: a discount of FULL_DISCOUNT_BPS waives the fee.
+pub const FULL_DISCOUNT_BPS: u32 = 10_000;
+
+/**
+ * @title FeeQuote
+ * @dev One year's maintenance fee in a prepayment.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeQuote {
+    pub year: u32,
+    /// The fee after the prepayment discount.
+    pub fee: i128,
+}
+
+/**
+ * @title Prepayment
+ * @dev A prepaid year's fee, held by the contract until the year begins.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Prepayment {
+    /// Token the fee was paid in.
+    pub token: Address,
+    pub amount: i128,
+}
+
+/**
+ * @title PrepaidYears
+ * @dev The years of a token whose prepaid fees the contract still holds.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrepaidYears {
+    pub first: u32,
+    pub last: u32,
+}
+
+/**
+ * @title MaintenancePrepaid
+ * @dev Published when an owner prepays maintenance for a run of years.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenancePrepaid {
+    #[topic]
+    pub token_id: u64,
+    pub owner: Address,
+    pub first_year: u32,
+    pub last_year: u32,
+    pub amount: i128,
+}
+
+/**
+ * @title PrepaymentRefunded
+ * @dev Published when the prepaid fees of years that haven't begun are
+ * refunded because the token was burned or clawed back.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrepaymentRefunded {
+    #[topic]
+    pub token_id: u64,
+    pub to: Address,
+    pub amount: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Sets the prepayment discount curve. Admin only.
+     * @param discounts Entry n - 1 is the discount, in basis points of each
+     * year's fee, for prepaying n years. At most MAX_PREPAY_YEARS entries of
+     * at most FULL_DISCOUNT_BPS; empty gives no discount.
+     */
+    pub fn set_prepay_discounts(env: Env, discounts: Vec<u32>) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "set_prepay_discounts")?;
+
+        if discounts.len() > MAX_PREPAY_YEARS || discounts.iter().any(|bps| bps > FULL_DISCOUNT_BPS)
+        {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage()
+            .persistent()
+            .set(&ExtKey2::PrepayDiscounts, &discounts);
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the prepayment discount curve.
+     */
+    pub fn prepay_discounts(env: Env) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::PrepayDiscounts)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /**
+     * @dev Previews a prepayment: the years it would cover, from the token's
+     * first unpaid year, and each year's discounted fee. Fails with
+     * InvalidAmount without a fee schedule or unless `years` is in
+     * 1..=MAX_PREPAY_YEARS, and MaintenanceArrears while years before the
+     * current one are owed: those are paid with `pay_maintenance`, undiscounted.
+     */
+    pub fn fee_schedule(env: Env, token_id: u64, years: u32) -> Result<Vec<FeeQuote>, Error> {
+        let status = Self::maintenance_status(env.clone(), token_id)?;
+        let schedule = Self::maintenance_schedule(env.clone()).ok_or(Error::InvalidAmount)?;
+        if years == 0 || years > MAX_PREPAY_YEARS {
+            return Err(Error::InvalidAmount);
+        }
+        if status.unpaid_years > 1 {
+            fail(&env, ExtError::MaintenanceArrears);
+        }
+        let discounts = Self::prepay_discounts(env.clone());
+        let discount = discounts
+            .get(years.min(discounts.len()).saturating_sub(1))
+            .unwrap_or(0);
+        let info = migration::read_info(&env, token_id).ok_or(Error::TokenNotFound)?;
+        let unit = money::minor_unit(&env, info.hotel_id, &schedule.token);
+        let fee = Amount::of(schedule.fee).and_then(|fee| {
+            let off = Bps::of(discount).and_then(|bps| money::share(fee, bps, unit))?;
+            fee.checked_sub(off)
+        });
+        let fee = or_fail(&env, fee).get();
+
+        let first = status.year.max(status.paid_through + 1);
+        let mut quotes = Vec::new(&env);
+        for year in first..first + years {
+            quotes.push_back(FeeQuote { year, fee });
+        }
+        Ok(quotes)
+    }
+
+    /**
+     * @dev Prepays maintenance for `years` years from the token's first unpaid
+     * year, as quoted by `fee_schedule`. The current year, if covered, is paid
+     * to the collector straight away. Fails like `fee_schedule`, and with
+     * InvalidAmount unless the schedule bills fees in `token_address`.
+     * @param owner The current owner (must sign and pay).
+     * @return The years paid for and their fees.
+     */
+    pub fn prepay_maintenance(
+        env: Env,
+        owner: Address,
+        token_id: u64,
+        token_address: Address,
+        years: u32,
+    ) -> Result<Vec<FeeQuote>, Error> {
+        owner.require_auth();
+        enter(&env, "prepay_maintenance")?;
+        pause::check(&env)?;
+
+        check_owner(&env, token_id, &owner)?;
+        Self::maintenance_schedule(env.clone())
+            .filter(|schedule| schedule.token == token_address)
+            .ok_or(Error::InvalidAmount)?;
+        let quotes = Self::fee_schedule(env.clone(), token_id, years)?;
+        let mut total = Amount::ZERO;
+        for quote in quotes.iter() {
+            total = or_fail(
+                &env,
+                Amount::of(quote.fee).and_then(|fee| total.checked_add(fee)),
+            );
+        }
+        if total > Amount::ZERO {
+            token::Client::new(&env, &token_address).transfer(
+                &owner,
+                env.current_contract_address(),
+                &total.get(),
+            );
+            fee_index::bill(&env);
+        }
+
+        for quote in quotes.iter() {
+            env.storage().persistent().set(
+                &ExtKey2::Prepaid(token_id, quote.year),
+                &Prepayment {
+                    token: token_address.clone(),
+                    amount: quote.fee,
+                },
+            );
+        }
+        let first_year = quotes.first().map_or(0, |quote| quote.year);
+        let last_year = quotes.last().map_or(0, |quote| quote.year);
+        let held = Self::prepaid_years(env.clone(), token_id).map_or(first_year, |held| held.first);
+        env.storage().persistent().set(
+            &ExtKey2::PrepaidYears(token_id),
+            &PrepaidYears {
+                first: held,
+                last: last_year,
+            },
+        );
+
+        events::emit(
+            &env,
+            &MaintenancePrepaid {
+                token_id,
+                owner,
+                first_year,
+                last_year,
+                amount: total.get(),
+            },
+        );
+        release(&env, token_id);
+        Ok(quotes)
+    }
+
+    /**
+     * @dev Releases the prepaid fees of a token's years that have begun to
+     * the collector. Anyone may call it.
+     */
+    pub fn release_prepaid(env: Env, token_id: u64) -> Result<(), Error> {
+        enter(&env, "release_prepaid")?;
+
+        Self::get_owner(env.clone(), token_id)?;
+        release(&env, token_id);
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the years of a token whose prepaid fees the contract still
+     * holds, if any.
+     */
+    pub fn prepaid_years(env: Env, token_id: u64) -> Option<PrepaidYears> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::PrepaidYears(token_id))
+    }
+
+    /**
+     * @dev Returns the prepaid fee the contract holds for one year of a token.
+     */
+    pub fn prepayment(env: Env, token_id: u64, year: u32) -> Option<Prepayment> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::Prepaid(token_id, year))
+    }
+}
+
+/// Extends the last year a token is paid through by the prepaid years that
+/// follow it.
+pub(crate) fn paid_through(env: &Env, token_id: u64, paid_through: u32) -> u32 {
+    match HotelTimeshareContract::prepaid_years(env.clone(), token_id) {
+        Some(held) if held.first <= paid_through + 1 => paid_through.max(held.last),
+        _ => paid_through,
+    }
+}
+
+/// Pays the prepaid fees of a token's years that have begun to the collector,
+/// recording each as the token's last paid year.
+pub(crate) fn release(env: &Env, token_id: u64) {
+    let Some(mut held) = HotelTimeshareContract::prepaid_years(env.clone(), token_id) else {
+        return;
+    };
+    let current = usage::current_year(env);
+    if held.first > current {
+        return;
+    }
+    // Prepayments need a schedule, and the admin can change it but not
+    // remove it.
+    let (Some(schedule), Some(info)) = (
+        HotelTimeshareContract::maintenance_schedule(env.clone()),
+        migration::read_info(env, token_id),
+    ) else {
+        return;
+    };
+    while held.first <= current.min(held.last) {
+        let key = ExtKey2::Prepaid(token_id, held.first);
+        if let Some(prepayment) = env.storage().persistent().get::<_, Prepayment>(&key) {
+            if prepayment.amount > 0 {
+                reserve::pay_fee(
+                    env,
+                    info.hotel_id,
+                    &prepayment.token,
+                    &env.current_contract_address(),
+                    &schedule.collector,
+                    or_fail(env, Amount::of(prepayment.amount)),
+                );
+            }
+            env.storage().persistent().remove(&key);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Maintenance(token_id), &held.first);
+        held.first += 1;
+    }
+
+    if held.first > held.last {
+        env.storage()
+            .persistent()
+            .remove(&ExtKey2::PrepaidYears(token_id));
+    } else {
+        env.storage()
+            .persistent()
+            .set(&ExtKey2::PrepaidYears(token_id), &held);
+    }
+}
+
+/// Settles a token's prepayment when it is burned or clawed back: years that
+/// have begun are released, and the rest refunded to `owner`.
+pub(crate) fn close(env: &Env, token_id: u64, owner: &Address) {
+    release(env, token_id);
+    let Some(held) = HotelTimeshareContract::prepaid_years(env.clone(), token_id) else {
+        return;
+    };
+    env.storage()
+        .persistent()
+        .remove(&ExtKey2::PrepaidYears(token_id));
+
+    let mut refunded = Amount::ZERO;
+    for year in held.first..=held.last {
+        let key = ExtKey2::Prepaid(token_id, year);
+        if let Some(prepayment) = env.storage().persistent().get::<_, Prepayment>(&key) {
+            env.storage().persistent().remove(&key);
+            if prepayment.amount > 0 {
+                token::Client::new(env, &prepayment.token).transfer(
+                    &env.current_contract_address(),
+                    owner,
+                    &prepayment.amount,
+                );
+                refunded = or_fail(
+                    env,
+                    Amount::of(prepayment.amount).and_then(|amount| refunded.checked_add(amount)),
+                );
+            }
+        }
+    }
+    events::emit(
+        env,
+        &PrepaymentRefunded {
+            token_id,
+            to: owner.clone(),
+            amount: refunded.get(),
+        },
+    );
+}
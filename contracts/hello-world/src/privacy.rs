@@ -0,0 +1,96 @@
+// Thresholded aggregate stats.
+//
+// Per-property counts tell competitors how well a hotel sells: a property
+// with three live tokens, or one listing, gives its occupancy away. The admin
+// can set how exact the public stats endpoints are with `set_stats_privacy`:
+// counts below `min_count` read as 0, and the rest are rounded down to a
+// multiple of `round_to`. The counts this applies to are `hotel_supply` and
+// the listing count of `market_depth`; a book whose count reads as 0 also
+// hides its floor and cheapest listings, in `floor_price` too. By default
+// (a `min_count` of 0 and a `round_to` of 1) every count is exact.
+//
+// It only changes what those endpoints report. Caps and other checks keep
+// using the exact counts, and the ledger entries behind them stay public, so
+// this keeps casual dashboards from showing small numbers rather than hiding
+// them from someone reading raw contract storage.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Env};
+
+use crate::{
+    enter, events, require_admin, Error, ExtKey2, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/**
+ * @title StatsPrivacy
+ * @dev How exact the public stats endpoints are; see the module comment.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatsPrivacy {
+    /// Counts below it read as 0.
+    pub min_count: u32,
+    /// Counts are rounded down to a multiple of it; at least 1.
+    pub round_to: u32,
+}
+
+/**
+ * @title StatsPrivacySet
+ * @dev Published when the admin changes how exact the stats are.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatsPrivacySet {
+    pub min_count: u32,
+    pub round_to: u32,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Sets how exact the public stats endpoints are. Admin only. Fails
+     * with InvalidAmount for a `round_to` of 0.
+     */
+    pub fn set_stats_privacy(env: Env, privacy: StatsPrivacy) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "set_stats_privacy")?;
+
+        if privacy.round_to == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage()
+            .persistent()
+            .set(&ExtKey2::StatsPrivacy, &privacy);
+
+        events::emit(
+            &env,
+            &StatsPrivacySet {
+                min_count: privacy.min_count,
+                round_to: privacy.round_to,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns how exact the public stats endpoints are.
+     */
+    pub fn stats_privacy(env: Env) -> StatsPrivacy {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::StatsPrivacy)
+            .unwrap_or(StatsPrivacy {
+                min_count: 0,
+                round_to: 1,
+            })
+    }
+}
+
+/// Returns `count` as the public stats endpoints report it.
+pub(crate) fn blur(env: &Env, count: u32) -> u32 {
+    let privacy = HotelTimeshareContract::stats_privacy(env.clone());
+    if count < privacy.min_count {
+        return 0;
+    }
+    count - count % privacy.round_to
+}
@@ -0,0 +1,238 @@
+// Purchase receipts.
+//
+// When the admin turns them on with `set_receipts`, every completed sale or
+// trade also writes a Receipt of its terms under the token: buyer, seller,
+// price, payment token and time. That covers resales (`buy`, `accept_offer`,
+// `settle_auction`, `settle_sealed`), primary sales (every sale paid through
+// `refunds::collect`: `buy_mint`, `allowlist_mint`, `redeem_voucher` and
+// `buy_dutch`) and token-for-token trades (`swap`, `accept_trade`), where each
+// token gets a receipt naming the one it was traded for. Events are only as
+// durable as whoever indexes them; a receipt stays queryable on-chain for as
+// long as the entry lives, and is kept when the token is burned.
+//
+// Receipts are numbered per token from 0, so settlements of different tokens
+// don't share a counter. Each is also indexed under its buyer and its seller,
+// so either party can page through theirs with `receipts_of`.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env, Vec};
+
+use crate::{
+    enter, events, require_admin, valuation, Error, ExtKey, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, MAX_PAGE,
+};
+
+/**
+ * @title ReceiptKind
+ * @dev How a token changed hands.
+ */
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ReceiptKind {
+    /// Bought from its owner, on the market, by offer, at auction or by the
+    /// hotel's buyback.
+    Resale,
+    /// Bought from the hotel when it was minted, or at a Dutch auction.
+    PrimarySale,
+    /// Exchanged for another token.
+    Trade,
+}
+
+/**
+ * @title Receipt
+ * @dev The terms of a completed sale or trade.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Receipt {
+    pub token_id: u64,
+    pub kind: ReceiptKind,
+    /// Who received the token.
+    pub buyer: Address,
+    /// Who gave it up; the admin for primary sales.
+    pub seller: Address,
+    /// What the buyer paid, royalty included; 0 for trades.
+    pub price: i128,
+    /// None for trades.
+    pub payment_token: Option<Address>,
+    /// For trades, the token the buyer gave in exchange.
+    pub traded_for: Option<u64>,
+    /// Ledger timestamp of the settlement.
+    pub timestamp: u64,
+}
+
+/**
+ * @title ReceiptIssued
+ * @dev Published when a settlement is recorded as a receipt.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReceiptIssued {
+    #[topic]
+    pub token_id: u64,
+    pub number: u32,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Turns receipts for future settlements on or off. Admin only.
+     * Receipts already issued are kept.
+     */
+    pub fn set_receipts(env: Env, enabled: bool) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "set_receipts")?;
+
+        if enabled {
+            env.storage()
+                .persistent()
+                .set(&ExtKey::ReceiptsEnabled, &true);
+        } else {
+            env.storage().persistent().remove(&ExtKey::ReceiptsEnabled);
+        }
+        Ok(())
+    }
+
+    /**
+     * @dev Returns whether settlements are recorded as receipts.
+     */
+    pub fn receipts_enabled(env: Env) -> bool {
+        env.storage().persistent().has(&ExtKey::ReceiptsEnabled)
+    }
+
+    /**
+     * @dev Returns how many receipts a token has.
+     */
+    pub fn receipt_count(env: Env, token_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::ReceiptCount(token_id))
+            .unwrap_or(0)
+    }
+
+    /**
+     * @dev Returns one of a token's receipts, numbered from 0 in the order
+     * they were issued.
+     */
+    pub fn receipt(env: Env, token_id: u64, number: u32) -> Option<Receipt> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::Receipt(token_id, number))
+    }
+
+    /**
+     * @dev Returns how many receipts name an address as buyer or seller.
+     */
+    pub fn party_receipt_count(env: Env, party: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::PartyReceiptCount(party))
+            .unwrap_or(0)
+    }
+
+    /**
+     * @dev Lists the receipts naming an address as buyer or seller, oldest
+     * first.
+     * @param start The index of the first receipt, 0 for the first page.
+     * @param limit The page size, capped at MAX_PAGE.
+     */
+    pub fn receipts_of(env: Env, party: Address, start: u32, limit: u32) -> Vec<Receipt> {
+        let count = Self::party_receipt_count(env.clone(), party.clone());
+        let end = count.min(start.saturating_add(limit.min(MAX_PAGE)));
+
+        let mut receipts = Vec::new(&env);
+        for index in start..end {
+            let entry: Option<(u64, u32)> = env
+                .storage()
+                .persistent()
+                .get(&ExtKey::PartyReceipt(party.clone(), index));
+            if let Some(receipt) =
+                entry.and_then(|(token_id, number)| Self::receipt(env.clone(), token_id, number))
+            {
+                receipts.push_back(receipt);
+            }
+        }
+        receipts
+    }
+}
+
+/// Records a sale of a token for `price` if receipts are on, and marks the
+/// token's price either way (see valuation.rs).
+pub(crate) fn record_sale(
+    env: &Env,
+    kind: ReceiptKind,
+    token_id: u64,
+    buyer: &Address,
+    seller: &Address,
+    price: i128,
+    payment_token: &Address,
+) {
+    valuation::mark(env, token_id, payment_token, price);
+    record(
+        env,
+        Receipt {
+            token_id,
+            kind,
+            buyer: buyer.clone(),
+            seller: seller.clone(),
+            price,
+            payment_token: Some(payment_token.clone()),
+            traded_for: None,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+/// Records one leg of a trade if receipts are on: `token_id` went from
+/// `from` to `to`, who gave `traded_for` for it.
+pub(crate) fn record_trade(
+    env: &Env,
+    token_id: u64,
+    from: &Address,
+    to: &Address,
+    traded_for: u64,
+) {
+    record(
+        env,
+        Receipt {
+            token_id,
+            kind: ReceiptKind::Trade,
+            buyer: to.clone(),
+            seller: from.clone(),
+            price: 0,
+            payment_token: None,
+            traded_for: Some(traded_for),
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+fn record(env: &Env, receipt: Receipt) {
+    if !HotelTimeshareContract::receipts_enabled(env.clone()) {
+        return;
+    }
+    let token_id = receipt.token_id;
+    let number = HotelTimeshareContract::receipt_count(env.clone(), token_id);
+    index(env, &receipt.buyer, token_id, number);
+    if receipt.seller != receipt.buyer {
+        index(env, &receipt.seller, token_id, number);
+    }
+    env.storage()
+        .persistent()
+        .set(&ExtKey::Receipt(token_id, number), &receipt);
+    env.storage()
+        .persistent()
+        .set(&ExtKey::ReceiptCount(token_id), &(number + 1));
+    events::emit(env, &ReceiptIssued { token_id, number });
+}
+
+/// Adds a token's receipt to a party's index.
+fn index(env: &Env, party: &Address, token_id: u64, number: u32) {
+    let count = HotelTimeshareContract::party_receipt_count(env.clone(), party.clone());
+    env.storage().persistent().set(
+        &ExtKey::PartyReceipt(party.clone(), count),
+        &(token_id, number),
+    );
+    env.storage()
+        .persistent()
+        .set(&ExtKey::PartyReceiptCount(party.clone()), &(count + 1));
+}
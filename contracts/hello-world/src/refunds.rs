@@ -0,0 +1,270 @@
+// Cooling-off period on primary sales.
+//
+// Consumer-protection rules let timeshare buyers back out shortly after
+// buying. Once the admin sets a cooling-off period with `set_cooling_off`,
+// the proceeds of every primary sale (`buy_mint`, vouchers, the allowlist and
+// Dutch auctions) are escrowed by the contract under a Purchase record instead
+// of being paid out. Within the period the buyer, while still the owner, can
+// `cancel_purchase`: the token is burned, freeing its week for sale again, and
+// the buyer is refunded the configured share of the price. The rest goes to
+// the seller. After the period anyone can `release_purchase` to pay the seller
+// in full.
+//
+// Sellers are paid through splitter.rs, so the hotel's payees get their
+// shares either way. The Purchase outlives the token (a burn or clawback
+// during the period doesn't strand the escrow): it is removed once released
+// or cancelled.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, token, Address, Env};
+
+use crate::{
+    burn, enter, events, fail, migration, money, or_fail, pause, receipts, require_admin, splitter,
+    Amount, Bps, Error, ExtError, ExtKey, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, ReceiptKind,
+};
+
+/// Basis points in a whole: a refund of FULL_REFUND_BPS is the full price.
+pub const FULL_REFUND_BPS: u32 = 10_000;
+
+/**
+ * @title CoolingOff
+ * @dev How long buyers have to cancel a primary purchase, and what they get back.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoolingOff {
+    /// Ledgers after the sale during which the buyer may cancel.
+    pub period: u32,
+    /// Share of the price refunded on cancellation, in basis points.
+    pub refund_bps: u32,
+}
+
+/**
+ * @title Purchase
+ * @dev A primary sale whose proceeds are escrowed until its cooling-off period ends.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Purchase {
+    pub buyer: Address,
+    /// Who is paid once the period ends.
+    pub seller: Address,
+    pub payment_token: Address,
+    pub price: i128,
+    /// Share of the price refunded on cancellation, as configured at the sale.
+    pub refund_bps: u32,
+    /// Last ledger sequence the buyer may cancel at.
+    pub cancel_until: u32,
+}
+
+/**
+ * @title PurchaseCancelled
+ * @dev Published when a buyer cancels a purchase, next to the Burn event.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurchaseCancelled {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub buyer: Address,
+    pub refund: i128,
+}
+
+/**
+ * @title PurchaseReleased
+ * @dev Published when a purchase's escrowed proceeds are paid to the seller.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurchaseReleased {
+    #[topic]
+    pub token_id: u64,
+    pub seller: Address,
+    pub amount: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Sets the cooling-off period for future primary sales. Admin only.
+     * Purchases already made keep their terms.
+     * @param period Ledgers buyers have to cancel; 0 turns cooling-off off.
+     * @param refund_bps Share of the price refunded, at most FULL_REFUND_BPS.
+     */
+    pub fn set_cooling_off(env: Env, period: u32, refund_bps: u32) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "set_cooling_off")?;
+
+        if refund_bps > FULL_REFUND_BPS {
+            return Err(Error::InvalidAmount);
+        }
+        if period == 0 {
+            env.storage().persistent().remove(&ExtKey::CoolingOff);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&ExtKey::CoolingOff, &CoolingOff { period, refund_bps });
+        }
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the cooling-off terms of new primary sales, if any.
+     */
+    pub fn cooling_off(env: Env) -> Option<CoolingOff> {
+        env.storage().persistent().get(&ExtKey::CoolingOff)
+    }
+
+    /**
+     * @dev Returns the escrowed purchase of a token, until it is released or
+     * cancelled.
+     */
+    pub fn purchase(env: Env, token_id: u64) -> Option<Purchase> {
+        env.storage().persistent().get(&ExtKey::Purchase(token_id))
+    }
+
+    /**
+     * @dev Cancels a purchase within its cooling-off period: burns the token
+     * and refunds the buyer. Fails with NoPurchase if there is no escrowed
+     * purchase, CoolingOffEnded after the period, like `burn` for tokens
+     * held in trust or frozen, and AmountOverflow if the refund overflows.
+     * @param buyer The buyer, who must still own the token (must sign).
+     * @return The amount refunded.
+     */
+    pub fn cancel_purchase(env: Env, buyer: Address, token_id: u64) -> Result<i128, Error> {
+        buyer.require_auth();
+        enter(&env, "cancel_purchase")?;
+        pause::check(&env)?;
+
+        // 1. Only the buyer, still holding the token, within the period.
+        let purchase = Self::purchase(env.clone(), token_id)
+            .unwrap_or_else(|| fail(&env, ExtError::NoPurchase));
+        if buyer != purchase.buyer {
+            return Err(Error::NotOwner);
+        }
+        if env.ledger().sequence() > purchase.cancel_until {
+            fail(&env, ExtError::CoolingOffEnded);
+        }
+        burn::check_burnable(&env, &buyer, token_id)?;
+        let info = migration::read_info(&env, token_id).ok_or(Error::TokenNotFound)?;
+        let unit = money::minor_unit(&env, info.hotel_id, &purchase.payment_token);
+        let price = or_fail(&env, Amount::of(purchase.price));
+        let refund = Bps::of(purchase.refund_bps).and_then(|bps| money::share(price, bps, unit));
+        let refund = or_fail(&env, refund);
+
+        // 2. Return the token, refund the buyer and pay the seller the rest.
+        remove_purchase(&env, token_id);
+        burn::burn_token(&env, &buyer, token_id);
+        let contract = env.current_contract_address();
+        token::Client::new(&env, &purchase.payment_token).transfer(
+            &contract,
+            &buyer,
+            &refund.get(),
+        );
+        splitter::pay(
+            &env,
+            &purchase.payment_token,
+            &contract,
+            &purchase.seller,
+            or_fail(&env, price.checked_sub(refund)).get(),
+        );
+
+        events::emit(
+            &env,
+            &PurchaseCancelled {
+                token_id,
+                buyer,
+                refund: refund.get(),
+            },
+        );
+        Ok(refund.get())
+    }
+
+    /**
+     * @dev Pays the seller a purchase's escrowed proceeds once its cooling-off
+     * period is over. Anyone may call it. Fails with CoolingOffRunning during
+     * the period.
+     */
+    pub fn release_purchase(env: Env, token_id: u64) -> Result<(), Error> {
+        enter(&env, "release_purchase")?;
+        pause::check(&env)?;
+
+        let purchase = Self::purchase(env.clone(), token_id)
+            .unwrap_or_else(|| fail(&env, ExtError::NoPurchase));
+        if env.ledger().sequence() <= purchase.cancel_until {
+            fail(&env, ExtError::CoolingOffRunning);
+        }
+        release(&env, token_id, purchase);
+        Ok(())
+    }
+}
+
+/// Takes payment for the primary sale of `token_id`: straight to the seller,
+/// or into escrow under a Purchase while a cooling-off period is set. A token
+/// sold again releases its earlier purchase, which its buyer no longer holds.
+/// The sale is receipted either way.
+pub(crate) fn collect(
+    env: &Env,
+    token_id: u64,
+    payment_token: &Address,
+    buyer: &Address,
+    seller: &Address,
+    price: i128,
+) {
+    if let Some(earlier) = HotelTimeshareContract::purchase(env.clone(), token_id) {
+        release(env, token_id, earlier);
+    }
+    receipts::record_sale(
+        env,
+        ReceiptKind::PrimarySale,
+        token_id,
+        buyer,
+        seller,
+        price,
+        payment_token,
+    );
+    let Some(terms) = HotelTimeshareContract::cooling_off(env.clone()) else {
+        splitter::pay(env, payment_token, buyer, seller, price);
+        return;
+    };
+    token::Client::new(env, payment_token).transfer(buyer, env.current_contract_address(), &price);
+    env.storage().persistent().set(
+        &ExtKey::Purchase(token_id),
+        &Purchase {
+            buyer: buyer.clone(),
+            seller: seller.clone(),
+            payment_token: payment_token.clone(),
+            price,
+            refund_bps: terms.refund_bps,
+            cancel_until: env.ledger().sequence().saturating_add(terms.period),
+        },
+    );
+}
+
+/// Pays the seller a purchase's escrowed proceeds.
+fn release(env: &Env, token_id: u64, purchase: Purchase) {
+    remove_purchase(env, token_id);
+    splitter::pay(
+        env,
+        &purchase.payment_token,
+        &env.current_contract_address(),
+        &purchase.seller,
+        purchase.price,
+    );
+
+    events::emit(
+        env,
+        &PurchaseReleased {
+            token_id,
+            seller: purchase.seller,
+            amount: purchase.price,
+        },
+    );
+}
+
+fn remove_purchase(env: &Env, token_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&ExtKey::Purchase(token_id));
+}
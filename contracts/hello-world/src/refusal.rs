@@ -0,0 +1,313 @@
+// Hotel right of first refusal.
+//
+// A property can reserve the hotel the right to match third-party sales of
+// its tokens: once the admin turns it on with `set_first_refusal`, an owner
+// accepting an offer (see offers.rs) doesn't settle the sale at once. The
+// token moves into the contract's custody and the escrowed offer into a
+// FirstRefusal, which is open for the GraceRule::FirstRefusal period
+// (FIRST_REFUSAL_WINDOW by default, see grace.rs).
+//
+// While it is open, the admin can `exercise_first_refusal`: the hotel pays the
+// seller the offered amount, takes the token, and the buyer's escrow is
+// refunded. Otherwise the sale falls through to the original buyer: once the
+// window has lapsed anyone can `complete_first_refusal`, which settles it
+// exactly as the accepted offer would have, royalty included. The admin can
+// waive the right early the same way. A token that leaves the contract's
+// custody some other way (clawback, admin burn) refunds the buyer.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, token, Address, Env, Vec};
+
+use crate::{
+    enter, events, fail, grace, hotels, migration, move_token, pause, receipts, require_admin,
+    royalties, Error, ExtError2, ExtKey2, GraceRule, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, Offer, OfferAccepted, ReceiptKind,
+    ADMIN,
+};
+
+/// Default seconds the hotel has to match an accepted offer.
+pub const FIRST_REFUSAL_WINDOW: u64 = 2 * 24 * 60 * 60;
+
+/**
+ * @title FirstRefusal
+ * @dev An accepted offer waiting out the hotel's right of first refusal,
+ * keyed by the token's ID.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FirstRefusal {
+    /// The owner who accepted the offer, and is paid for the token.
+    pub seller: Address,
+    /// Who made the offer, and receives the token if the hotel doesn't match it.
+    pub buyer: Address,
+    /// The escrowed offer.
+    pub offer: Offer,
+    /// Ledger timestamp after which the hotel can no longer match it.
+    pub ends_at: u64,
+}
+
+/**
+ * @title FirstRefusalSet
+ * @dev Published when the admin turns the right of first refusal on or off
+ * for a property.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FirstRefusalSet {
+    #[topic]
+    pub hotel_id: u32,
+    pub enabled: bool,
+}
+
+/**
+ * @title FirstRefusalOpened
+ * @dev Published when an accepted offer enters the hotel's refusal window.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FirstRefusalOpened {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub buyer: Address,
+    pub seller: Address,
+    pub amount: i128,
+    pub ends_at: u64,
+}
+
+/**
+ * @title FirstRefusalExercised
+ * @dev Published when the hotel matches an accepted offer, next to its
+ * Transfer event.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FirstRefusalExercised {
+    #[topic]
+    pub token_id: u64,
+    /// The buyer whose offer was matched, and refunded.
+    pub buyer: Address,
+    pub seller: Address,
+    pub amount: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Turns the hotel's right of first refusal on or off for a property.
+     * Offers accepted before it was turned off still wait out their window.
+     * Admin only.
+     */
+    pub fn set_first_refusal(env: Env, hotel_id: u32, enabled: bool) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "set_first_refusal")?;
+
+        hotels::check(&env, hotel_id)?;
+        let mut hotels = Self::first_refusal_hotels(env.clone());
+        match (hotels.first_index_of(hotel_id), enabled) {
+            (None, true) => hotels.push_back(hotel_id),
+            (Some(at), false) => {
+                hotels.remove(at);
+            }
+            _ => {}
+        }
+        env.storage()
+            .persistent()
+            .set(&ExtKey2::FirstRefusalHotels, &hotels);
+
+        events::emit(&env, &FirstRefusalSet { hotel_id, enabled });
+        Ok(())
+    }
+
+    /**
+     * @dev Lists the properties whose sales the hotel has a right of first
+     * refusal on, in the order it was turned on.
+     */
+    pub fn first_refusal_hotels(env: Env) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::FirstRefusalHotels)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /**
+     * @dev Matches an accepted offer while its refusal window is open: the
+     * seller is paid the offered amount by the admin (royalty included), the
+     * admin receives the token and the buyer is refunded. Admin only. Fails
+     * with NoFirstRefusal without one, and FirstRefusalLapsed once its
+     * window has passed.
+     */
+    pub fn exercise_first_refusal(env: Env, token_id: u64) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+        enter(&env, "exercise_first_refusal")?;
+        pause::check(&env)?;
+
+        let refusal = Self::first_refusal(env.clone(), token_id)
+            .unwrap_or_else(|| fail(&env, ExtError2::NoFirstRefusal));
+        if env.ledger().timestamp() > refusal.ends_at {
+            fail(&env, ExtError2::FirstRefusalLapsed);
+        }
+        remove(&env, token_id);
+        let offer = &refusal.offer;
+        refund(&env, &refusal);
+        royalties::pay(
+            &env,
+            token_id,
+            &offer.payment_token,
+            &admin,
+            &refusal.seller,
+            offer.amount,
+        )?;
+        move_token(&env, &env.current_contract_address(), &admin, token_id)?;
+        receipts::record_sale(
+            &env,
+            ReceiptKind::Resale,
+            token_id,
+            &admin,
+            &refusal.seller,
+            offer.amount,
+            &offer.payment_token,
+        );
+
+        events::emit(
+            &env,
+            &FirstRefusalExercised {
+                token_id,
+                buyer: refusal.buyer,
+                seller: refusal.seller,
+                amount: offer.amount,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Settles an accepted offer the hotel didn't match: the seller is
+     * paid the escrowed amount (royalty included) and the buyer receives the
+     * token. Anyone may call it once the refusal window has passed; the
+     * admin may call it earlier to waive the right. Fails with
+     * NoFirstRefusal without one, and FirstRefusalOpen while the window is
+     * open and the admin hasn't signed.
+     * @param caller Whoever settles it (must sign).
+     */
+    pub fn complete_first_refusal(env: Env, caller: Address, token_id: u64) -> Result<(), Error> {
+        caller.require_auth();
+        enter(&env, "complete_first_refusal")?;
+        pause::check(&env)?;
+
+        let refusal = Self::first_refusal(env.clone(), token_id)
+            .unwrap_or_else(|| fail(&env, ExtError2::NoFirstRefusal));
+        let admin: Option<Address> = env.storage().instance().get(&ADMIN);
+        if env.ledger().timestamp() <= refusal.ends_at && admin != Some(caller) {
+            fail(&env, ExtError2::FirstRefusalOpen);
+        }
+        remove(&env, token_id);
+        let offer = refusal.offer;
+        royalties::pay(
+            &env,
+            token_id,
+            &offer.payment_token,
+            &env.current_contract_address(),
+            &refusal.seller,
+            offer.amount,
+        )?;
+        move_token(
+            &env,
+            &env.current_contract_address(),
+            &refusal.buyer,
+            token_id,
+        )?;
+        receipts::record_sale(
+            &env,
+            ReceiptKind::Resale,
+            token_id,
+            &refusal.buyer,
+            &refusal.seller,
+            offer.amount,
+            &offer.payment_token,
+        );
+
+        events::emit(
+            &env,
+            &OfferAccepted {
+                token_id,
+                buyer: refusal.buyer,
+                seller: refusal.seller,
+                amount: offer.amount,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns a token's accepted offer waiting out the hotel's right of
+     * first refusal, if any.
+     */
+    pub fn first_refusal(env: Env, token_id: u64) -> Option<FirstRefusal> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey2::FirstRefusal(token_id))
+    }
+}
+
+/// Whether the hotel has a right of first refusal on sales of `token_id`.
+pub(crate) fn applies(env: &Env, token_id: u64) -> bool {
+    migration::read_info(env, token_id).is_some_and(|info| {
+        HotelTimeshareContract::first_refusal_hotels(env.clone()).contains(info.hotel_id)
+    })
+}
+
+/// Takes a token and its accepted offer into custody for the refusal window.
+pub(crate) fn hold(
+    env: &Env,
+    token_id: u64,
+    seller: &Address,
+    buyer: &Address,
+    offer: Offer,
+) -> Result<(), Error> {
+    move_token(env, seller, &env.current_contract_address(), token_id)?;
+    let ends_at = grace::ends(env, GraceRule::FirstRefusal, env.ledger().timestamp());
+    let amount = offer.amount;
+    env.storage().persistent().set(
+        &ExtKey2::FirstRefusal(token_id),
+        &FirstRefusal {
+            seller: seller.clone(),
+            buyer: buyer.clone(),
+            offer,
+            ends_at,
+        },
+    );
+    events::emit(
+        env,
+        &FirstRefusalOpened {
+            token_id,
+            buyer: buyer.clone(),
+            seller: seller.clone(),
+            amount,
+            ends_at,
+        },
+    );
+    Ok(())
+}
+
+/// Refunds the buyer when a token leaves the contract's custody other than
+/// by exercise_first_refusal or complete_first_refusal.
+pub(crate) fn lapse(env: &Env, token_id: u64) {
+    if let Some(refusal) = HotelTimeshareContract::first_refusal(env.clone(), token_id) {
+        remove(env, token_id);
+        refund(env, &refusal);
+    }
+}
+
+fn refund(env: &Env, refusal: &FirstRefusal) {
+    token::Client::new(env, &refusal.offer.payment_token).transfer(
+        &env.current_contract_address(),
+        &refusal.buyer,
+        &refusal.offer.amount,
+    );
+}
+
+fn remove(env: &Env, token_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&ExtKey2::FirstRefusal(token_id));
+}
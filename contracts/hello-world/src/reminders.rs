@@ -0,0 +1,158 @@
+// Deadline reminders.
+//
+// Indexers and webhook services that notify owners would otherwise have to
+// recompute every deadline from storage themselves. Instead, `deadlines`
+// lists a token's upcoming ones, and a keeper sweeping the tokens (see
+// `tokens`) calls `send_reminders` for each: every deadline that is less
+// than REMINDER_LEAD away publishes one DeadlineApproaching event, naming who
+// to notify. A deadline is reminded once; one that moves (a relisting, a new
+// year's fee) is reminded again. Deadlines tracked:
+//
+// - FeeDue: the owner's next unpaid maintenance fee, due the Delinquency
+//   grace period (see grace.rs) after 1 January, while a fee is billed;
+// - BookingWindow: the lister's rental listing nobody has taken, which
+//   closes at its renter window (see rental.rs);
+// - AuctionEnding: the seller's running auction (see auction.rs).
+//
+// The indexer matches each event's party against their notification
+// preferences (see notifications.rs).
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env, Map, Vec};
+
+use crate::{
+    enter, events, grace, owner_of, usage, Error, ExtKey2, GraceRule, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/// Seconds before a deadline from which it is reminded.
+pub const REMINDER_LEAD: u64 = 7 * 24 * 60 * 60;
+
+/**
+ * @title DeadlineKind
+ * @dev What a deadline is for; see the module comment.
+ */
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeadlineKind {
+    FeeDue,
+    BookingWindow,
+    AuctionEnding,
+}
+
+/**
+ * @title Deadline
+ * @dev An upcoming deadline of a token.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Deadline {
+    pub kind: DeadlineKind,
+    /// Ledger timestamp it falls at.
+    pub at: u64,
+    /// Who to notify: the owner, lister or seller.
+    pub party: Address,
+}
+
+/**
+ * @title DeadlineApproaching
+ * @dev Published once when a token's deadline is less than REMINDER_LEAD
+ * away.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadlineApproaching {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub kind: DeadlineKind,
+    pub party: Address,
+    pub at: u64,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Lists a token's upcoming deadlines, in DeadlineKind order.
+     */
+    pub fn deadlines(env: Env, token_id: u64) -> Result<Vec<Deadline>, Error> {
+        let owner = owner_of(&env, token_id)?;
+        let now = env.ledger().timestamp();
+        let mut deadlines = Vec::new(&env);
+
+        let billing = Self::maintenance_schedule(env.clone()).is_some_and(|s| s.fee > 0);
+        if billing {
+            let status = Self::maintenance_status(env.clone(), token_id)?;
+            let due = grace::ends(
+                &env,
+                GraceRule::Delinquency,
+                usage::year_start(status.paid_through + 1),
+            );
+            if due > now {
+                deadlines.push_back(Deadline {
+                    kind: DeadlineKind::FeeDue,
+                    at: due,
+                    party: owner,
+                });
+            }
+        }
+        if let Some(rental) = Self::rental(env.clone(), token_id) {
+            if rental.renter.is_none() && rental.renter_window > now {
+                deadlines.push_back(Deadline {
+                    kind: DeadlineKind::BookingWindow,
+                    at: rental.renter_window,
+                    party: rental.lister,
+                });
+            }
+        }
+        if let Some(auction) = Self::auction(env.clone(), token_id) {
+            if auction.ends_at > now {
+                deadlines.push_back(Deadline {
+                    kind: DeadlineKind::AuctionEnding,
+                    at: auction.ends_at,
+                    party: auction.seller,
+                });
+            }
+        }
+        Ok(deadlines)
+    }
+
+    /**
+     * @dev Publishes DeadlineApproaching for each of a token's deadlines less
+     * than REMINDER_LEAD away that hasn't been reminded yet. Anyone may call
+     * it, e.g. a keeper sweeping tokens().
+     * @return How many reminders were published.
+     */
+    pub fn send_reminders(env: Env, token_id: u64) -> Result<u32, Error> {
+        enter(&env, "send_reminders")?;
+
+        let now = env.ledger().timestamp();
+        let key = ExtKey2::Reminded(token_id);
+        let mut reminded: Map<DeadlineKind, u64> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Map::new(&env));
+        let mut sent = 0;
+        for deadline in Self::deadlines(env.clone(), token_id)?.iter() {
+            if deadline.at - now > REMINDER_LEAD || reminded.get(deadline.kind) == Some(deadline.at)
+            {
+                continue;
+            }
+            reminded.set(deadline.kind, deadline.at);
+            sent += 1;
+            events::emit(
+                &env,
+                &DeadlineApproaching {
+                    token_id,
+                    kind: deadline.kind,
+                    party: deadline.party,
+                    at: deadline.at,
+                },
+            );
+        }
+        if sent > 0 {
+            env.storage().persistent().set(&key, &reminded);
+        }
+        Ok(sent)
+    }
+}
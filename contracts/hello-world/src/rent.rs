@@ -0,0 +1,304 @@
+// Rent-prepayment pool.
+//
+// Owners who don't want to bump their deeds themselves deposit into the pool
+// (in the token named by the admin's RentPolicy, normally the native XLM asset
+// contract). Keepers then call `keeper_bump` for an owner's portfolio page
+// whenever it is due: the contract extends the page's entries exactly as
+// `bump_portfolio` does and pays the keeper `fee_per_token` per token from the
+// owner's balance.
+//
+// A page becomes due when its entries may have dropped below the policy's
+// threshold, i.e. `extend_to - threshold` ledgers after its last paid bump, so
+// keepers cannot drain a balance by bumping over and over. Owners withdraw
+// whatever they no longer want to keep in the pool at any time.
+//
+// Events: `rent_deposited` and `rent_withdrawn` for every balance change an
+// owner makes, `rent_charged` when a keeper is paid, and `rent_low_balance`
+// after a charge that leaves less than the policy's `low_balance`, so wallets
+// can prompt a top-up before bumps stop.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, token, Address, Env};
+
+use crate::{
+    enter, events, pause, require_admin, ttl, DataKey, Error, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/**
+ * @title RentPolicy
+ * @dev How the rent pool is funded and what keepers are paid for.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentPolicy {
+    /// Token balances are held in. Fixed once set.
+    pub token: Address,
+    /// Paid to the keeper for each token kept alive.
+    pub fee_per_token: i128,
+    /// TTL, in ledgers, under which entries are bumped.
+    pub threshold: u32,
+    /// TTL, in ledgers, entries are bumped to.
+    pub extend_to: u32,
+    /// Balance under which owners are warned after a charge.
+    pub low_balance: i128,
+}
+
+/**
+ * @title RentPool
+ * @dev Whether the rent pool is configured, as reported by config(). (A plain
+ * Option<RentPolicy> field can't be converted to an ScVal by the SDK.)
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RentPool {
+    Disabled,
+    Enabled(RentPolicy),
+}
+
+/**
+ * @title RentDeposited
+ * @dev Published when an owner adds to their rent balance.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentDeposited {
+    #[topic]
+    pub owner: Address,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+/**
+ * @title RentWithdrawn
+ * @dev Published when an owner takes back part of their rent balance.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentWithdrawn {
+    #[topic]
+    pub owner: Address,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+/**
+ * @title RentCharged
+ * @dev Published when a keeper is paid from an owner's balance for a bump.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentCharged {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub keeper: Address,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+/**
+ * @title RentLowBalance
+ * @dev Published when a charge leaves an owner's balance under the policy's low_balance.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentLowBalance {
+    #[topic]
+    pub owner: Address,
+    pub balance: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Configures the rent pool. Admin only. The token cannot change once
+     * set, since balances are held in it.
+     * @param policy threshold must be below extend_to; amounts must not be negative.
+     */
+    pub fn set_rent_policy(env: Env, policy: RentPolicy) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "set_rent_policy")?;
+
+        if policy.threshold >= policy.extend_to
+            || policy.fee_per_token < 0
+            || policy.low_balance < 0
+        {
+            return Err(Error::InvalidRentPolicy);
+        }
+        if let Some(current) = Self::rent_policy(env.clone()) {
+            if current.token != policy.token {
+                return Err(Error::InvalidRentPolicy);
+            }
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::RentPolicy, &policy);
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the rent pool's policy, if the admin has configured one.
+     */
+    pub fn rent_policy(env: Env) -> Option<RentPolicy> {
+        env.storage().persistent().get(&DataKey::RentPolicy)
+    }
+
+    /**
+     * @dev Returns `owner`'s unused rent balance.
+     */
+    pub fn rent_balance(env: Env, owner: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RentBalance(owner))
+            .unwrap_or(0)
+    }
+
+    /**
+     * @dev Moves `amount` of the policy token from `owner` into their rent balance.
+     * @return The new balance.
+     */
+    pub fn deposit_rent(env: Env, owner: Address, amount: i128) -> Result<i128, Error> {
+        owner.require_auth();
+        enter(&env, "deposit_rent")?;
+        pause::check(&env)?;
+
+        let policy = load_policy(&env)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        token::Client::new(&env, &policy.token).transfer(
+            &owner,
+            env.current_contract_address(),
+            &amount,
+        );
+
+        let balance = Self::rent_balance(env.clone(), owner.clone()) + amount;
+        set_balance(&env, &owner, balance);
+        events::emit(
+            &env,
+            &RentDeposited {
+                owner,
+                amount,
+                balance,
+            },
+        );
+        Ok(balance)
+    }
+
+    /**
+     * @dev Pays `amount` of `owner`'s unused rent balance back to them.
+     * @return The new balance.
+     */
+    pub fn withdraw_rent(env: Env, owner: Address, amount: i128) -> Result<i128, Error> {
+        owner.require_auth();
+        enter(&env, "withdraw_rent")?;
+        pause::check(&env)?;
+
+        let policy = load_policy(&env)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let balance = Self::rent_balance(env.clone(), owner.clone());
+        if amount > balance {
+            return Err(Error::InsufficientRentBalance);
+        }
+
+        let balance = balance - amount;
+        set_balance(&env, &owner, balance);
+        token::Client::new(&env, &policy.token).transfer(
+            &env.current_contract_address(),
+            &owner,
+            &amount,
+        );
+        events::emit(
+            &env,
+            &RentWithdrawn {
+                owner,
+                amount,
+                balance,
+            },
+        );
+        Ok(balance)
+    }
+
+    /**
+     * @dev Bumps one page of `owner`'s portfolio to the policy's extend_to and
+     * pays `keeper` fee_per_token per token from the owner's rent balance.
+     * Fails with RentNotDue if the page was paid for less than
+     * `extend_to - threshold` ledgers ago.
+     * @param page As for bump_portfolio().
+     * @return The next page to pass, or None once the whole portfolio is covered.
+     */
+    pub fn keeper_bump(
+        env: Env,
+        keeper: Address,
+        owner: Address,
+        page: u32,
+    ) -> Result<Option<u32>, Error> {
+        keeper.require_auth();
+        enter(&env, "keeper_bump")?;
+        pause::check(&env)?;
+
+        // 1. Only pages whose entries may be running low are paid for.
+        let policy = load_policy(&env)?;
+        let now = env.ledger().sequence();
+        let due_key = DataKey::RentDue(owner.clone(), page);
+        let due: u32 = env.storage().temporary().get(&due_key).unwrap_or(0);
+        if now < due {
+            return Err(Error::RentNotDue);
+        }
+
+        // 2. Bump the page; an empty page costs nothing.
+        let (bumped, next) = ttl::bump_page(&env, &owner, page, policy.threshold, policy.extend_to);
+        if bumped == 0 {
+            return Ok(next);
+        }
+
+        // 3. Charge the owner and pay the keeper.
+        let fee = policy.fee_per_token * bumped as i128;
+        let balance = Self::rent_balance(env.clone(), owner.clone());
+        if fee > balance {
+            return Err(Error::InsufficientRentBalance);
+        }
+        let balance = balance - fee;
+        set_balance(&env, &owner, balance);
+        let period = policy.extend_to - policy.threshold;
+        env.storage().temporary().set(&due_key, &(now + period));
+        env.storage()
+            .temporary()
+            .extend_ttl(&due_key, period, period);
+        token::Client::new(&env, &policy.token).transfer(
+            &env.current_contract_address(),
+            &keeper,
+            &fee,
+        );
+
+        // 4. Tell indexers, and warn the owner if the balance runs low.
+        events::emit(
+            &env,
+            &RentCharged {
+                owner: owner.clone(),
+                keeper,
+                amount: fee,
+                balance,
+            },
+        );
+        if balance < policy.low_balance {
+            events::emit(&env, &RentLowBalance { owner, balance });
+        }
+        Ok(next)
+    }
+}
+
+fn load_policy(env: &Env) -> Result<RentPolicy, Error> {
+    HotelTimeshareContract::rent_policy(env.clone()).ok_or(Error::RentPoolNotConfigured)
+}
+
+fn set_balance(env: &Env, owner: &Address, balance: i128) {
+    let key = DataKey::RentBalance(owner.clone());
+    if balance == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &balance);
+    }
+}
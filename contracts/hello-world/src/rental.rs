@@ -0,0 +1,256 @@
+// Renting out a week.
+//
+// An owner who won't use this year's week can rent it out without selling the
+// token. `list_for_rent` offers it at a price in a payment token until the end
+// of the renter window; the first caller of `rent` pays the owner and becomes
+// the week's renter for the year. Ownership never moves: the rental only
+// decides who may check in (see usage.rs). While a rental is active, the
+// renter checks in instead of the owner.
+//
+// A listing nobody has taken yet is withdrawn with `cancel_rental`, and lapses
+// when the token changes owner. A taken rental stays with the token until the
+// year ends, even if the token is sold, since the renter has paid for it; for
+// the same reason the token can't be burned until the renter has stayed.
+// Frozen tokens (see freeze.rs) can't be listed or rented.
+//
+// (Not to be confused with the rent pool in rent.rs, which pays for storage.)
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env};
+
+use crate::{
+    check_owner, enter, events, fail, force_majeure, freeze, owner_of, pause, retirement, splitter,
+    term, usage, DataKey, Error, ExtError, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, UsageState,
+};
+
+/**
+ * @title Rental
+ * @dev A token's rental listing for one year, and its renter once taken.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rental {
+    /// The owner who listed the week, and is paid the rent.
+    pub lister: Address,
+    /// Token the rent is paid in.
+    pub token: Address,
+    pub price: i128,
+    /// Ledger timestamp after which the listing can no longer be taken.
+    pub renter_window: u64,
+    /// The calendar year the week is rented for.
+    pub year: u32,
+    /// Who rented the week, once someone has.
+    pub renter: Option<Address>,
+}
+
+/**
+ * @title RentalListed
+ * @dev Published when an owner lists a week for rent.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentalListed {
+    #[topic]
+    pub token_id: u64,
+    pub token: Address,
+    pub price: i128,
+    pub renter_window: u64,
+}
+
+/**
+ * @title Rented
+ * @dev Published when a renter takes a listed week.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rented {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub renter: Address,
+    pub price: i128,
+}
+
+/**
+ * @title RentalCancelled
+ * @dev Published when an owner withdraws a listing nobody has taken.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentalCancelled {
+    #[topic]
+    pub token_id: u64,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Lists this year's week of a token for rent, replacing any listing
+     * nobody has taken. Fails with ListingClosed once a renter has taken it.
+     * @param owner The current owner (must sign); receives the rent.
+     * @param token The token the rent is paid in.
+     * @param price The rent.
+     * @param renter_window Ledger timestamp until which the listing can be taken.
+     */
+    pub fn list_for_rent(
+        env: Env,
+        owner: Address,
+        token_id: u64,
+        token: Address,
+        price: i128,
+        renter_window: u64,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "list_for_rent")?;
+
+        check_owner(&env, token_id, &owner)?;
+        freeze::check(&env, token_id);
+        term::check_token(&env, token_id)?;
+        if Self::rental(env.clone(), token_id).is_some_and(|r| r.renter.is_some()) {
+            return Err(Error::ListingClosed);
+        }
+        if price < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        set_rental(
+            &env,
+            token_id,
+            &Rental {
+                lister: owner,
+                token: token.clone(),
+                price,
+                renter_window,
+                year: usage::current_year(&env),
+                renter: None,
+            },
+        );
+
+        events::emit(
+            &env,
+            &RentalListed {
+                token_id,
+                token,
+                price,
+                renter_window,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Withdraws a listing nobody has taken.
+     * @param owner The current owner (must sign).
+     */
+    pub fn cancel_rental(env: Env, owner: Address, token_id: u64) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "cancel_rental")?;
+
+        check_owner(&env, token_id, &owner)?;
+        let rental = Self::rental(env.clone(), token_id).ok_or(Error::NotListed)?;
+        if rental.renter.is_some() {
+            return Err(Error::ListingClosed);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Rental(token_id));
+
+        events::emit(&env, &RentalCancelled { token_id });
+        Ok(())
+    }
+
+    /**
+     * @dev Rents a listed week, paying the price to the lister. The renter may
+     * then check in instead of the owner this year.
+     * @param renter The renter (must sign and pay).
+     */
+    pub fn rent(env: Env, renter: Address, token_id: u64) -> Result<Rental, Error> {
+        renter.require_auth();
+        enter(&env, "rent")?;
+        pause::check(&env)?;
+
+        // 1. An open listing, within its window and year, for a token that
+        // can still be used.
+        let mut rental = Self::rental(env.clone(), token_id).ok_or(Error::NotListed)?;
+        if rental.renter.is_some() {
+            return Err(Error::ListingClosed);
+        }
+        if env.ledger().timestamp() > rental.renter_window {
+            return Err(Error::ListingClosed);
+        }
+        freeze::check(&env, token_id);
+        retirement::check_token(&env, token_id)?;
+        term::check_token(&env, token_id)?;
+        force_majeure::check_open(&env, token_id);
+
+        // 2. Pay the lister and record the renter.
+        splitter::pay(&env, &rental.token, &renter, &rental.lister, rental.price);
+        rental.renter = Some(renter.clone());
+        set_rental(&env, token_id, &rental);
+
+        events::emit(
+            &env,
+            &Rented {
+                token_id,
+                renter,
+                price: rental.price,
+            },
+        );
+        Ok(rental)
+    }
+
+    /**
+     * @dev Returns a token's rental for the current year, listed or taken.
+     */
+    pub fn rental(env: Env, token_id: u64) -> Option<Rental> {
+        let year = usage::current_year(&env);
+        env.storage()
+            .persistent()
+            .get(&DataKey::Rental(token_id))
+            .filter(|rental: &Rental| rental.year == year)
+    }
+}
+
+/// Requires that `guest` may use this year's week of `token_id`: its renter
+/// while it is rented, its owner otherwise. Fails with NotOwner.
+pub(crate) fn check_guest(env: &Env, token_id: u64, guest: &Address) -> Result<(), Error> {
+    let owner = owner_of(env, token_id)?;
+    let allowed = match HotelTimeshareContract::rental(env.clone(), token_id) {
+        Some(Rental {
+            renter: Some(renter),
+            ..
+        }) => renter,
+        _ => owner,
+    };
+    if *guest != allowed {
+        return Err(Error::NotOwner);
+    }
+    Ok(())
+}
+
+/// Requires that `token_id` isn't rented out to a renter who hasn't checked
+/// out yet this year; burning it would take a week the renter paid for.
+/// Fails with RentalActive.
+pub(crate) fn check_not_rented(env: &Env, token_id: u64) {
+    let rented = HotelTimeshareContract::rental(env.clone(), token_id)
+        .is_some_and(|rental| rental.renter.is_some());
+    let stayed = HotelTimeshareContract::usage(env.clone(), token_id)
+        .is_ok_and(|usage| usage.state == UsageState::Consumed);
+    if rented && !stayed {
+        fail(env, ExtError::RentalActive);
+    }
+}
+
+/// Withdraws a listing nobody has taken, when the token changes owner.
+pub(crate) fn clear_listing(env: &Env, token_id: u64) {
+    let key = DataKey::Rental(token_id);
+    let listing: Option<Rental> = env.storage().persistent().get(&key);
+    if listing.is_some_and(|rental| rental.renter.is_none()) {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+fn set_rental(env: &Env, token_id: u64, rental: &Rental) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Rental(token_id), rental);
+}
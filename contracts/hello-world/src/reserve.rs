@@ -0,0 +1,506 @@
+// Maintenance reserve funds.
+//
+// Part of every maintenance fee can be set aside for a property's long-term
+// upkeep. A manager sets the hotel's reserve share with `set_reserve_share`;
+// `pay_maintenance` then holds that share of each of the hotel's fees in the
+// contract, credited to the hotel's reserve, and sends the rest to the
+// collector. Special assessments and other top-ups come in with
+// `fund_reserve`.
+//
+// The reserve only leaves through spend proposals recorded on-chain: a manager
+// proposes paying an amount to a recipient, with a memo saying what for, and
+// anyone can execute the payment RESERVE_SPEND_DELAY seconds later. Owners get
+// that long to see it coming, and managers or the admin can cancel it until
+// then. `reserve_report` sums up what went in, what went out and what is
+// committed, so owners can check that their fees aren't siphoned off.
+//
+// A reserve holds a single token, the one it was first funded in. Fees billed
+// in another token go to the collector in full.
+
+use soroban_sdk::{
+    contractevent, contractimpl, contracttype, token, Address, Env, Map, String, Vec,
+};
+
+use crate::{
+    enter, events, fail, hotels, money, or_fail, pause, roles, Amount, Bps, Error, ExtError,
+    ExtKey, HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient, Role,
+};
+
+/// Basis points in a whole: a share of FULL_SHARE_BPS reserves the whole fee.
+pub const FULL_SHARE_BPS: u32 = 10_000;
+
+/// Seconds between proposing a reserve spend and being able to execute it.
+pub const RESERVE_SPEND_DELAY: u64 = 14 * 24 * 60 * 60;
+
+/**
+ * @title ReserveFund
+ * @dev A hotel's maintenance reserve.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveFund {
+    /// The token the reserve is held in.
+    pub token: Address,
+    /// What the contract holds for the reserve.
+    pub balance: i128,
+    /// Everything ever paid in, from fees and top-ups.
+    pub funded: i128,
+    /// Everything ever paid out by executed proposals.
+    pub spent: i128,
+    /// What pending proposals will pay out; never more than the balance.
+    pub committed: i128,
+    /// Number of spend proposals made so far; they are numbered from 1.
+    pub proposals: u32,
+}
+
+/**
+ * @title ReserveReport
+ * @dev A hotel's reserve share and fund, as returned by reserve_report().
+ * The fund's fields are 0 until the reserve is first funded.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveReport {
+    pub share_bps: u32,
+    /// None until the reserve is first funded.
+    pub token: Option<Address>,
+    pub balance: i128,
+    pub funded: i128,
+    pub spent: i128,
+    pub committed: i128,
+    pub proposals: u32,
+}
+
+/**
+ * @title SpendState
+ * @dev Where a spend proposal stands.
+ */
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SpendState {
+    Pending,
+    Executed,
+    Cancelled,
+}
+
+/**
+ * @title SpendProposal
+ * @dev A proposed payment out of a hotel's reserve.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendProposal {
+    pub recipient: Address,
+    pub amount: i128,
+    /// What the payment is for, e.g. "Roof repair, invoice 2031-17".
+    pub memo: String,
+    /// Ledger timestamp from which the payment can be executed.
+    pub executable_at: u64,
+    pub state: SpendState,
+}
+
+/**
+ * @title ReserveFunded
+ * @dev Published when money is added to a hotel's reserve.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveFunded {
+    #[topic]
+    pub hotel_id: u32,
+    pub from: Address,
+    pub amount: i128,
+}
+
+/**
+ * @title ReserveSpendProposed
+ * @dev Published when a manager proposes a payment out of a reserve.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveSpendProposed {
+    #[topic]
+    pub hotel_id: u32,
+    pub proposal_id: u32,
+    pub recipient: Address,
+    pub amount: i128,
+    pub memo: String,
+    pub executable_at: u64,
+}
+
+/**
+ * @title ReserveSpent
+ * @dev Published when a spend proposal is executed.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveSpent {
+    #[topic]
+    pub hotel_id: u32,
+    pub proposal_id: u32,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+/**
+ * @title ReserveSpendCancelled
+ * @dev Published when a pending spend proposal is cancelled.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveSpendCancelled {
+    #[topic]
+    pub hotel_id: u32,
+    pub proposal_id: u32,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Sets the share of a hotel's maintenance fees held in its reserve.
+     * Manager role (or admin).
+     * @param caller The manager (must sign).
+     * @param share_bps At most FULL_SHARE_BPS; 0 reserves nothing.
+     */
+    pub fn set_reserve_share(
+        env: Env,
+        caller: Address,
+        hotel_id: u32,
+        share_bps: u32,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "set_reserve_share")?;
+
+        hotels::check(&env, hotel_id)?;
+        if share_bps > FULL_SHARE_BPS {
+            return Err(Error::InvalidAmount);
+        }
+        let mut hotels = share_hotels(&env);
+        if !hotels.contains(hotel_id) {
+            hotels.push_back(hotel_id);
+            env.storage()
+                .persistent()
+                .set(&ExtKey::ReserveHotels, &hotels);
+        }
+        env.storage()
+            .persistent()
+            .set(&ExtKey::ReserveShare(hotel_id), &share_bps);
+        Ok(())
+    }
+
+    /**
+     * @dev Returns every reserve share set, by hotel ID.
+     */
+    pub fn reserve_shares(env: Env) -> Map<u32, u32> {
+        let mut shares = Map::new(&env);
+        for hotel_id in share_hotels(&env).iter() {
+            shares.set(hotel_id, share(&env, hotel_id));
+        }
+        shares
+    }
+
+    /**
+     * @dev Pays into a hotel's reserve, e.g. a special assessment. Fails with
+     * InvalidAmount unless the amount is positive and in the reserve's token.
+     * @param from The payer (must sign and pay).
+     */
+    pub fn fund_reserve(
+        env: Env,
+        from: Address,
+        hotel_id: u32,
+        payment_token: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        from.require_auth();
+        enter(&env, "fund_reserve")?;
+        pause::check(&env)?;
+
+        hotels::check(&env, hotel_id)?;
+        if amount <= 0 || !accepts(&env, hotel_id, &payment_token) {
+            return Err(Error::InvalidAmount);
+        }
+        token::Client::new(&env, &payment_token).transfer(
+            &from,
+            env.current_contract_address(),
+            &amount,
+        );
+        let amount = or_fail(&env, Amount::of(amount));
+        credit(&env, hotel_id, &payment_token, &from, amount);
+        Ok(())
+    }
+
+    /**
+     * @dev Proposes paying an amount out of a hotel's reserve. Manager role
+     * (or admin). Fails with InsufficientReserve if the reserve, less what
+     * pending proposals commit, can't cover it.
+     * @param caller The manager (must sign).
+     * @param memo What the payment is for.
+     * @return The proposal's ID within the hotel.
+     */
+    pub fn propose_reserve_spend(
+        env: Env,
+        caller: Address,
+        hotel_id: u32,
+        recipient: Address,
+        amount: i128,
+        memo: String,
+    ) -> Result<u32, Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "propose_reserve_spend")?;
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let mut fund = load_fund(&env, hotel_id);
+        if amount > fund.balance - fund.committed {
+            fail(&env, ExtError::InsufficientReserve);
+        }
+        fund.committed += amount;
+        fund.proposals += 1;
+        let proposal_id = fund.proposals;
+        let executable_at = env.ledger().timestamp() + RESERVE_SPEND_DELAY;
+        set_fund(&env, hotel_id, &fund);
+        set_proposal(
+            &env,
+            hotel_id,
+            proposal_id,
+            &SpendProposal {
+                recipient: recipient.clone(),
+                amount,
+                memo: memo.clone(),
+                executable_at,
+                state: SpendState::Pending,
+            },
+        );
+
+        events::emit(
+            &env,
+            &ReserveSpendProposed {
+                hotel_id,
+                proposal_id,
+                recipient,
+                amount,
+                memo,
+                executable_at,
+            },
+        );
+        Ok(proposal_id)
+    }
+
+    /**
+     * @dev Pays out a pending spend proposal once RESERVE_SPEND_DELAY has
+     * passed. Anyone may call it. Fails with TimelockNotElapsed before then.
+     */
+    pub fn execute_reserve_spend(env: Env, hotel_id: u32, proposal_id: u32) -> Result<(), Error> {
+        enter(&env, "execute_reserve_spend")?;
+        pause::check(&env)?;
+
+        let mut proposal = load_pending(&env, hotel_id, proposal_id);
+        if env.ledger().timestamp() < proposal.executable_at {
+            return Err(Error::TimelockNotElapsed);
+        }
+        let mut fund = load_fund(&env, hotel_id);
+        fund.balance -= proposal.amount;
+        fund.committed -= proposal.amount;
+        fund.spent += proposal.amount;
+        set_fund(&env, hotel_id, &fund);
+        proposal.state = SpendState::Executed;
+        set_proposal(&env, hotel_id, proposal_id, &proposal);
+        token::Client::new(&env, &fund.token).transfer(
+            &env.current_contract_address(),
+            &proposal.recipient,
+            &proposal.amount,
+        );
+
+        events::emit(
+            &env,
+            &ReserveSpent {
+                hotel_id,
+                proposal_id,
+                recipient: proposal.recipient,
+                amount: proposal.amount,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Cancels a pending spend proposal. Manager role (or admin).
+     * @param caller The manager (must sign).
+     */
+    pub fn cancel_reserve_spend(
+        env: Env,
+        caller: Address,
+        hotel_id: u32,
+        proposal_id: u32,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "cancel_reserve_spend")?;
+
+        let mut proposal = load_pending(&env, hotel_id, proposal_id);
+        let mut fund = load_fund(&env, hotel_id);
+        fund.committed -= proposal.amount;
+        set_fund(&env, hotel_id, &fund);
+        proposal.state = SpendState::Cancelled;
+        set_proposal(&env, hotel_id, proposal_id, &proposal);
+
+        events::emit(
+            &env,
+            &ReserveSpendCancelled {
+                hotel_id,
+                proposal_id,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns a hotel's reserve share and what its reserve took in,
+     * paid out and has committed.
+     */
+    pub fn reserve_report(env: Env, hotel_id: u32) -> Result<ReserveReport, Error> {
+        hotels::check(&env, hotel_id)?;
+        let share_bps = share(&env, hotel_id);
+        let fund: Option<ReserveFund> = env.storage().persistent().get(&ExtKey::Reserve(hotel_id));
+        Ok(match fund {
+            Some(fund) => ReserveReport {
+                share_bps,
+                token: Some(fund.token),
+                balance: fund.balance,
+                funded: fund.funded,
+                spent: fund.spent,
+                committed: fund.committed,
+                proposals: fund.proposals,
+            },
+            None => ReserveReport {
+                share_bps,
+                token: None,
+                balance: 0,
+                funded: 0,
+                spent: 0,
+                committed: 0,
+                proposals: 0,
+            },
+        })
+    }
+
+    /**
+     * @dev Returns a spend proposal of a hotel's reserve, whatever its state.
+     */
+    pub fn reserve_spend(env: Env, hotel_id: u32, proposal_id: u32) -> Option<SpendProposal> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::ReserveSpend(hotel_id, proposal_id))
+    }
+}
+
+/// Pays a maintenance fee of `hotel_id` from `payer`: the hotel's reserve
+/// share to its reserve, held by the contract, and the rest to `collector`.
+/// Fails with AmountOverflow if the share of the fee overflows.
+pub(crate) fn pay_fee(
+    env: &Env,
+    hotel_id: u32,
+    payment_token: &Address,
+    payer: &Address,
+    collector: &Address,
+    fee: Amount,
+) {
+    let payment = token::Client::new(env, payment_token);
+    let reserved = if accepts(env, hotel_id, payment_token) {
+        let unit = money::minor_unit(env, hotel_id, payment_token);
+        let reserved = Bps::of(share(env, hotel_id)).and_then(|bps| money::share(fee, bps, unit));
+        or_fail(env, reserved)
+    } else {
+        Amount::ZERO
+    };
+    if reserved > Amount::ZERO {
+        // Fees the contract already holds (see prepay.rs) stay where they are.
+        if *payer != env.current_contract_address() {
+            payment.transfer(payer, env.current_contract_address(), &reserved.get());
+        }
+        credit(env, hotel_id, payment_token, payer, reserved);
+    }
+    if fee > reserved {
+        let rest = or_fail(env, fee.checked_sub(reserved));
+        payment.transfer(payer, collector, &rest.get());
+    }
+}
+
+/// The hotels a reserve share was set for.
+fn share_hotels(env: &Env) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&ExtKey::ReserveHotels)
+        .unwrap_or(Vec::new(env))
+}
+
+fn share(env: &Env, hotel_id: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ExtKey::ReserveShare(hotel_id))
+        .unwrap_or(0)
+}
+
+/// Whether a hotel's reserve is, or can start out, in `payment_token`.
+fn accepts(env: &Env, hotel_id: u32, payment_token: &Address) -> bool {
+    let fund: Option<ReserveFund> = env.storage().persistent().get(&ExtKey::Reserve(hotel_id));
+    fund.is_none_or(|fund| fund.token == *payment_token)
+}
+
+/// Credits `amount` to a hotel's reserve. Fails with AmountOverflow if its
+/// totals would overflow.
+fn credit(env: &Env, hotel_id: u32, payment_token: &Address, from: &Address, amount: Amount) {
+    let mut fund = env
+        .storage()
+        .persistent()
+        .get(&ExtKey::Reserve(hotel_id))
+        .unwrap_or(ReserveFund {
+            token: payment_token.clone(),
+            balance: 0,
+            funded: 0,
+            spent: 0,
+            committed: 0,
+            proposals: 0,
+        });
+    let balance = Amount::of(fund.balance).and_then(|balance| balance.checked_add(amount));
+    let funded = Amount::of(fund.funded).and_then(|funded| funded.checked_add(amount));
+    fund.balance = or_fail(env, balance).get();
+    fund.funded = or_fail(env, funded).get();
+    set_fund(env, hotel_id, &fund);
+
+    events::emit(
+        env,
+        &ReserveFunded {
+            hotel_id,
+            from: from.clone(),
+            amount: amount.get(),
+        },
+    );
+}
+
+/// A hotel's reserve; fails with InsufficientReserve if it was never funded.
+fn load_fund(env: &Env, hotel_id: u32) -> ReserveFund {
+    env.storage()
+        .persistent()
+        .get(&ExtKey::Reserve(hotel_id))
+        .unwrap_or_else(|| fail(env, ExtError::InsufficientReserve))
+}
+
+fn set_fund(env: &Env, hotel_id: u32, fund: &ReserveFund) {
+    env.storage()
+        .persistent()
+        .set(&ExtKey::Reserve(hotel_id), fund);
+}
+
+/// A pending spend proposal; fails with NoSpendProposal for any other.
+fn load_pending(env: &Env, hotel_id: u32, proposal_id: u32) -> SpendProposal {
+    HotelTimeshareContract::reserve_spend(env.clone(), hotel_id, proposal_id)
+        .filter(|proposal| proposal.state == SpendState::Pending)
+        .unwrap_or_else(|| fail(env, ExtError::NoSpendProposal))
+}
+
+fn set_proposal(env: &Env, hotel_id: u32, proposal_id: u32, proposal: &SpendProposal) {
+    env.storage()
+        .persistent()
+        .set(&ExtKey::ReserveSpend(hotel_id, proposal_id), proposal);
+}
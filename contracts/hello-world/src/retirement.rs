@@ -0,0 +1,305 @@
+// Property retirement and token redemption.
+//
+// When a hotel leaves the program the admin retires it with
+// `retire_property`, naming a payout per token and a redemption window, and
+// funds the payouts in the payment token up front. From then on:
+//
+// - no new tokens are minted for the property, and its tokens can no longer
+//   change hands (transfers and trust placements fail with PropertyRetired);
+// - until the window ends, each owner may `redeem` a token: it is burned and
+//   the payout is paid from the property's funding;
+// - after the window, unredeemed tokens are frozen, and the admin sweeps them
+//   with `sweep_property` (burning them in batches) and gets back whatever
+//   funding was not paid out.
+//
+// The `Retirement` record stays behind as the property's tombstone. Its
+// `funded` and `paid` totals, together with the contract's balance of the
+// payment token, let anyone verify that outstanding redemptions are covered.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, token, Address, Env, Vec};
+
+use crate::{
+    burn, check_owner, enter, events, hotels, owner_of, pause, require_admin, DataKey, Error,
+    HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+    MAX_MINT_BATCH,
+};
+
+/**
+ * @title Retirement
+ * @dev Terms and running totals of a retired property.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Retirement {
+    /// Token payouts are made in.
+    pub token: Address,
+    /// Paid per redeemed timeshare token.
+    pub payout: i128,
+    /// Ledger timestamp after which tokens can no longer be redeemed.
+    pub window_end: u64,
+    /// Total deposited to fund payouts.
+    pub funded: i128,
+    /// Total paid out (or refunded to the admin by a sweep).
+    pub paid: i128,
+    /// Tokens redeemed by their owners.
+    pub redeemed: u32,
+    /// Unredeemed tokens burned by sweeps.
+    pub swept: u32,
+}
+
+/**
+ * @title PropertyRetired
+ * @dev Published when a property is retired and its redemption window opens.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PropertyRetired {
+    #[topic]
+    pub hotel_id: u32,
+    pub payout: i128,
+    pub window_end: u64,
+}
+
+/**
+ * @title Redeemed
+ * @dev Published when an owner redeems a token of a retired property.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Redeemed {
+    #[topic]
+    pub token_id: u64,
+    pub owner: Address,
+    pub payout: i128,
+}
+
+/**
+ * @title PropertySwept
+ * @dev Published for every sweep of a retired property's unredeemed tokens.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PropertySwept {
+    #[topic]
+    pub hotel_id: u32,
+    pub burned: u32,
+    pub refunded: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Retires a property and opens its redemption window. Admin only.
+     * @param token The payment token payouts are made in.
+     * @param payout Paid per redeemed token.
+     * @param window_end Ledger timestamp at which the window closes.
+     * @param funding Moved from the admin into the contract now; top up with fund_retirement().
+     */
+    pub fn retire_property(
+        env: Env,
+        hotel_id: u32,
+        token: Address,
+        payout: i128,
+        window_end: u64,
+        funding: i128,
+    ) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+        enter(&env, "retire_property")?;
+        pause::check(&env)?;
+
+        hotels::check(&env, hotel_id)?;
+        if Self::retirement(env.clone(), hotel_id).is_some() {
+            return Err(Error::AlreadyRetired);
+        }
+        if payout < 0 || funding < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        token::Client::new(&env, &token).transfer(&admin, env.current_contract_address(), &funding);
+
+        let retirement = Retirement {
+            token,
+            payout,
+            window_end,
+            funded: funding,
+            paid: 0,
+            redeemed: 0,
+            swept: 0,
+        };
+        set_retirement(&env, hotel_id, &retirement);
+        events::emit(
+            &env,
+            &PropertyRetired {
+                hotel_id,
+                payout,
+                window_end,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Adds funding for a retired property's payouts. Admin only.
+     * @return The property's updated retirement record.
+     */
+    pub fn fund_retirement(env: Env, hotel_id: u32, amount: i128) -> Result<Retirement, Error> {
+        let admin = require_admin(&env)?;
+        enter(&env, "fund_retirement")?;
+        pause::check(&env)?;
+
+        let mut retirement = load(&env, hotel_id)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        token::Client::new(&env, &retirement.token).transfer(
+            &admin,
+            env.current_contract_address(),
+            &amount,
+        );
+        retirement.funded += amount;
+        set_retirement(&env, hotel_id, &retirement);
+        Ok(retirement)
+    }
+
+    /**
+     * @dev Returns a property's retirement record, if it has been retired.
+     */
+    pub fn retirement(env: Env, hotel_id: u32) -> Option<Retirement> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Retirement(hotel_id))
+    }
+
+    /**
+     * @dev Burns a token of a retired property and pays its owner the payout.
+     * Fails with RedemptionWindowClosed after the window, AlreadyInTrust for
+     * tokens held in trust, and RedemptionUnderfunded if the funding left
+     * doesn't cover the payout.
+     * @param owner The current owner (must sign).
+     * @param token_id The token to redeem.
+     */
+    pub fn redeem(env: Env, owner: Address, token_id: u64) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "redeem")?;
+        pause::check(&env)?;
+
+        // 1. Only owners of a retired property's tokens, within the window.
+        check_owner(&env, token_id, &owner)?;
+        let hotel_id = Self::get_info(env.clone(), token_id)?.hotel_id;
+        let mut retirement = load(&env, hotel_id)?;
+        if env.ledger().timestamp() >= retirement.window_end {
+            return Err(Error::RedemptionWindowClosed);
+        }
+        if env.storage().persistent().has(&DataKey::Trust(token_id)) {
+            return Err(Error::AlreadyInTrust);
+        }
+        if retirement.funded - retirement.paid < retirement.payout {
+            return Err(Error::RedemptionUnderfunded);
+        }
+
+        // 2. Burn the token and pay the owner.
+        burn::burn_token(&env, &owner, token_id);
+        retirement.paid += retirement.payout;
+        retirement.redeemed += 1;
+        set_retirement(&env, hotel_id, &retirement);
+        token::Client::new(&env, &retirement.token).transfer(
+            &env.current_contract_address(),
+            &owner,
+            &retirement.payout,
+        );
+
+        events::emit(
+            &env,
+            &Redeemed {
+                token_id,
+                owner,
+                payout: retirement.payout,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev After the redemption window, burns unredeemed tokens of a retired
+     * property and refunds the unused funding to the admin. Admin only.
+     * @param token_ids Tokens of the property to burn, at most MAX_MINT_BATCH per call.
+     * @return The property's updated retirement record.
+     */
+    pub fn sweep_property(
+        env: Env,
+        hotel_id: u32,
+        token_ids: Vec<u64>,
+    ) -> Result<Retirement, Error> {
+        let admin = require_admin(&env)?;
+        enter(&env, "sweep_property")?;
+        pause::check(&env)?;
+
+        let mut retirement = load(&env, hotel_id)?;
+        if env.ledger().timestamp() < retirement.window_end {
+            return Err(Error::RedemptionWindowOpen);
+        }
+        if token_ids.len() > MAX_MINT_BATCH {
+            return Err(Error::BatchTooLarge);
+        }
+
+        // 1. Burn the frozen tokens; each must belong to this property.
+        for token_id in token_ids.iter() {
+            if Self::get_info(env.clone(), token_id)?.hotel_id != hotel_id {
+                return Err(Error::WrongProperty);
+            }
+            let owner = owner_of(&env, token_id)?;
+            burn::burn_token(&env, &owner, token_id);
+        }
+
+        // 2. Return whatever funding no redemption can claim any more.
+        let refunded = retirement.funded - retirement.paid;
+        if refunded > 0 {
+            token::Client::new(&env, &retirement.token).transfer(
+                &env.current_contract_address(),
+                &admin,
+                &refunded,
+            );
+        }
+        retirement.paid = retirement.funded;
+        retirement.swept += token_ids.len();
+        set_retirement(&env, hotel_id, &retirement);
+
+        events::emit(
+            &env,
+            &PropertySwept {
+                hotel_id,
+                burned: token_ids.len(),
+                refunded,
+            },
+        );
+        Ok(retirement)
+    }
+}
+
+/// Fails with PropertyRetired if `hotel_id` has been retired. Guards minting and
+/// every change of a token's owner.
+pub(crate) fn check_active(env: &Env, hotel_id: u32) -> Result<(), Error> {
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::Retirement(hotel_id))
+    {
+        return Err(Error::PropertyRetired);
+    }
+    Ok(())
+}
+
+/// check_active() for the property of an existing token.
+pub(crate) fn check_token(env: &Env, token_id: u64) -> Result<(), Error> {
+    let hotel_id = HotelTimeshareContract::get_info(env.clone(), token_id)?.hotel_id;
+    check_active(env, hotel_id)
+}
+
+fn load(env: &Env, hotel_id: u32) -> Result<Retirement, Error> {
+    HotelTimeshareContract::retirement(env.clone(), hotel_id).ok_or(Error::NotRetired)
+}
+
+fn set_retirement(env: &Env, hotel_id: u32, retirement: &Retirement) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Retirement(hotel_id), retirement);
+}
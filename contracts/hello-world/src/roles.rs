@@ -0,0 +1,116 @@
+// Role-based access control.
+//
+// The admin delegates parts of its power to other addresses by granting them
+// roles, so day-to-day systems never hold the master key:
+//
+// - Minter: mints tokens with `mint_by`, drawing IDs from its own block.
+// - Manager: manages properties and their inventory.
+// - Pauser: stops and resumes activity.
+// - Compliance: sets tax withholding rules and registers exemptions.
+// - Oracle: flags weeks closed for force majeure (see force_majeure.rs).
+//
+// Role-gated endpoints take the caller's address as their first argument and
+// accept the admin as holding every role. Only the admin grants and revokes.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env};
+
+use crate::{
+    enter, events, require_admin, DataKey, Error, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, ADMIN,
+};
+
+/**
+ * @title Role
+ * @dev A delegated permission; see the module comment.
+ */
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Role {
+    Minter,
+    Manager,
+    Pauser,
+    Compliance,
+    Oracle,
+}
+
+/**
+ * @title RoleGranted
+ * @dev Published when the admin grants a role.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleGranted {
+    #[topic]
+    pub role: Role,
+    #[topic]
+    pub account: Address,
+}
+
+/**
+ * @title RoleRevoked
+ * @dev Published when the admin revokes a role.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleRevoked {
+    #[topic]
+    pub role: Role,
+    #[topic]
+    pub account: Address,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Grants `role` to `account`. Admin only.
+     */
+    pub fn grant_role(env: Env, role: Role, account: Address) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "grant_role")?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Role(role, account.clone()), &true);
+        events::emit(&env, &RoleGranted { role, account });
+        Ok(())
+    }
+
+    /**
+     * @dev Takes `role` away from `account`. Admin only.
+     */
+    pub fn revoke_role(env: Env, role: Role, account: Address) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "revoke_role")?;
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Role(role, account.clone()));
+        events::emit(&env, &RoleRevoked { role, account });
+        Ok(())
+    }
+
+    /**
+     * @dev Returns whether `account` was granted `role`. (The admin holds every
+     * role implicitly without being listed.)
+     */
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Role(role, account))
+    }
+}
+
+/// Requires `caller`'s signature and that it is the admin or holds `role`.
+pub(crate) fn require_role(env: &Env, role: Role, caller: &Address) -> Result<(), Error> {
+    caller.require_auth();
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&ADMIN)
+        .ok_or(Error::NotInitialized)?;
+    if *caller == admin || HotelTimeshareContract::has_role(env.clone(), role, caller.clone()) {
+        Ok(())
+    } else {
+        Err(Error::MissingRole)
+    }
+}
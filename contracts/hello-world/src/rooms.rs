@@ -0,0 +1,127 @@
+// Room inventory.
+//
+// Each registered hotel (see hotels.rs) keeps a list of the rooms it sells
+// weeks of, maintained by managers. A token can only be minted for a room in
+// its hotel's inventory, so a mistyped room number is rejected instead of
+// creating a token for a room that doesn't exist.
+//
+// A room taken out of service is retired: it leaves the inventory and no more
+// tokens are minted for it, while the tokens already sold stay valid. Retired
+// rooms keep their entry and cannot be added again, so a room name always
+// refers to the same room.
+
+use soroban_sdk::{contractevent, contractimpl, Address, Env, String, Vec};
+
+use crate::{
+    enter, events, hotels, roles, DataKey, Error, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, Role,
+};
+
+/**
+ * @title RoomAdded
+ * @dev Published when a room is added to a hotel's inventory.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoomAdded {
+    #[topic]
+    pub hotel_id: u32,
+    pub room: String,
+}
+
+/**
+ * @title RoomRetired
+ * @dev Published when a room is taken out of a hotel's inventory.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoomRetired {
+    #[topic]
+    pub hotel_id: u32,
+    pub room: String,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Adds a room to a hotel's inventory. Manager role (or admin).
+     * @param caller The manager (must sign).
+     * @param room The room's name, e.g. "Room 305"; unique within the hotel.
+     */
+    pub fn add_room(env: Env, caller: Address, hotel_id: u32, room: String) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "add_room")?;
+
+        hotels::check(&env, hotel_id)?;
+        let key = DataKey::Room(hotel_id, room.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::DuplicateRoom);
+        }
+        env.storage().persistent().set(&key, &true);
+        let mut rooms = Self::list_rooms(env.clone(), hotel_id);
+        rooms.push_back(room.clone());
+        set_rooms(&env, hotel_id, &rooms);
+
+        events::emit(&env, &RoomAdded { hotel_id, room });
+        Ok(())
+    }
+
+    /**
+     * @dev Takes a room out of a hotel's inventory for good: no more tokens can
+     * be minted for it. Existing tokens are unaffected. Manager role (or admin).
+     * @param caller The manager (must sign).
+     */
+    pub fn retire_room(
+        env: Env,
+        caller: Address,
+        hotel_id: u32,
+        room: String,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "retire_room")?;
+
+        check(&env, hotel_id, &room)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Room(hotel_id, room.clone()), &false);
+        let mut rooms = Self::list_rooms(env.clone(), hotel_id);
+        if let Some(i) = rooms.first_index_of(&room) {
+            rooms.remove(i);
+        }
+        set_rooms(&env, hotel_id, &rooms);
+
+        events::emit(&env, &RoomRetired { hotel_id, room });
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the rooms in a hotel's inventory (retired rooms excluded),
+     * in the order they were added.
+     */
+    pub fn list_rooms(env: Env, hotel_id: u32) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Rooms(hotel_id))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+/// Fails with RoomNotFound unless `room` is in the hotel's inventory, or with
+/// RoomRetired if it was retired. Guards minting.
+pub(crate) fn check(env: &Env, hotel_id: u32, room: &String) -> Result<(), Error> {
+    match env
+        .storage()
+        .persistent()
+        .get(&DataKey::Room(hotel_id, room.clone()))
+    {
+        Some(true) => Ok(()),
+        Some(false) => Err(Error::RoomRetired),
+        None => Err(Error::RoomNotFound),
+    }
+}
+
+fn set_rooms(env: &Env, hotel_id: u32, rooms: &Vec<String>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Rooms(hotel_id), rooms);
+}
@@ -0,0 +1,184 @@
+// Royalties on secondary sales.
+//
+// Each hotel can take a cut of every resale of its weeks: a manager sets the
+// hotel's royalty in basis points and the address it is paid to. The
+// contract's own resale paths (`buy`, `accept_offer` and auction settlement)
+// pay it automatically, splitting the price between the hotel and the seller
+// with `pay`. Dutch auctions are primary sales by the hotel and pay none.
+// Tax withholding, if the hotel's jurisdiction requires it, comes out of the
+// seller's part (see withholding.rs), and the hotel's charity split, if it has
+// one, out of the royalty (see charity.rs).
+//
+// External marketplaces read `get_royalty` and pay the hotel themselves.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, token, Address, Env};
+
+use crate::{
+    charity, enter, events, hotels, migration, money, or_fail, roles, splitter, withholding,
+    Amount, Bps, DataKey, Error, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, Role,
+};
+
+/// Basis points in a whole: a royalty of MAX_ROYALTY_BPS is the full price.
+pub const MAX_ROYALTY_BPS: u32 = 10_000;
+
+/**
+ * @title Royalty
+ * @dev A hotel's cut of resales of its tokens.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Royalty {
+    /// Receives the royalties.
+    pub payout: Address,
+    /// The cut, in basis points of the sale price.
+    pub bps: u32,
+}
+
+/**
+ * @title RoyaltySet
+ * @dev Published when a hotel's royalty changes.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoyaltySet {
+    #[topic]
+    pub hotel_id: u32,
+    pub payout: Address,
+    pub bps: u32,
+}
+
+/**
+ * @title RoyaltyPaid
+ * @dev Published when a resale pays a hotel its royalty.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoyaltyPaid {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub hotel_id: u32,
+    pub payout: Address,
+    /// What the payout received, after any charity donations.
+    pub amount: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Sets a hotel's royalty. Manager role (or admin).
+     * @param caller The manager (must sign).
+     * @param payout Receives the royalties.
+     * @param bps The cut in basis points, at most MAX_ROYALTY_BPS; 0 takes none.
+     */
+    pub fn set_royalty(
+        env: Env,
+        caller: Address,
+        hotel_id: u32,
+        payout: Address,
+        bps: u32,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "set_royalty")?;
+
+        hotels::check(&env, hotel_id)?;
+        if bps > MAX_ROYALTY_BPS {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage().persistent().set(
+            &DataKey::Royalty(hotel_id),
+            &Royalty {
+                payout: payout.clone(),
+                bps,
+            },
+        );
+
+        events::emit(
+            &env,
+            &RoyaltySet {
+                hotel_id,
+                payout,
+                bps,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the royalty owed on resales of a token, if its hotel set one.
+     */
+    pub fn get_royalty(env: Env, token_id: u64) -> Result<Option<Royalty>, Error> {
+        let info = migration::read_info(&env, token_id).ok_or(Error::TokenNotFound)?;
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKey::Royalty(info.hotel_id)))
+    }
+}
+
+/// Pays `price` of `payment_token` from `from` for a resale of `token_id`: the
+/// hotel's royalty to its charities and payout address, any tax withheld to
+/// its recipient, and the rest to `seller`.
+pub(crate) fn pay(
+    env: &Env,
+    token_id: u64,
+    payment_token: &Address,
+    from: &Address,
+    seller: &Address,
+    price: i128,
+) -> Result<(), Error> {
+    let payment = token::Client::new(env, payment_token);
+    let info = migration::read_info(env, token_id).ok_or(Error::TokenNotFound)?;
+    let royalty: Option<Royalty> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Royalty(info.hotel_id));
+    let price = or_fail(env, Amount::of(price));
+    let cut = match &royalty {
+        Some(royalty) if royalty.payout != *seller => {
+            let unit = money::minor_unit(env, info.hotel_id, payment_token);
+            or_fail(
+                env,
+                Bps::of(royalty.bps).and_then(|bps| money::share(price, bps, unit)),
+            )
+        }
+        _ => Amount::ZERO,
+    };
+    let proceeds = or_fail(env, price.checked_sub(cut));
+
+    if let Some(royalty) = royalty.filter(|_| cut > Amount::ZERO) {
+        let donated = charity::donate(env, token_id, info.hotel_id, payment_token, from, cut);
+        let kept = or_fail(env, cut.checked_sub(donated));
+        if kept > Amount::ZERO {
+            payment.transfer(from, &royalty.payout, &kept.get());
+        }
+        events::emit(
+            env,
+            &RoyaltyPaid {
+                token_id,
+                hotel_id: info.hotel_id,
+                payout: royalty.payout,
+                amount: kept.get(),
+            },
+        );
+    }
+    let withheld = withholding::withhold(
+        env,
+        token_id,
+        info.hotel_id,
+        payment_token,
+        from,
+        seller,
+        price,
+        proceeds,
+    );
+    splitter::pay(
+        env,
+        payment_token,
+        from,
+        seller,
+        or_fail(env, proceeds.checked_sub(withheld)).get(),
+    );
+    Ok(())
+}
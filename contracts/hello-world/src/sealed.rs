@@ -0,0 +1,488 @@
+// Sealed-bid second-price (Vickrey) auctions.
+//
+// For the most sought-after weeks an owner can run a sealed auction instead of
+// an English one (see auction.rs), so nobody gains by bidding in the last
+// second. It runs in two windows:
+//
+// 1. Commit: bidders `commit_bid` the hash
+//    sha256(xdr((bidder, amount, salt))) of their bid and a random 32-byte
+//    salt, and post the auction's bond. Committing again replaces the hash.
+// 2. Reveal, for the SealedReveal grace period (see grace.rs): bidders `reveal_bid` the amount and
+//    salt. The bond comes back, and a bid that becomes the highest is
+//    escrowed, refunding the one it beats. Bids under the reserve don't count.
+//
+// Once the reveal window has closed anyone may `settle_sealed`: the highest
+// bidder gets the token and pays the second-highest revealed bid (or the
+// reserve, if higher), the rest of their bid is refunded, and the seller is
+// paid like any resale (see royalties.rs). Bonds of bids never revealed go to
+// the seller. As with English auctions, the auction lapses if the token
+// changes owner first, refunding the high bid and every bond still held, and
+// a token that can't be handed over at settlement has its high bid refunded.
+
+use soroban_sdk::{
+    contractevent, contractimpl, contracttype, token, xdr::ToXdr, Address, BytesN, Env, Vec,
+};
+
+use crate::{
+    check_owner, enter, events, fail, freeze, grace, move_token, or_fail, pause, receipts,
+    royalties, Amount, Error, ExtError, ExtKey, GraceRule, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, ReceiptKind,
+};
+
+/// Seconds bidders have to reveal once commitments close, unless the grace
+/// period table says otherwise.
+pub const SEALED_REVEAL_WINDOW: u64 = 24 * 60 * 60;
+
+/// Most bidders a sealed auction takes, so settlement can return their bonds
+/// in one call.
+pub const MAX_SEALED_BIDDERS: u32 = 25;
+
+/**
+ * @title SealedAuction
+ * @dev A token's sealed-bid second-price auction.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SealedAuction {
+    /// The owner who started the auction, and is paid for the token.
+    pub seller: Address,
+    /// Token bids and bonds are paid in.
+    pub payment_token: Address,
+    /// The lowest bid that counts, and the lowest price paid.
+    pub reserve: i128,
+    /// Posted with each commitment; forfeited to the seller if not revealed.
+    pub bond: i128,
+    /// Ledger timestamp at which commitments close and reveals open.
+    pub commit_ends_at: u64,
+    /// Ledger timestamp at which reveals close.
+    pub reveal_ends_at: u64,
+    /// Everyone who committed, in order.
+    pub bidders: Vec<Address>,
+    /// The highest revealed bidder so far, whose bid the contract holds.
+    pub high_bidder: Option<Address>,
+    pub high_bid: i128,
+    /// The second-highest revealed bid, 0 if there is none.
+    pub second_bid: i128,
+}
+
+/**
+ * @title SealedAuctionStarted
+ * @dev Published when an owner starts a sealed auction.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SealedAuctionStarted {
+    #[topic]
+    pub token_id: u64,
+    pub seller: Address,
+    pub payment_token: Address,
+    pub reserve: i128,
+    pub bond: i128,
+    pub commit_ends_at: u64,
+    pub reveal_ends_at: u64,
+}
+
+/**
+ * @title BidCommitted
+ * @dev Published when a bidder commits (or recommits) a sealed bid.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BidCommitted {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub bidder: Address,
+}
+
+/**
+ * @title BidRevealed
+ * @dev Published when a bidder reveals a sealed bid.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BidRevealed {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub bidder: Address,
+    pub amount: i128,
+}
+
+/**
+ * @title SealedAuctionSettled
+ * @dev Published when a sealed auction is settled; winner is None if no bid
+ * met the reserve.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SealedAuctionSettled {
+    #[topic]
+    pub token_id: u64,
+    pub winner: Option<Address>,
+    pub price: i128,
+    /// Bonds of unrevealed bids paid to the seller.
+    pub forfeited: i128,
+}
+
+/**
+ * @title SealedAuctionLapsed
+ * @dev Published when a sealed auction ends because its token changed owner,
+ * or couldn't be handed over at settlement.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SealedAuctionLapsed {
+    #[topic]
+    pub token_id: u64,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Starts a sealed auction of a token. Fails with AuctionRunning while
+     * one is running.
+     * @param seller The current owner (must sign); receives the price.
+     * @param reserve The lowest bid that counts; must not be negative.
+     * @param bond Posted with each commitment; must not be negative.
+     * @param duration Seconds commitments are taken, before the reveal
+     * window; must not be 0.
+     */
+    pub fn start_sealed_auction(
+        env: Env,
+        seller: Address,
+        token_id: u64,
+        payment_token: Address,
+        reserve: i128,
+        bond: i128,
+        duration: u64,
+    ) -> Result<(), Error> {
+        seller.require_auth();
+        enter(&env, "start_sealed_auction")?;
+
+        check_owner(&env, token_id, &seller)?;
+        freeze::check(&env, token_id);
+        if reserve < 0 || bond < 0 || duration == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if Self::sealed_auction(env.clone(), token_id).is_some() {
+            fail(&env, ExtError::AuctionRunning);
+        }
+        let commit_ends_at = env.ledger().timestamp().saturating_add(duration);
+        let reveal_ends_at = grace::ends(&env, GraceRule::SealedReveal, commit_ends_at);
+        set_sealed(
+            &env,
+            token_id,
+            &SealedAuction {
+                seller: seller.clone(),
+                payment_token: payment_token.clone(),
+                reserve,
+                bond,
+                commit_ends_at,
+                reveal_ends_at,
+                bidders: Vec::new(&env),
+                high_bidder: None,
+                high_bid: 0,
+                second_bid: 0,
+            },
+        );
+
+        events::emit(
+            &env,
+            &SealedAuctionStarted {
+                token_id,
+                seller,
+                payment_token,
+                reserve,
+                bond,
+                commit_ends_at,
+                reveal_ends_at,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Commits a sealed bid, posting the bond the first time. Fails with
+     * AuctionEnded once commitments have closed, and BatchTooLarge past
+     * MAX_SEALED_BIDDERS bidders.
+     * @param bidder The bidder (must sign and pay the bond).
+     * @param commitment sha256 of the XDR of (bidder, amount, salt).
+     */
+    pub fn commit_bid(
+        env: Env,
+        bidder: Address,
+        token_id: u64,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        bidder.require_auth();
+        enter(&env, "commit_bid")?;
+        pause::check(&env)?;
+
+        let mut auction = Self::sealed_auction(env.clone(), token_id)
+            .unwrap_or_else(|| fail(&env, ExtError::NoAuction));
+        if env.ledger().timestamp() >= auction.commit_ends_at {
+            fail(&env, ExtError::AuctionEnded);
+        }
+        let key = ExtKey::SealedBid(token_id, bidder.clone());
+        if !env.storage().persistent().has(&key) {
+            if auction.bidders.len() >= MAX_SEALED_BIDDERS {
+                return Err(Error::BatchTooLarge);
+            }
+            if auction.bond > 0 {
+                token::Client::new(&env, &auction.payment_token).transfer(
+                    &bidder,
+                    env.current_contract_address(),
+                    &auction.bond,
+                );
+            }
+            auction.bidders.push_back(bidder.clone());
+            set_sealed(&env, token_id, &auction);
+        }
+        env.storage().persistent().set(&key, &commitment);
+
+        events::emit(&env, &BidCommitted { token_id, bidder });
+        Ok(())
+    }
+
+    /**
+     * @dev Reveals a committed bid and returns its bond. A bid that is the
+     * highest so far is escrowed, refunding the one it beats. Fails with
+     * AuctionRunning before the reveal window, AuctionEnded after it,
+     * NoCommitment without a commitment and CommitmentMismatch if the bid
+     * and salt don't hash to it.
+     * @param bidder The bidder (must sign, and pay the bid if it leads).
+     */
+    pub fn reveal_bid(
+        env: Env,
+        bidder: Address,
+        token_id: u64,
+        amount: i128,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        bidder.require_auth();
+        enter(&env, "reveal_bid")?;
+        pause::check(&env)?;
+
+        let mut auction = Self::sealed_auction(env.clone(), token_id)
+            .unwrap_or_else(|| fail(&env, ExtError::NoAuction));
+        let now = env.ledger().timestamp();
+        if now < auction.commit_ends_at {
+            fail(&env, ExtError::AuctionRunning);
+        }
+        if now >= auction.reveal_ends_at {
+            fail(&env, ExtError::AuctionEnded);
+        }
+        let key = ExtKey::SealedBid(token_id, bidder.clone());
+        let commitment: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| fail(&env, ExtError::NoCommitment));
+        if commitment != commitment_of(&env, &bidder, amount, &salt) {
+            fail(&env, ExtError::CommitmentMismatch);
+        }
+        env.storage().persistent().remove(&key);
+
+        let payment = token::Client::new(&env, &auction.payment_token);
+        if auction.bond > 0 {
+            payment.transfer(&env.current_contract_address(), &bidder, &auction.bond);
+        }
+        if amount >= auction.reserve {
+            match &auction.high_bidder {
+                Some(high_bidder) if amount > auction.high_bid => {
+                    payment.transfer(&bidder, env.current_contract_address(), &amount);
+                    payment.transfer(
+                        &env.current_contract_address(),
+                        high_bidder,
+                        &auction.high_bid,
+                    );
+                    auction.second_bid = auction.high_bid;
+                    auction.high_bidder = Some(bidder.clone());
+                    auction.high_bid = amount;
+                }
+                Some(_) => auction.second_bid = auction.second_bid.max(amount),
+                None => {
+                    payment.transfer(&bidder, env.current_contract_address(), &amount);
+                    auction.high_bidder = Some(bidder.clone());
+                    auction.high_bid = amount;
+                }
+            }
+            set_sealed(&env, token_id, &auction);
+        }
+
+        events::emit(
+            &env,
+            &BidRevealed {
+                token_id,
+                bidder,
+                amount,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Settles a sealed auction once reveals have closed: the highest
+     * bidder gets the token for the second-highest bid (at least the
+     * reserve), and unrevealed bonds go to the seller. Anyone may call it.
+     * Fails with AuctionRunning until reveals close. If the token can't move
+     * to the winner, the auction lapses and the bid is refunded instead.
+     * @return The price paid, 0 if nobody won.
+     */
+    pub fn settle_sealed(env: Env, token_id: u64) -> Result<i128, Error> {
+        enter(&env, "settle_sealed")?;
+        pause::check(&env)?;
+
+        let auction = Self::sealed_auction(env.clone(), token_id)
+            .unwrap_or_else(|| fail(&env, ExtError::NoAuction));
+        if env.ledger().timestamp() < auction.reveal_ends_at {
+            fail(&env, ExtError::AuctionRunning);
+        }
+        // Removed first, so that move_token doesn't see it lapse.
+        remove_sealed(&env, token_id);
+        let forfeited = forfeit_bonds(&env, token_id, &auction);
+
+        let mut price = 0;
+        if let Some(winner) = &auction.high_bidder {
+            if Self::is_frozen(env.clone(), token_id)
+                || move_token(&env, &auction.seller, winner, token_id).is_err()
+            {
+                refund(&env, &auction);
+                events::emit(&env, &SealedAuctionLapsed { token_id });
+                return Ok(0);
+            }
+            price = auction.second_bid.max(auction.reserve);
+            royalties::pay(
+                &env,
+                token_id,
+                &auction.payment_token,
+                &env.current_contract_address(),
+                &auction.seller,
+                price,
+            )?;
+            let change = or_fail(
+                &env,
+                Amount::of(auction.high_bid).and_then(|bid| bid.checked_sub(Amount::of(price)?)),
+            );
+            if change > Amount::ZERO {
+                token::Client::new(&env, &auction.payment_token).transfer(
+                    &env.current_contract_address(),
+                    winner,
+                    &change.get(),
+                );
+            }
+            receipts::record_sale(
+                &env,
+                ReceiptKind::Resale,
+                token_id,
+                winner,
+                &auction.seller,
+                price,
+                &auction.payment_token,
+            );
+        }
+
+        events::emit(
+            &env,
+            &SealedAuctionSettled {
+                token_id,
+                winner: auction.high_bidder,
+                price,
+                forfeited,
+            },
+        );
+        Ok(price)
+    }
+
+    /**
+     * @dev Returns a token's sealed auction, if one is running or awaiting
+     * settlement.
+     */
+    pub fn sealed_auction(env: Env, token_id: u64) -> Option<SealedAuction> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::SealedAuction(token_id))
+    }
+}
+
+/// Ends a token's sealed auction when the token changes owner outside of
+/// settlement, refunding the high bid and every outstanding bond.
+pub(crate) fn lapse(env: &Env, token_id: u64) {
+    let Some(auction) = HotelTimeshareContract::sealed_auction(env.clone(), token_id) else {
+        return;
+    };
+    remove_sealed(env, token_id);
+    for bidder in auction.bidders.iter() {
+        release_bond(env, token_id, &auction, &bidder, &bidder);
+    }
+    refund(env, &auction);
+    events::emit(env, &SealedAuctionLapsed { token_id });
+}
+
+/// The commitment a bidder makes to `amount` with `salt`.
+fn commitment_of(env: &Env, bidder: &Address, amount: i128, salt: &BytesN<32>) -> BytesN<32> {
+    env.crypto()
+        .sha256(&(bidder.clone(), amount, salt.clone()).to_xdr(env))
+        .into()
+}
+
+/// Pays the bonds of bids never revealed to the seller. Returns the total.
+fn forfeit_bonds(env: &Env, token_id: u64, auction: &SealedAuction) -> i128 {
+    let mut total = Amount::ZERO;
+    for bidder in auction.bidders.iter() {
+        let bond = release_bond(env, token_id, auction, &bidder, &auction.seller);
+        total = or_fail(
+            env,
+            Amount::of(bond).and_then(|bond| total.checked_add(bond)),
+        );
+    }
+    total.get()
+}
+
+/// Pays `bidder`'s bond to `to` if their bid was never revealed, and drops
+/// the commitment. Returns the bond paid.
+fn release_bond(
+    env: &Env,
+    token_id: u64,
+    auction: &SealedAuction,
+    bidder: &Address,
+    to: &Address,
+) -> i128 {
+    let key = ExtKey::SealedBid(token_id, bidder.clone());
+    if !env.storage().persistent().has(&key) {
+        return 0;
+    }
+    env.storage().persistent().remove(&key);
+    if auction.bond > 0 {
+        token::Client::new(env, &auction.payment_token).transfer(
+            &env.current_contract_address(),
+            to,
+            &auction.bond,
+        );
+    }
+    auction.bond
+}
+
+/// Returns a sealed auction's escrowed high bid, if any, to its bidder.
+fn refund(env: &Env, auction: &SealedAuction) {
+    if let Some(high_bidder) = &auction.high_bidder {
+        token::Client::new(env, &auction.payment_token).transfer(
+            &env.current_contract_address(),
+            high_bidder,
+            &auction.high_bid,
+        );
+    }
+}
+
+fn set_sealed(env: &Env, token_id: u64, auction: &SealedAuction) {
+    env.storage()
+        .persistent()
+        .set(&ExtKey::SealedAuction(token_id), auction);
+}
+
+fn remove_sealed(env: &Env, token_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&ExtKey::SealedAuction(token_id));
+}
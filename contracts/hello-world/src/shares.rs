@@ -0,0 +1,269 @@
+// Fractional ownership.
+//
+// An owner splits a token into fungible shares with `fractionalize`: the
+// token moves into the contract's custody and the owner is credited every
+// share. Shares then change hands with `transfer_share`, so several buyers can
+// co-own an expensive week. Whoever comes to hold all of them calls
+// `redeem_shares` to take the token back out of custody as its sole owner.
+// (`redeem` itself is the retirement payout, see retirement.rs.)
+//
+// The token moves through `move_token` both ways, so it can't be fractionalized
+// while frozen, in trust or otherwise locked. If it leaves the contract some
+// other way (clawback, admin burn), its shares are dropped with it. Balances
+// are tagged with the token's fractionalization round (see ShareBalance), so
+// dropping them is a single write however many holders there are, and shares
+// of an earlier round never count towards a later one.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env, Vec};
+
+use crate::{
+    check_owner, enter, events, fail, move_token, pause, DataKey, Error, ExtError, ExtKey,
+    HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/**
+ * @title ShareBalance
+ * @dev A holder's shares of a token, in the fractionalization round they were
+ * issued in.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShareBalance {
+    pub round: u32,
+    pub amount: u32,
+}
+
+/**
+ * @title Fractionalized
+ * @dev Published when an owner splits a token into shares.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Fractionalized {
+    #[topic]
+    pub token_id: u64,
+    pub owner: Address,
+    pub shares: u32,
+}
+
+/**
+ * @title ShareTransfer
+ * @dev Published when shares of a token change hands.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShareTransfer {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub amount: u32,
+}
+
+/**
+ * @title SharesRedeemed
+ * @dev Published when the holder of all of a token's shares takes it back.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SharesRedeemed {
+    #[topic]
+    pub token_id: u64,
+    pub owner: Address,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Splits a token into shares, all credited to its owner, and moves
+     * the token into the contract.
+     * @param owner The current owner (must sign).
+     * @param shares How many shares to split it into; must be at least 2.
+     */
+    pub fn fractionalize(
+        env: Env,
+        owner: Address,
+        token_id: u64,
+        shares: u32,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "fractionalize")?;
+
+        check_owner(&env, token_id, &owner)?;
+        if shares < 2 {
+            return Err(Error::InvalidAmount);
+        }
+        move_token(&env, &owner, &env.current_contract_address(), token_id)?;
+        let round = round(&env, token_id) + 1;
+        env.storage()
+            .persistent()
+            .set(&ExtKey::ShareRound(token_id), &round);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Fraction(token_id), &shares);
+        set_balance(&env, token_id, &owner, shares);
+
+        events::emit(
+            &env,
+            &Fractionalized {
+                token_id,
+                owner,
+                shares,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Moves shares of a fractionalized token. Fails with
+     * NotFractionalized if the token isn't fractionalized, InvalidAmount for
+     * no shares, and InsufficientShares if `from` holds fewer than `amount`.
+     * @param from The holder (must sign).
+     */
+    pub fn transfer_share(
+        env: Env,
+        from: Address,
+        to: Address,
+        token_id: u64,
+        amount: u32,
+    ) -> Result<(), Error> {
+        from.require_auth();
+        enter(&env, "transfer_share")?;
+        pause::check(&env)?;
+
+        Self::fraction(env.clone(), token_id)
+            .unwrap_or_else(|| fail(&env, ExtError::NotFractionalized));
+        let held = Self::share_balance(env.clone(), token_id, from.clone());
+        if amount == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if amount > held {
+            fail(&env, ExtError::InsufficientShares);
+        }
+        if from != to {
+            set_balance(&env, token_id, &from, held - amount);
+            let received = Self::share_balance(env.clone(), token_id, to.clone());
+            set_balance(&env, token_id, &to, received + amount);
+        }
+
+        events::emit(
+            &env,
+            &ShareTransfer {
+                token_id,
+                from,
+                to,
+                amount,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Reconstitutes sole ownership: the holder of every share gets the
+     * token back and the shares are retired. Fails with InsufficientShares
+     * while anyone else holds some.
+     * @param holder The holder of all shares (must sign).
+     */
+    pub fn redeem_shares(env: Env, holder: Address, token_id: u64) -> Result<(), Error> {
+        holder.require_auth();
+        enter(&env, "redeem_shares")?;
+
+        let shares = Self::fraction(env.clone(), token_id)
+            .unwrap_or_else(|| fail(&env, ExtError::NotFractionalized));
+        if Self::share_balance(env.clone(), token_id, holder.clone()) != shares {
+            fail(&env, ExtError::InsufficientShares);
+        }
+        let contract = env.current_contract_address();
+        check_owner(&env, token_id, &contract)?;
+        clear(&env, token_id);
+        set_balance(&env, token_id, &holder, 0);
+        move_token(&env, &contract, &holder, token_id)?;
+
+        events::emit(
+            &env,
+            &SharesRedeemed {
+                token_id,
+                owner: holder,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns how many shares a fractionalized token was split into.
+     */
+    pub fn fraction(env: Env, token_id: u64) -> Option<u32> {
+        env.storage().persistent().get(&DataKey::Fraction(token_id))
+    }
+
+    /**
+     * @dev Returns how many of a token's shares an address holds.
+     */
+    pub fn share_balance(env: Env, token_id: u64, holder: Address) -> u32 {
+        if Self::fraction(env.clone(), token_id).is_none() {
+            return 0;
+        }
+        let balance: Option<ShareBalance> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Shares(token_id, holder));
+        match balance {
+            Some(balance) if balance.round == round(&env, token_id) => balance.amount,
+            _ => 0,
+        }
+    }
+}
+
+/// Forgets that a token is fractionalized, and drops every share of it, when
+/// it is redeemed, leaves the contract or is burned.
+pub(crate) fn clear(env: &Env, token_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Fraction(token_id));
+}
+
+/// The tokens an address holds or has held shares of. Tokens redeemed or
+/// burned since stay listed until the holder's balance next changes, so a
+/// reader checks `share_balance`.
+pub(crate) fn held_by(env: &Env, holder: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&ExtKey::HeldShares(holder.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_balance(env: &Env, token_id: u64, holder: &Address, amount: u32) {
+    let key = DataKey::Shares(token_id, holder.clone());
+    let mut held = held_by(env, holder);
+    let index = held.first_index_of(token_id);
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+        if let Some(index) = index {
+            held.remove(index);
+        }
+    } else {
+        if index.is_none() {
+            held.push_back(token_id);
+        }
+        let round = round(env, token_id);
+        env.storage()
+            .persistent()
+            .set(&key, &ShareBalance { round, amount });
+    }
+    let key = ExtKey::HeldShares(holder.clone());
+    if held.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &held);
+    }
+}
+
+/// How many times `token_id` has been fractionalized.
+fn round(env: &Env, token_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ExtKey::ShareRound(token_id))
+        .unwrap_or(0)
+}
@@ -0,0 +1,86 @@
+// Dead-address protection.
+//
+// A deed sent to an address nobody controls is bricked forever. Transfers are
+// therefore rejected when the recipient is the contract itself, one of the
+// all-zero "burn" addresses, or an address the admin has flagged as a known
+// sink. The admin can still push a token to such an address deliberately with
+// `force_transfer`.
+
+use soroban_sdk::{contractimpl, Address, Env, String};
+
+use crate::{
+    check_owner, enter, move_token, require_admin, DataKey, Error, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+// The account and contract strkeys of an all-zero public key / contract hash.
+const ZERO_ACCOUNT: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+const ZERO_CONTRACT: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Flags an address as a known sink that must not receive tokens. Admin only.
+     */
+    pub fn add_sink_address(env: Env, addr: Address) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "add_sink_address")?;
+        env.storage().persistent().set(&DataKey::Sink(addr), &true);
+        Ok(())
+    }
+
+    /**
+     * @dev Removes an address from the sink list. Admin only.
+     */
+    pub fn remove_sink_address(env: Env, addr: Address) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "remove_sink_address")?;
+        env.storage().persistent().remove(&DataKey::Sink(addr));
+        Ok(())
+    }
+
+    /**
+     * @dev Returns true if tokens would be rejected when sent to `addr`.
+     */
+    pub fn is_sink_address(env: Env, addr: Address) -> bool {
+        is_sink(&env, &addr)
+    }
+
+    /**
+     * @dev Transfers a token even if the recipient is a sink address.
+     * Requires the owner's signature AND the admin's, so a deliberate burn-by-transfer
+     * is always a two-party decision.
+     * @param from The current owner's address (who must sign).
+     * @param to The recipient, which may be a sink address.
+     * @param token_id The ID of the token to transfer.
+     */
+    pub fn force_transfer(
+        env: Env,
+        from: Address,
+        to: Address,
+        token_id: u64,
+    ) -> Result<(), Error> {
+        from.require_auth();
+        require_admin(&env)?;
+        enter(&env, "force_transfer")?;
+
+        check_owner(&env, token_id, &from)?;
+
+        move_token(&env, &from, &to, token_id)
+    }
+}
+
+fn is_sink(env: &Env, addr: &Address) -> bool {
+    *addr == env.current_contract_address()
+        || *addr == Address::from_string(&String::from_str(env, ZERO_ACCOUNT))
+        || *addr == Address::from_string(&String::from_str(env, ZERO_CONTRACT))
+        || env.storage().persistent().has(&DataKey::Sink(addr.clone()))
+}
+
+/// Fails if `to` is an address a token must never be sent to without `force_transfer`.
+pub(crate) fn check_recipient(env: &Env, to: &Address) -> Result<(), Error> {
+    if is_sink(env, to) {
+        return Err(Error::InvalidRecipient);
+    }
+    Ok(())
+}
@@ -0,0 +1,138 @@
+// Timeshare slot validation.
+//
+// A timeshare token stands for one week (1 to 52) of one room, and no week of
+// a room may be sold twice for the same year. Every mint checks the week and
+// claims the (hotel, room, week, year) slot in a uniqueness index, keyed by a
+// hash so the key has a fixed size however long the names are:
+//
+//     slot = sha256(xdr((hotel_id, room, week, year)))
+//
+// Perpetual tokens (no year, see term.rs) have a slot of their own, and a week
+// sold for every year can't also be sold for one year, or the other way round.
+// While any year of a week is claimed, its perpetual slot holds YEARLY instead
+// of a token ID (which is never 0), so perpetual mints fail on it at no extra
+// cost; a count of the claimed years frees it again when the last goes.
+//
+// The entry holds the token minted for the slot. Burning the token frees the
+// slot, so the week can be sold again. Tokens minted before the index existed
+// have no entry and do not block new mints of their slot.
+
+use soroban_sdk::{contractimpl, xdr::ToXdr, BytesN, Env, String};
+
+use crate::{
+    migration, DataKey, Error, ExtKey, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, TimeshareInfo,
+};
+
+/// Weeks are numbered 1 to WEEKS_PER_YEAR.
+pub const WEEKS_PER_YEAR: u32 = 52;
+
+// What a perpetual slot holds while years of its week are claimed.
+const YEARLY: u64 = 0;
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Returns the token minted for a week of a room, if there is one.
+     * @param year The year, or None for the perpetual token.
+     */
+    pub fn token_by_slot(
+        env: Env,
+        hotel_id: u32,
+        room: String,
+        week: u32,
+        year: Option<u32>,
+    ) -> Option<u64> {
+        let info = TimeshareInfo {
+            hotel_id,
+            room,
+            week,
+            year,
+            lease_end_ledger: None,
+        };
+        env.storage()
+            .persistent()
+            .get(&DataKey::Slot(slot_hash(&env, &info)))
+            .filter(|token_id| *token_id != YEARLY)
+    }
+}
+
+/// Validates `info`'s week and records `token_id` as the holder of its slot.
+/// Fails with InvalidWeek or DuplicateTimeshare, including when the week is
+/// claimed perpetually and `info` names a year, or the other way round.
+pub(crate) fn claim(env: &Env, token_id: u64, info: &TimeshareInfo) -> Result<(), Error> {
+    if info.week == 0 || info.week > WEEKS_PER_YEAR {
+        return Err(Error::InvalidWeek);
+    }
+    let key = DataKey::Slot(slot_hash(env, info));
+    if env.storage().persistent().has(&key) {
+        return Err(Error::DuplicateTimeshare);
+    }
+    if info.year.is_some() {
+        let perpetual = perpetual_hash(env, info);
+        let years = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::Slot(perpetual.clone()))
+        {
+            Some(YEARLY) => year_claims(env, &perpetual),
+            Some(_) => return Err(Error::DuplicateTimeshare),
+            None => 0,
+        };
+        set_year_claims(env, &perpetual, years + 1);
+    }
+    env.storage().persistent().set(&key, &token_id);
+    Ok(())
+}
+
+/// Frees the slot of a token about to be burned or given new info.
+pub(crate) fn release(env: &Env, token_id: u64) {
+    let Some(info) = migration::read_info(env, token_id) else {
+        return;
+    };
+    let key = DataKey::Slot(slot_hash(env, &info));
+    // Only the slot's own token frees it (legacy duplicates have no entry).
+    if env.storage().persistent().get(&key) == Some(token_id) {
+        env.storage().persistent().remove(&key);
+        if info.year.is_some() {
+            let perpetual = perpetual_hash(env, &info);
+            let years = year_claims(env, &perpetual);
+            set_year_claims(env, &perpetual, years.saturating_sub(1));
+        }
+    }
+}
+
+/// How many years of a room-week are claimed, keyed by its perpetual slot.
+fn year_claims(env: &Env, perpetual: &BytesN<32>) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ExtKey::YearClaims(perpetual.clone()))
+        .unwrap_or(0)
+}
+
+fn set_year_claims(env: &Env, perpetual: &BytesN<32>, years: u32) {
+    let slot = DataKey::Slot(perpetual.clone());
+    let key = ExtKey::YearClaims(perpetual.clone());
+    if years == 0 {
+        env.storage().persistent().remove(&slot);
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&slot, &YEARLY);
+        env.storage().persistent().set(&key, &years);
+    }
+}
+
+fn perpetual_hash(env: &Env, info: &TimeshareInfo) -> BytesN<32> {
+    slot_hash(
+        env,
+        &TimeshareInfo {
+            year: None,
+            ..info.clone()
+        },
+    )
+}
+
+fn slot_hash(env: &Env, info: &TimeshareInfo) -> BytesN<32> {
+    let preimage = (info.hotel_id, info.room.clone(), info.week, info.year).to_xdr(env);
+    env.crypto().sha256(&preimage).into()
+}
@@ -0,0 +1,171 @@
+// Revenue splitting.
+//
+// The hotel's sale and rental proceeds (primary sales, Dutch auctions, and any
+// sale or rental where the admin is the seller or lister) are paid to the
+// admin. Once the admin configures payees with `set_payees`, e.g. hotel 80%,
+// platform 15%, charity 5%, those proceeds are held by the contract instead
+// and credited to each payee by share; payees pull what they are owed with
+// `withdraw`. Shares are in basis points and must add up to a whole.
+//
+// Every payment path pays through `pay`, which splits only what goes to the
+// admin: royalties and proceeds owed to other sellers are paid as before.
+// Proceeds are divided with `money::split`, so the payees' parts add up to
+// the whole. Balances are kept per payment token.
+
+use soroban_sdk::{contractevent, contractimpl, token, Address, Env, Vec};
+
+use crate::{
+    enter, events, fail, money, or_fail, require_admin, Amount, Error, ExtError, ExtKey,
+    HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient, ADMIN,
+};
+
+/// Most payees a split may have, to bound the cost of each payment.
+pub const MAX_PAYEES: u32 = 10;
+
+/// Basis points in a whole: the payees' shares must add up to this.
+pub const SPLIT_TOTAL_BPS: u32 = 10_000;
+
+/**
+ * @title PayeesSet
+ * @dev Published when the admin changes the revenue split.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayeesSet {
+    pub payees: Vec<(Address, u32)>,
+}
+
+/**
+ * @title RevenueWithdrawn
+ * @dev Published when a payee withdraws their share of the proceeds.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevenueWithdrawn {
+    #[topic]
+    pub payee: Address,
+    pub payment_token: Address,
+    pub amount: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Sets the revenue split. Admin only. Balances already credited are
+     * kept. Fails with InvalidSplit unless the shares add up to
+     * SPLIT_TOTAL_BPS, and TooManyPayees past MAX_PAYEES.
+     * @param payees Each payee and its share in basis points; empty to pay
+     * the admin directly again.
+     */
+    pub fn set_payees(env: Env, payees: Vec<(Address, u32)>) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "set_payees")?;
+
+        if payees.len() > MAX_PAYEES {
+            fail(&env, ExtError::TooManyPayees);
+        }
+        let total = payees
+            .iter()
+            .try_fold(0u32, |total, (_, share)| total.checked_add(share));
+        if !payees.is_empty() && total != Some(SPLIT_TOTAL_BPS) {
+            fail(&env, ExtError::InvalidSplit);
+        }
+        env.storage().persistent().set(&ExtKey::Payees, &payees);
+
+        events::emit(&env, &PayeesSet { payees });
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the revenue split; empty if proceeds go to the admin.
+     */
+    pub fn payees(env: Env) -> Vec<(Address, u32)> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::Payees)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /**
+     * @dev Returns what a payee is owed in a payment token.
+     */
+    pub fn withdrawable(env: Env, payee: Address, payment_token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::Payable(payee, payment_token))
+            .unwrap_or(0)
+    }
+
+    /**
+     * @dev Pays a payee everything they are owed in a payment token. Fails
+     * with InvalidAmount if that is nothing.
+     * @param payee The payee (must sign).
+     * @return The amount paid.
+     */
+    pub fn withdraw(env: Env, payee: Address, payment_token: Address) -> Result<i128, Error> {
+        payee.require_auth();
+        enter(&env, "withdraw")?;
+
+        let amount = Self::withdrawable(env.clone(), payee.clone(), payment_token.clone());
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage()
+            .persistent()
+            .remove(&ExtKey::Payable(payee.clone(), payment_token.clone()));
+        token::Client::new(&env, &payment_token).transfer(
+            &env.current_contract_address(),
+            &payee,
+            &amount,
+        );
+
+        events::emit(
+            &env,
+            &RevenueWithdrawn {
+                payee,
+                payment_token,
+                amount,
+            },
+        );
+        Ok(amount)
+    }
+}
+
+/// Pays `amount` of `payment_token` from `from` to `to`. Proceeds owed to the
+/// admin are split between the payees, if there are any, and held by the
+/// contract until withdrawn. `from` may be the contract itself. Fails with
+/// AmountOverflow if a payee's balance would overflow.
+pub(crate) fn pay(env: &Env, payment_token: &Address, from: &Address, to: &Address, amount: i128) {
+    let payment = token::Client::new(env, payment_token);
+    let admin: Option<Address> = env.storage().instance().get(&ADMIN);
+    let payees = HotelTimeshareContract::payees(env.clone());
+    if admin.as_ref() != Some(to) || payees.is_empty() || amount <= 0 {
+        payment.transfer(from, to, &amount);
+        return;
+    }
+
+    let contract = env.current_contract_address();
+    if *from != contract {
+        payment.transfer(from, &contract, &amount);
+    }
+    // Payees span every property, so the split is in the asset's base unit.
+    let mut shares = Vec::new(env);
+    for (_, share) in payees.iter() {
+        shares.push_back(share);
+    }
+    let amount = or_fail(env, Amount::of(amount));
+    let parts = or_fail(env, money::split(env, amount, &shares, Amount::ONE));
+    for ((payee, _), part) in payees.iter().zip(parts.iter()) {
+        credit(env, &payee, payment_token, part);
+    }
+}
+
+fn credit(env: &Env, payee: &Address, payment_token: &Address, amount: i128) {
+    let owed =
+        HotelTimeshareContract::withdrawable(env.clone(), payee.clone(), payment_token.clone());
+    let owed = Amount::of(owed).and_then(|owed| owed.checked_add(Amount::of(amount)?));
+    env.storage().persistent().set(
+        &ExtKey::Payable(payee.clone(), payment_token.clone()),
+        &or_fail(env, owed).get(),
+    );
+}
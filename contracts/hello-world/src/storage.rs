@@ -0,0 +1,151 @@
+// Storage layout and footprint contention.
+//
+// Soroban executes transactions in parallel only when their footprints don't
+// conflict: two transactions conflict when one writes a ledger entry the other
+// reads or writes. The whole instance storage map is ONE ledger entry, read by
+// every call (ADMIN, deprecations in `enter`), so any instance write conflicts
+// with every other call to the contract.
+//
+// Keys written per endpoint, before sharding:
+//
+//   endpoint          instance keys written                 other shared keys
+//   mint              COUNTER, TotalSupply, Info, Owner,    EventChain, Metrics(day)
+//                     OwnedTokens
+//   transfer          Owner, OwnedTokens (x2), Approval,    EventChain, Metrics(day)
+//                     PendingTransfer
+//   any other write   its own keys                          EventChain, Metrics(day)
+//
+// i.e. every state change serialized on three entries: the instance, the
+// single event chain head and the day's metrics bucket.
+//
+// Sharding applied:
+//
+// - Only ADMIN, the layout version and the pause flag live in instance
+//   storage; older deployments are migrated by migration.rs. Everything else
+//   (counters, per-token and per-owner data, settings, logs) has its own
+//   persistent entry, or a temporary one for metrics. The instance entry stays small
+//   however many tokens exist, and each entry's TTL can be managed on its own
+//   (see ttl.rs).
+// - Token IDs and mint counts come from per-minter blocks (see ids.rs), so
+//   COUNTER is written once per ID_BLOCK_SIZE mints and there is no single
+//   supply entry bumped by every mint.
+// - The event hash chain is split into EVENT_LANES lanes chosen by a hash of
+//   the event's topics (see events.rs). Events about different tokens or
+//   owners usually land in different lanes.
+// - Metrics keep one counter per endpoint and day (see metrics.rs), so mints
+//   and transfers no longer bump the same entry. The mint endpoints further
+//   spread their counts over METRICS_LANES lanes picked by the signer.
+//
+// Still serialized after this:
+//
+// - Mints by the same minter, on its MinterState, and burns, on Burned.
+//   Admin-signed, allowlist, voucher and priced mints all draw from the
+//   admin's ID block, so they serialize on the admin's MinterState.
+// - Mints and burns in the same hotel, on its HotelSupply (see supply.rs).
+// - Writes to the same token, or to the same owner's token list.
+// - Mints and info changes of the same week, at any property, on its filter
+//   index, and tier changes in the same tier of a property (see filters.rs).
+// - Events whose topics hash to the same lane.
+// - Calls to the same endpoint on the same day, on its Metrics counter, and
+//   mints whose signers hash to the same metrics lane.
+// - Primary sales paying out in the same payment token, on the payees'
+//   Payable balances (see splitter.rs).
+
+use soroban_sdk::{vec, Env, IntoVal, Val, Vec};
+
+use crate::{DataKey, ExtKey, ExtKey2, HotelTimeshareContract};
+
+/// TTL kept on shared persistent entries, in ledgers (~30 days at 5s per ledger).
+pub const SHARED_TTL_LEDGERS: u32 = 30 * 17_280;
+
+/// Writes a persistent entry shared by the whole contract (a counter, an event
+/// lane) and extends its TTL, so a quiet month never archives it.
+pub(crate) fn set_shared<K, V>(env: &Env, key: &K, value: &V)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, SHARED_TTL_LEDGERS, SHARED_TTL_LEDGERS);
+}
+
+/// Every persistent key that may hold data about `token_id`. Only Info and
+/// Owner always exist; the rest exist while the token is approved, listed,
+/// auctioned, escrowed for a trade or fractionalized, pending or in trust,
+/// once its week has been used or rented, once its maintenance fee has been
+/// paid, while it is frozen, or once it has been clawed back. The keys that
+/// didn't fit in DataKey are in `ext_token_keys` and `more_token_keys`.
+pub(crate) fn token_keys(token_id: u64) -> [DataKey; 17] {
+    [
+        DataKey::Info(token_id),
+        DataKey::Owner(token_id),
+        DataKey::Approval(token_id),
+        DataKey::Listing(token_id),
+        DataKey::Auction(token_id),
+        DataKey::DutchAuction(token_id),
+        DataKey::Trade(token_id),
+        DataKey::Fraction(token_id),
+        DataKey::PendingTransfer(token_id),
+        DataKey::Trust(token_id),
+        DataKey::TrustAck(token_id),
+        DataKey::PendingBeneficiary(token_id),
+        DataKey::Usage(token_id),
+        DataKey::Rental(token_id),
+        DataKey::Maintenance(token_id),
+        DataKey::Frozen(token_id),
+        DataKey::Clawback(token_id),
+    ]
+}
+
+/// The ExtKey half of `token_keys`: the token's metadata URI and lock, once
+/// set, its primary sale while the proceeds are escrowed, its share round
+/// once fractionalized, its guest's check-in key once registered, its sealed
+/// auction while one runs, its price mark and appraisal once it has sold or
+/// been appraised, its tier once set, its swap chain or intent while escrowed
+/// for one, and its receipts once it has any. Receipts are numbered, so their
+/// keys depend on how many the token has.
+pub(crate) fn ext_token_keys(env: &Env, token_id: u64) -> Vec<ExtKey> {
+    let mut keys = vec![
+        env,
+        ExtKey::TokenUri(token_id),
+        ExtKey::MetadataFrozen(token_id),
+        ExtKey::Purchase(token_id),
+        ExtKey::ShareRound(token_id),
+        ExtKey::CheckinKey(token_id),
+        ExtKey::SealedAuction(token_id),
+        ExtKey::PriceMark(token_id),
+        ExtKey::Appraisal(token_id),
+        ExtKey::Tier(token_id),
+        ExtKey::ChainOf(token_id),
+        ExtKey::SwapIntent(token_id),
+        ExtKey::ReceiptCount(token_id),
+    ];
+    for number in 0..HotelTimeshareContract::receipt_count(env.clone(), token_id) {
+        keys.push_back(ExtKey::Receipt(token_id, number));
+    }
+    keys
+}
+
+/// The ExtKey2 part of `token_keys`: the token's recorded delinquency, its
+/// accepted offer while the hotel may match it, its last force-majeure
+/// settlement, the deadlines it was last reminded of, and its prepaid
+/// maintenance while the contract holds any, one key per prepaid year, so
+/// their keys depend on which years the token has prepaid.
+pub(crate) fn more_token_keys(env: &Env, token_id: u64) -> Vec<ExtKey2> {
+    let mut keys = vec![
+        env,
+        ExtKey2::Delinquency(token_id),
+        ExtKey2::PrepaidYears(token_id),
+        ExtKey2::FirstRefusal(token_id),
+        ExtKey2::ForceMajeureSettled(token_id),
+        ExtKey2::Reminded(token_id),
+    ];
+    if let Some(held) = HotelTimeshareContract::prepaid_years(env.clone(), token_id) {
+        for year in held.first..=held.last {
+            keys.push_back(ExtKey2::Prepaid(token_id, year));
+        }
+    }
+    keys
+}
@@ -0,0 +1,189 @@
+// Per-hotel supply caps.
+//
+// Slots (see slots.rs) stop a week of a room being sold twice, but not a hotel
+// being sold beyond its real capacity, e.g. through rooms registered by
+// mistake. The admin can cap how many live tokens a hotel may have with
+// `set_supply_cap`; every mint path (`mint`, `mint_batch`, `mint_by`, paid
+// mints, vouchers and the allowlist) checks it in `issue_token`.
+//
+// A hotel's supply counts its live tokens: mints add to it, burns free room
+// under the cap, and moving a token to another hotel with `update_info` moves
+// it between counts. Mints past the cap fail with SupplyCapReached.
+//
+// Deployments from before supplies were counted are recounted: the version 5
+// migration (see migration.rs) resets every hotel's supply, and the admin then
+// calls `count_supply` until it returns true, counting the live tokens in ID
+// order a page at a time. Mints and burns of tokens the recount hasn't reached
+// yet are left to it. Until it finishes, supplies are low, so caps let more
+// mints through than they should.
+
+use soroban_sdk::{contractimpl, Env, Map, Vec};
+
+use crate::{
+    enter, fail, hotels, ids, migration, privacy, require_admin, storage, DataKey, Error, ExtError,
+    ExtKey, HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient,
+    MAX_PAGE,
+};
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Caps how many live tokens a hotel may have. Admin only. A cap
+     * under the current supply only stops further mints.
+     * @param max_tokens The most live tokens; u32::MAX for no limit.
+     */
+    pub fn set_supply_cap(env: Env, hotel_id: u32, max_tokens: u32) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "set_supply_cap")?;
+
+        hotels::check(&env, hotel_id)?;
+        let mut capped = capped_hotels(&env);
+        if !capped.contains(hotel_id) {
+            capped.push_back(hotel_id);
+            env.storage()
+                .persistent()
+                .set(&ExtKey::CappedHotels, &capped);
+        }
+        env.storage()
+            .persistent()
+            .set(&ExtKey::SupplyCap(hotel_id), &max_tokens);
+        Ok(())
+    }
+
+    /**
+     * @dev Returns every hotel's supply cap, by hotel ID.
+     */
+    pub fn supply_caps(env: Env) -> Map<u32, u32> {
+        let mut caps = Map::new(&env);
+        for hotel_id in capped_hotels(&env).iter() {
+            if let Some(cap) = Self::supply_cap(env.clone(), hotel_id) {
+                caps.set(hotel_id, cap);
+            }
+        }
+        caps
+    }
+
+    /**
+     * @dev Returns a hotel's supply cap, if it has one.
+     */
+    pub fn supply_cap(env: Env, hotel_id: u32) -> Option<u32> {
+        env.storage().persistent().get(&ExtKey::SupplyCap(hotel_id))
+    }
+
+    /**
+     * @dev Returns how many live tokens a hotel has, as exact as the stats
+     * privacy allows (see privacy.rs).
+     */
+    pub fn hotel_supply(env: Env, hotel_id: u32) -> u32 {
+        privacy::blur(&env, supply(&env, hotel_id))
+    }
+
+    /**
+     * @dev Counts the next `limit` (capped at MAX_PAGE) token IDs into their
+     * hotels' supplies, while a recount started by migrate() is running.
+     * Admin only.
+     * @return Whether every token is counted.
+     */
+    pub fn count_supply(env: Env, limit: u32) -> Result<bool, Error> {
+        require_admin(&env)?;
+        enter(&env, "count_supply")?;
+
+        let Some(cursor) = recount_cursor(&env) else {
+            return Ok(true);
+        };
+        let last = ids::reserved(&env);
+        let end = last.min(cursor.saturating_add(limit.min(MAX_PAGE) as u64));
+        for token_id in cursor + 1..=end {
+            if !migration::has(&env, &DataKey::Owner(token_id)) {
+                continue;
+            }
+            if let Some(info) = migration::read_info(&env, token_id) {
+                let supply = supply(&env, info.hotel_id);
+                storage::set_shared(&env, &ExtKey::HotelSupply(info.hotel_id), &(supply + 1));
+            }
+        }
+
+        if end < last {
+            env.storage().persistent().set(&ExtKey::SupplyRecount, &end);
+            return Ok(false);
+        }
+        env.storage().persistent().remove(&ExtKey::SupplyRecount);
+        Ok(true)
+    }
+}
+
+/// Starts a recount of every hotel's supply (the version 5 migration step),
+/// indexing the hotels capped before caps were listed.
+pub(crate) fn start_recount(env: &Env) {
+    let mut capped = Vec::new(env);
+    for hotel_id in 1..=hotels::hotel_count(env) {
+        env.storage()
+            .persistent()
+            .remove(&ExtKey::HotelSupply(hotel_id));
+        if env.storage().persistent().has(&ExtKey::SupplyCap(hotel_id)) {
+            capped.push_back(hotel_id);
+        }
+    }
+    env.storage()
+        .persistent()
+        .set(&ExtKey::CappedHotels, &capped);
+    env.storage()
+        .persistent()
+        .set(&ExtKey::SupplyRecount, &0u64);
+}
+
+/// Counts a new token of `hotel_id`. Fails with SupplyCapReached if the hotel
+/// is at its cap.
+pub(crate) fn record_mint(env: &Env, token_id: u64, hotel_id: u32) -> Result<(), Error> {
+    let supply = supply(env, hotel_id);
+    let cap = HotelTimeshareContract::supply_cap(env.clone(), hotel_id).unwrap_or(u32::MAX);
+    if supply >= cap {
+        fail(env, ExtError::SupplyCapReached);
+    }
+    if counted(env, token_id) {
+        storage::set_shared(env, &ExtKey::HotelSupply(hotel_id), &(supply + 1));
+    }
+    Ok(())
+}
+
+/// Uncounts a token about to be burned or moved to another hotel.
+pub(crate) fn record_burn(env: &Env, token_id: u64) {
+    if !counted(env, token_id) {
+        return;
+    }
+    let Some(info) = migration::read_info(env, token_id) else {
+        return;
+    };
+    let supply = supply(env, info.hotel_id);
+    storage::set_shared(
+        env,
+        &ExtKey::HotelSupply(info.hotel_id),
+        &supply.saturating_sub(1),
+    );
+}
+
+/// Whether `token_id` belongs in its hotel's supply now, rather than when a
+/// running recount reaches it.
+fn counted(env: &Env, token_id: u64) -> bool {
+    recount_cursor(env).is_none_or(|cursor| token_id <= cursor)
+}
+
+/// The last token ID a running recount has counted.
+fn recount_cursor(env: &Env) -> Option<u64> {
+    env.storage().persistent().get(&ExtKey::SupplyRecount)
+}
+
+fn capped_hotels(env: &Env) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&ExtKey::CappedHotels)
+        .unwrap_or(Vec::new(env))
+}
+
+/// How many live tokens a hotel has, exactly.
+fn supply(env: &Env, hotel_id: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ExtKey::HotelSupply(hotel_id))
+        .unwrap_or(0)
+}
@@ -0,0 +1,78 @@
+// Atomic week swaps.
+//
+// Two owners trade weeks directly with `swap`: both sign the one call, and
+// either both tokens change hands or neither does. Without it, the first
+// owner to transfer has to trust the other to transfer back.
+//
+// Each side goes through `move_token` like any transfer (so frozen tokens,
+// tokens of retired properties, expired leases and tokens in trust are refused
+// the same way) and publishes its own Transfer event; the Swap event ties the
+// two together.
+
+use soroban_sdk::{contractevent, contractimpl, Address, Env};
+
+use crate::{
+    check_owner, enter, events, move_token, receipts, Error, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/**
+ * @title Swap
+ * @dev Published when two owners swap tokens.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Swap {
+    #[topic]
+    pub token_a: u64,
+    #[topic]
+    pub token_b: u64,
+    pub owner_a: Address,
+    pub owner_b: Address,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Gives `token_a` to `owner_b` and `token_b` to `owner_a` atomically.
+     * Fails with InvalidSwap if both sides are the same owner or token.
+     * @param owner_a The current owner of token_a (must sign).
+     * @param owner_b The current owner of token_b (must sign).
+     */
+    pub fn swap(
+        env: Env,
+        owner_a: Address,
+        token_a: u64,
+        owner_b: Address,
+        token_b: u64,
+    ) -> Result<(), Error> {
+        // 1. Two distinct owners (an address can't authorize the same call
+        // twice), each signing and holding their side.
+        if owner_a == owner_b || token_a == token_b {
+            return Err(Error::InvalidSwap);
+        }
+        owner_a.require_auth();
+        owner_b.require_auth();
+        enter(&env, "swap")?;
+
+        check_owner(&env, token_a, &owner_a)?;
+        check_owner(&env, token_b, &owner_b)?;
+
+        // 2. Move both tokens; any failure reverts the whole swap.
+        move_token(&env, &owner_a, &owner_b, token_a)?;
+        move_token(&env, &owner_b, &owner_a, token_b)?;
+        receipts::record_trade(&env, token_a, &owner_a, &owner_b, token_b);
+        receipts::record_trade(&env, token_b, &owner_b, &owner_a, token_a);
+
+        events::emit(
+            &env,
+            &Swap {
+                token_a,
+                token_b,
+                owner_a,
+                owner_b,
+            },
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+// Timeshare terms.
+//
+// A token is either a perpetual right to its week of the room, every year, or
+// a right limited in time:
+//
+// - `year` is the calendar year the right starts in (None: every year);
+// - `lease_end_ledger` is the ledger sequence at which it expires (None: never).
+//
+// Once the lease has ended the token is expired. It still exists, with its
+// info and owner, until it is burned (which frees its slot for a new sale),
+// but it can no longer change hands, and reading it no longer extends its
+// TTL, so an expired token the owner doesn't keep alive is eventually archived.
+
+use soroban_sdk::{contractimpl, Env};
+
+use crate::{
+    migration, Error, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, TimeshareInfo,
+};
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Returns whether a token's term is still running.
+     * @return false once the ledger has reached the token's lease_end_ledger.
+     */
+    pub fn is_active(env: Env, token_id: u64) -> Result<bool, Error> {
+        let info = migration::read_info(&env, token_id).ok_or(Error::TokenNotFound)?;
+        Ok(!expired(&env, &info))
+    }
+}
+
+/// Validates the term of a token about to be minted. Fails with
+/// InvalidLeaseTerm for year 0 or a lease that has already ended.
+pub(crate) fn validate(env: &Env, info: &TimeshareInfo) -> Result<(), Error> {
+    if info.year == Some(0) || expired(env, info) {
+        return Err(Error::InvalidLeaseTerm);
+    }
+    Ok(())
+}
+
+/// Whether the lease of `info` has ended.
+pub(crate) fn expired(env: &Env, info: &TimeshareInfo) -> bool {
+    match info.lease_end_ledger {
+        Some(end) => env.ledger().sequence() >= end,
+        None => false,
+    }
+}
+
+/// Fails with LeaseExpired if an existing token's lease has ended. Guards
+/// every change of a token's owner.
+pub(crate) fn check_token(env: &Env, token_id: u64) -> Result<(), Error> {
+    if !HotelTimeshareContract::is_active(env.clone(), token_id)? {
+        return Err(Error::LeaseExpired);
+    }
+    Ok(())
+}
@@ -1,21 +1,6076 @@
 #![cfg(test)]
 
+extern crate std;
+
 use super::*;
-use soroban_sdk::{vec, Env, String};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    vec,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, Event, String, Symbol,
+};
+
+fn setup(env: &Env) -> (HotelTimeshareContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(HotelTimeshareContract, ());
+    let client = HotelTimeshareContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    assert_eq!(
+        client.register_hotel(&admin, &String::from_str(env, "Grand Hotel")),
+        GRAND_HOTEL
+    );
+    for room in 305..310 {
+        let room = String::from_str(env, &std::format!("Room {room}"));
+        client.add_room(&admin, &GRAND_HOTEL, &room);
+    }
+    (client, admin)
+}
+
+// The hotel setup() registers, with Rooms 305 to 309.
+const GRAND_HOTEL: u32 = 1;
+
+// The n-th distinct week of Grand Hotel: Room 305 week 28 first, then the
+// following weeks and rooms.
+fn unit(env: &Env, n: u64) -> TimeshareInfo {
+    TimeshareInfo {
+        hotel_id: GRAND_HOTEL,
+        room: String::from_str(env, &std::format!("Room {}", 305 + (n + 27) / 52)),
+        week: ((n + 27) % 52) as u32 + 1,
+        year: None,
+        lease_end_ledger: None,
+    }
+}
+
+// The next `count` units the admin hasn't minted yet.
+fn units(env: &Env, client: &HotelTimeshareContractClient, count: u32) -> Vec<TimeshareInfo> {
+    let admin = client.config().admin;
+    let minted = client.minter_state(&admin).map_or(0, |state| state.minted);
+    let mut infos = Vec::new(env);
+    for n in minted..minted + count as u64 {
+        infos.push_back(unit(env, n));
+    }
+    infos
+}
+
+fn mint_one(env: &Env, client: &HotelTimeshareContractClient, to: &Address) -> u64 {
+    let info = units(env, client, 1).first().unwrap();
+    client.mint(to, &info.hotel_id, &info.room, &info.week, &None, &None)
+}
+
+#[test]
+fn test_mint_and_get_info() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let owner = Address::generate(&env);
+
+    let token_id = mint_one(&env, &client, &owner);
+    assert_eq!(token_id, 1);
+
+    let info = client.get_info(&token_id);
+    assert_eq!(info.hotel_id, GRAND_HOTEL);
+    assert_eq!(info.room, String::from_str(&env, "Room 305"));
+    assert_eq!(info.week, 28);
+}
+
+#[test]
+fn test_transfer() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+
+    client.transfer(&alice, &bob, &token_id);
+    // Bob can now move it on; Alice no longer can.
+    client.transfer(&bob, &alice, &token_id);
+}
+
+#[test]
+fn test_transfer_not_owner() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+
+    assert_eq!(
+        client.try_transfer(&bob, &alice, &token_id),
+        Err(Ok(Error::NotOwner))
+    );
+}
+
+#[test]
+fn test_initialize_twice() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    assert_eq!(
+        client.try_initialize(&admin),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_unknown_token() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    assert_eq!(client.try_get_info(&42), Err(Ok(Error::TokenNotFound)));
+    assert_eq!(
+        client.try_transfer(&alice, &bob, &42),
+        Err(Ok(Error::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_mint_before_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(HotelTimeshareContract, ());
+    let client = HotelTimeshareContractClient::new(&env, &contract_id);
+    let owner = Address::generate(&env);
+
+    assert_eq!(
+        client.try_mint(
+            &owner,
+            &GRAND_HOTEL,
+            &String::from_str(&env, "Room 305"),
+            &28,
+            &None,
+            &None
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_owner_directory() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let first = mint_one(&env, &client, &alice);
+    let second = mint_one(&env, &client, &alice);
+    let third = mint_one(&env, &client, &bob);
+    let contact = |seed: u8| BytesN::from_array(&env, &[seed; 32]);
+
+    // Only owners join, under the property of a token they hold.
+    assert_eq!(
+        client.try_join_directory(&bob, &first, &contact(1), &false),
+        Err(Ok(Error::NotOwner))
+    );
+    assert_eq!(
+        client.join_directory(&alice, &first, &contact(1), &false),
+        GRAND_HOTEL
+    );
+    let joined = DirectoryChanged {
+        hotel_id: GRAND_HOTEL,
+        owner: alice.clone(),
+        listed: true,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, joined.topics(&env));
+    assert_eq!(data.to_xdr(&env), joined.data(&env).to_xdr(&env));
+    client.join_directory(&bob, &third, &contact(2), &true);
+
+    // Joining again updates the entry in place.
+    client.join_directory(&alice, &second, &contact(3), &true);
+    let alice_entry = DirectoryEntry {
+        owner: alice.clone(),
+        contact_hash: contact(3),
+        private: true,
+    };
+    let bob_entry = DirectoryEntry {
+        owner: bob.clone(),
+        contact_hash: contact(2),
+        private: true,
+    };
+    assert_eq!(
+        client.directory(&GRAND_HOTEL, &0, &10),
+        vec![&env, alice_entry.clone(), bob_entry.clone()]
+    );
+    assert_eq!(
+        client.directory(&GRAND_HOTEL, &1, &1),
+        vec![&env, bob_entry.clone()]
+    );
+
+    // Owners who sold up can be pruned by anyone.
+    assert!(!client.prune_directory(&GRAND_HOTEL, &alice));
+    client.transfer(&alice, &bob, &first);
+    client.transfer(&alice, &bob, &second);
+    assert!(client.prune_directory(&GRAND_HOTEL, &alice));
+    assert_eq!(client.directory_entry(&GRAND_HOTEL, &alice), None);
+
+    // Leaving deletes the entry.
+    client.leave_directory(&bob, &GRAND_HOTEL);
+    client.leave_directory(&bob, &GRAND_HOTEL);
+    assert_eq!(client.directory(&GRAND_HOTEL, &0, &10).len(), 0);
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        assert!(!storage.has(&ExtKey::DirectoryEntry(GRAND_HOTEL, bob.clone())));
+        assert!(!storage.has(&ExtKey::Directory(GRAND_HOTEL)));
+    });
+}
+
+#[test]
+fn test_notification_prefs() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let user = Address::generate(&env);
+
+    assert_eq!(client.get_notification_prefs(&user), 0);
+
+    let mask = NOTIFY_FEE_DUE | NOTIFY_OUTBID;
+    client.set_notification_prefs(&user, &mask);
+    assert_eq!(client.get_notification_prefs(&user), mask);
+
+    client.set_notification_prefs(&user, &0);
+    assert_eq!(client.get_notification_prefs(&user), 0);
+}
+
+#[test]
+fn test_notification_prefs_unknown_bits() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_notification_prefs(&user, &(1 << 31)),
+        Err(Ok(Error::UnknownNotificationBits))
+    );
+}
+
+#[test]
+fn test_trust_transfer_requires_acknowledgment() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let owner = Address::generate(&env);
+    let trustee = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &owner);
+
+    client.place_in_trust(&owner, &token_id, &trustee, &beneficiary);
+    let trust = client.get_trust(&token_id).unwrap();
+    assert_eq!(trust.trustee, trustee);
+    assert_eq!(trust.beneficiary, beneficiary);
+
+    assert_eq!(
+        client.try_transfer(&trustee, &buyer, &token_id),
+        Err(Ok(Error::TransferNotAcknowledged))
+    );
+
+    client.acknowledge_transfer(&beneficiary, &token_id, &buyer);
+    client.transfer(&trustee, &buyer, &token_id);
+    // The buyer holds the token outright.
+    assert_eq!(client.get_trust(&token_id), None);
+}
+
+#[test]
+fn test_trust_beneficiary_timelock() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let owner = Address::generate(&env);
+    let trustee = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let heir = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &owner);
+    client.place_in_trust(&owner, &token_id, &trustee, &beneficiary);
+
+    client.propose_beneficiary(&trustee, &token_id, &heir);
+    assert_eq!(
+        client.try_execute_beneficiary_change(&token_id),
+        Err(Ok(Error::TimelockNotElapsed))
+    );
+
+    env.ledger()
+        .with_mut(|l| l.timestamp += BENEFICIARY_TIMELOCK);
+    client.execute_beneficiary_change(&token_id);
+    assert_eq!(client.get_trust(&token_id).unwrap().beneficiary, heir);
+}
+
+#[test]
+fn test_place_in_trust_moves_title() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let owner = Address::generate(&env);
+    let trustee = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &owner);
+    let payment = env.register_stellar_asset_contract_v2(admin).address();
+
+    // Frozen tokens can't be placed in trust, like any transfer.
+    client.freeze(&token_id);
+    assert_eq!(
+        client.try_place_in_trust(&owner, &token_id, &trustee, &beneficiary),
+        Err(Err(ExtError::TokenFrozen.into()))
+    );
+    client.unfreeze(&token_id);
+
+    // Title moves as a transfer, and the owner's listing lapses.
+    client.list_for_sale(&owner, &token_id, &100, &payment);
+    client.approve(&owner, &beneficiary, &token_id);
+    client.place_in_trust(&owner, &token_id, &trustee, &beneficiary);
+    let events = env.events().all();
+    let (_, topics, _) = events.get(events.len() - 2).unwrap();
+    let transfer = Transfer {
+        token_id,
+        from: owner.clone(),
+        to: trustee.clone(),
+    };
+    assert_eq!(topics, transfer.topics(&env));
+    assert_eq!(client.listing(&token_id), None);
+    assert_eq!(client.get_approved(&token_id), None);
+    assert_eq!(client.tokens_of_owner(&trustee), vec![&env, token_id]);
+    assert_eq!(client.balance_of(&owner), 0);
+}
+
+#[test]
+fn test_transfer_to_alias() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let name_hash = BytesN::from_array(&env, &[7; 32]);
+
+    assert_eq!(
+        client.try_transfer_to_alias(&alice, &name_hash, &token_id),
+        Err(Ok(Error::UnknownAlias))
+    );
+
+    client.set_alias(&alice, &name_hash, &bob);
+    assert_eq!(client.resolve_alias(&alice, &name_hash), Some(bob.clone()));
+
+    client.transfer_to_alias(&alice, &name_hash, &token_id);
+    // Bob owns it now, so he can send it back.
+    client.transfer(&bob, &alice, &token_id);
+
+    client.remove_alias(&alice, &name_hash);
+    assert_eq!(client.resolve_alias(&alice, &name_hash), None);
+}
+
+#[test]
+fn test_two_phase_transfer() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+
+    client.set_transfer_confirmation(&alice, &true);
+    client.transfer(&alice, &bob, &token_id);
+    let pending = client.get_pending_transfer(&token_id).unwrap();
+    assert_eq!(pending.to, bob);
+    // Nothing moved yet: Bob can't transfer it.
+    assert_eq!(
+        client.try_transfer(&bob, &alice, &token_id),
+        Err(Ok(Error::NotOwner))
+    );
+
+    client.accept_transfer(&token_id);
+    assert_eq!(client.get_pending_transfer(&token_id), None);
+    client.transfer(&bob, &alice, &token_id);
+}
+
+#[test]
+fn test_two_phase_transfer_expires() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+
+    client.set_transfer_confirmation(&alice, &true);
+    client.transfer(&alice, &bob, &token_id);
+
+    env.ledger()
+        .with_mut(|l| l.timestamp += PENDING_TRANSFER_WINDOW + 1);
+    assert_eq!(
+        client.try_accept_transfer(&token_id),
+        Err(Ok(Error::PendingTransferExpired))
+    );
+
+    // Alice still owns it and can opt back out and transfer directly.
+    client.set_transfer_confirmation(&alice, &false);
+    client.transfer(&alice, &bob, &token_id);
+    assert_eq!(client.get_pending_transfer(&token_id), None);
+}
+
+#[test]
+fn test_sink_addresses_rejected() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let sink = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+
+    // The contract itself and the all-zero account are always sinks.
+    assert_eq!(
+        client.try_transfer(&alice, &client.address, &token_id),
+        Err(Ok(Error::InvalidRecipient))
+    );
+    let zero = Address::from_str(
+        &env,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    );
+    assert!(client.is_sink_address(&zero));
+
+    assert!(!client.is_sink_address(&sink));
+    client.add_sink_address(&sink);
+    assert_eq!(
+        client.try_transfer(&alice, &sink, &token_id),
+        Err(Ok(Error::InvalidRecipient))
+    );
+
+    // Admin co-signed transfers still go through.
+    client.force_transfer(&alice, &sink, &token_id);
+
+    client.remove_sink_address(&sink);
+    assert!(!client.is_sink_address(&sink));
+}
+
+#[test]
+fn test_config() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let owner = Address::generate(&env);
+    mint_one(&env, &client, &owner);
+
+    let config = client.config();
+    assert_eq!(config.admin, admin);
+    assert_eq!(config.last_token_id, ID_BLOCK_SIZE);
+    assert_eq!(config.pending_transfer_window, PENDING_TRANSFER_WINDOW);
+}
+
+#[test]
+fn test_version_info() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    let info = client.version_info();
+    assert_eq!(
+        info.version,
+        String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    );
+    assert!(info.upgrades.is_empty());
+}
+
+#[test]
+fn test_capabilities() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    let caps = client.capabilities();
+    assert_eq!(caps.len() as usize, CAPABILITIES.len());
+    assert!(caps.contains(Symbol::new(&env, "nft_core")));
+}
+
+#[test]
+fn test_commit_daily_root() {
+    let env = Env::default();
+    env.mock_all_auths();
+    // Not setup(): registering a hotel would publish an event before genesis.
+    let client = HotelTimeshareContractClient::new(&env, &env.register(HotelTimeshareContract, ()));
+    client.initialize(&Address::generate(&env));
+    let user = Address::generate(&env);
+
+    let genesis = client.event_chain_head();
+    assert_eq!(genesis.count, 0);
+
+    client.set_notification_prefs(&user, &NOTIFY_FEE_DUE);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let head = client.event_chain_head();
+    assert_eq!(head.count, 1);
+
+    // A light client can recompute the lane and chain heads from the event.
+    let topics = topics.to_xdr(&env);
+    let lane = env.crypto().sha256(&topics).to_array()[0] as u32 % EVENT_LANES;
+    let mut preimage = Bytes::from_array(&env, &[0; 32]);
+    preimage.append(&topics);
+    preimage.append(&data.to_xdr(&env));
+    let lane_head: BytesN<32> = env.crypto().sha256(&preimage).into();
+
+    let mut lanes = Bytes::new(&env);
+    for i in 0..EVENT_LANES {
+        let h = if i == lane {
+            lane_head.to_array()
+        } else {
+            [0; 32]
+        };
+        lanes.append(&Bytes::from_array(&env, &h));
+    }
+    let expected: BytesN<32> = env.crypto().sha256(&lanes).into();
+    assert_eq!(head.head, expected);
+    assert_eq!(client.event_lanes().get(lane).unwrap().head, lane_head);
+
+    let day = client.commit_daily_root();
+    assert_eq!(client.daily_root(&day), Some(head));
+    assert_eq!(
+        client.daily_root_lanes(&day).unwrap().get(lane),
+        Some(lane_head)
+    );
+    assert_eq!(
+        client.try_commit_daily_root(),
+        Err(Ok(Error::DailyRootAlreadyCommitted))
+    );
+
+    // Tomorrow's root also covers the commit event itself.
+    env.ledger().with_mut(|l| l.timestamp += DAY_SECONDS);
+    let next_day = client.commit_daily_root();
+    assert_eq!(next_day, day + 1);
+    assert_eq!(client.daily_root(&next_day).unwrap().count, 2);
+}
+
+#[test]
+fn test_mint_and_transfer_events() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let token_id = mint_one(&env, &client, &alice);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let mint = Mint {
+        token_id,
+        owner: alice.clone(),
+        info: TimeshareInfo {
+            hotel_id: GRAND_HOTEL,
+            room: String::from_str(&env, "Room 305"),
+            week: 28,
+            year: None,
+            lease_end_ledger: None,
+        },
+    };
+    assert_eq!(topics, mint.topics(&env));
+    assert_eq!(data.to_xdr(&env), mint.data(&env).to_xdr(&env));
+
+    client.transfer(&alice, &bob, &token_id);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let transfer = Transfer {
+        token_id,
+        from: alice,
+        to: bob,
+    };
+    assert_eq!(topics, transfer.topics(&env));
+    assert_eq!(data.to_xdr(&env), transfer.data(&env).to_xdr(&env));
+}
+
+#[test]
+fn test_metrics() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let day = env.ledger().timestamp() / DAY_SECONDS;
+
+    let token_id = mint_one(&env, &client, &alice);
+    client.transfer(&alice, &bob, &token_id);
+    client.transfer(&bob, &alice, &token_id);
+    // Failed calls roll back, so they are not counted.
+    let _ = client.try_transfer(&bob, &alice, &token_id);
+
+    let counts = client.metrics(&day);
+    assert_eq!(counts.get(Symbol::new(&env, "mint")), Some(1));
+    assert_eq!(counts.get(Symbol::new(&env, "transfer")), Some(2));
+    assert!(client.metrics(&(day + 1)).is_empty());
+
+    // Mints count in their signer's lane; the lanes add up.
+    let mint = Symbol::new(&env, "mint");
+    env.as_contract(&client.address, || {
+        let lane = metrics::lane(&env, &admin);
+        let other = lane % (METRICS_LANES - 1) + 1;
+        assert_ne!(lane, other);
+        let key = ExtKey::MetricsLane(day, mint.clone(), other);
+        env.storage().temporary().set(&key, &4u32);
+    });
+    assert_eq!(client.metrics(&day).get(mint), Some(5));
+}
+
+#[test]
+fn test_get_owner_and_token() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+
+    assert_eq!(client.get_owner(&token_id), alice);
+    client.transfer(&alice, &bob, &token_id);
+    assert_eq!(client.get_owner(&token_id), bob);
+
+    let token = client.get_token(&token_id);
+    assert_eq!(token.token_id, token_id);
+    assert_eq!(token.owner, bob);
+    assert_eq!(token.info, client.get_info(&token_id));
+
+    assert_eq!(client.try_get_owner(&99), Err(Ok(Error::TokenNotFound)));
+    assert_eq!(client.try_get_token(&99), Err(Ok(Error::TokenNotFound)));
+}
+
+#[test]
+fn test_endpoint_deprecation_and_sunset() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let name_hash = BytesN::from_array(&env, &[1; 32]);
+    client.set_alias(&alice, &name_hash, &bob);
+
+    let endpoint = Symbol::new(&env, "transfer_to_alias");
+    let sunset = env.ledger().sequence() + 100;
+    client.deprecate_endpoint(&endpoint, &sunset, &Some(Symbol::new(&env, "transfer")));
+    assert_eq!(client.deprecations().len(), 1);
+    assert_eq!(client.config().deprecations, client.deprecations());
+
+    // Before the sunset the call works but carries a warning event.
+    client.transfer_to_alias(&alice, &name_hash, &token_id);
+    let warned = env.events().all().iter().any(|(_, topics, _)| {
+        topics
+            == EndpointDeprecated {
+                endpoint: endpoint.clone(),
+                sunset_ledger: sunset,
+                replacement: None,
+            }
+            .topics(&env)
+    });
+    assert!(warned);
+
+    env.ledger().with_mut(|l| l.sequence_number = sunset);
+    client.set_alias(&bob, &name_hash, &alice);
+    assert_eq!(
+        client.try_transfer_to_alias(&bob, &name_hash, &token_id),
+        Err(Ok(Error::EndpointSunset))
+    );
+
+    client.undeprecate_endpoint(&endpoint);
+    client.transfer_to_alias(&bob, &name_hash, &token_id);
+}
+
+#[test]
+fn test_conformance_suite() {
+    timeshare_conformance::run_all(&|env| env.register(HotelTimeshareContract, ()));
+}
+
+#[test]
+fn test_owner_enumeration() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let trustee = Address::generate(&env);
+
+    let first = mint_one(&env, &client, &alice);
+    let second = mint_one(&env, &client, &alice);
+    let third = mint_one(&env, &client, &bob);
+    assert_eq!(client.balance_of(&alice), 2);
+    assert_eq!(client.tokens_of_owner(&alice), vec![&env, first, second]);
+
+    client.transfer(&alice, &bob, &first);
+    assert_eq!(client.tokens_of_owner(&alice), vec![&env, second]);
+    assert_eq!(client.tokens_of_owner(&bob), vec![&env, third, first]);
+
+    client.place_in_trust(&alice, &second, &trustee, &alice);
+    assert_eq!(client.balance_of(&alice), 0);
+    assert_eq!(client.tokens_of_owner(&trustee), vec![&env, second]);
+}
+
+#[test]
+fn test_replay_generated_dataset() {
+    use timeshare_testdata::{generate, Activity, Profile};
+
+    let data = generate(7, &Profile::SMALL);
+    assert_eq!(data, generate(7, &Profile::SMALL));
+
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let owners: std::vec::Vec<Address> = (0..Profile::SMALL.owners)
+        .map(|_| Address::generate(&env))
+        .collect();
+
+    let mut hotels = std::collections::BTreeMap::new();
+    let mut rooms = std::collections::BTreeSet::new();
+    for (unit, holder) in data.units.iter().zip(&data.holders) {
+        let hotel_id = *hotels
+            .entry(unit.hotel.clone())
+            .or_insert_with(|| client.register_hotel(&admin, &String::from_str(&env, &unit.hotel)));
+        if rooms.insert((hotel_id, unit.room.clone())) {
+            client.add_room(&admin, &hotel_id, &String::from_str(&env, &unit.room));
+        }
+        client.mint(
+            &owners[*holder as usize],
+            &hotel_id,
+            &String::from_str(&env, &unit.room),
+            &unit.week,
+            &None,
+            &None,
+        );
+    }
+    for event in &data.activity {
+        if let Activity::Sale { unit, from, to, .. } | Activity::Gift { unit, from, to, .. } =
+            *event
+        {
+            let token_id = unit as u64 + 1;
+            client.transfer(&owners[from as usize], &owners[to as usize], &token_id);
+        }
+    }
+
+    let holders = data.final_holders();
+    for (unit, holder) in holders.iter().enumerate() {
+        assert_eq!(
+            client.get_owner(&(unit as u64 + 1)),
+            owners[*holder as usize]
+        );
+    }
+    let total: u32 = owners.iter().map(|o| client.balance_of(o)).sum();
+    assert_eq!(total as usize, data.units.len());
+}
+
+#[test]
+fn test_total_supply_and_token_pages() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let owner = Address::generate(&env);
+    assert_eq!(client.total_supply(), 0);
+    assert_eq!(
+        client.tokens(&0, &10),
+        TokenPage {
+            token_ids: vec![&env],
+            next_start_after: None,
+        }
+    );
+
+    for _ in 0..5 {
+        mint_one(&env, &client, &owner);
+    }
+    assert_eq!(client.total_supply(), 5);
+
+    let first = client.tokens(&0, &3);
+    assert_eq!(first.token_ids, vec![&env, 1, 2, 3]);
+    assert_eq!(first.next_start_after, Some(3));
+    let second = client.tokens(&3, &3);
+    assert_eq!(second.token_ids, vec![&env, 4, 5]);
+    // The rest of the minter's reserved ID block is walked but holds no tokens.
+    let rest = client.tokens(&6, &MAX_PAGE);
+    assert!(rest.token_ids.is_empty());
+    assert_eq!(rest.next_start_after, None);
+}
+
+#[test]
+fn test_approve_and_transfer_from() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let market = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+
+    assert_eq!(
+        client.try_transfer_from(&market, &alice, &bob, &token_id),
+        Err(Ok(Error::NotApproved))
+    );
+    assert_eq!(
+        client.try_approve(&bob, &market, &token_id),
+        Err(Ok(Error::NotOwner))
+    );
+
+    client.approve(&alice, &market, &token_id);
+    assert_eq!(client.get_approved(&token_id), Some(market.clone()));
+
+    client.transfer_from(&market, &alice, &bob, &token_id);
+    assert_eq!(client.get_owner(&token_id), bob);
+    // The approval was alice's and does not survive the transfer.
+    assert_eq!(client.get_approved(&token_id), None);
+    assert_eq!(
+        client.try_transfer_from(&market, &bob, &alice, &token_id),
+        Err(Ok(Error::NotApproved))
+    );
+}
+
+// --- Chaos: partial failures ---
+//
+// A failed or trapped invocation rolls back every write it made, in this
+// contract and in every contract it called. These tests pin that down for the
+// flows that move money next to tokens, using a marketplace that pays first and
+// takes the token second, so neither leg can be lost on its own.
+
+mod chaos_market {
+    use crate::HotelTimeshareContractClient;
+    use soroban_sdk::{contract, contractimpl, token, Address, Env};
+
+    #[contract]
+    pub struct ChaosMarket;
+
+    #[contractimpl]
+    impl ChaosMarket {
+        /// Pays the seller, then takes the token with transfer_from, then
+        /// traps if `trap_after` is set.
+        #[allow(clippy::too_many_arguments)]
+        pub fn buy(
+            env: Env,
+            nft: Address,
+            payment: Address,
+            buyer: Address,
+            seller: Address,
+            token_id: u64,
+            price: i128,
+            trap_after: bool,
+        ) {
+            buyer.require_auth();
+            token::Client::new(&env, &payment).transfer(&buyer, &seller, &price);
+            HotelTimeshareContractClient::new(&env, &nft).transfer_from(
+                &env.current_contract_address(),
+                &seller,
+                &buyer,
+                &token_id,
+            );
+            if trap_after {
+                panic!("trap after settlement");
+            }
+        }
+    }
+}
+
+struct Chaos<'a> {
+    client: HotelTimeshareContractClient<'a>,
+    market: chaos_market::ChaosMarketClient<'a>,
+    payment: soroban_sdk::token::Client<'a>,
+    seller: Address,
+    buyer: Address,
+    token_id: u64,
+}
+
+const PRICE: i128 = 1_000;
+
+fn chaos_setup(env: &Env) -> Chaos<'_> {
+    let (client, admin) = setup(env);
+    let seller = Address::generate(env);
+    let buyer = Address::generate(env);
+    let token_id = mint_one(env, &client, &seller);
+
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    soroban_sdk::token::StellarAssetClient::new(env, &sac.address()).mint(&buyer, &PRICE);
+    let market_id = env.register(chaos_market::ChaosMarket, ());
+
+    Chaos {
+        client,
+        market: chaos_market::ChaosMarketClient::new(env, &market_id),
+        payment: soroban_sdk::token::Client::new(env, &sac.address()),
+        seller,
+        buyer,
+        token_id,
+    }
+}
+
+impl Chaos<'_> {
+    fn try_buy(&self, trap_after: bool) -> bool {
+        self.market
+            .try_buy(
+                &self.client.address,
+                &self.payment.address,
+                &self.buyer,
+                &self.seller,
+                &self.token_id,
+                &PRICE,
+                &trap_after,
+            )
+            .is_ok()
+    }
+
+    /// Nothing moved: the buyer keeps the money, the seller keeps the token.
+    fn assert_untouched(&self) {
+        assert_eq!(self.payment.balance(&self.buyer), PRICE);
+        assert_eq!(self.payment.balance(&self.seller), 0);
+        assert_eq!(self.client.get_owner(&self.token_id), self.seller);
+        assert_eq!(self.client.balance_of(&self.seller), 1);
+        assert_eq!(self.client.balance_of(&self.buyer), 0);
+    }
+}
+
+#[test]
+fn test_chaos_token_leg_fails_after_payment() {
+    let env = Env::default();
+    let chaos = chaos_setup(&env);
+
+    // The market was never approved: payment succeeds, transfer_from fails.
+    assert!(!chaos.try_buy(false));
+    chaos.assert_untouched();
+}
+
+#[test]
+fn test_chaos_stale_approval_after_payment() {
+    let env = Env::default();
+    let chaos = chaos_setup(&env);
+    let carol = Address::generate(&env);
+
+    // The seller approves the market, then sells elsewhere before the buy lands.
+    chaos
+        .client
+        .approve(&chaos.seller, &chaos.market.address, &chaos.token_id);
+    chaos
+        .client
+        .transfer(&chaos.seller, &carol, &chaos.token_id);
+
+    assert!(!chaos.try_buy(false));
+    assert_eq!(chaos.payment.balance(&chaos.buyer), PRICE);
+    assert_eq!(chaos.client.get_owner(&chaos.token_id), carol);
+}
+
+#[test]
+fn test_chaos_trap_after_both_legs() {
+    let env = Env::default();
+    let chaos = chaos_setup(&env);
+    chaos
+        .client
+        .approve(&chaos.seller, &chaos.market.address, &chaos.token_id);
+    let head = chaos.client.event_chain_head();
+
+    // Both legs complete, then the market traps: both are rolled back, and
+    // so is every side effect in this contract.
+    assert!(!chaos.try_buy(true));
+    chaos.assert_untouched();
+    assert_eq!(
+        chaos.client.get_approved(&chaos.token_id),
+        Some(chaos.market.address.clone())
+    );
+    assert_eq!(chaos.client.event_chain_head(), head);
+
+    // The state is exactly the pre-trade one, so the trade can simply be retried.
+    assert!(chaos.try_buy(false));
+    assert_eq!(chaos.payment.balance(&chaos.seller), PRICE);
+    assert_eq!(chaos.client.get_owner(&chaos.token_id), chaos.buyer);
+}
+
+#[test]
+fn test_chaos_expired_temporary_entries() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    mint_one(&env, &client, &alice);
+
+    // Today's metrics bucket is evicted mid-day: the next call starts a fresh
+    // bucket instead of failing.
+    let day = env.ledger().timestamp() / DAY_SECONDS;
+    env.ledger()
+        .with_mut(|l| l.sequence_number += METRICS_TTL_LEDGERS + 1);
+    assert!(client.metrics(&day).is_empty());
+
+    mint_one(&env, &client, &alice);
+    assert_eq!(client.metrics(&day).get(Symbol::new(&env, "mint")), Some(1));
+}
+
+// Escrowed payments: the contract holds a bid, an offer or a purchase price
+// and pays it out in a later call. Revoking an account's authorization on the
+// payment asset makes any payment to it fail, after the contract has already
+// updated its own records, paid another party or moved a token; none of it
+// may survive the failure.
+
+/// A payment asset whose issuer can revoke holders, with 1_000 for each of
+/// `holders`.
+fn revocable_asset<'a>(
+    env: &Env,
+    admin: &Address,
+    holders: &[&Address],
+) -> (
+    soroban_sdk::token::Client<'a>,
+    soroban_sdk::token::StellarAssetClient<'a>,
+) {
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    sac.issuer()
+        .set_flag(soroban_sdk::testutils::IssuerFlags::RevocableFlag);
+    let asset = soroban_sdk::token::StellarAssetClient::new(env, &sac.address());
+    for holder in holders {
+        asset.mint(holder, &1_000);
+    }
+    (soroban_sdk::token::Client::new(env, &sac.address()), asset)
+}
+
+#[test]
+fn test_chaos_auction_payouts_fail() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let (xlm, asset) = revocable_asset(&env, &admin, &[&bob, &carol]);
+    client.start_auction(&alice, &token_id, &xlm.address, &100, &60);
+    client.bid(&bob, &token_id, &100);
+
+    // Carol's bid is taken, then refunding Bob fails: Carol keeps her money
+    // and Bob's bid stands.
+    asset.set_authorized(&bob, &false);
+    assert!(client.try_bid(&carol, &token_id, &200).is_err());
+    assert_eq!(xlm.balance(&carol), 1_000);
+    assert_eq!(xlm.balance(&client.address), 100);
+    let auction = client.auction(&token_id).unwrap();
+    assert_eq!(
+        (auction.high_bidder, auction.high_bid),
+        (Some(bob.clone()), 100)
+    );
+    asset.set_authorized(&bob, &true);
+
+    // Settlement removes the auction, then paying Alice fails: the auction,
+    // the escrowed bid and the token are all still there, and settlement can
+    // be retried.
+    env.ledger().set_timestamp(env.ledger().timestamp() + 60);
+    asset.set_authorized(&alice, &false);
+    assert!(client.try_settle_auction(&token_id).is_err());
+    assert_eq!(client.auction(&token_id).unwrap().high_bid, 100);
+    assert_eq!(xlm.balance(&client.address), 100);
+    assert_eq!(client.get_owner(&token_id), alice);
+    asset.set_authorized(&alice, &true);
+    client.settle_auction(&token_id);
+    assert_eq!(client.get_owner(&token_id), bob);
+    assert_eq!(xlm.balance(&alice), 100);
+}
+
+#[test]
+fn test_chaos_offer_payouts_fail() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let (xlm, asset) = revocable_asset(&env, &admin, &[&bob]);
+    let expires_at = env.ledger().timestamp() + 60;
+    client.make_offer(&bob, &token_id, &xlm.address, &300, &expires_at);
+
+    // Accepting removes the offer, then paying Alice fails.
+    asset.set_authorized(&alice, &false);
+    assert!(client.try_accept_offer(&alice, &token_id, &bob).is_err());
+    assert_eq!(client.offer(&token_id, &bob).unwrap().amount, 300);
+    assert_eq!(client.get_owner(&token_id), alice);
+    asset.set_authorized(&alice, &true);
+
+    // Cancelling removes the offer, then refunding Bob fails.
+    asset.set_authorized(&bob, &false);
+    assert!(client.try_cancel_offer(&bob, &token_id).is_err());
+    assert_eq!(client.offer(&token_id, &bob).unwrap().amount, 300);
+    assert_eq!(xlm.balance(&client.address), 300);
+    asset.set_authorized(&bob, &true);
+
+    // A new offer is taken, then refunding the old one fails.
+    asset.mint(&bob, &500);
+    asset.set_authorized(&bob, &false);
+    assert!(client
+        .try_make_offer(&bob, &token_id, &xlm.address, &500, &expires_at)
+        .is_err());
+    assert_eq!(client.offer(&token_id, &bob).unwrap().amount, 300);
+    asset.set_authorized(&bob, &true);
+
+    client.accept_offer(&alice, &token_id, &bob);
+    assert_eq!(client.get_owner(&token_id), bob);
+    assert_eq!(xlm.balance(&alice), 300);
+    assert_eq!(xlm.balance(&client.address), 0);
+}
+
+#[test]
+fn test_chaos_cooling_off_payouts_fail() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let (xlm, asset) = revocable_asset(&env, &admin, &[&alice]);
+    client.set_cooling_off(&100, &9_000);
+    client.set_mint_price(&admin, &GRAND_HOTEL, &1_000, &xlm.address);
+    let room = String::from_str(&env, "Room 305");
+    let token_id = client.buy_mint(&alice, &GRAND_HOTEL, &room, &1);
+
+    // Cancelling burns the token and refunds Alice, then paying the hotel its
+    // share fails: the token, the purchase and the escrow are all restored.
+    asset.set_authorized(&admin, &false);
+    assert!(client.try_cancel_purchase(&alice, &token_id).is_err());
+    assert_eq!(client.get_owner(&token_id), alice);
+    assert_eq!(client.purchase(&token_id).unwrap().price, 1_000);
+    assert_eq!(xlm.balance(&alice), 0);
+    assert_eq!(xlm.balance(&client.address), 1_000);
+    assert_eq!(
+        client.token_by_slot(&GRAND_HOTEL, &room, &1, &None),
+        Some(token_id)
+    );
+
+    // After the period, releasing to the hotel fails the same way, and
+    // succeeds once it can be paid.
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    assert!(client.try_release_purchase(&token_id).is_err());
+    assert!(client.purchase(&token_id).is_some());
+    asset.set_authorized(&admin, &true);
+    client.release_purchase(&token_id);
+    assert_eq!(xlm.balance(&admin), 1_000);
+    assert_eq!(xlm.balance(&client.address), 0);
+}
+
+#[test]
+fn test_chaos_trade_release_fails() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let escrowed = mint_one(&env, &client, &alice);
+    let wanted = mint_one(&env, &client, &bob);
+    client.open_trade(&alice, &escrowed, &bob, &wanted);
+
+    // Bob's token moves to Alice, then releasing the frozen escrowed token
+    // fails: Bob keeps his token and the trade stays open.
+    client.freeze(&escrowed);
+    assert_eq!(
+        client.try_accept_trade(&bob, &escrowed),
+        Err(Err(ExtError::TokenFrozen.into()))
+    );
+    assert_eq!(client.get_owner(&wanted), bob);
+    assert_eq!(client.get_owner(&escrowed), client.address);
+    assert_eq!(client.trade(&escrowed).unwrap().wanted_token_id, wanted);
+    assert_eq!(
+        client.try_withdraw_trade(&alice, &escrowed),
+        Err(Err(ExtError::TokenFrozen.into()))
+    );
+    assert!(client.trade(&escrowed).is_some());
+
+    client.unfreeze(&escrowed);
+    client.accept_trade(&bob, &escrowed);
+    assert_eq!(client.get_owner(&wanted), alice);
+    assert_eq!(client.get_owner(&escrowed), bob);
+}
+
+#[test]
+fn test_operator_approvals() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let first = mint_one(&env, &client, &alice);
+    let second = mint_one(&env, &client, &alice);
+
+    client.set_approval_for_all(&alice, &manager, &true);
+    assert!(client.is_approved_for_all(&alice, &manager));
+    assert!(!client.is_approved_for_all(&bob, &manager));
+
+    // The operator can move every token and keeps its approval afterwards.
+    client.transfer_from(&manager, &alice, &bob, &first);
+    client.transfer_from(&manager, &alice, &bob, &second);
+    assert_eq!(client.balance_of(&bob), 2);
+    assert!(client.is_approved_for_all(&alice, &manager));
+
+    // Operator approval is per owner: bob never approved the manager.
+    assert_eq!(
+        client.try_transfer_from(&manager, &bob, &alice, &first),
+        Err(Ok(Error::NotApproved))
+    );
+
+    client.set_approval_for_all(&alice, &manager, &false);
+    client.transfer(&bob, &alice, &first);
+    assert_eq!(
+        client.try_transfer_from(&manager, &alice, &bob, &first),
+        Err(Ok(Error::NotApproved))
+    );
+}
+
+#[test]
+fn test_burn() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let market = Address::generate(&env);
+    let first = mint_one(&env, &client, &alice);
+    let second = mint_one(&env, &client, &alice);
+    client.approve(&alice, &market, &first);
+
+    assert_eq!(client.try_burn(&bob, &first), Err(Ok(Error::NotOwner)));
+    client.burn(&alice, &first);
+    let event = Burn {
+        token_id: first,
+        owner: alice.clone(),
+    };
+    assert_eq!(env.events().all().last().unwrap().1, event.topics(&env));
+
+    assert_eq!(client.try_get_info(&first), Err(Ok(Error::TokenNotFound)));
+    assert_eq!(client.try_get_owner(&first), Err(Ok(Error::TokenNotFound)));
+    assert_eq!(client.get_approved(&first), None);
+    assert_eq!(client.total_supply(), 1);
+    assert_eq!(client.tokens_of_owner(&alice), vec![&env, second]);
+    assert_eq!(client.tokens(&0, &10).token_ids, vec![&env, second]);
+    // IDs are never reused.
+    assert_eq!(mint_one(&env, &client, &alice), 3);
+}
+
+#[test]
+fn test_burn_token_in_trust() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let trustee = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    client.place_in_trust(&alice, &token_id, &trustee, &alice);
+
+    assert_eq!(
+        client.try_burn(&trustee, &token_id),
+        Err(Ok(Error::AlreadyInTrust))
+    );
+
+    client.admin_burn(&token_id);
+    assert_eq!(client.get_trust(&token_id), None);
+    assert_eq!(client.balance_of(&trustee), 0);
+    assert_eq!(client.total_supply(), 0);
+}
+
+#[test]
+fn test_mint_batch() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    mint_one(&env, &client, &alice);
+
+    let mut infos = units(&env, &client, 52);
+    let ids = client.mint_batch(&alice, &infos);
+    assert_eq!(ids.len(), 52);
+    assert_eq!(ids.first(), Some(2));
+    assert_eq!(ids.last(), Some(53));
+    assert_eq!(client.get_info(&53), infos.last().unwrap());
+    assert_eq!(client.balance_of(&alice), 53);
+    assert_eq!(client.total_supply(), 53);
+    assert_eq!(mint_one(&env, &client, &alice), 54);
+
+    assert_eq!(client.mint_batch(&alice, &Vec::new(&env)), Vec::new(&env));
+    for _ in 0..MAX_MINT_BATCH {
+        infos.push_back(infos.first().unwrap());
+    }
+    assert_eq!(
+        client.try_mint_batch(&alice, &infos),
+        Err(Ok(Error::BatchTooLarge))
+    );
+}
+
+#[test]
+fn test_id_blocks() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let other_minter = Address::generate(&env);
+
+    // Exhaust the admin's first block; the next mint reserves a new one.
+    let info = client.get_info(&mint_one(&env, &client, &alice));
+    client.mint_batch(&alice, &units(&env, &client, MAX_MINT_BATCH));
+    let rest = ID_BLOCK_SIZE as u32 - 1 - MAX_MINT_BATCH;
+    client.mint_batch(&alice, &units(&env, &client, rest));
+    assert_eq!(
+        client.minter_state(&admin),
+        Some(MinterState {
+            next: 101,
+            end: 100,
+            minted: 100,
+        })
+    );
+    assert_eq!(mint_one(&env, &client, &alice), 101);
+    assert_eq!(client.config().last_token_id, 200);
+
+    // Another minter draws from its own block and never touches the admin's.
+    let ids = env.as_contract(&client.address, || {
+        ids::allocate(&env, &other_minter, 2).unwrap()
+    });
+    assert_eq!(ids, vec![&env, 201, 202]);
+    assert_eq!(mint_one(&env, &client, &alice), 102);
+
+    // An ID that is somehow already taken is never reissued.
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(&DataKey::Info(103), &info);
+    });
+    assert_eq!(
+        client.try_mint(
+            &alice,
+            &GRAND_HOTEL,
+            &String::from_str(&env, "Room 305"),
+            &28,
+            &None,
+            &None
+        ),
+        Err(Ok(Error::TokenIdCollision))
+    );
+}
+
+#[test]
+fn test_restore_hint() {
+    use soroban_sdk::xdr::{
+        ContractDataDurability, LedgerKey, LedgerKeyContractData, Limits, ReadXdr, ScAddress, ScVal,
+    };
+
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let token_id = mint_one(&env, &client, &Address::generate(&env));
+
+    let data_key = |key: ScVal| {
+        LedgerKey::ContractData(LedgerKeyContractData {
+            contract: ScAddress::from(&client.address),
+            key,
+            durability: ContractDataDurability::Persistent,
+        })
+    };
+    let keys: std::vec::Vec<LedgerKey> = client
+        .restore_hint(&token_id)
+        .iter()
+        .map(|raw| {
+            let raw: std::vec::Vec<u8> = raw.iter().collect();
+            LedgerKey::from_xdr(raw, Limits::none()).unwrap()
+        })
+        .collect();
+
+    // The instance, the code, then every per-token key.
+    assert_eq!(keys.len(), 36);
+    assert_eq!(keys[0], data_key(ScVal::LedgerKeyContractInstance));
+    assert!(matches!(keys[1], LedgerKey::ContractCode(_)));
+    let info_key = DataKey::Info(token_id).to_xdr(&env);
+    let info_key: std::vec::Vec<u8> = info_key.iter().collect();
+    assert_eq!(
+        keys[2],
+        data_key(ScVal::from_xdr(info_key, Limits::none()).unwrap())
+    );
+}
+
+#[test]
+fn test_bump_portfolio() {
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    client.mint_batch(&alice, &units(&env, &client, MAX_BUMP_PAGE + 10));
+
+    let ttl = |key: DataKey| {
+        env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key))
+    };
+    let extend_to = ttl(DataKey::Info(1)) + 10_000;
+    assert_eq!(client.bump_portfolio(&alice, &0, &extend_to), Some(1));
+    assert_eq!(ttl(DataKey::Info(1)), extend_to);
+    assert_eq!(ttl(DataKey::Owner(MAX_BUMP_PAGE as u64)), extend_to);
+    assert_eq!(ttl(DataKey::OwnedTokens(alice.clone())), extend_to);
+    assert!(ttl(DataKey::Info(MAX_BUMP_PAGE as u64 + 1)) < extend_to);
+    assert_eq!(client.bump_portfolio(&alice, &1, &extend_to), None);
+    assert_eq!(ttl(DataKey::Info(MAX_BUMP_PAGE as u64 + 10)), extend_to);
+
+    // Past the end of the portfolio, or for an owner without tokens, nothing is bumped.
+    let bob = Address::generate(&env);
+    assert_eq!(client.bump_portfolio(&bob, &0, &(extend_to + 1)), None);
+    assert_eq!(client.bump_portfolio(&alice, &2, &(extend_to + 1)), None);
+    assert_eq!(ttl(DataKey::OwnedTokens(alice)), extend_to);
+}
+
+#[test]
+fn test_rent_pool() {
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    for _ in 0..3 {
+        mint_one(&env, &client, &alice);
+    }
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    soroban_sdk::token::StellarAssetClient::new(&env, &sac.address()).mint(&alice, &1_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &sac.address());
+
+    assert_eq!(
+        client.try_deposit_rent(&alice, &100),
+        Err(Ok(Error::RentPoolNotConfigured))
+    );
+    let policy = RentPolicy {
+        token: sac.address(),
+        fee_per_token: 10,
+        threshold: TOKEN_TTL_EXTEND_TO,
+        extend_to: TOKEN_TTL_EXTEND_TO * 2,
+        low_balance: 50,
+    };
+    client.set_rent_policy(&policy);
+    assert_eq!(client.config().rent_pool, RentPool::Enabled(policy.clone()));
+    let other_token = RentPolicy {
+        token: Address::generate(&env),
+        ..policy.clone()
+    };
+    assert_eq!(
+        client.try_set_rent_policy(&other_token),
+        Err(Ok(Error::InvalidRentPolicy))
+    );
+
+    assert_eq!(client.deposit_rent(&alice, &100), 100);
+    assert_eq!(xlm.balance(&alice), 900);
+
+    // A bump of three tokens costs 30 and keeps the entries alive.
+    assert_eq!(client.keeper_bump(&keeper, &alice, &0), None);
+    assert_eq!(client.rent_balance(&alice), 70);
+    assert_eq!(xlm.balance(&keeper), 30);
+    let ttl = env.as_contract(&client.address, || {
+        env.storage().persistent().get_ttl(&DataKey::Info(1))
+    });
+    assert_eq!(ttl, TOKEN_TTL_EXTEND_TO * 2);
+
+    // The page can't be charged again until it may be running low.
+    assert_eq!(
+        client.try_keeper_bump(&keeper, &alice, &0),
+        Err(Ok(Error::RentNotDue))
+    );
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TOKEN_TTL_EXTEND_TO);
+    client.keeper_bump(&keeper, &alice, &0);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let low = RentLowBalance {
+        owner: alice.clone(),
+        balance: 40,
+    };
+    assert_eq!(topics, low.topics(&env));
+    assert_eq!(data.to_xdr(&env), low.data(&env).to_xdr(&env));
+    assert_eq!(client.rent_balance(&alice), 40);
+
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TOKEN_TTL_EXTEND_TO);
+    client.withdraw_rent(&alice, &20);
+    assert_eq!(
+        client.try_keeper_bump(&keeper, &alice, &0),
+        Err(Ok(Error::InsufficientRentBalance))
+    );
+    assert_eq!(
+        client.try_withdraw_rent(&alice, &21),
+        Err(Ok(Error::InsufficientRentBalance))
+    );
+    assert_eq!(client.withdraw_rent(&alice, &20), 0);
+    assert_eq!(xlm.balance(&alice), 940);
+}
+
+#[test]
+fn test_token_ttl() {
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let ttl = |key: DataKey| {
+        env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key))
+    };
+
+    // Minting bumps the new token's entries.
+    assert_eq!(ttl(DataKey::Info(token_id)), TOKEN_TTL_EXTEND_TO);
+    assert_eq!(ttl(DataKey::Owner(token_id)), TOKEN_TTL_EXTEND_TO);
+
+    // Once a token runs low, reading or transferring it bumps it again.
+    let elapsed = TOKEN_TTL_EXTEND_TO - TOKEN_TTL_THRESHOLD + 1;
+    env.ledger().with_mut(|l| l.sequence_number += elapsed);
+    client.get_info(&token_id);
+    assert_eq!(ttl(DataKey::Info(token_id)), TOKEN_TTL_EXTEND_TO);
+    env.ledger().with_mut(|l| l.sequence_number += elapsed);
+    client.transfer(&alice, &bob, &token_id);
+    assert_eq!(ttl(DataKey::Owner(token_id)), TOKEN_TTL_EXTEND_TO);
+
+    // Anyone can keep a token alive for longer.
+    client.extend_token_ttl(&token_id, &(TOKEN_TTL_EXTEND_TO * 2));
+    assert_eq!(ttl(DataKey::Info(token_id)), TOKEN_TTL_EXTEND_TO * 2);
+    assert_eq!(
+        client.try_extend_token_ttl(&99, &TOKEN_TTL_EXTEND_TO),
+        Err(Ok(Error::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_property_retirement() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let redeemed = mint_one(&env, &client, &alice);
+    let frozen = mint_one(&env, &client, &bob);
+    let beach_resort = client.register_hotel(&admin, &String::from_str(&env, "Beach Resort"));
+    client.add_room(&admin, &beach_resort, &String::from_str(&env, "Room 1"));
+    let other = client.mint(
+        &alice,
+        &beach_resort,
+        &String::from_str(&env, "Room 1"),
+        &10,
+        &None,
+        &None,
+    );
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    soroban_sdk::token::StellarAssetClient::new(&env, &sac.address()).mint(&admin, &1_000);
+    let usdc = soroban_sdk::token::Client::new(&env, &sac.address());
+
+    let hotel = GRAND_HOTEL;
+    let window_end = env.ledger().timestamp() + 1_000;
+    client.retire_property(&hotel, &sac.address(), &300, &window_end, &500);
+    assert_eq!(usdc.balance(&client.address), 500);
+    assert_eq!(
+        client.try_retire_property(&hotel, &sac.address(), &300, &window_end, &0),
+        Err(Ok(Error::AlreadyRetired))
+    );
+
+    // The property's tokens are frozen in place and no new ones are minted.
+    assert_eq!(
+        client.try_transfer(&alice, &bob, &redeemed),
+        Err(Ok(Error::PropertyRetired))
+    );
+    assert_eq!(
+        client.try_mint(
+            &alice,
+            &hotel,
+            &String::from_str(&env, "Room 1"),
+            &1,
+            &None,
+            &None
+        ),
+        Err(Ok(Error::PropertyRetired))
+    );
+    client.transfer(&alice, &bob, &other);
+    assert_eq!(client.try_redeem(&bob, &other), Err(Ok(Error::NotRetired)));
+
+    // Owners redeem during the window, as long as the funding lasts.
+    client.redeem(&alice, &redeemed);
+    assert_eq!(usdc.balance(&alice), 300);
+    assert_eq!(
+        client.try_get_owner(&redeemed),
+        Err(Ok(Error::TokenNotFound))
+    );
+    assert_eq!(
+        client.try_redeem(&bob, &frozen),
+        Err(Ok(Error::RedemptionUnderfunded))
+    );
+    client.fund_retirement(&hotel, &100);
+
+    // After the window, the rest is swept and the funding left is refunded.
+    env.ledger().with_mut(|l| l.timestamp = window_end);
+    assert_eq!(
+        client.try_redeem(&bob, &frozen),
+        Err(Ok(Error::RedemptionWindowClosed))
+    );
+    assert_eq!(
+        client.try_sweep_property(&hotel, &vec![&env, other]),
+        Err(Ok(Error::WrongProperty))
+    );
+    let retirement = client.sweep_property(&hotel, &vec![&env, frozen]);
+    assert_eq!(retirement.redeemed, 1);
+    assert_eq!(retirement.swept, 1);
+    assert_eq!(retirement.funded, 600);
+    assert_eq!(retirement.paid, 600);
+    assert_eq!(usdc.balance(&admin), 700);
+    assert_eq!(usdc.balance(&client.address), 0);
+    assert_eq!(client.balance_of(&bob), 1);
+    assert_eq!(client.total_supply(), 1);
+}
+
+// TimeshareInfo as version 1 stored it, naming its hotel.
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+struct InfoV1 {
+    hotel: String,
+    room: String,
+    week: u32,
+}
+
+#[test]
+fn test_schema_migration() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    assert_eq!(client.schema_version(), SCHEMA_VERSION);
+    assert_eq!(client.config().schema_version, SCHEMA_VERSION);
+
+    // Recreate a version 1 deployment: two tokens, their data, the ID counter
+    // and settings in instance storage.
+    let legacy = |room: &str| InfoV1 {
+        hotel: String::from_str(&env, "Grand Hotel"),
+        room: String::from_str(&env, room),
+        week: 28,
+    };
+    let deprecations: soroban_sdk::Map<Symbol, Deprecation> = soroban_sdk::Map::new(&env);
+    env.as_contract(&client.address, || {
+        let instance = env.storage().instance();
+        instance.remove(&migration::SCHEMA);
+        env.storage().persistent().remove(&COUNTER);
+        instance.set(&COUNTER, &2u64);
+        instance.set(&DataKey::Info(1), &legacy("Room 305"));
+        instance.set(&DataKey::Owner(1), &alice);
+        instance.set(&DataKey::Info(2), &legacy("Room 306"));
+        instance.set(&DataKey::Owner(2), &alice);
+        instance.set(&DataKey::OwnedTokens(alice.clone()), &vec![&env, 1u64, 2]);
+        instance.set(&DataKey::Trust(1), &0u32);
+        instance.set(&DataKey::Deprecations, &deprecations);
+    });
+    assert_eq!(client.schema_version(), 1);
+    assert_eq!(client.total_supply(), 2);
+
+    // Token data is readable, and rewritten, on first use.
+    let info = TimeshareInfo {
+        hotel_id: GRAND_HOTEL,
+        room: String::from_str(&env, "Room 305"),
+        week: 28,
+        year: None,
+        lease_end_ledger: None,
+    };
+    assert_eq!(client.get_info(&1), info);
+    client.transfer(&alice, &bob, &1);
+    assert_eq!(client.tokens_of_owner(&bob), vec![&env, 1]);
+    assert_eq!(client.balance_of(&alice), 1);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&DataKey::Info(1)));
+        assert!(env.storage().persistent().has(&DataKey::Owner(1)));
+    });
+
+    // Legacy tokens can be burned before the migration.
+    client.burn(&alice, &2);
+    assert_eq!(client.total_supply(), 1);
+
+    // migrate() moves the contract-wide entries; the rest is moved on request.
+    assert_eq!(client.migrate(), SCHEMA_VERSION);
+    assert_eq!(client.migrate(), SCHEMA_VERSION);
+    assert_eq!(
+        client.migrate_entries(&vec![&env, DataKey::Trust(1), DataKey::Trust(2)]),
+        1
+    );
+    env.as_contract(&client.address, || {
+        let instance = env.storage().instance();
+        assert!(!instance.has(&DataKey::Deprecations));
+        assert!(!instance.has(&DataKey::Trust(1)));
+        assert!(!instance.has(&COUNTER));
+        assert!(env.storage().persistent().has(&DataKey::Deprecations));
+        assert!(instance.has(&ADMIN));
+    });
+    assert_eq!(client.schema_version(), SCHEMA_VERSION);
+
+    // The legacy tokens count as the admin's mints, and new IDs follow them.
+    assert_eq!(client.total_supply(), 1);
+    assert_eq!(mint_one(&env, &client, &alice), 3);
+    assert_eq!(client.total_supply(), 2);
+}
+
+#[test]
+fn test_admin_handover() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let new_admin = Address::generate(&env);
+
+    assert_eq!(client.try_accept_admin(), Err(Ok(Error::NoPendingAdmin)));
+    client.propose_admin(&new_admin);
+    assert_eq!(client.pending_admin(), Some(new_admin.clone()));
+    assert_eq!(client.config().admin, admin);
+
+    // Only the proposed admin can accept.
+    env.set_auths(&[]);
+    assert!(client.try_accept_admin().is_err());
+    env.mock_all_auths();
+    client.accept_admin();
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            new_admin.clone(),
+            soroban_sdk::testutils::AuthorizedInvocation {
+                function: soroban_sdk::testutils::AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "accept_admin"),
+                    vec![&env],
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+    let config = client.config();
+    assert_eq!(config.admin, new_admin);
+    assert_eq!(config.pending_admin, None);
+    assert_eq!(client.try_accept_admin(), Err(Ok(Error::NoPendingAdmin)));
+}
+
+#[test]
+fn test_roles() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let front_desk = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    assert!(!client.has_role(&Role::Minter, &front_desk));
+    assert_eq!(
+        client.try_mint_by(&front_desk, &alice, &unit(&env, 0)),
+        Err(Ok(Error::MissingRole))
+    );
+
+    client.grant_role(&Role::Minter, &front_desk);
+    assert!(client.has_role(&Role::Minter, &front_desk));
+    assert!(!client.has_role(&Role::Pauser, &front_desk));
+    let token_id = client.mint_by(&front_desk, &alice, &unit(&env, 0));
+    assert_eq!(client.get_owner(&token_id), alice);
+    assert_eq!(client.minter_state(&front_desk).unwrap().minted, 1);
+
+    // The admin holds every role implicitly.
+    client.mint_by(&admin, &alice, &unit(&env, 1));
+
+    client.revoke_role(&Role::Minter, &front_desk);
+    assert_eq!(
+        client.try_mint_by(&front_desk, &alice, &unit(&env, 2)),
+        Err(Ok(Error::MissingRole))
+    );
+    assert_eq!(client.total_supply(), 2);
+}
+
+#[test]
+fn test_pause() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let pauser = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+
+    assert_eq!(client.try_pause(&pauser), Err(Ok(Error::MissingRole)));
+    client.grant_role(&Role::Pauser, &pauser);
+    client.pause(&pauser);
+    assert!(client.paused());
+    assert!(client.config().paused);
+
+    // Mints and transfers stop; reads keep working.
+    assert_eq!(
+        client.try_mint(
+            &alice,
+            &GRAND_HOTEL,
+            &String::from_str(&env, "Room 305"),
+            &28,
+            &None,
+            &None
+        ),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_transfer(&alice, &bob, &token_id),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(client.get_owner(&token_id), alice);
+
+    client.unpause(&pauser);
+    assert!(!client.paused());
+    client.transfer(&alice, &bob, &token_id);
+    assert_eq!(client.get_owner(&token_id), bob);
+}
+
+#[test]
+fn test_upgrade() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let token_id = mint_one(&env, &client, &Address::generate(&env));
+
+    // The smallest module the host accepts: a header plus the environment
+    // interface version (protocol 23) in a "contractenvmetav0" custom section.
+    let mut wasm = std::vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+    let name = b"contractenvmetav0";
+    let meta = [0u8, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0];
+    wasm.push(0);
+    wasm.push((1 + name.len() + meta.len()) as u8);
+    wasm.push(name.len() as u8);
+    wasm.extend_from_slice(name);
+    wasm.extend_from_slice(&meta);
+    let hash = env
+        .deployer()
+        .upload_contract_wasm(Bytes::from_slice(&env, &wasm));
+
+    // Only the admin can upgrade.
+    env.set_auths(&[]);
+    assert!(client.try_upgrade(&hash).is_err());
+    env.mock_all_auths();
+
+    client.upgrade(&hash);
+    env.as_contract(&client.address, || {
+        let history = version::upgrade_history(&env);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.get_unchecked(0).wasm_hash, hash);
+        assert_eq!(history.get_unchecked(0).scheduler, admin);
+
+        // State survives the code swap.
+        assert!(env.storage().persistent().has(&DataKey::Info(token_id)));
+    });
+    assert_eq!(
+        client.address.executable(),
+        Some(soroban_sdk::Executable::Wasm(hash))
+    );
+}
+
+#[test]
+fn test_unique_slots() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let hotel = GRAND_HOTEL;
+    let room = String::from_str(&env, "Room 305");
+
+    // Weeks run from 1 to 52.
+    for week in [0, 53] {
+        assert_eq!(
+            client.try_mint(&alice, &hotel, &room, &week, &None, &None),
+            Err(Ok(Error::InvalidWeek))
+        );
+    }
+    assert_eq!(client.token_by_slot(&hotel, &room, &28, &None), None);
+
+    // A week of a room is sold once, in single and batch mints alike.
+    let token_id = mint_one(&env, &client, &alice);
+    assert_eq!(
+        client.token_by_slot(&hotel, &room, &28, &None),
+        Some(token_id)
+    );
+    assert_eq!(
+        client.try_mint(&alice, &hotel, &room, &28, &None, &None),
+        Err(Ok(Error::DuplicateTimeshare))
+    );
+    let mut infos = units(&env, &client, 1);
+    infos.push_back(infos.first().unwrap());
+    assert_eq!(
+        client.try_mint_batch(&alice, &infos),
+        Err(Ok(Error::DuplicateTimeshare))
+    );
+    let other_room = String::from_str(&env, "Room 306");
+    let other = client.mint(&alice, &hotel, &other_room, &28, &None, &None);
+    assert_eq!(
+        client.token_by_slot(&hotel, &other_room, &28, &None),
+        Some(other)
+    );
+
+    // Burning the token frees its week for a new sale.
+    client.burn(&alice, &token_id);
+    assert_eq!(client.token_by_slot(&hotel, &room, &28, &None), None);
+    let resold = client.mint(&alice, &hotel, &room, &28, &None, &None);
+    assert_eq!(
+        client.token_by_slot(&hotel, &room, &28, &None),
+        Some(resold)
+    );
+}
+
+#[test]
+fn test_lease_terms() {
+    use soroban_sdk::{IntoVal, Val};
+
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let hotel = GRAND_HOTEL;
+    let room = String::from_str(&env, "Room 305");
+    let end = env.ledger().sequence() + 100;
+
+    // A lease must name a real year and end in the future.
+    assert_eq!(
+        client.try_mint(&alice, &hotel, &room, &28, &Some(0), &None),
+        Err(Ok(Error::InvalidLeaseTerm))
+    );
+    assert_eq!(
+        client.try_mint(
+            &alice,
+            &hotel,
+            &room,
+            &28,
+            &Some(2027),
+            &Some(env.ledger().sequence())
+        ),
+        Err(Ok(Error::InvalidLeaseTerm))
+    );
+
+    // Each year of a week is a slot of its own, but a week sold for every
+    // year can't also be sold for one year, or the other way round.
+    let lease = client.mint(&alice, &hotel, &room, &28, &Some(2027), &Some(end));
+    let perpetual = client.mint(&alice, &hotel, &room, &29, &None, &None);
+    assert_eq!(
+        client.token_by_slot(&hotel, &room, &28, &Some(2027)),
+        Some(lease)
+    );
+    assert_eq!(
+        client.token_by_slot(&hotel, &room, &29, &None),
+        Some(perpetual)
+    );
+    assert_eq!(
+        client.try_mint(&bob, &hotel, &room, &28, &Some(2027), &None),
+        Err(Ok(Error::DuplicateTimeshare))
+    );
+    assert_eq!(
+        client.try_mint(&bob, &hotel, &room, &28, &None, &None),
+        Err(Ok(Error::DuplicateTimeshare))
+    );
+    assert_eq!(
+        client.try_mint(&bob, &hotel, &room, &29, &Some(2028), &None),
+        Err(Ok(Error::DuplicateTimeshare))
+    );
+    let next_year = client.mint(&bob, &hotel, &room, &28, &Some(2028), &None);
+    let info = client.get_info(&lease);
+    assert_eq!((info.year, info.lease_end_ledger), (Some(2027), Some(end)));
+    assert!(client.is_active(&lease));
+
+    // Once the lease ends the token is frozen but can still be read and burned.
+    env.ledger().with_mut(|li| li.sequence_number = end);
+    assert!(!client.is_active(&lease));
+    assert!(client.is_active(&perpetual));
+    assert_eq!(
+        client.try_transfer(&alice, &bob, &lease),
+        Err(Ok(Error::LeaseExpired))
+    );
+    client.transfer(&alice, &bob, &perpetual);
+    assert_eq!(client.get_info(&lease), info);
+    client.burn(&alice, &lease);
+    assert_eq!(client.token_by_slot(&hotel, &room, &28, &Some(2027)), None);
+
+    // The week can be sold perpetually again once no year of it is claimed.
+    assert_eq!(
+        client.try_mint(&bob, &hotel, &room, &28, &None, &None),
+        Err(Ok(Error::DuplicateTimeshare))
+    );
+    client.burn(&bob, &next_year);
+    client.mint(&bob, &hotel, &room, &28, &None, &None);
+
+    // Info stored before terms existed reads as perpetual.
+    let old: soroban_sdk::Map<Symbol, Val> = soroban_sdk::map![
+        &env,
+        (
+            Symbol::new(&env, "hotel"),
+            String::from_str(&env, "Grand Hotel").into_val(&env),
+        ),
+        (Symbol::new(&env, "room"), room.into_val(&env)),
+        (Symbol::new(&env, "week"), 28u32.into_val(&env)),
+    ];
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Info(perpetual), &old);
+    });
+    let info = client.get_info(&perpetual);
+    assert_eq!(
+        (info.week, info.year, info.lease_end_ledger),
+        (28, None, None)
+    );
+    assert!(client.is_active(&perpetual));
+}
+
+#[test]
+fn test_hotel_registry() {
+    use soroban_sdk::{IntoVal, Val};
+
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let manager = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let name = |name: &str| String::from_str(&env, name);
+
+    // Managers (and the admin) keep the registry.
+    assert_eq!(
+        client.try_register_hotel(&manager, &name("Beach Resort")),
+        Err(Ok(Error::MissingRole))
+    );
+    client.grant_role(&Role::Manager, &manager);
+    let beach = client.register_hotel(&manager, &name("Beach Resort"));
+    assert_eq!(beach, GRAND_HOTEL + 1);
+    assert_eq!(
+        client.try_register_hotel(&manager, &name("Grand Hotel")),
+        Err(Ok(Error::DuplicateHotel))
+    );
+
+    // Renaming frees the old name; tokens keep pointing at the ID.
+    client.add_room(&manager, &beach, &name("Room 1"));
+    let token_id = client.mint(&alice, &beach, &name("Room 1"), &10, &None, &None);
+    let renamed = client.update_hotel(&manager, &beach, &name("Beach Resort & Spa"));
+    assert_eq!(renamed.name, name("Beach Resort & Spa"));
+    assert_eq!(client.get_hotel(&beach), renamed);
+    assert_eq!(
+        client.try_update_hotel(&manager, &beach, &name("Grand Hotel")),
+        Err(Ok(Error::DuplicateHotel))
+    );
+    assert_eq!(client.get_info(&token_id).hotel_id, beach);
+    assert_eq!(
+        client.list_hotels(&0, &10),
+        vec![&env, client.get_hotel(&GRAND_HOTEL), renamed.clone()]
+    );
+    assert_eq!(client.list_hotels(&GRAND_HOTEL, &10), vec![&env, renamed]);
+
+    // Only registered hotels can be minted for.
+    assert_eq!(
+        client.try_mint(&alice, &(beach + 1), &name("Room 1"), &10, &None, &None),
+        Err(Ok(Error::HotelNotFound))
+    );
+    assert_eq!(
+        client.try_get_hotel(&(beach + 1)),
+        Err(Ok(Error::HotelNotFound))
+    );
+
+    // A token that names its hotel is linked to the registry when read.
+    let old: soroban_sdk::Map<Symbol, Val> = soroban_sdk::map![
+        &env,
+        (Symbol::new(&env, "hotel"), name("Old Inn").into_val(&env)),
+        (Symbol::new(&env, "room"), name("Room 7").into_val(&env)),
+        (Symbol::new(&env, "week"), 3u32.into_val(&env)),
+    ];
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Info(token_id), &old);
+    });
+    let info = client.get_info(&token_id);
+    assert_eq!(client.get_hotel(&info.hotel_id).name, name("Old Inn"));
+    assert_eq!((info.room, info.week), (name("Room 7"), 3));
+    assert_eq!(client.get_info(&token_id).hotel_id, info.hotel_id);
+}
+
+#[test]
+fn test_room_inventory() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let manager = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let room = |name: &str| String::from_str(&env, name);
+
+    // Only rooms in the hotel's inventory can be minted for.
+    assert_eq!(
+        client.try_mint(&alice, &GRAND_HOTEL, &room("Room 310"), &1, &None, &None),
+        Err(Ok(Error::RoomNotFound))
+    );
+    assert_eq!(
+        client.try_add_room(&manager, &GRAND_HOTEL, &room("Room 310")),
+        Err(Ok(Error::MissingRole))
+    );
+    client.grant_role(&Role::Manager, &manager);
+    client.add_room(&manager, &GRAND_HOTEL, &room("Room 310"));
+    let token_id = client.mint(&alice, &GRAND_HOTEL, &room("Room 310"), &1, &None, &None);
+    assert_eq!(
+        client.try_add_room(&manager, &GRAND_HOTEL, &room("Room 310")),
+        Err(Ok(Error::DuplicateRoom))
+    );
+    assert_eq!(
+        client.try_add_room(&admin, &(GRAND_HOTEL + 1), &room("Room 1")),
+        Err(Ok(Error::HotelNotFound))
+    );
+    assert_eq!(client.list_rooms(&GRAND_HOTEL).len(), 6);
+    assert_eq!(client.list_rooms(&(GRAND_HOTEL + 1)), Vec::new(&env));
+
+    // A retired room leaves the inventory for good; its tokens live on.
+    client.retire_room(&manager, &GRAND_HOTEL, &room("Room 310"));
+    assert!(!client.list_rooms(&GRAND_HOTEL).contains(room("Room 310")));
+    assert_eq!(
+        client.try_mint(&alice, &GRAND_HOTEL, &room("Room 310"), &2, &None, &None),
+        Err(Ok(Error::RoomRetired))
+    );
+    assert_eq!(
+        client.try_add_room(&manager, &GRAND_HOTEL, &room("Room 310")),
+        Err(Ok(Error::DuplicateRoom))
+    );
+    assert_eq!(
+        client.try_retire_room(&manager, &GRAND_HOTEL, &room("Room 310")),
+        Err(Ok(Error::RoomRetired))
+    );
+    client.transfer(&alice, &manager, &token_id);
+    assert_eq!(client.get_info(&token_id).room, room("Room 310"));
+}
+
+#[test]
+fn test_check_in() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let front_desk = Address::generate(&env);
+    client.grant_role(&Role::Manager, &front_desk);
+    let token_id = mint_one(&env, &client, &alice);
+    env.ledger().set_timestamp(1_783_641_600); // 2026-07-10
+    let usage = |state, confirmed| Usage {
+        year: 2026,
+        state,
+        confirmed,
+    };
+    assert_eq!(client.usage(&token_id), usage(UsageState::Unused, false));
+
+    // The owner checks in, the front desk confirms, then the owner checks out.
+    client.check_in(&alice, &token_id);
+    assert_eq!(client.usage(&token_id), usage(UsageState::CheckedIn, false));
+    assert_eq!(
+        client.try_check_out(&alice, &token_id),
+        Err(Ok(Error::CheckInNotConfirmed))
+    );
+    assert_eq!(
+        client.try_confirm_check_in(&alice, &token_id),
+        Err(Ok(Error::MissingRole))
+    );
+    client.confirm_check_in(&front_desk, &token_id);
+    assert_eq!(client.usage(&token_id), usage(UsageState::CheckedIn, true));
+    client.check_out(&alice, &token_id);
+    assert_eq!(client.usage(&token_id), usage(UsageState::Consumed, true));
+
+    // The week is used up until the year ends.
+    assert_eq!(
+        client.try_check_in(&alice, &token_id),
+        Err(Ok(Error::InvalidUsageState))
+    );
+    assert_eq!(
+        client.try_confirm_check_in(&front_desk, &token_id),
+        Err(Ok(Error::InvalidUsageState))
+    );
+    env.ledger().set_timestamp(1_798_761_600 - 1);
+    assert_eq!(client.usage(&token_id).state, UsageState::Consumed);
+    env.ledger().set_timestamp(1_798_761_600); // 2027-01-01
+    assert_eq!(
+        client.usage(&token_id),
+        Usage {
+            year: 2027,
+            state: UsageState::Unused,
+            confirmed: false,
+        }
+    );
+    assert_eq!(
+        client.try_check_in(&Address::generate(&env), &token_id),
+        Err(Ok(Error::NotOwner))
+    );
+    client.check_in(&alice, &token_id);
+
+    env.ledger().set_timestamp(951_782_400); // 2000-02-29
+    assert_eq!(client.usage(&token_id).year, 2000);
+}
+
+#[test]
+fn test_checkin_code() {
+    use ed25519_dalek::{Signer, SigningKey};
+    use timeshare_client::checkin;
+
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let kiosk = Address::generate(&env);
+    client.grant_role(&Role::Manager, &kiosk);
+    let token_id = mint_one(&env, &client, &alice);
+    env.ledger().set_timestamp(1_783_641_600); // 2026-07-10
+
+    let key = SigningKey::from_bytes(&[9; 32]);
+    let sign = |code: &CheckinCode| {
+        let message = client.checkin_code_message(code);
+        let message: std::vec::Vec<u8> = message.iter().collect();
+        BytesN::from_array(&env, &key.sign(&message).to_bytes())
+    };
+    let code = |year, nonce| CheckinCode {
+        token_id,
+        year,
+        nonce,
+        expires_at: 1_783_641_600 + 600,
+    };
+    let first = code(2026, 1);
+    assert_eq!(
+        client.try_verify_checkin_code(&kiosk, &first, &sign(&first)),
+        Err(Err(ExtError::NoCheckinKey.into()))
+    );
+
+    // Only the guest registers a key, and only the front desk scans codes.
+    let public_key = BytesN::from_array(&env, key.verifying_key().as_bytes());
+    assert_eq!(
+        client.try_set_checkin_key(&bob, &token_id, &public_key),
+        Err(Ok(Error::NotOwner))
+    );
+    client.set_checkin_key(&alice, &token_id, &public_key);
+    assert_eq!(
+        client.try_verify_checkin_code(&bob, &first, &sign(&first)),
+        Err(Ok(Error::MissingRole))
+    );
+    assert!(client
+        .try_verify_checkin_code(&kiosk, &code(2026, 2), &sign(&first))
+        .is_err());
+    let stale = CheckinCode {
+        expires_at: 1_783_641_600 - 1,
+        ..first.clone()
+    };
+    assert_eq!(
+        client.try_verify_checkin_code(&kiosk, &stale, &sign(&stale)),
+        Err(Err(ExtError::CheckinCodeExpired.into()))
+    );
+    let next_year = code(2027, 1);
+    assert_eq!(
+        client.try_verify_checkin_code(&kiosk, &next_year, &sign(&next_year)),
+        Err(Err(ExtError::CheckinCodeExpired.into()))
+    );
+
+    // A good code, as the guest's wallet shows it, checks the guest in
+    // confirmed, and can't be scanned again.
+    let shown = checkin::CheckinCode {
+        token_id,
+        year: 2026,
+        nonce: 1,
+        expires_at: 1_783_641_600 + 600,
+    };
+    let qr = checkin::encode(&shown, &checkin::sign(&env, &client.address, &shown, &key));
+    let (scanned, signature) = checkin::decode(&qr).unwrap();
+    assert_eq!(scanned, shown);
+    let signature = BytesN::from_array(&env, &signature);
+    assert_eq!(signature, sign(&first));
+    assert_eq!(client.verify_checkin_code(&kiosk, &first, &signature), alice);
+    let confirmed = CheckInConfirmed {
+        token_id,
+        by: kiosk.clone(),
+    };
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(topics, confirmed.topics(&env));
+    assert_eq!(
+        client.usage(&token_id),
+        Usage {
+            year: 2026,
+            state: UsageState::CheckedIn,
+            confirmed: true,
+        }
+    );
+    assert_eq!(client.checkin_key(&token_id).unwrap().nonce, 1);
+    assert_eq!(
+        client.try_verify_checkin_code(&kiosk, &first, &sign(&first)),
+        Err(Err(ExtError::CheckinCodeUsed.into()))
+    );
+    client.check_out(&alice, &token_id);
+
+    // Nonces stay used across years; a fresh one works next year.
+    env.ledger().set_timestamp(1_798_761_600); // 2027-01-01
+    let next_year = CheckinCode {
+        expires_at: 1_798_761_600 + 600,
+        ..code(2027, 1)
+    };
+    assert_eq!(
+        client.try_verify_checkin_code(&kiosk, &next_year, &sign(&next_year)),
+        Err(Err(ExtError::CheckinCodeUsed.into()))
+    );
+    let next_year = CheckinCode {
+        nonce: 2,
+        ..next_year
+    };
+    client.verify_checkin_code(&kiosk, &next_year, &sign(&next_year));
+
+    // A key stops working once its guest no longer holds the token.
+    let later = CheckinCode {
+        nonce: 3,
+        ..next_year
+    };
+    client.transfer(&alice, &bob, &token_id);
+    assert_eq!(
+        client.try_verify_checkin_code(&kiosk, &later, &sign(&later)),
+        Err(Ok(Error::NotOwner))
+    );
+    client.burn(&bob, &token_id);
+    assert_eq!(client.checkin_key(&token_id), None);
+}
+
+#[test]
+fn test_swap() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let week_28 = mint_one(&env, &client, &alice);
+    let week_29 = mint_one(&env, &client, &bob);
+
+    client.swap(&alice, &week_28, &bob, &week_29);
+    let swap = Swap {
+        token_a: week_28,
+        token_b: week_29,
+        owner_a: alice.clone(),
+        owner_b: bob.clone(),
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, swap.topics(&env));
+    assert_eq!(data.to_xdr(&env), swap.data(&env).to_xdr(&env));
+    assert_eq!(client.get_owner(&week_28), bob);
+    assert_eq!(client.get_owner(&week_29), alice);
+
+    // Both sides must hold what they offer, and sign.
+    assert_eq!(
+        client.try_swap(&alice, &week_28, &bob, &week_29),
+        Err(Ok(Error::NotOwner))
+    );
+    assert_eq!(
+        client.try_swap(&alice, &week_29, &alice, &week_29),
+        Err(Ok(Error::InvalidSwap))
+    );
+    env.set_auths(&[]);
+    assert!(client.try_swap(&alice, &week_29, &bob, &week_28).is_err());
+    env.mock_all_auths();
+
+    // If either side can't move, neither does.
+    let end = env.ledger().sequence() + 10;
+    let lease = client.mint(
+        &bob,
+        &GRAND_HOTEL,
+        &String::from_str(&env, "Room 306"),
+        &28,
+        &Some(2027),
+        &Some(end),
+    );
+    env.ledger().with_mut(|li| li.sequence_number = end);
+    assert_eq!(
+        client.try_swap(&alice, &week_29, &bob, &lease),
+        Err(Ok(Error::LeaseExpired))
+    );
+    assert_eq!(client.get_owner(&week_29), alice);
+}
+
+#[test]
+fn test_rental() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    soroban_sdk::token::StellarAssetClient::new(&env, &sac.address()).mint(&bob, &1_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &sac.address());
+    env.ledger().set_timestamp(1_783_641_600); // 2026-07-10
+    let window = 1_783_641_600 + 86_400;
+
+    assert_eq!(client.try_rent(&bob, &token_id), Err(Ok(Error::NotListed)));
+    assert_eq!(
+        client.try_list_for_rent(&bob, &token_id, &sac.address(), &300, &window),
+        Err(Ok(Error::NotOwner))
+    );
+    client.list_for_rent(&alice, &token_id, &sac.address(), &300, &window);
+    let rental = client.rent(&bob, &token_id);
+    assert_eq!(rental.renter, Some(bob.clone()));
+    assert_eq!(rental.year, 2026);
+    assert_eq!(xlm.balance(&alice), 300);
+    assert_eq!(xlm.balance(&bob), 700);
+    assert_eq!(client.get_owner(&token_id), alice);
+
+    // The renter checks in this year, not the owner, and the week can't be
+    // rented twice.
+    assert_eq!(
+        client.try_check_in(&alice, &token_id),
+        Err(Ok(Error::NotOwner))
+    );
+    client.check_in(&bob, &token_id);
+    assert_eq!(
+        client.try_rent(&carol, &token_id),
+        Err(Ok(Error::ListingClosed))
+    );
+    assert_eq!(
+        client.try_cancel_rental(&alice, &token_id),
+        Err(Ok(Error::ListingClosed))
+    );
+
+    // A sold token keeps its renter until the year ends.
+    client.transfer(&alice, &carol, &token_id);
+    assert_eq!(client.rental(&token_id).unwrap().renter, Some(bob.clone()));
+
+    // Nobody can burn a week its renter hasn't stayed in yet.
+    assert_eq!(
+        client.try_burn(&carol, &token_id),
+        Err(Err(ExtError::RentalActive.into()))
+    );
+    assert_eq!(
+        client.try_admin_burn(&token_id),
+        Err(Err(ExtError::RentalActive.into()))
+    );
+    env.ledger().set_timestamp(1_798_761_600); // 2027-01-01
+    assert_eq!(client.rental(&token_id), None);
+    assert_eq!(
+        client.try_check_in(&bob, &token_id),
+        Err(Ok(Error::NotOwner))
+    );
+
+    // Listings can be cancelled, lapse on transfer, and close with their window.
+    let window = 1_798_761_600 + 86_400;
+    client.list_for_rent(&carol, &token_id, &sac.address(), &300, &window);
+    client.cancel_rental(&carol, &token_id);
+    assert_eq!(client.try_rent(&bob, &token_id), Err(Ok(Error::NotListed)));
+    client.list_for_rent(&carol, &token_id, &sac.address(), &300, &window);
+    client.transfer(&carol, &alice, &token_id);
+    assert_eq!(client.rental(&token_id), None);
+    client.list_for_rent(&alice, &token_id, &sac.address(), &300, &window);
+    env.ledger().set_timestamp(window + 1);
+    assert_eq!(
+        client.try_rent(&bob, &token_id),
+        Err(Ok(Error::ListingClosed))
+    );
+    assert_eq!(xlm.balance(&bob), 700);
+
+    // Once the renter has stayed, the token can be burned.
+    let second = mint_one(&env, &client, &alice);
+    client.list_for_rent(&alice, &second, &sac.address(), &300, &(window + 86_400));
+    client.rent(&bob, &second);
+    client.check_in(&bob, &second);
+    client.confirm_check_in(&admin, &second);
+    client.check_out(&bob, &second);
+    client.burn(&alice, &second);
+}
+
+#[test]
+fn test_maintenance_fees() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let collector = Address::generate(&env);
+    let first = mint_one(&env, &client, &alice);
+    let second = mint_one(&env, &client, &bob);
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    let sac_admin = soroban_sdk::token::StellarAssetClient::new(&env, &sac.address());
+    sac_admin.mint(&alice, &1_000);
+    sac_admin.mint(&bob, &1_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &sac.address());
+    env.ledger().set_timestamp(1_783_641_600); // 2026-07-10
+
+    // Nothing is owed before the admin bills a fee.
+    assert!(!client.maintenance_status(&first).delinquent);
+    assert_eq!(client.delinquent_tokens(&0, &10).token_ids.len(), 0);
+    assert_eq!(
+        client.try_pay_maintenance(&alice, &first, &sac.address()),
+        Err(Ok(Error::InvalidAmount))
+    );
+    let schedule = MaintenanceSchedule {
+        token: sac.address(),
+        fee: 250,
+        collector: collector.clone(),
+    };
+    assert_eq!(
+        client.try_set_maintenance_schedule(&MaintenanceSchedule {
+            fee: -1,
+            ..schedule.clone()
+        }),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_maintenance_schedule(&schedule);
+    assert_eq!(client.maintenance_schedule(), Some(schedule));
+    assert_eq!(
+        client.delinquent_tokens(&0, &10).token_ids,
+        vec![&env, first, second]
+    );
+
+    // Alice pays for her token, once a year.
+    assert_eq!(
+        client.try_pay_maintenance(&bob, &first, &sac.address()),
+        Err(Ok(Error::NotOwner))
+    );
+    assert_eq!(
+        client.try_pay_maintenance(&alice, &first, &Address::generate(&env)),
+        Err(Ok(Error::InvalidAmount))
+    );
+    let paid = MaintenanceStatus {
+        year: 2026,
+        paid_through: 2026,
+        unpaid_years: 0,
+        paid: true,
+        delinquent: false,
+    };
+    assert_eq!(client.pay_maintenance(&alice, &first, &sac.address()), paid);
+    let event = MaintenancePaid {
+        token_id: first,
+        owner: alice.clone(),
+        year: 2026,
+        years: 1,
+        amount: 250,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, event.topics(&env));
+    assert_eq!(data.to_xdr(&env), event.data(&env).to_xdr(&env));
+    assert_eq!(client.pay_maintenance(&alice, &first, &sac.address()), paid);
+    assert_eq!(xlm.balance(&collector), 250);
+    assert_eq!(client.maintenance_status(&first), paid);
+    assert_eq!(
+        client.delinquent_tokens(&0, &10).token_ids,
+        vec![&env, second]
+    );
+
+    // The payment stays with the token, and is due again next year.
+    client.transfer(&alice, &bob, &first);
+    assert_eq!(client.maintenance_status(&first), paid);
+    env.ledger().set_timestamp(1_798_761_600); // 2027-01-01
+    assert_eq!(
+        client.maintenance_status(&first),
+        MaintenanceStatus {
+            year: 2027,
+            paid_through: 2026,
+            unpaid_years: 1,
+            paid: false,
+            delinquent: true,
+        }
+    );
+    assert_eq!(
+        client.delinquent_tokens(&0, &1),
+        TokenPage {
+            token_ids: vec![&env, first],
+            next_start_after: Some(first),
+        }
+    );
+
+    // Unpaid years stay owed, and are settled together with their fee each.
+    assert_eq!(
+        client.maintenance_status(&second),
+        MaintenanceStatus {
+            year: 2027,
+            paid_through: 2025,
+            unpaid_years: 2,
+            paid: false,
+            delinquent: true,
+        }
+    );
+    client.pay_maintenance(&bob, &second, &sac.address());
+    let event = MaintenancePaid {
+        token_id: second,
+        owner: bob.clone(),
+        year: 2027,
+        years: 2,
+        amount: 500,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, event.topics(&env));
+    assert_eq!(data.to_xdr(&env), event.data(&env).to_xdr(&env));
+    assert_eq!(xlm.balance(&collector), 750);
+    assert!(client.maintenance_status(&second).paid);
+
+    // A token minted now owes from this year only.
+    let third = mint_one(&env, &client, &alice);
+    assert_eq!(client.maintenance_status(&third).unpaid_years, 1);
+    assert_eq!(
+        client.config().maintenance,
+        MaintenanceBilling::Enabled(client.maintenance_schedule().unwrap())
+    );
+}
+
+#[test]
+fn test_prepaid_maintenance() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let collector = Address::generate(&env);
+    let first = mint_one(&env, &client, &alice);
+    let second = mint_one(&env, &client, &bob);
+    let payment = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let sac = soroban_sdk::token::StellarAssetClient::new(&env, &payment);
+    sac.mint(&alice, &5_000);
+    sac.mint(&bob, &5_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+    env.ledger().set_timestamp(1_783_641_600); // 2026-07-10
+
+    assert_eq!(
+        client.try_fee_schedule(&first, &1),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_maintenance_schedule(&MaintenanceSchedule {
+        token: payment.clone(),
+        fee: 1_000,
+        collector: collector.clone(),
+    });
+    assert_eq!(
+        client.try_set_prepay_discounts(&vec![&env, 0, 10_001]),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_prepay_discounts(&vec![&env, 0, 500, 1_000]);
+
+    // Terms past the end of the curve get its last discount.
+    let quote = |year, fee| FeeQuote { year, fee };
+    assert_eq!(
+        client.fee_schedule(&first, &1),
+        vec![&env, quote(2026, 1_000)]
+    );
+    assert_eq!(
+        client.fee_schedule(&first, &4),
+        vec![
+            &env,
+            quote(2026, 900),
+            quote(2027, 900),
+            quote(2028, 900),
+            quote(2029, 900)
+        ]
+    );
+    for years in [0, MAX_PREPAY_YEARS + 1] {
+        assert_eq!(
+            client.try_fee_schedule(&first, &years),
+            Err(Ok(Error::InvalidAmount))
+        );
+    }
+
+    // The current year is paid at once; later years are held per year.
+    client.prepay_maintenance(&alice, &first, &payment, &3);
+    assert_eq!(xlm.balance(&alice), 2_300);
+    assert_eq!(xlm.balance(&collector), 900);
+    assert_eq!(xlm.balance(&client.address), 1_800);
+    assert_eq!(
+        client.prepaid_years(&first),
+        Some(PrepaidYears {
+            first: 2027,
+            last: 2028
+        })
+    );
+    assert_eq!(client.prepayment(&first, &2027).unwrap().amount, 900);
+    assert!(client.maintenance_status(&first).paid);
+    // Each year held is one more entry to restore and bump.
+    assert_eq!(
+        client.restore_hint(&first).len(),
+        client.restore_hint(&second).len() + 2
+    );
+    assert_eq!(
+        client.fee_schedule(&first, &1),
+        vec![&env, quote(2029, 1_000)]
+    );
+
+    // A prepaid year counts as paid once it begins, and its fee is released.
+    env.ledger().set_timestamp(1_815_177_600); // 2027-07-10
+    assert!(client.maintenance_status(&first).paid);
+    assert_eq!(
+        client.delinquent_tokens(&0, &10).token_ids,
+        vec![&env, second]
+    );
+    client.release_prepaid(&first);
+    assert_eq!(xlm.balance(&collector), 1_800);
+    client.pay_maintenance(&alice, &first, &payment);
+    assert_eq!(xlm.balance(&alice), 2_300);
+
+    // Years that haven't begun are refunded on burn and clawback.
+    client.burn(&alice, &first);
+    assert_eq!(xlm.balance(&alice), 3_200);
+    assert_eq!(client.prepayment(&first, &2028), None);
+
+    // Owed years are paid in full before any prepayment.
+    assert_eq!(
+        client.try_prepay_maintenance(&bob, &second, &payment, &2),
+        Err(Err(ExtError::MaintenanceArrears.into()))
+    );
+    client.pay_maintenance(&bob, &second, &payment);
+    assert_eq!(
+        client.prepay_maintenance(&bob, &second, &payment, &2),
+        vec![&env, quote(2028, 950), quote(2029, 950)]
+    );
+    assert_eq!(xlm.balance(&bob), 1_100);
+    client.clawback(&second, &Some(admin.clone()), &1);
+    assert_eq!(xlm.balance(&bob), 3_000);
+    assert_eq!(client.prepaid_years(&second), None);
+    assert_eq!(xlm.balance(&client.address), 0);
+}
+
+#[test]
+fn test_late_fees() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let collector = Address::generate(&env);
+    let first = mint_one(&env, &client, &alice);
+    let second = mint_one(&env, &client, &bob);
+    let payment = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &payment).mint(&alice, &5_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+    env.ledger().set_timestamp(1_767_225_600); // 2026-01-01
+    client.set_maintenance_schedule(&MaintenanceSchedule {
+        token: payment.clone(),
+        fee: 1_000,
+        collector: collector.clone(),
+    });
+    const DAY: u64 = 86_400;
+    let policy = LateFeePolicy {
+        flat: 50,
+        rate_bps: 100,
+        period: 30 * DAY,
+        max: 200,
+        thresholds: vec![&env, 30 * DAY, 90 * DAY, 180 * DAY],
+    };
+    for invalid in [
+        LateFeePolicy {
+            period: 0,
+            ..policy.clone()
+        },
+        LateFeePolicy {
+            thresholds: vec![&env, 90 * DAY, 30 * DAY],
+            ..policy.clone()
+        },
+    ] {
+        assert_eq!(
+            client.try_set_late_fee_policy(&Some(invalid)),
+            Err(Ok(Error::InvalidAmount))
+        );
+    }
+    client.set_late_fee_policy(&Some(policy.clone()));
+
+    // Nothing accrues during the grace period.
+    env.ledger().set_timestamp(1_767_225_600 + 30 * DAY); // 2026-01-31
+    assert_eq!(client.late_fee(&first), 0);
+    assert_eq!(client.check_delinquency(&first), 0);
+
+    // 160 days overdue: the flat fee plus five periods of 1% interest.
+    env.ledger().set_timestamp(1_783_641_600); // 2026-07-10
+    assert_eq!(client.late_fee(&first), 100);
+    assert_eq!(client.check_delinquency(&first), 2);
+    let escalated = DelinquencyEscalated {
+        token_id: first,
+        year: 2026,
+        level: 2,
+        overdue: 160 * DAY,
+        late_fee: 100,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, escalated.topics(&env));
+    assert_eq!(data.to_xdr(&env), escalated.data(&env).to_xdr(&env));
+    assert_eq!(
+        client.delinquency(&first),
+        Some(Delinquency {
+            year: 2026,
+            level: 2
+        })
+    );
+    client.check_delinquency(&first);
+    assert_eq!(env.events().all().len(), 0);
+
+    // The late fee is capped, and paid with the fee.
+    client.set_late_fee_policy(&Some(LateFeePolicy { max: 80, ..policy }));
+    assert_eq!(client.late_fee(&second), 80);
+    assert!(client.pay_maintenance(&alice, &first, &payment).paid);
+    assert_eq!(xlm.balance(&collector), 1_080);
+    assert_eq!(client.late_fee(&first), 0);
+    assert_eq!(client.delinquency(&first), None);
+    assert_eq!(client.check_delinquency(&first), 0);
+
+    client.set_late_fee_policy(&None);
+    assert_eq!(client.late_fee(&second), 0);
+}
+
+#[test]
+fn test_reserve_fund() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let collector = Address::generate(&env);
+    let roofer = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    let asset = soroban_sdk::token::StellarAssetClient::new(&env, &payment);
+    asset.mint(&alice, &1_000);
+    asset.mint(&bob, &1_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+    client.set_maintenance_schedule(&MaintenanceSchedule {
+        token: payment.clone(),
+        fee: 1_000,
+        collector: collector.clone(),
+    });
+
+    // A share of each maintenance fee goes to the hotel's reserve.
+    assert_eq!(
+        client.try_set_reserve_share(&admin, &GRAND_HOTEL, &10_001),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_reserve_share(&bob, &GRAND_HOTEL, &2_000),
+        Err(Ok(Error::MissingRole))
+    );
+    client.set_reserve_share(&admin, &GRAND_HOTEL, &2_000);
+    assert_eq!(
+        client.config().reserve_shares,
+        soroban_sdk::map![&env, (GRAND_HOTEL, 2_000)]
+    );
+    client.pay_maintenance(&alice, &token_id, &payment);
+    assert_eq!(xlm.balance(&collector), 800);
+    assert_eq!(xlm.balance(&client.address), 200);
+
+    // Assessments top it up, in the reserve's token only.
+    client.fund_reserve(&bob, &GRAND_HOTEL, &payment, &300);
+    let other = env.register_stellar_asset_contract_v2(admin.clone());
+    assert_eq!(
+        client.try_fund_reserve(&bob, &GRAND_HOTEL, &other.address(), &300),
+        Err(Ok(Error::InvalidAmount))
+    );
+    let report = client.reserve_report(&GRAND_HOTEL);
+    assert_eq!(
+        (
+            report.share_bps,
+            report.token,
+            report.balance,
+            report.funded
+        ),
+        (2_000, Some(payment.clone()), 500, 500)
+    );
+
+    // Spending takes a proposal, which commits part of the reserve and can
+    // be executed once the delay has passed.
+    let memo = String::from_str(&env, "Roof repair");
+    assert_eq!(
+        client.try_propose_reserve_spend(&admin, &GRAND_HOTEL, &roofer, &600, &memo),
+        Err(Err(ExtError::InsufficientReserve.into()))
+    );
+    let roof = client.propose_reserve_spend(&admin, &GRAND_HOTEL, &roofer, &400, &memo);
+    assert_eq!(roof, 1);
+    assert_eq!(
+        client.try_propose_reserve_spend(&admin, &GRAND_HOTEL, &roofer, &200, &memo),
+        Err(Err(ExtError::InsufficientReserve.into()))
+    );
+    assert_eq!(
+        client.try_execute_reserve_spend(&GRAND_HOTEL, &roof),
+        Err(Ok(Error::TimelockNotElapsed))
+    );
+    env.ledger()
+        .with_mut(|l| l.timestamp += RESERVE_SPEND_DELAY);
+    client.execute_reserve_spend(&GRAND_HOTEL, &roof);
+    let spent = ReserveSpent {
+        hotel_id: GRAND_HOTEL,
+        proposal_id: roof,
+        recipient: roofer.clone(),
+        amount: 400,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, spent.topics(&env));
+    assert_eq!(data.to_xdr(&env), spent.data(&env).to_xdr(&env));
+    assert_eq!(xlm.balance(&roofer), 400);
+    assert_eq!(
+        client.try_execute_reserve_spend(&GRAND_HOTEL, &roof),
+        Err(Err(ExtError::NoSpendProposal.into()))
+    );
+
+    // A cancelled proposal releases what it committed.
+    let paint = client.propose_reserve_spend(&admin, &GRAND_HOTEL, &roofer, &100, &memo);
+    assert_eq!(client.reserve_report(&GRAND_HOTEL).committed, 100);
+    client.cancel_reserve_spend(&admin, &GRAND_HOTEL, &paint);
+    assert_eq!(
+        client.reserve_spend(&GRAND_HOTEL, &paint).unwrap().state,
+        SpendState::Cancelled
+    );
+    let report = client.reserve_report(&GRAND_HOTEL);
+    assert_eq!(
+        (
+            report.balance,
+            report.funded,
+            report.spent,
+            report.committed
+        ),
+        (100, 500, 400, 0)
+    );
+    assert_eq!(report.proposals, 2);
+    assert_eq!(xlm.balance(&client.address), 100);
+    // A reserve share too large to compute fails the payment.
+    client.set_maintenance_schedule(&MaintenanceSchedule {
+        token: payment.clone(),
+        fee: i128::MAX,
+        collector,
+    });
+    env.ledger().with_mut(|l| l.timestamp += 366 * 24 * 60 * 60);
+    assert_eq!(
+        client.try_pay_maintenance(&alice, &token_id, &payment),
+        Err(Err(ExtError::AmountOverflow.into()))
+    );
+}
+
+#[test]
+fn test_freeze() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let other = mint_one(&env, &client, &bob);
+    let sac = env.register_stellar_asset_contract_v2(admin);
+
+    assert!(!client.is_frozen(&token_id));
+    client.freeze(&token_id);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(topics, TokenFrozen { token_id }.topics(&env));
+    assert!(client.is_frozen(&token_id));
+
+    // A frozen token can't move, be listed for rent or be burned by its owner.
+    assert_eq!(
+        client.try_transfer(&alice, &bob, &token_id),
+        Err(Err(ExtError::TokenFrozen.into()))
+    );
+    client.approve(&alice, &bob, &token_id);
+    assert_eq!(
+        client.try_transfer_from(&bob, &alice, &bob, &token_id),
+        Err(Err(ExtError::TokenFrozen.into()))
+    );
+    assert_eq!(
+        client.try_swap(&alice, &token_id, &bob, &other),
+        Err(Err(ExtError::TokenFrozen.into()))
+    );
+    assert_eq!(
+        client.try_list_for_rent(&alice, &token_id, &sac.address(), &100, &u64::MAX),
+        Err(Err(ExtError::TokenFrozen.into()))
+    );
+    assert_eq!(
+        client.try_burn(&alice, &token_id),
+        Err(Err(ExtError::TokenFrozen.into()))
+    );
+    // Other tokens are unaffected.
+    client.transfer(&bob, &alice, &other);
+
+    client.unfreeze(&token_id);
+    assert!(!client.is_frozen(&token_id));
+    client.transfer(&alice, &bob, &token_id);
+    assert_eq!(client.get_owner(&token_id), bob);
+
+    assert_eq!(client.try_freeze(&999), Err(Ok(Error::TokenNotFound)));
+    env.set_auths(&[]);
+    assert!(client.try_freeze(&token_id).is_err());
+}
+
+#[test]
+fn test_clawback() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let hotel = Address::generate(&env);
+    let reassigned = mint_one(&env, &client, &alice);
+    let burned = mint_one(&env, &client, &alice);
+    client.freeze(&reassigned);
+    client.place_in_trust(&alice, &burned, &bob, &carol);
+
+    // A frozen token goes to the hotel, and comes back unfrozen.
+    client.clawback(&reassigned, &Some(hotel.clone()), &7);
+    let clawback = Clawback {
+        token_id: reassigned,
+        from: alice.clone(),
+        to: Some(hotel.clone()),
+        reason_code: 7,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, clawback.topics(&env));
+    assert_eq!(data.to_xdr(&env), clawback.data(&env).to_xdr(&env));
+    assert_eq!(client.get_owner(&reassigned), hotel);
+    assert!(!client.is_frozen(&reassigned));
+    assert_eq!(client.tokens_of_owner(&hotel), vec![&env, reassigned]);
+    assert_eq!(
+        client.clawback_record(&reassigned),
+        Some(ClawbackRecord {
+            from: alice.clone(),
+            to: Some(hotel.clone()),
+            reason_code: 7,
+            ledger: env.ledger().sequence(),
+        })
+    );
+
+    // A token in trust is burned; the record stays.
+    let trustee = client.get_owner(&burned);
+    client.clawback(&burned, &None, &9);
+    assert_eq!(client.try_get_owner(&burned), Err(Ok(Error::TokenNotFound)));
+    assert_eq!(client.clawback_record(&burned).unwrap().from, trustee);
+    assert_eq!(client.clawback_record(&burned).unwrap().to, None);
+    assert_eq!(client.clawback_record(&burned).unwrap().reason_code, 9);
+    assert_eq!(client.total_supply(), 1);
+
+    assert_eq!(
+        client.try_clawback(&burned, &None, &9),
+        Err(Ok(Error::TokenNotFound))
+    );
+    client.pause(&admin);
+    assert_eq!(
+        client.try_clawback(&reassigned, &None, &9),
+        Err(Ok(Error::ContractPaused))
+    );
+    client.unpause(&admin);
+    env.set_auths(&[]);
+    assert!(client.try_clawback(&reassigned, &None, &9).is_err());
+}
+
+#[test]
+fn test_clawback_closes_custody() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let wanted = mint_one(&env, &client, &carol);
+
+    // A token escrowed for a trade is clawed back to Bob, who fractionalizes
+    // it: Alice's old trade is gone and can't release it.
+    client.open_trade(&alice, &token_id, &carol, &wanted);
+    client.clawback(&token_id, &Some(bob.clone()), &1);
+    assert_eq!(client.trade(&token_id), None);
+    client.fractionalize(&bob, &token_id, &10);
+    assert_eq!(
+        client.try_withdraw_trade(&alice, &token_id),
+        Err(Err(ExtError::NoTrade.into()))
+    );
+    assert_eq!(client.get_owner(&token_id), client.address);
+
+    // Clawing it back again drops Bob's shares, and escrowing it for a trade
+    // doesn't revive them.
+    client.transfer_share(&bob, &carol, &token_id, &4);
+    client.clawback(&token_id, &Some(alice.clone()), &2);
+    assert_eq!(client.fraction(&token_id), None);
+    client.open_trade(&alice, &token_id, &carol, &wanted);
+    assert_eq!(
+        client.try_redeem_shares(&bob, &token_id),
+        Err(Err(ExtError::NotFractionalized.into()))
+    );
+
+    // Nor does fractionalizing it anew.
+    client.withdraw_trade(&alice, &token_id);
+    client.fractionalize(&alice, &token_id, &10);
+    assert_eq!(client.share_balance(&token_id, &bob), 0);
+    assert_eq!(client.share_balance(&token_id, &carol), 0);
+    assert_eq!(client.share_balance(&token_id, &alice), 10);
+}
+
+#[test]
+fn test_marketplace() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &payment).mint(&bob, &1_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+
+    assert_eq!(client.try_buy(&bob, &token_id), Err(Ok(Error::NotListed)));
+    assert_eq!(
+        client.try_list_for_sale(&bob, &token_id, &400, &payment),
+        Err(Ok(Error::NotOwner))
+    );
+    assert_eq!(
+        client.try_list_for_sale(&alice, &token_id, &-1, &payment),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.list_for_sale(&alice, &token_id, &400, &payment);
+    assert_eq!(
+        client.listing(&token_id),
+        Some(Listing {
+            seller: alice.clone(),
+            price: 400,
+            payment_token: payment.clone(),
+        })
+    );
+
+    // Payment and ownership change hands together.
+    client.buy(&bob, &token_id);
+    let sale = Sale {
+        token_id,
+        buyer: bob.clone(),
+        seller: alice.clone(),
+        price: 400,
+        payment_token: payment.clone(),
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, sale.topics(&env));
+    assert_eq!(data.to_xdr(&env), sale.data(&env).to_xdr(&env));
+    assert_eq!(client.get_owner(&token_id), bob);
+    assert_eq!(xlm.balance(&alice), 400);
+    assert_eq!(xlm.balance(&bob), 600);
+    assert_eq!(client.listing(&token_id), None);
+
+    // A buyer who can't pay gets nothing.
+    client.list_for_sale(&bob, &token_id, &400, &payment);
+    assert!(client.try_buy(&carol, &token_id).is_err());
+    assert_eq!(client.get_owner(&token_id), bob);
+
+    // Listings lapse on transfer, can be cancelled, and frozen tokens can't
+    // be listed or bought.
+    client.transfer(&bob, &alice, &token_id);
+    assert_eq!(client.listing(&token_id), None);
+    client.list_for_sale(&alice, &token_id, &400, &payment);
+    client.cancel_listing(&alice, &token_id);
+    assert_eq!(client.try_buy(&bob, &token_id), Err(Ok(Error::NotListed)));
+    assert_eq!(
+        client.try_cancel_listing(&alice, &token_id),
+        Err(Ok(Error::NotListed))
+    );
+    client.list_for_sale(&alice, &token_id, &400, &payment);
+    client.freeze(&token_id);
+    assert_eq!(
+        client.try_buy(&bob, &token_id),
+        Err(Err(ExtError::TokenFrozen.into()))
+    );
+    assert_eq!(
+        client.try_list_for_sale(&alice, &token_id, &400, &payment),
+        Err(Err(ExtError::TokenFrozen.into()))
+    );
+    assert_eq!(xlm.balance(&bob), 600);
+    client.unfreeze(&token_id);
+
+    // A listing left behind by a former owner (e.g. from before listings
+    // lapsed on every transfer) can't sell the token, and nothing is listed
+    // while paused.
+    env.as_contract(&client.address, || {
+        let stale = Listing {
+            seller: carol.clone(),
+            price: 1,
+            payment_token: payment.clone(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Listing(token_id), &stale);
+    });
+    assert_eq!(client.try_buy(&bob, &token_id), Err(Ok(Error::NotOwner)));
+    assert_eq!(client.get_owner(&token_id), alice);
+    assert_eq!(xlm.balance(&bob), 600);
+    client.pause(&admin);
+    assert_eq!(
+        client.try_list_for_sale(&alice, &token_id, &400, &payment),
+        Err(Ok(Error::ContractPaused))
+    );
+}
+
+#[test]
+fn test_bulk_listing() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let broker = Address::generate(&env);
+    let [one, two, three] = [0; 3].map(|_| mint_one(&env, &client, &alice));
+    let bobs = mint_one(&env, &client, &bob);
+    let payment = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let not_owner = Error::NotOwner as u32;
+
+    // Each item reports its own outcome; the rest of the batch goes ahead.
+    let items = vec![&env, (one, 100), (two, -1), (bobs, 50), (three, 300)];
+    assert_eq!(
+        client.list_many(&alice, &alice, &items, &payment),
+        vec![
+            &env,
+            BULK_OK,
+            Error::InvalidAmount as u32,
+            not_owner,
+            BULK_OK
+        ]
+    );
+    assert_eq!(client.listing(&one).unwrap().price, 100);
+    assert_eq!(client.listing(&two), None);
+    assert_eq!(client.listing(&bobs), None);
+
+    // Only the owner's operators may act for it, and listings stay in the
+    // owner's name.
+    let prices = vec![&env, (one, 150), (two, 200), (three, 350)];
+    assert_eq!(
+        client.try_reprice_many(&broker, &alice, &prices),
+        Err(Ok(Error::NotApproved))
+    );
+    client.set_approval_for_all(&alice, &broker, &true);
+    client.freeze(&three);
+    assert_eq!(
+        client.reprice_many(&broker, &alice, &prices),
+        vec![
+            &env,
+            BULK_OK,
+            Error::NotListed as u32,
+            ExtError::TokenFrozen as u32
+        ]
+    );
+    let listing = client.listing(&one).unwrap();
+    assert_eq!((listing.seller, listing.price), (alice.clone(), 150));
+    assert_eq!(client.listing(&three).unwrap().price, 300);
+
+    assert_eq!(
+        client.cancel_many(&broker, &alice, &vec![&env, one, two, bobs]),
+        vec![&env, BULK_OK, Error::NotListed as u32, not_owner]
+    );
+    assert_eq!(client.listing(&one), None);
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..=MAX_LIST_BATCH {
+        too_many.push_back(one);
+    }
+    assert_eq!(
+        client.try_cancel_many(&alice, &alice, &too_many),
+        Err(Ok(Error::BatchTooLarge))
+    );
+}
+
+#[test]
+fn test_receipts() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let payment = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let sac = soroban_sdk::token::StellarAssetClient::new(&env, &payment);
+    sac.mint(&alice, &1_000);
+    sac.mint(&bob, &1_000);
+
+    // Off by default: settlements leave no receipt.
+    assert!(!client.receipts_enabled());
+    client.list_for_sale(&alice, &token_id, &400, &payment);
+    client.buy(&bob, &token_id);
+    assert_eq!(client.receipt_count(&token_id), 0);
+
+    client.set_receipts(&true);
+    assert!(client.config().receipts);
+    env.ledger().set_timestamp(1_000);
+    client.list_for_sale(&bob, &token_id, &300, &payment);
+    client.buy(&alice, &token_id);
+    let issued = ReceiptIssued {
+        token_id,
+        number: 0,
+    };
+    let events = env.events().all();
+    assert!(events
+        .iter()
+        .any(|(_, topics, data)| topics == issued.topics(&env)
+            && data.to_xdr(&env) == issued.data(&env).to_xdr(&env)));
+    assert_eq!(client.receipt_count(&token_id), 1);
+    let receipt = Receipt {
+        token_id,
+        kind: ReceiptKind::Resale,
+        buyer: alice.clone(),
+        seller: bob.clone(),
+        price: 300,
+        payment_token: Some(payment.clone()),
+        traded_for: None,
+        timestamp: 1_000,
+    };
+    assert_eq!(client.receipt(&token_id, &0), Some(receipt.clone()));
+
+    // Offers are receipted too, numbered per token, and receipts outlive
+    // the token.
+    client.make_offer(&bob, &token_id, &payment, &200, &2_000);
+    client.accept_offer(&alice, &token_id, &bob);
+    assert_eq!(client.receipt_count(&token_id), 2);
+    assert_eq!(client.receipt(&token_id, &1).unwrap().buyer, bob);
+    client.burn(&bob, &token_id);
+    assert_eq!(client.receipt_count(&token_id), 2);
+    assert_eq!(client.receipt(&token_id, &2), None);
+
+    // Primary sales name the admin as seller.
+    let room = String::from_str(&env, "Room 305");
+    client.set_mint_price(&admin, &GRAND_HOTEL, &250, &payment);
+    let bought = client.buy_mint(&alice, &GRAND_HOTEL, &room, &10);
+    let primary = client.receipt(&bought, &0).unwrap();
+    assert_eq!(primary.kind, ReceiptKind::PrimarySale);
+    assert_eq!((primary.buyer, primary.seller), (alice.clone(), admin));
+    assert_eq!(primary.price, 250);
+
+    // A swap gives each token a receipt naming the one it was traded for.
+    let carol = Address::generate(&env);
+    let carols = mint_one(&env, &client, &carol);
+    client.swap(&alice, &bought, &carol, &carols);
+    let leg = client.receipt(&bought, &1).unwrap();
+    assert_eq!(leg.kind, ReceiptKind::Trade);
+    assert_eq!((leg.buyer, leg.seller), (carol.clone(), alice.clone()));
+    assert_eq!((leg.price, leg.payment_token), (0, None));
+    assert_eq!(leg.traded_for, Some(carols));
+    assert_eq!(client.receipt(&carols, &0).unwrap().traded_for, Some(bought));
+
+    // Each party can page through the receipts naming them, oldest first.
+    assert_eq!(client.party_receipt_count(&alice), 5);
+    let alices = client.receipts_of(&alice, &0, &10);
+    assert_eq!(alices.len(), 5);
+    assert_eq!(alices.get(0), Some(receipt));
+    assert_eq!(alices.get(2).unwrap().kind, ReceiptKind::PrimarySale);
+    let page = client.receipts_of(&alice, &3, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(1).unwrap().token_id, carols);
+    assert_eq!(client.party_receipt_count(&carol), 2);
+    assert_eq!(client.receipts_of(&bob, &0, &10).len(), 2);
+
+    client.set_receipts(&false);
+    assert!(!client.receipts_enabled());
+    assert!(!client.config().receipts);
+    assert_eq!(client.receipt(&token_id, &0).unwrap().price, 300);
+}
+
+#[test]
+fn test_english_auction() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    let sac_admin = soroban_sdk::token::StellarAssetClient::new(&env, &payment);
+    sac_admin.mint(&bob, &1_000);
+    sac_admin.mint(&carol, &1_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+    env.ledger().set_timestamp(1_000);
+
+    assert_eq!(
+        client.try_start_auction(&bob, &token_id, &payment, &100, &3_600),
+        Err(Ok(Error::NotOwner))
+    );
+    client.pause(&admin);
+    assert_eq!(
+        client.try_start_auction(&alice, &token_id, &payment, &100, &3_600),
+        Err(Ok(Error::ContractPaused))
+    );
+    client.unpause(&admin);
+    client.start_auction(&alice, &token_id, &payment, &100, &3_600);
+    assert_eq!(client.auction(&token_id).unwrap().ends_at, 4_600);
+
+    // Bids must meet the reserve, then beat the high bid, which is refunded.
+    assert_eq!(
+        client.try_bid(&bob, &token_id, &99),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.bid(&bob, &token_id, &100);
+    assert_eq!(xlm.balance(&bob), 900);
+    assert_eq!(xlm.balance(&client.address), 100);
+    assert_eq!(
+        client.try_bid(&carol, &token_id, &100),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.bid(&carol, &token_id, &250);
+    assert_eq!(xlm.balance(&bob), 1_000);
+    assert_eq!(xlm.balance(&carol), 750);
+    assert_eq!(xlm.balance(&client.address), 250);
+    assert_eq!(
+        client.try_start_auction(&alice, &token_id, &payment, &0, &60),
+        Err(Err(ExtError::AuctionRunning.into()))
+    );
+
+    // Bidding closes at the end; settlement pays the seller and moves the token.
+    assert_eq!(
+        client.try_settle_auction(&token_id),
+        Err(Err(ExtError::AuctionRunning.into()))
+    );
+    env.ledger().set_timestamp(4_600);
+    assert_eq!(
+        client.try_bid(&bob, &token_id, &500),
+        Err(Err(ExtError::AuctionEnded.into()))
+    );
+    client.settle_auction(&token_id);
+    let settled = AuctionSettled {
+        token_id,
+        winner: Some(carol.clone()),
+        price: 250,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, settled.topics(&env));
+    assert_eq!(data.to_xdr(&env), settled.data(&env).to_xdr(&env));
+    assert_eq!(client.get_owner(&token_id), carol);
+    assert_eq!(xlm.balance(&alice), 250);
+    assert_eq!(xlm.balance(&client.address), 0);
+    assert_eq!(client.auction(&token_id), None);
+    assert_eq!(
+        client.try_settle_auction(&token_id),
+        Err(Err(ExtError::NoAuction.into()))
+    );
+
+    // An auction lapses, refunding the high bid, if the token moves first.
+    client.start_auction(&carol, &token_id, &payment, &100, &3_600);
+    client.bid(&bob, &token_id, &300);
+    client.transfer(&carol, &alice, &token_id);
+    assert_eq!(client.auction(&token_id), None);
+    assert_eq!(xlm.balance(&bob), 1_000);
+    assert_eq!(xlm.balance(&client.address), 0);
+
+    // With no bids, settling changes nothing.
+    client.start_auction(&alice, &token_id, &payment, &100, &60);
+    env.ledger().set_timestamp(4_660);
+    client.settle_auction(&token_id);
+    assert_eq!(client.get_owner(&token_id), alice);
+
+    // A token that can't be handed over lapses its auction at settlement,
+    // refunding the high bid instead of locking it up.
+    client.start_auction(&alice, &token_id, &payment, &100, &60);
+    client.bid(&bob, &token_id, &400);
+    client.freeze(&token_id);
+    env.ledger().set_timestamp(4_720);
+    client.settle_auction(&token_id);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(topics, AuctionLapsed { token_id }.topics(&env));
+    assert_eq!(client.get_owner(&token_id), alice);
+    assert_eq!(client.auction(&token_id), None);
+    assert_eq!(xlm.balance(&bob), 1_000);
+    assert_eq!(xlm.balance(&alice), 250);
+    assert_eq!(xlm.balance(&client.address), 0);
+}
+
+#[test]
+fn test_sealed_auction() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let payment = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let sac = soroban_sdk::token::StellarAssetClient::new(&env, &payment);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+    let bidders: std::vec::Vec<Address> = (0..4).map(|_| Address::generate(&env)).collect();
+    let [bob, carol, dave, erin] = [0, 1, 2, 3].map(|i| bidders[i].clone());
+    for bidder in &bidders {
+        sac.mint(bidder, &10_000);
+    }
+    let salt = BytesN::from_array(&env, &[7; 32]);
+    let commit = |bidder: &Address, amount: i128| -> BytesN<32> {
+        env.crypto()
+            .sha256(&(bidder.clone(), amount, salt.clone()).to_xdr(&env))
+            .into()
+    };
+
+    assert_eq!(
+        client.try_start_sealed_auction(&alice, &token_id, &payment, &300, &-1, &100),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.start_sealed_auction(&alice, &token_id, &payment, &300, &50, &100);
+    assert_eq!(
+        client.try_start_sealed_auction(&alice, &token_id, &payment, &300, &50, &100),
+        Err(Err(ExtError::AuctionRunning.into()))
+    );
+
+    // Commitments post the bond and hide the amount.
+    client.commit_bid(&bob, &token_id, &commit(&bob, 1_000));
+    client.commit_bid(&carol, &token_id, &commit(&carol, 700));
+    client.commit_bid(&dave, &token_id, &commit(&dave, 900));
+    client.commit_bid(&erin, &token_id, &commit(&erin, 200));
+    assert_eq!(xlm.balance(&client.address), 200);
+    assert_eq!(
+        client.try_reveal_bid(&bob, &token_id, &1_000, &salt),
+        Err(Err(ExtError::AuctionRunning.into()))
+    );
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    assert_eq!(
+        client.try_commit_bid(&admin, &token_id, &commit(&admin, 5_000)),
+        Err(Err(ExtError::AuctionEnded.into()))
+    );
+
+    // Reveals must match the commitment; the leader's bid is escrowed.
+    assert_eq!(
+        client.try_reveal_bid(&carol, &token_id, &900, &salt),
+        Err(Err(ExtError::CommitmentMismatch.into()))
+    );
+    assert_eq!(
+        client.try_reveal_bid(&admin, &token_id, &900, &salt),
+        Err(Err(ExtError::NoCommitment.into()))
+    );
+    client.reveal_bid(&carol, &token_id, &700, &salt);
+    assert_eq!(xlm.balance(&carol), 9_300);
+    client.reveal_bid(&bob, &token_id, &1_000, &salt);
+    assert_eq!(xlm.balance(&carol), 10_000);
+    client.reveal_bid(&erin, &token_id, &200, &salt);
+    assert_eq!(xlm.balance(&erin), 10_000);
+    let auction = client.sealed_auction(&token_id).unwrap();
+    assert_eq!(auction.high_bidder, Some(bob.clone()));
+    assert_eq!((auction.high_bid, auction.second_bid), (1_000, 700));
+    assert_eq!(
+        client.try_settle_sealed(&token_id),
+        Err(Err(ExtError::AuctionRunning.into()))
+    );
+
+    // The winner pays the second price; the unrevealed bond goes to the seller.
+    env.ledger()
+        .with_mut(|li| li.timestamp += SEALED_REVEAL_WINDOW);
+    assert_eq!(
+        client.try_reveal_bid(&dave, &token_id, &900, &salt),
+        Err(Err(ExtError::AuctionEnded.into()))
+    );
+    assert_eq!(client.settle_sealed(&token_id), 700);
+    assert_eq!(client.get_owner(&token_id), bob);
+    assert_eq!(xlm.balance(&bob), 9_300);
+    assert_eq!(xlm.balance(&alice), 750);
+    assert_eq!(xlm.balance(&dave), 9_950);
+    assert_eq!(xlm.balance(&client.address), 0);
+    assert_eq!(client.sealed_auction(&token_id), None);
+
+    // A sealed auction lapses when the token changes owner, returning bonds
+    // and the escrowed bid.
+    client.start_sealed_auction(&bob, &token_id, &payment, &0, &50, &100);
+    client.commit_bid(&carol, &token_id, &commit(&carol, 400));
+    client.commit_bid(&dave, &token_id, &commit(&dave, 500));
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.reveal_bid(&dave, &token_id, &500, &salt);
+    client.transfer(&bob, &alice, &token_id);
+    assert_eq!(client.sealed_auction(&token_id), None);
+    assert_eq!(xlm.balance(&carol), 10_000);
+    assert_eq!(xlm.balance(&dave), 9_950);
+    assert_eq!(xlm.balance(&client.address), 0);
+}
+
+#[test]
+fn test_dutch_auction() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &admin);
+    let other = mint_one(&env, &client, &alice);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &payment).mint(&alice, &1_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+
+    assert_eq!(
+        client.try_start_dutch_auction(&other, &payment, &900, &300, &10),
+        Err(Ok(Error::NotOwner))
+    );
+    assert_eq!(
+        client.try_start_dutch_auction(&token_id, &payment, &300, &900, &10),
+        Err(Ok(Error::InvalidAmount))
+    );
+    let start = env.ledger().sequence();
+    client.start_dutch_auction(&token_id, &payment, &900, &300, &10);
+
+    // The price falls by the decay each ledger, down to the floor.
+    assert_eq!(client.dutch_price(&token_id), 900);
+    env.ledger().with_mut(|li| li.sequence_number = start + 25);
+    assert_eq!(client.dutch_price(&token_id), 650);
+    env.ledger()
+        .with_mut(|li| li.sequence_number = start + 1_000);
+    assert_eq!(client.dutch_price(&token_id), 300);
+
+    env.ledger().with_mut(|li| li.sequence_number = start + 40);
+    assert_eq!(client.buy_dutch(&alice, &token_id), 500);
+    let sale = Sale {
+        token_id,
+        buyer: alice.clone(),
+        seller: admin.clone(),
+        price: 500,
+        payment_token: payment.clone(),
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, sale.topics(&env));
+    assert_eq!(data.to_xdr(&env), sale.data(&env).to_xdr(&env));
+    assert_eq!(client.get_owner(&token_id), alice);
+    assert_eq!(xlm.balance(&admin), 500);
+    assert_eq!(client.dutch_auction(&token_id), None);
+    assert_eq!(
+        client.try_buy_dutch(&alice, &token_id),
+        Err(Ok(Error::NotListed))
+    );
+}
+
+#[test]
+fn test_offers() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    let sac_admin = soroban_sdk::token::StellarAssetClient::new(&env, &payment);
+    sac_admin.mint(&bob, &1_000);
+    sac_admin.mint(&carol, &1_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+    env.ledger().set_timestamp(1_000);
+
+    assert_eq!(
+        client.try_make_offer(&bob, &token_id, &payment, &0, &2_000),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_make_offer(&bob, &token_id, &payment, &100, &999),
+        Err(Err(ExtError::OfferExpired.into()))
+    );
+    assert_eq!(
+        client.try_make_offer(&bob, &999, &payment, &100, &2_000),
+        Err(Ok(Error::TokenNotFound))
+    );
+
+    // Offers are escrowed, and a new offer replaces the buyer's old one.
+    client.make_offer(&bob, &token_id, &payment, &100, &2_000);
+    client.make_offer(&bob, &token_id, &payment, &300, &2_000);
+    client.make_offer(&carol, &token_id, &payment, &200, &2_000);
+    assert_eq!(xlm.balance(&bob), 700);
+    assert_eq!(xlm.balance(&client.address), 500);
+    assert_eq!(
+        client.offer(&token_id, &bob),
+        Some(Offer {
+            payment_token: payment.clone(),
+            amount: 300,
+            expires_at: 2_000,
+        })
+    );
+
+    // The owner accepts one; the others stay until their buyers cancel them.
+    assert_eq!(
+        client.try_accept_offer(&bob, &token_id, &bob),
+        Err(Ok(Error::NotOwner))
+    );
+    client.accept_offer(&alice, &token_id, &bob);
+    let accepted = OfferAccepted {
+        token_id,
+        buyer: bob.clone(),
+        seller: alice.clone(),
+        amount: 300,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, accepted.topics(&env));
+    assert_eq!(data.to_xdr(&env), accepted.data(&env).to_xdr(&env));
+    assert_eq!(client.get_owner(&token_id), bob);
+    assert_eq!(xlm.balance(&alice), 300);
+    assert_eq!(client.offer(&token_id, &bob), None);
+    assert_eq!(
+        client.try_accept_offer(&bob, &token_id, &bob),
+        Err(Err(ExtError::NoOffer.into()))
+    );
+
+    // Carol's offer now stands with bob, until it expires.
+    env.ledger().set_timestamp(2_001);
+    assert_eq!(
+        client.try_accept_offer(&bob, &token_id, &carol),
+        Err(Err(ExtError::OfferExpired.into()))
+    );
+    // Even a pause doesn't lock her funds in escrow.
+    client.pause(&admin);
+    client.cancel_offer(&carol, &token_id);
+    client.unpause(&admin);
+    assert_eq!(xlm.balance(&carol), 1_000);
+    assert_eq!(xlm.balance(&client.address), 0);
+    assert_eq!(
+        client.try_cancel_offer(&carol, &token_id),
+        Err(Err(ExtError::NoOffer.into()))
+    );
+}
+
+#[test]
+fn test_escrow_trade() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let offered = mint_one(&env, &client, &alice);
+    let wanted = mint_one(&env, &client, &bob);
+
+    assert_eq!(
+        client.try_open_trade(&alice, &offered, &bob, &offered),
+        Err(Ok(Error::InvalidSwap))
+    );
+    assert_eq!(
+        client.try_open_trade(&bob, &offered, &carol, &wanted),
+        Err(Ok(Error::NotOwner))
+    );
+
+    // Alice's token is held by the contract until the trade settles.
+    client.open_trade(&alice, &offered, &bob, &wanted);
+    assert_eq!(client.get_owner(&offered), client.address);
+    assert_eq!(
+        client.try_accept_trade(&carol, &offered),
+        Err(Ok(Error::NotOwner))
+    );
+    client.accept_trade(&bob, &offered);
+    let accepted = TradeAccepted {
+        token_id: offered,
+        wanted_token_id: wanted,
+        owner: alice.clone(),
+        counterparty: bob.clone(),
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, accepted.topics(&env));
+    assert_eq!(data.to_xdr(&env), accepted.data(&env).to_xdr(&env));
+    assert_eq!(client.get_owner(&offered), bob);
+    assert_eq!(client.get_owner(&wanted), alice);
+    assert_eq!(client.trade(&offered), None);
+
+    // Either party can call a trade off; a counterparty without the wanted
+    // token can't accept it.
+    client.open_trade(&bob, &offered, &alice, &wanted);
+    client.withdraw_trade(&alice, &offered);
+    assert_eq!(client.get_owner(&offered), bob);
+    client.open_trade(&bob, &offered, &carol, &wanted);
+    assert_eq!(
+        client.try_accept_trade(&carol, &offered),
+        Err(Ok(Error::NotOwner))
+    );
+    assert_eq!(
+        client.try_withdraw_trade(&alice, &offered),
+        Err(Ok(Error::NotOwner))
+    );
+    client.withdraw_trade(&bob, &offered);
+    assert_eq!(client.get_owner(&offered), bob);
+    assert_eq!(
+        client.try_withdraw_trade(&bob, &offered),
+        Err(Err(ExtError::NoTrade.into()))
+    );
+}
+
+#[test]
+fn test_royalties() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let hotel = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &payment).mint(&bob, &10_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+
+    assert_eq!(client.get_royalty(&token_id), None);
+    assert_eq!(
+        client.try_set_royalty(&admin, &GRAND_HOTEL, &hotel, &10_001),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_royalty(&admin, &99, &hotel, &500),
+        Err(Ok(Error::HotelNotFound))
+    );
+    client.set_royalty(&admin, &GRAND_HOTEL, &hotel, &500);
+    let royalty = Royalty {
+        payout: hotel.clone(),
+        bps: 500,
+    };
+    assert_eq!(client.get_royalty(&token_id), Some(royalty));
+
+    // 5% of every resale goes to the hotel: through buy...
+    client.list_for_sale(&alice, &token_id, &1_000, &payment);
+    client.buy(&bob, &token_id);
+    assert_eq!(xlm.balance(&hotel), 50);
+    assert_eq!(xlm.balance(&alice), 950);
+
+    // ...accepted offers...
+    client.make_offer(&alice, &token_id, &payment, &900, &u64::MAX);
+    client.accept_offer(&bob, &token_id, &alice);
+    assert_eq!(xlm.balance(&hotel), 95);
+    assert_eq!(xlm.balance(&bob), 10_000 - 1_000 + 855);
+
+    // ...and auctions.
+    client.start_auction(&alice, &token_id, &payment, &0, &60);
+    client.bid(&bob, &token_id, &2_000);
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    client.settle_auction(&token_id);
+    assert_eq!(xlm.balance(&hotel), 195);
+    assert_eq!(xlm.balance(&alice), 950 - 900 + 1_900);
+    assert_eq!(xlm.balance(&client.address), 0);
+
+    // A royalty too large to compute fails the sale instead of overflowing.
+    client.list_for_sale(&bob, &token_id, &i128::MAX, &payment);
+    assert_eq!(
+        client.try_buy(&alice, &token_id),
+        Err(Err(ExtError::AmountOverflow.into()))
+    );
+    assert_eq!(client.get_owner(&token_id), bob);
+}
+
+#[test]
+fn test_withholding() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let officer = Address::generate(&env);
+    let authority = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let payment = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &payment).mint(&bob, &10_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+    let es = Symbol::new(&env, "ES");
+    env.ledger().set_timestamp(1_767_225_600); // 2026-01-01
+
+    // Only the Compliance role (or the admin) configures withholding.
+    assert_eq!(
+        client.try_set_withholding(&officer, &GRAND_HOTEL, &es, &300, &authority),
+        Err(Ok(Error::MissingRole))
+    );
+    client.grant_role(&Role::Compliance, &officer);
+    assert_eq!(
+        client.try_set_withholding(&officer, &GRAND_HOTEL, &es, &10_001, &authority),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_withholding(&officer, &GRAND_HOTEL, &es, &300, &authority);
+    let rule = WithholdingRule {
+        jurisdiction: es.clone(),
+        bps: 300,
+        recipient: authority.clone(),
+    };
+    assert_eq!(client.withholding(&GRAND_HOTEL), Some(rule.clone()));
+    assert_eq!(
+        client.config().withholdings,
+        soroban_sdk::map![&env, (GRAND_HOTEL, rule)]
+    );
+
+    // 3% of the price is withheld from the seller, after the royalty.
+    client.set_royalty(&admin, &GRAND_HOTEL, &admin, &500);
+    client.list_for_sale(&alice, &token_id, &1_000, &payment);
+    client.buy(&bob, &token_id);
+    assert_eq!(xlm.balance(&authority), 30);
+    assert_eq!(xlm.balance(&admin), 50);
+    assert_eq!(xlm.balance(&alice), 920);
+    assert_eq!(client.withheld(&alice, &2026, &payment), 30);
+
+    // Amounts add up per seller and year.
+    client.make_offer(&alice, &token_id, &payment, &500, &u64::MAX);
+    client.accept_offer(&bob, &token_id, &alice);
+    client.make_offer(&bob, &token_id, &payment, &2_000, &u64::MAX);
+    client.accept_offer(&alice, &token_id, &bob);
+    assert_eq!(client.withheld(&alice, &2026, &payment), 90);
+    assert_eq!(client.withheld(&bob, &2026, &payment), 15);
+    assert_eq!(client.withheld(&alice, &2027, &payment), 0);
+
+    // A registered exemption pays the seller in full until it runs out.
+    client.register_exemption(&officer, &bob, &es, &1_767_312_000);
+    assert!(client.is_exempt(&bob, &es));
+    client.list_for_sale(&bob, &token_id, &1_000, &payment);
+    client.buy(&alice, &token_id);
+    assert_eq!(client.withheld(&bob, &2026, &payment), 15);
+    env.ledger().set_timestamp(1_767_312_001);
+    assert!(!client.is_exempt(&bob, &es));
+    client.register_exemption(&officer, &alice, &es, &u64::MAX);
+    client.revoke_exemption(&officer, &alice, &es);
+    client.list_for_sale(&alice, &token_id, &1_000, &payment);
+    client.buy(&bob, &token_id);
+    assert_eq!(client.withheld(&alice, &2026, &payment), 120);
+
+    // Clearing the rule stops withholding.
+    client.set_withholding(&officer, &GRAND_HOTEL, &es, &0, &authority);
+    assert_eq!(client.withholding(&GRAND_HOTEL), None);
+    assert!(client.config().withholdings.is_empty());
+}
+
+#[test]
+fn test_money_rounding() {
+    let amount = |value: i128| Amount::of(value).unwrap();
+    let share = |value: i128, bps: u32, unit: i128| {
+        money::share(amount(value), Bps::of(bps).unwrap(), amount(unit)).map(Amount::get)
+    };
+    let prorate = |value: i128, numerator: i128, denominator: i128, unit: i128| {
+        money::prorate(amount(value), numerator, denominator, amount(unit)).map(Amount::get)
+    };
+
+    // Halves round to even, everything else to the nearest.
+    assert_eq!(share(5, 5_000, 1), Ok(2));
+    assert_eq!(share(15, 5_000, 1), Ok(8));
+    assert_eq!(share(7, 5_000, 1), Ok(4));
+    assert_eq!(share(1_030, 500, 1), Ok(52));
+    assert_eq!(prorate(100, 1, 2, 100), Ok(0));
+    assert_eq!(prorate(300, 1, 2, 100), Ok(200));
+    assert_eq!(prorate(150, 1, 2, 100), Ok(100));
+    // Rounding up to a whole unit never takes more than the amount.
+    assert_eq!(share(150, 10_000, 100), Ok(150));
+    assert_eq!(prorate(10, 3, 2, 1), Ok(15));
+
+    // Every share of every small amount is the nearest whole unit, at most
+    // half a unit off and never more than the amount.
+    for unit in [1, 2, 7, 100] {
+        for value in 0..=1_000 {
+            for bps in (0..=10_000).step_by(37) {
+                let share = share(value, bps, unit).unwrap();
+                assert!(share >= 0 && share <= value);
+                assert!(share % unit == 0 || share == value);
+                let error = (share * 10_000 - value * bps as i128).abs();
+                if share != value {
+                    assert!(2 * error <= 10_000 * unit, "{value} {bps} {unit}");
+                }
+            }
+        }
+    }
+
+    // Overflow and nonsense fail with typed errors instead of wrapping.
+    assert_eq!(share(i128::MAX, 2, 1), Err(ExtError::AmountOverflow));
+    assert_eq!(prorate(1, 1, 0, 1), Err(ExtError::AmountOverflow));
+    assert_eq!(prorate(1, -1, 2, 1), Err(ExtError::NegativeAmount));
+    assert_eq!(share(1, 1, 0), Err(ExtError::InvalidMinorUnit));
+}
+
+#[test]
+fn test_amount_newtypes() {
+    let amount = |value: i128| Amount::of(value).unwrap();
+
+    assert_eq!(Amount::of(-1), Err(ExtError::NegativeAmount));
+    assert_eq!(Amount::of(0), Ok(Amount::ZERO));
+    assert_eq!(amount(2).checked_add(amount(3)), Ok(amount(5)));
+    assert_eq!(
+        amount(i128::MAX).checked_add(Amount::ONE),
+        Err(ExtError::AmountOverflow)
+    );
+    assert_eq!(amount(5).checked_sub(amount(5)), Ok(Amount::ZERO));
+    assert_eq!(
+        amount(5).checked_sub(amount(6)),
+        Err(ExtError::NegativeAmount)
+    );
+    assert_eq!(amount(250).checked_mul(3), Ok(amount(750)));
+    assert_eq!(
+        amount(i128::MAX).checked_mul(2),
+        Err(ExtError::AmountOverflow)
+    );
+    assert_eq!(Bps::of(FULL_BPS), Ok(Bps::FULL));
+    assert_eq!(Bps::of(FULL_BPS + 1), Err(ExtError::InvalidBps));
+    assert_eq!(Bps::of(250).unwrap().get(), 250);
+}
+
+#[test]
+fn test_money_split() {
+    let env = Env::default();
+    // Thousands of splits, far more than one invocation's budget.
+    env.cost_estimate().budget().reset_unlimited();
+    let split = |value: i128, weights: &[u32], unit: i128| {
+        let weights = Vec::from_slice(&env, weights);
+        let value = Amount::of(value).unwrap();
+        money::split(&env, value, &weights, Amount::of(unit).unwrap())
+    };
+
+    // Leftover units go to the largest remainders, the earliest on a tie.
+    assert_eq!(split(10, &[1, 1, 1], 1), Ok(vec![&env, 4, 3, 3]));
+    assert_eq!(
+        split(100, &[3_333, 3_333, 3_334], 1),
+        Ok(vec![&env, 33, 33, 34])
+    );
+    assert_eq!(split(11, &[1, 2, 2], 1), Ok(vec![&env, 2, 5, 4]));
+    assert_eq!(split(5, &[0, 1], 1), Ok(vec![&env, 0, 5]));
+    // Whole units only, with what is left below one to the first recipient.
+    assert_eq!(split(7, &[1, 1], 2), Ok(vec![&env, 5, 2]));
+    assert_eq!(split(1_050, &[1, 1, 1], 100), Ok(vec![&env, 450, 300, 300]));
+
+    // The parts of every small amount add up to it, and each is within a
+    // unit of its exact quota.
+    let weight_sets: [&[u32]; 5] = [
+        &[10_000],
+        &[5_000, 5_000],
+        &[8_000, 1_500, 500],
+        &[1, 2, 3, 4, 5, 6, 7],
+        &[3_333, 3_333, 3_334],
+    ];
+    for weights in weight_sets {
+        let total: i128 = weights.iter().map(|weight| *weight as i128).sum();
+        for unit in [1, 3, 100] {
+            for amount in 0..=500 {
+                let parts = split(amount, weights, unit).unwrap();
+                assert_eq!(parts.iter().sum::<i128>(), amount);
+                let whole = amount - amount % unit;
+                for (index, part) in parts.iter().enumerate() {
+                    let part = if index == 0 { part - amount % unit } else { part };
+                    assert_eq!(part % unit, 0);
+                    let quota = whole * weights[index] as i128;
+                    assert!((part * total - quota).abs() < unit * total);
+                }
+            }
+        }
+    }
+
+    assert_eq!(split(1, &[0, 0], 1), Err(ExtError::InvalidBps));
+    assert_eq!(split(1, &[1], 0), Err(ExtError::InvalidMinorUnit));
+    assert_eq!(split(i128::MAX, &[2, 1], 1), Err(ExtError::AmountOverflow));
+}
+
+#[test]
+fn test_minor_units() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let payout = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let payment = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &payment).mint(&bob, &10_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+
+    assert!(client
+        .try_set_minor_unit(&manager, &GRAND_HOTEL, &payment, &100)
+        .is_err());
+    client.grant_role(&Role::Manager, &manager);
+    assert_eq!(
+        client.try_set_minor_unit(&manager, &GRAND_HOTEL, &payment, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_minor_unit(&manager, &GRAND_HOTEL, &payment, &100);
+    let set = MinorUnitSet {
+        hotel_id: GRAND_HOTEL,
+        asset: payment.clone(),
+        unit: 100,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, set.topics(&env));
+    assert_eq!(data.to_xdr(&env), set.data(&env).to_xdr(&env));
+    let units = soroban_sdk::map![&env, (payment.clone(), 100)];
+    assert_eq!(client.minor_units(&GRAND_HOTEL), units.clone());
+    assert_eq!(
+        client.config().minor_units,
+        soroban_sdk::map![&env, (GRAND_HOTEL, units)]
+    );
+
+    // A 5% royalty on 1_030 is 51.5, taken as one whole unit of 100.
+    client.set_royalty(&admin, &GRAND_HOTEL, &payout, &500);
+    client.list_for_sale(&alice, &token_id, &1_030, &payment);
+    client.buy(&bob, &token_id);
+    assert_eq!(xlm.balance(&payout), 100);
+    assert_eq!(xlm.balance(&alice), 930);
+
+    // A unit of 1 clears it.
+    client.set_minor_unit(&manager, &GRAND_HOTEL, &payment, &1);
+    assert!(client.minor_units(&GRAND_HOTEL).is_empty());
+    assert!(client.config().minor_units.is_empty());
+}
+
+#[test]
+fn test_fractional_shares() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+
+    assert_eq!(
+        client.try_fractionalize(&alice, &token_id, &1),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_fractionalize(&bob, &token_id, &100),
+        Err(Ok(Error::NotOwner))
+    );
+    client.fractionalize(&alice, &token_id, &100);
+    assert_eq!(client.get_owner(&token_id), client.address);
+    assert_eq!(client.fraction(&token_id), Some(100));
+    assert_eq!(client.share_balance(&token_id, &alice), 100);
+
+    client.transfer_share(&alice, &bob, &token_id, &60);
+    let moved = ShareTransfer {
+        token_id,
+        from: alice.clone(),
+        to: bob.clone(),
+        amount: 60,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, moved.topics(&env));
+    assert_eq!(data.to_xdr(&env), moved.data(&env).to_xdr(&env));
+    client.transfer_share(&alice, &carol, &token_id, &40);
+    assert_eq!(
+        client.try_transfer_share(&alice, &bob, &token_id, &1),
+        Err(Err(ExtError::InsufficientShares.into()))
+    );
+
+    // Nobody can redeem while the shares are split...
+    assert_eq!(
+        client.try_redeem_shares(&bob, &token_id),
+        Err(Err(ExtError::InsufficientShares.into()))
+    );
+    // ...until one holder has bought the others out.
+    client.transfer_share(&carol, &bob, &token_id, &40);
+    assert_eq!(client.share_balance(&token_id, &bob), 100);
+    client.redeem_shares(&bob, &token_id);
+    assert_eq!(client.get_owner(&token_id), bob);
+    assert_eq!(client.fraction(&token_id), None);
+    assert_eq!(client.share_balance(&token_id, &bob), 0);
+    assert_eq!(
+        client.try_transfer_share(&bob, &alice, &token_id, &1),
+        Err(Err(ExtError::NotFractionalized.into()))
+    );
+}
+
+#[test]
+fn test_redeem_voucher() {
+    use ed25519_dalek::{Signer, SigningKey};
+
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &payment).mint(&alice, &1_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let sign = |voucher: &Voucher| {
+        let message = client.voucher_message(voucher);
+        let message: std::vec::Vec<u8> = message.iter().collect();
+        BytesN::from_array(&env, &key.sign(&message).to_bytes())
+    };
+    let voucher = Voucher {
+        info: unit(&env, 0),
+        price: 400,
+        payment_token: payment.clone(),
+        buyer: Some(alice.clone()),
+        expires_at: 1_000,
+    };
+    let signature = sign(&voucher);
+    assert_eq!(
+        client.try_redeem_voucher(&alice, &voucher, &signature),
+        Err(Err(ExtError::NoVoucherSigner.into()))
+    );
+    assert_eq!(client.config().voucher_signer, None);
+    let signer = BytesN::from_array(&env, key.verifying_key().as_bytes());
+    client.set_voucher_signer(&signer);
+    assert_eq!(client.config().voucher_signer, Some(signer));
+
+    // Only the named buyer, with the voucher exactly as signed.
+    assert_eq!(
+        client.try_redeem_voucher(&bob, &voucher, &signature),
+        Err(Err(ExtError::WrongBuyer.into()))
+    );
+    let tampered = Voucher {
+        price: 1,
+        ..voucher.clone()
+    };
+    assert!(client
+        .try_redeem_voucher(&alice, &tampered, &signature)
+        .is_err());
+
+    let token_id = client.redeem_voucher(&alice, &voucher, &signature);
+    let redeemed = VoucherRedeemed {
+        token_id,
+        buyer: alice.clone(),
+        price: 400,
+        payment_token: payment.clone(),
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, redeemed.topics(&env));
+    assert_eq!(data.to_xdr(&env), redeemed.data(&env).to_xdr(&env));
+    assert_eq!(client.get_owner(&token_id), alice);
+    assert_eq!(client.get_info(&token_id), unit(&env, 0));
+    assert_eq!(xlm.balance(&admin), 400);
+
+    // A voucher is good once, even after its token is burned.
+    client.burn(&alice, &token_id);
+    assert_eq!(
+        client.try_redeem_voucher(&alice, &voucher, &signature),
+        Err(Err(ExtError::VoucherRedeemed.into()))
+    );
+
+    // Open vouchers can be redeemed by anyone, until they expire.
+    let open = Voucher {
+        info: unit(&env, 1),
+        buyer: None,
+        ..voucher
+    };
+    let signature = sign(&open);
+    env.ledger().with_mut(|li| li.timestamp = 1_001);
+    assert_eq!(
+        client.try_redeem_voucher(&bob, &open, &signature),
+        Err(Err(ExtError::VoucherExpired.into()))
+    );
+}
+
+#[test]
+fn test_allowlist_mint() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let dave = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    let asset = soroban_sdk::token::StellarAssetClient::new(&env, &payment);
+    asset.mint(&alice, &1_000);
+    asset.mint(&carol, &1_000);
+    asset.mint(&dave, &1_000);
+
+    // A tree of alice, bob and carol: root = H(H(a, b), c), pairs sorted.
+    let leaf = |who: &Address| -> BytesN<32> { env.crypto().sha256(&who.to_xdr(&env)).into() };
+    let node = |x: BytesN<32>, y: BytesN<32>| -> BytesN<32> {
+        let (low, high) = if x.to_array() <= y.to_array() {
+            (x, y)
+        } else {
+            (y, x)
+        };
+        let mut pair = Bytes::from(low);
+        pair.append(&Bytes::from(high));
+        env.crypto().sha256(&pair).into()
+    };
+    let ab = node(leaf(&alice), leaf(&bob));
+    let root = node(ab.clone(), leaf(&carol));
+
+    assert_eq!(
+        client.try_allowlist_mint(&alice, &vec![&env], &unit(&env, 0)),
+        Err(Err(ExtError::NoAllowlist.into()))
+    );
+    client.set_allowlist(&Allowlist {
+        root,
+        price: 250,
+        payment_token: payment.clone(),
+    });
+
+    let alice_proof = vec![&env, leaf(&bob), leaf(&carol)];
+    let token_id = client.allowlist_mint(&alice, &alice_proof, &unit(&env, 0));
+    assert_eq!(client.get_owner(&token_id), alice);
+    let token_id = client.allowlist_mint(&carol, &vec![&env, ab], &unit(&env, 1));
+    assert_eq!(client.get_owner(&token_id), carol);
+    assert_eq!(
+        soroban_sdk::token::Client::new(&env, &payment).balance(&admin),
+        500
+    );
+
+    // Once per address, and only for addresses in the tree.
+    assert_eq!(
+        client.try_allowlist_mint(&alice, &alice_proof, &unit(&env, 2)),
+        Err(Err(ExtError::AllowlistClaimed.into()))
+    );
+    assert_eq!(
+        client.try_allowlist_mint(&dave, &alice_proof, &unit(&env, 2)),
+        Err(Err(ExtError::NotAllowlisted.into()))
+    );
+
+    client.close_allowlist();
+    assert_eq!(client.allowlist(), None);
+}
+
+#[test]
+fn test_token_uri() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let uri = String::from_str(
+        &env,
+        "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+
+    assert_eq!(client.token_uri(&token_id), None);
+    assert_eq!(
+        client.try_set_token_uri(&alice, &token_id, &uri),
+        Err(Ok(Error::MissingRole))
+    );
+    client.set_token_uri(&admin, &token_id, &uri);
+    let set = TokenUriSet {
+        token_id,
+        uri: uri.clone(),
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, set.topics(&env));
+    assert_eq!(data.to_xdr(&env), set.data(&env).to_xdr(&env));
+    assert_eq!(client.token_uri(&token_id), Some(uri.clone()));
+
+    client.burn(&alice, &token_id);
+    assert_eq!(
+        client.try_token_uri(&token_id),
+        Err(Ok(Error::TokenNotFound))
+    );
+    assert_eq!(
+        client.try_set_token_uri(&admin, &token_id, &uri),
+        Err(Ok(Error::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_update_and_freeze_metadata() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let taken = mint_one(&env, &client, &alice);
+    let old_info = client.get_info(&token_id);
+
+    // Room 305 was renumbered 306; the new week must be unsold.
+    let new_info = TimeshareInfo {
+        room: String::from_str(&env, "Room 306"),
+        ..old_info.clone()
+    };
+    assert_eq!(
+        client.try_update_info(&alice, &token_id, &new_info),
+        Err(Ok(Error::MissingRole))
+    );
+    assert_eq!(
+        client.try_update_info(&admin, &token_id, &client.get_info(&taken)),
+        Err(Ok(Error::DuplicateTimeshare))
+    );
+    client.update_info(&admin, &token_id, &new_info);
+    let updated = InfoUpdated {
+        token_id,
+        old_info: old_info.clone(),
+        new_info: new_info.clone(),
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, updated.topics(&env));
+    assert_eq!(data.to_xdr(&env), updated.data(&env).to_xdr(&env));
+    assert_eq!(client.get_info(&token_id), new_info);
+    // The old week is free again, the new one taken.
+    assert_eq!(
+        client.token_by_slot(&GRAND_HOTEL, &old_info.room, &old_info.week, &None),
+        None
+    );
+    assert_eq!(
+        client.token_by_slot(&GRAND_HOTEL, &new_info.room, &new_info.week, &None),
+        Some(token_id)
+    );
+
+    // Freezing is for good.
+    client.freeze_metadata(&admin, &token_id);
+    assert!(client.is_metadata_frozen(&token_id));
+    assert_eq!(
+        client.try_update_info(&admin, &token_id, &old_info),
+        Err(Err(ExtError::MetadataFrozen.into()))
+    );
+    assert_eq!(
+        client.try_set_token_uri(&admin, &token_id, &String::from_str(&env, "ipfs://x")),
+        Err(Err(ExtError::MetadataFrozen.into()))
+    );
+    // The token itself still moves.
+    let bob = Address::generate(&env);
+    client.transfer(&alice, &bob, &token_id);
+    assert_eq!(client.get_info(&token_id), new_info);
+}
+
+#[test]
+fn test_buy_mint() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &payment).mint(&alice, &2_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+    let room = String::from_str(&env, "Room 305");
+
+    assert_eq!(
+        client.try_buy_mint(&alice, &GRAND_HOTEL, &room, &10),
+        Err(Ok(Error::NotListed))
+    );
+    client.set_mint_price(&admin, &GRAND_HOTEL, &600, &payment);
+    let token_id = client.buy_mint(&alice, &GRAND_HOTEL, &room, &10);
+    let purchased = MintPurchased {
+        token_id,
+        buyer: alice.clone(),
+        price: 600,
+        payment_token: payment.clone(),
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, purchased.topics(&env));
+    assert_eq!(data.to_xdr(&env), purchased.data(&env).to_xdr(&env));
+    assert_eq!(client.get_owner(&token_id), alice);
+    assert_eq!(client.get_info(&token_id).week, 10);
+    assert_eq!(xlm.balance(&admin), 600);
+    assert_eq!(xlm.balance(&alice), 1_400);
+
+    // Each week sells once, and only while the hotel's weeks are on sale.
+    assert_eq!(
+        client.try_buy_mint(&alice, &GRAND_HOTEL, &room, &10),
+        Err(Ok(Error::DuplicateTimeshare))
+    );
+    assert_eq!(xlm.balance(&alice), 1_400);
+    client.clear_mint_price(&admin, &GRAND_HOTEL);
+    assert_eq!(
+        client.try_buy_mint(&alice, &GRAND_HOTEL, &room, &11),
+        Err(Ok(Error::NotListed))
+    );
+}
+
+#[test]
+fn test_revenue_split() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let hotel = Address::generate(&env);
+    let platform = Address::generate(&env);
+    let charity = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    let asset = soroban_sdk::token::StellarAssetClient::new(&env, &payment);
+    asset.mint(&alice, &10_000);
+    asset.mint(&bob, &10_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+
+    assert_eq!(
+        client.try_set_payees(&vec![
+            &env,
+            (hotel.clone(), 8_000),
+            (platform.clone(), 1_500)
+        ]),
+        Err(Err(ExtError::InvalidSplit.into()))
+    );
+    // Shares that overflow don't wrap around to a whole.
+    assert_eq!(
+        client.try_set_payees(&vec![
+            &env,
+            (hotel.clone(), u32::MAX),
+            (platform.clone(), SPLIT_TOTAL_BPS + 1)
+        ]),
+        Err(Err(ExtError::InvalidSplit.into()))
+    );
+    let mut crowd = vec![&env, (hotel.clone(), SPLIT_TOTAL_BPS)];
+    for _ in 0..MAX_PAYEES {
+        crowd.push_back((Address::generate(&env), 0));
+    }
+    assert_eq!(
+        client.try_set_payees(&crowd),
+        Err(Err(ExtError::TooManyPayees.into()))
+    );
+    let payees = vec![
+        &env,
+        (hotel.clone(), 8_000),
+        (platform.clone(), 1_500),
+        (charity.clone(), 500),
+    ];
+    client.set_payees(&payees);
+    assert_eq!(client.payees(), payees);
+    assert_eq!(client.config().payees, payees);
+
+    // The hotel's proceeds are held and split...
+    client.set_mint_price(&admin, &GRAND_HOTEL, &1_000, &payment);
+    let token_id = client.buy_mint(
+        &alice,
+        &GRAND_HOTEL,
+        &String::from_str(&env, "Room 305"),
+        &1,
+    );
+    assert_eq!(xlm.balance(&admin), 0);
+    assert_eq!(xlm.balance(&client.address), 1_000);
+    assert_eq!(client.withdrawable(&hotel, &payment), 800);
+    assert_eq!(client.withdrawable(&platform, &payment), 150);
+    assert_eq!(client.withdrawable(&charity, &payment), 50);
+
+    // ...while other sellers are paid directly.
+    client.list_for_sale(&alice, &token_id, &700, &payment);
+    client.buy(&bob, &token_id);
+    assert_eq!(xlm.balance(&alice), 10_000 - 1_000 + 700);
+
+    assert_eq!(client.withdraw(&platform, &payment), 150);
+    let withdrawn = RevenueWithdrawn {
+        payee: platform.clone(),
+        payment_token: payment.clone(),
+        amount: 150,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, withdrawn.topics(&env));
+    assert_eq!(data.to_xdr(&env), withdrawn.data(&env).to_xdr(&env));
+    assert_eq!(xlm.balance(&platform), 150);
+    assert_eq!(
+        client.try_withdraw(&platform, &payment),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    // With the split removed, the admin is paid directly again; balances stay.
+    client.set_payees(&vec![&env]);
+    client.buy_mint(
+        &alice,
+        &GRAND_HOTEL,
+        &String::from_str(&env, "Room 305"),
+        &2,
+    );
+    assert_eq!(xlm.balance(&admin), 1_000);
+    assert_eq!(client.withdraw(&hotel, &payment), 800);
+}
+
+#[test]
+fn test_cooling_off() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &payment).mint(&alice, &10_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+    let room = String::from_str(&env, "Room 305");
+
+    assert_eq!(
+        client.try_set_cooling_off(&100, &10_001),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_cooling_off(&100, &9_000);
+    assert_eq!(
+        client.config().cooling_off,
+        CoolingOff {
+            period: 100,
+            refund_bps: 9_000,
+        }
+    );
+    client.set_mint_price(&admin, &GRAND_HOTEL, &1_000, &payment);
+
+    // The price is escrowed; within the period the buyer can back out for a
+    // 90% refund, which burns the token and frees its week.
+    let token_id = client.buy_mint(&alice, &GRAND_HOTEL, &room, &1);
+    assert_eq!(xlm.balance(&client.address), 1_000);
+    assert_eq!(client.purchase(&token_id).unwrap().buyer, alice);
+    assert_eq!(
+        client.try_cancel_purchase(&bob, &token_id),
+        Err(Ok(Error::NotOwner))
+    );
+    assert_eq!(
+        client.try_release_purchase(&token_id),
+        Err(Err(ExtError::CoolingOffRunning.into()))
+    );
+    assert_eq!(client.cancel_purchase(&alice, &token_id), 900);
+    let cancelled = PurchaseCancelled {
+        token_id,
+        buyer: alice.clone(),
+        refund: 900,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, cancelled.topics(&env));
+    assert_eq!(data.to_xdr(&env), cancelled.data(&env).to_xdr(&env));
+    assert_eq!(xlm.balance(&alice), 9_900);
+    assert_eq!(xlm.balance(&admin), 100);
+    assert_eq!(
+        client.try_get_owner(&token_id),
+        Err(Ok(Error::TokenNotFound))
+    );
+    assert_eq!(client.purchase(&token_id), None);
+
+    // After the period the sale stands and anyone can release the proceeds.
+    let token_id = client.buy_mint(&alice, &GRAND_HOTEL, &room, &1);
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    assert_eq!(
+        client.try_cancel_purchase(&alice, &token_id),
+        Err(Err(ExtError::CoolingOffEnded.into()))
+    );
+    client.release_purchase(&token_id);
+    assert_eq!(xlm.balance(&admin), 1_100);
+    assert_eq!(xlm.balance(&client.address), 0);
+    assert_eq!(
+        client.try_release_purchase(&token_id),
+        Err(Err(ExtError::NoPurchase.into()))
+    );
+    assert_eq!(client.get_owner(&token_id), alice);
+
+    // A refund too large to compute fails instead of wrapping around.
+    let carol = Address::generate(&env);
+    soroban_sdk::token::StellarAssetClient::new(&env, &payment).mint(&carol, &i128::MAX);
+    client.set_mint_price(&admin, &GRAND_HOTEL, &i128::MAX, &payment);
+    let token_id = client.buy_mint(&carol, &GRAND_HOTEL, &room, &2);
+    assert_eq!(
+        client.try_cancel_purchase(&carol, &token_id),
+        Err(Err(ExtError::AmountOverflow.into()))
+    );
+    assert_eq!(client.get_owner(&token_id), carol);
+    assert_eq!(xlm.balance(&carol), 0);
+}
+
+#[test]
+fn test_supply_cap() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+
+    client.set_supply_cap(&GRAND_HOTEL, &3);
+    assert_eq!(client.supply_cap(&GRAND_HOTEL), Some(3));
+    assert_eq!(
+        client.try_set_supply_cap(&99, &3),
+        Err(Ok(Error::HotelNotFound))
+    );
+    let first = mint_one(&env, &client, &alice);
+    client.mint_batch(&alice, &units(&env, &client, 2));
+    assert_eq!(client.hotel_supply(&GRAND_HOTEL), 3);
+
+    // At the cap, every mint path is refused.
+    let info = units(&env, &client, 1).first().unwrap();
+    assert_eq!(
+        client.try_mint(&alice, &info.hotel_id, &info.room, &info.week, &None, &None),
+        Err(Err(ExtError::SupplyCapReached.into()))
+    );
+    assert_eq!(
+        client.try_mint_batch(&alice, &units(&env, &client, 1)),
+        Err(Err(ExtError::SupplyCapReached.into()))
+    );
+
+    // Burning a token frees room under the cap, for one mint only.
+    client.burn(&alice, &first);
+    assert_eq!(client.hotel_supply(&GRAND_HOTEL), 2);
+    assert_eq!(
+        client.try_mint_batch(&alice, &units(&env, &client, 2)),
+        Err(Err(ExtError::SupplyCapReached.into()))
+    );
+    let last = mint_one(&env, &client, &alice);
+    assert_eq!(client.hotel_supply(&GRAND_HOTEL), 3);
+    client.set_supply_cap(&GRAND_HOTEL, &10);
+    assert_eq!(
+        client.config().supply_caps,
+        soroban_sdk::map![&env, (GRAND_HOTEL, 10)]
+    );
+
+    // A deployment from before supplies were counted is recounted after the
+    // migration, a page at a time.
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&migration::SCHEMA, &4u32);
+        env.storage()
+            .persistent()
+            .remove(&ExtKey::HotelSupply(GRAND_HOTEL));
+        env.storage().persistent().remove(&ExtKey::CappedHotels);
+    });
+    assert_eq!(client.hotel_supply(&GRAND_HOTEL), 0);
+    assert_eq!(client.supply_caps().len(), 0);
+    assert_eq!(client.migrate(), SCHEMA_VERSION);
+    assert_eq!(
+        client.supply_caps(),
+        soroban_sdk::map![&env, (GRAND_HOTEL, 10)]
+    );
+    assert!(!client.count_supply(&2));
+    assert_eq!(client.hotel_supply(&GRAND_HOTEL), 1);
+
+    // Mints and burns the recount hasn't reached are left to it.
+    client.burn(&alice, &last);
+    let newest = mint_one(&env, &client, &alice);
+    assert_eq!(client.hotel_supply(&GRAND_HOTEL), 1);
+    client.burn(&alice, &2);
+    assert_eq!(client.hotel_supply(&GRAND_HOTEL), 0);
+    assert!(client.count_supply(&MAX_PAGE));
+    assert!(client.count_supply(&MAX_PAGE));
+    assert_eq!(client.hotel_supply(&GRAND_HOTEL), 2);
+    client.burn(&alice, &newest);
+    assert_eq!(client.hotel_supply(&GRAND_HOTEL), 1);
+}
+
+#[test]
+fn test_portfolio_value() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let sold = mint_one(&env, &client, &alice);
+    let kept = mint_one(&env, &client, &alice);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    let other = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let sac_admin = soroban_sdk::token::StellarAssetClient::new(&env, &payment);
+    sac_admin.mint(&alice, &10_000);
+    sac_admin.mint(&bob, &10_000);
+    env.ledger().set_timestamp(1_000);
+
+    // Unsold and unappraised tokens are worth nothing.
+    assert_eq!(client.portfolio_value(&alice, &payment), 0);
+    assert_eq!(
+        client.try_set_appraisal(&admin, &kept, &payment, &-1),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_appraisal(&admin, &kept, &payment, &500);
+    assert_eq!(client.token_value(&kept, &payment), 500);
+    assert_eq!(client.token_value(&kept, &other), 0);
+
+    // A sale marks the price; the average follows later prices over the window.
+    client.list_for_sale(&alice, &sold, &1_000, &payment);
+    client.buy(&bob, &sold);
+    assert_eq!(client.portfolio_value(&alice, &payment), 500);
+    assert_eq!(client.portfolio_value(&bob, &payment), 1_000);
+    env.ledger()
+        .with_mut(|li| li.timestamp += VALUATION_WINDOW / 2);
+    client.list_for_sale(&bob, &sold, &2_000, &payment);
+    client.buy(&alice, &sold);
+    assert_eq!(
+        client.price_mark(&sold),
+        Some(PriceMark {
+            asset: payment.clone(),
+            price: 2_000,
+            average: 1_000,
+            sold_at: 1_000 + VALUATION_WINDOW / 2,
+        })
+    );
+    env.ledger()
+        .with_mut(|li| li.timestamp += VALUATION_WINDOW / 4);
+    assert_eq!(client.token_value(&sold, &payment), 1_250);
+    env.ledger().with_mut(|li| li.timestamp += VALUATION_WINDOW);
+    assert_eq!(client.token_value(&sold, &payment), 2_000);
+
+    // A later appraisal takes over from the sales.
+    client.set_appraisal(&admin, &sold, &payment, &1_800);
+    assert_eq!(client.portfolio_value(&alice, &payment), 2_300);
+
+    // Shares of a fractionalized token count for their holders.
+    client.fractionalize(&alice, &kept, &4);
+    client.transfer_share(&alice, &bob, &kept, &1);
+    assert_eq!(client.portfolio_value(&alice, &payment), 2_175);
+    assert_eq!(client.portfolio_value(&bob, &payment), 125);
+    client.transfer_share(&bob, &alice, &kept, &1);
+    client.redeem_shares(&alice, &kept);
+    assert_eq!(client.portfolio_value(&alice, &payment), 2_300);
+    assert_eq!(client.portfolio_value(&bob, &payment), 0);
+}
+
+#[test]
+fn test_collection_offers() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    // Room 305, weeks 28 to 30.
+    let plain = mint_one(&env, &client, &alice);
+    let platinum = mint_one(&env, &client, &alice);
+    let late = mint_one(&env, &client, &alice);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &payment).mint(&bob, &1_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+    env.ledger().set_timestamp(1_000);
+
+    let tier = Symbol::new(&env, "Platinum");
+    client.set_tier(&admin, &platinum, &Some(tier.clone()));
+    client.set_tier(&admin, &late, &Some(tier.clone()));
+    assert_eq!(client.tier(&platinum), Some(tier.clone()));
+    assert_eq!(client.tier(&plain), None);
+    client.freeze_metadata(&admin, &late);
+    assert_eq!(
+        client.try_set_tier(&admin, &late, &None),
+        Err(Err(ExtError::MetadataFrozen.into()))
+    );
+
+    let criteria = Criteria {
+        hotel_id: Some(GRAND_HOTEL),
+        tier: Some(tier.clone()),
+        first_week: 28,
+        last_week: 29,
+    };
+    let backwards = Criteria {
+        first_week: 30,
+        ..criteria.clone()
+    };
+    assert_eq!(
+        client.try_make_collection_offer(&bob, &backwards, &payment, &300, &2_000),
+        Err(Ok(Error::InvalidWeek))
+    );
+    let elsewhere = Criteria {
+        hotel_id: Some(99),
+        ..criteria.clone()
+    };
+    assert_eq!(
+        client.try_make_collection_offer(&bob, &elsewhere, &payment, &300, &2_000),
+        Err(Ok(Error::HotelNotFound))
+    );
+
+    // The offer is escrowed, and only a token meeting the criteria fills it.
+    let offer_id = client.make_collection_offer(&bob, &criteria, &payment, &300, &2_000);
+    assert_eq!(offer_id, 1);
+    assert_eq!(xlm.balance(&client.address), 300);
+    assert_eq!(
+        client.try_fill_collection_offer(&alice, &offer_id, &plain),
+        Err(Err(ExtError::CriteriaMismatch.into()))
+    );
+    assert_eq!(
+        client.try_fill_collection_offer(&alice, &offer_id, &late),
+        Err(Err(ExtError::CriteriaMismatch.into()))
+    );
+    assert_eq!(
+        client.try_cancel_collection_offer(&alice, &offer_id),
+        Err(Ok(Error::NotOwner))
+    );
+    client.fill_collection_offer(&alice, &offer_id, &platinum);
+    assert_eq!(client.get_owner(&platinum), bob);
+    assert_eq!(xlm.balance(&alice), 300);
+    assert_eq!(client.collection_offer(&offer_id), None);
+    assert_eq!(
+        client.try_fill_collection_offer(&alice, &offer_id, &platinum),
+        Err(Ok(Error::NotOwner))
+    );
+
+    // Offers expire, and buyers take them back whenever they like.
+    let any = Criteria {
+        hotel_id: None,
+        tier: None,
+        first_week: 1,
+        last_week: WEEKS_PER_YEAR,
+    };
+    let offer_id = client.make_collection_offer(&bob, &any, &payment, &200, &2_000);
+    env.ledger().set_timestamp(2_001);
+    assert_eq!(
+        client.try_fill_collection_offer(&alice, &offer_id, &plain),
+        Err(Err(ExtError::OfferExpired.into()))
+    );
+    client.cancel_collection_offer(&bob, &offer_id);
+    assert_eq!(xlm.balance(&bob), 700);
+    assert_eq!(
+        client.try_cancel_collection_offer(&bob, &offer_id),
+        Err(Err(ExtError::NoCollectionOffer.into()))
+    );
+}
+
+#[test]
+fn test_attribute_filters() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    // Room 305, weeks 28 to 30.
+    let first = mint_one(&env, &client, &alice);
+    let second = mint_one(&env, &client, &alice);
+    let third = mint_one(&env, &client, &alice);
+    let platinum = Symbol::new(&env, "Platinum");
+
+    assert_eq!(client.tokens_by_week(&28, &0, &10), vec![&env, first]);
+    assert_eq!(client.tokens_by_week(&30, &0, &10), vec![&env, third]);
+    assert!(client.tokens_by_week(&31, &0, &10).is_empty());
+
+    client.set_tier(&admin, &first, &Some(platinum.clone()));
+    client.set_tier(&admin, &second, &Some(platinum.clone()));
+    assert_eq!(
+        client.tokens_by_tier(&GRAND_HOTEL, &platinum, &0, &10),
+        vec![&env, first, second]
+    );
+    assert_eq!(
+        client.tokens_by_tier(&GRAND_HOTEL, &platinum, &1, &1),
+        vec![&env, second]
+    );
+    assert!(client
+        .tokens_by_tier(&GRAND_HOTEL, &platinum, &2, &10)
+        .is_empty());
+
+    // Changes to a token's info or tier move it between indexes.
+    let mut info = client.get_info(&first);
+    info.week = 40;
+    client.update_info(&admin, &first, &info);
+    assert!(client.tokens_by_week(&28, &0, &10).is_empty());
+    assert_eq!(client.tokens_by_week(&40, &0, &10), vec![&env, first]);
+    assert_eq!(
+        client.tokens_by_tier(&GRAND_HOTEL, &platinum, &0, &10),
+        vec![&env, first, second]
+    );
+    client.set_tier(&admin, &second, &None);
+    assert_eq!(
+        client.tokens_by_tier(&GRAND_HOTEL, &platinum, &0, &10),
+        vec![&env, first]
+    );
+
+    // Indexing again changes nothing, and burned tokens drop out.
+    client.index_tokens(&vec![&env, first, third, 999]);
+    assert_eq!(client.tokens_by_week(&30, &0, &10), vec![&env, third]);
+    client.burn(&alice, &first);
+    assert!(client.tokens_by_week(&40, &0, &10).is_empty());
+    assert!(client
+        .tokens_by_tier(&GRAND_HOTEL, &platinum, &0, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_swap_chains() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let first = mint_one(&env, &client, &alice);
+    let second = mint_one(&env, &client, &bob);
+    let third = mint_one(&env, &client, &carol);
+    let leg = |owner: &Address, token_id: u64| ChainLeg {
+        owner: owner.clone(),
+        token_id,
+    };
+    let legs = vec![
+        &env,
+        leg(&alice, first),
+        leg(&bob, second),
+        leg(&carol, third),
+    ];
+
+    assert_eq!(
+        client.try_propose_chain(&alice, &vec![&env, leg(&alice, first)]),
+        Err(Ok(Error::InvalidSwap))
+    );
+    assert_eq!(
+        client.try_propose_chain(&alice, &vec![&env, leg(&alice, first), leg(&bob, first)]),
+        Err(Ok(Error::InvalidSwap))
+    );
+    assert_eq!(
+        client.try_propose_chain(&alice, &vec![&env, leg(&alice, second), leg(&bob, first)]),
+        Err(Ok(Error::NotOwner))
+    );
+
+    // Every owner escrows their leg; the last to join settles the cycle.
+    let chain_id = client.propose_chain(&alice, &legs);
+    assert_eq!(client.get_owner(&first), client.address);
+    assert_eq!(
+        client.try_join_chain(&Address::generate(&env), &chain_id),
+        Err(Ok(Error::NotOwner))
+    );
+    client.join_chain(&bob, &chain_id);
+    client.join_chain(&bob, &chain_id);
+    assert_eq!(client.get_owner(&second), client.address);
+    client.join_chain(&carol, &chain_id);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(topics, ChainSettled { chain_id }.topics(&env));
+    assert_eq!(client.get_owner(&first), bob);
+    assert_eq!(client.get_owner(&second), carol);
+    assert_eq!(client.get_owner(&third), alice);
+    assert_eq!(client.swap_chain(&chain_id), None);
+    assert_eq!(
+        client.try_join_chain(&alice, &chain_id),
+        Err(Err(ExtError::NoSwapChain.into()))
+    );
+
+    // Any party can call a chain off, returning the escrowed tokens.
+    let chain_id = client.propose_chain(&bob, &vec![&env, leg(&bob, first), leg(&carol, second)]);
+    client.withdraw_chain(&carol, &chain_id);
+    assert_eq!(client.get_owner(&first), bob);
+    assert_eq!(client.swap_chain(&chain_id), None);
+
+    // A token burned out of escrow drops out of its chain.
+    let chain_id = client.propose_chain(&bob, &vec![&env, leg(&bob, first), leg(&carol, second)]);
+    client.admin_burn(&first);
+    let chain = client.swap_chain(&chain_id).unwrap();
+    assert_eq!(chain.joined, vec![&env, false, false]);
+    client.withdraw_chain(&bob, &chain_id);
+    assert_eq!(client.get_owner(&second), carol);
+}
+
+#[test]
+fn test_swap_intents() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let dave = Address::generate(&env);
+    // Weeks 28 to 31.
+    let alices = mint_one(&env, &client, &alice);
+    let bobs = mint_one(&env, &client, &bob);
+    let carols = mint_one(&env, &client, &carol);
+    let daves = mint_one(&env, &client, &dave);
+    let week = |week: u32| Criteria {
+        hotel_id: Some(GRAND_HOTEL),
+        tier: None,
+        first_week: week,
+        last_week: week,
+    };
+
+    assert_eq!(
+        client.try_post_intent(&alice, &bobs, &week(30)),
+        Err(Ok(Error::NotOwner))
+    );
+    assert_eq!(
+        client.try_post_intent(&alice, &alices, &week(0)),
+        Err(Ok(Error::InvalidWeek))
+    );
+
+    // Bob and Dave both want Alice's week; Alice wants Carol's and Carol
+    // wants Bob's, so only Bob completes a cycle with her.
+    client.post_intent(&alice, &alices, &week(30));
+    client.post_intent(&dave, &daves, &week(28));
+    client.post_intent(&bob, &bobs, &week(28));
+    assert_eq!(client.get_owner(&alices), client.address);
+    assert_eq!(client.match_swaps(&5), 0);
+    client.post_intent(&carol, &carols, &week(29));
+    assert_eq!(
+        client.swap_intents(),
+        vec![&env, alices, daves, bobs, carols]
+    );
+    assert_eq!(client.match_swaps(&0), 0);
+    assert_eq!(client.match_swaps(&5), 1);
+    let matched = IntentsMatched {
+        token_ids: vec![&env, alices, bobs, carols],
+    };
+    let (_, _, data) = env.events().all().last().unwrap();
+    assert_eq!(data.to_xdr(&env), matched.data(&env).to_xdr(&env));
+    assert_eq!(client.get_owner(&alices), bob);
+    assert_eq!(client.get_owner(&bobs), carol);
+    assert_eq!(client.get_owner(&carols), alice);
+    assert_eq!(client.swap_intents(), vec![&env, daves]);
+    assert_eq!(client.swap_intent(&alices), None);
+
+    // Unmatched intents wait for their owner to take them back.
+    assert_eq!(
+        client.try_cancel_intent(&alice, &daves),
+        Err(Ok(Error::NotOwner))
+    );
+    client.cancel_intent(&dave, &daves);
+    assert_eq!(client.get_owner(&daves), dave);
+    assert!(client.swap_intents().is_empty());
+    assert_eq!(
+        client.try_cancel_intent(&dave, &daves),
+        Err(Err(ExtError::NoSwapIntent.into()))
+    );
+}
+
+#[test]
+fn test_branding() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let stranger = Address::generate(&env);
+    let branding = Branding {
+        display_name: String::from_str(&env, "Grand Hotel Riviera"),
+        logo_uri: String::from_str(&env, "ipfs://logo"),
+        colors: vec![&env, 0x1A_2B3C, 0xFF_FFFF],
+        support_contact_hash: BytesN::from_array(&env, &[7; 32]),
+    };
+
+    assert_eq!(
+        client.try_set_branding(&stranger, &GRAND_HOTEL, &branding),
+        Err(Ok(Error::MissingRole))
+    );
+    assert_eq!(
+        client.try_set_branding(&admin, &99, &branding),
+        Err(Ok(Error::HotelNotFound))
+    );
+    let garish = Branding {
+        colors: vec![&env, 0x100_0000],
+        ..branding.clone()
+    };
+    assert_eq!(
+        client.try_set_branding(&admin, &GRAND_HOTEL, &garish),
+        Err(Err(ExtError::InvalidBranding.into()))
+    );
+
+    client.set_branding(&admin, &GRAND_HOTEL, &branding);
+    assert_eq!(client.branding(&GRAND_HOTEL), Some(branding.clone()));
+    assert_eq!(
+        client.config().brandings,
+        soroban_sdk::map![&env, (GRAND_HOTEL, branding)]
+    );
+    client.clear_branding(&admin, &GRAND_HOTEL);
+    assert_eq!(client.branding(&GRAND_HOTEL), None);
+    assert!(client.config().brandings.is_empty());
+}
+
+#[test]
+fn test_localized_labels() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let es = Symbol::new(&env, "es");
+    let platinum = LabelCode::Tier(Symbol::new(&env, "Platinum"));
+    let expired = LabelCode::ErrorCode(ExtError::OfferExpired as u32);
+    let high_season = LabelCode::Custom(Symbol::new(&env, "season_high"));
+
+    let mut labels = soroban_sdk::Map::new(&env);
+    labels.set(platinum.clone(), String::from_str(&env, "Platino"));
+    labels.set(
+        expired.clone(),
+        String::from_str(&env, "La oferta ha caducado"),
+    );
+    client.set_labels(&es, &labels);
+    assert_eq!(
+        client.label(&es, &platinum),
+        Some(String::from_str(&env, "Platino"))
+    );
+    assert_eq!(client.label(&es, &high_season), None);
+    assert_eq!(client.locales(), vec![&env, es.clone()]);
+    assert_eq!(client.config().locales, vec![&env, es.clone()]);
+
+    // Later calls keep the other labels; an empty label removes one.
+    let mut labels = soroban_sdk::Map::new(&env);
+    labels.set(
+        high_season.clone(),
+        String::from_str(&env, "Temporada alta"),
+    );
+    labels.set(expired, String::from_str(&env, ""));
+    client.set_labels(&es, &labels);
+    assert_eq!(
+        client.labels(&es).keys(),
+        vec![&env, high_season.clone(), platinum.clone()]
+    );
+
+    let mut labels = soroban_sdk::Map::new(&env);
+    labels.set(platinum.clone(), String::from_str(&env, &"x".repeat(65)));
+    assert_eq!(
+        client.try_set_labels(&es, &labels),
+        Err(Err(ExtError::LabelTooLong.into()))
+    );
+
+    // A locale without labels is no longer listed.
+    let mut labels = soroban_sdk::Map::new(&env);
+    labels.set(platinum, String::from_str(&env, ""));
+    labels.set(high_season, String::from_str(&env, ""));
+    client.set_labels(&es, &labels);
+    assert!(client.labels(&es).is_empty());
+    assert!(client.locales().is_empty());
+}
+
+#[test]
+fn test_late_fees_across_years() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let collector = Address::generate(&env);
+    let first = mint_one(&env, &client, &alice);
+    let second = mint_one(&env, &client, &bob);
+    let payment = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &payment).mint(&alice, &5_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+    env.ledger().set_timestamp(1_767_225_600); // 2026-01-01
+    client.set_maintenance_schedule(&MaintenanceSchedule {
+        token: payment.clone(),
+        fee: 1_000,
+        collector: collector.clone(),
+    });
+    const DAY: u64 = 86_400;
+    let policy = LateFeePolicy {
+        flat: 50,
+        rate_bps: 100,
+        period: 30 * DAY,
+        max: 200,
+        thresholds: vec![&env, 30 * DAY, 90 * DAY, 180 * DAY],
+    };
+    assert_eq!(
+        client.try_set_late_fee_policy(&Some(LateFeePolicy {
+            rate_bps: 10_001,
+            ..policy.clone()
+        })),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_late_fee_policy(&Some(policy.clone()));
+    assert_eq!(client.config().late_fees, LateFees::Enabled(policy));
+
+    // Each unpaid year accrues from its own due date: 2026 is 395 days
+    // overdue (flat fee plus 13 periods), 2027 only 30 (plus one period).
+    env.ledger().set_timestamp(1_803_945_600); // 2027-03-02
+    assert_eq!(client.maintenance_status(&first).unpaid_years, 2);
+    assert_eq!(client.late_fee(&first), 180 + 60);
+
+    // Delinquency counts from the oldest unpaid year.
+    assert_eq!(client.check_delinquency(&first), 3);
+    assert_eq!(
+        client.delinquency(&first),
+        Some(Delinquency {
+            year: 2026,
+            level: 3
+        })
+    );
+
+    let status = client.pay_maintenance(&alice, &first, &payment);
+    assert_eq!(status.paid_through, 2027);
+    assert_eq!(xlm.balance(&collector), 2_240);
+    assert_eq!(xlm.balance(&alice), 2_760);
+    assert_eq!(client.late_fee(&first), 0);
+    assert_eq!(client.delinquency(&first), None);
+
+    // Burning a token drops its recorded delinquency.
+    client.check_delinquency(&second);
+    assert!(client.delinquency(&second).is_some());
+    client.burn(&bob, &second);
+    assert_eq!(client.delinquency(&second), None);
+}
+
+#[test]
+fn test_grace_periods() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    const DAY: u64 = 86_400;
+    assert_eq!(
+        client.policy(),
+        soroban_sdk::map![
+            &env,
+            (GraceRule::PendingTransfer, PENDING_TRANSFER_WINDOW),
+            (GraceRule::SealedReveal, SEALED_REVEAL_WINDOW),
+            (GraceRule::Delinquency, MAINTENANCE_GRACE),
+            (GraceRule::FirstRefusal, FIRST_REFUSAL_WINDOW)
+        ]
+    );
+
+    // Changes wait out the timelock, and can be withdrawn until then.
+    client.propose_grace_period(&GraceRule::PendingTransfer, &DAY);
+    let proposed = GracePeriodProposed {
+        rule: GraceRule::PendingTransfer,
+        seconds: DAY,
+        effective_at: GRACE_POLICY_DELAY,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, proposed.topics(&env));
+    assert_eq!(data.to_xdr(&env), proposed.data(&env).to_xdr(&env));
+    assert_eq!(
+        client.try_execute_grace_period(&GraceRule::PendingTransfer),
+        Err(Ok(Error::TimelockNotElapsed))
+    );
+    client.cancel_grace_period(&GraceRule::PendingTransfer);
+    assert_eq!(
+        client.try_execute_grace_period(&GraceRule::PendingTransfer),
+        Err(Err(ExtError::NoPendingGracePeriod.into()))
+    );
+
+    client.propose_grace_period(&GraceRule::PendingTransfer, &DAY);
+    env.ledger().with_mut(|l| l.timestamp += GRACE_POLICY_DELAY);
+    client.execute_grace_period(&GraceRule::PendingTransfer);
+    assert_eq!(
+        client.pending_grace_period(&GraceRule::PendingTransfer),
+        None
+    );
+    assert_eq!(client.policy().get(GraceRule::PendingTransfer), Some(DAY));
+    assert_eq!(client.config().pending_transfer_window, DAY);
+    assert_eq!(client.config().grace_periods, client.policy());
+
+    // New windows take the new length.
+    client.set_transfer_confirmation(&alice, &true);
+    client.transfer(&alice, &bob, &token_id);
+    assert_eq!(
+        client.get_pending_transfer(&token_id).unwrap().expires_at,
+        GRACE_POLICY_DELAY + DAY
+    );
+}
+
+#[test]
+fn test_market_depth() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let gold = Some(Symbol::new(&env, "Gold"));
+    let xlm = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let ids = client.mint_batch(&alice, &units(&env, &client, 4));
+    let (first, second, third, fourth) = (
+        ids.get(0).unwrap(),
+        ids.get(1).unwrap(),
+        ids.get(2).unwrap(),
+        ids.get(3).unwrap(),
+    );
+    for token_id in [first, second, fourth] {
+        client.set_tier(&admin, &token_id, &gold);
+    }
+    client.list_for_sale(&alice, &first, &500, &xlm);
+    client.list_for_sale(&alice, &second, &300, &xlm);
+    client.list_for_sale(&alice, &third, &400, &xlm);
+    client.list_for_sale(&alice, &fourth, &100, &usdc);
+
+    // Each tier and asset has its own book.
+    assert_eq!(
+        client.market_depth(&GRAND_HOTEL, &gold, &xlm),
+        MarketDepth {
+            listings: 2,
+            floor: Some(300),
+            cheapest: vec![&env, second, first],
+        }
+    );
+    assert_eq!(client.floor_price(&GRAND_HOTEL, &None, &xlm), Some(400));
+    assert_eq!(client.floor_price(&GRAND_HOTEL, &gold, &usdc), Some(100));
+    assert_eq!(client.floor_price(&2, &gold, &xlm), None);
+
+    // Repricing, retiering, transfers and cancellations keep the books current.
+    client.list_for_sale(&alice, &second, &600, &xlm);
+    assert_eq!(client.floor_price(&GRAND_HOTEL, &gold, &xlm), Some(500));
+    client.set_tier(&admin, &first, &None);
+    assert_eq!(client.floor_price(&GRAND_HOTEL, &gold, &xlm), Some(600));
+    assert_eq!(
+        client.market_depth(&GRAND_HOTEL, &None, &xlm),
+        MarketDepth {
+            listings: 2,
+            floor: Some(400),
+            cheapest: vec![&env, third, first],
+        }
+    );
+    client.transfer(&alice, &bob, &third);
+    assert_eq!(client.floor_price(&GRAND_HOTEL, &None, &xlm), Some(500));
+    client.cancel_listing(&alice, &first);
+    assert_eq!(
+        client.market_depth(&GRAND_HOTEL, &None, &xlm),
+        MarketDepth {
+            listings: 0,
+            floor: None,
+            cheapest: Vec::new(&env),
+        }
+    );
+    client.burn(&alice, &second);
+    assert_eq!(client.market_depth(&GRAND_HOTEL, &gold, &xlm).listings, 0);
+}
+
+#[test]
+fn test_market_depth_untracked() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let xlm = Address::generate(&env);
+    let mut ids = client.mint_batch(&alice, &units(&env, &client, MAX_MINT_BATCH));
+    ids.append(&client.mint_batch(&alice, &units(&env, &client, 30)));
+    let count = MAX_DEPTH_TRACKED + 2;
+    for (i, token_id) in ids.iter().take(count as usize).enumerate() {
+        client.list_for_sale(&alice, &token_id, &(1_000 + i as i128), &xlm);
+    }
+    let depth = client.market_depth(&GRAND_HOTEL, &None, &xlm);
+    assert_eq!(depth.listings, count);
+    assert_eq!(depth.floor, Some(1_000));
+    assert_eq!(depth.cheapest.len(), MAX_DEPTH_TRACKED);
+
+    // A cheaper listing is tracked; once every tracked one is gone, the floor
+    // is unknown until the untracked ones are gone too.
+    let cheap = ids.get(count).unwrap();
+    client.list_for_sale(&alice, &cheap, &10, &xlm);
+    assert_eq!(client.floor_price(&GRAND_HOTEL, &None, &xlm), Some(10));
+    for token_id in client.market_depth(&GRAND_HOTEL, &None, &xlm).cheapest {
+        client.cancel_listing(&alice, &token_id);
+    }
+    let depth = client.market_depth(&GRAND_HOTEL, &None, &xlm);
+    assert_eq!((depth.listings, depth.floor), (3, None));
+    for token_id in ids.slice(MAX_DEPTH_TRACKED - 1..count) {
+        client.cancel_listing(&alice, &token_id);
+    }
+    client.list_for_sale(&alice, &cheap, &10, &xlm);
+    assert_eq!(client.floor_price(&GRAND_HOTEL, &None, &xlm), Some(10));
+}
+
+#[test]
+fn test_buyback() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let gold = Symbol::new(&env, "Gold");
+    let ids = client.mint_batch(&alice, &units(&env, &client, 4));
+    let (first, second, third, plain) = (
+        ids.get(0).unwrap(),
+        ids.get(1).unwrap(),
+        ids.get(2).unwrap(),
+        ids.get(3).unwrap(),
+    );
+    for token_id in [first, second, third] {
+        client.set_tier(&admin, &token_id, &Some(gold.clone()));
+    }
+    client.transfer(&alice, &bob, &third);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &payment).mint(&admin, &1_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+    env.ledger().set_timestamp(1_000);
+
+    assert_eq!(
+        client.try_fund_buyback(&GRAND_HOTEL, &gold, &payment, &0, &500, &1_000, &2_000),
+        Err(Err(ExtError::InvalidBuyback.into()))
+    );
+    assert_eq!(
+        client.try_fund_buyback(&GRAND_HOTEL, &gold, &payment, &200, &500, &2_000, &1_000),
+        Err(Err(ExtError::InvalidBuyback.into()))
+    );
+    assert_eq!(
+        client.try_sell_to_hotel(&alice, &first),
+        Err(Err(ExtError::NoBuyback.into()))
+    );
+
+    // A funded buyback holds its budget and pays owners of the tier from it.
+    client.fund_buyback(&GRAND_HOTEL, &gold, &payment, &200, &500, &1_500, &3_000);
+    assert_eq!(xlm.balance(&client.address), 500);
+    assert_eq!(
+        client.try_sell_to_hotel(&alice, &first),
+        Err(Err(ExtError::BuybackNotOpen.into()))
+    );
+    env.ledger().set_timestamp(1_500);
+    assert_eq!(
+        client.try_sell_to_hotel(&alice, &plain),
+        Err(Err(ExtError::NoBuyback.into()))
+    );
+    assert_eq!(client.sell_to_hotel(&alice, &first), 300);
+    assert_eq!(client.get_owner(&first), admin);
+    assert_eq!(xlm.balance(&alice), 200);
+
+    // The budget can't pay a third sale, so the buyback pauses itself.
+    assert_eq!(client.sell_to_hotel(&alice, &second), 100);
+    assert!(client.buyback(&GRAND_HOTEL, &gold).unwrap().exhausted);
+    assert_eq!(
+        client.try_sell_to_hotel(&bob, &third),
+        Err(Err(ExtError::BuybackExhausted.into()))
+    );
+    assert_eq!(
+        client.try_fund_buyback(
+            &GRAND_HOTEL,
+            &gold,
+            &Address::generate(&env),
+            &200,
+            &100,
+            &1_500,
+            &3_000
+        ),
+        Err(Err(ExtError::InvalidBuyback.into()))
+    );
+
+    // A top-up reopens it.
+    let buyback = client.fund_buyback(&GRAND_HOTEL, &gold, &payment, &150, &100, &1_500, &3_000);
+    assert_eq!((buyback.budget, buyback.exhausted), (200, false));
+    assert_eq!(client.config().buybacks, vec![&env, buyback]);
+    assert_eq!(client.sell_to_hotel(&bob, &third), 50);
+    assert_eq!(xlm.balance(&bob), 150);
+
+    // Closing refunds the rest of the budget.
+    client.close_buyback(&GRAND_HOTEL, &gold);
+    assert_eq!(xlm.balance(&admin), 450);
+    assert_eq!(xlm.balance(&client.address), 0);
+    assert_eq!(client.buyback(&GRAND_HOTEL, &gold), None);
+    assert_eq!(client.buybacks(), Vec::new(&env));
+    assert_eq!(
+        client.try_close_buyback(&GRAND_HOTEL, &gold),
+        Err(Err(ExtError::NoBuyback.into()))
+    );
+}
+
+#[test]
+fn test_first_refusal() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let ids = client.mint_batch(&alice, &units(&env, &client, 3));
+    let (first, second, third) = (
+        ids.get(0).unwrap(),
+        ids.get(1).unwrap(),
+        ids.get(2).unwrap(),
+    );
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let payment = sac.address();
+    let sac_admin = soroban_sdk::token::StellarAssetClient::new(&env, &payment);
+    sac_admin.mint(&bob, &1_000);
+    sac_admin.mint(&carol, &1_000);
+    sac_admin.mint(&admin, &1_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+    env.ledger().set_timestamp(1_000);
+    client.set_first_refusal(&GRAND_HOTEL, &true);
+    assert_eq!(
+        client.config().first_refusal_hotels,
+        vec![&env, GRAND_HOTEL]
+    );
+
+    // An accepted offer waits in custody for the hotel's window.
+    client.make_offer(&bob, &first, &payment, &300, &10_000);
+    client.accept_offer(&alice, &first, &bob);
+    let ends_at = 1_000 + FIRST_REFUSAL_WINDOW;
+    assert_eq!(
+        client.first_refusal(&first),
+        Some(FirstRefusal {
+            seller: alice.clone(),
+            buyer: bob.clone(),
+            offer: Offer {
+                payment_token: payment.clone(),
+                amount: 300,
+                expires_at: 10_000,
+            },
+            ends_at,
+        })
+    );
+    assert_eq!(client.get_owner(&first), client.address);
+    assert_eq!(
+        client.try_complete_first_refusal(&bob, &first),
+        Err(Err(ExtError2::FirstRefusalOpen.into()))
+    );
+
+    // The hotel matches it: the seller is paid, the buyer refunded.
+    client.exercise_first_refusal(&first);
+    assert_eq!(client.get_owner(&first), admin);
+    assert_eq!(
+        (xlm.balance(&alice), xlm.balance(&bob), xlm.balance(&admin)),
+        (300, 1_000, 700)
+    );
+    assert_eq!(client.first_refusal(&first), None);
+
+    // Unmatched, the sale falls through to the buyer once the window lapses.
+    client.make_offer(&carol, &second, &payment, &200, &10_000);
+    client.accept_offer(&alice, &second, &carol);
+    env.ledger().set_timestamp(ends_at + 1);
+    assert_eq!(
+        client.try_exercise_first_refusal(&second),
+        Err(Err(ExtError2::FirstRefusalLapsed.into()))
+    );
+    client.complete_first_refusal(&bob, &second);
+    assert_eq!(client.get_owner(&second), carol);
+    assert_eq!((xlm.balance(&alice), xlm.balance(&carol)), (500, 800));
+    assert_eq!(
+        client.try_complete_first_refusal(&bob, &second),
+        Err(Err(ExtError2::NoFirstRefusal.into()))
+    );
+
+    // A token burned in custody refunds the buyer; turned off, offers settle at once.
+    client.make_offer(&bob, &third, &payment, &100, &20_000_000);
+    client.accept_offer(&alice, &third, &bob);
+    client.admin_burn(&third);
+    assert_eq!(xlm.balance(&bob), 1_000);
+    client.set_first_refusal(&GRAND_HOTEL, &false);
+    assert_eq!(client.first_refusal_hotels(), Vec::new(&env));
+    client.make_offer(&bob, &first, &payment, &100, &20_000_000);
+    client.accept_offer(&admin, &first, &bob);
+    assert_eq!(client.get_owner(&first), bob);
+}
+
+// The entry points external marketplaces call on an NFT contract, with this
+// contract's u64 token IDs. Only the generated client is used.
+#[soroban_sdk::contractclient(name = "MarketplaceNftClient")]
+#[allow(dead_code)]
+pub trait MarketplaceNft {
+    fn balance(env: Env, account: Address) -> u32;
+    fn owner_of(env: Env, token_id: u64) -> Address;
+    fn transfer(env: Env, from: Address, to: Address, token_id: u64);
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, token_id: u64);
+    fn approve_for_all(env: Env, owner: Address, operator: Address, live_until_ledger: u32);
+    fn get_approved(env: Env, token_id: u64) -> Option<Address>;
+    fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool;
+    fn name(env: Env) -> String;
+    fn symbol(env: Env) -> String;
+    fn royalty_info(env: Env, token_id: u64, sale_price: i128) -> (Address, i128);
+}
+
+#[test]
+fn test_marketplace_adapter() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let market = Address::generate(&env);
+    let hotel = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let nft = MarketplaceNftClient::new(&env, &client.address);
+
+    assert_eq!(nft.name(), String::from_str(&env, COLLECTION_NAME));
+    assert_eq!(nft.symbol(), String::from_str(&env, COLLECTION_SYMBOL));
+    assert_eq!(nft.balance(&alice), 1);
+    assert_eq!(nft.owner_of(&token_id), alice);
+    assert_eq!(
+        nft.royalty_info(&token_id, &1_000),
+        (client.address.clone(), 0)
+    );
+    client.set_royalty(&admin, &GRAND_HOTEL, &hotel, &250);
+    assert_eq!(nft.royalty_info(&token_id, &1_000), (hotel, 25));
+
+    // A marketplace approved until a ledger may move the owner's tokens
+    // until then.
+    env.ledger().set_sequence_number(100);
+    assert_eq!(
+        client.try_approve_for_all(&alice, &market, &99),
+        Err(Ok(Error::InvalidAmount))
+    );
+    nft.approve_for_all(&alice, &market, &200);
+    assert!(nft.is_approved_for_all(&alice, &market));
+    assert_eq!(nft.get_approved(&token_id), None);
+    nft.transfer_from(&market, &alice, &bob, &token_id);
+    assert_eq!(nft.owner_of(&token_id), bob);
+    nft.transfer(&bob, &alice, &token_id);
+    env.ledger().set_sequence_number(201);
+    assert!(!nft.is_approved_for_all(&alice, &market));
+    assert_eq!(
+        client.try_transfer_from(&market, &alice, &bob, &token_id),
+        Err(Ok(Error::NotApproved))
+    );
+
+    // 0 revokes, and either call revokes an approval made with the other.
+    nft.approve_for_all(&alice, &market, &300);
+    nft.approve_for_all(&alice, &market, &0);
+    assert!(!nft.is_approved_for_all(&alice, &market));
+    nft.approve_for_all(&alice, &market, &300);
+    client.set_approval_for_all(&alice, &market, &false);
+    assert!(!nft.is_approved_for_all(&alice, &market));
+    assert_eq!(
+        client.try_transfer_from(&market, &alice, &bob, &token_id),
+        Err(Ok(Error::NotApproved))
+    );
+    client.set_approval_for_all(&alice, &market, &true);
+    nft.approve_for_all(&alice, &market, &0);
+    assert!(!nft.is_approved_for_all(&alice, &market));
+    assert_eq!(
+        client.try_transfer_from(&market, &alice, &bob, &token_id),
+        Err(Ok(Error::NotApproved))
+    );
+
+    // An expiring approval replaces a permanent one.
+    client.set_approval_for_all(&alice, &market, &true);
+    nft.approve_for_all(&alice, &market, &250);
+    env.ledger().set_sequence_number(251);
+    assert!(!nft.is_approved_for_all(&alice, &market));
+
+    // Royalties round like the payments they describe.
+    assert_eq!(nft.royalty_info(&token_id, &1_030).1, 26);
+    assert_eq!(nft.royalty_info(&token_id, &1_020).1, 26);
+    assert_eq!(nft.royalty_info(&token_id, &1_060).1, 26);
+}
+
+#[test]
+fn test_charity_donations() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let hotel = Address::generate(&env);
+    let shelter = Address::generate(&env);
+    let school = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &alice);
+    let payment = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &payment).mint(&bob, &10_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &payment);
+    env.ledger().set_timestamp(1_767_225_600); // 2026-01-01
+
+    // Only registered charities can be named, within the royalty.
+    let split = vec![&env, (shelter.clone(), 2_500), (school.clone(), 1_000)];
+    assert_eq!(
+        client.try_set_charity_split(&admin, &GRAND_HOTEL, &split),
+        Err(Err(ExtError2::NotCharity.into()))
+    );
+    client.register_charity(&shelter, &String::from_str(&env, "City Shelter"));
+    client.register_charity(&school, &String::from_str(&env, "Village School"));
+    assert_eq!(
+        client.charity(&shelter),
+        Some(String::from_str(&env, "City Shelter"))
+    );
+    assert_eq!(
+        client.try_set_charity_split(&admin, &GRAND_HOTEL, &vec![&env, (shelter.clone(), 10_001)]),
+        Err(Err(ExtError::InvalidSplit.into()))
+    );
+    client.set_charity_split(&admin, &GRAND_HOTEL, &split);
+    assert_eq!(client.charity_split(&GRAND_HOTEL), split);
+    assert_eq!(
+        client.config().charity_splits,
+        soroban_sdk::map![&env, (GRAND_HOTEL, split.clone())]
+    );
+
+    // A resale donates 25% and 10% of the 10% royalty; the hotel keeps the rest.
+    client.set_royalty(&admin, &GRAND_HOTEL, &hotel, &1_000);
+    client.list_for_sale(&alice, &token_id, &1_000, &payment);
+    client.buy(&bob, &token_id);
+    let donation = RoyaltyDonated {
+        token_id,
+        charity: shelter.clone(),
+        hotel_id: GRAND_HOTEL,
+        payment_token: payment.clone(),
+        amount: 25,
+    };
+    assert!(env.events().all().iter().any(|(_, topics, data)| {
+        topics == donation.topics(&env) && data.to_xdr(&env) == donation.data(&env).to_xdr(&env)
+    }));
+    assert_eq!(xlm.balance(&shelter), 25);
+    assert_eq!(xlm.balance(&school), 10);
+    assert_eq!(xlm.balance(&hotel), 65);
+    assert_eq!(xlm.balance(&alice), 900);
+
+    // Donations add up per charity, hotel, year and payment token.
+    client.make_offer(&alice, &token_id, &payment, &800, &u64::MAX);
+    client.accept_offer(&bob, &token_id, &alice);
+    assert_eq!(client.donated(&shelter, &2026, &payment), 45);
+    assert_eq!(client.donated(&school, &2026, &payment), 18);
+    assert_eq!(client.hotel_donated(&GRAND_HOTEL, &2026, &payment), 63);
+    assert_eq!(client.donated(&shelter, &2027, &payment), 0);
+
+    // A removed charity's share stays with the hotel.
+    client.remove_charity(&school);
+    assert_eq!(
+        client.try_remove_charity(&school),
+        Err(Err(ExtError2::NotCharity.into()))
+    );
+    client.list_for_sale(&alice, &token_id, &1_000, &payment);
+    client.buy(&bob, &token_id);
+    assert_eq!(xlm.balance(&school), 18);
+    assert_eq!(xlm.balance(&shelter), 70);
+    assert_eq!(xlm.balance(&hotel), 65 + 52 + 75);
+
+    // A charity can't be named twice.
+    client.register_charity(&school, &String::from_str(&env, "Village School"));
+    assert_eq!(
+        client.try_set_charity_split(
+            &admin,
+            &GRAND_HOTEL,
+            &vec![&env, (school.clone(), 1_000), (school.clone(), 1_000)]
+        ),
+        Err(Err(ExtError2::DuplicateCharity.into()))
+    );
+
+    // Shares of a small royalty add up to it exactly.
+    let halves = vec![&env, (shelter.clone(), 5_000), (school.clone(), 5_000)];
+    client.set_charity_split(&admin, &GRAND_HOTEL, &halves);
+    client.list_for_sale(&bob, &token_id, &30, &payment);
+    client.buy(&alice, &token_id);
+    assert_eq!(xlm.balance(&shelter), 72);
+    assert_eq!(xlm.balance(&school), 19);
+    assert_eq!(xlm.balance(&hotel), 65 + 52 + 75);
+    assert_eq!(
+        client.hotel_donated(&GRAND_HOTEL, &2026, &payment),
+        63 + 25 + 3
+    );
+
+    // An empty split donates nothing.
+    client.set_charity_split(&admin, &GRAND_HOTEL, &vec![&env]);
+    assert_eq!(client.charity_split(&GRAND_HOTEL), vec![&env]);
+}
+
+// A SEP-40 oracle publishing a `CPI` index, with readings set by the test.
+mod index_oracle {
+    use crate::{OracleAsset, PriceData};
+    use soroban_sdk::{contract, contractimpl, symbol_short, Env};
+
+    #[contract]
+    pub struct MockOracle;
+
+    #[contractimpl]
+    impl MockOracle {
+        pub fn set_reading(env: Env, price: i128, timestamp: u64) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("CPI"), &PriceData { price, timestamp });
+        }
+
+        pub fn lastprice(env: Env, asset: OracleAsset) -> Option<PriceData> {
+            assert_eq!(asset, OracleAsset::Other(symbol_short!("CPI")));
+            env.storage().instance().get(&symbol_short!("CPI"))
+        }
+    }
+}
+
+#[test]
+fn test_fee_index() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let collector = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    let oracle_id = env.register(index_oracle::MockOracle, ());
+    let oracle = index_oracle::MockOracleClient::new(&env, &oracle_id);
+    let schedule = MaintenanceSchedule {
+        token: sac.address(),
+        fee: 1_000,
+        collector: collector.clone(),
+    };
+    client.set_maintenance_schedule(&schedule);
+    env.ledger().set_timestamp(1_767_225_600); // 2026-01-01
+
+    // The fee follows the index only once linked, within sane caps.
+    assert_eq!(
+        client.try_index_maintenance_fee(),
+        Err(Err(ExtError2::NoFeeIndex.into()))
+    );
+    let index = FeeIndex {
+        oracle: oracle_id.clone(),
+        feed: Symbol::new(&env, "CPI"),
+        base_fee: 1_000,
+        base_index: 30_000,
+        max_step: 50,
+        min_fee: 900,
+        max_fee: 1_200,
+        max_age: 7 * 86_400,
+    };
+    assert_eq!(
+        client.try_set_fee_index(&Some(FeeIndex {
+            min_fee: 1_300,
+            ..index.clone()
+        })),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_fee_index(&Some(index.clone()));
+    assert_eq!(client.fee_index(), Some(index.clone()));
+
+    // While indexed, the admin can't reprice by hand.
+    assert_eq!(
+        client.try_set_maintenance_schedule(&MaintenanceSchedule {
+            fee: 2_000,
+            ..schedule.clone()
+        }),
+        Err(Err(ExtError2::FeeIndexed.into()))
+    );
+    client.set_maintenance_schedule(&schedule);
+
+    // A missing reading is refused.
+    assert_eq!(
+        client.try_index_maintenance_fee(),
+        Err(Err(ExtError2::StaleIndex.into()))
+    );
+
+    // A 4% rise in the index raises the fee 4%, once a year.
+    oracle.set_reading(&31_200, &1_767_225_000);
+    assert_eq!(client.index_maintenance_fee(), 1_040);
+    let indexed = MaintenanceFeeIndexed {
+        year: 2026,
+        index: 31_200,
+        previous: 1_000,
+        fee: 1_040,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, indexed.topics(&env));
+    assert_eq!(data.to_xdr(&env), indexed.data(&env).to_xdr(&env));
+    assert_eq!(client.maintenance_schedule().unwrap().fee, 1_040);
+    assert_eq!(client.fee_indexed_year(), Some(2026));
+    assert_eq!(
+        client.try_index_maintenance_fee(),
+        Err(Err(ExtError2::FeeAlreadyIndexed.into()))
+    );
+
+    // Last year's reading is too old; a 20% jump is capped at the step.
+    env.ledger().set_timestamp(1_798_761_600); // 2027-01-01
+    assert_eq!(
+        client.try_index_maintenance_fee(),
+        Err(Err(ExtError2::StaleIndex.into()))
+    );
+    oracle.set_reading(&36_000, &1_798_761_600);
+    assert_eq!(client.index_maintenance_fee(), 1_090);
+
+    // The ceiling holds whatever the step.
+    env.ledger().set_timestamp(1_830_297_600); // 2028-01-01
+    oracle.set_reading(&36_000, &1_830_297_600);
+    client.set_fee_index(&Some(FeeIndex {
+        max_step: 1_000,
+        max_fee: 1_100,
+        ..index.clone()
+    }));
+    assert_eq!(client.index_maintenance_fee(), 1_100);
+
+    // Unlinked, the fee is the admin's again.
+    client.set_fee_index(&None);
+    client.set_maintenance_schedule(&schedule);
+    assert_eq!(client.maintenance_schedule().unwrap().fee, 1_000);
+
+    // A waived fee rises by the step like any other, and starts billing.
+    client.set_maintenance_schedule(&MaintenanceSchedule {
+        fee: 0,
+        ..schedule.clone()
+    });
+    client.set_fee_index(&Some(FeeIndex {
+        min_fee: 0,
+        ..index
+    }));
+    env.ledger().set_timestamp(1_861_920_000); // 2029-01-01
+    oracle.set_reading(&36_000, &1_861_920_000);
+    assert_eq!(client.index_maintenance_fee(), 50);
+    let owner = Address::generate(&env);
+    let token_id = mint_one(&env, &client, &owner);
+    assert_eq!(client.maintenance_status(&token_id).unpaid_years, 1);
+
+    // Once this year's fees are being collected, the fee holds until the next.
+    env.ledger().set_timestamp(1_893_456_000); // 2030-01-01
+    oracle.set_reading(&36_000, &1_893_456_000);
+    soroban_sdk::token::StellarAssetClient::new(&env, &sac.address()).mint(&owner, &100);
+    client.pay_maintenance(&owner, &token_id, &sac.address());
+    assert_eq!(client.fee_billed_year(), Some(2030));
+    assert_eq!(
+        client.try_index_maintenance_fee(),
+        Err(Err(ExtError2::FeeYearBilled.into()))
+    );
+    env.ledger().set_timestamp(1_924_992_000); // 2031-01-01
+    oracle.set_reading(&36_000, &1_924_992_000);
+    assert_eq!(client.index_maintenance_fee(), 100);
+
+    let config = client.config();
+    assert_eq!(
+        config.fee_index,
+        FeeIndexing::Indexed(client.fee_index().unwrap())
+    );
+}
+
+#[test]
+fn test_force_majeure() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let insurer = Address::generate(&env);
+    let rented = mint_one(&env, &client, &alice);
+    let other_room = client.mint(
+        &carol,
+        &GRAND_HOTEL,
+        &String::from_str(&env, "Room 306"),
+        &28,
+        &None,
+        &None,
+    );
+    let next_week = mint_one(&env, &client, &carol);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let usdc = sac.address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &usdc).mint(&bob, &1_000);
+    soroban_sdk::token::StellarAssetClient::new(&env, &usdc).mint(&insurer, &1_000);
+    let xlm = soroban_sdk::token::Client::new(&env, &usdc);
+    env.ledger().set_timestamp(1_782_864_000); // 2026-07-01
+    client.list_for_rent(&alice, &rented, &usdc, &300, &1_783_641_600);
+    client.rent(&bob, &rented);
+
+    // Only oracles flag weeks, of this year or later.
+    let hurricane = Symbol::new(&env, "hurricane");
+    assert_eq!(
+        client.try_flag_force_majeure(&oracle, &GRAND_HOTEL, &2026, &28, &hurricane),
+        Err(Ok(Error::MissingRole))
+    );
+    client.grant_role(&Role::Oracle, &oracle);
+    assert_eq!(
+        client.try_flag_force_majeure(&oracle, &GRAND_HOTEL, &2026, &53, &hurricane),
+        Err(Ok(Error::InvalidWeek))
+    );
+    assert_eq!(
+        client.try_flag_force_majeure(&oracle, &GRAND_HOTEL, &2025, &28, &hurricane),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.flag_force_majeure(&oracle, &GRAND_HOTEL, &2026, &28, &hurricane);
+    let flagged = ForceMajeureFlagged {
+        hotel_id: GRAND_HOTEL,
+        year: 2026,
+        week: 28,
+        oracle: oracle.clone(),
+        reason: hurricane.clone(),
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, flagged.topics(&env));
+    assert_eq!(data.to_xdr(&env), flagged.data(&env).to_xdr(&env));
+    assert_eq!(
+        client.force_majeure(&GRAND_HOTEL, &2026, &28),
+        Some(ForceMajeureFlag {
+            oracle: oracle.clone(),
+            reason: hurricane,
+            flagged_at: 1_782_864_000,
+        })
+    );
+
+    // The closed week can't be booked or checked into.
+    client.list_for_rent(&carol, &other_room, &usdc, &300, &1_783_641_600);
+    assert_eq!(
+        client.try_rent(&bob, &other_room),
+        Err(Err(ExtError2::WeekClosed.into()))
+    );
+    assert_eq!(
+        client.try_check_in(&carol, &other_room),
+        Err(Err(ExtError2::WeekClosed.into()))
+    );
+    client.check_in(&carol, &next_week);
+
+    // Settling refunds the renter in full from the relief fund, once funded.
+    assert_eq!(
+        client.try_settle_force_majeure(&next_week),
+        Err(Err(ExtError2::NotForceMajeure.into()))
+    );
+    assert_eq!(
+        client.try_settle_force_majeure(&rented),
+        Err(Err(ExtError2::ReliefUnfunded.into()))
+    );
+    assert_eq!(client.fund_relief(&insurer, &GRAND_HOTEL, &usdc, &500), 500);
+    client.settle_force_majeure(&rented);
+    let settled = ForceMajeureSettled {
+        token_id: rented,
+        hotel_id: GRAND_HOTEL,
+        year: 2026,
+        week: 28,
+        owner: alice.clone(),
+        renter: Some(bob.clone()),
+        refunded: 300,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, settled.topics(&env));
+    assert_eq!(data.to_xdr(&env), settled.data(&env).to_xdr(&env));
+    assert_eq!(xlm.balance(&bob), 1_000);
+    assert_eq!(xlm.balance(&alice), 300);
+    assert_eq!(client.relief_fund(&GRAND_HOTEL, &usdc), 200);
+    assert_eq!(client.rental(&rented), None);
+    assert_eq!(client.force_majeure_settled(&rented), Some(2026));
+    assert_eq!(
+        client.try_settle_force_majeure(&rented),
+        Err(Err(ExtError2::AlreadySettled.into()))
+    );
+
+    // Owners accrue a replacement stay, redeemed by the front desk.
+    client.settle_force_majeure(&other_room);
+    assert_eq!(client.replacement_credits(&alice, &GRAND_HOTEL), 1);
+    assert_eq!(client.replacement_credits(&carol, &GRAND_HOTEL), 1);
+    assert_eq!(
+        client.try_redeem_replacement_credit(&bob, &alice, &GRAND_HOTEL),
+        Err(Ok(Error::MissingRole))
+    );
+    assert_eq!(
+        client.redeem_replacement_credit(&admin, &alice, &GRAND_HOTEL),
+        0
+    );
+    assert_eq!(
+        client.try_redeem_replacement_credit(&admin, &alice, &GRAND_HOTEL),
+        Err(Err(ExtError2::NoReplacementCredit.into()))
+    );
+
+    // The admin withdraws what is left; a lifted flag reopens the week.
+    assert_eq!(
+        client.try_withdraw_relief(&GRAND_HOTEL, &usdc, &insurer, &300),
+        Err(Err(ExtError2::ReliefUnfunded.into()))
+    );
+    assert_eq!(
+        client.withdraw_relief(&GRAND_HOTEL, &usdc, &insurer, &200),
+        0
+    );
+    assert_eq!(xlm.balance(&insurer), 700);
+    client.lift_force_majeure(&oracle, &GRAND_HOTEL, &2026, &28);
+    assert_eq!(
+        client.try_lift_force_majeure(&oracle, &GRAND_HOTEL, &2026, &28),
+        Err(Err(ExtError2::NotForceMajeure.into()))
+    );
+    client.check_in(&carol, &other_room);
+}
 
 #[test]
-fn test() {
+fn test_reminders() {
     let env = Env::default();
-    let contract_id = env.register(Contract, ());
-    let client = ContractClient::new(&env, &contract_id);
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let listed = mint_one(&env, &client, &alice);
+    let auctioned = mint_one(&env, &client, &bob);
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    let usdc = sac.address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &usdc).mint(&alice, &1_000);
+    env.ledger().set_timestamp(1_767_225_600); // 2026-01-01
+    client.set_maintenance_schedule(&MaintenanceSchedule {
+        token: usdc.clone(),
+        fee: 1_000,
+        collector: Address::generate(&env),
+    });
+    let due_2026 = 1_769_817_600; // 2026-01-31, after the Delinquency grace period
+
+    // Deadlines are listed ahead, but only reminded within REMINDER_LEAD.
+    env.ledger().set_timestamp(1_768_003_200); // 2026-01-10
+    let fee_due = Deadline {
+        kind: DeadlineKind::FeeDue,
+        at: due_2026,
+        party: alice.clone(),
+    };
+    assert_eq!(client.deadlines(&listed), vec![&env, fee_due.clone()]);
+    assert_eq!(client.send_reminders(&listed), 0);
+
+    // An auction ending in three days is reminded once, to its seller.
+    client.start_auction(&bob, &auctioned, &usdc, &100, &(3 * 86_400));
+    assert_eq!(client.send_reminders(&auctioned), 1);
+    let approaching = DeadlineApproaching {
+        token_id: auctioned,
+        kind: DeadlineKind::AuctionEnding,
+        party: bob.clone(),
+        at: 1_768_003_200 + 3 * 86_400,
+    };
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, approaching.topics(&env));
+    assert_eq!(data.to_xdr(&env), approaching.data(&env).to_xdr(&env));
+    assert_eq!(client.send_reminders(&auctioned), 0);
 
-    let words = client.hello(&String::from_str(&env, "Dev"));
+    // The fee falls within the lead; a lapsed booking window is dropped.
+    client.list_for_rent(&alice, &listed, &usdc, &300, &1_768_867_200);
+    env.ledger().set_timestamp(1_769_299_200); // 2026-01-25
+    assert_eq!(client.deadlines(&listed), vec![&env, fee_due]);
+    assert_eq!(client.send_reminders(&listed), 1);
+    assert_eq!(client.send_reminders(&listed), 0);
+
+    // Paying moves the fee to next year's due date.
+    client.pay_maintenance(&alice, &listed, &usdc);
     assert_eq!(
-        words,
+        client.deadlines(&listed),
         vec![
             &env,
-            String::from_str(&env, "Hello"),
-            String::from_str(&env, "Dev"),
+            Deadline {
+                kind: DeadlineKind::FeeDue,
+                at: 1_801_353_600, // 2027-01-31
+                party: alice.clone(),
+            }
         ]
     );
+
+    // A booking window that moves is reminded again.
+    client.list_for_rent(&alice, &listed, &usdc, &300, &1_769_558_400);
+    assert_eq!(client.send_reminders(&listed), 1);
+    client.list_for_rent(&alice, &listed, &usdc, &300, &1_769_731_200);
+    assert_eq!(client.send_reminders(&listed), 1);
+    assert_eq!(client.send_reminders(&listed), 0);
+}
+
+#[test]
+fn test_stats_privacy() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let xlm = Address::generate(&env);
+    let ids = client.mint_batch(&alice, &units(&env, &client, 3));
+    for token_id in ids.iter() {
+        client.list_for_sale(&alice, &token_id, &(100 * token_id as i128), &xlm);
+    }
+    assert_eq!(client.hotel_supply(&GRAND_HOTEL), 3);
+    assert_eq!(
+        client.stats_privacy(),
+        StatsPrivacy {
+            min_count: 0,
+            round_to: 1,
+        }
+    );
+
+    // Small counts read as 0, and their books show no prices.
+    assert_eq!(
+        client.try_set_stats_privacy(&StatsPrivacy {
+            min_count: 5,
+            round_to: 0,
+        }),
+        Err(Ok(Error::InvalidAmount))
+    );
+    let privacy = StatsPrivacy {
+        min_count: 5,
+        round_to: 1,
+    };
+    client.set_stats_privacy(&privacy);
+    assert_eq!(client.config().stats_privacy, privacy);
+    assert_eq!(client.hotel_supply(&GRAND_HOTEL), 0);
+    assert_eq!(
+        client.market_depth(&GRAND_HOTEL, &None, &xlm),
+        MarketDepth {
+            listings: 0,
+            floor: None,
+            cheapest: Vec::new(&env),
+        }
+    );
+    assert_eq!(client.floor_price(&GRAND_HOTEL, &None, &xlm), None);
+
+    // Counts at the threshold show, rounded down; caps still use exact ones.
+    client.mint_batch(&alice, &units(&env, &client, 4));
+    assert_eq!(client.hotel_supply(&GRAND_HOTEL), 7);
+    client.set_stats_privacy(&StatsPrivacy {
+        min_count: 5,
+        round_to: 5,
+    });
+    assert_eq!(client.hotel_supply(&GRAND_HOTEL), 5);
+    client.set_supply_cap(&GRAND_HOTEL, &7);
+    assert_eq!(
+        client.try_mint_batch(&alice, &units(&env, &client, 1)),
+        Err(Err(ExtError::SupplyCapReached.into()))
+    );
 }
@@ -0,0 +1,240 @@
+// Trust ownership.
+//
+// Mirrors how real deeds are often held: the trustee holds legal title (and is
+// the token's owner of record) on behalf of a beneficiary. While a token is in
+// trust, the trustee can only transfer it to an address the beneficiary has
+// acknowledged, and the beneficiary can only be replaced after a timelock.
+//
+// Placing a token in trust transfers it to the trustee through `move_token`,
+// so paused contracts and frozen, retired or expired tokens refuse it, and
+// the old owner's listings, auctions, approvals and pending transfer lapse.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env};
+
+use crate::{
+    check_owner, enter, events, move_token, DataKey, Error, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/// Delay (in seconds of ledger time) before a proposed beneficiary takes effect.
+pub const BENEFICIARY_TIMELOCK: u64 = 7 * 24 * 60 * 60;
+
+/**
+ * @title Trust
+ * @dev The parties of a token held in trust. The trustee is also the token's owner.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trust {
+    pub trustee: Address,
+    pub beneficiary: Address,
+}
+
+/**
+ * @title PendingBeneficiary
+ * @dev A beneficiary change waiting for its timelock to pass.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingBeneficiary {
+    pub beneficiary: Address,
+    pub effective_at: u64, // Ledger timestamp from which the change can be executed.
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrustCreated {
+    #[topic]
+    pub token_id: u64,
+    pub trustee: Address,
+    pub beneficiary: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeneficiaryChanged {
+    #[topic]
+    pub token_id: u64,
+    pub beneficiary: Address,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Places a token in trust. Title moves to the trustee, who becomes the
+     * owner, and a Transfer event is published.
+     * @param owner The current owner (must sign).
+     * @param token_id The token to place in trust.
+     * @param trustee The address that will hold title.
+     * @param beneficiary The address the token is held for.
+     */
+    pub fn place_in_trust(
+        env: Env,
+        owner: Address,
+        token_id: u64,
+        trustee: Address,
+        beneficiary: Address,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        enter(&env, "place_in_trust")?;
+
+        check_owner(&env, token_id, &owner)?;
+        if env.storage().persistent().has(&DataKey::Trust(token_id)) {
+            return Err(Error::AlreadyInTrust);
+        }
+        crate::sinks::check_recipient(&env, &trustee)?;
+
+        // Title moves like any transfer, with the same checks and bookkeeping.
+        move_token(&env, &owner, &trustee, token_id)?;
+        let trust = Trust {
+            trustee: trustee.clone(),
+            beneficiary: beneficiary.clone(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Trust(token_id), &trust);
+
+        events::emit(
+            &env,
+            &TrustCreated {
+                token_id,
+                trustee,
+                beneficiary,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the trust a token is held in, if any.
+     */
+    pub fn get_trust(env: Env, token_id: u64) -> Option<Trust> {
+        env.storage().persistent().get(&DataKey::Trust(token_id))
+    }
+
+    /**
+     * @dev Records the beneficiary's consent to the trustee transferring the token to `to`.
+     * @param beneficiary The current beneficiary (must sign).
+     * @param token_id The token held in trust.
+     * @param to The only recipient the trustee may transfer to.
+     */
+    pub fn acknowledge_transfer(
+        env: Env,
+        beneficiary: Address,
+        token_id: u64,
+        to: Address,
+    ) -> Result<(), Error> {
+        beneficiary.require_auth();
+        enter(&env, "acknowledge_transfer")?;
+
+        let trust = load_trust(&env, token_id)?;
+        if trust.beneficiary != beneficiary {
+            return Err(Error::NotBeneficiary);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::TrustAck(token_id), &to);
+        Ok(())
+    }
+
+    /**
+     * @dev Proposes a new beneficiary. It can be executed once BENEFICIARY_TIMELOCK has passed.
+     * A new proposal replaces any pending one.
+     * @param trustee The trustee (must sign).
+     */
+    pub fn propose_beneficiary(
+        env: Env,
+        trustee: Address,
+        token_id: u64,
+        beneficiary: Address,
+    ) -> Result<(), Error> {
+        trustee.require_auth();
+        enter(&env, "propose_beneficiary")?;
+
+        let trust = load_trust(&env, token_id)?;
+        if trust.trustee != trustee {
+            return Err(Error::NotTrustee);
+        }
+
+        let pending = PendingBeneficiary {
+            beneficiary,
+            effective_at: env.ledger().timestamp() + BENEFICIARY_TIMELOCK,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingBeneficiary(token_id), &pending);
+        Ok(())
+    }
+
+    /**
+     * @dev Applies a pending beneficiary change whose timelock has passed. Anyone can call this.
+     */
+    pub fn execute_beneficiary_change(env: Env, token_id: u64) -> Result<(), Error> {
+        enter(&env, "execute_beneficiary_change")?;
+        let mut trust = load_trust(&env, token_id)?;
+        let pending_key = DataKey::PendingBeneficiary(token_id);
+        let pending: PendingBeneficiary = env
+            .storage()
+            .persistent()
+            .get(&pending_key)
+            .ok_or(Error::NoPendingBeneficiary)?;
+        if env.ledger().timestamp() < pending.effective_at {
+            return Err(Error::TimelockNotElapsed);
+        }
+
+        trust.beneficiary = pending.beneficiary.clone();
+        env.storage()
+            .persistent()
+            .set(&DataKey::Trust(token_id), &trust);
+        env.storage().persistent().remove(&pending_key);
+        // An acknowledgment given by the previous beneficiary no longer counts.
+        env.storage()
+            .persistent()
+            .remove(&DataKey::TrustAck(token_id));
+
+        events::emit(
+            &env,
+            &BeneficiaryChanged {
+                token_id,
+                beneficiary: pending.beneficiary,
+            },
+        );
+        Ok(())
+    }
+}
+
+fn load_trust(env: &Env, token_id: u64) -> Result<Trust, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Trust(token_id))
+        .ok_or(Error::NotInTrust)
+}
+
+/// Called before a token changes hands. Tokens in trust may only go to the
+/// acknowledged recipient; the trust is then dissolved and the recipient holds
+/// the token outright.
+pub(crate) fn before_transfer(env: &Env, token_id: u64, to: &Address) -> Result<(), Error> {
+    if !env.storage().persistent().has(&DataKey::Trust(token_id)) {
+        return Ok(());
+    }
+
+    let ack: Option<Address> = env.storage().persistent().get(&DataKey::TrustAck(token_id));
+    if ack.as_ref() != Some(to) {
+        return Err(Error::TransferNotAcknowledged);
+    }
+
+    dissolve(env, token_id);
+    Ok(())
+}
+
+/// Removes a token's trust and everything attached to it.
+pub(crate) fn dissolve(env: &Env, token_id: u64) {
+    env.storage().persistent().remove(&DataKey::Trust(token_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TrustAck(token_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PendingBeneficiary(token_id));
+}
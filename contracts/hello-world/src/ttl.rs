@@ -0,0 +1,135 @@
+// Keeping token entries alive.
+//
+// Ledger entries expire unless their TTL is extended. Tokens in use stay alive
+// on their own: minting, transferring or reading a token (get_info) bumps its
+// entries to TOKEN_TTL_EXTEND_TO whenever they have fallen under
+// TOKEN_TTL_THRESHOLD. Anyone can bump a single token further with
+// `extend_token_ttl`.
+//
+// Owners holding many
+// deeds extend all of them with `bump_portfolio`, one page of their token list
+// per call, instead of bumping each token separately. Extending a TTL needs no
+// authorization, so anyone (a keeper, a wallet) may pay for it; owners can
+// prepay keepers through the rent pool (see rent.rs).
+
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::{
+    enter, migration, owner_of, storage, DataKey, Error, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/// Most tokens `bump_portfolio` covers per call, to bound its cost (each token
+/// has up to `token_keys` entries to check).
+pub const MAX_BUMP_PAGE: u32 = 20;
+
+/// TTL, in ledgers, under which mints, transfers and reads bump a token (~30 days).
+pub const TOKEN_TTL_THRESHOLD: u32 = 30 * 17_280;
+
+/// TTL, in ledgers, those automatic bumps extend a token to (~120 days).
+pub const TOKEN_TTL_EXTEND_TO: u32 = 120 * 17_280;
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Extends the TTL of all of a token's entries to at least `extend_to`
+     * ledgers (capped at the network's maximum TTL).
+     * @param token_id The token to keep alive.
+     */
+    pub fn extend_token_ttl(env: Env, token_id: u64, extend_to: u32) -> Result<(), Error> {
+        enter(&env, "extend_token_ttl")?;
+
+        owner_of(&env, token_id)?;
+        bump_token(&env, token_id, extend_to, extend_to);
+        Ok(())
+    }
+
+    /**
+     * @dev Extends the TTL of `owner`'s token entries, and of the indexes that
+     * refer to them, to at least `extend_to` ledgers (capped at the network's
+     * maximum TTL).
+     * @param page Which MAX_BUMP_PAGE-sized slice of tokens_of_owner() to cover, from 0.
+     * @return The next page to pass, or None once the whole portfolio is covered.
+     */
+    pub fn bump_portfolio(
+        env: Env,
+        owner: Address,
+        page: u32,
+        extend_to: u32,
+    ) -> Result<Option<u32>, Error> {
+        enter(&env, "bump_portfolio")?;
+        Ok(bump_page(&env, &owner, page, extend_to, extend_to).1)
+    }
+}
+
+/// Extends the TTL of one page of `owner`'s token entries, and of the owner's
+/// index, to `extend_to` (capped at the network's maximum) wherever it is below
+/// `threshold`. Returns how many tokens the page held and the next page, if any.
+pub(crate) fn bump_page(
+    env: &Env,
+    owner: &Address,
+    page: u32,
+    threshold: u32,
+    extend_to: u32,
+) -> (u32, Option<u32>) {
+    let tokens = HotelTimeshareContract::tokens_of_owner(env.clone(), owner.clone());
+    let start = page.saturating_mul(MAX_BUMP_PAGE);
+    let end = tokens.len().min(start.saturating_add(MAX_BUMP_PAGE));
+    if start >= end {
+        return (0, None);
+    }
+
+    let extend_to = extend_to.min(env.storage().max_ttl());
+    let threshold = threshold.min(extend_to);
+    for i in start..end {
+        bump_token(env, tokens.get_unchecked(i), threshold, extend_to);
+    }
+    env.storage().persistent().extend_ttl(
+        &DataKey::OwnedTokens(owner.clone()),
+        threshold,
+        extend_to,
+    );
+
+    let next = if end < tokens.len() {
+        Some(page + 1)
+    } else {
+        None
+    };
+    (end - start, next)
+}
+
+/// The automatic bump done by mint, transfer and get_info. It only covers the
+/// entries every token has (Info, Owner), so it never adds reads of keys that
+/// may not exist to the footprint.
+pub(crate) fn touch(env: &Env, token_id: u64) {
+    let extend_to = TOKEN_TTL_EXTEND_TO.min(env.storage().max_ttl());
+    for key in [DataKey::Info(token_id), DataKey::Owner(token_id)] {
+        migration::upgrade(env, &key);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TOKEN_TTL_THRESHOLD, extend_to);
+    }
+}
+
+/// Extends every existing entry of `token_id` to `extend_to` (capped at the
+/// network's maximum) wherever it is below `threshold`.
+fn bump_token(env: &Env, token_id: u64, threshold: u32, extend_to: u32) {
+    let extend_to = extend_to.min(env.storage().max_ttl());
+    let threshold = threshold.min(extend_to);
+    let persistent = env.storage().persistent();
+    for key in storage::token_keys(token_id) {
+        if persistent.has(&key) {
+            persistent.extend_ttl(&key, threshold, extend_to);
+        }
+    }
+    for key in storage::ext_token_keys(env, token_id) {
+        if persistent.has(&key) {
+            persistent.extend_ttl(&key, threshold, extend_to);
+        }
+    }
+    for key in storage::more_token_keys(env, token_id) {
+        if persistent.has(&key) {
+            persistent.extend_ttl(&key, threshold, extend_to);
+        }
+    }
+}
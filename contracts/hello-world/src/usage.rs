@@ -0,0 +1,206 @@
+// Check-in and usage of a token's week.
+//
+// Each year, a token's week goes through:
+//
+//     Unused --check_in--> CheckedIn --check_out--> Consumed
+//
+// The guest checks in on arrival, and the hotel's front desk (Manager role, see
+// roles.rs) confirms it with `confirm_check_in` once the guest is at the desk.
+// The guest can only check out of a confirmed stay; from then on the week is
+// used up for the year. Records are per calendar year (UTC, from the ledger
+// timestamp): a record from an earlier year reads as Unused. The guest is the
+// token's owner, or this year's renter if the week is rented out (rental.rs).
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env};
+
+use crate::{
+    enter, events, force_majeure, rental, roles, term, DataKey, Error, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient, Role,
+};
+
+/**
+ * @title UsageState
+ * @dev Where a token's week stands this year.
+ */
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum UsageState {
+    Unused,
+    CheckedIn,
+    Consumed,
+}
+
+/**
+ * @title Usage
+ * @dev A token's usage record for one year.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Usage {
+    /// The calendar year the record is for.
+    pub year: u32,
+    pub state: UsageState,
+    /// Whether the front desk confirmed the check-in.
+    pub confirmed: bool,
+}
+
+/**
+ * @title CheckedIn
+ * @dev Published when a guest checks in on a token's week.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckedIn {
+    #[topic]
+    pub token_id: u64,
+    pub guest: Address,
+}
+
+/**
+ * @title CheckInConfirmed
+ * @dev Published when the front desk confirms a check-in.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckInConfirmed {
+    #[topic]
+    pub token_id: u64,
+    pub by: Address,
+}
+
+/**
+ * @title CheckedOut
+ * @dev Published when a guest checks out, using up the week for the year.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckedOut {
+    #[topic]
+    pub token_id: u64,
+    pub guest: Address,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Checks in on this year's week of a token. Fails with
+     * InvalidUsageState unless the week is unused this year.
+     * @param guest The owner, or this year's renter (must sign).
+     */
+    pub fn check_in(env: Env, guest: Address, token_id: u64) -> Result<(), Error> {
+        guest.require_auth();
+        enter(&env, "check_in")?;
+
+        rental::check_guest(&env, token_id, &guest)?;
+        term::check_token(&env, token_id)?;
+        force_majeure::check_open(&env, token_id);
+        let mut usage = Self::usage(env.clone(), token_id)?;
+        if usage.state != UsageState::Unused {
+            return Err(Error::InvalidUsageState);
+        }
+        usage.state = UsageState::CheckedIn;
+        set_usage(&env, token_id, &usage);
+
+        events::emit(&env, &CheckedIn { token_id, guest });
+        Ok(())
+    }
+
+    /**
+     * @dev Confirms a guest's check-in at the front desk. Manager role (or admin).
+     * @param caller The front desk (must sign).
+     */
+    pub fn confirm_check_in(env: Env, caller: Address, token_id: u64) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "confirm_check_in")?;
+
+        let mut usage = Self::usage(env.clone(), token_id)?;
+        if usage.state != UsageState::CheckedIn || usage.confirmed {
+            return Err(Error::InvalidUsageState);
+        }
+        usage.confirmed = true;
+        set_usage(&env, token_id, &usage);
+
+        events::emit(
+            &env,
+            &CheckInConfirmed {
+                token_id,
+                by: caller,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Checks out of a confirmed stay; the week is then used up for the
+     * year. Fails with CheckInNotConfirmed before the front desk confirmed.
+     * @param guest The guest who checked in (must sign).
+     */
+    pub fn check_out(env: Env, guest: Address, token_id: u64) -> Result<(), Error> {
+        guest.require_auth();
+        enter(&env, "check_out")?;
+
+        rental::check_guest(&env, token_id, &guest)?;
+        let mut usage = Self::usage(env.clone(), token_id)?;
+        if usage.state != UsageState::CheckedIn {
+            return Err(Error::InvalidUsageState);
+        }
+        if !usage.confirmed {
+            return Err(Error::CheckInNotConfirmed);
+        }
+        usage.state = UsageState::Consumed;
+        set_usage(&env, token_id, &usage);
+
+        events::emit(&env, &CheckedOut { token_id, guest });
+        Ok(())
+    }
+
+    /**
+     * @dev Returns a token's usage record for the current year.
+     */
+    pub fn usage(env: Env, token_id: u64) -> Result<Usage, Error> {
+        Self::get_owner(env.clone(), token_id)?;
+        let year = current_year(&env);
+        let usage = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Usage(token_id))
+            .filter(|usage: &Usage| usage.year == year);
+        Ok(usage.unwrap_or(Usage {
+            year,
+            state: UsageState::Unused,
+            confirmed: false,
+        }))
+    }
+}
+
+/// The current calendar year (UTC) according to the ledger timestamp.
+pub(crate) fn current_year(env: &Env) -> u32 {
+    // Civil-from-days: count in 400-year eras starting on 1 March 0000.
+    let days = (env.ledger().timestamp() / 86_400) as i64 + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153; // 0 = March
+    let year = year_of_era + era * 400 + if month_index >= 10 { 1 } else { 0 };
+    year as u32
+}
+
+/// The ledger timestamp at which a calendar year (UTC) begins.
+pub(crate) fn year_start(year: u32) -> u64 {
+    // Days-from-civil for 1 January, which falls in the era's previous year
+    // when counting from 1 March.
+    let year = year as i64 - 1;
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_era = 365 * year_of_era + year_of_era / 4 - year_of_era / 100 + 306;
+    let days = era * 146_097 + day_of_era - 719_468;
+    days.max(0) as u64 * 86_400
+}
+
+pub(crate) fn set_usage(env: &Env, token_id: u64, usage: &Usage) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Usage(token_id), usage);
+}
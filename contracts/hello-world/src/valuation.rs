@@ -0,0 +1,239 @@
+// Portfolio valuation.
+//
+// Wallets and lenders value an owner's holdings with one call,
+// `portfolio_value`, instead of pricing every token themselves. A token is
+// valued from two sources:
+//
+// - Its sales. Every sale (see receipts.rs) updates the token's PriceMark: the
+//   last price and a time-weighted average of the prices it has sold at. Each
+//   price weighs in for as long as it stood, over a VALUATION_WINDOW, so a
+//   single odd sale moves the average only gradually and a price that has
+//   stood for the whole window is the average.
+// - Appraisals. A manager can appraise a token with `set_appraisal`, e.g. a
+//   week that hasn't traded in years.
+//
+// Whichever of the two is more recent values the token, and only in the asset
+// it was quoted in: a token that last sold for another asset is worth 0 in
+// this one, as the contract keeps no exchange rates. `portfolio_value` adds up
+// the tokens the owner holds (see enumeration.rs) and their share of every
+// fractionalized token they hold shares of (see shares.rs). The contract has
+// no staking, so there are no staked tokens to count.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env};
+
+use crate::{
+    enter, events, money, or_fail, owner_of, roles, shares, Amount, Error, ExtKey,
+    HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient, Role,
+};
+
+/// Seconds over which a token's sale prices are averaged (30 days).
+pub const VALUATION_WINDOW: u64 = 30 * 24 * 60 * 60;
+
+/**
+ * @title PriceMark
+ * @dev What a token has sold for.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceMark {
+    /// The asset of the last sale. A sale in another asset starts afresh.
+    pub asset: Address,
+    /// The last sale price.
+    pub price: i128,
+    /// The time-weighted average price up to the last sale.
+    pub average: i128,
+    /// Ledger timestamp of the last sale.
+    pub sold_at: u64,
+}
+
+/**
+ * @title Appraisal
+ * @dev A manager's appraisal of a token.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Appraisal {
+    pub asset: Address,
+    pub value: i128,
+    /// Ledger timestamp of the appraisal.
+    pub appraised_at: u64,
+}
+
+/**
+ * @title Appraised
+ * @dev Published when a manager appraises a token.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Appraised {
+    #[topic]
+    pub token_id: u64,
+    pub asset: Address,
+    pub value: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Appraises a token, replacing any earlier appraisal. Manager role
+     * (or admin). It values the token until the token next sells.
+     * @param caller The manager (must sign).
+     * @param value In the asset's base units; must not be negative.
+     */
+    pub fn set_appraisal(
+        env: Env,
+        caller: Address,
+        token_id: u64,
+        asset: Address,
+        value: i128,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Manager, &caller)?;
+        enter(&env, "set_appraisal")?;
+
+        owner_of(&env, token_id)?;
+        if value < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage().persistent().set(
+            &ExtKey::Appraisal(token_id),
+            &Appraisal {
+                asset: asset.clone(),
+                value,
+                appraised_at: env.ledger().timestamp(),
+            },
+        );
+
+        events::emit(
+            &env,
+            &Appraised {
+                token_id,
+                asset,
+                value,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns a token's appraisal, if it has one.
+     */
+    pub fn appraisal(env: Env, token_id: u64) -> Option<Appraisal> {
+        env.storage().persistent().get(&ExtKey::Appraisal(token_id))
+    }
+
+    /**
+     * @dev Returns what a token has sold for, if it has sold.
+     */
+    pub fn price_mark(env: Env, token_id: u64) -> Option<PriceMark> {
+        env.storage().persistent().get(&ExtKey::PriceMark(token_id))
+    }
+
+    /**
+     * @dev Returns a token's value in an asset: its appraisal or its
+     * time-weighted average sale price, whichever is more recent, or 0 if
+     * neither is in that asset.
+     */
+    pub fn token_value(env: Env, token_id: u64, asset: Address) -> i128 {
+        let appraisal = Self::appraisal(env.clone(), token_id);
+        let mark = Self::price_mark(env.clone(), token_id);
+        let appraisal_is_newer = match (&appraisal, &mark) {
+            (Some(appraisal), Some(mark)) => appraisal.appraised_at >= mark.sold_at,
+            (appraisal, _) => appraisal.is_some(),
+        };
+        if appraisal_is_newer {
+            let appraisal = appraisal.unwrap();
+            return if appraisal.asset == asset {
+                appraisal.value
+            } else {
+                0
+            };
+        }
+        match mark {
+            Some(mark) if mark.asset == asset => {
+                let elapsed = env.ledger().timestamp().saturating_sub(mark.sold_at);
+                average(&env, mark.average, mark.price, elapsed)
+            }
+            _ => 0,
+        }
+    }
+
+    /**
+     * @dev Returns the value in an asset of everything an owner holds: their
+     * tokens, and their part of each fractionalized token they hold shares
+     * of, rounded half to even.
+     */
+    pub fn portfolio_value(env: Env, owner: Address, asset: Address) -> i128 {
+        let mut total = Amount::ZERO;
+        for token_id in Self::tokens_of_owner(env.clone(), owner.clone()) {
+            let value = Self::token_value(env.clone(), token_id, asset.clone());
+            total = or_fail(&env, Amount::of(value).and_then(|v| total.checked_add(v)));
+        }
+        for token_id in shares::held_by(&env, &owner) {
+            let Some(fraction) = Self::fraction(env.clone(), token_id) else {
+                continue;
+            };
+            let held = Self::share_balance(env.clone(), token_id, owner.clone());
+            let value = Self::token_value(env.clone(), token_id, asset.clone());
+            let part = or_fail(
+                &env,
+                Amount::of(value).and_then(|value| {
+                    money::prorate(value, held as i128, fraction as i128, Amount::ONE)
+                }),
+            );
+            total = or_fail(&env, total.checked_add(part));
+        }
+        total.get()
+    }
+}
+
+/// Records that a token sold for `price` in `asset`.
+pub(crate) fn mark(env: &Env, token_id: u64, asset: &Address, price: i128) {
+    let now = env.ledger().timestamp();
+    let average = match HotelTimeshareContract::price_mark(env.clone(), token_id) {
+        Some(mark) if mark.asset == *asset => average(
+            env,
+            mark.average,
+            mark.price,
+            now.saturating_sub(mark.sold_at),
+        ),
+        _ => price,
+    };
+    env.storage().persistent().set(
+        &ExtKey::PriceMark(token_id),
+        &PriceMark {
+            asset: asset.clone(),
+            price,
+            average,
+            sold_at: now,
+        },
+    );
+}
+
+/// Drops a token's price mark and appraisal, when the token is burned.
+pub(crate) fn clear(env: &Env, token_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&ExtKey::PriceMark(token_id));
+    env.storage()
+        .persistent()
+        .remove(&ExtKey::Appraisal(token_id));
+}
+
+/// Moves `average` towards `price` as if `price` had stood for `elapsed`
+/// seconds of the VALUATION_WINDOW.
+fn average(env: &Env, average: i128, price: i128, elapsed: u64) -> i128 {
+    if elapsed >= VALUATION_WINDOW {
+        return price;
+    }
+    let gap = or_fail(
+        env,
+        Amount::of((price - average).abs()).and_then(|gap| {
+            money::prorate(gap, elapsed as i128, VALUATION_WINDOW as i128, Amount::ONE)
+        }),
+    );
+    if price >= average {
+        average + gap.get()
+    } else {
+        average - gap.get()
+    }
+}
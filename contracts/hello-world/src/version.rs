@@ -0,0 +1,109 @@
+// Version and build information.
+//
+// Lets integrators gate behavior on the contract version, and lets auditors see
+// exactly which code was live at any ledger by walking the upgrade log.
+//
+// The admin ships fixes with `upgrade`, which swaps the code in place: the
+// contract address and all stored state are kept. If the new build changes
+// the storage layout, call `migrate()` right after (see migration.rs).
+
+use soroban_sdk::{
+    contractevent, contractimpl, contracttype, Address, BytesN, Env, Executable, String, Vec,
+};
+
+use crate::{
+    enter, events, require_admin, storage, DataKey, Error, HotelTimeshareContract,
+    HotelTimeshareContractArgs, HotelTimeshareContractClient,
+};
+
+/**
+ * @title UpgradeRecord
+ * @dev One entry in the on-chain upgrade log.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeRecord {
+    pub wasm_hash: BytesN<32>, // Hash of the code installed by this upgrade.
+    pub ledger: u32,           // Ledger sequence in which the upgrade happened.
+    pub scheduler: Address,    // The admin who performed it.
+}
+
+/**
+ * @title VersionInfo
+ * @dev Returned by version_info().
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionInfo {
+    pub version: String,               // Semantic version of the running build.
+    pub wasm_hash: Option<BytesN<32>>, // Hash of the running code (None outside of wasm).
+    pub upgrades: Vec<UpgradeRecord>,  // Every upgrade so far, oldest first.
+}
+
+/**
+ * @title Upgraded
+ * @dev Published when the admin replaces the contract's code.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Upgraded {
+    #[topic]
+    pub wasm_hash: BytesN<32>,
+    pub ledger: u32,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Replaces the contract's code, keeping its address and state. Admin only.
+     * The new code runs from the next invocation on.
+     * @param new_wasm_hash Hash of wasm already uploaded to the network.
+     */
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+        enter(&env, "upgrade")?;
+
+        let ledger = env.ledger().sequence();
+        let mut history = upgrade_history(&env);
+        history.push_back(UpgradeRecord {
+            wasm_hash: new_wasm_hash.clone(),
+            ledger,
+            scheduler: admin,
+        });
+        storage::set_shared(&env, &DataKey::UpgradeHistory, &history);
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        events::emit(
+            &env,
+            &Upgraded {
+                wasm_hash: new_wasm_hash,
+                ledger,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the semantic version, the hash of the running code, and the upgrade log.
+     */
+    pub fn version_info(env: Env) -> VersionInfo {
+        let wasm_hash = match env.current_contract_address().executable() {
+            Some(Executable::Wasm(hash)) => Some(hash),
+            _ => None,
+        };
+
+        VersionInfo {
+            version: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            wasm_hash,
+            upgrades: upgrade_history(&env),
+        }
+    }
+}
+
+pub(crate) fn upgrade_history(env: &Env) -> Vec<UpgradeRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UpgradeHistory)
+        .unwrap_or_else(|| Vec::new(env))
+}
@@ -0,0 +1,158 @@
+// Lazy minting.
+//
+// Rather than minting and paying for all of its inventory up front, the hotel
+// signs vouchers off-chain: a week of a room, its price, and optionally the
+// only buyer allowed to use it. A buyer presents the voucher and its signature
+// to `redeem_voucher`, which pays the price to the admin and mints the token
+// to the buyer in the same call.
+//
+// Vouchers are signed with an ed25519 key the admin registers with
+// `set_voucher_signer`, over the XDR of (this contract's address, voucher), so
+// a voucher for one deployment can't be replayed on another. Each voucher is
+// redeemed at most once, even if the token it minted is later burned, and the
+// token is minted like any other (see `issue_token`), so the week must be
+// unsold and its hotel and room active.
+
+use soroban_sdk::{
+    contractevent, contractimpl, contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env,
+};
+
+use crate::{
+    enter, enter_by, events, fail, ids, issue_token, pause, refunds, require_admin, Error,
+    ExtError, ExtKey, HotelTimeshareContract, HotelTimeshareContractArgs,
+    HotelTimeshareContractClient, TimeshareInfo, ADMIN,
+};
+
+/**
+ * @title Voucher
+ * @dev The admin's signed promise to mint a week for a price.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Voucher {
+    /// The token to mint.
+    pub info: TimeshareInfo,
+    pub price: i128,
+    /// Token the price is paid in.
+    pub payment_token: Address,
+    /// The only buyer who may redeem it, or None for anyone.
+    pub buyer: Option<Address>,
+    /// Ledger timestamp after which it can't be redeemed.
+    pub expires_at: u64,
+}
+
+/**
+ * @title VoucherRedeemed
+ * @dev Published when a voucher is redeemed, next to the Mint event.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoucherRedeemed {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub buyer: Address,
+    pub price: i128,
+    pub payment_token: Address,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Registers the ed25519 public key vouchers must be signed with,
+     * replacing any earlier one. Admin only.
+     */
+    pub fn set_voucher_signer(env: Env, public_key: BytesN<32>) -> Result<(), Error> {
+        require_admin(&env)?;
+        enter(&env, "set_voucher_signer")?;
+
+        env.storage()
+            .persistent()
+            .set(&ExtKey::VoucherSigner, &public_key);
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the key vouchers are signed with, if one is registered.
+     */
+    pub fn voucher_signer(env: Env) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&ExtKey::VoucherSigner)
+    }
+
+    /**
+     * @dev Redeems a voucher: the buyer pays its price to the admin and is
+     * minted its token. Fails with NoVoucherSigner before a signer is
+     * registered, WrongBuyer if the voucher names another buyer, and
+     * VoucherExpired or VoucherRedeemed once it can't be used. A bad signature
+     * traps.
+     * @param buyer The buyer (must sign and pay).
+     * @param signature The signer's ed25519 signature of the voucher.
+     * @return The new token ID.
+     */
+    pub fn redeem_voucher(
+        env: Env,
+        buyer: Address,
+        voucher: Voucher,
+        signature: BytesN<64>,
+    ) -> Result<u64, Error> {
+        buyer.require_auth();
+        enter_by(&env, "redeem_voucher", &buyer)?;
+        pause::check(&env)?;
+
+        // 1. Only a genuine, unused voucher, by the buyer it names.
+        let signer = Self::voucher_signer(env.clone())
+            .unwrap_or_else(|| fail(&env, ExtError::NoVoucherSigner));
+        let message = Self::voucher_message(env.clone(), voucher.clone());
+        env.crypto().ed25519_verify(&signer, &message, &signature);
+        if voucher.buyer.as_ref().is_some_and(|named| *named != buyer) {
+            fail(&env, ExtError::WrongBuyer);
+        }
+        if voucher.price < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let id: BytesN<32> = env.crypto().sha256(&message).into();
+        let key = ExtKey::Voucher(id);
+        if env.ledger().timestamp() > voucher.expires_at {
+            fail(&env, ExtError::VoucherExpired);
+        }
+        if env.storage().persistent().has(&key) {
+            fail(&env, ExtError::VoucherRedeemed);
+        }
+
+        // 2. Take payment and mint from the admin's ID block.
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&ADMIN)
+            .ok_or(Error::NotInitialized)?;
+        let token_id = ids::allocate(&env, &admin, 1)?.get_unchecked(0);
+        issue_token(&env, token_id, &buyer, voucher.info)?;
+        refunds::collect(
+            &env,
+            token_id,
+            &voucher.payment_token,
+            &buyer,
+            &admin,
+            voucher.price,
+        );
+        env.storage().persistent().set(&key, &token_id);
+
+        events::emit(
+            &env,
+            &VoucherRedeemed {
+                token_id,
+                buyer,
+                price: voucher.price,
+                payment_token: voucher.payment_token,
+            },
+        );
+        Ok(token_id)
+    }
+
+    /**
+     * @dev Returns the bytes a voucher's signature must cover, for signers.
+     */
+    pub fn voucher_message(env: Env, voucher: Voucher) -> Bytes {
+        (env.current_contract_address(), voucher).to_xdr(&env)
+    }
+}
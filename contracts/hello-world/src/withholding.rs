@@ -0,0 +1,304 @@
+// Tax withholding on resales.
+//
+// Some jurisdictions require a share of a property sale to be withheld from
+// the seller and paid to the tax authority. A compliance officer (Compliance
+// role) sets a property's WithholdingRule: the jurisdiction, the share of the
+// sale price, and the address it is paid to. The contract's resale paths pay
+// it at settlement through `royalties::pay`, out of the seller's proceeds
+// after the royalty. Primary sales are the hotel's own and withhold nothing.
+//
+// What was withheld from each seller is added up per calendar year and
+// payment token, for `withheld` to report. Sellers with an exemption
+// certificate for the jurisdiction are registered with `register_exemption`
+// and are paid in full until it runs out.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, token, Address, Env, Map, Symbol};
+
+use crate::{
+    enter, events, hotels, money, or_fail, roles, usage, Amount, Bps, Error, ExtKey,
+    HotelTimeshareContract, HotelTimeshareContractArgs, HotelTimeshareContractClient, Role,
+};
+
+/// Basis points in a whole: withholding FULL_WITHHOLDING_BPS takes the full price.
+pub const FULL_WITHHOLDING_BPS: u32 = 10_000;
+
+/**
+ * @title WithholdingRule
+ * @dev What a property's resales withhold from the seller.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithholdingRule {
+    /// The jurisdiction whose rules apply, e.g. `ES` or `US_FIRPTA`.
+    pub jurisdiction: Symbol,
+    /// The share withheld, in basis points of the sale price.
+    pub bps: u32,
+    /// Receives what is withheld.
+    pub recipient: Address,
+}
+
+/**
+ * @title WithholdingSet
+ * @dev Published when a property's withholding rule changes or is cleared
+ * (`bps` 0).
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithholdingSet {
+    #[topic]
+    pub hotel_id: u32,
+    pub jurisdiction: Symbol,
+    pub bps: u32,
+}
+
+/**
+ * @title ExemptionRegistered
+ * @dev Published when a seller's exemption certificate is registered.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExemptionRegistered {
+    #[topic]
+    pub seller: Address,
+    #[topic]
+    pub jurisdiction: Symbol,
+    pub valid_until: u64,
+}
+
+/**
+ * @title ExemptionRevoked
+ * @dev Published when a seller's exemption certificate is withdrawn.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExemptionRevoked {
+    #[topic]
+    pub seller: Address,
+    #[topic]
+    pub jurisdiction: Symbol,
+}
+
+/**
+ * @title TaxWithheld
+ * @dev Published when a resale withholds part of the seller's proceeds.
+ */
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaxWithheld {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub seller: Address,
+    pub jurisdiction: Symbol,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+#[contractimpl]
+impl HotelTimeshareContract {
+    /**
+     * @dev Sets the withholding rule of a property's resales. Compliance role
+     * (or admin).
+     * @param caller The compliance officer (must sign).
+     * @param bps The share withheld, at most FULL_WITHHOLDING_BPS; 0 clears
+     * the rule.
+     */
+    pub fn set_withholding(
+        env: Env,
+        caller: Address,
+        hotel_id: u32,
+        jurisdiction: Symbol,
+        bps: u32,
+        recipient: Address,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Compliance, &caller)?;
+        enter(&env, "set_withholding")?;
+
+        hotels::check(&env, hotel_id)?;
+        if bps > FULL_WITHHOLDING_BPS {
+            return Err(Error::InvalidAmount);
+        }
+        if bps == 0 {
+            env.storage()
+                .persistent()
+                .remove(&ExtKey::Withholding(hotel_id));
+        } else {
+            env.storage().persistent().set(
+                &ExtKey::Withholding(hotel_id),
+                &WithholdingRule {
+                    jurisdiction: jurisdiction.clone(),
+                    bps,
+                    recipient,
+                },
+            );
+        }
+
+        events::emit(
+            &env,
+            &WithholdingSet {
+                hotel_id,
+                jurisdiction,
+                bps,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns the withholding rule of a property's resales, if any.
+     */
+    pub fn withholding(env: Env, hotel_id: u32) -> Option<WithholdingRule> {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::Withholding(hotel_id))
+    }
+
+    /**
+     * @dev Returns the withholding rules of every property that has one, by
+     * hotel ID.
+     */
+    pub fn withholdings(env: Env) -> Map<u32, WithholdingRule> {
+        let mut rules = Map::new(&env);
+        for hotel_id in 1..=hotels::hotel_count(&env) {
+            if let Some(rule) = Self::withholding(env.clone(), hotel_id) {
+                rules.set(hotel_id, rule);
+            }
+        }
+        rules
+    }
+
+    /**
+     * @dev Registers a seller's exemption certificate for a jurisdiction.
+     * Compliance role (or admin). Registering again replaces the expiry.
+     * @param caller The compliance officer (must sign).
+     * @param valid_until Ledger timestamp the certificate is valid through.
+     */
+    pub fn register_exemption(
+        env: Env,
+        caller: Address,
+        seller: Address,
+        jurisdiction: Symbol,
+        valid_until: u64,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Compliance, &caller)?;
+        enter(&env, "register_exemption")?;
+
+        env.storage().persistent().set(
+            &ExtKey::WithholdingExempt(seller.clone(), jurisdiction.clone()),
+            &valid_until,
+        );
+        events::emit(
+            &env,
+            &ExemptionRegistered {
+                seller,
+                jurisdiction,
+                valid_until,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Withdraws a seller's exemption certificate. Compliance role (or
+     * admin).
+     * @param caller The compliance officer (must sign).
+     */
+    pub fn revoke_exemption(
+        env: Env,
+        caller: Address,
+        seller: Address,
+        jurisdiction: Symbol,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, Role::Compliance, &caller)?;
+        enter(&env, "revoke_exemption")?;
+
+        env.storage()
+            .persistent()
+            .remove(&ExtKey::WithholdingExempt(
+                seller.clone(),
+                jurisdiction.clone(),
+            ));
+        events::emit(
+            &env,
+            &ExemptionRevoked {
+                seller,
+                jurisdiction,
+            },
+        );
+        Ok(())
+    }
+
+    /**
+     * @dev Returns whether a seller holds a valid exemption for a jurisdiction
+     * in this ledger.
+     */
+    pub fn is_exempt(env: Env, seller: Address, jurisdiction: Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, u64>(&ExtKey::WithholdingExempt(seller, jurisdiction))
+            .is_some_and(|valid_until| env.ledger().timestamp() <= valid_until)
+    }
+
+    /**
+     * @dev Returns how much was withheld from a seller's resales in a calendar
+     * year, in one payment token.
+     */
+    pub fn withheld(env: Env, seller: Address, year: u32, payment_token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&ExtKey::Withheld(seller, year, payment_token))
+            .unwrap_or(0)
+    }
+}
+
+/// Withholds the tax on a resale of `token_id` of `hotel_id` for `price`,
+/// paying it from `from` to the rule's recipient. At most `proceeds`, what is
+/// left for the seller, is withheld. Returns the amount withheld.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn withhold(
+    env: &Env,
+    token_id: u64,
+    hotel_id: u32,
+    payment_token: &Address,
+    from: &Address,
+    seller: &Address,
+    price: Amount,
+    proceeds: Amount,
+) -> Amount {
+    let Some(rule) = HotelTimeshareContract::withholding(env.clone(), hotel_id) else {
+        return Amount::ZERO;
+    };
+    if HotelTimeshareContract::is_exempt(env.clone(), seller.clone(), rule.jurisdiction.clone()) {
+        return Amount::ZERO;
+    }
+    let unit = money::minor_unit(env, hotel_id, payment_token);
+    let share = Bps::of(rule.bps).and_then(|bps| money::share(price, bps, unit));
+    let amount = or_fail(env, share).min(proceeds);
+    if amount == Amount::ZERO {
+        return amount;
+    }
+
+    token::Client::new(env, payment_token).transfer(from, &rule.recipient, &amount.get());
+    let key = ExtKey::Withheld(
+        seller.clone(),
+        usage::current_year(env),
+        payment_token.clone(),
+    );
+    let total = Amount::of(env.storage().persistent().get(&key).unwrap_or(0))
+        .and_then(|total| total.checked_add(amount));
+    env.storage()
+        .persistent()
+        .set(&key, &or_fail(env, total).get());
+
+    events::emit(
+        env,
+        &TaxWithheld {
+            token_id,
+            seller: seller.clone(),
+            jurisdiction: rule.jurisdiction,
+            recipient: rule.recipient,
+            amount: amount.get(),
+        },
+    );
+    amount
+}
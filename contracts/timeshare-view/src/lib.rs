@@ -0,0 +1,256 @@
+//! Read-only companion view of the hotel timeshare contract.
+//!
+//! Dashboards want answers that take many entries of the core contract to
+//! assemble: every token an owner holds with its tier, listing and
+//! maintenance status, or the weeks an owner holds laid out in calendar
+//! order. This contract builds them from cross-contract reads, so those
+//! queries add nothing to the core contract's wasm or instance storage.
+//!
+//! It never writes to the core contract. Its own entries are a cache:
+//! `refresh_portfolio` stores a page of an owner's portfolio in temporary
+//! storage, and `portfolio` and `calendar` serve it while it is at most
+//! CACHE_LEDGERS old, building the page afresh otherwise. Cache entries
+//! expire on their own after CACHE_TTL ledgers.
+//!
+//! Like the conformance suite, it carries its own copies of the wire types it
+//! reads rather than depending on the contract crate.
+
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, Address, Env, String, Symbol, Vec,
+};
+
+/// Most tokens one portfolio page covers.
+pub const MAX_PORTFOLIO_PAGE: u32 = 20;
+
+/// Ledgers a cached page is served for (about a minute).
+pub const CACHE_LEDGERS: u32 = 12;
+
+/// TTL, in ledgers, of cached pages (about a day).
+pub const CACHE_TTL: u32 = 17_280;
+
+// --- WIRE TYPES OF THE CORE CONTRACT ---
+
+/// Details of one timeshare token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimeshareInfo {
+    pub hotel_id: u32,
+    pub room: String,
+    pub week: u32,
+    pub year: Option<u32>,
+    pub lease_end_ledger: Option<u32>,
+}
+
+/// A token listed for sale at a fixed price.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Listing {
+    pub seller: Address,
+    pub price: i128,
+    pub payment_token: Address,
+}
+
+/// Whether a token's maintenance fee is paid through the current year.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceStatus {
+    pub year: u32,
+    pub paid_through: u32,
+    pub unpaid_years: u32,
+    pub paid: bool,
+    pub delinquent: bool,
+}
+
+/// The reads of the core contract this view is built from.
+#[contractclient(name = "CoreClient")]
+pub trait Core {
+    fn tokens_of_owner(env: Env, owner: Address) -> Vec<u64>;
+    fn get_info(env: Env, token_id: u64) -> TimeshareInfo;
+    fn tier(env: Env, token_id: u64) -> Option<Symbol>;
+    fn listing(env: Env, token_id: u64) -> Option<Listing>;
+    fn maintenance_status(env: Env, token_id: u64) -> MaintenanceStatus;
+}
+
+// --- VIEWS ---
+
+/**
+ * @title ListingStatus
+ * @dev Whether a holding is for sale. (A plain Option<Listing> field can't be
+ * converted to an ScVal by the SDK.)
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ListingStatus {
+    Unlisted,
+    Listed(Listing),
+}
+
+/**
+ * @title Holding
+ * @dev One token of a portfolio, with what a dashboard shows next to it.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Holding {
+    pub token_id: u64,
+    pub info: TimeshareInfo,
+    pub tier: Option<Symbol>,
+    pub listing: ListingStatus,
+    pub maintenance: MaintenanceStatus,
+}
+
+/**
+ * @title Portfolio
+ * @dev One page of an owner's tokens, in the order tokens_of_owner() returns them.
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Portfolio {
+    pub owner: Address,
+    pub holdings: Vec<Holding>,
+    /// The next page to pass, or None on the last page.
+    pub next_page: Option<u32>,
+    /// Ledger sequence the page was built in.
+    pub ledger: u32,
+}
+
+/**
+ * @title CalendarEntry
+ * @dev A week an owner holds, as listed by calendar().
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CalendarEntry {
+    pub week: u32,
+    pub token_id: u64,
+    pub hotel_id: u32,
+    pub room: String,
+    /// The only year the right is for; None for every year.
+    pub year: Option<u32>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Core,                    // Stores the core contract's Address
+    Portfolio(Address, u32), // Stores a cached Portfolio page (owner, page)
+}
+
+#[contract]
+pub struct TimeshareView;
+
+#[contractimpl]
+impl TimeshareView {
+    /**
+     * @dev Binds the view to the core contract it reads.
+     */
+    pub fn __constructor(env: Env, core: Address) {
+        env.storage().instance().set(&DataKey::Core, &core);
+    }
+
+    /**
+     * @dev Returns the core contract this view reads.
+     */
+    pub fn core(env: Env) -> Address {
+        core(&env)
+    }
+
+    /**
+     * @dev Returns a page of `owner`'s portfolio: the cached one while it is
+     * at most CACHE_LEDGERS old, otherwise one built afresh (without caching
+     * it).
+     * @param page Which MAX_PORTFOLIO_PAGE-sized slice of the owner's tokens, from 0.
+     */
+    pub fn portfolio(env: Env, owner: Address, page: u32) -> Portfolio {
+        let cached = Self::cached_portfolio(env.clone(), owner.clone(), page)
+            .filter(|cached| env.ledger().sequence() <= cached.ledger + CACHE_LEDGERS);
+        cached.unwrap_or_else(|| build(&env, &owner, page))
+    }
+
+    /**
+     * @dev Builds a page of `owner`'s portfolio and caches it. Anyone may call
+     * it, e.g. a dashboard's backend before a burst of reads.
+     */
+    pub fn refresh_portfolio(env: Env, owner: Address, page: u32) -> Portfolio {
+        let portfolio = build(&env, &owner, page);
+        let key = DataKey::Portfolio(owner, page);
+        env.storage().temporary().set(&key, &portfolio);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, CACHE_TTL, CACHE_TTL);
+        portfolio
+    }
+
+    /**
+     * @dev Returns the cached page of `owner`'s portfolio, however old, if one
+     * hasn't expired.
+     */
+    pub fn cached_portfolio(env: Env, owner: Address, page: u32) -> Option<Portfolio> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Portfolio(owner, page))
+    }
+
+    /**
+     * @dev Lists the weeks held in a page of `owner`'s portfolio, by week and
+     * then token ID, from the same data as portfolio().
+     */
+    pub fn calendar(env: Env, owner: Address, page: u32) -> Vec<CalendarEntry> {
+        let mut calendar: Vec<CalendarEntry> = Vec::new(&env);
+        for holding in Self::portfolio(env.clone(), owner, page).holdings.iter() {
+            let entry = CalendarEntry {
+                week: holding.info.week,
+                token_id: holding.token_id,
+                hotel_id: holding.info.hotel_id,
+                room: holding.info.room,
+                year: holding.info.year,
+            };
+            let at = calendar
+                .iter()
+                .position(|other| (other.week, other.token_id) > (entry.week, entry.token_id))
+                .map_or(calendar.len(), |at| at as u32);
+            calendar.insert(at, entry);
+        }
+        calendar
+    }
+}
+
+fn core(env: &Env) -> Address {
+    env.storage().instance().get(&DataKey::Core).unwrap()
+}
+
+fn build(env: &Env, owner: &Address, page: u32) -> Portfolio {
+    let core = CoreClient::new(env, &core(env));
+    let tokens = core.tokens_of_owner(owner);
+    let start = page.saturating_mul(MAX_PORTFOLIO_PAGE).min(tokens.len());
+    let end = tokens.len().min(start.saturating_add(MAX_PORTFOLIO_PAGE));
+
+    let mut holdings = Vec::new(env);
+    for token_id in tokens.slice(start..end).iter() {
+        holdings.push_back(Holding {
+            token_id,
+            info: core.get_info(&token_id),
+            tier: core.tier(&token_id),
+            listing: match core.listing(&token_id) {
+                Some(listing) => ListingStatus::Listed(listing),
+                None => ListingStatus::Unlisted,
+            },
+            maintenance: core.maintenance_status(&token_id),
+        });
+    }
+    Portfolio {
+        owner: owner.clone(),
+        holdings,
+        next_page: if end < tokens.len() {
+            Some(page + 1)
+        } else {
+            None
+        },
+        ledger: env.ledger().sequence(),
+    }
+}
+
+#[cfg(test)]
+mod test;
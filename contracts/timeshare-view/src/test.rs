@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use hello_world::{HotelTimeshareContract, HotelTimeshareContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+fn setup(env: &Env) -> (HotelTimeshareContractClient<'_>, TimeshareViewClient<'_>) {
+    env.mock_all_auths();
+    let core_id = env.register(HotelTimeshareContract, ());
+    let core = HotelTimeshareContractClient::new(env, &core_id);
+    let admin = Address::generate(env);
+    core.initialize(&admin);
+    core.register_hotel(&admin, &String::from_str(env, "Grand Hotel"));
+    core.add_room(&admin, &1, &String::from_str(env, "Room 305"));
+    let view_id = env.register(TimeshareView, (core_id.clone(),));
+    (core, TimeshareViewClient::new(env, &view_id))
+}
+
+fn mint(env: &Env, core: &HotelTimeshareContractClient, to: &Address, week: u32) -> u64 {
+    let room = String::from_str(env, "Room 305");
+    core.mint(to, &1, &room, &week, &None, &None)
+}
+
+#[test]
+fn test_portfolio_and_calendar() {
+    let env = Env::default();
+    let (core, view) = setup(&env);
+    let alice = Address::generate(&env);
+    let late = mint(&env, &core, &alice, 40);
+    let early = mint(&env, &core, &alice, 12);
+    let payment = Address::generate(&env);
+    core.list_for_sale(&alice, &late, &500, &payment);
+    assert_eq!(view.core(), core.address);
+
+    let portfolio = view.portfolio(&alice, &0);
+    assert_eq!(portfolio.owner, alice);
+    assert_eq!(portfolio.next_page, None);
+    assert_eq!(portfolio.holdings.len(), 2);
+    let holding = portfolio.holdings.get(0).unwrap();
+    assert_eq!(holding.token_id, late);
+    assert_eq!(holding.info.week, 40);
+    assert_eq!(
+        holding.listing,
+        ListingStatus::Listed(Listing {
+            seller: alice.clone(),
+            price: 500,
+            payment_token: payment,
+        })
+    );
+    assert!(!holding.maintenance.delinquent);
+    assert_eq!(
+        portfolio.holdings.get(1).unwrap().listing,
+        ListingStatus::Unlisted
+    );
+
+    // The calendar orders the same tokens by week.
+    let calendar = view.calendar(&alice, &0);
+    assert_eq!(calendar.get(0).unwrap().token_id, early);
+    assert_eq!(calendar.get(1).unwrap().token_id, late);
+    assert_eq!(
+        calendar.get(1).unwrap().room,
+        String::from_str(&env, "Room 305")
+    );
+}
+
+#[test]
+fn test_portfolio_pages() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let (core, view) = setup(&env);
+    let alice = Address::generate(&env);
+    for week in 1..=MAX_PORTFOLIO_PAGE + 1 {
+        mint(&env, &core, &alice, week);
+    }
+
+    let first = view.portfolio(&alice, &0);
+    assert_eq!(first.holdings.len(), MAX_PORTFOLIO_PAGE);
+    assert_eq!(first.next_page, Some(1));
+    let last = view.portfolio(&alice, &1);
+    assert_eq!(last.holdings.len(), 1);
+    assert_eq!(last.next_page, None);
+    assert_eq!(view.portfolio(&alice, &2).holdings.len(), 0);
+}
+
+#[test]
+fn test_portfolio_cache() {
+    let env = Env::default();
+    let (core, view) = setup(&env);
+    let alice = Address::generate(&env);
+    let token_id = mint(&env, &core, &alice, 28);
+    assert_eq!(view.cached_portfolio(&alice, &0), None);
+
+    let cached = view.refresh_portfolio(&alice, &0);
+    assert_eq!(view.cached_portfolio(&alice, &0), Some(cached.clone()));
+
+    // A listing made after the refresh shows once the cache goes stale.
+    core.list_for_sale(&alice, &token_id, &500, &Address::generate(&env));
+    env.ledger()
+        .with_mut(|l| l.sequence_number += CACHE_LEDGERS);
+    assert_eq!(view.portfolio(&alice, &0), cached);
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    let fresh = view.portfolio(&alice, &0);
+    assert_ne!(
+        fresh.holdings.get(0).unwrap().listing,
+        ListingStatus::Unlisted
+    );
+    assert_eq!(fresh.ledger, cached.ledger + CACHE_LEDGERS + 1);
+    // Reading doesn't replace the cache.
+    assert_eq!(view.cached_portfolio(&alice, &0), Some(cached));
+}
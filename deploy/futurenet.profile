@@ -0,0 +1,5 @@
+# Deployment profile for `cargo xtask deploy futurenet` / `cargo xtask init futurenet`.
+# source and admin may be stellar CLI identity names (see `stellar keys ls`) or addresses.
+network = futurenet
+source = deployer
+admin = deployer
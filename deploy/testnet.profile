@@ -0,0 +1,5 @@
+# Deployment profile for `cargo xtask deploy testnet` / `cargo xtask init testnet`.
+# source and admin may be stellar CLI identity names (see `stellar keys ls`) or addresses.
+network = testnet
+source = deployer
+admin = deployer
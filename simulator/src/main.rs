@@ -0,0 +1,346 @@
+// Scenario simulator, run with `cargo run --release -p timeshare-simulator -- <scenario.yaml>`.
+//
+// Product and finance teams model policy changes (maintenance fees, royalties,
+// reserve shares) by running a scripted scenario against the contract in the
+// SDK's test environment, entirely off-chain, then reading the final state and
+// what each endpoint cost. `scenarios/baseline.yaml` mints 200 tokens, runs 50
+// trades, advances a year and runs a fee cycle.
+//
+// Costs are the SDK's estimates for each top-level call. The contract runs
+// natively rather than as Wasm, so they leave out VM instantiation and
+// execution: compare them between scenarios rather than read them as network
+// fees. `advance` only moves the ledger clock, not the sequence, so storage
+// TTLs don't run out mid-scenario. Run it in release: debug builds echo every
+// contract log line.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Debug;
+use std::path::Path;
+use std::process;
+
+use hello_world::{
+    HotelTimeshareContract, HotelTimeshareContractClient, MaintenanceSchedule, TimeshareInfo,
+};
+use soroban_sdk::testutils::{Address as _, EnvTestConfig, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String};
+
+mod scenario;
+
+use scenario::{Scenario, Step};
+
+const USAGE: &str = "Usage: cargo run --release -p timeshare-simulator -- <scenario.yaml>";
+
+const WEEKS: u32 = 52;
+
+fn main() {
+    let args: Vec<std::string::String> = env::args().skip(1).collect();
+    let [path] = args.as_slice() else {
+        eprintln!("{USAGE}");
+        process::exit(2);
+    };
+
+    let result = Scenario::load(Path::new(path)).and_then(|scenario| run(&scenario));
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
+
+fn run(scenario: &Scenario) -> Result<(), std::string::String> {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+    env.ledger().set_timestamp(scenario.start);
+
+    let mut sim = Simulation::new(&env, scenario);
+    for (index, step) in scenario.steps.iter().enumerate() {
+        sim.step(step)
+            .map_err(|err| format!("step {} ({step:?}): {err}", index + 1))?;
+    }
+    sim.report(scenario);
+    Ok(())
+}
+
+/// What the calls to one endpoint cost, summed.
+#[derive(Default)]
+struct Cost {
+    calls: u64,
+    instructions: i64,
+    fee: i64,
+}
+
+struct Simulation<'a> {
+    env: &'a Env,
+    client: HotelTimeshareContractClient<'a>,
+    admin: Address,
+    /// The payment token every price and fee is paid in.
+    asset: Address,
+    collector: Address,
+    payout: Address,
+    owners: Vec<Address>,
+    properties: u32,
+    rooms: u32,
+    tokens: Vec<u64>,
+    trades: u32,
+    volume: i128,
+    late: u32,
+    costs: BTreeMap<&'static str, Cost>,
+}
+
+impl<'a> Simulation<'a> {
+    fn new(env: &'a Env, scenario: &Scenario) -> Self {
+        let contract_id = env.register(HotelTimeshareContract, ());
+        let client = HotelTimeshareContractClient::new(env, &contract_id);
+        let admin = Address::generate(env);
+        client.initialize(&admin);
+        for property in 1..=scenario.properties {
+            let name = format!("Property {property}");
+            let hotel_id = client.register_hotel(&admin, &String::from_str(env, &name));
+            for room in 1..=scenario.rooms {
+                let room = String::from_str(env, &format!("Room {room}"));
+                client.add_room(&admin, &hotel_id, &room);
+            }
+        }
+
+        let asset = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let owners: Vec<Address> = (0..scenario.owners)
+            .map(|_| {
+                let owner = Address::generate(env);
+                StellarAssetClient::new(env, &asset).mint(&owner, &scenario.balance);
+                owner
+            })
+            .collect();
+
+        Simulation {
+            env,
+            client,
+            admin,
+            asset,
+            collector: Address::generate(env),
+            payout: Address::generate(env),
+            owners,
+            properties: scenario.properties,
+            rooms: scenario.rooms,
+            tokens: Vec::new(),
+            trades: 0,
+            volume: 0,
+            late: 0,
+            costs: BTreeMap::new(),
+        }
+    }
+
+    fn step(&mut self, step: &Step) -> Result<(), std::string::String> {
+        match *step {
+            Step::Maintenance { fee } => {
+                let schedule = MaintenanceSchedule {
+                    token: self.asset.clone(),
+                    fee,
+                    collector: self.collector.clone(),
+                };
+                call(self.client.try_set_maintenance_schedule(&schedule))?;
+                self.meter("set_maintenance_schedule");
+            }
+            Step::Royalty { bps } => {
+                for hotel_id in 1..=self.properties {
+                    call(
+                        self.client
+                            .try_set_royalty(&self.admin, &hotel_id, &self.payout, &bps),
+                    )?;
+                    self.meter("set_royalty");
+                }
+            }
+            Step::ReserveShare { bps } => {
+                for hotel_id in 1..=self.properties {
+                    call(
+                        self.client
+                            .try_set_reserve_share(&self.admin, &hotel_id, &bps),
+                    )?;
+                    self.meter("set_reserve_share");
+                }
+            }
+            Step::Mint { count } => {
+                for _ in 0..count {
+                    self.mint()?;
+                }
+            }
+            Step::Trade { count, price } => {
+                for _ in 0..count {
+                    self.trade(price)?;
+                }
+            }
+            Step::Advance { days } => {
+                let seconds = u64::from(days) * 86_400;
+                self.env
+                    .ledger()
+                    .with_mut(|ledger| ledger.timestamp += seconds);
+            }
+            Step::FeeCycle => self.fee_cycle(),
+        }
+        Ok(())
+    }
+
+    /// Mints the next week: properties take turns, then rooms, then weeks.
+    fn mint(&mut self) -> Result<(), std::string::String> {
+        let n = self.tokens.len() as u32;
+        let hotel_id = n % self.properties + 1;
+        let slot = n / self.properties;
+        if slot >= self.rooms * WEEKS {
+            return Err("every week of every room is minted already".into());
+        }
+        let owner = &self.owners[n as usize % self.owners.len()];
+        let info = TimeshareInfo {
+            hotel_id,
+            room: String::from_str(self.env, &format!("Room {}", slot / WEEKS + 1)),
+            week: slot % WEEKS + 1,
+            year: None,
+            lease_end_ledger: None,
+        };
+        let token_id = call(self.client.try_mint(
+            owner,
+            &info.hotel_id,
+            &info.room,
+            &info.week,
+            &info.year,
+            &info.lease_end_ledger,
+        ))?;
+        self.meter("mint");
+        self.tokens.push(token_id);
+        Ok(())
+    }
+
+    /// Lists a token and sells it to another owner. Tokens are picked with a
+    /// fixed stride so trades spread over the portfolio.
+    fn trade(&mut self, price: i128) -> Result<(), std::string::String> {
+        if self.tokens.is_empty() {
+            return Err("nothing minted to trade".into());
+        }
+        let pick = (self.trades as usize * 7_919) % self.tokens.len();
+        let token_id = self.tokens[pick];
+        let seller = self.client.get_owner(&token_id);
+        // Buyers take turns too, so no one owner runs out of funds first.
+        let mut buyer = self.trades as usize % self.owners.len();
+        if self.owners[buyer] == seller {
+            buyer = (buyer + 1) % self.owners.len();
+        }
+        let buyer = self.owners[buyer].clone();
+        if TokenClient::new(self.env, &self.asset).balance(&buyer) < price {
+            return Err("the buyer can't afford the price; raise the owners' balance".into());
+        }
+
+        call(
+            self.client
+                .try_list_for_sale(&seller, &token_id, &price, &self.asset),
+        )?;
+        self.meter("list_for_sale");
+        call(self.client.try_buy(&buyer, &token_id))?;
+        self.meter("buy");
+        self.trades += 1;
+        self.volume += price;
+        Ok(())
+    }
+
+    /// Every token's owner pays what it owes. An owner who can't pay leaves the
+    /// token late.
+    fn fee_cycle(&mut self) {
+        if self.client.maintenance_schedule().is_none() {
+            return;
+        }
+        for token_id in self.tokens.clone() {
+            let status = self.client.maintenance_status(&token_id);
+            if status.unpaid_years == 0 {
+                continue;
+            }
+            let owner = self.client.get_owner(&token_id);
+            let paid = self
+                .client
+                .try_pay_maintenance(&owner, &token_id, &self.asset)
+                .is_ok_and(|result| result.is_ok());
+            self.meter("pay_maintenance");
+            if !paid {
+                self.late += 1;
+            }
+        }
+    }
+
+    /// Adds the last call's estimated cost to its endpoint's total.
+    fn meter(&mut self, endpoint: &'static str) {
+        let estimate = self.env.cost_estimate();
+        let cost = self.costs.entry(endpoint).or_default();
+        cost.calls += 1;
+        cost.instructions += estimate.resources().instructions;
+        cost.fee += estimate.fee().total;
+    }
+
+    fn report(&self, scenario: &Scenario) {
+        let token = TokenClient::new(self.env, &self.asset);
+        let reserves: i128 = (1..=self.properties)
+            .filter_map(|hotel_id| self.client.try_reserve_report(&hotel_id).ok()?.ok())
+            .map(|report| report.balance)
+            .sum();
+        let delinquent = self
+            .tokens
+            .iter()
+            .filter(|token_id| self.client.maintenance_status(token_id).delinquent)
+            .count();
+
+        println!("Scenario: {}", scenario.name);
+        println!();
+        println!("Final state");
+        println!("  ledger time          {}", self.env.ledger().timestamp());
+        println!("  tokens               {}", self.client.total_supply());
+        println!("  trades               {}", self.trades);
+        println!("  trade volume         {}", amount(self.volume));
+        println!(
+            "  maintenance paid     {}",
+            amount(token.balance(&self.collector))
+        );
+        println!("  held in reserves     {}", amount(reserves));
+        println!(
+            "  royalties paid       {}",
+            amount(token.balance(&self.payout))
+        );
+        println!("  payments missed      {}", self.late);
+        println!("  tokens delinquent    {delinquent}");
+        println!();
+        println!("Costs (estimated; native execution, no Wasm VM)");
+        println!(
+            "  {:<26} {:>6} {:>16} {:>16}",
+            "endpoint", "calls", "instructions", "fee (stroops)"
+        );
+        let mut total = Cost::default();
+        for (endpoint, cost) in &self.costs {
+            println!(
+                "  {endpoint:<26} {:>6} {:>16} {:>16}",
+                cost.calls, cost.instructions, cost.fee
+            );
+            total.calls += cost.calls;
+            total.instructions += cost.instructions;
+            total.fee += cost.fee;
+        }
+        println!(
+            "  {:<26} {:>6} {:>16} {:>16}",
+            "total", total.calls, total.instructions, total.fee
+        );
+    }
+}
+
+/// Unwraps a `try_` client call's result, describing the error if it failed.
+fn call<T, C: Debug, E: Debug>(result: Result<Result<T, C>, E>) -> Result<T, std::string::String> {
+    match result {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(err)) => Err(format!("{err:?}")),
+        Err(err) => Err(format!("{err:?}")),
+    }
+}
+
+/// Formats stroops as a decimal amount of the payment token (7 decimals).
+fn amount(stroops: i128) -> std::string::String {
+    let sign = if stroops < 0 { "-" } else { "" };
+    let stroops = stroops.unsigned_abs();
+    format!("{sign}{}.{:07}", stroops / 10_000_000, stroops % 10_000_000)
+}
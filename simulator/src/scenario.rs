@@ -0,0 +1,87 @@
+// Scenario files.
+//
+// A scenario describes the portfolio (properties, rooms, owners and what each
+// owner starts with) and then a list of steps run in order. Policy steps set
+// the fees the contract charges; activity steps mint, trade, let time pass and
+// collect maintenance fees. Amounts are in stroops of the payment token.
+//
+// ```yaml
+// name: Baseline year
+// properties: 2
+// rooms: 10
+// owners: 60
+// balance: 1000000000000
+// steps:
+//   - maintenance: { fee: 2500000000 }
+//   - mint: { count: 200 }
+//   - trade: { count: 50, price: 80000000000 }
+//   - advance: { days: 365 }
+//   - fee_cycle
+// ```
+
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// 2026-01-01T00:00:00Z, where scenarios start unless they say otherwise.
+const DEFAULT_START: u64 = 1_767_225_600;
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    pub name: String,
+    /// Unix time the ledger clock starts at.
+    #[serde(default = "default_start")]
+    pub start: u64,
+    /// Properties registered before the first step.
+    pub properties: u32,
+    /// Rooms per property; each room has 52 weeks to mint.
+    pub rooms: u32,
+    /// Owners the minted tokens are spread over, who also trade among themselves.
+    pub owners: u32,
+    /// What each owner starts with.
+    pub balance: i128,
+    /// Written `- step: { ... }` rather than with YAML tags.
+    #[serde(with = "serde_yaml::with::singleton_map_recursive")]
+    pub steps: Vec<Step>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum Step {
+    /// Bills every token this fee per year.
+    Maintenance { fee: i128 },
+    /// Takes a royalty of `bps` on every sale, at every property.
+    Royalty { bps: u32 },
+    /// Holds `bps` of every property's maintenance fees in its reserve.
+    ReserveShare { bps: u32 },
+    /// Mints the next `count` weeks, round-robin over the properties and owners.
+    Mint { count: u32 },
+    /// Sells `count` tokens at `price` each, each to another owner.
+    Trade { count: u32, price: i128 },
+    /// Moves the ledger clock forward.
+    Advance { days: u32 },
+    /// Every owner pays what their tokens owe in maintenance, if they can.
+    FeeCycle,
+}
+
+fn default_start() -> u64 {
+    DEFAULT_START
+}
+
+impl Scenario {
+    pub fn load(path: &Path) -> Result<Scenario, String> {
+        let yaml = fs::read_to_string(path)
+            .map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+        let scenario: Scenario = serde_yaml::from_str(&yaml)
+            .map_err(|err| format!("invalid scenario {}: {err}", path.display()))?;
+        if scenario.properties == 0 || scenario.rooms == 0 {
+            return Err("a scenario needs at least one property and one room".into());
+        }
+        if scenario.owners < 2 {
+            return Err("a scenario needs at least two owners to trade".into());
+        }
+        Ok(scenario)
+    }
+}
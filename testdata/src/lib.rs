@@ -0,0 +1,295 @@
+//! Deterministic generators of realistic hotel timeshare data.
+//!
+//! Benchmarks, the simulator and fuzz tests should measure against data shaped
+//! like a real deployment rather than one hotel with one room:
+//!
+//! - **Inventory**: properties of very different sizes (boutique hotels to large
+//!   resorts), rooms numbered by floor, and only part of each room's 52 weeks
+//!   sold as timeshares, with high-season weeks sold far more often.
+//! - **Owners**: a long-tailed population. Most owners hold one or two weeks; a
+//!   few investors hold dozens.
+//! - **Activity**: a year of sales, gifts and rentals with a seasonal rhythm,
+//!   always consistent with the current holder of each unit.
+//!
+//! Everything is derived from a `u64` seed with a built-in PRNG, so the same
+//! seed yields the same dataset on every platform and toolchain.
+//!
+//! ```ignore
+//! let data = timeshare_testdata::generate(42, &Profile::SMALL);
+//! for (unit, holder) in data.units.iter().zip(&data.holders) { /* mint */ }
+//! for activity in &data.activity { /* replay */ }
+//! ```
+//!
+//! Owners and renters are plain indices (`0..profile.owners`) so callers can map
+//! them to whatever addresses their environment provides.
+
+/// Size of a generated dataset.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Profile {
+    pub properties: u32,
+    /// Rooms in the largest property; most are much smaller.
+    pub max_rooms: u32,
+    pub owners: u32,
+    /// Length of the activity log, in days.
+    pub days: u32,
+}
+
+impl Profile {
+    /// Quick enough for unit tests.
+    pub const SMALL: Profile = Profile {
+        properties: 2,
+        max_rooms: 6,
+        owners: 40,
+        days: 365,
+    };
+    /// A regional operator's portfolio over a year; for benchmarks and simulation.
+    pub const REALISTIC: Profile = Profile {
+        properties: 25,
+        max_rooms: 250,
+        owners: 20_000,
+        days: 365,
+    };
+}
+
+/// One sold timeshare week, i.e. one token to mint.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unit {
+    pub hotel: String,
+    pub room: String,
+    /// Week of the year, 1-52.
+    pub week: u32,
+}
+
+/// One day's event in the activity log. `unit` indexes `Dataset::units`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Activity {
+    /// The holder sells the unit on the secondary market.
+    Sale {
+        day: u32,
+        unit: usize,
+        from: u32,
+        to: u32,
+        /// Price in stroops.
+        price: i128,
+    },
+    /// The holder gives the unit away (inheritance, family).
+    Gift {
+        day: u32,
+        unit: usize,
+        from: u32,
+        to: u32,
+    },
+    /// The holder rents the week out without giving up ownership.
+    Rental {
+        day: u32,
+        unit: usize,
+        owner: u32,
+        renter: u32,
+        price: i128,
+    },
+}
+
+/// A generated inventory, its initial holders and a log of activity on it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dataset {
+    pub units: Vec<Unit>,
+    /// Initial holder of each unit, parallel to `units`.
+    pub holders: Vec<u32>,
+    /// Events in chronological order.
+    pub activity: Vec<Activity>,
+}
+
+impl Dataset {
+    /// Holder of each unit after replaying the whole activity log.
+    pub fn final_holders(&self) -> Vec<u32> {
+        let mut holders = self.holders.clone();
+        for event in &self.activity {
+            match *event {
+                Activity::Sale { unit, to, .. } | Activity::Gift { unit, to, .. } => {
+                    holders[unit] = to
+                }
+                Activity::Rental { .. } => {}
+            }
+        }
+        holders
+    }
+}
+
+/// Generates a dataset. The same `seed` and `profile` always give the same result.
+pub fn generate(seed: u64, profile: &Profile) -> Dataset {
+    let mut rng = Rng::new(seed);
+    let units = inventory(&mut rng, profile);
+    let holders = units
+        .iter()
+        .map(|_| owner(&mut rng, profile.owners))
+        .collect();
+    let mut data = Dataset {
+        units,
+        holders,
+        activity: Vec::new(),
+    };
+    data.activity = activity(&mut rng, &data, profile);
+    data
+}
+
+// --- INVENTORY ---
+
+const BRANDS: &[&str] = &[
+    "Grand", "Royal", "Harbor", "Summit", "Palm", "Coral", "Alpine", "Lakeside", "Sunset", "Marina",
+];
+const KINDS: &[&str] = &["Hotel", "Resort", "Suites", "Lodge", "Beach Club", "Inn"];
+const PLACES: &[&str] = &[
+    "Lisbon", "Cancun", "Aspen", "Maui", "Algarve", "Phuket", "Tenerife", "Orlando", "Bali",
+];
+
+fn inventory(rng: &mut Rng, profile: &Profile) -> Vec<Unit> {
+    let mut units = Vec::new();
+    for p in 0..profile.properties {
+        let hotel = format!(
+            "{} {} {} #{}",
+            rng.pick(BRANDS),
+            rng.pick(KINDS),
+            rng.pick(PLACES),
+            p + 1
+        );
+        // Sizes are long-tailed: many boutique properties, a few big resorts.
+        let smallest = profile.max_rooms.min(4);
+        let rooms =
+            smallest + (rng.unit() * rng.unit() * (profile.max_rooms - smallest) as f64) as u32;
+        let floors = (rooms / 20).max(1);
+        let per_floor = rooms.div_ceil(floors);
+        // Share of weeks sold as timeshares varies per property.
+        let sold = 0.3 + rng.unit() * 0.5;
+
+        for r in 0..rooms {
+            let room = format!("Room {}{:02}", r / per_floor + 1, r % per_floor + 1);
+            for week in 1..=52 {
+                if rng.unit() < sold * season(week) {
+                    units.push(Unit {
+                        hotel: hotel.clone(),
+                        room: room.clone(),
+                        week,
+                    });
+                }
+            }
+        }
+    }
+    units
+}
+
+/// Relative demand for a week (0.4 off-season to 1.0 at peak): northern summer
+/// and the end-of-year holidays.
+fn season(week: u32) -> f64 {
+    match week {
+        26..=35 | 51..=52 | 1 => 1.0,
+        22..=25 | 36..=38 | 12..=15 => 0.75,
+        _ => 0.4,
+    }
+}
+
+// --- OWNERS AND ACTIVITY ---
+
+/// Picks an owner index. One in five picks goes to the investors (the lowest 2%
+/// of indices), so they end up holding dozens of weeks while private owners
+/// hold one or two.
+fn owner(rng: &mut Rng, owners: u32) -> u32 {
+    let investors = (owners / 50).max(1);
+    if rng.unit() < 0.2 {
+        rng.below(investors as u64) as u32
+    } else {
+        rng.below(owners as u64) as u32
+    }
+}
+
+fn activity(rng: &mut Rng, data: &Dataset, profile: &Profile) -> Vec<Activity> {
+    let mut holders = data.holders.clone();
+    let mut log = Vec::new();
+    if data.units.is_empty() {
+        return log;
+    }
+    // About 4% of units change hands and 10% are rented out per year.
+    let per_day = data.units.len() as f64 * 0.14 / 365.0;
+
+    for day in 0..profile.days {
+        // Weeks of the year drive the seasonal rhythm of trading too.
+        let busy = season(day / 7 % 52 + 1);
+        let expected = per_day * busy * 1.6;
+        let events = expected as u32 + u32::from(rng.unit() < expected.fract());
+
+        for _ in 0..events {
+            let unit = rng.below(data.units.len() as u64) as usize;
+            let from = holders[unit];
+            let to = owner(rng, profile.owners);
+            let price = base_price(&data.units[unit]);
+            let roll = rng.unit();
+
+            let event = if roll < 0.25 && to != from {
+                holders[unit] = to;
+                Activity::Sale {
+                    day,
+                    unit,
+                    from,
+                    to,
+                    price: price * (80 + rng.below(41) as i128) / 100,
+                }
+            } else if roll < 0.30 && to != from {
+                holders[unit] = to;
+                Activity::Gift {
+                    day,
+                    unit,
+                    from,
+                    to,
+                }
+            } else {
+                Activity::Rental {
+                    day,
+                    unit,
+                    owner: from,
+                    renter: rng.below(profile.owners as u64) as u32,
+                    price: price / 12,
+                }
+            };
+            log.push(event);
+        }
+    }
+    log
+}
+
+/// Typical resale price of a unit, in stroops (1 XLM = 10^7 stroops).
+fn base_price(unit: &Unit) -> i128 {
+    let xlm = (8_000.0 * season(unit.week)) as i128;
+    xlm * 10_000_000
+}
+
+// --- PRNG ---
+
+/// SplitMix64: tiny, fast and identical everywhere, which is all test data needs.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1).
+    fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform in [0, n).
+    fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len() as u64) as usize]
+    }
+}
@@ -0,0 +1,223 @@
+// Workspace automation, run with `cargo xtask <command>`.
+//
+// Everything here shells out to the `stellar` CLI, the same tool the contract
+// Makefile uses, so the xtask only adds reproducible paths and ordering.
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+
+use sha2::{Digest, Sha256};
+
+mod smoke;
+
+// The contract crate and the wasm artifacts `stellar contract build/optimize` produce for it.
+const CONTRACT_DIR: &str = "contracts/hello-world";
+const WASM_PATH: &str = "target/wasm32v1-none/release/hello_world.wasm";
+const OPTIMIZED_WASM_PATH: &str = "target/wasm32v1-none/release/hello_world.optimized.wasm";
+
+// Where the generated TypeScript package is written.
+const TS_BINDINGS_DIR: &str = "bindings/typescript";
+const TS_PACKAGE_NAME: &str = "hotel-timeshare-client";
+
+// Deployment profiles (`<name>.profile`) and the contract IDs recorded for them.
+const DEPLOY_DIR: &str = "deploy";
+
+const USAGE: &str = "\
+Usage: cargo xtask <command> [args]
+
+Commands:
+  build               Build the contract wasm
+  optimize            Build, then optimize the wasm
+  hash                Build, optimize and print the sha256 of the optimized wasm
+  deploy <profile>    Build, optimize and deploy using deploy/<profile>.profile
+  init <profile>      Call initialize on the contract deployed for <profile>
+  bindings            Build the contract and regenerate the TypeScript client package
+  smoke <profile>     Deploy a throwaway instance to <profile>'s network and run an end-to-end scenario";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let profile = args.get(1).map(String::as_str);
+    let result = match (args.first().map(String::as_str), profile) {
+        (Some("build"), _) => build_wasm(&workspace_root()),
+        (Some("optimize"), _) => optimize(&workspace_root()),
+        (Some("hash"), _) => hash(),
+        (Some("deploy"), Some(profile)) => deploy(profile),
+        (Some("init"), Some(profile)) => init(profile),
+        (Some("bindings"), _) => bindings(),
+        (Some("smoke"), Some(profile)) => smoke::smoke(profile),
+        _ => {
+            eprintln!("{USAGE}");
+            process::exit(2);
+        }
+    };
+
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
+
+/// Prints the hash the network will report for the deployed code, so a
+/// release can be matched against what is live on chain.
+fn hash() -> Result<(), String> {
+    let root = workspace_root();
+    optimize(&root)?;
+    println!("{}", wasm_hash(&root.join(OPTIMIZED_WASM_PATH))?);
+    Ok(())
+}
+
+/// Deploys a fresh instance and records its ID in `deploy/<profile>.contract-id`
+/// for `init` and later invocations.
+fn deploy(name: &str) -> Result<(), String> {
+    let root = workspace_root();
+    let profile = Profile::load(&root, name)?;
+    optimize(&root)?;
+    println!("wasm hash: {}", wasm_hash(&root.join(OPTIMIZED_WASM_PATH))?);
+
+    let contract_id = capture(
+        Command::new("stellar")
+            .current_dir(&root)
+            .args(["contract", "deploy", "--wasm", OPTIMIZED_WASM_PATH])
+            .args(["--network", &profile.network])
+            .args(["--source", &profile.source]),
+    )?;
+
+    fs::write(profile.contract_id_path(&root), format!("{contract_id}\n"))
+        .map_err(|e| format!("failed to record contract id: {e}"))?;
+    println!("deployed {contract_id} to {}", profile.network);
+    Ok(())
+}
+
+/// Runs the one-time `initialize` transaction with the profile's admin.
+fn init(name: &str) -> Result<(), String> {
+    let root = workspace_root();
+    let profile = Profile::load(&root, name)?;
+    let path = profile.contract_id_path(&root);
+    let contract_id = fs::read_to_string(&path)
+        .map_err(|e| format!("no deployment recorded at {}: {e}", path.display()))?;
+
+    run(Command::new("stellar")
+        .current_dir(&root)
+        .args(["contract", "invoke", "--id", contract_id.trim()])
+        .args(["--network", &profile.network])
+        .args(["--source", &profile.source])
+        .args(["--", "initialize", "--admin", &profile.admin]))
+}
+
+/// Regenerates the npm package in `bindings/typescript` from the contract spec
+/// embedded in a fresh wasm build, so the frontend never hand-maintains types.
+fn bindings() -> Result<(), String> {
+    let root = workspace_root();
+    build_wasm(&root)?;
+
+    run(Command::new("stellar")
+        .current_dir(&root)
+        .args(["contract", "bindings", "typescript", "--wasm", WASM_PATH])
+        .args(["--output-dir", TS_BINDINGS_DIR])
+        .args(["--contract-name", TS_PACKAGE_NAME])
+        .arg("--overwrite"))?;
+
+    println!("TypeScript bindings written to {TS_BINDINGS_DIR}");
+    Ok(())
+}
+
+fn build_wasm(root: &Path) -> Result<(), String> {
+    run(Command::new("stellar")
+        .current_dir(root.join(CONTRACT_DIR))
+        .args(["contract", "build"]))
+}
+
+fn optimize(root: &Path) -> Result<(), String> {
+    build_wasm(root)?;
+    run(Command::new("stellar")
+        .current_dir(root)
+        .args(["contract", "optimize", "--wasm", WASM_PATH]))
+}
+
+fn wasm_hash(path: &Path) -> Result<String, String> {
+    let wasm = fs::read(path).map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    Ok(Sha256::digest(&wasm)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect())
+}
+
+/// A deployment target, read from `deploy/<name>.profile`: one `key = value`
+/// per line, `#` starts a comment.
+struct Profile {
+    name: String,
+    network: String,
+    source: String,
+    admin: String,
+}
+
+impl Profile {
+    fn load(root: &Path, name: &str) -> Result<Self, String> {
+        let path = root.join(DEPLOY_DIR).join(format!("{name}.profile"));
+        let text = fs::read_to_string(&path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+
+        let mut values: HashMap<&str, &str> = HashMap::new();
+        for line in text.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if let Some((key, value)) = line.split_once('=') {
+                values.insert(key.trim(), value.trim());
+            }
+        }
+        let get = |key: &str| {
+            values
+                .get(key)
+                .map(|v| v.to_string())
+                .ok_or_else(|| format!("{} is missing `{key}`", path.display()))
+        };
+
+        Ok(Profile {
+            name: name.to_string(),
+            network: get("network")?,
+            source: get("source")?,
+            admin: get("admin")?,
+        })
+    }
+
+    fn contract_id_path(&self, root: &Path) -> PathBuf {
+        root.join(DEPLOY_DIR)
+            .join(format!("{}.contract-id", self.name))
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    // CARGO_MANIFEST_DIR is <root>/xtask.
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives inside the workspace")
+        .to_path_buf()
+}
+
+fn run(cmd: &mut Command) -> Result<(), String> {
+    let status = cmd
+        .status()
+        .map_err(|e| format!("failed to run {:?}: {e}", cmd.get_program()))?;
+    if !status.success() {
+        return Err(format!("{:?} exited with {status}", cmd.get_program()));
+    }
+    Ok(())
+}
+
+/// Like `run`, but returns the command's trimmed stdout.
+fn capture(cmd: &mut Command) -> Result<String, String> {
+    let output = cmd
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("failed to run {:?}: {e}", cmd.get_program()))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{:?} exited with {}",
+            cmd.get_program(),
+            output.status
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
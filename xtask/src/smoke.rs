@@ -0,0 +1,358 @@
+// End-to-end smoke test against a live network: `cargo xtask smoke <profile>`.
+//
+// Deploys a fresh contract instance with the profile's network and source,
+// funds throwaway identities for the scenario's actors, drives the contract
+// through its lifecycle with `stellar contract invoke`, and checks every
+// result read back over RPC. Nothing in `deploy/` is touched: the instance
+// under test is separate from the one recorded for the profile.
+//
+// The throwaway identities are removed at the end whether the scenario passed
+// or not. The test instance itself stays on chain (contracts cannot be deleted)
+// and simply expires.
+
+use std::process::{self, Command};
+
+use crate::{capture, optimize, run, workspace_root, Profile, OPTIMIZED_WASM_PATH};
+
+// Actors of the scenario, created as stellar CLI identities for the run.
+const ACTORS: [&str; 3] = ["admin", "alice", "bob"];
+
+/// Runs the scenario; see the module comment.
+pub fn smoke(name: &str) -> Result<(), String> {
+    let root = workspace_root();
+    let profile = Profile::load(&root, name)?;
+    optimize(&root)?;
+
+    let run_tag = process::id();
+    let mut ctx = Context {
+        network: profile.network.clone(),
+        contract_id: String::new(),
+        identities: ACTORS
+            .iter()
+            .map(|a| format!("smoke-{run_tag}-{a}"))
+            .collect(),
+    };
+
+    let result = ctx.setup(&profile).and_then(|_| ctx.scenario());
+    ctx.teardown();
+    result?;
+
+    println!("smoke test passed on {}", ctx.network);
+    Ok(())
+}
+
+struct Context {
+    network: String,
+    contract_id: String,
+    /// CLI identity names, parallel to ACTORS.
+    identities: Vec<String>,
+}
+
+impl Context {
+    fn setup(&mut self, profile: &Profile) -> Result<(), String> {
+        for identity in &self.identities {
+            run(Command::new("stellar")
+                .args(["keys", "generate", identity, "--fund"])
+                .args(["--network", &self.network]))?;
+        }
+
+        self.contract_id = capture(
+            Command::new("stellar")
+                .current_dir(workspace_root())
+                .args(["contract", "deploy", "--wasm", OPTIMIZED_WASM_PATH])
+                .args(["--network", &self.network])
+                .args(["--source", &profile.source]),
+        )?;
+        println!("smoke instance: {}", self.contract_id);
+        Ok(())
+    }
+
+    /// The scripted lifecycle. Each step asserts on what the network returns.
+    /// Extend it whenever an endpoint ships that a release must not break.
+    fn scenario(&self) -> Result<(), String> {
+        let admin = self.address("admin")?;
+        let alice = self.address("alice")?;
+        let bob = self.address("bob")?;
+
+        step("initialize");
+        self.invoke("admin", &["initialize", "--admin", &admin])?;
+
+        step("inventory");
+        let hotel = self.invoke(
+            "admin",
+            &[
+                "register_hotel",
+                "--caller",
+                &admin,
+                "--name",
+                "Smoke Test Hotel",
+            ],
+        )?;
+        let listed = self.invoke(
+            "alice",
+            &["list_hotels", "--start_after", "0", "--limit", "10"],
+        )?;
+        expect_contains("list_hotels", &listed, "Smoke Test Hotel")?;
+        self.invoke(
+            "admin",
+            &[
+                "add_room",
+                "--caller",
+                &admin,
+                "--hotel_id",
+                &hotel,
+                "--room",
+                "Room 101",
+            ],
+        )?;
+        let rooms = self.invoke("alice", &["list_rooms", "--hotel_id", &hotel])?;
+        expect_contains("list_rooms", &rooms, "Room 101")?;
+
+        step("mint");
+        let token = self.invoke(
+            "admin",
+            &[
+                "mint",
+                "--to",
+                &alice,
+                "--hotel_id",
+                &hotel,
+                "--room",
+                "Room 101",
+                "--week",
+                "28",
+            ],
+        )?;
+        let info = self.invoke("alice", &["get_info", "--token_id", &token])?;
+        expect_contains("get_info", &info, "Room 101")?;
+
+        step("enumerate");
+        expect(
+            "get_owner",
+            &self.invoke("alice", &["get_owner", "--token_id", &token])?,
+            &alice,
+        )?;
+        expect(
+            "total_supply",
+            &self.invoke("alice", &["total_supply"])?,
+            "1",
+        )?;
+        let owned = self.invoke("alice", &["tokens_of_owner", "--owner", &alice])?;
+        expect_contains("tokens_of_owner", &owned, &token)?;
+
+        step("transfer");
+        self.invoke(
+            "alice",
+            &[
+                "transfer",
+                "--from",
+                &alice,
+                "--to",
+                &bob,
+                "--token_id",
+                &token,
+            ],
+        )?;
+        expect(
+            "get_owner",
+            &self.invoke("bob", &["get_owner", "--token_id", &token])?,
+            &bob,
+        )?;
+        expect(
+            "balance_of",
+            &self.invoke("bob", &["balance_of", "--owner", &bob])?,
+            "1",
+        )?;
+        expect(
+            "balance_of",
+            &self.invoke("alice", &["balance_of", "--owner", &alice])?,
+            "0",
+        )?;
+
+        step("rental");
+        // Bob rents this year's week out to alice, paid in XLM.
+        let xlm = self.native_asset()?;
+        self.invoke(
+            "bob",
+            &[
+                "list_for_rent",
+                "--owner",
+                &bob,
+                "--token_id",
+                &token,
+                "--token",
+                &xlm,
+                "--price",
+                "10000000",
+                "--renter_window",
+                &u64::MAX.to_string(),
+            ],
+        )?;
+        let rental = self.invoke("alice", &["rent", "--renter", &alice, "--token_id", &token])?;
+        expect_contains("rent", &rental, &alice)?;
+        expect(
+            "get_owner",
+            &self.invoke("bob", &["get_owner", "--token_id", &token])?,
+            &bob,
+        )?;
+
+        step("maintenance");
+        self.invoke(
+            "admin",
+            &[
+                "set_maintenance_schedule",
+                "--schedule",
+                &format!(r#"{{"token":"{xlm}","fee":"10000000","collector":"{admin}"}}"#),
+            ],
+        )?;
+        let delinquent = self.invoke(
+            "alice",
+            &["delinquent_tokens", "--start_after", "0", "--limit", "10"],
+        )?;
+        expect_contains("delinquent_tokens", &delinquent, &token)?;
+        self.invoke(
+            "bob",
+            &[
+                "pay_maintenance",
+                "--owner",
+                &bob,
+                "--token_id",
+                &token,
+                "--token_address",
+                &xlm,
+            ],
+        )?;
+        let status = self.invoke("bob", &["maintenance_status", "--token_id", &token])?;
+        expect_contains("maintenance_status", &status, r#""paid":true"#)?;
+
+        step("check_in");
+        // Alice stays as the renter.
+        self.invoke(
+            "alice",
+            &["check_in", "--guest", &alice, "--token_id", &token],
+        )?;
+        self.invoke(
+            "admin",
+            &["confirm_check_in", "--caller", &admin, "--token_id", &token],
+        )?;
+        self.invoke(
+            "alice",
+            &["check_out", "--guest", &alice, "--token_id", &token],
+        )?;
+        let usage = self.invoke("bob", &["usage", "--token_id", &token])?;
+        expect_contains("usage", &usage, "Consumed")?;
+
+        step("marketplace");
+        // Alice buys the token back from bob.
+        self.invoke(
+            "bob",
+            &[
+                "list_for_sale",
+                "--owner",
+                &bob,
+                "--token_id",
+                &token,
+                "--price",
+                "10000000",
+                "--payment_token",
+                &xlm,
+            ],
+        )?;
+        self.invoke("alice", &["buy", "--buyer", &alice, "--token_id", &token])?;
+        expect(
+            "get_owner",
+            &self.invoke("alice", &["get_owner", "--token_id", &token])?,
+            &alice,
+        )?;
+
+        step("upgrade");
+        // Re-installs the same build: exercises the upgrade path and the
+        // upgrade log without changing behavior for the steps that follow.
+        let wasm_hash = capture(
+            Command::new("stellar")
+                .current_dir(workspace_root())
+                .args(["contract", "upload", "--wasm", OPTIMIZED_WASM_PATH])
+                .args(["--network", &self.network])
+                .args(["--source", &self.identity("admin")]),
+        )?;
+        self.invoke("admin", &["upgrade", "--new_wasm_hash", &wasm_hash])?;
+        let version = self.invoke("alice", &["version_info"])?;
+        expect_contains("version_info", &version, &wasm_hash)?;
+        expect(
+            "get_owner",
+            &self.invoke("bob", &["get_owner", "--token_id", &token])?,
+            &alice,
+        )?;
+
+        Ok(())
+    }
+
+    fn teardown(&self) {
+        for identity in &self.identities {
+            // Best effort: a failed removal must not mask the scenario's result.
+            let _ = Command::new("stellar")
+                .args(["keys", "rm", identity])
+                .status();
+        }
+    }
+
+    /// Calls `args` on the smoke instance, signed by `actor`, and returns the
+    /// result with JSON string quotes removed.
+    fn invoke(&self, actor: &str, args: &[&str]) -> Result<String, String> {
+        let out = capture(
+            Command::new("stellar")
+                .args(["contract", "invoke", "--id", &self.contract_id])
+                .args(["--network", &self.network])
+                .args(["--source", &self.identity(actor)])
+                .arg("--")
+                .args(args),
+        )?;
+        Ok(out.trim_matches('"').to_string())
+    }
+
+    /// The native asset's contract ID, deploying its contract first if the
+    /// network doesn't have it yet.
+    fn native_asset(&self) -> Result<String, String> {
+        // Best effort: fails harmlessly when the contract already exists.
+        let _ = Command::new("stellar")
+            .args(["contract", "asset", "deploy", "--asset", "native"])
+            .args(["--network", &self.network])
+            .args(["--source", &self.identity("admin")])
+            .status();
+        capture(
+            Command::new("stellar")
+                .args(["contract", "id", "asset", "--asset", "native"])
+                .args(["--network", &self.network]),
+        )
+    }
+
+    fn address(&self, actor: &str) -> Result<String, String> {
+        capture(Command::new("stellar").args(["keys", "address", &self.identity(actor)]))
+    }
+
+    fn identity(&self, actor: &str) -> String {
+        let i = ACTORS
+            .iter()
+            .position(|a| *a == actor)
+            .expect("actor is listed in ACTORS");
+        self.identities[i].clone()
+    }
+}
+
+fn step(name: &str) {
+    println!("== {name}");
+}
+
+fn expect(what: &str, actual: &str, expected: &str) -> Result<(), String> {
+    if actual != expected {
+        return Err(format!("{what}: expected {expected}, got {actual}"));
+    }
+    Ok(())
+}
+
+fn expect_contains(what: &str, actual: &str, needle: &str) -> Result<(), String> {
+    if !actual.contains(needle) {
+        return Err(format!("{what}: expected {needle} in {actual}"));
+    }
+    Ok(())
+}